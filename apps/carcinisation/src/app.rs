@@ -2,7 +2,7 @@
 
 #![allow(clippy::too_many_lines)]
 
-use carcinisation_input::GBInput;
+use carcinisation_input::{GBActionsPlugin, GBInput, InputBindings};
 #[cfg(not(target_arch = "wasm32"))]
 use std::env;
 
@@ -22,7 +22,8 @@ use bevy_inspector_egui::{
     bevy_egui::{EguiContext, EguiPrimaryContextPass, PrimaryEguiContext, egui},
     bevy_inspector,
 };
-use carcinisation_core::components::{DespawnMark, VolumeSettings};
+use carcinisation_core::audio::{AudioMixerPlugin, AudioSettings};
+use carcinisation_core::components::DespawnMark;
 use carcinisation_core::systems::despawn_entities;
 #[cfg(not(target_arch = "wasm32"))]
 use dotenvy::dotenv_override;
@@ -31,6 +32,8 @@ use dotenvy::dotenv_override;
 use crate::debug::{DebugColliderOverlay, DebugGodMode, DebugPlugin};
 #[cfg(feature = "gallery")]
 use crate::gallery::GalleryPlugin;
+#[cfg(debug_assertions)]
+use crate::systems::camera::move_camera;
 #[cfg(not(target_arch = "wasm32"))]
 use crate::systems::setup::{set_fixed_timestep, set_framespace};
 use crate::{
@@ -47,7 +50,6 @@ use crate::{
     splash::SplashPlugin,
     stage::{StagePlugin, depth_debug::DepthDebugOverlay, player::crosshair::CrosshairSettings},
     systems::{
-        camera::move_camera,
         movement::{PositionSyncSystems, update_position_x, update_position_y},
         on_post_startup,
         setup::spawn_camera,
@@ -56,7 +58,9 @@ use crate::{
 };
 use carapace::animation::CxAnimationPlugin;
 use carapace::prelude::*;
-use carcinisation_input::init_gb_input;
+#[cfg(debug_assertions)]
+use carcinisation_input::{GBDebugInput, init_gb_debug_input};
+#[cfg(debug_assertions)]
 use leafwing_input_manager::prelude::InputManagerPlugin;
 
 /// Controls whether the full start/menu stack should run.
@@ -204,9 +208,11 @@ pub fn build_app(options: AppLaunchOptions) -> App {
     }
 
     app.init_resource::<DifficultySelected>()
-        .insert_resource(initial_volume_settings())
+        .insert_resource(initial_audio_settings())
         .insert_resource(load_dev_flags())
-        .add_plugins(InputManagerPlugin::<GBInput>::default());
+        .insert_resource(initial_input_bindings())
+        .add_plugins((AudioMixerPlugin, GBActionsPlugin::<GBInput>::default()))
+        .add_systems(Last, (persist_audio_settings, persist_input_bindings));
 
     app.insert_resource(DepthDebugOverlay::new(load_show_perspective()));
 
@@ -244,7 +250,10 @@ pub fn build_app(options: AppLaunchOptions) -> App {
         app.add_systems(Startup, spawn_camera);
     }
 
-    app.add_systems(Startup, init_gb_input);
+    #[cfg(debug_assertions)]
+    app.add_plugins(InputManagerPlugin::<GBDebugInput>::default())
+        .add_systems(Startup, init_gb_debug_input)
+        .add_systems(Update, move_camera);
 
     if options.start_flow.includes_start_flow() {
         app.add_plugins(SplashPlugin)
@@ -259,13 +268,16 @@ pub fn build_app(options: AppLaunchOptions) -> App {
     app.world_mut()
         .resource_mut::<carcinisation_ors::stage::StageHooks>()
         .trigger_transition = crate::transitions::trigger_transition;
+    // Stage cinematics need the cutscene player; without it they are skipped.
+    if options.start_flow.includes_start_flow() {
+        app.world_mut()
+            .resource_mut::<carcinisation_ors::stage::StageHooks>()
+            .play_cutscene = crate::cutscene::play_stage_cutscene;
+    }
     app.add_plugins(GamePlugin)
         .add_systems(
             Update,
-            (
-                move_camera,
-                (update_position_x, update_position_y).in_set(PositionSyncSystems),
-            ),
+            (update_position_x, update_position_y).in_set(PositionSyncSystems),
         )
         // NOTE:
         // Systems in PostUpdate that mutate gameplay entities via Commands must
@@ -293,12 +305,49 @@ pub fn build_app(options: AppLaunchOptions) -> App {
     app
 }
 
-fn initial_volume_settings() -> VolumeSettings {
+/// Storage name of the persisted [`AudioSettings`].
+const AUDIO_SETTINGS_STORAGE: &str = "audio_settings";
+
+fn initial_audio_settings() -> AudioSettings {
+    let settings =
+        crate::game::storage::load::<AudioSettings>(AUDIO_SETTINGS_STORAGE).unwrap_or_default();
     let Some(initial_sound_level) = load_initial_sound_level() else {
-        return VolumeSettings::default();
+        return settings;
     };
 
-    VolumeSettings::default().with_master_level(initial_sound_level)
+    settings.with_master_level(initial_sound_level)
+}
+
+/// @system Saves the audio settings whenever they are changed at runtime.
+fn persist_audio_settings(settings: Res<AudioSettings>) {
+    if settings.is_changed() && !settings.is_added() {
+        crate::game::storage::save(AUDIO_SETTINGS_STORAGE, &*settings);
+    }
+}
+
+/// Storage name of the persisted [`InputBindings`].
+const INPUT_BINDINGS_STORAGE: &str = "input_bindings";
+
+/// Stored bindings, or the defaults when none are stored or they conflict.
+/// Standalone binaries use this too, so a rebind applies everywhere.
+#[must_use]
+pub fn initial_input_bindings() -> InputBindings {
+    let Some(bindings) = crate::game::storage::load::<InputBindings>(INPUT_BINDINGS_STORAGE) else {
+        return InputBindings::default();
+    };
+    let conflicts = bindings.conflicts();
+    if !conflicts.is_empty() {
+        warn!("Ignoring conflicting input bindings {conflicts:?}; using defaults");
+        return InputBindings::default();
+    }
+    bindings
+}
+
+/// @system Saves the input bindings whenever they are rebound.
+fn persist_input_bindings(bindings: Res<InputBindings>) {
+    if bindings.is_changed() && !bindings.is_added() {
+        crate::game::storage::save(INPUT_BINDINGS_STORAGE, &*bindings);
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -22,6 +22,12 @@
 //! - `Cmd+P` / `Ctrl+P` to toggle the depth perspective grid overlay.
 //! - `Cmd+O` / `Ctrl+O` to toggle entity anchor markers.
 //! - `Shift+Left/Right` to pan the lateral view offset.
+//! - `Cmd+B` / `Ctrl+B` to toggle the collision broad-phase benchmark, which
+//!   logs grid refresh/query cost against pairwise bounds tests.
+//!
+//! The headless `broad_phase_bench_headless` test replays the same swarm
+//! layout with the pixel narrow phase included, for runs without a window:
+//! `cargo test -p carcinisation --bin carapace_mosquiton_stress --release -- --ignored --nocapture`
 //!
 //! This demo uses a synthetic traversal authority model and does not represent
 //! the gameplay spawn pipeline. Do not use it as a reference for the stage
@@ -57,6 +63,8 @@ use carcinisation::{
         resources::{ActiveProjection, DebugPanConfig, ProjectionView, StageTimeDomain},
     },
 };
+use carcinisation_collision::{BroadPhaseGrid, world_mask_rect_from_top_left};
+use std::time::{Duration, Instant};
 
 const MOSQUITON_COUNT: usize = 2000;
 const AUTHORED_DEPTH: Depth = Depth::Three;
@@ -71,6 +79,11 @@ const PERSPECTIVE_BIAS: f32 = 3.0;
 const AIRBORNE_DUTY_CYCLE: f32 = 0.65;
 const LIFTOFF_SECS: f32 = 0.5;
 const LANDING_SECS: f32 = 0.5;
+/// Probe rects per frame in the broad-phase benchmark, laid out as a square grid.
+const BENCH_PROBES_PER_AXIS: i32 = 8;
+/// Probe edge, roughly a player melee/shot mask.
+const BENCH_PROBE_SIZE: i32 = 12;
+const BENCH_REPORT_FRAMES: i32 = 120;
 
 /// Stress-only continuous traversal mapping.
 ///
@@ -196,6 +209,7 @@ fn main() {
     app.insert_resource(ClearColor(Color::BLACK))
         .insert_resource(DepthScaleConfig::load_or_default())
         .init_resource::<StressHorizonProfile>()
+        .init_resource::<BroadPhaseBench>()
         .insert_resource(ActiveProjection(StressHorizonProfile::default().profile))
         .init_resource::<ProjectionView>()
         .init_resource::<DebugPanConfig>()
@@ -216,6 +230,8 @@ fn main() {
                 prepare_composed_atlas_assets,
                 ensure_composed_enemy_parts,
                 update_composed_enemy_visuals,
+                toggle_broad_phase_bench,
+                run_broad_phase_bench,
                 update_stats_text,
             )
                 .chain(),
@@ -455,6 +471,155 @@ fn traverse_depths(
     }
 }
 
+/// Broad-phase benchmark state, toggled with `Cmd+B` / `Ctrl+B`.
+///
+/// Each frame refreshes a [`BroadPhaseGrid`] from the swarm's composed
+/// fragment bounds, then runs the same probe rects through the grid and
+/// through a pairwise bounds scan. Averages are logged every
+/// [`BENCH_REPORT_FRAMES`] frames. Bounds only; the headless test in this
+/// file also measures the narrow phase.
+#[derive(Resource, Default)]
+struct BroadPhaseBench {
+    enabled: bool,
+    grid: BroadPhaseGrid<Entity>,
+    bounds: Vec<(Entity, IRect, i8)>,
+    candidates: Vec<Entity>,
+    frames: i32,
+    refresh: Duration,
+    grid_query: Duration,
+    pairwise: Duration,
+    grid_candidates: usize,
+    pairwise_candidates: usize,
+}
+
+impl BroadPhaseBench {
+    fn reset_stats(&mut self) {
+        self.frames = 0;
+        self.refresh = Duration::ZERO;
+        self.grid_query = Duration::ZERO;
+        self.pairwise = Duration::ZERO;
+        self.grid_candidates = 0;
+        self.pairwise_candidates = 0;
+    }
+}
+
+/// Toggle the broad-phase benchmark with `Cmd+B` / `Ctrl+B`.
+fn toggle_broad_phase_bench(keys: Res<ButtonInput<KeyCode>>, mut bench: ResMut<BroadPhaseBench>) {
+    let modifier_held = keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]);
+    if !modifier_held || !keys.just_pressed(KeyCode::KeyB) {
+        return;
+    }
+
+    bench.enabled = !bench.enabled;
+    bench.grid.clear();
+    bench.reset_stats();
+    info!(
+        "Broad-phase benchmark {}",
+        if bench.enabled { "enabled" } else { "disabled" }
+    );
+}
+
+fn run_broad_phase_bench(
+    mut bench: ResMut<BroadPhaseBench>,
+    query: Query<(Entity, &ComposedResolvedParts, &Depth)>,
+) {
+    if !bench.enabled {
+        return;
+    }
+    let bench = &mut *bench;
+
+    bench.bounds.clear();
+    for (entity, parts, depth) in &query {
+        let bounds = parts
+            .fragments()
+            .iter()
+            .filter_map(|fragment| {
+                world_mask_rect_from_top_left(
+                    fragment.visual_top_left_position,
+                    fragment.frame_size,
+                    fragment.flip_x,
+                    fragment.flip_y,
+                )
+            })
+            .map(|world| world.rect)
+            .reduce(IRect::union);
+        if let Some(bounds) = bounds {
+            bench.bounds.push((entity, bounds, depth.to_i8()));
+        }
+    }
+    let Some(extent) = bench
+        .bounds
+        .iter()
+        .map(|(_, bounds, _)| *bounds)
+        .reduce(IRect::union)
+    else {
+        return;
+    };
+
+    let start = Instant::now();
+    bench.grid.begin_frame();
+    for &(entity, bounds, lane) in &bench.bounds {
+        bench.grid.update(entity, bounds, lane);
+    }
+    bench.grid.end_frame();
+    bench.refresh += start.elapsed();
+
+    // Probes sweep the swarm extent; the per-frame shift keeps them from
+    // sampling the same cells every frame.
+    let step = extent.size() / BENCH_PROBES_PER_AXIS;
+    let shift = IVec2::splat(bench.frames % BENCH_PROBE_SIZE);
+    let probes: Vec<IRect> = (0..BENCH_PROBES_PER_AXIS)
+        .flat_map(|y| (0..BENCH_PROBES_PER_AXIS).map(move |x| IVec2::new(x, y)))
+        .map(|slot| {
+            let min = extent.min + slot * step + shift;
+            IRect::from_corners(min, min + IVec2::splat(BENCH_PROBE_SIZE))
+        })
+        .collect();
+
+    let start = Instant::now();
+    for probe in &probes {
+        bench
+            .grid
+            .query_into(*probe, i8::MIN..=i8::MAX, &mut bench.candidates);
+        bench.grid_candidates += bench.candidates.len();
+    }
+    bench.grid_query += start.elapsed();
+
+    let start = Instant::now();
+    for probe in &probes {
+        bench.pairwise_candidates += bench
+            .bounds
+            .iter()
+            .filter(|(_, bounds, _)| !bounds.intersect(*probe).is_empty())
+            .count();
+    }
+    bench.pairwise += start.elapsed();
+
+    bench.frames += 1;
+    if bench.frames < BENCH_REPORT_FRAMES {
+        return;
+    }
+    let frames = f64::from(bench.frames);
+    let micros = |total: Duration| total.as_secs_f64() * 1_000_000.0 / frames;
+    info!(
+        "Broad phase: {} entities, {} probes/frame -- refresh {:.1}us, grid query {:.1}us \
+         ({:.1} candidates), pairwise {:.1}us ({:.1} overlaps)",
+        bench.bounds.len(),
+        probes.len(),
+        micros(bench.refresh),
+        micros(bench.grid_query),
+        bench.grid_candidates as f64 / frames,
+        micros(bench.pairwise),
+        bench.pairwise_candidates as f64 / frames,
+    );
+    bench.reset_stats();
+}
+
 /// Marker for the stats text UI node.
 #[derive(Component)]
 struct StatsText;
@@ -680,3 +845,163 @@ const fn foreground_depth_for_rank(index: usize) -> Depth {
         _ => Depth::Nine,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use carcinisation_collision::{
+        PixelMaskSource, SpritePixelData, WorldMaskInstance, WorldMaskRect, world_mask_overlap,
+    };
+
+    /// Approximate composed Mosquiton bounds at [`AUTHORED_DEPTH`].
+    const BENCH_MASK_SIZE: Vec2 = Vec2::new(40.0, 32.0);
+    /// Stage seconds sampled by the headless benchmark.
+    const BENCH_SAMPLE_SECS: [f32; 4] = [0.0, 7.5, 21.0, 42.0];
+
+    /// Filled ellipse, so the narrow phase rejects bounds-only overlaps at
+    /// the corners.
+    fn ellipse_mask(size: UVec2) -> SpritePixelData {
+        let half = size.as_vec2() * 0.5;
+        let pixels = (0..size.y)
+            .flat_map(|y| (0..size.x).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let offset = (Vec2::new(x as f32, y as f32) + 0.5 - half) / half;
+                u8::from(offset.length_squared() <= 1.0)
+            })
+            .collect();
+        SpritePixelData::from_pixels(size.x, size.y, pixels).unwrap()
+    }
+
+    fn mask_instance(mask: &SpritePixelData, center: IVec2) -> WorldMaskInstance<'_> {
+        let min = center - mask.frame_size().as_ivec2() / 2;
+        WorldMaskInstance {
+            source: PixelMaskSource::Sprite(mask),
+            frame: None,
+            world: WorldMaskRect {
+                rect: IRect::from_corners(min, min + mask.frame_size().as_ivec2()),
+                flip_x: false,
+                flip_y: false,
+            },
+            closed: false,
+        }
+    }
+
+    /// Swarm centers and depths at `elapsed`, using the same placement math as
+    /// the running scene.
+    fn swarm_layout(elapsed: f32, depth_scale: &DepthScaleConfig) -> Vec<(IVec2, Depth)> {
+        let profile = StressHorizonProfile::default().profile;
+        (0..MOSQUITON_COUNT)
+            .map(|index| {
+                let motion = motion_for(index);
+                let locomotion = initial_locomotion(elapsed, &motion);
+                let progress = StressDepthTraversal::wave_t(elapsed, &motion);
+                let depth = StressDepthTraversal::depth_for_progress(progress);
+                let x = elapsed
+                    .mul_add(motion.x_angular_velocity, motion.x_phase)
+                    .sin()
+                    .mul_add(motion.x_amplitude, motion.center_x);
+                let y = entity_y_for(
+                    progress,
+                    depth,
+                    &motion,
+                    locomotion.state,
+                    None,
+                    profile,
+                    depth_scale,
+                );
+                (Vec2::new(x, y).round().as_ivec2(), depth)
+            })
+            .collect()
+    }
+
+    /// Headless counterpart of the `Cmd+B` benchmark: runs probe masks over
+    /// the swarm through the grid and through a pairwise scan, both followed
+    /// by the pixel narrow phase. Both paths must report the same hits.
+    #[test]
+    #[ignore = "timing benchmark"]
+    fn broad_phase_bench_headless() {
+        let depth_scale = DepthScaleConfig::default();
+        let masks: Vec<SpritePixelData> = (0..=StressDepthTraversal::DEPTH_MAX)
+            .map(|lane| {
+                let depth = Depth::try_from(lane).unwrap();
+                let scale =
+                    depth_scale.resolve_fallback(depth, &AuthoredDepths::single(AUTHORED_DEPTH));
+                ellipse_mask((BENCH_MASK_SIZE * scale).round().as_uvec2().max(UVec2::ONE))
+            })
+            .collect();
+        let probe_mask = ellipse_mask(UVec2::splat(BENCH_PROBE_SIZE as u32));
+
+        let mut grid = BroadPhaseGrid::<usize>::default();
+        let mut candidates = Vec::new();
+        let mut refresh = Duration::ZERO;
+        let mut grid_total = Duration::ZERO;
+        let mut pairwise_total = Duration::ZERO;
+        let mut grid_candidates = 0;
+        let mut pairwise_candidates = 0;
+        let mut hits = 0;
+
+        for elapsed in BENCH_SAMPLE_SECS {
+            let layout = swarm_layout(elapsed, &depth_scale);
+            let swarm: Vec<WorldMaskInstance> = layout
+                .iter()
+                .map(|&(center, depth)| mask_instance(&masks[depth.to_i8() as usize], center))
+                .collect();
+            let extent = swarm
+                .iter()
+                .map(|instance| instance.world.rect)
+                .reduce(IRect::union)
+                .unwrap();
+
+            let start = Instant::now();
+            grid.begin_frame();
+            for (index, (instance, (_, depth))) in swarm.iter().zip(&layout).enumerate() {
+                grid.update(index, instance.world.rect, depth.to_i8());
+            }
+            grid.end_frame();
+            refresh += start.elapsed();
+
+            let step = extent.size() / BENCH_PROBES_PER_AXIS;
+            let probes: Vec<WorldMaskInstance> = (0..BENCH_PROBES_PER_AXIS)
+                .flat_map(|y| (0..BENCH_PROBES_PER_AXIS).map(move |x| IVec2::new(x, y)))
+                .map(|slot| mask_instance(&probe_mask, extent.min + slot * step + step / 2))
+                .collect();
+
+            for probe in &probes {
+                let start = Instant::now();
+                grid.query_into(probe.world.rect, i8::MIN..=i8::MAX, &mut candidates);
+                grid_candidates += candidates.len();
+                let mut grid_hits: Vec<usize> = candidates
+                    .iter()
+                    .copied()
+                    .filter(|&index| world_mask_overlap(*probe, swarm[index]).is_some())
+                    .collect();
+                grid_total += start.elapsed();
+
+                let start = Instant::now();
+                let pairwise_hits: Vec<usize> = swarm
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, instance)| {
+                        !instance.world.rect.intersect(probe.world.rect).is_empty()
+                    })
+                    .inspect(|_| pairwise_candidates += 1)
+                    .filter(|(_, instance)| world_mask_overlap(*probe, **instance).is_some())
+                    .map(|(index, _)| index)
+                    .collect();
+                pairwise_total += start.elapsed();
+
+                grid_hits.sort_unstable();
+                assert_eq!(grid_hits, pairwise_hits);
+                hits += pairwise_hits.len();
+            }
+        }
+
+        let probe_count = BENCH_SAMPLE_SECS.len() * (BENCH_PROBES_PER_AXIS as usize).pow(2);
+        println!(
+            "Broad phase: {MOSQUITON_COUNT} entities, {probe_count} probes over {} frames -- \
+             refresh {refresh:?}, grid+narrow {grid_total:?} ({grid_candidates} candidates), \
+             pairwise+narrow {pairwise_total:?} ({pairwise_candidates} candidates), {hits} hits",
+            BENCH_SAMPLE_SECS.len(),
+        );
+    }
+}
@@ -15,6 +15,7 @@ use bevy::prelude::*;
 #[cfg(feature = "brp")]
 use bevy_brp_extras::BrpExtrasPlugin;
 use carapace::prelude::*;
+use carcinisation::app::initial_input_bindings;
 use carcinisation_fps::data::{EntityKind, MapData};
 use carcinisation_fps::enemy::Enemy;
 use carcinisation_fps::mosquiton::{Mosquiton, MosquitonConfig};
@@ -22,8 +23,8 @@ use carcinisation_fps::player_attack::AttackInput;
 use carcinisation_fps::player_attack::PlayerAttackState;
 use carcinisation_fps::plugin::{
     CameraRes, CameraShakeState, CharDecals, Config, DeathViewState, EnemySpriteIndex, FpsPlugin,
-    MapRes, PlayerDead, PlayerHealth, PlayerSpeedModifier, ProjectileImpacts, Projectiles,
-    QuickTurnState, SelectActionOutcome, SelectActionTurnInput, SelectActionTurnState,
+    MapRes, PlayerDead, PlayerHealth, PlayerPush, PlayerSpeedModifier, ProjectileImpacts,
+    Projectiles, QuickTurnState, SelectActionOutcome, SelectActionTurnInput, SelectActionTurnState,
     ShootRequest, Systems, TurnChordInput, TurnChordState, request_snap_turn,
     resolve_select_action_turn, resolve_turn_chord, select_actions_allowed_outside_aim_mode,
 };
@@ -93,6 +94,7 @@ fn handle_input(
     mut select_action_turn: ResMut<SelectActionTurnState>,
     mut quick_turn_state: ResMut<QuickTurnState>,
     mut speed_modifier: ResMut<PlayerSpeedModifier>,
+    mut push: ResMut<PlayerPush>,
 ) {
     if dead.0 {
         return;
@@ -247,6 +249,7 @@ fn handle_input(
     }
 
     cam.angle += turn_delta;
+    push.0 = movement.y > 0.0;
 
     let pos_before = cam.position;
     if movement != Vec2::ZERO {
@@ -364,6 +367,7 @@ impl DeathRestartGate {
 struct ResetParams<'w, 's> {
     config: Res<'w, Config>,
     camera: ResMut<'w, CameraRes>,
+    map: ResMut<'w, MapRes>,
     health: ResMut<'w, PlayerHealth>,
     dead: ResMut<'w, PlayerDead>,
     shoot: ResMut<'w, ShootRequest>,
@@ -411,6 +415,7 @@ fn reset_stage(reset: &mut ResetParams<'_, '_>) {
     reset.projectiles.0.clear();
     reset.impacts.0.clear();
     reset.char_decals.0.clear();
+    reset.map.0.mechanisms.reset();
 
     // Spawn new enemies and mosquitons from map data.
     for spawn in &map_data.entities {
@@ -421,7 +426,7 @@ fn reset_stage(reset: &mut ResetParams<'_, '_>) {
                 let enemy = Enemy::new(pos, *health, *speed);
                 reset.commands.spawn((enemy, EnemySpriteIndex(0)));
             }
-            EntityKind::Mosquiton { health, speed } => {
+            EntityKind::Mosquiton { health, speed, .. } => {
                 let config = MosquitonConfig {
                     health: *health,
                     move_speed: *speed,
@@ -431,7 +436,7 @@ fn reset_stage(reset: &mut ResetParams<'_, '_>) {
                 reset.commands.spawn(mosquiton);
             }
             EntityKind::Pillar { .. } | EntityKind::Pickup { .. } => {}
-            EntityKind::Spidey { health, speed } => {
+            EntityKind::Spidey { health, speed, .. } => {
                 let combat = carcinisation_fps_core::FpsCombatConfig::load();
                 let config = SpideyConfig {
                     health: *health,
@@ -559,6 +564,7 @@ fn main() {
     }
 
     app.add_plugins(InputManagerPlugin::<GBInput>::default());
+    app.insert_resource(initial_input_bindings());
     app.add_systems(
         Startup,
         (init_gb_input, |mut commands: Commands| {
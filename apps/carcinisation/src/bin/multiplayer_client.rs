@@ -4,8 +4,10 @@ use bevy::prelude::*;
 #[cfg(feature = "brp")]
 use bevy_brp_extras::BrpExtrasPlugin;
 use carapace::prelude::*;
-use carcinisation::first_person::FpsClientPlugin;
+use carcinisation::app::initial_input_bindings;
+use carcinisation::first_person::chat::ChatPlugin;
 use carcinisation::first_person::monitor::MapMonitorClientPlugin;
+use carcinisation::first_person::{ClientConnectToken, FpsClientPlugin};
 use carcinisation_fps::plugin::{Config, FpsAuthorityMode, FpsPlugin, PlayerDead, PlayerHealth};
 use carcinisation_map_view::MapViewPlugin;
 use clap::Parser;
@@ -14,6 +16,7 @@ use serde::{Deserialize, Serialize};
 const MAP_PATH: &str = "assets/config/fp/test_room.fp_map.ron";
 const SKY_PATH: &str = "assets/config/sky/park.sky.ron";
 const CONNECT_ENV: &str = "CARCINISATION_CONNECT";
+const TOKEN_ENV: &str = "CARCINISATION_TOKEN";
 const SCREEN_W: u32 = 160;
 const SCREEN_H: u32 = 144;
 
@@ -23,6 +26,11 @@ struct MpClientArgs {
     /// Server address to connect to. Overrides `CARCINISATION_CONNECT` env var.
     #[arg(long = "connect")]
     connect: Option<String>,
+    /// Connect token for secure servers: a token file or an `http://` token
+    /// service URL. Overrides `CARCINISATION_TOKEN` env var. Without one the
+    /// client connects unsecure.
+    #[arg(long = "token")]
+    token: Option<String>,
     #[arg(long = "map", default_value = MAP_PATH)]
     map_path: PathBuf,
     #[arg(long = "sky", default_value = SKY_PATH)]
@@ -49,6 +57,7 @@ enum Layer {
     Main,
     MapView,
     MapViewOverlay,
+    Hud,
 }
 
 #[allow(dead_code, clippy::needless_pass_by_value)]
@@ -128,6 +137,7 @@ fn main() -> ExitCode {
         app.add_plugins(leafwing_input_manager::prelude::InputManagerPlugin::<
             carcinisation_input::GBInput,
         >::default());
+        app.insert_resource(initial_input_bindings());
         app.add_systems(Startup, carcinisation_input::init_gb_input);
     }
 
@@ -139,10 +149,22 @@ fn main() -> ExitCode {
 
     if let Some(addr_str) = connect_addr {
         let addr: SocketAddr = addr_str.parse().expect("invalid connect address");
+        if let Some(source) = args.token.or_else(|| std::env::var(TOKEN_ENV).ok()) {
+            match carcinisation_net::auth::load_connect_token(&source) {
+                Ok(token) => {
+                    app.insert_resource(ClientConnectToken(token));
+                }
+                Err(e) => {
+                    eprintln!("{e}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
         if args.monitor {
             app.add_plugins(MapMonitorClientPlugin { connect_addr: addr });
         } else {
-            app.add_plugins(FpsClientPlugin { connect_addr: addr });
+            app.add_plugins(FpsClientPlugin { connect_addr: addr })
+                .add_plugins(ChatPlugin::new(Layer::Hud));
         }
     }
 
@@ -1,10 +1,8 @@
 //! Cutscene-specific input actions (skip, etc.).
 
-use bevy::prelude::*;
 use bevy::reflect::Reflect;
-use carcinisation_input::GBInput;
+use carcinisation_input::{GBActions, GBInput};
 use leafwing_input_manager::Actionlike;
-use leafwing_input_manager::{action_state::ActionState, input_map::InputMap};
 
 #[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
 /// Actions available while a cutscene plays.
@@ -12,9 +10,6 @@ pub enum CutsceneInput {
     Skip,
 }
 
-/// @system Registers the cutscene input map using the shared GB bindings.
-pub fn init_input(mut commands: Commands) {
-    let ms: Vec<(CutsceneInput, KeyCode)> = vec![(CutsceneInput::Skip, GBInput::Start.into())];
-    commands.insert_resource(ActionState::<CutsceneInput>::default());
-    commands.insert_resource(InputMap::<CutsceneInput>::new(ms));
+impl GBActions for CutsceneInput {
+    const GB_ACTIONS: &'static [(Self, GBInput)] = &[(Self::Skip, GBInput::Start)];
 }
@@ -5,8 +5,10 @@ pub mod input;
 pub mod messages;
 mod systems;
 
+pub use self::systems::setup::{StageCutscene, play_stage_cutscene};
+
 use self::{
-    input::{CutsceneInput, init_input},
+    input::CutsceneInput,
     messages::{CutsceneShutdownEvent, CutsceneStartupEvent},
     systems::{
         interactions::check_press_start_input,
@@ -14,7 +16,8 @@ use self::{
             check_cutscene_appear_times, check_cutscene_elapsed, drive_cutscene_rotation_keyframes,
             drive_rotation_followers, drive_timeline_curve_followers,
             process_cutscene_animations_spawn, process_cutscene_images_spawn,
-            process_cutscene_music_despawn, process_cutscene_music_spawn, read_step_trigger,
+            process_cutscene_music_despawn, process_cutscene_music_spawn,
+            process_cutscene_voice_spawn, read_step_trigger,
         },
         setup::{on_cutscene_shutdown, on_cutscene_startup},
     },
@@ -26,12 +29,12 @@ use bevy::asset::AssetEvent;
 use bevy::prelude::*;
 use bevy_common_assets::ron::RonAssetPlugin;
 use carcinisation_cutscene::resources::CutsceneTimeDomain;
+use carcinisation_input::GBActionsPlugin;
 use cween::linear::{
     LinearTweenPlugin,
     components::{TargetingValueX, TargetingValueY},
 };
 use data::CutsceneData;
-use leafwing_input_manager::plugin::InputManagerPlugin;
 
 /// Registers cutscene resources, input mapping, and playback systems.
 #[derive(Activable)]
@@ -44,7 +47,7 @@ impl Plugin for CutscenePlugin {
         #[cfg(feature = "hot_reload")]
         app.add_systems(Update, log_cutscene_data_asset_changes);
 
-        app.add_plugins(InputManagerPlugin::<CutsceneInput>::default())
+        app.add_plugins(GBActionsPlugin::<CutsceneInput>::default())
             .add_plugins(LinearTweenPlugin::<CutsceneTimeDomain, TargetingValueX>::default())
             .add_plugins(LinearTweenPlugin::<CutsceneTimeDomain, TargetingValueY>::default())
             .init_resource::<Time<CutsceneTimeDomain>>()
@@ -53,7 +56,6 @@ impl Plugin for CutscenePlugin {
             .add_message::<CutsceneShutdownEvent>()
             .add_observer(on_cutscene_shutdown)
             .add_observer(on_trigger_write_event::<CutsceneShutdownEvent>)
-            .add_systems(Startup, init_input)
             .add_active_systems_in::<Self, _>(
                 FixedUpdate,
                 (
@@ -65,6 +67,7 @@ impl Plugin for CutscenePlugin {
                             process_cutscene_images_spawn,
                             process_cutscene_music_spawn,
                             process_cutscene_music_despawn,
+                            process_cutscene_voice_spawn,
                             drive_cutscene_rotation_keyframes,
                             drive_timeline_curve_followers,
                             check_cutscene_appear_times,
@@ -14,7 +14,7 @@ pub fn check_press_start_input(
     mut commands: Commands,
     gb_input: Res<ActionState<CutsceneInput>>,
     data: Option<Res<CutsceneData>>,
-    keyboard: Res<ButtonInput<KeyCode>>,
+    gameplay_input: Res<ActionState<GBInput>>,
 ) {
     let skip_mode = data
         .as_ref()
@@ -23,19 +23,7 @@ pub fn check_press_start_input(
     let should_skip = match skip_mode {
         CutsceneSkipMode::StartOnly => gb_input.just_pressed(&CutsceneInput::Skip),
         CutsceneSkipMode::AnyGameplayKey => {
-            let gameplay_actions = [
-                GBInput::A,
-                GBInput::B,
-                GBInput::Start,
-                GBInput::Select,
-                GBInput::Up,
-                GBInput::Down,
-                GBInput::Left,
-                GBInput::Right,
-            ];
-            gameplay_actions
-                .iter()
-                .any(|a| keyboard.just_pressed(KeyCode::from(*a)))
+            GBInput::ALL.iter().any(|a| gameplay_input.just_pressed(a))
         }
     };
 
@@ -1,23 +1,22 @@
 #![allow(clippy::type_complexity)]
 
 use crate::assets::CxAssets;
-use crate::components::VolumeSettings;
 use crate::{
-    components::{Cleared, CutsceneElapsedStarted, Music, Tag},
+    audio::{AudioBus, DuckMusic, PlayMusic, StopMusic, make_sound_bundle},
+    components::{Cleared, CutsceneElapsedStarted, Tag},
     cutscene::{
         data::{
             CutsceneAnimationsSpawn, CutsceneData, CutsceneElapse, CutsceneImagesSpawn,
-            CutsceneMusicDespawn, CutsceneMusicSpawn,
+            CutsceneMusicDespawn, CutsceneMusicSpawn, CutsceneVoiceSpawn,
         },
         messages::CutsceneShutdownEvent,
     },
     globals::mark_for_despawn_by_query,
     layer::{CutsceneLayer, Layer},
     letterbox::messages::LetterboxMoveEvent,
-    systems::spawn::make_music_bundle,
     transitions::trigger_transition,
 };
-use bevy::{audio::PlaybackMode, prelude::*};
+use bevy::prelude::*;
 use carapace::{
     prelude::{
         CxAnchor, CxAnimationBundle, CxAnimationDirection, CxAnimationDuration,
@@ -84,6 +83,14 @@ pub fn read_step_trigger(
         if let Some(x) = &act.spawn_images_o {
             entity_commands.insert(x.clone());
         }
+        if let Some(x) = &act.voice_spawn_o {
+            entity_commands.insert(x.clone());
+        }
+        if act.duck_music {
+            entity_commands.insert(DuckMusic);
+        } else {
+            entity_commands.remove::<DuckMusic>();
+        }
         if let Some(x) = &act.transition_o {
             trigger_transition(&mut commands, &x.request);
         }
@@ -298,33 +305,32 @@ pub fn process_cutscene_images_spawn(
     }
 }
 
-/// @system Starts the configured cutscene music, replacing any previous tracks.
+/// @system Starts the configured cutscene music, crossfading from any previous track.
 pub fn process_cutscene_music_spawn(
     mut commands: Commands,
     query: Query<(Entity, &CutsceneMusicSpawn), (With<Cinematic>, Added<CutsceneMusicSpawn>)>,
-    music_query: Query<Entity, With<Music>>,
-    asset_server: Res<AssetServer>,
-    volume_settings: Res<VolumeSettings>,
+    mut play_music_writer: MessageWriter<PlayMusic>,
 ) {
     for (entity, spawn) in query.iter() {
-        mark_for_despawn_by_query(&mut commands, &music_query);
-
-        let (player, settings, system_bundle, music_tag) = make_music_bundle(
-            &asset_server,
-            &volume_settings,
-            spawn.music_path.clone(),
-            PlaybackMode::Loop,
-        );
+        play_music_writer.write(PlayMusic::looped(spawn.music_path.clone()));
+        commands.entity(entity).remove::<CutsceneMusicSpawn>();
+    }
+}
 
+/// @system Plays the act's dialogue line on the voice bus, which ducks music
+/// until the line ends.
+pub fn process_cutscene_voice_spawn(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    query: Query<(Entity, &CutsceneVoiceSpawn), (With<Cinematic>, Added<CutsceneVoiceSpawn>)>,
+) {
+    for (entity, spawn) in query.iter() {
         commands.spawn((
+            make_sound_bundle(&asset_server, spawn.voice_path.clone(), AudioBus::Voice),
             CutsceneEntity,
-            player,
-            settings,
-            system_bundle,
-            music_tag,
-            Name::new("Cutscene music"),
+            Name::new("Cutscene Voice"),
         ));
-        commands.entity(entity).remove::<CutsceneMusicSpawn>();
+        commands.entity(entity).remove::<CutsceneVoiceSpawn>();
     }
 }
 
@@ -458,10 +464,10 @@ pub fn check_cutscene_appear_times(
 pub fn process_cutscene_music_despawn(
     mut commands: Commands,
     query: Query<(Entity, &CutsceneMusicDespawn), (With<Cinematic>, Added<CutsceneMusicDespawn>)>,
-    music_query: Query<Entity, With<Music>>,
+    mut stop_music_writer: MessageWriter<StopMusic>,
 ) {
     for (entity, _despawn) in query.iter() {
-        mark_for_despawn_by_query(&mut commands, &music_query);
+        stop_music_writer.write(StopMusic);
         commands.entity(entity).remove::<CutsceneMusicDespawn>();
     }
 }
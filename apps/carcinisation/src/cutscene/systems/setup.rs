@@ -4,6 +4,7 @@ use crate::cutscene::CutscenePlugin;
 #[cfg(debug_assertions)]
 use crate::debug::plugin::{debug_print_shutdown, debug_print_startup};
 use crate::{
+    audio::StopMusic,
     cutscene::{
         data::CutsceneData,
         messages::{CutsceneShutdownEvent, CutsceneStartupEvent},
@@ -15,9 +16,25 @@ use activable::{activate, deactivate};
 use bevy::prelude::*;
 use carcinisation_cutscene::components::{Cinematic, CutsceneEntity};
 use carcinisation_cutscene::resources::CutsceneProgress;
+use std::sync::Arc;
 
 const DEBUG_MODULE: &str = "Cutscene";
 
+#[derive(Resource, Debug, Default)]
+/// Present while a cutscene plays for a stage cinematic step rather than as
+/// a game step, so its end resumes the stage instead of advancing the game.
+pub struct StageCutscene;
+
+/// [`StageHooks::play_cutscene`] implementation: plays a stage cinematic
+/// step's cutscene behind the letterbox.
+///
+/// [`StageHooks::play_cutscene`]: crate::stage::StageHooks::play_cutscene
+pub fn play_stage_cutscene(commands: &mut Commands, data: Arc<CutsceneData>) {
+    commands.insert_resource(StageCutscene);
+    commands.trigger(LetterboxMoveEvent::open());
+    commands.trigger(CutsceneStartupEvent { data });
+}
+
 /// @trigger Boots a cutscene, loading data and enabling systems.
 ///
 /// When `DevFlags::skip_cutscenes` is set, immediately triggers shutdown
@@ -63,6 +80,7 @@ pub fn on_cutscene_shutdown(
     mut commands: Commands,
     cinematic_query: Query<Entity, With<Cinematic>>,
     cutscene_entity_query: Query<Entity, With<CutsceneEntity>>,
+    mut stop_music_writer: MessageWriter<StopMusic>,
 ) {
     #[cfg(debug_assertions)]
     debug_print_shutdown(DEBUG_MODULE);
@@ -75,4 +93,6 @@ pub fn on_cutscene_shutdown(
 
     mark_for_despawn_by_query(&mut commands, &cutscene_entity_query);
     mark_for_despawn_by_query(&mut commands, &cinematic_query);
+    // Fades out while the next step starts, crossfading into its music.
+    stop_music_writer.write(StopMusic);
 }
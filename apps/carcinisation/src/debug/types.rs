@@ -31,6 +31,7 @@ use crate::{
         },
         destructible::{components::DestructibleType, data::DestructibleSpawn},
         enemy::{
+            boss::BossPhases,
             components::{
                 CircleAround, EnemyContinuousDepth, LinearTween,
                 behavior::{EnemyBehaviors, EnemyCurrentBehavior, JumpTween},
@@ -52,6 +53,7 @@ use crate::{
                 EnemyMosquitoAttack, EnemyMosquitoAttacking,
             },
             spidey::entity::{EnemySpidey, EnemySpideyAnimation, EnemySpideyAttacking},
+            spidomonsta::entity::EnemySpidomonsta,
             tardigrade::entity::{
                 EnemyTardigrade, EnemyTardigradeAnimation, EnemyTardigradeAttacking,
             },
@@ -108,6 +110,8 @@ pub fn register_types(app: &mut App) {
         .register_type::<EnemySpidey>()
         .register_type::<EnemySpideyAnimation>()
         .register_type::<EnemySpideyAttacking>()
+        .register_type::<EnemySpidomonsta>()
+        .register_type::<BossPhases>()
         .register_type::<EnemyTardigrade>()
         .register_type::<EnemyTardigradeAnimation>()
         .register_type::<EnemyTardigradeAttacking>()
@@ -0,0 +1,307 @@
+//! Multiplayer chat HUD: recent `ChatMessage` lines plus a local entry line.
+//!
+//! Press `T` to start typing, `Enter` to send, `Escape` to cancel. While the
+//! entry line is open, gameplay intent is not sent (see
+//! [`chat_input_closed`]) so typed letters don't also move the player.
+
+use std::collections::VecDeque;
+
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput, NativeKey};
+use bevy::prelude::*;
+use bevy_replicon::prelude::*;
+use carapace::prelude::*;
+use carcinisation_fps::plugin::Config;
+use carcinisation_net::{CHAT_MAX_CHARS, ChatMessage, ChatSend, ChatSender, sanitize_chat_text};
+
+use crate::globals::load_inverted_typeface;
+
+use super::LocalPlayerId;
+
+/// Wrapped HUD rows kept on screen.
+const CHAT_HUD_ROWS: usize = 4;
+/// Characters per HUD row before wrapping.
+const CHAT_HUD_COLUMNS: usize = 24;
+/// Pixel height of one HUD row (inverted pixeboy glyph height).
+const CHAT_ROW_HEIGHT: i32 = 10;
+/// Seconds a line stays visible while the entry line is closed.
+const CHAT_LINE_SECS: f32 = 8.0;
+/// Received lines remembered for display while typing.
+const CHAT_LOG_CAPACITY: usize = 16;
+
+/// Recently received chat lines, oldest first.
+#[derive(Resource, Debug, Default)]
+pub struct ChatLog {
+    lines: VecDeque<ChatLogLine>,
+}
+
+#[derive(Debug, Clone)]
+struct ChatLogLine {
+    text: String,
+    age: f32,
+}
+
+impl ChatLog {
+    fn push(&mut self, text: String) {
+        if self.lines.len() == CHAT_LOG_CAPACITY {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(ChatLogLine { text, age: 0.0 });
+    }
+}
+
+/// Local chat entry line. `Some` while the player is typing.
+#[derive(Resource, Debug, Default)]
+pub struct ChatInput(pub Option<String>);
+
+/// Run condition: true unless the chat entry line is open.
+#[must_use]
+pub fn chat_input_closed(input: Option<Res<ChatInput>>) -> bool {
+    input.is_none_or(|input| input.0.is_none())
+}
+
+/// HUD row entity. Row 0 is the entry line; rows `1..=CHAT_HUD_ROWS` show
+/// the log, newest at the bottom.
+#[derive(Component, Debug, Clone, Copy)]
+struct ChatHudRow(usize);
+
+/// Chat log, entry line and HUD text on layer `L`.
+pub struct ChatPlugin<L: CxLayer> {
+    layer: L,
+}
+
+impl<L: CxLayer> ChatPlugin<L> {
+    #[must_use]
+    pub fn new(layer: L) -> Self {
+        Self { layer }
+    }
+}
+
+/// Layer the chat HUD draws on.
+#[derive(Resource, Clone)]
+struct ChatHudLayer<L: CxLayer>(L);
+
+impl<L: CxLayer> Plugin for ChatPlugin<L> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ChatLog>()
+            .init_resource::<ChatInput>()
+            .insert_resource(ChatHudLayer(self.layer.clone()))
+            .add_observer(handle_chat_message)
+            .add_systems(Startup, spawn_chat_hud::<L>)
+            .add_systems(
+                Update,
+                (edit_chat_input, tick_chat_log, update_chat_hud).chain(),
+            );
+    }
+}
+
+fn handle_chat_message(
+    trigger: On<ChatMessage>,
+    local_id: Option<Res<LocalPlayerId>>,
+    mut log: ResMut<ChatLog>,
+) {
+    let message = trigger.event();
+    let local = local_id.and_then(|id| id.0);
+    let prefix = match message.sender {
+        ChatSender::Server => "*".to_string(),
+        ChatSender::Player(id) if Some(id) == local => "You:".to_string(),
+        ChatSender::Player(id) => format!("P{}:", id.0),
+    };
+    log.push(format!("{prefix} {}", message.text));
+}
+
+fn spawn_chat_hud<L: CxLayer>(
+    mut commands: Commands,
+    assets_typeface: crate::assets::CxAssets<CxTypeface>,
+    layer: Res<ChatHudLayer<L>>,
+    config: Res<Config>,
+) {
+    let typeface = load_inverted_typeface(&assets_typeface);
+    let top = i32::try_from(config.screen_height).unwrap_or(i32::MAX) - 2;
+    for row in 0..=CHAT_HUD_ROWS {
+        // Entry line sits below the log.
+        let slot = if row == 0 { CHAT_HUD_ROWS } else { row - 1 };
+        let y = top - i32::try_from(slot).unwrap_or(0) * CHAT_ROW_HEIGHT;
+        commands.spawn((
+            ChatHudRow(row),
+            CxTextBundle::<L> {
+                position: CxPosition::from(IVec2::new(2, y)),
+                anchor: CxAnchor::TopLeft,
+                canvas: CxRenderSpace::Camera,
+                layer: layer.0.clone(),
+                text: CxText::new(String::new(), typeface.clone()),
+                ..default()
+            },
+            Name::new(format!("ChatHudRow<{row}>")),
+        ));
+    }
+}
+
+/// Open/close the entry line and feed typed characters into it.
+fn edit_chat_input(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut key_events: MessageReader<KeyboardInput>,
+    mut input: ResMut<ChatInput>,
+) {
+    let Some(buffer) = input.0.as_mut() else {
+        key_events.read().for_each(drop);
+        if keys.just_pressed(KeyCode::KeyT) {
+            input.0 = Some(String::new());
+        }
+        return;
+    };
+
+    if keys.just_pressed(KeyCode::Escape) {
+        key_events.read().for_each(drop);
+        input.0 = None;
+        return;
+    }
+    if keys.just_pressed(KeyCode::Enter) {
+        key_events.read().for_each(drop);
+        if let Some(text) = sanitize_chat_text(buffer) {
+            commands.client_trigger(ChatSend { text });
+        }
+        input.0 = None;
+        return;
+    }
+
+    for event in key_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Character(characters) | Key::Unidentified(NativeKey::Web(characters)) => {
+                for c in characters.chars() {
+                    if buffer.chars().count() < CHAT_MAX_CHARS {
+                        buffer.push(c);
+                    }
+                }
+            }
+            Key::Space => {
+                if buffer.chars().count() < CHAT_MAX_CHARS {
+                    buffer.push(' ');
+                }
+            }
+            Key::Backspace => {
+                buffer.pop();
+            }
+            _ => {}
+        }
+    }
+}
+
+fn tick_chat_log(time: Res<Time>, mut log: ResMut<ChatLog>) {
+    let dt = time.delta_secs();
+    for line in &mut log.lines {
+        line.age += dt;
+    }
+}
+
+fn update_chat_hud(
+    log: Res<ChatLog>,
+    input: Res<ChatInput>,
+    mut rows: Query<(&ChatHudRow, &mut CxText)>,
+) {
+    let typing = input.0.is_some();
+    let visible = log
+        .lines
+        .iter()
+        .filter(|line| typing || line.age < CHAT_LINE_SECS)
+        .flat_map(|line| wrap_chat_line(&line.text, CHAT_HUD_COLUMNS));
+    let wrapped: Vec<String> = visible.collect();
+    let log_rows = &wrapped[wrapped.len().saturating_sub(CHAT_HUD_ROWS)..];
+
+    let entry = input.0.as_ref().map(|buffer| {
+        // Show the tail of long input so the caret stays on screen.
+        let tail: String = buffer
+            .chars()
+            .skip(buffer.chars().count().saturating_sub(CHAT_HUD_COLUMNS - 2))
+            .collect();
+        format!(">{tail}_")
+    });
+
+    for (row, mut text) in &mut rows {
+        let value = if row.0 == 0 {
+            entry.clone().unwrap_or_default()
+        } else {
+            log_rows.get(row.0 - 1).cloned().unwrap_or_default()
+        };
+        if text.value != value {
+            text.value = value;
+        }
+    }
+}
+
+/// Greedy word wrap at `columns` characters; words longer than a row are
+/// split.
+fn wrap_chat_line(text: &str, columns: usize) -> Vec<String> {
+    let mut rows = Vec::new();
+    let mut current = String::new();
+    for word in text.split(' ').filter(|w| !w.is_empty()) {
+        let mut word: Vec<char> = word.chars().collect();
+        loop {
+            let used = current.chars().count();
+            let gap = usize::from(used > 0);
+            if used + gap + word.len() <= columns {
+                if gap == 1 {
+                    current.push(' ');
+                }
+                current.extend(word.iter());
+                break;
+            }
+            if used > 0 {
+                rows.push(std::mem::take(&mut current));
+                continue;
+            }
+            let rest = word.split_off(columns);
+            rows.push(word.into_iter().collect());
+            word = rest;
+        }
+    }
+    if !current.is_empty() {
+        rows.push(current);
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrap_keeps_short_lines_whole() {
+        assert_eq!(wrap_chat_line("P1: hi there", 24), vec!["P1: hi there"]);
+    }
+
+    #[test]
+    fn wrap_breaks_on_word_boundaries() {
+        assert_eq!(
+            wrap_chat_line("aaa bbb ccc", 7),
+            vec!["aaa bbb".to_string(), "ccc".to_string()]
+        );
+    }
+
+    #[test]
+    fn wrap_splits_long_words() {
+        assert_eq!(
+            wrap_chat_line("* abcdefghij", 4),
+            vec![
+                "*".to_string(),
+                "abcd".to_string(),
+                "efgh".to_string(),
+                "ij".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn log_drops_oldest_past_capacity() {
+        let mut log = ChatLog::default();
+        for i in 0..CHAT_LOG_CAPACITY + 2 {
+            log.push(format!("{i}"));
+        }
+        assert_eq!(log.lines.len(), CHAT_LOG_CAPACITY);
+        assert_eq!(log.lines.front().unwrap().text, "2");
+    }
+}
@@ -27,6 +27,13 @@ use crate::first_person::prediction::PendingInput;
 #[derive(Resource, Default)]
 pub struct ClientInputSequence(pub InputSequence);
 
+/// Newest `InputAck` sequence received from the server.
+///
+/// Echoed in every `ClientIntent` so the server can tell how far behind the
+/// world we are drawing is, and rewind hitscan to match.
+#[derive(Resource, Default)]
+pub struct ClientAckedSequence(pub InputSequence);
+
 /// Simulated one-way latency (half RTT) in seconds.
 ///
 /// Set via `CARCINISATION_SIMULATED_PING_MS` env var. The value is treated
@@ -129,6 +136,7 @@ pub fn collect_and_send_intent(
     mut commands: Commands,
     action: Res<ActionState<GBInput>>,
     mut input_sequence: ResMut<ClientInputSequence>,
+    acked_sequence: Res<ClientAckedSequence>,
     mut send_timer: ResMut<InputSendTimer>,
     time: Res<Time>,
     local_player_id: Res<LocalPlayerId>,
@@ -316,6 +324,7 @@ pub fn collect_and_send_intent(
     // -- Build intent --
     let intent = ClientIntent {
         sequence: input_sequence.0,
+        acked_sequence: acked_sequence.0,
         movement,
         turn,
         fire_held,
@@ -40,7 +40,7 @@ use carcinisation_fps::raycast::{HitSide, WallSurfaceId};
 use carcinisation_fps::render::CharDecal;
 use carcinisation_fps::screen_particles::FpsScreenParticles;
 use carcinisation_fps_core::ScreenParticleConfig;
-use carcinisation_net::components::{NetEnemy, NetPickup};
+use carcinisation_net::components::{NetDoor, NetEnemy, NetPickup};
 use carcinisation_net::protocol::PickupEffect;
 use carcinisation_net::{
     DamageEffect, DeathEffect, EnemyAttackKind, EnemyAttackVisual, FlameActive, FlameCharMark,
@@ -52,6 +52,7 @@ use std::net::SocketAddr;
 #[cfg(not(target_family = "wasm"))]
 use std::time::{SystemTime, UNIX_EPOCH};
 
+pub mod chat;
 pub mod input;
 pub mod interpolation;
 pub mod monitor;
@@ -199,6 +200,7 @@ impl Plugin for FpsClientPlugin {
         }
         app.add_plugins(bevy_replicon_renet2::RepliconRenetPlugins)
             .init_resource::<input::ClientInputSequence>()
+            .init_resource::<input::ClientAckedSequence>()
             .init_resource::<input::InputSendTimer>()
             .init_resource::<carcinisation_fps::plugin::TurnChordState>()
             .init_resource::<carcinisation_fps::plugin::SelectActionTurnState>()
@@ -244,7 +246,12 @@ impl Plugin for FpsClientPlugin {
             .add_observer(handle_hit_confirm)
             .add_observer(handle_pickup_effect)
             .add_observer(prediction::handle_input_ack)
-            .add_systems(Update, collect_and_send_intent.run_if(is_connected))
+            .add_systems(
+                Update,
+                collect_and_send_intent
+                    .run_if(is_connected)
+                    .run_if(chat::chat_input_closed),
+            )
             .add_systems(
                 Update,
                 prediction::tick_predicted_render
@@ -304,6 +311,13 @@ impl Plugin for FpsClientPlugin {
                     .run_if(resource_exists::<Active>)
                     .run_if(is_connected),
             )
+            .add_systems(
+                Update,
+                apply_net_doors
+                    .before(Systems)
+                    .run_if(resource_exists::<MapRes>)
+                    .run_if(is_connected),
+            )
             .add_systems(
                 Update,
                 sync_weapon_hud_and_flame_visual
@@ -396,6 +410,14 @@ impl Plugin for FpsClientPlugin {
 #[cfg(not(target_family = "wasm"))]
 struct ConnectAddr(SocketAddr);
 
+/// Connect token for servers running in secure mode (see
+/// `carcinisation_net::auth`). Insert before the client plugin builds; without
+/// it the client connects unsecure. The token also decides the
+/// `ConnectMode`, so monitors need a monitor token.
+#[derive(Resource)]
+#[cfg(not(target_family = "wasm"))]
+pub struct ClientConnectToken(pub bevy_renet2::netcode::ConnectToken);
+
 fn handle_player_id_assigned(
     trigger: On<PlayerIdAssigned>,
     mut local_id: ResMut<LocalPlayerId>,
@@ -771,6 +793,28 @@ fn queue_pickup_billboards(
     }
 }
 
+/// Write replicated door state into the render map and the prediction map,
+/// so the raycaster and predicted collision follow the server.
+///
+/// `MapRes` is written without change detection: it signals a map reload,
+/// which would make `sync_client_map_from_map_res` re-copy the whole map.
+fn apply_net_doors(
+    net_doors: Query<&NetDoor, Changed<NetDoor>>,
+    mut map_res: ResMut<MapRes>,
+    mut client_map: Option<ResMut<carcinisation_net::prediction::ClientMap>>,
+) {
+    for net_door in &net_doors {
+        let index = usize::from(net_door.index);
+        let maps = std::iter::once(&mut map_res.bypass_change_detection().0)
+            .chain(client_map.as_mut().map(|map| &mut map.0));
+        for map in maps {
+            if let Some(door) = map.mechanisms.doors.get_mut(index) {
+                door.open = net_door.open;
+            }
+        }
+    }
+}
+
 fn sync_local_player_health_from_net_health(
     net_players: Query<(&NetPlayer, &NetHealth), Changed<NetHealth>>,
     local_player_id: Res<LocalPlayerId>,
@@ -915,6 +959,7 @@ fn sync_weapon_hud_and_flame_visual(
     net_players: Query<&NetPlayer>,
     local_id: Res<LocalPlayerId>,
     loadout: Res<AttackLoadout>,
+    weapons: Res<carcinisation_fps_core::WeaponTable>,
     mut attack_state: ResMut<PlayerAttackState>,
     mut attack_input: ResMut<AttackInput>,
     mut flame_active: ResMut<LocalFlameActive>,
@@ -937,11 +982,13 @@ fn sync_weapon_hud_and_flame_visual(
         *prev_bob_position = None;
         return;
     };
-    let target_attack = match player.current_attack {
-        NetAttackId::Projectile => AttackId::Flamethrower,
-        NetAttackId::None | NetAttackId::Melee => AttackId::Pistol,
-    };
-    attack_state.sync_to_authoritative_weapon(loadout.current(), target_attack);
+    // Only loadout weapons have a view-model; anything else keeps the
+    // presented one.
+    if let Some(target_attack) = AttackId::from_slot(&weapons, player.current_attack.0)
+        .filter(|&attack| loadout.contains(attack))
+    {
+        attack_state.sync_to_authoritative_weapon(loadout.current(), target_attack);
+    }
 
     // Derive aim_turn_velocity from replicated angle delta so the
     // flamethrower chain bends (whip effect) during turns.
@@ -980,8 +1027,11 @@ fn sync_weapon_hud_and_flame_visual(
     }
 
     let active = flame_active.0
-        && matches!(player.current_attack, NetAttackId::Projectile)
-        && loadout.current() == AttackId::Flamethrower
+        && player
+            .current_attack
+            .weapon(&weapons)
+            .is_some_and(carcinisation_fps_core::WeaponDef::is_flame)
+        && loadout.current().slot == player.current_attack.0
         && !attack_state.has_pending_weapon_switch();
     if active {
         attack_input.shoot_held = true;
@@ -1071,10 +1121,12 @@ fn kickstart_client_transport(
 fn init_client_setup(
     mut commands: Commands,
     connect_addr: Res<ConnectAddr>,
+    connect_token: Option<Res<ClientConnectToken>>,
     channels: Res<RepliconChannels>,
     mut connection_state: ResMut<ConnectionState>,
 ) {
     use bevy_renet2::netcode::{ClientAuthentication, NativeSocket};
+    use carcinisation_net::transport::MultiplayerTransport;
     // Reset start_time — build() captured Instant::now() during plugin ctor,
     // but app init (asset loading, shader compilation) may have taken seconds.
     if let ConnectionState::Connecting { start_time, .. } = &mut *connection_state {
@@ -1100,12 +1152,23 @@ fn init_client_setup(
     let socket = NativeSocket::new(std::net::UdpSocket::bind(local_addr).expect("bind"))
         .expect("create socket");
 
-    let authentication = ClientAuthentication::Unsecure {
-        client_id,
-        protocol_id: carcinisation_net::PROTOCOL_ID,
-        socket_id: 0,
-        server_addr: connect_addr.0,
-        user_data: None,
+    let (authentication, auth_label) = match connect_token {
+        Some(token) => (
+            ClientAuthentication::Secure {
+                connect_token: token.0.clone(),
+            },
+            "connect token".to_string(),
+        ),
+        None => (
+            ClientAuthentication::Unsecure {
+                client_id,
+                protocol_id: carcinisation_net::PROTOCOL_ID,
+                socket_id: MultiplayerTransport::NativeUdp.socket_id(),
+                server_addr: connect_addr.0,
+                user_data: None,
+            },
+            format!("unsecure, client_id={client_id}"),
+        ),
     };
 
     let transport = NetcodeClientTransport::new(current_time, authentication, socket)
@@ -1117,8 +1180,8 @@ fn init_client_setup(
     commands.insert_resource(transport);
 
     info!(
-        "Client connecting to {} (UDP, client_id={})",
-        connect_addr.0, client_id
+        "Client connecting to {} (UDP, {auth_label})",
+        connect_addr.0
     );
 }
 
@@ -1304,6 +1367,8 @@ struct SyncSpriteResources<'w> {
     blood_shot: Option<Res<'w, BloodShotSprites>>,
     attack: Option<Res<'w, PlayerAttackSprites>>,
     burn_config: Res<'w, carcinisation_fps_core::BurnConfig>,
+    /// Tells remote players' flame weapons apart from hitscan ones.
+    weapons: Res<'w, carcinisation_fps_core::WeaponTable>,
 }
 
 /// Bundled prediction visual state for `sync_camera_from_net_player`.
@@ -1575,10 +1640,10 @@ fn sync_camera_from_net_player(
         // Flame uses interpolated position/angle for smooth nozzle tracking.
         {
             let flame_active = rp.flame_active
-                && matches!(
-                    rp.current_attack,
-                    carcinisation_net::NetAttackId::Projectile
-                );
+                && rp
+                    .current_attack
+                    .weapon(&sprites.weapons)
+                    .is_some_and(carcinisation_fps_core::WeaponDef::is_flame);
 
             let flame_3p_cfg = sync_locals.remote_flame_config.clone().unwrap_or_default();
             let world_range = flame_cfg.range;
@@ -2408,6 +2473,14 @@ mod tests {
     use carcinisation_fps_core::presentation::{AttackPresentationKind, EnemyPresentationState};
     use carcinisation_net::{NetPickupKind, NetworkObjectId, PlayerNetState};
 
+    fn stock_attack(id: &str) -> AttackId {
+        AttackId::from_weapon_id(&carcinisation_fps_core::WeaponTable::default(), id).unwrap()
+    }
+
+    fn stock_net_attack(id: &str) -> NetAttackId {
+        NetAttackId::of(&carcinisation_fps_core::WeaponTable::default(), id).unwrap()
+    }
+
     #[test]
     fn weapon_bob_displacement_source_ignores_stationary_input() {
         let mut previous = None;
@@ -2443,6 +2516,7 @@ mod tests {
         app.add_plugins(MinimalPlugins);
         app.insert_resource(LocalPlayerId(Some(PlayerId(1))));
         app.init_resource::<AttackLoadout>();
+        app.init_resource::<carcinisation_fps_core::WeaponTable>();
         app.init_resource::<PlayerAttackState>();
         app.insert_resource(AttackInput {
             moving_forward_back: true,
@@ -2455,7 +2529,7 @@ mod tests {
             player_id: PlayerId(1),
             position: Vec2::new(2.0, 3.0),
             angle: 0.0,
-            current_attack: NetAttackId::None,
+            current_attack: NetAttackId::default(),
             state: PlayerNetState::Alive,
             flame_active: false,
             avatar_palette_variant: None,
@@ -2479,6 +2553,7 @@ mod tests {
         app.add_plugins(MinimalPlugins);
         app.insert_resource(LocalPlayerId(Some(PlayerId(1))));
         app.init_resource::<AttackLoadout>();
+        app.init_resource::<carcinisation_fps_core::WeaponTable>();
         app.init_resource::<PlayerAttackState>();
         app.init_resource::<AttackInput>();
         app.init_resource::<LocalFlameActive>();
@@ -2488,7 +2563,7 @@ mod tests {
             player_id: PlayerId(1),
             position: Vec2::new(2.0, 3.0),
             angle: 0.0,
-            current_attack: NetAttackId::None,
+            current_attack: NetAttackId::default(),
             state: PlayerNetState::Alive,
             flame_active: false,
             avatar_palette_variant: None,
@@ -2498,7 +2573,7 @@ mod tests {
 
         assert_eq!(
             app.world().resource::<AttackLoadout>().current(),
-            AttackId::Flamethrower
+            stock_attack("flamethrower")
         );
         assert!(
             app.world()
@@ -2513,6 +2588,7 @@ mod tests {
         app.add_plugins(MinimalPlugins);
         app.insert_resource(LocalPlayerId(Some(PlayerId(1))));
         app.init_resource::<AttackLoadout>();
+        app.init_resource::<carcinisation_fps_core::WeaponTable>();
         app.init_resource::<PlayerAttackState>();
         app.init_resource::<AttackInput>();
         app.init_resource::<LocalFlameActive>();
@@ -2520,13 +2596,13 @@ mod tests {
 
         app.world_mut()
             .resource_mut::<PlayerAttackState>()
-            .request_weapon_switch_to(AttackId::Pistol);
+            .request_weapon_switch_to(stock_attack("pistol"));
 
         app.world_mut().spawn(NetPlayer {
             player_id: PlayerId(1),
             position: Vec2::new(2.0, 3.0),
             angle: 0.0,
-            current_attack: NetAttackId::Projectile,
+            current_attack: stock_net_attack("flamethrower"),
             state: PlayerNetState::Alive,
             flame_active: false,
             avatar_palette_variant: None,
@@ -2536,7 +2612,7 @@ mod tests {
 
         assert_eq!(
             app.world().resource::<AttackLoadout>().current(),
-            AttackId::Flamethrower
+            stock_attack("flamethrower")
         );
         assert!(
             !app.world()
@@ -2568,7 +2644,6 @@ mod tests {
             &camera,
             &map,
             &sprites,
-            37,
             1.0,
             0.0,
             &mut input,
@@ -2587,13 +2662,12 @@ mod tests {
             1.5,
             2.0,
             SnapTurnVisualInput::default(),
-            &carcinisation_fps_core::EnemyReactionTuning::default(),
+            &carcinisation_fps_core::WeaponTable::default(),
         );
         process_player_attacks(
             &camera,
             &map,
             &sprites,
-            37,
             1.0,
             1.0,
             &mut input,
@@ -2612,15 +2686,16 @@ mod tests {
             1.5,
             2.0,
             SnapTurnVisualInput::default(),
-            &carcinisation_fps_core::EnemyReactionTuning::default(),
+            &carcinisation_fps_core::WeaponTable::default(),
         );
-        assert_eq!(loadout.current(), AttackId::Pistol);
+        assert_eq!(loadout.current(), stock_attack("pistol"));
         assert!(!attack_state.has_pending_weapon_switch());
 
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
         app.insert_resource(LocalPlayerId(Some(PlayerId(1))));
         app.insert_resource(loadout);
+        app.init_resource::<carcinisation_fps_core::WeaponTable>();
         app.insert_resource(attack_state);
         app.init_resource::<AttackInput>();
         app.init_resource::<LocalFlameActive>();
@@ -2630,7 +2705,7 @@ mod tests {
             player_id: PlayerId(1),
             position: Vec2::new(2.0, 3.0),
             angle: 0.0,
-            current_attack: NetAttackId::Projectile,
+            current_attack: stock_net_attack("flamethrower"),
             state: PlayerNetState::Alive,
             flame_active: true,
             avatar_palette_variant: None,
@@ -2643,7 +2718,7 @@ mod tests {
         assert!(!input.shoot_just_pressed);
         assert_eq!(
             app.world().resource::<AttackLoadout>().current(),
-            AttackId::Pistol
+            stock_attack("pistol")
         );
         assert!(
             app.world()
@@ -2716,6 +2791,7 @@ mod tests {
         app.insert_resource(PlayerFlamethrower3pConfig::default());
         app.insert_resource(carcinisation_fps_core::PlayerFlamethrowerConfig::load());
         app.insert_resource(carcinisation_fps_core::BurnConfig::default());
+        app.init_resource::<carcinisation_fps_core::WeaponTable>();
         app.insert_resource(ScreenParticleConfig::default());
     }
 
@@ -2734,7 +2810,7 @@ mod tests {
             player_id: PlayerId(1),
             position: Vec2::new(2.0, 3.0),
             angle: 0.25,
-            current_attack: NetAttackId::None,
+            current_attack: NetAttackId::default(),
             state: PlayerNetState::Alive,
             flame_active: false,
             avatar_palette_variant: None,
@@ -2804,7 +2880,7 @@ mod tests {
             player_id: PlayerId(1),
             position: Vec2::new(2.0, 3.0),
             angle: 0.25,
-            current_attack: NetAttackId::None,
+            current_attack: NetAttackId::default(),
             state: PlayerNetState::Alive,
             flame_active: false,
             avatar_palette_variant: None,
@@ -2891,7 +2967,7 @@ mod tests {
                     player_id: PlayerId(1),
                     position: Vec2::new(2.0, 3.0),
                     angle: 0.25,
-                    current_attack: NetAttackId::None,
+                    current_attack: NetAttackId::default(),
                     state: PlayerNetState::Alive,
                     flame_active: false,
                     avatar_palette_variant: None,
@@ -2995,7 +3071,7 @@ mod tests {
                     player_id: PlayerId(1),
                     position: Vec2::new(2.0, 3.0),
                     angle: 0.25,
-                    current_attack: NetAttackId::None,
+                    current_attack: NetAttackId::default(),
                     state: PlayerNetState::Alive,
                     flame_active: false,
                     avatar_palette_variant: None,
@@ -3033,7 +3109,7 @@ mod tests {
                     player_id: PlayerId(1),
                     position: Vec2::new(2.0, 3.0),
                     angle: 0.25,
-                    current_attack: NetAttackId::None,
+                    current_attack: NetAttackId::default(),
                     state: PlayerNetState::Alive,
                     flame_active: false,
                     avatar_palette_variant: None,
@@ -3286,7 +3362,7 @@ mod tests {
             player_id: PlayerId(1),
             position: Vec2::new(2.0, 3.0),
             angle: 0.0,
-            current_attack: NetAttackId::None,
+            current_attack: NetAttackId::default(),
             state: PlayerNetState::Alive,
             flame_active: false,
             avatar_palette_variant: None,
@@ -3297,7 +3373,7 @@ mod tests {
             player_id: PlayerId(2),
             position: Vec2::new(4.0, 5.0),
             angle: 0.0,
-            current_attack: NetAttackId::Projectile,
+            current_attack: stock_net_attack("flamethrower"),
             state: PlayerNetState::Alive,
             flame_active: true,
             avatar_palette_variant: None,
@@ -3331,7 +3407,7 @@ mod tests {
             player_id: PlayerId(1),
             position: Vec2::new(2.0, 3.0),
             angle: 0.0,
-            current_attack: NetAttackId::None,
+            current_attack: NetAttackId::default(),
             state: PlayerNetState::Alive,
             flame_active: false,
             avatar_palette_variant: None,
@@ -3342,7 +3418,7 @@ mod tests {
             player_id: PlayerId(2),
             position: Vec2::new(4.0, 5.0),
             angle: 0.0,
-            current_attack: NetAttackId::Projectile,
+            current_attack: stock_net_attack("flamethrower"),
             state: PlayerNetState::Alive,
             flame_active: false,
             avatar_palette_variant: None,
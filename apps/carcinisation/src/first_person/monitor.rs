@@ -219,15 +219,18 @@ fn monitor_connection_watchdog(
     }
 }
 
-/// Set up the renet2 client transport with `ConnectMode::Monitor` in `user_data`.
+/// Set up the renet2 client transport with `ConnectMode::Monitor` in `user_data`,
+/// or with the monitor connect token when one is supplied.
 #[cfg(not(target_family = "wasm"))]
 fn init_monitor_transport(
     mut commands: Commands,
     connect_addr: Res<MonitorConnectAddr>,
+    connect_token: Option<Res<super::ClientConnectToken>>,
     channels: Res<RepliconChannels>,
     mut connection_state: ResMut<ConnectionState>,
 ) {
     use bevy_renet2::netcode::{ClientAuthentication, NativeSocket};
+    use carcinisation_net::transport::MultiplayerTransport;
 
     // Reset start_time — plugin ctor captured Instant::now() before app init.
     if let ConnectionState::Connecting { start_time, .. } = &mut *connection_state {
@@ -252,12 +255,18 @@ fn init_monitor_transport(
     let socket = NativeSocket::new(std::net::UdpSocket::bind(local_addr).expect("bind"))
         .expect("create socket");
 
-    let authentication = ClientAuthentication::Unsecure {
-        client_id,
-        protocol_id: carcinisation_net::PROTOCOL_ID,
-        socket_id: 0,
-        server_addr: connect_addr.0,
-        user_data: Some(ConnectMode::Monitor.to_user_data()),
+    // With a token the server reads the mode sealed inside it instead.
+    let authentication = match connect_token {
+        Some(token) => ClientAuthentication::Secure {
+            connect_token: token.0.clone(),
+        },
+        None => ClientAuthentication::Unsecure {
+            client_id,
+            protocol_id: carcinisation_net::PROTOCOL_ID,
+            socket_id: MultiplayerTransport::NativeUdp.socket_id(),
+            server_addr: connect_addr.0,
+            user_data: Some(ConnectMode::Monitor.to_user_data()),
+        },
     };
 
     let transport =
@@ -84,6 +84,7 @@ use carcinisation_net::tick::{InputSequence, STALE_INPUT_TICKS};
 use carcinisation_net::{InputAck, NetPlayer};
 
 use crate::first_person::LocalPlayerId;
+use crate::first_person::input::ClientAckedSequence;
 use crate::first_person::interpolation::shortest_angle_delta;
 
 /// Diagnostic metrics for prediction debugging. Visible via BRP.
@@ -630,10 +631,14 @@ pub fn clear_prediction_on_death(
 ///    so the predicted state stays ahead of the server by the local input lag.
 /// 4. Updates `PredictedRenderState` so render interpolation converges toward
 ///    the corrected position without waiting for the next `FixedUpdate`.
+///
+/// The acked sequence is also stored in `ClientAckedSequence` for the server's
+/// hitscan lag compensation.
 #[allow(clippy::too_many_arguments)]
 pub fn handle_input_ack(
     trigger: On<InputAck>,
     local_id: Res<LocalPlayerId>,
+    mut acked_sequence: ResMut<ClientAckedSequence>,
     mut predicted: ResMut<PredictedPlayerState>,
     mut history: ResMut<PredictionHistory>,
     mut render_state: ResMut<PredictedRenderState>,
@@ -650,6 +655,10 @@ pub fn handle_input_ack(
         return;
     }
 
+    if ack.last_processed_sequence.is_after(acked_sequence.0) {
+        acked_sequence.0 = ack.last_processed_sequence;
+    }
+
     if !predicted.initialised {
         return;
     }
@@ -851,6 +860,8 @@ mod tests {
                 1, 0, 0, 0, 1, //
                 1, 1, 1, 1, 1,
             ],
+            mechanisms: carcinisation_fps_core::MapMechanisms::default(),
+            heights: Vec::new(),
         }
     }
 
@@ -0,0 +1,300 @@
+//! Declarative campaigns authored as `.campaign.ron` files.
+//!
+//! A campaign lists cinematic and stage steps by asset path. Each step may be
+//! flagged as a checkpoint (autosaved on entry) and may carry a
+//! [`StepCondition`]; a step whose condition is not met when the campaign
+//! reaches it is skipped.
+
+use std::path::Path;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    components::steps::{CinematicAssetGameStep, StageAssetGameStep},
+    data::GameStep,
+    resources::GameData,
+};
+use crate::stage::messages::StageDeathEvent;
+
+const CINEMATIC_EXTENSION: &str = ".cs.ron";
+const STAGE_EXTENSION: &str = ".sg.ron";
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Resource, Serialize)]
+/// The campaign new runs are built from.
+///
+/// Hot reloading replaces this resource; a run already in progress keeps the
+/// `GameData` it started with.
+pub struct CampaignData {
+    pub name: String,
+    pub steps: Vec<CampaignStep>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+/// A campaign step referencing a cinematic (`.cs.ron`) or stage (`.sg.ron`)
+/// asset.
+pub enum CampaignStep {
+    Cinematic {
+        src: String,
+        #[serde(default)]
+        checkpoint: bool,
+        #[serde(default)]
+        condition: Option<StepCondition>,
+    },
+    Stage {
+        src: String,
+        #[serde(default)]
+        checkpoint: bool,
+        #[serde(default)]
+        condition: Option<StepCondition>,
+    },
+}
+
+impl CampaignStep {
+    #[must_use]
+    pub fn src(&self) -> &str {
+        match self {
+            Self::Cinematic { src, .. } | Self::Stage { src, .. } => src,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// Gate on whether a campaign step is played.
+pub enum StepCondition {
+    /// The run's score is at least this value.
+    ScoreAtLeast(u32),
+    /// The previous stage was cleared without dying.
+    NoDeathClear,
+    All(Vec<StepCondition>),
+    Any(Vec<StepCondition>),
+    Not(Box<StepCondition>),
+}
+
+impl StepCondition {
+    #[must_use]
+    pub fn is_met(&self, score: u32, stats: &CampaignStats) -> bool {
+        match self {
+            Self::ScoreAtLeast(threshold) => score >= *threshold,
+            Self::NoDeathClear => stats.last_clear_deaths == Some(0),
+            Self::All(conditions) => conditions.iter().all(|c| c.is_met(score, stats)),
+            Self::Any(conditions) => conditions.iter().any(|c| c.is_met(score, stats)),
+            Self::Not(condition) => !condition.is_met(score, stats),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Resource)]
+/// Per-run progress that step conditions are evaluated against. Reset when a
+/// run starts; not persisted in saves.
+pub struct CampaignStats {
+    /// Deaths in the stage currently being played.
+    pub stage_deaths: u32,
+    /// Deaths in the most recently cleared stage, if any was cleared.
+    pub last_clear_deaths: Option<u32>,
+}
+
+impl CampaignStats {
+    /// Close out the current stage as cleared.
+    pub const fn record_clear(&mut self) {
+        self.last_clear_deaths = Some(self.stage_deaths);
+        self.stage_deaths = 0;
+    }
+}
+
+impl CampaignData {
+    /// Load the main story campaign.
+    ///
+    /// # Panics
+    ///
+    /// If the embedded campaign fails [`Self::validate`].
+    #[must_use]
+    pub fn load() -> Self {
+        let campaign: Self = carcinisation_core::ron_config!("assets/campaigns/main.campaign.ron");
+        campaign.validate_or_panic();
+        campaign
+    }
+
+    /// Validate the campaign layout.
+    ///
+    /// Returns a list of validation error messages (empty = valid).
+    #[must_use]
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        if self.name.is_empty() {
+            errors.push("name must not be empty".into());
+        }
+        if self.steps.is_empty() {
+            errors.push("steps must not be empty".into());
+        }
+        for (i, step) in self.steps.iter().enumerate() {
+            let extension = match step {
+                CampaignStep::Cinematic { .. } => CINEMATIC_EXTENSION,
+                CampaignStep::Stage { .. } => STAGE_EXTENSION,
+            };
+            if !step.src().ends_with(extension) {
+                errors.push(format!(
+                    "steps[{i}].src '{}' must end with {extension}",
+                    step.src()
+                ));
+            }
+        }
+
+        errors
+    }
+
+    /// Referenced asset files that do not exist under `assets_root`.
+    #[must_use]
+    pub fn missing_assets(&self, assets_root: &Path) -> Vec<String> {
+        self.steps
+            .iter()
+            .map(CampaignStep::src)
+            .filter(|src| !assets_root.join(src).is_file())
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Panic if the campaign is invalid.
+    ///
+    /// Hot reload additionally checks [`Self::missing_assets`] and wraps this
+    /// in `catch_unwind`, keeping the previous campaign on failure.
+    ///
+    /// # Panics
+    ///
+    /// If [`Self::validate`] reports any error.
+    pub fn validate_or_panic(&self) {
+        let errors = self.validate();
+        assert!(
+            errors.is_empty(),
+            "invalid CampaignData: {}",
+            errors.join("; ")
+        );
+    }
+}
+
+impl From<&CampaignStep> for GameStep {
+    fn from(step: &CampaignStep) -> Self {
+        match step.clone() {
+            CampaignStep::Cinematic {
+                src,
+                checkpoint,
+                condition,
+            } => CinematicAssetGameStep {
+                src,
+                is_checkpoint: checkpoint,
+                condition,
+            }
+            .into(),
+            CampaignStep::Stage {
+                src,
+                checkpoint,
+                condition,
+            } => StageAssetGameStep {
+                src,
+                is_checkpoint: checkpoint,
+                condition,
+            }
+            .into(),
+        }
+    }
+}
+
+impl From<&CampaignData> for GameData {
+    fn from(campaign: &CampaignData) -> Self {
+        Self {
+            name: campaign.name.clone(),
+            steps: campaign.steps.iter().map(GameStep::from).collect(),
+        }
+    }
+}
+
+/// @trigger Counts deaths towards the current stage's clear.
+pub fn count_stage_death(_trigger: On<StageDeathEvent>, stats: Option<ResMut<CampaignStats>>) {
+    if let Some(mut stats) = stats {
+        stats.stage_deaths += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stage(src: &str) -> CampaignStep {
+        CampaignStep::Stage {
+            src: src.to_string(),
+            checkpoint: false,
+            condition: None,
+        }
+    }
+
+    #[test]
+    fn main_campaign_is_valid() {
+        assert!(CampaignData::load().validate().is_empty());
+    }
+
+    #[test]
+    fn step_defaults_and_conditions_parse() {
+        let campaign: CampaignData = ron::from_str(
+            r#"CampaignData(
+                name: "Test",
+                steps: [
+                    Stage(src: "stages/a.sg.ron"),
+                    Stage(
+                        src: "stages/b.sg.ron",
+                        checkpoint: true,
+                        condition: Some(All([ScoreAtLeast(1000), NoDeathClear])),
+                    ),
+                ],
+            )"#,
+        )
+        .unwrap();
+        assert_eq!(campaign.steps[0], stage("stages/a.sg.ron"));
+        assert_eq!(
+            campaign.steps[1],
+            CampaignStep::Stage {
+                src: "stages/b.sg.ron".to_string(),
+                checkpoint: true,
+                condition: Some(StepCondition::All(vec![
+                    StepCondition::ScoreAtLeast(1000),
+                    StepCondition::NoDeathClear,
+                ])),
+            }
+        );
+    }
+
+    #[test]
+    fn validate_rejects_mismatched_extensions() {
+        let campaign = CampaignData {
+            name: "Test".to_string(),
+            steps: vec![CampaignStep::Cinematic {
+                src: "stages/park.sg.ron".to_string(),
+                checkpoint: false,
+                condition: None,
+            }],
+        };
+        assert_eq!(campaign.validate().len(), 1);
+    }
+
+    #[test]
+    fn no_death_clear_needs_a_clean_previous_stage() {
+        let condition = StepCondition::NoDeathClear;
+        let mut stats = CampaignStats::default();
+        assert!(!condition.is_met(0, &stats));
+
+        stats.stage_deaths = 1;
+        stats.record_clear();
+        assert!(!condition.is_met(0, &stats));
+
+        stats.record_clear();
+        assert!(condition.is_met(0, &stats));
+    }
+
+    #[test]
+    fn score_threshold_combines_with_not() {
+        let stats = CampaignStats::default();
+        let below = StepCondition::Not(Box::new(StepCondition::ScoreAtLeast(500)));
+        assert!(below.is_met(499, &stats));
+        assert!(!below.is_met(500, &stats));
+    }
+}
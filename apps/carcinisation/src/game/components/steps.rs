@@ -1,6 +1,6 @@
 //! Component types representing queued game steps.
 
-use crate::{cutscene::data::CutsceneData, stage::data::StageData};
+use crate::{cutscene::data::CutsceneData, game::campaign::StepCondition, stage::data::StageData};
 use bevy::prelude::*;
 use derive_new::new;
 use std::sync::Arc;
@@ -20,6 +20,8 @@ pub struct CinematicAssetGameStep {
     pub src: String,
     #[new(value = "true")]
     pub is_checkpoint: bool,
+    #[new(default)]
+    pub condition: Option<StepCondition>,
 }
 
 #[derive(Component, Clone, Debug)]
@@ -36,6 +38,12 @@ pub struct StageGameStep {
     pub data: Arc<StageData>,
 }
 
-#[derive(Component, Clone, Debug)]
+#[derive(new, Component, Clone, Debug)]
 /// Stage step referencing a serialized stage asset path.
-pub struct StageAssetGameStep(pub String);
+pub struct StageAssetGameStep {
+    pub src: String,
+    #[new(value = "false")]
+    pub is_checkpoint: bool,
+    #[new(default)]
+    pub condition: Option<StepCondition>,
+}
@@ -1,8 +1,11 @@
 //! Shared constants and enums describing game progression steps.
 
-use super::components::steps::{
-    CinematicAssetGameStep, CreditsGameStep, CutsceneGameStep, StageAssetGameStep, StageGameStep,
-    TransitionGameStep,
+use super::{
+    campaign::StepCondition,
+    components::steps::{
+        CinematicAssetGameStep, CreditsGameStep, CutsceneGameStep, StageAssetGameStep,
+        StageGameStep, TransitionGameStep,
+    },
 };
 use derive_more::From;
 
@@ -18,3 +21,40 @@ pub enum GameStep {
     StageAsset(StageAssetGameStep),
     Transition(TransitionGameStep),
 }
+
+impl GameStep {
+    /// Stable identifier used to find this step again after the campaign's
+    /// step list changes (e.g. when migrating a save).
+    #[must_use]
+    pub fn key(&self) -> String {
+        match self {
+            Self::Credits(_) => "credits".to_string(),
+            Self::Cutscene(step) => format!("cutscene:{}", step.data.name),
+            Self::CutsceneAsset(step) => step.src.clone(),
+            Self::Stage(step) => format!("stage:{}", step.data.name),
+            Self::StageAsset(step) => step.src.clone(),
+            Self::Transition(_) => "transition".to_string(),
+        }
+    }
+
+    /// Whether the campaign autosaves when this step begins.
+    #[must_use]
+    pub const fn is_checkpoint(&self) -> bool {
+        match self {
+            Self::Cutscene(step) => step.is_checkpoint,
+            Self::CutsceneAsset(step) => step.is_checkpoint,
+            Self::StageAsset(step) => step.is_checkpoint,
+            Self::Credits(_) | Self::Stage(_) | Self::Transition(_) => false,
+        }
+    }
+
+    /// Condition that must hold for this step to be played.
+    #[must_use]
+    pub const fn condition(&self) -> Option<&StepCondition> {
+        match self {
+            Self::CutsceneAsset(step) => step.condition.as_ref(),
+            Self::StageAsset(step) => step.condition.as_ref(),
+            Self::Credits(_) | Self::Cutscene(_) | Self::Stage(_) | Self::Transition(_) => None,
+        }
+    }
+}
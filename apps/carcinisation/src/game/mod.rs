@@ -1,18 +1,23 @@
 //! Game progression plugin: orchestrates stages, cutscenes, and score handling.
 
+pub mod campaign;
 pub mod components;
 pub mod data;
 pub mod messages;
 pub mod resources;
+pub mod save;
 pub mod score;
+pub mod storage;
 mod systems;
 
 use crate::core::event::on_trigger_write_event;
 use activable::{Activable, ActivableAppExt};
 
 use self::{
+    campaign::{CampaignData, count_stage_death},
     messages::{GameOverEvent, GameStartupEvent},
     resources::GameProgress,
+    save::SavePlugin,
     score::ScorePlugin,
     systems::setup::{
         check_cutscene_data_loaded, check_stage_data_loaded, on_cutscene_shutdown, on_game_over,
@@ -29,7 +34,9 @@ pub struct GamePlugin;
 
 impl Plugin for GamePlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(ScorePlugin)
+        app.insert_resource(CampaignData::load())
+            .add_plugins((ScorePlugin, SavePlugin))
+            .add_observer(count_stage_death)
             .init_state::<GameProgressState>()
             .add_message::<GameOverEvent>()
             .add_observer(on_game_over)
@@ -48,6 +55,25 @@ impl Plugin for GamePlugin {
                     .run_if(resource_exists::<GameProgress>),),
             );
 
+        #[cfg(feature = "hot_reload")]
+        {
+            carcinisation_core::watch_config!(app, "assets/campaigns/main.campaign.ron");
+            carcinisation_core::reload_ron_system!(
+                reload_campaign,
+                CampaignData,
+                "assets/campaigns/main.campaign.ron",
+                |c: &CampaignData| {
+                    c.validate_or_panic();
+                    let missing = c.missing_assets(std::path::Path::new(concat!(
+                        env!("CARGO_MANIFEST_DIR"),
+                        "/../../assets"
+                    )));
+                    assert!(missing.is_empty(), "missing assets: {}", missing.join(", "));
+                }
+            );
+            app.add_systems(Update, reload_campaign);
+        }
+
         #[cfg(debug_assertions)]
         {
             app.add_observer(debug_on_game_over);
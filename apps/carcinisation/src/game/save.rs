@@ -0,0 +1,318 @@
+//! Campaign save/continue.
+//!
+//! The run is autosaved whenever a campaign step flagged as a checkpoint
+//! begins and when the player reaches a stage's authored checkpoint. Game over and finishing the
+//! campaign delete the save. The main menu offers "Continue" while one
+//! exists.
+
+use activable::ActivableAppExt;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    GamePlugin,
+    data::GameStep,
+    messages::GameOverEvent,
+    resources::{GameData, GameProgress, Lives},
+    score::components::Score,
+    storage,
+};
+use crate::stage::{
+    StageProgressState,
+    data::StageData,
+    player::attacks::{AttackId, AttackLoadout},
+    resources::StageProgress,
+};
+
+/// Storage name of the campaign save.
+pub const SAVE_STORAGE: &str = "save";
+
+/// Bump when the layout of [`SaveData`] changes, and teach
+/// [`SaveData::migrate`] to upgrade the previous version.
+pub const SAVE_VERSION: u32 = 1;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+/// Persisted campaign progress.
+pub struct SaveData {
+    pub version: u32,
+    /// `GameData::name` of the campaign this run belongs to.
+    pub campaign: String,
+    /// `GameStep::key` of every campaign step when saved, used to remap
+    /// `step_index` after the step list changes.
+    pub step_keys: Vec<String>,
+    /// Index into `GameData::steps` of the step to resume.
+    pub step_index: usize,
+    /// Whether the player had reached the current stage's checkpoint.
+    pub at_checkpoint: bool,
+    pub lives: u8,
+    pub score: u32,
+    pub unlocked_attacks: Vec<AttackId>,
+}
+
+impl SaveData {
+    /// Bring a loaded save in line with the current build and campaign.
+    ///
+    /// Returns `None` when the save cannot be used: it was written by a newer
+    /// build or for a different campaign. When the step list changed, the
+    /// saved step is looked up by key; if it was removed, the run resumes
+    /// from the start of the latest earlier step that still exists.
+    #[must_use]
+    pub fn migrate(mut self, game_data: &GameData) -> Option<Self> {
+        if self.version > SAVE_VERSION || self.campaign != game_data.name {
+            return None;
+        }
+        self.version = SAVE_VERSION;
+
+        let current_keys: Vec<String> = game_data.steps.iter().map(GameStep::key).collect();
+        if self.step_keys != current_keys {
+            let saved_index = self.step_index.min(self.step_keys.len().saturating_sub(1));
+            let remapped = self
+                .step_keys
+                .get(self.step_index)
+                .and_then(|key| current_keys.iter().position(|k| k == key));
+            match remapped {
+                Some(index) => self.step_index = index,
+                None => {
+                    self.at_checkpoint = false;
+                    self.step_index = self.step_keys[..saved_index]
+                        .iter()
+                        .rev()
+                        .find_map(|key| current_keys.iter().position(|k| k == key))
+                        .unwrap_or(0);
+                }
+            }
+            self.step_keys = current_keys;
+        }
+
+        (self.step_index < game_data.steps.len()).then_some(self)
+    }
+}
+
+/// Load the save for `game_data`'s campaign, migrated to the current build.
+#[must_use]
+pub fn load_save(game_data: &GameData) -> Option<SaveData> {
+    storage::load::<SaveData>(SAVE_STORAGE)?.migrate(game_data)
+}
+
+#[derive(Resource, Debug)]
+/// Set by "Continue" when the save was taken at a stage checkpoint; the next
+/// stage startup resumes from that checkpoint and clears it.
+pub struct ContinueFromCheckpoint;
+
+/// Insert the resources a run restored from `save` starts with. The caller
+/// then triggers `GameStartupEvent`, which keeps them.
+pub fn restore_save(commands: &mut Commands, game_data: &GameData, save: SaveData) {
+    commands.insert_resource(game_data.clone());
+    commands.insert_resource(GameProgress {
+        index: save.step_index,
+    });
+    commands.insert_resource(Lives(save.lives));
+    commands.insert_resource(Score { value: save.score });
+    commands.insert_resource(AttackLoadout::from_unlocked(save.unlocked_attacks));
+    if save.at_checkpoint {
+        commands.insert_resource(ContinueFromCheckpoint);
+    }
+}
+
+/// Consume a pending [`ContinueFromCheckpoint`] for a stage about to start.
+/// Returns whether the stage should start from its checkpoint, and points
+/// `stage_progress` at the checkpoint step when it does.
+pub fn take_checkpoint_resume(
+    commands: &mut Commands,
+    resume: Option<&ContinueFromCheckpoint>,
+    data: &StageData,
+    stage_progress: &mut StageProgress,
+) -> bool {
+    if resume.is_none() {
+        return false;
+    }
+    commands.remove_resource::<ContinueFromCheckpoint>();
+    let Some(checkpoint) = &data.checkpoint else {
+        return false;
+    };
+    stage_progress.index = checkpoint.step_index;
+    true
+}
+
+fn write_save(
+    game_data: &GameData,
+    step_index: usize,
+    at_checkpoint: bool,
+    lives: &Lives,
+    score: &Score,
+    loadout: Option<&AttackLoadout>,
+) {
+    let save = SaveData {
+        version: SAVE_VERSION,
+        campaign: game_data.name.clone(),
+        step_keys: game_data.steps.iter().map(GameStep::key).collect(),
+        step_index,
+        at_checkpoint,
+        lives: lives.0,
+        score: score.value,
+        unlocked_attacks: loadout.map(|l| l.unlocked().to_vec()).unwrap_or_default(),
+    };
+    storage::save(SAVE_STORAGE, &save);
+}
+
+/// @system Autosaves when a checkpoint campaign step begins; deletes the save
+/// once the campaign is finished.
+pub fn autosave_on_step(
+    game_progress: Res<GameProgress>,
+    game_data: Res<GameData>,
+    lives: Res<Lives>,
+    score: Res<Score>,
+    loadout: Option<Res<AttackLoadout>>,
+    resume: Option<Res<ContinueFromCheckpoint>>,
+) {
+    if !game_progress.is_changed() {
+        return;
+    }
+    let Some(step) = game_data.steps.get(game_progress.index) else {
+        storage::remove(SAVE_STORAGE);
+        return;
+    };
+    // A checkpoint continue is already saved further in than the step start.
+    if resume.is_some() || !step.is_checkpoint() {
+        return;
+    }
+    write_save(
+        &game_data,
+        game_progress.index,
+        false,
+        &lives,
+        &score,
+        loadout.as_deref(),
+    );
+}
+
+/// @system Autosaves when the stage reaches its authored checkpoint step.
+pub fn autosave_on_checkpoint(
+    stage_progress: Res<StageProgress>,
+    stage_data: Res<StageData>,
+    game_progress: Res<GameProgress>,
+    game_data: Res<GameData>,
+    lives: Res<Lives>,
+    score: Res<Score>,
+    loadout: Option<Res<AttackLoadout>>,
+) {
+    if !stage_progress.is_changed() {
+        return;
+    }
+    let Some(checkpoint) = &stage_data.checkpoint else {
+        return;
+    };
+    if stage_progress.index == checkpoint.step_index {
+        write_save(
+            &game_data,
+            game_progress.index,
+            true,
+            &lives,
+            &score,
+            loadout.as_deref(),
+        );
+    }
+}
+
+/// @system Deletes the save when the run ends in game over.
+pub fn clear_save_on_game_over(mut reader: MessageReader<GameOverEvent>) {
+    if reader.read().count() > 0 {
+        storage::remove(SAVE_STORAGE);
+    }
+}
+
+/// Autosave and continue support for the campaign.
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_active_systems::<GamePlugin, _>(
+            (
+                autosave_on_step,
+                autosave_on_checkpoint.run_if(resource_exists::<StageData>),
+            )
+                // The game over screen rewinds progress before returning to
+                // the menu; that reset must not be saved.
+                .run_if(
+                    resource_exists::<GameProgress>
+                        .and(resource_exists::<GameData>)
+                        .and(not(in_state(StageProgressState::GameOver))),
+                ),
+        )
+        .add_systems(Update, clear_save_on_game_over);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::components::steps::StageAssetGameStep;
+
+    fn campaign(paths: &[&str]) -> GameData {
+        GameData {
+            name: "Test".to_string(),
+            steps: paths
+                .iter()
+                .map(|p| StageAssetGameStep::new((*p).to_string()).into())
+                .collect(),
+        }
+    }
+
+    fn save_at(game_data: &GameData, step_index: usize) -> SaveData {
+        SaveData {
+            version: SAVE_VERSION,
+            campaign: game_data.name.clone(),
+            step_keys: game_data.steps.iter().map(GameStep::key).collect(),
+            step_index,
+            at_checkpoint: true,
+            lives: 2,
+            score: 300,
+            unlocked_attacks: vec![AttackId::Pistol],
+        }
+    }
+
+    #[test]
+    fn unchanged_campaign_keeps_save() {
+        let data = campaign(&["a", "b", "c"]);
+        let save = save_at(&data, 1);
+        assert_eq!(save.clone().migrate(&data), Some(save));
+    }
+
+    #[test]
+    fn inserted_step_remaps_index_by_key() {
+        let old = campaign(&["a", "b", "c"]);
+        let new = campaign(&["intro", "a", "b", "c"]);
+        let migrated = save_at(&old, 1).migrate(&new).unwrap();
+        assert_eq!(migrated.step_index, 2);
+        assert!(migrated.at_checkpoint);
+        assert_eq!(migrated.step_keys.len(), 4);
+    }
+
+    #[test]
+    fn removed_step_falls_back_to_previous_surviving_step() {
+        let old = campaign(&["a", "b", "c"]);
+        let new = campaign(&["a", "c"]);
+        let migrated = save_at(&old, 1).migrate(&new).unwrap();
+        assert_eq!(migrated.step_index, 0);
+        assert!(!migrated.at_checkpoint);
+    }
+
+    #[test]
+    fn rejects_newer_version_and_other_campaigns() {
+        let data = campaign(&["a"]);
+        let mut newer = save_at(&data, 0);
+        newer.version = SAVE_VERSION + 1;
+        assert_eq!(newer.migrate(&data), None);
+
+        let mut other = save_at(&data, 0);
+        other.campaign = "Other".to_string();
+        assert_eq!(other.migrate(&data), None);
+    }
+
+    #[test]
+    fn save_roundtrips_through_ron() {
+        let save = save_at(&campaign(&["a", "b"]), 1);
+        let text = ron::to_string(&save).unwrap();
+        assert_eq!(ron::from_str::<SaveData>(&text).unwrap(), save);
+    }
+}
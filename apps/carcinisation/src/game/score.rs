@@ -1,23 +1,44 @@
 //! Score tracking plugin and systems.
 
 pub mod components;
+mod name_entry;
 mod systems;
 
 use bevy::prelude::*;
 
 use self::{
-    components::{HighScores, Score},
+    components::{HIGH_SCORES_STORAGE, HighScores, NameEntry, Score},
+    name_entry::{
+        close_name_entry_outside_game_over, spawn_name_entry_screen, sync_name_entry_text,
+        update_name_entry,
+    },
     systems::{debug_high_scores_updated, on_game_over_update_high_scores},
 };
 
-/// Maintains run score and high-score tables.
+/// Maintains run score and persisted per-campaign high-score tables.
 pub struct ScorePlugin;
 
 impl Plugin for ScorePlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<HighScores>()
-            .init_resource::<Score>()
-            .add_systems(Update, on_game_over_update_high_scores);
+        app.insert_resource(
+            crate::game::storage::load::<HighScores>(HIGH_SCORES_STORAGE).unwrap_or_default(),
+        )
+        .init_resource::<Score>()
+        .add_systems(
+            Update,
+            (
+                on_game_over_update_high_scores,
+                (
+                    spawn_name_entry_screen.run_if(resource_added::<NameEntry>),
+                    update_name_entry,
+                    sync_name_entry_text,
+                    close_name_entry_outside_game_over,
+                )
+                    .chain()
+                    .run_if(resource_exists::<NameEntry>),
+            )
+                .chain(),
+        );
         #[cfg(debug_assertions)]
         {
             app.add_systems(Update, debug_high_scores_updated);
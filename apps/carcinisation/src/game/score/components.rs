@@ -1,24 +1,154 @@
 //! Score-related resources.
 
+use std::collections::BTreeMap;
+
 use bevy::prelude::*;
 pub use carcinisation_base::game::Score;
+use serde::{Deserialize, Serialize};
+
+/// Rows kept per campaign table.
+pub const HIGH_SCORE_TABLE_LEN: usize = 5;
+
+/// Letters in a high-score name.
+pub const NAME_LEN: usize = 3;
+
+/// Storage name of the persisted [`HighScores`].
+pub const HIGH_SCORES_STORAGE: &str = "high_scores";
+
+/// Table key used when no campaign is loaded (e.g. `single_stage`).
+pub const DEFAULT_CAMPAIGN: &str = "default";
 
-#[derive(Resource)]
-/// Stores the top high scores for display.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+/// One row of a high-score table.
+pub struct HighScoreEntry {
+    pub name: String,
+    pub score: u32,
+}
+
+#[derive(Resource, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+/// Top scores per campaign, keyed by `GameData::name`. Persisted between runs.
 pub struct HighScores {
-    pub scores: Vec<(String, u32)>,
+    pub tables: BTreeMap<String, Vec<HighScoreEntry>>,
+}
+
+impl HighScores {
+    /// Rows for `campaign`, highest first.
+    #[must_use]
+    pub fn table(&self, campaign: &str) -> &[HighScoreEntry] {
+        self.tables.get(campaign).map_or(&[], Vec::as_slice)
+    }
+
+    /// Whether `score` would earn a place in `campaign`'s table.
+    #[must_use]
+    pub fn qualifies(&self, campaign: &str, score: u32) -> bool {
+        let table = self.table(campaign);
+        score > 0
+            && (table.len() < HIGH_SCORE_TABLE_LEN || table.last().is_some_and(|e| score > e.score))
+    }
+
+    /// Insert a row, keeping the table sorted and capped. Ties rank below
+    /// existing rows. Returns the 0-based rank, or `None` if it fell off.
+    pub fn insert(&mut self, campaign: &str, name: String, score: u32) -> Option<usize> {
+        let table = self.tables.entry(campaign.to_string()).or_default();
+        let rank = table.partition_point(|e| e.score >= score);
+        table.insert(rank, HighScoreEntry { name, score });
+        table.truncate(HIGH_SCORE_TABLE_LEN);
+        (rank < table.len()).then_some(rank)
+    }
 }
 
-impl Default for HighScores {
-    fn default() -> Self {
+#[derive(Resource, Clone, Debug, PartialEq, Eq)]
+/// GB-style three-letter name being entered after a qualifying game over.
+pub struct NameEntry {
+    pub campaign: String,
+    pub score: u32,
+    pub letters: [u8; NAME_LEN],
+    pub cursor: usize,
+}
+
+impl NameEntry {
+    #[must_use]
+    pub fn new(campaign: String, score: u32) -> Self {
         Self {
-            scores: vec![
-                ("Player 1".to_string(), 100),
-                ("Player 2".to_string(), 80),
-                ("Player 3".to_string(), 60),
-                ("Player 4".to_string(), 40),
-                ("Player 5".to_string(), 20),
-            ],
+            campaign,
+            score,
+            letters: [b'A'; NAME_LEN],
+            cursor: 0,
         }
     }
+
+    /// Step the letter under the cursor through A–Z, wrapping.
+    pub fn cycle_letter(&mut self, delta: i8) {
+        let letter = &mut self.letters[self.cursor];
+        let index = i16::from(*letter - b'A') + i16::from(delta);
+        *letter = b'A' + index.rem_euclid(26) as u8;
+    }
+
+    /// Move the cursor, clamped to the name.
+    pub fn move_cursor(&mut self, delta: i8) {
+        self.cursor = self
+            .cursor
+            .saturating_add_signed(isize::from(delta))
+            .min(NAME_LEN - 1);
+    }
+
+    #[must_use]
+    pub fn name(&self) -> String {
+        self.letters.iter().map(|&b| char::from(b)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables_are_per_campaign_and_capped() {
+        let mut scores = HighScores::default();
+        for score in [50, 10, 40, 30, 20] {
+            assert!(scores.qualifies("a", score));
+            scores.insert("a", "AAA".to_string(), score);
+        }
+        assert!(!scores.qualifies("a", 10));
+        assert!(scores.qualifies("b", 10));
+
+        assert_eq!(scores.insert("a", "NEW".to_string(), 35), Some(2));
+        let table = scores.table("a");
+        assert_eq!(table.len(), HIGH_SCORE_TABLE_LEN);
+        assert_eq!(
+            table.iter().map(|e| e.score).collect::<Vec<_>>(),
+            [50, 40, 35, 30, 20]
+        );
+        assert!(scores.table("b").is_empty());
+    }
+
+    #[test]
+    fn ties_rank_below_existing_rows() {
+        let mut scores = HighScores::default();
+        scores.insert("a", "OLD".to_string(), 10);
+        assert_eq!(scores.insert("a", "NEW".to_string(), 10), Some(1));
+        assert_eq!(scores.table("a")[0].name, "OLD");
+    }
+
+    #[test]
+    fn zero_score_never_qualifies() {
+        assert!(!HighScores::default().qualifies("a", 0));
+    }
+
+    #[test]
+    fn name_entry_wraps_letters_and_clamps_cursor() {
+        let mut entry = NameEntry::new("a".to_string(), 1);
+        entry.cycle_letter(-1);
+        assert_eq!(entry.name(), "ZAA");
+        entry.cycle_letter(1);
+        entry.cycle_letter(1);
+        assert_eq!(entry.name(), "BAA");
+
+        entry.move_cursor(-1);
+        assert_eq!(entry.cursor, 0);
+        entry.move_cursor(5);
+        assert_eq!(entry.cursor, NAME_LEN - 1);
+        entry.cycle_letter(3);
+        assert_eq!(entry.name(), "BAD");
+    }
 }
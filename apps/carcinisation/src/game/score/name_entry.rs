@@ -0,0 +1,177 @@
+//! Three-letter name entry shown over the game over screen when a run makes
+//! the high-score table.
+//!
+//! Up/Down change the letter, Left/Right move the cursor, A advances (and
+//! confirms on the last letter), B steps back, Start confirms. While entry is
+//! open the game over screen's continue input is swallowed.
+
+use bevy::prelude::*;
+use carapace::prelude::{CxAnchor, CxPosition, CxRenderSpace, CxText, CxTextBundle, CxTypeface};
+use carcinisation_input::GBInput;
+use leafwing_input_manager::prelude::ActionState;
+
+use super::components::{HIGH_SCORES_STORAGE, HighScores, NAME_LEN, NameEntry};
+use crate::{
+    assets::CxAssets,
+    game::storage,
+    globals::{SCREEN_RESOLUTION_H, load_inverted_typeface, mark_for_despawn_by_query},
+    layer::{Layer, MenuLayer},
+    stage::{StageProgressState, ui::game_over_screen::input::GameOverScreenInput},
+};
+
+const LETTERS_Y: i32 = 38;
+const CURSOR_Y: i32 = 30;
+const LETTER_SPACING: i32 = 8;
+
+#[derive(Component)]
+/// Root of the name entry overlay.
+pub struct NameEntryScreen;
+
+#[derive(Component)]
+/// Text showing the letter at this index.
+pub struct NameEntryLetter(pub usize);
+
+#[derive(Component)]
+/// Marker under the letter being edited.
+pub struct NameEntryCursor;
+
+fn letter_x(index: usize) -> i32 {
+    SCREEN_RESOLUTION_H.x + (index as i32 - (NAME_LEN as i32 - 1) / 2) * LETTER_SPACING
+}
+
+fn text_bundle(value: String, x: i32, y: i32, typeface: Handle<CxTypeface>) -> CxTextBundle<Layer> {
+    CxTextBundle::<Layer> {
+        anchor: CxAnchor::BottomCenter,
+        canvas: CxRenderSpace::Camera,
+        layer: Layer::Menu(MenuLayer::Foreground),
+        position: CxPosition::from(IVec2::new(x, y)),
+        text: CxText {
+            value,
+            typeface,
+            ..Default::default()
+        },
+        ..default()
+    }
+}
+
+/// @system Spawns the letter and cursor texts when name entry opens.
+pub fn spawn_name_entry_screen(
+    mut commands: Commands,
+    assets_typeface: CxAssets<CxTypeface>,
+    entry: Res<NameEntry>,
+) {
+    let typeface = load_inverted_typeface(&assets_typeface);
+
+    commands
+        .spawn((
+            NameEntryScreen,
+            Name::new("NameEntry Screen"),
+            Visibility::Visible,
+        ))
+        .with_children(|parent| {
+            for (index, &letter) in entry.letters.iter().enumerate() {
+                parent.spawn((
+                    text_bundle(
+                        char::from(letter).to_string(),
+                        letter_x(index),
+                        LETTERS_Y,
+                        typeface.clone(),
+                    ),
+                    NameEntryLetter(index),
+                    Name::new(format!("NameEntryLetter{index}")),
+                ));
+            }
+            parent.spawn((
+                text_bundle(
+                    "^".to_string(),
+                    letter_x(entry.cursor),
+                    CURSOR_Y,
+                    typeface.clone(),
+                ),
+                NameEntryCursor,
+                Name::new("NameEntryCursor"),
+            ));
+        });
+}
+
+/// @system Applies GB input to the open name entry and commits it on confirm.
+pub fn update_name_entry(
+    mut commands: Commands,
+    gb_input: Res<ActionState<GBInput>>,
+    game_over_input: Option<ResMut<ActionState<GameOverScreenInput>>>,
+    mut entry: ResMut<NameEntry>,
+    mut high_scores: ResMut<HighScores>,
+    screen_query: Query<Entity, With<NameEntryScreen>>,
+) {
+    // The game over screen reads A/B/Start as "back to menu".
+    if let Some(mut game_over_input) = game_over_input {
+        game_over_input.consume(&GameOverScreenInput::BackToMenu);
+    }
+
+    let cycle = i8::from(gb_input.just_pressed(&GBInput::Up))
+        - i8::from(gb_input.just_pressed(&GBInput::Down));
+    if cycle != 0 {
+        entry.cycle_letter(cycle);
+    }
+
+    let step = i8::from(gb_input.just_pressed(&GBInput::Right))
+        - i8::from(gb_input.just_pressed(&GBInput::Left))
+        - i8::from(gb_input.just_pressed(&GBInput::B));
+    if step != 0 {
+        entry.move_cursor(step);
+    }
+
+    let confirm = gb_input.just_pressed(&GBInput::Start)
+        || (gb_input.just_pressed(&GBInput::A) && entry.cursor == NAME_LEN - 1);
+    if confirm {
+        commit_name_entry(&mut commands, &entry, &mut high_scores, &screen_query);
+    } else if gb_input.just_pressed(&GBInput::A) {
+        entry.move_cursor(1);
+    }
+}
+
+/// @system Mirrors the entry state into the letter and cursor texts.
+pub fn sync_name_entry_text(
+    entry: Res<NameEntry>,
+    mut letter_query: Query<(&NameEntryLetter, &mut CxText)>,
+    mut cursor_query: Query<&mut CxPosition, With<NameEntryCursor>>,
+) {
+    if !entry.is_changed() {
+        return;
+    }
+    for (letter, mut text) in &mut letter_query {
+        text.value = char::from(entry.letters[letter.0]).to_string();
+    }
+    for mut position in &mut cursor_query {
+        *position = CxPosition::from(IVec2::new(letter_x(entry.cursor), CURSOR_Y));
+    }
+}
+
+/// @system Saves the entry as typed if the game over screen goes away first
+/// (e.g. a debug jump to the main menu).
+pub fn close_name_entry_outside_game_over(
+    mut commands: Commands,
+    stage_state: Option<Res<State<StageProgressState>>>,
+    entry: Res<NameEntry>,
+    mut high_scores: ResMut<HighScores>,
+    screen_query: Query<Entity, With<NameEntryScreen>>,
+) {
+    // The game over transition lands the frame after the entry opens.
+    let left_game_over = !entry.is_added()
+        && stage_state.is_some_and(|s| s.is_changed() && *s.get() != StageProgressState::GameOver);
+    if left_game_over {
+        commit_name_entry(&mut commands, &entry, &mut high_scores, &screen_query);
+    }
+}
+
+fn commit_name_entry(
+    commands: &mut Commands,
+    entry: &NameEntry,
+    high_scores: &mut HighScores,
+    screen_query: &Query<Entity, With<NameEntryScreen>>,
+) {
+    high_scores.insert(&entry.campaign, entry.name(), entry.score);
+    storage::save(HIGH_SCORES_STORAGE, high_scores);
+    commands.remove_resource::<NameEntry>();
+    mark_for_despawn_by_query(commands, screen_query);
+}
@@ -2,21 +2,23 @@
 
 use bevy::prelude::*;
 
-use super::components::HighScores;
-use crate::game::messages::GameOverEvent;
+use super::components::{DEFAULT_CAMPAIGN, HighScores, NameEntry};
+use crate::game::{messages::GameOverEvent, resources::GameData};
 
-/// @system Inserts the final run score into the high-score list.
+/// @system Opens name entry when the final run score makes the campaign's table.
 pub fn on_game_over_update_high_scores(
+    mut commands: Commands,
     mut reader: MessageReader<GameOverEvent>,
-    mut high_scores: ResMut<HighScores>,
+    high_scores: Res<HighScores>,
+    game_data: Option<Res<GameData>>,
 ) {
+    let campaign = game_data
+        .as_deref()
+        .map_or(DEFAULT_CAMPAIGN, |data| data.name.as_str());
     for game_over in reader.read() {
-        high_scores
-            .scores
-            .push(("Player".to_string(), game_over.score));
-        high_scores.scores.sort();
-        high_scores.scores.reverse();
-        high_scores.scores.truncate(5);
+        if high_scores.qualifies(campaign, game_over.score) {
+            commands.insert_resource(NameEntry::new(campaign.to_string(), game_over.score));
+        }
     }
 }
 
@@ -24,8 +26,11 @@ pub fn on_game_over_update_high_scores(
 pub fn debug_high_scores_updated(high_scores: Res<HighScores>) {
     if high_scores.is_changed() {
         info!("High scores updated!");
-        for (i, (name, score)) in high_scores.scores.iter().enumerate() {
-            println!("{}. {} - {}", i + 1, name, score);
+        for (campaign, table) in &high_scores.tables {
+            println!("[{campaign}]");
+            for (i, entry) in table.iter().enumerate() {
+                println!("{}. {} - {}", i + 1, entry.name, entry.score);
+            }
         }
     }
 }
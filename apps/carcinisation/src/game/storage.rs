@@ -0,0 +1,115 @@
+//! Per-user persistent storage: RON files in the user data dir on native,
+//! browser local storage on wasm.
+//!
+//! Failures are logged and otherwise ignored — missing or corrupt user data
+//! must never keep the game from starting.
+
+use bevy::prelude::*;
+use serde::{Serialize, de::DeserializeOwned};
+
+/// Read and parse `name` (e.g. `"high_scores"`), or `None` if it is missing
+/// or unreadable.
+#[must_use]
+pub fn load<T: DeserializeOwned>(name: &str) -> Option<T> {
+    let text = backend::read(name)?;
+    ron::from_str(&text)
+        .inspect_err(|e| warn!("Ignoring unreadable {name}: {e}"))
+        .ok()
+}
+
+/// Serialize and write `value` under `name`.
+pub fn save<T: Serialize>(name: &str, value: &T) {
+    match ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default()) {
+        Ok(text) => backend::write(name, &text),
+        Err(e) => warn!("Failed to serialize {name}: {e}"),
+    }
+}
+
+/// Delete `name` if it exists.
+pub fn remove(name: &str) {
+    backend::remove(name);
+}
+
+/// Whether `name` has been saved.
+#[must_use]
+pub fn exists(name: &str) -> bool {
+    backend::read(name).is_some()
+}
+
+#[cfg(not(target_family = "wasm"))]
+mod backend {
+    use std::path::PathBuf;
+
+    use bevy::prelude::*;
+
+    /// Per-user data directory, following each platform's convention.
+    fn data_dir() -> Option<PathBuf> {
+        let env_dir = |key: &str| std::env::var_os(key).filter(|v| !v.is_empty());
+        let base = if cfg!(target_os = "windows") {
+            PathBuf::from(env_dir("APPDATA")?)
+        } else if cfg!(target_os = "macos") {
+            PathBuf::from(env_dir("HOME")?).join("Library/Application Support")
+        } else {
+            env_dir("XDG_DATA_HOME")
+                .map(PathBuf::from)
+                .or_else(|| Some(PathBuf::from(env_dir("HOME")?).join(".local/share")))?
+        };
+        Some(base.join("carcinisation"))
+    }
+
+    fn file_name(name: &str) -> String {
+        format!("{name}.ron")
+    }
+
+    pub fn read(name: &str) -> Option<String> {
+        std::fs::read_to_string(data_dir()?.join(file_name(name))).ok()
+    }
+
+    pub fn write(name: &str, text: &str) {
+        let Some(dir) = data_dir() else {
+            warn!("No user data dir; {name} not saved");
+            return;
+        };
+        let result = std::fs::create_dir_all(&dir)
+            .and_then(|()| std::fs::write(dir.join(file_name(name)), text));
+        if let Err(e) = result {
+            warn!("Failed to save {name} to {}: {e}", dir.display());
+        }
+    }
+
+    pub fn remove(name: &str) {
+        if let Some(dir) = data_dir() {
+            let _ = std::fs::remove_file(dir.join(file_name(name)));
+        }
+    }
+}
+
+#[cfg(target_family = "wasm")]
+mod backend {
+    use bevy::prelude::*;
+
+    fn storage() -> Option<web_sys::Storage> {
+        web_sys::window()?.local_storage().ok()?
+    }
+
+    fn key(name: &str) -> String {
+        format!("carcinisation.{name}")
+    }
+
+    pub fn read(name: &str) -> Option<String> {
+        storage()?.get_item(&key(name)).ok()?
+    }
+
+    pub fn write(name: &str, text: &str) {
+        let saved = storage().is_some_and(|s| s.set_item(&key(name), text).is_ok());
+        if !saved {
+            warn!("Local storage unavailable; {name} not saved");
+        }
+    }
+
+    pub fn remove(name: &str) {
+        if let Some(storage) = storage() {
+            let _ = storage.remove_item(&key(name));
+        }
+    }
+}
@@ -6,11 +6,13 @@ use std::sync::Arc;
 use crate::debug::plugin::debug_print_startup;
 use crate::{
     cutscene::{
+        StageCutscene,
         data::CutsceneData,
         messages::{CutsceneShutdownEvent, CutsceneStartupEvent},
     },
     game::{
         GamePlugin,
+        campaign::{CampaignData, CampaignStats},
         components::steps::{
             CinematicAssetGameStep, CreditsGameStep, CutsceneGameStep, StageAssetGameStep,
             StageGameStep, TransitionGameStep,
@@ -18,12 +20,14 @@ use crate::{
         data::{GameStep, STARTING_LIVES},
         messages::{GameOverEvent, GameStartupEvent},
         resources::{CutsceneAssetHandle, GameData, GameProgress, Lives, StageAssetHandle},
+        save::{ContinueFromCheckpoint, take_checkpoint_resume},
+        score::components::Score,
     },
-    progression::game::GAME_DATA,
     stage::{
         StageHooks,
         data::StageData,
-        messages::{StageClearedEvent, StageStartupEvent},
+        messages::{StageCinematicFinishedEvent, StageClearedEvent, StageStartupEvent},
+        resources::StageProgress,
     },
 };
 use activable::activate;
@@ -38,6 +42,7 @@ pub fn on_game_startup(
     existing_progress: Option<Res<GameProgress>>,
     existing_game_data: Option<Res<GameData>>,
     existing_lives: Option<Res<Lives>>,
+    campaign: Res<CampaignData>,
 ) {
     #[cfg(debug_assertions)]
     debug_print_startup(DEBUG_MODULE);
@@ -47,8 +52,9 @@ pub fn on_game_startup(
         commands.insert_resource::<GameProgress>(GameProgress { index: 0 });
     }
     if existing_game_data.is_none() {
-        commands.insert_resource::<GameData>(GAME_DATA.clone());
+        commands.insert_resource(GameData::from(&*campaign));
     }
+    commands.insert_resource(CampaignStats::default());
     if existing_lives.is_none() {
         commands.insert_resource(Lives(STARTING_LIVES));
     }
@@ -73,8 +79,12 @@ pub fn on_stage_cleared(
     mut commands: Commands,
     mut progress: ResMut<GameProgress>,
     stage_hooks: Res<StageHooks>,
+    mut stats: Option<ResMut<CampaignStats>>,
 ) {
     for _ in event_reader.read() {
+        if let Some(stats) = stats.as_deref_mut() {
+            stats.record_clear();
+        }
         progress.index += 1;
         (stage_hooks.deactivate_stage)(&mut commands);
         commands.remove_resource::<StageData>();
@@ -82,14 +92,23 @@ pub fn on_stage_cleared(
 }
 
 /// @trigger Advances progress when a cutscene finishes.
+///
+/// A stage cinematic's cutscene resumes its stage instead.
 pub fn on_cutscene_shutdown(
     event_reader: Option<MessageReader<CutsceneShutdownEvent>>,
+    mut commands: Commands,
     mut progress: ResMut<GameProgress>,
+    stage_cutscene: Option<Res<StageCutscene>>,
 ) {
     let Some(mut reader) = event_reader else {
         return;
     };
     for _ in reader.read() {
+        if stage_cutscene.is_some() {
+            commands.remove_resource::<StageCutscene>();
+            commands.trigger(StageCinematicFinishedEvent);
+            continue;
+        }
         progress.index += 1;
         // Plugin deactivation and resource cleanup are handled by
         // the cutscene plugin's own on_cutscene_shutdown observer.
@@ -97,38 +116,69 @@ pub fn on_cutscene_shutdown(
 }
 
 /// @system Reacts to game progression changes, triggering the next step.
+///
+/// Steps whose condition is not met are skipped.
+#[allow(clippy::too_many_arguments)]
 pub fn progress(
     asset_server: Res<AssetServer>,
-    game_progress: Res<GameProgress>,
+    mut game_progress: ResMut<GameProgress>,
     game_data: Res<GameData>,
     mut commands: Commands,
     // mut cutscene_startup_event_writer: MessageWriter<CutsceneStartupEvent>,
     mut stage_startup_event_writer: MessageWriter<StageStartupEvent>,
+    resume: Option<Res<ContinueFromCheckpoint>>,
+    mut stage_progress: ResMut<StageProgress>,
+    score: Option<Res<Score>>,
+    stats: Option<Res<CampaignStats>>,
 ) {
-    if (game_progress.is_added() || game_progress.is_changed())
-        && let Some(data) = game_data.steps.get(game_progress.index)
+    if !game_progress.is_changed() {
+        return;
+    }
+    let score = score.map_or(0, |score| score.value);
+    let stats = stats.as_deref().copied().unwrap_or_default();
+    let mut index = game_progress.index;
+    while game_data
+        .steps
+        .get(index)
+        .and_then(GameStep::condition)
+        .is_some_and(|condition| !condition.is_met(score, &stats))
     {
+        index += 1;
+    }
+    if index != game_progress.index {
+        game_progress.index = index;
+    }
+
+    if let Some(data) = game_data.steps.get(game_progress.index) {
         #[allow(clippy::match_same_arms)]
         match data {
             GameStep::Credits(CreditsGameStep {}) => {
                 // TODO
             }
             GameStep::Cutscene(CutsceneGameStep { data, .. }) => {
+                commands.remove_resource::<ContinueFromCheckpoint>();
                 commands.trigger(CutsceneStartupEvent { data: data.clone() });
                 // cutscene_startup_event_writer.write();
             }
             GameStep::CutsceneAsset(CinematicAssetGameStep { src, .. }) => {
+                commands.remove_resource::<ContinueFromCheckpoint>();
                 commands.insert_resource(CutsceneAssetHandle {
                     handle: asset_server.load::<CutsceneData>(src),
                 });
             }
             GameStep::Stage(StageGameStep { data }) => {
+                let from_checkpoint = take_checkpoint_resume(
+                    &mut commands,
+                    resume.as_deref(),
+                    data,
+                    &mut stage_progress,
+                );
                 stage_startup_event_writer.write(StageStartupEvent {
                     data: data.clone(),
-                    from_checkpoint: false,
+                    from_checkpoint,
                 });
             }
-            GameStep::StageAsset(StageAssetGameStep(src)) => {
+            GameStep::StageAsset(StageAssetGameStep { src, .. }) => {
                 commands.insert_resource(StageAssetHandle {
                     handle: asset_server.load::<StageData>(src),
                 });
@@ -165,15 +215,19 @@ pub fn check_stage_data_loaded(
     asset_handle: Res<StageAssetHandle>,
     data_assets: Res<Assets<StageData>>,
     mut commands: Commands,
+    resume: Option<Res<ContinueFromCheckpoint>>,
+    mut stage_progress: ResMut<StageProgress>,
 ) {
     if let Some(data) = data_assets.get(&asset_handle.handle) {
         #[cfg(debug_assertions)]
         println!("Stage data loaded: {data:?}");
         commands.remove_resource::<StageAssetHandle>();
+        let from_checkpoint =
+            take_checkpoint_resume(&mut commands, resume.as_deref(), data, &mut stage_progress);
         commands.trigger(StageStartupEvent {
             // TODO do I need Arc for this? Can it not be handled by a simple pointer reference?
             data: Arc::new(data.clone()),
-            from_checkpoint: false,
+            from_checkpoint,
         });
     } else {
         #[cfg(debug_assertions)]
@@ -22,6 +22,9 @@
 pub mod assets {
     pub use carcinisation_ors::assets::*;
 }
+pub mod audio {
+    pub use carcinisation_core::audio::*;
+}
 pub mod components {
     pub use carcinisation_core::components::*;
 }
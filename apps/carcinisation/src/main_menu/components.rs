@@ -18,6 +18,10 @@ pub struct MainMenuSelect;
 /// Entity for the main selection screen container.
 pub struct MainMenuSelectScreenEntity;
 
+#[derive(Component)]
+/// Arrow indicator that points at the currently selected main entry.
+pub struct MainMenuSelectionIndicator;
+
 #[derive(Component)]
 /// Entity for the press-start prompt screen.
 pub struct PressStartScreenEntity;
@@ -31,5 +35,16 @@ pub struct DifficultySelectScreenEntity;
 pub struct DifficultySelectionIndicator;
 
 #[derive(Component)]
-/// Marks the music player associated with the main menu.
-pub struct MainMenuMusic;
+/// Entity for the controls screen.
+pub struct ControlsScreenEntity;
+
+#[derive(Component)]
+/// Arrow indicator that points at the highlighted controls row.
+pub struct ControlsSelectionIndicator;
+
+#[derive(Clone, Copy, Component, Debug, Eq, PartialEq)]
+/// Controls screen text showing one binding of the action on `row`.
+pub struct ControlsBindingText {
+    pub row: usize,
+    pub gamepad: bool,
+}
@@ -1,10 +1,8 @@
 //! Input mapping for navigating the main menu.
 
-use bevy::prelude::*;
 use bevy::reflect::Reflect;
-use carcinisation_input::GBInput;
+use carcinisation_input::{GBActions, GBInput};
 use leafwing_input_manager::Actionlike;
-use leafwing_input_manager::{action_state::ActionState, input_map::InputMap};
 
 #[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
 /// Menu actions handled by interaction systems.
@@ -16,16 +14,13 @@ pub enum MainMenuScreenInput {
     Cancel,
 }
 
-/// @system Registers menu input bindings using the shared GB schema.
-pub fn init_input(mut commands: Commands) {
-    let ms: Vec<(MainMenuScreenInput, KeyCode)> = vec![
-        (MainMenuScreenInput::Select, GBInput::A.into()),
-        (MainMenuScreenInput::Cancel, GBInput::B.into()),
-        (MainMenuScreenInput::Down, GBInput::Down.into()),
-        (MainMenuScreenInput::Up, GBInput::Up.into()),
-        (MainMenuScreenInput::Switch, GBInput::Select.into()),
-        (MainMenuScreenInput::Select, GBInput::Start.into()),
+impl GBActions for MainMenuScreenInput {
+    const GB_ACTIONS: &'static [(Self, GBInput)] = &[
+        (Self::Select, GBInput::A),
+        (Self::Cancel, GBInput::B),
+        (Self::Down, GBInput::Down),
+        (Self::Up, GBInput::Up),
+        (Self::Switch, GBInput::Select),
+        (Self::Select, GBInput::Start),
     ];
-    commands.insert_resource(ActionState::<MainMenuScreenInput>::default());
-    commands.insert_resource(InputMap::<MainMenuScreenInput>::new(ms));
 }
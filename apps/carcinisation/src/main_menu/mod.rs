@@ -6,15 +6,21 @@ pub mod resources;
 mod systems;
 
 use self::{
-    resources::DifficultySelection,
+    resources::{DifficultySelection, MainMenuSelection},
     systems::{
+        controls::{
+            controls_screen_input, enter_controls_screen, exit_controls_screen,
+            update_controls_screen,
+        },
         interactions::{
             check_main_select_select_option_input, check_press_start_input,
-            game_difficulty_select_change, game_difficulty_select_option,
+            game_difficulty_select_change, game_difficulty_select_option, main_select_change,
         },
         layout::{
-            enter_game_difficulty_screen, enter_press_start_screen, exit_game_difficulty_screen,
-            exit_press_start_screen, spawn_main_menu, update_difficulty_selection_indicator,
+            enter_game_difficulty_screen, enter_main_select_screen, enter_press_start_screen,
+            exit_game_difficulty_screen, exit_main_select_screen, exit_press_start_screen,
+            spawn_main_menu, update_difficulty_selection_indicator,
+            update_main_selection_indicator,
         },
         setup::{
             cleanup_main_menu_music, on_main_menu_shutdown, on_main_menu_startup,
@@ -33,6 +39,7 @@ impl Plugin for MainMenuPlugin {
     fn build(&self, app: &mut App) {
         app.insert_state(MainMenuScreen::default())
             .init_resource::<DifficultySelection>()
+            .init_resource::<MainMenuSelection>()
             .on_active::<Self, _>((spawn_main_menu, on_main_menu_startup, spawn_main_menu_music))
             .on_inactive::<Self, _>((on_main_menu_shutdown, cleanup_main_menu_music))
             .add_systems(
@@ -40,6 +47,16 @@ impl Plugin for MainMenuPlugin {
                 enter_press_start_screen,
             )
             .add_systems(OnExit(MainMenuScreen::PressStart), exit_press_start_screen)
+            .add_systems(
+                OnEnter(MainMenuScreen::MainMenuSelect),
+                enter_main_select_screen,
+            )
+            .add_systems(
+                OnExit(MainMenuScreen::MainMenuSelect),
+                exit_main_select_screen,
+            )
+            .add_systems(OnEnter(MainMenuScreen::Controls), enter_controls_screen)
+            .add_systems(OnExit(MainMenuScreen::Controls), exit_controls_screen)
             .add_systems(
                 OnEnter(MainMenuScreen::DifficultySelect),
                 enter_game_difficulty_screen,
@@ -52,8 +69,16 @@ impl Plugin for MainMenuPlugin {
                 // Handle input according to the active menu screen.
                 (
                     (check_press_start_input).run_if(in_state(MainMenuScreen::PressStart)),
-                    (check_main_select_select_option_input)
+                    (
+                        main_select_change,
+                        check_main_select_select_option_input,
+                        update_main_selection_indicator,
+                    )
+                        .chain()
                         .run_if(in_state(MainMenuScreen::MainMenuSelect)),
+                    (controls_screen_input, update_controls_screen)
+                        .chain()
+                        .run_if(in_state(MainMenuScreen::Controls)),
                     (
                         game_difficulty_select_change,
                         game_difficulty_select_option,
@@ -72,6 +97,7 @@ pub enum MainMenuScreen {
     Disabled,
     PressStart,
     MainMenuSelect,
+    Controls,
     // TODO can this be nested under MainSelect?
     DifficultySelect,
 }
@@ -1,8 +1,46 @@
 //! Main menu resources (difficulty selection etc.).
 
-use crate::game::resources::Difficulty;
+use crate::game::{resources::Difficulty, save::SaveData};
 use bevy::prelude::*;
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
 
 #[derive(Resource, Debug, Clone, Eq, PartialEq, Default)]
 /// Stores the difficulty chosen from the menu.
 pub struct DifficultySelection(pub Difficulty);
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default, EnumIter)]
+/// Entries of the main selection screen.
+pub enum MainMenuOption {
+    Continue,
+    #[default]
+    NewGame,
+    Controls,
+}
+
+impl MainMenuOption {
+    /// Entries shown on the main selection screen; "Continue" only when a
+    /// save exists.
+    #[must_use]
+    pub fn available(has_save: bool) -> Vec<Self> {
+        Self::iter()
+            .filter(|option| has_save || *option != Self::Continue)
+            .collect()
+    }
+}
+
+#[derive(Resource, Debug, Clone, Eq, PartialEq, Default)]
+/// Stores the highlighted main selection entry.
+pub struct MainMenuSelection(pub MainMenuOption);
+
+#[derive(Resource, Debug, Clone)]
+/// Save found when leaving the press-start screen, restored by "Continue".
+pub struct SavedRun(pub SaveData);
+
+#[derive(Resource, Debug, Clone, Copy, Eq, PartialEq, Default)]
+/// Highlighted row of the controls screen, and whether it is waiting for the
+/// next key or button press to rebind it.
+pub struct ControlsSelection {
+    pub index: usize,
+    pub capturing: bool,
+}
@@ -1,5 +1,6 @@
 //! Systems handling menu input, layout, and setup/teardown.
 
+pub mod controls;
 pub mod interactions;
 pub mod layout;
 pub mod setup;
@@ -0,0 +1,293 @@
+//! Controls screen: lists the key and gamepad button bound to each
+//! `GBInput` action and rebinds the highlighted one on the next press.
+
+use crate::{
+    assets::CxAssets,
+    globals::{SCREEN_RESOLUTION, load_inverted_typeface, mark_for_despawn_by_query},
+    layer::{Layer, MenuLayer},
+    main_menu::{
+        MainMenuScreen,
+        components::{
+            ControlsBindingText, ControlsScreenEntity, ControlsSelectionIndicator, MainMenuEntity,
+        },
+        resources::ControlsSelection,
+    },
+};
+use bevy::prelude::*;
+use carapace::prelude::{CxAnchor, CxPosition, CxRenderSpace, CxText, CxTextBundle, CxTypeface};
+use carcinisation_input::{GBInput, InputBindings};
+use leafwing_input_manager::prelude::ActionState;
+
+const ROW_SPACING: i32 = 12;
+const FIRST_ROW_Y: i32 = SCREEN_RESOLUTION.y as i32 - 26;
+const LABEL_X: i32 = 14;
+const KEY_X: i32 = 54;
+const BUTTON_X: i32 = 108;
+const CAPTURE_PLACEHOLDER: &str = "...";
+
+/// Row after the actions that restores the default bindings.
+const RESET_ROW: usize = GBInput::ALL.len();
+
+fn row_y(row: usize) -> i32 {
+    FIRST_ROW_Y - row as i32 * ROW_SPACING
+}
+
+fn action_label(action: GBInput) -> &'static str {
+    match action {
+        GBInput::A => "A",
+        GBInput::B => "B",
+        GBInput::Up => "Up",
+        GBInput::Down => "Down",
+        GBInput::Left => "Left",
+        GBInput::Right => "Right",
+        GBInput::Start => "Start",
+        GBInput::Select => "Select",
+    }
+}
+
+/// Short display name of a key.
+#[must_use]
+pub fn key_label(key: KeyCode) -> String {
+    let name = format!("{key:?}");
+    ["Key", "Digit", "Arrow"]
+        .iter()
+        .find_map(|prefix| name.strip_prefix(prefix))
+        .filter(|rest| !rest.is_empty())
+        .map_or_else(|| name.clone(), str::to_string)
+}
+
+/// Short display name of a gamepad button.
+#[must_use]
+pub fn button_label(button: GamepadButton) -> String {
+    match button {
+        GamepadButton::LeftTrigger => "L1".to_string(),
+        GamepadButton::LeftTrigger2 => "L2".to_string(),
+        GamepadButton::RightTrigger => "R1".to_string(),
+        GamepadButton::RightTrigger2 => "R2".to_string(),
+        GamepadButton::LeftThumb => "L3".to_string(),
+        GamepadButton::RightThumb => "R3".to_string(),
+        other => format!("{other:?}").replace("DPad", "D"),
+    }
+}
+
+fn binding_label(
+    bindings: &InputBindings,
+    selection: ControlsSelection,
+    cell: ControlsBindingText,
+) -> String {
+    if selection.capturing && selection.index == cell.row {
+        return CAPTURE_PLACEHOLDER.to_string();
+    }
+    let action = GBInput::ALL[cell.row];
+    if cell.gamepad {
+        button_label(bindings.button(action))
+    } else {
+        key_label(bindings.key(action))
+    }
+}
+
+fn spawn_text(
+    commands: &mut Commands,
+    typeface: &Handle<CxTypeface>,
+    position: IVec2,
+    value: String,
+    extra: impl Bundle,
+) {
+    commands.spawn((
+        MainMenuEntity,
+        ControlsScreenEntity,
+        CxTextBundle::<Layer> {
+            position: CxPosition::from(position),
+            anchor: CxAnchor::CenterLeft,
+            canvas: CxRenderSpace::Camera,
+            layer: Layer::Menu(MenuLayer::Foreground),
+            text: CxText {
+                value,
+                typeface: typeface.clone(),
+                ..Default::default()
+            },
+            ..default()
+        },
+        extra,
+    ));
+}
+
+/// @system Builds the controls list when that screen activates.
+pub fn enter_controls_screen(
+    mut commands: Commands,
+    assets_typeface: CxAssets<CxTypeface>,
+    bindings: Res<InputBindings>,
+    selection: Res<ControlsSelection>,
+) {
+    let typeface = load_inverted_typeface(&assets_typeface);
+
+    spawn_text(
+        &mut commands,
+        &typeface,
+        IVec2::new(LABEL_X, row_y(0) + ROW_SPACING),
+        "Controls".to_string(),
+        Name::new("Text<Controls>"),
+    );
+
+    for (row, action) in GBInput::ALL.into_iter().enumerate() {
+        let y = row_y(row);
+        let label = action_label(action);
+        spawn_text(
+            &mut commands,
+            &typeface,
+            IVec2::new(LABEL_X, y),
+            label.to_string(),
+            Name::new(format!("Text<{label}>")),
+        );
+        for (x, gamepad) in [(KEY_X, false), (BUTTON_X, true)] {
+            let cell = ControlsBindingText { row, gamepad };
+            spawn_text(
+                &mut commands,
+                &typeface,
+                IVec2::new(x, y),
+                binding_label(&bindings, *selection, cell),
+                (cell, Name::new(format!("Text<{label}Binding>"))),
+            );
+        }
+    }
+
+    spawn_text(
+        &mut commands,
+        &typeface,
+        IVec2::new(LABEL_X, row_y(RESET_ROW)),
+        "Defaults".to_string(),
+        Name::new("Text<Defaults>"),
+    );
+
+    commands.spawn((
+        MainMenuEntity,
+        ControlsScreenEntity,
+        ControlsSelectionIndicator,
+        CxTextBundle::<Layer> {
+            position: CxPosition::from(IVec2::new(LABEL_X - 2, row_y(selection.index))),
+            anchor: CxAnchor::CenterRight,
+            canvas: CxRenderSpace::Camera,
+            layer: Layer::Menu(MenuLayer::Foreground),
+            text: CxText {
+                value: ">".to_string(),
+                typeface,
+                ..Default::default()
+            },
+            ..default()
+        },
+        Name::new("ControlsSelectionIndicator"),
+    ));
+}
+
+/// @system Despawns controls screen entities.
+pub fn exit_controls_screen(
+    mut commands: Commands,
+    controls_query: Query<Entity, With<ControlsScreenEntity>>,
+) {
+    mark_for_despawn_by_query(&mut commands, &controls_query);
+    commands.remove_resource::<ControlsSelection>();
+}
+
+/// Physical input pressed while capturing a binding.
+enum CapturedInput {
+    Key(KeyCode),
+    Button(GamepadButton),
+}
+
+fn captured_input(
+    keys: &ButtonInput<KeyCode>,
+    gamepads: &Query<&Gamepad>,
+) -> Option<CapturedInput> {
+    keys.get_just_pressed()
+        .next()
+        .map(|key| CapturedInput::Key(*key))
+        .or_else(|| {
+            gamepads
+                .iter()
+                .find_map(|gamepad| gamepad.get_just_pressed().next())
+                .map(|button| CapturedInput::Button(*button))
+        })
+}
+
+/// @system While capturing, rebinds the highlighted action to the next key or
+/// gamepad button pressed; one already bound to another action is swapped
+/// with it. Otherwise moves between rows, starts capturing on confirm, and
+/// returns to the main selection on B.
+///
+/// Capturing and navigation share one system so the press that starts or
+/// ends a capture is not also read by the other.
+pub fn controls_screen_input(
+    mut selection: ResMut<ControlsSelection>,
+    mut bindings: ResMut<InputBindings>,
+    mut next_screen: ResMut<NextState<MainMenuScreen>>,
+    gb_input: Res<ActionState<GBInput>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+) {
+    if selection.capturing {
+        let action = GBInput::ALL[selection.index];
+        match captured_input(&keys, &gamepads) {
+            Some(CapturedInput::Key(key)) => bindings.bind_key(action, key),
+            Some(CapturedInput::Button(button)) => bindings.bind_button(action, button),
+            None => return,
+        }
+        selection.capturing = false;
+        return;
+    }
+    if gb_input.just_pressed(&GBInput::B) {
+        next_screen.set(MainMenuScreen::MainMenuSelect);
+        return;
+    }
+    if gb_input.just_pressed(&GBInput::Start) || gb_input.just_pressed(&GBInput::A) {
+        if selection.index == RESET_ROW {
+            *bindings = InputBindings::default();
+        } else {
+            selection.capturing = true;
+        }
+        return;
+    }
+    let input = i8::from(gb_input.just_pressed(&GBInput::Down))
+        - i8::from(gb_input.just_pressed(&GBInput::Up));
+    if let Some(index) = selection
+        .index
+        .checked_add_signed(input.into())
+        .filter(|index| *index <= RESET_ROW && *index != selection.index)
+    {
+        selection.index = index;
+    }
+}
+
+/// @system Refreshes binding texts and the row indicator.
+pub fn update_controls_screen(
+    bindings: Res<InputBindings>,
+    selection: Res<ControlsSelection>,
+    mut text_query: Query<(&ControlsBindingText, &mut CxText)>,
+    mut indicator_query: Query<&mut CxPosition, With<ControlsSelectionIndicator>>,
+) {
+    if !bindings.is_changed() && !selection.is_changed() {
+        return;
+    }
+    for (cell, mut text) in &mut text_query {
+        let value = binding_label(&bindings, *selection, *cell);
+        if text.value != value {
+            text.value = value;
+        }
+    }
+    if let Ok(mut position) = indicator_query.single_mut() {
+        *position = CxPosition::from(IVec2::new(LABEL_X - 2, row_y(selection.index)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels_are_short() {
+        assert_eq!(key_label(KeyCode::KeyX), "X");
+        assert_eq!(key_label(KeyCode::ArrowUp), "Up");
+        assert_eq!(key_label(KeyCode::Enter), "Enter");
+        assert_eq!(button_label(GamepadButton::DPadLeft), "DLeft");
+        assert_eq!(button_label(GamepadButton::LeftTrigger2), "L2");
+    }
+}
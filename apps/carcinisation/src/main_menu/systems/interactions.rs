@@ -3,8 +3,18 @@
 #[cfg(feature = "gallery")]
 use crate::gallery::{GalleryPlugin, messages::GalleryStartupEvent};
 use crate::{
-    game::{messages::GameStartupEvent, resources::Difficulty},
-    main_menu::{MainMenuPlugin, MainMenuScreen, resources::DifficultySelection},
+    game::{
+        campaign::CampaignData,
+        messages::GameStartupEvent,
+        resources::{Difficulty, GameData},
+        save::{load_save, restore_save},
+    },
+    main_menu::{
+        MainMenuPlugin, MainMenuScreen,
+        resources::{
+            ControlsSelection, DifficultySelection, MainMenuOption, MainMenuSelection, SavedRun,
+        },
+    },
     resources::DifficultySelected,
 };
 #[cfg(feature = "gallery")]
@@ -14,12 +24,14 @@ use bevy::prelude::*;
 use carcinisation_input::GBInput;
 use leafwing_input_manager::prelude::ActionState;
 
-/// @system Transitions from press-start screen to difficulty selection, or gallery via Select.
+/// @system Transitions from press-start screen to the main selection, or gallery
+/// via Select.
 #[allow(unused_mut, unused_variables)]
 pub fn check_press_start_input(
     mut commands: Commands,
     mut next_screen: ResMut<NextState<MainMenuScreen>>,
     gb_input: Res<ActionState<GBInput>>,
+    campaign: Res<CampaignData>,
 ) {
     #[cfg(feature = "gallery")]
     if gb_input.just_pressed(&GBInput::Select) {
@@ -33,17 +45,75 @@ pub fn check_press_start_input(
         || gb_input.just_pressed(&GBInput::A)
         || gb_input.just_pressed(&GBInput::B)
     {
-        next_screen.set(MainMenuScreen::DifficultySelect);
+        let save = load_save(&GameData::from(&*campaign));
+        let selection = if save.is_some() {
+            MainMenuOption::Continue
+        } else {
+            MainMenuOption::NewGame
+        };
+        match save {
+            Some(save) => commands.insert_resource(SavedRun(save)),
+            None => commands.remove_resource::<SavedRun>(),
+        }
+        commands.insert_resource(MainMenuSelection(selection));
+        next_screen.set(MainMenuScreen::MainMenuSelect);
     }
 }
 
-/// @system Confirms selection from the main menu screen, transitions to difficulty select.
+/// @system Moves between the main selection entries based on directional input.
+pub fn main_select_change(
+    mut selection: ResMut<MainMenuSelection>,
+    saved_run: Option<Res<SavedRun>>,
+    gb_input: Res<ActionState<GBInput>>,
+) {
+    let input = i8::from(gb_input.just_pressed(&GBInput::Down))
+        - i8::from(gb_input.just_pressed(&GBInput::Up));
+    let options = MainMenuOption::available(saved_run.is_some());
+    let Some(index) = options.iter().position(|o| *o == selection.0) else {
+        return;
+    };
+    if let Some(option) = index
+        .checked_add_signed(input.into())
+        .and_then(|i| options.get(i))
+        && *option != selection.0
+    {
+        selection.0 = *option;
+    }
+}
+
+/// @system Confirms selection from the main menu screen: "Continue" restores the
+/// save and starts the game, "New Game" transitions to difficulty select and
+/// "Controls" opens the controls screen.
 pub fn check_main_select_select_option_input(
+    mut commands: Commands,
     mut next_screen: ResMut<NextState<MainMenuScreen>>,
+    selection: Res<MainMenuSelection>,
+    saved_run: Option<Res<SavedRun>>,
     gb_input: Res<ActionState<GBInput>>,
+    campaign: Res<CampaignData>,
 ) {
-    if gb_input.just_pressed(&GBInput::Start) || gb_input.just_pressed(&GBInput::A) {
-        next_screen.set(MainMenuScreen::DifficultySelect);
+    if !gb_input.just_pressed(&GBInput::Start) && !gb_input.just_pressed(&GBInput::A) {
+        return;
+    }
+    match (selection.0, saved_run) {
+        (MainMenuOption::Continue, Some(saved_run)) => {
+            restore_save(
+                &mut commands,
+                &GameData::from(&*campaign),
+                saved_run.0.clone(),
+            );
+            commands.remove_resource::<SavedRun>();
+            commands.trigger(GameStartupEvent);
+            deactivate::<MainMenuPlugin>(&mut commands);
+        }
+        (MainMenuOption::Controls, _) => {
+            commands.insert_resource(ControlsSelection::default());
+            next_screen.set(MainMenuScreen::Controls);
+        }
+        _ => {
+            commands.remove_resource::<SavedRun>();
+            next_screen.set(MainMenuScreen::DifficultySelect);
+        }
     }
 }
 
@@ -1,6 +1,6 @@
 use super::super::components::{
     DifficultySelectScreenEntity, DifficultySelectionIndicator, MainMenu, MainMenuEntity,
-    PressStartScreenEntity,
+    MainMenuSelectScreenEntity, MainMenuSelectionIndicator, PressStartScreenEntity,
 };
 use crate::{
     assets::CxAssets,
@@ -10,7 +10,10 @@ use crate::{
         SCREEN_RESOLUTION_H, load_inverted_typeface, mark_for_despawn_by_query,
     },
     layer::{Layer, MenuLayer, OrsLayer},
-    main_menu::{MainMenuScreen, resources::DifficultySelection},
+    main_menu::{
+        MainMenuScreen,
+        resources::{DifficultySelection, MainMenuOption, MainMenuSelection, SavedRun},
+    },
 };
 use assert_assets_path::assert_assets_path;
 use bevy::prelude::*;
@@ -74,6 +77,93 @@ pub fn exit_press_start_screen(
     mark_for_despawn_by_query(&mut commands, &press_start_query);
 }
 
+/// @system Builds the main selection list when that screen activates.
+pub fn enter_main_select_screen(
+    mut commands: Commands,
+    assets_typeface: CxAssets<CxTypeface>,
+    selection: Res<MainMenuSelection>,
+    saved_run: Option<Res<SavedRun>>,
+) {
+    let typeface = load_inverted_typeface(&assets_typeface);
+    let options = MainMenuOption::available(saved_run.is_some());
+    let total = options.len();
+
+    for (index, option) in options.iter().enumerate() {
+        let name = match option {
+            MainMenuOption::Continue => "Continue",
+            MainMenuOption::NewGame => "New Game",
+            MainMenuOption::Controls => "Controls",
+        };
+        commands.spawn((
+            MainMenuEntity,
+            MainMenuSelectScreenEntity,
+            CxTextBundle::<Layer> {
+                position: CxPosition::from(IVec2::new(
+                    SCREEN_RESOLUTION_H.x,
+                    option_y(index, total),
+                )),
+                anchor: CxAnchor::Center,
+                canvas: CxRenderSpace::Camera,
+                layer: Layer::Menu(MenuLayer::Foreground),
+                text: CxText {
+                    value: name.to_string(),
+                    typeface: typeface.clone(),
+                    ..Default::default()
+                },
+                ..default()
+            },
+            Name::new(format!("Text<{name}>")),
+        ));
+    }
+
+    let selected = options.iter().position(|o| *o == selection.0).unwrap_or(0);
+    commands.spawn((
+        MainMenuEntity,
+        MainMenuSelectScreenEntity,
+        MainMenuSelectionIndicator,
+        CxTextBundle::<Layer> {
+            position: CxPosition::from(arrow_position(selected, total)),
+            anchor: CxAnchor::CenterRight,
+            canvas: CxRenderSpace::Camera,
+            layer: Layer::Menu(MenuLayer::Foreground),
+            text: CxText {
+                value: ">".to_string(),
+                typeface,
+                ..Default::default()
+            },
+            ..default()
+        },
+        Name::new("MainMenuSelectionIndicator"),
+    ));
+}
+
+/// @system Despawns main selection screen entities.
+pub fn exit_main_select_screen(
+    mut commands: Commands,
+    main_select_query: Query<Entity, With<MainMenuSelectScreenEntity>>,
+) {
+    mark_for_despawn_by_query(&mut commands, &main_select_query);
+}
+
+/// @system Moves the arrow indicator when the selected main entry changes.
+pub fn update_main_selection_indicator(
+    selection: Res<MainMenuSelection>,
+    saved_run: Option<Res<SavedRun>>,
+    mut indicator_query: Query<&mut CxPosition, With<MainMenuSelectionIndicator>>,
+) {
+    if !selection.is_changed() {
+        return;
+    }
+
+    let options = MainMenuOption::available(saved_run.is_some());
+    if let (Ok(mut position), Some(index)) = (
+        indicator_query.single_mut(),
+        options.iter().position(|o| *o == selection.0),
+    ) {
+        *position = CxPosition::from(arrow_position(index, options.len()));
+    }
+}
+
 /// @system Builds the difficulty selection UI when that screen activates.
 pub fn enter_game_difficulty_screen(
     mut commands: Commands,
@@ -178,15 +268,24 @@ fn difficulty_index(target: Difficulty) -> Option<usize> {
 }
 
 fn difficulty_option_y(index: usize) -> i32 {
-    let total = Difficulty::iter().len() as f32;
+    option_y(index, Difficulty::iter().len())
+}
+
+fn difficulty_arrow_position(index: usize) -> IVec2 {
+    arrow_position(index, Difficulty::iter().len())
+}
+
+/// Vertical position of entry `index` in a list of `total` centred entries.
+fn option_y(index: usize, total: usize) -> i32 {
+    let total = total as f32;
     let spacing = FONT_SIZE as f32 + 8.0;
     let vertical_origin = SCREEN_RESOLUTION_F32.y / 2.;
     let offset = (total - 1.0).mul_add(0.5, -(index as f32));
     (vertical_origin + offset * spacing).round() as i32
 }
 
-fn difficulty_arrow_position(index: usize) -> IVec2 {
-    let option_y = difficulty_option_y(index);
+fn arrow_position(index: usize, total: usize) -> IVec2 {
+    let option_y = option_y(index, total);
     let arrow_x = SCREEN_RESOLUTION_H.x - 30;
     IVec2::new(arrow_x, option_y)
 }
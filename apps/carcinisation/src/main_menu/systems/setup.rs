@@ -3,16 +3,15 @@
 #[cfg(debug_assertions)]
 use crate::debug::plugin::{debug_print_shutdown, debug_print_startup};
 use crate::{
-    components::VolumeSettings,
+    audio::{PlayMusic, StopMusic},
     globals::mark_for_despawn_by_query,
     main_menu::{
         MainMenuScreen,
-        components::{MainMenu, MainMenuEntity, MainMenuMusic},
+        components::{MainMenu, MainMenuEntity},
     },
-    systems::spawn::make_music_bundle,
 };
 use assert_assets_path::assert_assets_path;
-use bevy::{audio::PlaybackMode, prelude::*};
+use bevy::prelude::*;
 use carapace::prelude::WorldPos;
 use carcinisation_base::game::CameraPos;
 
@@ -55,26 +54,14 @@ pub fn on_main_menu_shutdown(
     mark_for_despawn_by_query(&mut commands, &main_menu_entity_query);
 }
 
-/// @system Spawns the looping main-menu music track.
-pub fn spawn_main_menu_music(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    volume_settings: Res<VolumeSettings>,
-) {
-    let (player, settings, system_bundle, music_tag) = make_music_bundle(
-        &asset_server,
-        &volume_settings,
-        assert_assets_path!("audio/music/intro.ogg").to_string(),
-        PlaybackMode::Loop,
-    );
-
-    commands.spawn((player, settings, system_bundle, music_tag, MainMenuMusic));
+/// @system Starts the looping main-menu music track.
+pub fn spawn_main_menu_music(mut play_music_writer: MessageWriter<PlayMusic>) {
+    play_music_writer.write(PlayMusic::looped(assert_assets_path!(
+        "audio/music/intro.ogg"
+    )));
 }
 
-/// @system Despawns the main-menu music entity.
-pub fn cleanup_main_menu_music(
-    mut commands: Commands,
-    music_query: Query<Entity, With<MainMenuMusic>>,
-) {
-    mark_for_despawn_by_query(&mut commands, &music_query);
+/// @system Fades out the main-menu music.
+pub fn cleanup_main_menu_music(mut stop_music_writer: MessageWriter<StopMusic>) {
+    stop_music_writer.write(StopMusic);
 }
@@ -1,4 +1,4 @@
-//! Stage progression recipes: game-wide step lists and stage presets.
+//! Stage progression recipes: stage presets. The campaign step list lives in
+//! `assets/campaigns/main.campaign.ron`.
 
-pub mod game;
 pub mod stages;
@@ -32,6 +32,7 @@ pub static STAGE_DEBUG_DATA: std::sync::LazyLock<Arc<StageData>> = std::sync::La
         on_end_transition_o: None,
         gravity: None,
         projection: None,
+        projection_source: None,
         checkpoint: None,
         parallax_attenuation: None,
         primitives: vec![],
@@ -3,23 +3,23 @@ use carapace::prelude::{CxCamera, WorldPos};
 use leafwing_input_manager::prelude::ActionState;
 
 use carcinisation_base::game::CameraPos;
-use carcinisation_input::GBInput;
+use carcinisation_input::GBDebugInput;
 
 const CAMERA_MOVEMENT_SPEED: f32 = 30.;
 
 /// @system DEBUG — moves the camera via debug arrow keys.
 pub fn move_camera(
     mut camera_pos_query: Query<&mut WorldPos, With<CameraPos>>,
-    gb_input: Res<ActionState<GBInput>>,
+    debug_input: Res<ActionState<GBDebugInput>>,
     time: Res<Time>,
     mut camera: ResMut<CxCamera>,
 ) {
     if let Ok(mut camera_pos) = camera_pos_query.single_mut() {
         **camera_pos += IVec2::new(
-            i32::from(gb_input.pressed(&GBInput::DRight))
-                - i32::from(gb_input.pressed(&GBInput::DLeft)),
-            i32::from(gb_input.pressed(&GBInput::DUp))
-                - i32::from(gb_input.pressed(&GBInput::DDown)),
+            i32::from(debug_input.pressed(&GBDebugInput::Right))
+                - i32::from(debug_input.pressed(&GBDebugInput::Left)),
+            i32::from(debug_input.pressed(&GBDebugInput::Up))
+                - i32::from(debug_input.pressed(&GBDebugInput::Down)),
         )
         .as_vec2()
         .normalize_or_zero()
@@ -1,24 +1,19 @@
 pub mod camera;
 pub mod movement;
 pub mod setup;
-pub mod spawn;
 
-use crate::{
-    components::{AudioSystemType, VolumeSettings},
-    game::messages::GameStartupEvent,
-    splash::messages::SplashStartupEvent,
-};
+use crate::{game::messages::GameStartupEvent, splash::messages::SplashStartupEvent};
 use bevy::prelude::*;
 
 /*
  * DEBUG
  */
 // pub fn input_exit_game(
-//     gb_input_query: Query<&ActionState<GBInput>>,
+//     debug_input_query: Query<&ActionState<GBDebugInput>>,
 //     mut exit: ResMut<Events<AppExit>>,
 // ) {
-//     let gb_input = gb_input_query.single();
-//     if gb_input.just_pressed(&GBInput::DExit) {
+//     let debug_input = debug_input_query.single();
+//     if debug_input.just_pressed(&GBDebugInput::Exit) {
 //         exit.write(AppExit);
 //     }
 // }
@@ -27,12 +22,12 @@ use bevy::prelude::*;
 //  * DEBUG
 //  */
 // pub fn transition_to_game_state(
-//     gb_input_query: Query<&ActionState<GBInput>>,
+//     debug_input_query: Query<&ActionState<GBDebugInput>>,
 //     app_state: Res<State<AppState>>,
 //     mut next_state: ResMut<NextState<AppState>>,
 // ) {
-//     let gb_input = gb_input_query.single();
-//     if gb_input.just_pressed(&GBInput::DToGame) {
+//     let debug_input = debug_input_query.single();
+//     if debug_input.just_pressed(&GBDebugInput::ToGame) {
 //         if app_state.get().to_owned() != AppState::Game {
 //             next_state.set(AppState::Game);
 //             info!("Entered AppState::Game");
@@ -44,12 +39,12 @@ use bevy::prelude::*;
 //  * DEBUG
 //  */
 // pub fn transition_to_main_menu_state(
-//     gb_input_query: Query<&ActionState<GBInput>>,
+//     debug_input_query: Query<&ActionState<GBDebugInput>>,
 //     app_state: Res<State<AppState>>,
 //     mut next_state: ResMut<NextState<AppState>>,
 // ) {
-//     let gb_input = gb_input_query.single();
-//     if gb_input.just_pressed(&GBInput::DToMainMenu) {
+//     let debug_input = debug_input_query.single();
+//     if debug_input.just_pressed(&GBDebugInput::ToMainMenu) {
 //         if app_state.get().to_owned() != AppState::MainMenu {
 //             // commands.insert_resource(NextState(Some(AppState::MainMenu)));
 //             next_state.set(AppState::MainMenu);
@@ -58,28 +53,6 @@ use bevy::prelude::*;
 //     }
 // }
 
-/// @system Syncs music source volumes with the global volume settings.
-pub fn update_music_volume(
-    mut source_settings: Query<(&mut PlaybackSettings, &AudioSystemType)>,
-    volume_settings: Res<VolumeSettings>,
-) {
-    for (mut music_source_settings, audio_system_type) in &mut source_settings {
-        if matches!(audio_system_type, AudioSystemType::SFX) {
-            music_source_settings.volume = volume_settings.music;
-        }
-    }
-}
-
-/// @system Syncs SFX source volumes with the global volume settings.
-pub fn update_sfx_volume(
-    mut source_settings: Query<&mut PlaybackSettings>,
-    volume_settings: Res<VolumeSettings>,
-) {
-    for mut sfx_source_settings in &mut source_settings {
-        sfx_source_settings.volume = volume_settings.sfx;
-    }
-}
-
 /// @system DEBUG — fires `GameStartupEvent` immediately.
 pub fn debug_trigger_game_startup(mut commands: Commands) {
     commands.trigger(GameStartupEvent);
@@ -1 +0,0 @@
-pub use carcinisation_core::components::make_music_bundle;
@@ -31,7 +31,11 @@
 //! **Mosquiton composed assets**:
 //! - `sprites/enemies/mosquiton_3/atlas.json`
 //! - `sprites/enemies/mosquiton_3/atlas.pxi`
+//!
+//! **Campaign steps**:
+//! - Every `src` in `campaigns/main.campaign.ron`
 
+use carcinisation::game::campaign::CampaignData;
 use carcinisation::stage::{
     components::placement::Depth,
     enemy::data::{mosquito::MOSQUITO_ANIMATIONS, tardigrade::TARDIGRADE_ANIMATIONS},
@@ -383,3 +387,15 @@ fn tardigrade_sprites_use_px_sprite_extension() {
         );
     }
 }
+
+/// Validates every cinematic and stage referenced by the main campaign exists.
+#[test]
+fn all_campaign_steps_exist() {
+    let campaign = CampaignData::load();
+    let missing = campaign.missing_assets(&PathBuf::from("../../assets"));
+    assert!(
+        missing.is_empty(),
+        "Campaign references missing assets:\n{}",
+        missing.join("\n")
+    );
+}
@@ -0,0 +1,143 @@
+//! Audio mixer behaviour without an audio device.
+//!
+//! The mixer only touches `PlaybackSettings` and optional `AudioSink`s, so it
+//! runs on `MinimalPlugins` with no audio backend. These tests drive it with a
+//! fixed frame time and inspect the voice entities it manages.
+
+use std::time::Duration;
+
+use bevy::{audio::AudioSource, prelude::*, time::TimeUpdateStrategy};
+use carcinisation::audio::{
+    AudioBus, AudioMixer, AudioMixerPlugin, AudioVoice, DuckMusic, MAX_VOICES_PER_SOUND,
+    MUSIC_DUCK_LEVEL, MusicTrack, PlayMusic, StopMusic, make_sound_bundle,
+};
+
+const FRAME: Duration = Duration::from_millis(100);
+
+fn mixer_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), AudioMixerPlugin))
+        .init_asset::<AudioSource>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+    app.update();
+    app
+}
+
+fn tracks(app: &mut App) -> Vec<String> {
+    let mut query = app.world_mut().query::<&MusicTrack>();
+    let mut paths: Vec<String> = query
+        .iter(app.world())
+        .map(|track| track.path.clone())
+        .collect();
+    paths.sort();
+    paths
+}
+
+fn run_for(app: &mut App, duration: Duration) {
+    for _ in 0..duration.div_duration_f32(FRAME).ceil() as u32 {
+        app.update();
+    }
+}
+
+#[test]
+fn requesting_current_track_keeps_it_playing() {
+    let mut app = mixer_app();
+    app.world_mut()
+        .write_message(PlayMusic::looped("audio/music/a.ogg"));
+    app.update();
+    app.world_mut()
+        .write_message(PlayMusic::looped("audio/music/a.ogg"));
+    run_for(&mut app, Duration::from_secs(2));
+
+    assert_eq!(tracks(&mut app), vec!["audio/music/a.ogg".to_string()]);
+}
+
+#[test]
+fn new_track_crossfades_from_previous() {
+    let mut app = mixer_app();
+    app.world_mut()
+        .write_message(PlayMusic::looped("audio/music/a.ogg"));
+    app.update();
+    app.world_mut()
+        .write_message(PlayMusic::looped("audio/music/b.ogg"));
+    app.update();
+
+    assert_eq!(
+        tracks(&mut app),
+        vec![
+            "audio/music/a.ogg".to_string(),
+            "audio/music/b.ogg".to_string()
+        ],
+        "both tracks play during the crossfade"
+    );
+
+    let crossfade = app.world().resource::<AudioMixer>().crossfade;
+    run_for(&mut app, crossfade);
+    assert_eq!(tracks(&mut app), vec!["audio/music/b.ogg".to_string()]);
+}
+
+#[test]
+fn stop_fades_out_music() {
+    let mut app = mixer_app();
+    app.world_mut()
+        .write_message(PlayMusic::looped("audio/music/a.ogg"));
+    app.update();
+    app.world_mut().write_message(StopMusic);
+    let crossfade = app.world().resource::<AudioMixer>().crossfade;
+    run_for(&mut app, crossfade + FRAME);
+
+    assert!(tracks(&mut app).is_empty());
+}
+
+#[test]
+fn repeated_sound_is_capped() {
+    let mut app = mixer_app();
+    let asset_server = app.world().resource::<AssetServer>().clone();
+    for _ in 0..MAX_VOICES_PER_SOUND + 2 {
+        app.world_mut().spawn(make_sound_bundle(
+            &asset_server,
+            "audio/sfx/player_shot.ogg",
+            AudioBus::Sfx,
+        ));
+    }
+    app.update();
+
+    let mut query = app.world_mut().query::<&AudioVoice>();
+    assert_eq!(query.iter(app.world()).count(), MAX_VOICES_PER_SOUND);
+}
+
+#[test]
+fn dialogue_ducks_music_until_it_ends() {
+    let mut app = mixer_app();
+    let dialogue = app.world_mut().spawn(DuckMusic).id();
+    run_for(&mut app, Duration::from_secs(1));
+    let ducked = app.world().resource::<AudioMixer>().music_duck;
+    assert!((ducked - MUSIC_DUCK_LEVEL).abs() < f32::EPSILON);
+
+    app.world_mut().despawn(dialogue);
+    run_for(&mut app, Duration::from_secs(1));
+    let released = app.world().resource::<AudioMixer>().music_duck;
+    assert!((released - 1.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn voice_line_ducks_music() {
+    let mut app = mixer_app();
+    let asset_server = app.world().resource::<AssetServer>().clone();
+    let line = app
+        .world_mut()
+        .spawn(make_sound_bundle(
+            &asset_server,
+            "audio/voice/line.ogg",
+            AudioBus::Voice,
+        ))
+        .id();
+    run_for(&mut app, Duration::from_secs(1));
+    let ducked = app.world().resource::<AudioMixer>().music_duck;
+    assert!((ducked - MUSIC_DUCK_LEVEL).abs() < f32::EPSILON);
+
+    app.world_mut().despawn(line);
+    run_for(&mut app, Duration::from_secs(1));
+    let released = app.world().resource::<AudioMixer>().music_duck;
+    assert!((released - 1.0).abs() < f32::EPSILON);
+}
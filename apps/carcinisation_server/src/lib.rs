@@ -2,7 +2,9 @@
 #![allow(clippy::needless_pass_by_value)]
 
 pub mod systems;
+pub mod token_service;
 
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy_replicon::prelude::ServerTriggerExt;
 use bevy_replicon::prelude::*;
@@ -15,33 +17,43 @@ use bevy_replicon_renet2::renet2::RenetServer;
 
 pub use crate::systems::pickup::PickupSet;
 use carcinisation_fps_core::{
-    MosquitonAiConfig,
+    EnemyPerception, MosquitonAiConfig,
     map::{EntitySpawnData, EntitySpawnKind, Map, PlayerStartData},
     pickup::PickupRules,
 };
+use carcinisation_net::auth::PrivateKey;
 use carcinisation_net::protocol::NetPickupKind;
 use carcinisation_net::{
-    AvatarPaletteVariant, ConnectMode, FlameActive, MonitorAck, NetAttackId, NetEnemyState,
-    NetEnemyType, NetHealth, NetPlayer, NetProtocolPlugin, NetworkObjectId, PlayerId,
-    PlayerIdAssigned, PlayerNetState, components::NetPickup, register_net_all,
+    AvatarPaletteVariant, ConnectMode, ConnectUserData, FlameActive, MonitorAck, NetAttackId,
+    NetEnemyState, NetEnemyType, NetHealth, NetPlayer, NetProtocolPlugin, NetScore,
+    NetworkObjectId, PlayerId, PlayerIdAssigned, PlayerNetState, components::NetPickup,
+    register_net_all,
 };
-use carcinisation_net::{CombatSet, MovementSet, TickSet};
+use carcinisation_net::{CombatSet, MovementSet, TickCounter, TickSet};
 use systems::admin::{poll_admin_socket, setup_admin_socket};
+use systems::chat::receive_chat_send;
 use systems::combat::process_combat;
 use systems::diagnostics::{DiagnosticsState, tick_diagnostics_end, tick_diagnostics_start};
+use systems::doors::{reset_map_mechanisms, spawn_map_doors, tick_map_mechanisms};
+use systems::game_mode::{
+    GameMode, MatchState, score_kills_and_deaths, spawn_match_state, tick_match,
+};
 use systems::input::{apply_buffered_movement, receive_client_intent, send_input_acks};
 use systems::occupancy::{
     OccupancySet, resolve_soft_occupancy, sync_enemy_occupancy_profiles, tick_player_impulses,
 };
+use systems::replay::{ReplayEvent, ReplayRecorder, record_map_reset, record_replay_hash};
 use systems::reset::{MapResetRequested, handle_map_reset};
 use systems::{
-    BurnContactCooldowns, EnemyAiSet, EnemyAttackSet, FireCooldownMap, FlameActiveTracker,
-    FlameCharCooldowns, GroundFireContactCooldowns, GroundFireCount, NextProjectileId,
-    PlayerInputTracker, PlayerIntentBuffer, ProjectileSet, ServerEnemyAiConfig, ServerMosquitonSim,
-    ServerMosquitonSimConfig, ServerQuickTurn, ServerSpideySim, ServerSpideySimConfig,
-    tick_burn_contact_damage, tick_despawn_timers, tick_enemy_attacks, tick_enemy_death_timers,
-    tick_ground_fire_damage, tick_net_enemy_ai, tick_pending_projectiles, tick_player_lifecycle,
-    tick_projectiles_server, tick_spidey_attacks,
+    BurnContactCooldowns, ChatRateLimiter, EnemyAiSet, EnemyAttackSet, FireCooldownMap,
+    FlameActiveTracker, FlameCharCooldowns, GroundFireContactCooldowns, GroundFireCount,
+    LagCompensation, NextProjectileId, PendingNoises, PlayerInputTracker, PlayerIntentBuffer,
+    ProjectileSet, ServerEnemyAiConfig, ServerEnemyNavigation, ServerEnemyPerception,
+    ServerMosquitonSim, ServerMosquitonSimConfig, ServerQuickTurn, ServerSpideySim,
+    ServerSpideySimConfig, record_enemy_history, tick_burn_contact_damage, tick_despawn_timers,
+    tick_enemy_attacks, tick_enemy_death_timers, tick_enemy_perception, tick_ground_fire_damage,
+    tick_net_enemy_ai, tick_pending_projectiles, tick_player_lifecycle, tick_projectiles_server,
+    tick_spidey_attacks, update_enemy_navigation,
 };
 
 /// Component attached to `ConnectedClient` to track assigned `PlayerId`.
@@ -52,6 +64,12 @@ struct ClientPlayerId(PlayerId);
 #[derive(Component, Debug, Clone, Copy)]
 pub(crate) struct ClientMonitor;
 
+/// Player names from the handshake `user_data`, keyed by `PlayerId`. Only
+/// trustworthy in secure mode, where the name is sealed into the token.
+/// Players who joined without a name have no entry.
+#[derive(Resource, Default)]
+pub(crate) struct PlayerNames(pub(crate) std::collections::HashMap<PlayerId, String>);
+
 /// Server-side map resource for collision.
 #[derive(Resource)]
 pub struct ServerMap(pub Map);
@@ -81,7 +99,7 @@ impl NextPlayerId {
 /// variant based on `PlayerId`. Variants are returned to the pool on
 /// disconnect.
 #[derive(Resource)]
-struct AvatarPalettePool {
+pub(crate) struct AvatarPalettePool {
     /// Indexed by the variant enum's discriminant.
     free: [bool; AvatarPaletteVariant::COUNT],
 }
@@ -160,6 +178,29 @@ pub struct ServerPlugin {
     pub instance_name: String,
     /// Map file path for status reporting.
     pub map_path: String,
+    /// If set, the server also accepts WebSocket (browser) clients on this
+    /// TCP port. Requires the `websocket` feature. Ignored under
+    /// [`ServerAuthMode::Secure`]: connect tokens only cover UDP.
+    pub ws_port: Option<u16>,
+    /// Netcode authentication. Unsecure unless a private key is configured.
+    pub auth: ServerAuthMode,
+}
+
+/// How the netcode transport authenticates connecting clients.
+#[derive(Clone, Default)]
+pub enum ServerAuthMode {
+    /// Accept any client that knows the protocol id. Local development only:
+    /// clients choose their own `user_data` (connect mode and name).
+    #[default]
+    Unsecure,
+    /// Only admit clients presenting a connect token sealed with
+    /// `private_key` (see `carcinisation_net::auth`).
+    Secure {
+        private_key: PrivateKey,
+        /// Public UDP address baked into minted tokens. netcode rejects
+        /// tokens that do not list it.
+        public_addr: std::net::SocketAddr,
+    },
 }
 
 #[allow(clippy::too_many_lines)]
@@ -192,18 +233,24 @@ impl Plugin for ServerPlugin {
             )
             .init_resource::<PlayerInputTracker>()
             .init_resource::<PlayerIntentBuffer>()
+            .init_resource::<ChatRateLimiter>()
             .init_resource::<FireCooldownMap>()
             .init_resource::<FlameActiveTracker>()
             .init_resource::<FlameCharCooldowns>()
             .init_resource::<BurnContactCooldowns>()
             .init_resource::<GroundFireContactCooldowns>()
             .init_resource::<GroundFireCount>()
+            .init_resource::<ServerEnemyNavigation>()
+            .init_resource::<PendingNoises>()
+            .init_resource::<LagCompensation>()
             .insert_resource(systems::combat::load_burn_config())
             .insert_resource(carcinisation_fps_core::PlayerFlamethrowerConfig::load())
             .insert_resource(carcinisation_fps_core::FpsMovementConfig::load())
             .insert_resource(carcinisation_fps_core::FpsCombatConfig::load())
+            .insert_resource(carcinisation_fps_core::WeaponTable::load())
             .init_resource::<NextPlayerId>()
             .init_resource::<AvatarPalettePool>()
+            .init_resource::<PlayerNames>()
             .init_resource::<SpawnIndex>()
             .insert_resource(PickupRules::load())
             .init_resource::<systems::pickup::PickupEventBuffer>()
@@ -214,9 +261,10 @@ impl Plugin for ServerPlugin {
                 &self.player_starts,
             )))
             .add_observer(receive_client_intent)
+            .add_observer(receive_chat_send)
             .add_observer(handle_client_connect)
             .add_observer(handle_client_disconnect)
-            .add_systems(Startup, spawn_map_enemies)
+            .add_systems(Startup, (spawn_map_enemies, spawn_map_doors))
             .configure_sets(
                 FixedUpdate,
                 (
@@ -238,13 +286,28 @@ impl Plugin for ServerPlugin {
                     .in_set(MovementSet)
                     .after(apply_buffered_movement),
             )
+            .add_systems(
+                FixedUpdate,
+                tick_map_mechanisms
+                    .in_set(MovementSet)
+                    .after(tick_player_impulses),
+            )
             .add_systems(
                 FixedUpdate,
                 send_input_acks
                     .in_set(MovementSet)
                     .after(tick_player_impulses),
             )
-            .add_systems(FixedUpdate, tick_net_enemy_ai.in_set(EnemyAiSet))
+            .add_systems(
+                FixedUpdate,
+                (
+                    update_enemy_navigation,
+                    tick_enemy_perception,
+                    tick_net_enemy_ai,
+                )
+                    .chain()
+                    .in_set(EnemyAiSet),
+            )
             .add_systems(FixedUpdate, tick_enemy_attacks.in_set(EnemyAttackSet))
             .add_systems(FixedUpdate, tick_spidey_attacks.in_set(EnemyAttackSet))
             .add_systems(
@@ -299,12 +362,34 @@ impl Plugin for ServerPlugin {
                     .after(process_combat)
                     .after(tick_burn_contact_damage),
             )
+            // Game-mode rules (only when a `GameMode` is installed).
+            .init_resource::<MatchState>()
+            .add_systems(
+                Startup,
+                spawn_match_state.run_if(resource_exists::<GameMode>),
+            )
+            .add_systems(
+                FixedUpdate,
+                (score_kills_and_deaths, tick_match)
+                    .chain()
+                    .in_set(CombatSet)
+                    .after(systems::combat::tick_enemy_burning)
+                    .after(tick_enemy_death_timers)
+                    .after(tick_player_lifecycle)
+                    .run_if(resource_exists::<GameMode>),
+            )
             .add_systems(FixedUpdate, systems::pickup_system.in_set(PickupSet))
             .add_systems(
                 FixedUpdate,
                 systems::pickup::flush_pickup_events.after(PickupSet),
             )
             .add_systems(FixedUpdate, tick_despawn_timers.in_set(TickSet))
+            .add_systems(
+                FixedUpdate,
+                record_enemy_history
+                    .in_set(TickSet)
+                    .before(carcinisation_net::tick::increment_tick),
+            )
             .add_systems(
                 FixedUpdate,
                 tick_diagnostics_start
@@ -321,11 +406,35 @@ impl Plugin for ServerPlugin {
             .init_resource::<NextProjectileId>()
             .init_resource::<MapResetRequested>()
             .insert_resource(ServerPort(self.port))
+            .insert_resource(ServerWebSocketPort(self.ws_port))
+            .insert_resource(ServerAuth(self.auth.clone()))
             .add_systems(
                 FixedUpdate,
                 handle_map_reset
                     .in_set(MovementSet)
                     .before(tick_diagnostics_start),
+            )
+            .add_systems(
+                FixedUpdate,
+                reset_map_mechanisms
+                    .in_set(MovementSet)
+                    .before(handle_map_reset),
+            )
+            // Match recording (only when a `ReplayRecorder` is installed).
+            .add_systems(
+                FixedUpdate,
+                record_map_reset
+                    .in_set(MovementSet)
+                    .before(handle_map_reset)
+                    .run_if(resource_exists::<ReplayRecorder>),
+            )
+            .add_systems(
+                FixedUpdate,
+                record_replay_hash
+                    .in_set(TickSet)
+                    .after(tick_diagnostics_end)
+                    .before(carcinisation_net::tick::increment_tick)
+                    .run_if(resource_exists::<ReplayRecorder>),
             );
 
         // Admin socket (optional — skipped in tests or when no path is given).
@@ -346,9 +455,19 @@ impl Plugin for ServerPlugin {
         let wall_count = self.map.cells.iter().filter(|&&c| c > 0).count();
         let spawn_count = self.player_starts.len();
         let entity_count = self.entities.len();
+        let auth_label = match self.auth {
+            ServerAuthMode::Unsecure => "unsecure",
+            ServerAuthMode::Secure { .. } => "secure",
+        };
         info!(
-            "ServerPlugin built: port={} map={}x{} walls={} spawns={} entities={} tick_hz=30",
-            self.port, self.map.width, self.map.height, wall_count, spawn_count, entity_count
+            "ServerPlugin built: port={} ws_port={:?} auth={auth_label} map={}x{} walls={} spawns={} entities={} tick_hz=30",
+            self.port,
+            self.ws_port,
+            self.map.width,
+            self.map.height,
+            wall_count,
+            spawn_count,
+            entity_count
         );
     }
 }
@@ -356,11 +475,28 @@ impl Plugin for ServerPlugin {
 #[derive(Resource)]
 pub struct ServerPort(pub u16);
 
+/// Optional WebSocket listen port (see [`ServerPlugin::ws_port`]).
+#[derive(Resource)]
+pub struct ServerWebSocketPort(pub Option<u16>);
+
+/// Authentication mode (see [`ServerPlugin::auth`]).
+#[derive(Resource)]
+pub struct ServerAuth(pub ServerAuthMode);
+
+/// Tokio runtime driving the WebSocket listener. Kept alive for as long as the
+/// server transport exists; dropping it closes every WebSocket connection.
+#[cfg(feature = "websocket")]
+#[derive(Resource)]
+pub struct WebSocketRuntime(pub tokio::runtime::Runtime);
+
 fn init_server_setup(
     mut commands: Commands,
     server_port: Res<ServerPort>,
+    ws_port: Res<ServerWebSocketPort>,
+    auth: Res<ServerAuth>,
     channels: Res<RepliconChannels>,
 ) {
+    use bevy_replicon_renet2::netcode::BoxedSocket;
     use bevy_replicon_renet2::renet2::ConnectionConfig;
     use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -374,26 +510,105 @@ fn init_server_setup(
     let connection_config = ConnectionConfig::from_channels(server_configs, client_configs);
 
     let server = RenetServer::new(connection_config);
-    let public_addr = std::net::SocketAddr::new(
+    let bind_addr = std::net::SocketAddr::new(
         std::net::IpAddr::V4(std::net::Ipv4Addr::UNSPECIFIED),
         server_port.0,
     );
-    let socket = NativeSocket::new(std::net::UdpSocket::bind(public_addr).expect("bind"))
+    let socket = NativeSocket::new(std::net::UdpSocket::bind(bind_addr).expect("bind"))
         .expect("create socket");
+
+    // Socket order must match `MultiplayerTransport::socket_id`.
+    #[cfg_attr(not(feature = "websocket"), allow(unused_mut))]
+    let mut sockets = vec![BoxedSocket::new(socket)];
+    let (authentication, udp_addr) = match &auth.0 {
+        ServerAuthMode::Unsecure => (ServerAuthentication::Unsecure, bind_addr),
+        ServerAuthMode::Secure {
+            private_key,
+            public_addr: advertised,
+        } => (
+            ServerAuthentication::Secure {
+                private_key: *private_key,
+            },
+            *advertised,
+        ),
+    };
+    #[cfg_attr(not(feature = "websocket"), allow(unused_mut))]
+    let mut socket_addresses = vec![vec![udp_addr]];
+
+    if let Some(ws_port) = ws_port.0 {
+        if matches!(auth.0, ServerAuthMode::Secure { .. }) {
+            error!("ws_port={ws_port} ignored: secure connect tokens only cover UDP");
+        } else {
+            #[cfg(feature = "websocket")]
+            {
+                let (runtime, ws_socket, ws_addr) = bind_websocket(ws_port, MAX_CLIENTS);
+                sockets.push(BoxedSocket::new(ws_socket));
+                socket_addresses.push(vec![ws_addr]);
+                commands.insert_resource(WebSocketRuntime(runtime));
+                info!("Server listening on {ws_addr} (WebSocket)");
+            }
+            #[cfg(not(feature = "websocket"))]
+            warn!("ws_port={ws_port} ignored: server built without the `websocket` feature");
+        }
+    }
+
     let server_config = ServerSetupConfig {
         current_time,
-        // Includes headroom for monitor/spectator connections beyond the player slots.
-        max_clients: 16,
+        max_clients: MAX_CLIENTS,
         protocol_id: carcinisation_net::PROTOCOL_ID,
-        authentication: ServerAuthentication::Unsecure,
-        socket_addresses: vec![vec![public_addr]],
+        authentication,
+        socket_addresses,
     };
-    let transport = NetcodeServerTransport::new(server_config, socket).expect("create transport");
+    let transport =
+        NetcodeServerTransport::new_with_sockets(server_config, sockets).expect("create transport");
 
     commands.insert_resource(server);
     commands.insert_resource(transport);
 
-    info!("Server listening on 0.0.0.0:{} (UDP)", server_port.0);
+    if matches!(auth.0, ServerAuthMode::Secure { .. }) {
+        info!(
+            "Server listening on 0.0.0.0:{} (UDP, connect tokens for {udp_addr})",
+            server_port.0
+        );
+    } else {
+        info!("Server listening on 0.0.0.0:{} (UDP)", server_port.0);
+    }
+}
+
+/// Connection slots shared by every transport. Includes headroom for
+/// monitor/spectator connections beyond the player slots.
+const MAX_CLIENTS: usize = 16;
+
+/// Bind the WebSocket listener on its own small tokio runtime.
+///
+/// Returns the runtime (which must outlive the socket), the socket, and the
+/// bound address registered with netcode for this socket.
+#[cfg(feature = "websocket")]
+fn bind_websocket(
+    port: u16,
+    max_clients: usize,
+) -> (
+    tokio::runtime::Runtime,
+    bevy_replicon_renet2::netcode::WebSocketServer,
+    std::net::SocketAddr,
+) {
+    use bevy_replicon_renet2::netcode::{WebSocketServer, WebSocketServerConfig};
+
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .thread_name("ws-transport")
+        .enable_all()
+        .build()
+        .expect("create WebSocket runtime");
+    let listen =
+        std::net::SocketAddr::new(std::net::IpAddr::V4(std::net::Ipv4Addr::UNSPECIFIED), port);
+    let socket = WebSocketServer::new(
+        WebSocketServerConfig::new(listen, max_clients),
+        runtime.handle().clone(),
+    )
+    .expect("bind WebSocket listener");
+    let addr = std::net::SocketAddr::new(listen.ip(), socket.url().port().unwrap_or(port));
+    (runtime, socket, addr)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -404,21 +619,27 @@ fn handle_client_connect(
     mut spawn_idx: ResMut<SpawnIndex>,
     player_starts: Res<MapPlayerStarts>,
     mut palette_pool: ResMut<AvatarPalettePool>,
+    mut player_names: ResMut<PlayerNames>,
     transport: Res<NetcodeServerTransport>,
     network_ids: Query<&NetworkId>,
     combat_config: Res<carcinisation_fps_core::FpsCombatConfig>,
     movement_config: Res<carcinisation_fps_core::FpsMovementConfig>,
+    tick_counter: Res<TickCounter>,
+    mut recorder: Option<ResMut<ReplayRecorder>>,
 ) {
     let client_entity = trigger.event().entity;
 
-    // Determine connect mode from user_data embedded in the renet2 handshake.
-    let connect_mode = network_ids
+    // Determine connect mode (and, with connect tokens, the player name)
+    // from user_data embedded in the renet2 handshake.
+    let ConnectUserData {
+        mode: connect_mode,
+        player_name,
+    } = network_ids
         .get(client_entity)
-        .map_or(ConnectMode::Player, |nid| {
-            transport
-                .user_data(nid.get())
-                .map_or(ConnectMode::Player, |ud| ConnectMode::from_user_data(&ud))
-        });
+        .ok()
+        .and_then(|nid| transport.user_data(nid.get()))
+        .map(|ud| ConnectUserData::from_user_data(&ud))
+        .unwrap_or_default();
 
     let client_id = bevy_replicon::prelude::ClientId::Client(client_entity);
 
@@ -444,9 +665,12 @@ fn handle_client_connect(
             let avatar_variant = palette_pool.assign(player_id);
 
             info!(
-                "Client entity {:?} connected, assigned PlayerId {:?} variant {:?}",
-                client_entity, player_id, avatar_variant
+                "Client entity {:?} connected, assigned PlayerId {:?} variant {:?} name {:?}",
+                client_entity, player_id, avatar_variant, player_name
             );
+            if let Some(name) = player_name {
+                player_names.0.insert(player_id, name);
+            }
 
             commands
                 .entity(client_entity)
@@ -457,28 +681,62 @@ fn handle_client_connect(
                 message: PlayerIdAssigned(player_id),
             });
 
-            commands.spawn((
-                NetPlayer {
-                    player_id,
-                    position,
-                    angle,
-                    current_attack: NetAttackId::None,
-                    state: PlayerNetState::Alive,
-                    flame_active: false,
-                    avatar_palette_variant: Some(avatar_variant),
-                },
-                NetHealth {
-                    current: 100.0,
-                    max: 100.0,
-                },
-                ServerQuickTurn::default(),
-                systems::occupancy::player_occupancy(&combat_config, &movement_config),
-                Replicated,
-            ));
+            spawn_net_player(
+                &mut commands,
+                player_id,
+                position,
+                angle,
+                avatar_variant,
+                &combat_config,
+                &movement_config,
+            );
+
+            if let Some(recorder) = recorder.as_mut() {
+                recorder.record_event(
+                    tick_counter.0,
+                    ReplayEvent::PlayerJoined {
+                        player_id,
+                        position,
+                        angle,
+                        avatar_variant,
+                    },
+                );
+            }
         }
     }
 }
 
+/// Spawn the replicated player entity for a newly joined player.
+pub(crate) fn spawn_net_player(
+    commands: &mut Commands,
+    player_id: PlayerId,
+    position: Vec2,
+    angle: f32,
+    avatar_variant: AvatarPaletteVariant,
+    combat_config: &carcinisation_fps_core::FpsCombatConfig,
+    movement_config: &carcinisation_fps_core::FpsMovementConfig,
+) {
+    commands.spawn((
+        NetPlayer {
+            player_id,
+            position,
+            angle,
+            current_attack: NetAttackId::default(),
+            state: PlayerNetState::Alive,
+            flame_active: false,
+            avatar_palette_variant: Some(avatar_variant),
+        },
+        NetHealth {
+            current: 100.0,
+            max: 100.0,
+        },
+        NetScore::default(),
+        ServerQuickTurn::default(),
+        systems::occupancy::player_occupancy(combat_config, movement_config),
+        Replicated,
+    ));
+}
+
 fn normalized_player_starts(map: &Map, map_starts: &[PlayerStartData]) -> Vec<PlayerStartData> {
     let mut starts = map_starts.to_vec();
     starts.extend(fallback_player_starts(map));
@@ -544,6 +802,22 @@ fn fallback_player_starts(map: &Map) -> Vec<PlayerStartData> {
     starts
 }
 
+/// Per-player server state dropped when a player leaves.
+#[derive(SystemParam)]
+pub(crate) struct PlayerSessionState<'w> {
+    tracker: ResMut<'w, PlayerInputTracker>,
+    buffer: ResMut<'w, PlayerIntentBuffer>,
+    cooldowns: ResMut<'w, FireCooldownMap>,
+    flame_tracker: ResMut<'w, FlameActiveTracker>,
+    burn_cooldowns: ResMut<'w, BurnContactCooldowns>,
+    char_cooldowns: ResMut<'w, FlameCharCooldowns>,
+    gf_cooldowns: ResMut<'w, GroundFireContactCooldowns>,
+    chat_limiter: ResMut<'w, ChatRateLimiter>,
+    palette_pool: ResMut<'w, AvatarPalettePool>,
+    player_names: ResMut<'w, PlayerNames>,
+    lag_compensation: ResMut<'w, LagCompensation>,
+}
+
 #[allow(clippy::too_many_arguments)]
 fn handle_client_disconnect(
     trigger: On<Remove, ConnectedClient>,
@@ -551,14 +825,9 @@ fn handle_client_disconnect(
     client_query: Query<&ClientPlayerId>,
     monitor_query: Query<&ClientMonitor>,
     player_query: Query<(Entity, &NetPlayer)>,
-    mut tracker: ResMut<PlayerInputTracker>,
-    mut buffer: ResMut<PlayerIntentBuffer>,
-    mut cooldowns: ResMut<FireCooldownMap>,
-    mut flame_tracker: ResMut<FlameActiveTracker>,
-    mut burn_cooldowns: ResMut<BurnContactCooldowns>,
-    mut char_cooldowns: ResMut<FlameCharCooldowns>,
-    mut gf_cooldowns: ResMut<GroundFireContactCooldowns>,
-    mut palette_pool: ResMut<AvatarPalettePool>,
+    mut session: PlayerSessionState,
+    tick_counter: Res<TickCounter>,
+    mut recorder: Option<ResMut<ReplayRecorder>>,
 ) {
     let client_entity = trigger.event().entity;
 
@@ -577,10 +846,28 @@ fn handle_client_disconnect(
     };
     let player_id = client_pid.0;
 
+    remove_net_player(&mut commands, &player_query, &mut session, player_id);
+    if let Some(recorder) = recorder.as_mut() {
+        recorder.record_event(tick_counter.0, ReplayEvent::PlayerLeft { player_id });
+    }
+
+    info!(
+        "Client {:?} disconnected, cleaned up PlayerId {:?}",
+        client_entity, player_id
+    );
+}
+
+/// Despawn a player's entity and drop all of their per-player server state.
+pub(crate) fn remove_net_player(
+    commands: &mut Commands,
+    player_query: &Query<(Entity, &NetPlayer)>,
+    session: &mut PlayerSessionState,
+    player_id: PlayerId,
+) {
     for (entity, np) in player_query.iter() {
         if np.player_id == player_id {
             if let Some(variant) = np.avatar_palette_variant {
-                palette_pool.release(variant);
+                session.palette_pool.release(variant);
             }
             commands.entity(entity).despawn();
             break;
@@ -588,7 +875,13 @@ fn handle_client_disconnect(
     }
 
     // Emit FlameActive(false) if the player was flaming, so clients clear visuals.
-    if flame_tracker.0.get(&player_id).copied().unwrap_or(false) {
+    if session
+        .flame_tracker
+        .0
+        .get(&player_id)
+        .copied()
+        .unwrap_or(false)
+    {
         commands.server_trigger(ToClients {
             mode: SendMode::Broadcast,
             message: FlameActive {
@@ -598,18 +891,16 @@ fn handle_client_disconnect(
         });
     }
 
-    tracker.remove_player(&player_id);
-    buffer.remove_player(&player_id);
-    cooldowns.remove_player(&player_id);
-    flame_tracker.remove_player(&player_id);
-    burn_cooldowns.remove_player(&player_id);
-    char_cooldowns.remove_player(&player_id);
-    gf_cooldowns.remove_player(&player_id);
-
-    info!(
-        "Client {:?} disconnected, cleaned up PlayerId {:?}",
-        client_entity, player_id
-    );
+    session.tracker.remove_player(&player_id);
+    session.buffer.remove_player(&player_id);
+    session.cooldowns.remove_player(&player_id);
+    session.flame_tracker.remove_player(&player_id);
+    session.burn_cooldowns.remove_player(&player_id);
+    session.char_cooldowns.remove_player(&player_id);
+    session.gf_cooldowns.remove_player(&player_id);
+    session.chat_limiter.remove_player(&player_id);
+    session.lag_compensation.remove_player(&player_id);
+    session.player_names.0.remove(&player_id);
 }
 
 /// Spawns enemies from the map's entity list on server startup.
@@ -668,6 +959,11 @@ pub fn spawn_map_enemies_inner(commands: &mut Commands, entities: &[EntitySpawnD
             if let Some(ai_config) = server_enemy_ai_config_from_spawn(spawn) {
                 enemy_commands.insert(ai_config);
             }
+            if let EntitySpawnKind::Mosquiton { patrol, .. }
+            | EntitySpawnKind::Spidey { patrol, .. } = &spawn.kind
+            {
+                enemy_commands.insert(ServerEnemyPerception(EnemyPerception::with_patrol(patrol)));
+            }
             if let EntitySpawnKind::Mosquiton { speed, .. } = &spawn.kind {
                 let seed =
                     carcinisation_fps_core::corpse_seed(bevy::math::Vec2::new(spawn.x, spawn.y));
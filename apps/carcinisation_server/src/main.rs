@@ -1,19 +1,35 @@
-use std::path::PathBuf;
+use std::io::Write;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 use std::time::Duration;
 
 use bevy::app::ScheduleRunnerPlugin;
 use bevy::prelude::*;
-use carcinisation_fps_core::map::Map;
-use carcinisation_server::ServerPlugin;
-use clap::Parser;
+use carcinisation_fps_core::game_mode::{GameModeConfig, GameModeKind};
+use carcinisation_fps_core::map::{Map, MapLoadData};
+use carcinisation_net::ConnectMode;
+use carcinisation_net::auth::{generate_private_key, load_private_key, private_key_to_hex};
+use carcinisation_server::systems::replay::DEFAULT_HASH_INTERVAL_TICKS;
+use carcinisation_server::systems::{
+    GameMode, ReplayLog, ReplayRecorder, ReplayReport, run_replay,
+};
+use carcinisation_server::token_service::{TokenIssuer, run_token_service};
+use carcinisation_server::{ServerAuthMode, ServerPlugin};
+use clap::{Args as ClapArgs, Parser, Subcommand, ValueEnum};
 
 /// Default map — must match the `multiplayer_client` default.
 const DEFAULT_MAP: &str = "assets/config/fp/test_room.fp_map.ron";
 
 #[derive(Parser, Clone, Debug)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
     #[arg(long, default_value = "7142")]
     port: u16,
+    /// TCP port for browser (WebSocket) clients. Disabled when unset.
+    #[arg(long, env = "WS_PORT")]
+    ws_port: Option<u16>,
     #[arg(long, default_value = DEFAULT_MAP)]
     map: PathBuf,
     /// Instance name for admin/status reporting.
@@ -24,15 +40,136 @@ struct Args {
     /// Defaults to the `ADMIN_SOCKET` env var. If unset, admin socket is disabled.
     #[arg(long, env = "ADMIN_SOCKET")]
     admin_socket: Option<String>,
+    /// Record the match (accepted intents + periodic sim hashes) to this file.
+    #[arg(long, env = "RECORD_REPLAY", conflicts_with = "replay")]
+    record: Option<PathBuf>,
+    /// Ticks between sim hash checkpoints in a recording.
+    #[arg(long, default_value_t = DEFAULT_HASH_INTERVAL_TICKS)]
+    record_hash_interval: u32,
+    /// Re-simulate a recorded match headlessly and report the first tick
+    /// whose sim hash diverges. Exits non-zero on divergence.
+    #[arg(long)]
+    replay: Option<PathBuf>,
+    /// Match rules. Overrides the map's `game_mode` block; without either the
+    /// server runs an open-ended free-for-all.
+    #[arg(long, env = "GAME_MODE", value_enum)]
+    mode: Option<ModeArg>,
+    /// Require netcode connect tokens sealed with this key file (64 hex
+    /// characters, see `gen-key`). Without it the server runs unsecure.
+    /// Tokens only cover UDP, so this cannot be combined with `--ws-port`.
+    #[arg(
+        long,
+        env = "PRIVATE_KEY_FILE",
+        conflicts_with_all = ["replay", "ws_port"]
+    )]
+    private_key: Option<PathBuf>,
+    /// Public UDP address clients reach this server on. Must match the
+    /// `--server-addr` used to mint tokens. Defaults to 127.0.0.1:<port>.
+    #[arg(long, env = "PUBLIC_ADDR", requires = "private_key")]
+    public_addr: Option<SocketAddr>,
 }
 
-fn main() {
+/// Secure-mode key and token tooling.
+#[derive(Subcommand, Clone, Debug)]
+enum Command {
+    /// Write a new random private key file.
+    GenKey {
+        /// Destination; refuses to overwrite an existing file.
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Mint a single connect token into a file.
+    MintToken {
+        #[command(flatten)]
+        issuer: IssuerArgs,
+        /// Player name sealed into the token.
+        #[arg(long)]
+        name: Option<String>,
+        /// Mint a monitor (spectator) token instead of a player token.
+        #[arg(long)]
+        monitor: bool,
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Serve `GET /token?name=..&mode=player|monitor` over plain HTTP.
+    TokenService {
+        #[command(flatten)]
+        issuer: IssuerArgs,
+        #[arg(long, default_value = "127.0.0.1:7150")]
+        listen: SocketAddr,
+    },
+}
+
+#[derive(ClapArgs, Clone, Debug)]
+struct IssuerArgs {
+    /// Server private key file.
+    #[arg(long, env = "PRIVATE_KEY_FILE")]
+    private_key: PathBuf,
+    /// Server public UDP address baked into the token (repeatable).
+    #[arg(long = "server-addr", default_value = "127.0.0.1:7142")]
+    server_addr: Vec<SocketAddr>,
+}
+
+impl IssuerArgs {
+    fn issuer(&self) -> Result<TokenIssuer, String> {
+        Ok(TokenIssuer {
+            private_key: load_private_key(&self.private_key)?,
+            server_addresses: self.server_addr.clone(),
+        })
+    }
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum ModeArg {
+    Deathmatch,
+    Coop,
+}
+
+impl From<ModeArg> for GameModeKind {
+    fn from(mode: ModeArg) -> Self {
+        match mode {
+            ModeArg::Deathmatch => Self::Deathmatch,
+            ModeArg::Coop => Self::Coop,
+        }
+    }
+}
+
+fn load_map(path: &Path) -> MapLoadData {
+    let map_ron = std::fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("failed to read map {}: {e}", path.display()));
+    Map::load_data(&map_ron)
+        .unwrap_or_else(|e| panic!("failed to parse map {}: {e}", path.display()))
+}
+
+fn main() -> ExitCode {
     let args = Args::parse();
 
-    let map_ron = std::fs::read_to_string(&args.map)
-        .unwrap_or_else(|e| panic!("failed to read map {}: {e}", args.map.display()));
-    let map_data = Map::load_data(&map_ron)
-        .unwrap_or_else(|e| panic!("failed to parse map {}: {e}", args.map.display()));
+    if let Some(command) = &args.command {
+        return command_main(command);
+    }
+
+    if let Some(replay) = &args.replay {
+        return replay_main(replay);
+    }
+
+    let auth = match &args.private_key {
+        None => ServerAuthMode::Unsecure,
+        Some(path) => match load_private_key(path) {
+            Ok(private_key) => ServerAuthMode::Secure {
+                private_key,
+                public_addr: args
+                    .public_addr
+                    .unwrap_or_else(|| SocketAddr::from(([127, 0, 0, 1], args.port))),
+            },
+            Err(e) => {
+                eprintln!("{e}");
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+
+    let map_data = load_map(&args.map);
+    let game_mode = GameModeConfig::resolve(map_data.game_mode, args.mode.map(Into::into));
 
     let mut app = App::new();
 
@@ -55,9 +192,144 @@ fn main() {
         admin_socket: args.admin_socket,
         instance_name: args.instance,
         map_path: args.map.display().to_string(),
+        ws_port: args.ws_port,
+        auth,
     });
 
+    if let Some(config) = game_mode {
+        app.insert_resource(GameMode(config));
+    }
+
+    if let Some(record) = &args.record {
+        let recorder = ReplayRecorder::create(
+            record,
+            &args.map.display().to_string(),
+            game_mode,
+            args.record_hash_interval,
+        )
+        .unwrap_or_else(|e| panic!("failed to create replay {}: {e}", record.display()));
+        app.insert_resource(recorder);
+        eprintln!("Recording match to {}", record.display());
+    }
+
     app.run();
     // Reached after Ctrl+C / AppExit.
     eprintln!("Server stopped.");
+    ExitCode::SUCCESS
+}
+
+/// Key/token subcommands; none of them start the game server.
+fn command_main(command: &Command) -> ExitCode {
+    let result = match command {
+        Command::GenKey { out } => write_new_key(out),
+        Command::MintToken {
+            issuer,
+            name,
+            monitor,
+            out,
+        } => {
+            let mode = if *monitor {
+                ConnectMode::Monitor
+            } else {
+                ConnectMode::Player
+            };
+            issuer
+                .issuer()
+                .and_then(|issuer| issuer.issue(name.clone(), mode))
+                .and_then(|token| {
+                    std::fs::write(out, token)
+                        .map_err(|e| format!("failed to write {}: {e}", out.display()))
+                })
+                .map(|()| eprintln!("Wrote {mode:?} token to {}", out.display()))
+        }
+        Command::TokenService { issuer, listen } => issuer.issuer().and_then(|issuer| {
+            run_token_service(*listen, &issuer)
+                .map_err(|e| format!("token service on {listen} failed: {e}"))
+        }),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// `gen-key`: create the key file owner-readable only.
+fn write_new_key(out: &Path) -> Result<(), String> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options
+        .open(out)
+        .map_err(|e| format!("failed to create {}: {e}", out.display()))?;
+    writeln!(file, "{}", private_key_to_hex(&generate_private_key()))
+        .map_err(|e| format!("failed to write {}: {e}", out.display()))?;
+    eprintln!("Wrote private key to {}", out.display());
+    Ok(())
+}
+
+/// `--replay`: re-simulate a recording on a network-less server.
+fn replay_main(path: &Path) -> ExitCode {
+    let log = match ReplayLog::read(path) {
+        Ok(log) => log,
+        Err(e) => {
+            eprintln!("failed to read replay {}: {e}", path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+    let map_path = PathBuf::from(&log.header.map_path);
+    let map_data = load_map(&map_path);
+
+    let mut app = App::new();
+    app.add_plugins((
+        bevy::app::TaskPoolPlugin::default(),
+        bevy::diagnostic::FrameCountPlugin,
+        bevy::time::TimePlugin,
+    ));
+    app.add_plugins(bevy::state::app::StatesPlugin);
+    // Port 0: the transport is created but nobody connects.
+    app.add_plugins(ServerPlugin {
+        port: 0,
+        map: map_data.map,
+        entities: map_data.entities,
+        player_starts: map_data.player_starts,
+        admin_socket: None,
+        instance_name: "replay".to_string(),
+        map_path: log.header.map_path.clone(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
+    });
+    app.finish();
+    app.cleanup();
+
+    eprintln!(
+        "Replaying {} on {} ({} checkpoints, every {} ticks)",
+        path.display(),
+        log.header.map_path,
+        log.hash_count(),
+        log.header.hash_interval
+    );
+    match run_replay(&mut app, &log) {
+        ReplayReport::Matched { ticks, checkpoints } => {
+            eprintln!("OK: {ticks} ticks, {checkpoints} checkpoints matched");
+            ExitCode::SUCCESS
+        }
+        ReplayReport::Diverged {
+            tick,
+            expected,
+            actual,
+            last_good,
+        } => {
+            let last_good = last_good.map_or_else(|| "none".to_string(), |t| t.0.to_string());
+            eprintln!(
+                "DESYNC at tick {}: expected {expected:#018x}, got {actual:#018x} \
+                 (last matching checkpoint: {last_good})",
+                tick.0
+            );
+            ExitCode::FAILURE
+        }
+    }
 }
@@ -12,9 +12,11 @@ use std::time::{Duration, Instant};
 
 use bevy::prelude::*;
 use carcinisation_admin::{AdminRequest, AdminResponse};
-use carcinisation_net::{NetHealth, NetPlayer, TickCounter};
+use carcinisation_net::{NetHealth, NetPlayer, NetScore, TickConfig, TickCounter};
 
 use super::NetEnemy;
+use super::chat::broadcast_server_chat;
+use super::game_mode::{GameMode, MatchState, match_status_json};
 use super::reset::MapResetRequested;
 
 /// Server-side admin socket state.
@@ -68,15 +70,24 @@ pub fn setup_admin_socket(
 }
 
 /// Poll the admin socket for incoming commands. Runs in `FixedUpdate`.
-#[allow(clippy::missing_panics_doc, clippy::needless_pass_by_value)]
+#[allow(
+    clippy::missing_panics_doc,
+    clippy::needless_pass_by_value,
+    clippy::too_many_arguments
+)]
 pub fn poll_admin_socket(
+    mut commands: Commands,
     admin: ResMut<AdminSocketState>,
-    players: Query<(&NetPlayer, &NetHealth)>,
+    players: Query<(&NetPlayer, &NetHealth, Option<&NetScore>)>,
+    player_names: Res<crate::PlayerNames>,
     enemies: Query<&NetEnemy>,
     tick_counter: Res<TickCounter>,
     server_port: Res<crate::ServerPort>,
     mut exit: MessageWriter<AppExit>,
     mut map_reset: ResMut<MapResetRequested>,
+    game_mode: Option<Res<GameMode>>,
+    match_state: Res<MatchState>,
+    tick_config: Res<TickConfig>,
 ) {
     // Accept at most a few connections per tick to avoid stalling the game loop.
     for _ in 0..4 {
@@ -121,7 +132,27 @@ pub fn poll_admin_socket(
                     }
                     _ => None,
                 };
-                handle_request(&admin, &players, &enemies, &tick_counter, &server_port, req)
+                let match_status = game_mode.as_deref().map(|mode| {
+                    match_status_json(
+                        mode,
+                        &match_state,
+                        tick_config.hz.get(),
+                        players
+                            .iter()
+                            .filter_map(|(np, _, score)| Some((np, score?))),
+                    )
+                });
+                handle_request(
+                    &mut commands,
+                    &admin,
+                    &players,
+                    &player_names,
+                    &enemies,
+                    &tick_counter,
+                    &server_port,
+                    match_status,
+                    req,
+                )
             }
             Err(e) => {
                 exit_code = None;
@@ -147,13 +178,16 @@ pub fn poll_admin_socket(
     }
 }
 
-#[allow(clippy::cast_precision_loss)]
+#[allow(clippy::cast_precision_loss, clippy::too_many_arguments)]
 fn handle_request(
+    commands: &mut Commands,
     admin: &AdminSocketState,
-    players: &Query<(&NetPlayer, &NetHealth)>,
+    players: &Query<(&NetPlayer, &NetHealth, Option<&NetScore>)>,
+    player_names: &crate::PlayerNames,
     enemies: &Query<&NetEnemy>,
     tick_counter: &TickCounter,
     server_port: &crate::ServerPort,
+    match_status: Option<serde_json::Value>,
     request: AdminRequest,
 ) -> AdminResponse {
     match request {
@@ -170,6 +204,17 @@ fn handle_request(
             let player_count = players.iter().count();
             let enemy_count = enemies.iter().count();
 
+            let mode_label = match_status
+                .as_ref()
+                .and_then(|m| {
+                    Some(format!(
+                        " | {} ({})",
+                        m["mode"].as_str()?,
+                        m["phase"].as_str()?
+                    ))
+                })
+                .unwrap_or_default();
+
             let data = serde_json::json!({
                 "instance": admin.instance_name,
                 "port": server_port.0,
@@ -179,11 +224,12 @@ fn handle_request(
                 "tick": tick_counter.0.0,
                 "players": player_count,
                 "enemies": enemy_count,
+                "match": match_status,
             });
 
             AdminResponse::success_with_data(
                 format!(
-                    "{} | port {} | {} players | up {hours}h{minutes}m{secs}s",
+                    "{} | port {} | {} players{mode_label} | up {hours}h{minutes}m{secs}s",
                     admin.instance_name, server_port.0, player_count
                 ),
                 data,
@@ -192,12 +238,16 @@ fn handle_request(
 
         AdminRequest::Players => {
             let mut list = Vec::new();
-            for (np, health) in players.iter() {
+            for (np, health, score) in players.iter() {
+                let score = score.copied().unwrap_or_default();
                 list.push(serde_json::json!({
                     "player_id": np.player_id.0,
+                    "name": player_names.0.get(&np.player_id),
                     "state": format!("{:?}", np.state),
                     "health": format!("{}/{}", health.current, health.max),
                     "position": format!("({:.1}, {:.1})", np.position.x, np.position.y),
+                    "kills": score.kills,
+                    "deaths": score.deaths,
                 }));
             }
 
@@ -211,9 +261,13 @@ fn handle_request(
             }
         }
 
-        AdminRequest::Say { .. } => {
-            AdminResponse::error("say: not implemented — no in-game chat system yet")
-        }
+        AdminRequest::Say { message } => match broadcast_server_chat(commands, &message) {
+            Some(text) => {
+                let recipients = players.iter().count();
+                AdminResponse::success(format!("Sent to {recipients} player(s): {text}"))
+            }
+            None => AdminResponse::error("say: message is empty"),
+        },
 
         AdminRequest::Restart => {
             AdminResponse::success("Restart acknowledged. Server exiting (systemd will restart).")
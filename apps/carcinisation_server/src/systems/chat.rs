@@ -0,0 +1,159 @@
+//! Server chat: validate + rate-limit player chat, broadcast to all clients.
+//!
+//! Player lines arrive as `ChatSend` and are rebroadcast as `ChatMessage`.
+//! Operator lines (admin `say`) skip the rate limiter and are sent with
+//! `ChatSender::Server`.
+
+use crate::{ClientMonitor, ClientPlayerId};
+use bevy::prelude::*;
+use bevy_replicon::prelude::*;
+use carcinisation_net::{
+    ChatMessage, ChatSend, ChatSender, PlayerId, Tick, TickCounter, sanitize_chat_text,
+};
+use std::collections::HashMap;
+
+/// Messages a player may send back-to-back before being throttled.
+pub const CHAT_BURST: u32 = 3;
+
+/// Ticks to regain one message of burst allowance (2 s at 30 Hz).
+pub const CHAT_REFILL_TICKS: u32 = 60;
+
+#[derive(Clone, Copy)]
+struct ChatBucket {
+    tokens: u32,
+    last_refill: Tick,
+}
+
+/// Per-player token bucket for chat messages.
+#[derive(Resource, Default)]
+pub struct ChatRateLimiter {
+    buckets: HashMap<PlayerId, ChatBucket>,
+}
+
+impl ChatRateLimiter {
+    /// Spend one message of allowance at `now`. Returns `false` when the
+    /// player is throttled.
+    pub fn try_consume(&mut self, player_id: PlayerId, now: Tick) -> bool {
+        let bucket = self.buckets.entry(player_id).or_insert(ChatBucket {
+            tokens: CHAT_BURST,
+            last_refill: now,
+        });
+        let refills = now.0.wrapping_sub(bucket.last_refill.0) / CHAT_REFILL_TICKS;
+        if refills > 0 {
+            bucket.tokens = bucket.tokens.saturating_add(refills).min(CHAT_BURST);
+            bucket.last_refill = Tick(
+                bucket
+                    .last_refill
+                    .0
+                    .wrapping_add(refills * CHAT_REFILL_TICKS),
+            );
+        }
+        if bucket.tokens == 0 {
+            return false;
+        }
+        bucket.tokens -= 1;
+        true
+    }
+
+    pub fn remove_player(&mut self, player_id: &PlayerId) {
+        self.buckets.remove(player_id);
+    }
+}
+
+/// Broadcast an operator line to every connected client.
+///
+/// Returns the text actually sent, or `None` if nothing printable was left
+/// after sanitising.
+pub fn broadcast_server_chat(commands: &mut Commands, text: &str) -> Option<String> {
+    let text = sanitize_chat_text(text)?;
+    commands.server_trigger(ToClients {
+        mode: SendMode::Broadcast,
+        message: ChatMessage {
+            sender: ChatSender::Server,
+            text: text.clone(),
+        },
+    });
+    Some(text)
+}
+
+/// Receives `ChatSend` from a player, sanitises and rate-limits it, then
+/// rebroadcasts it to every client.
+pub(crate) fn receive_chat_send(
+    trigger: On<FromClient<ChatSend>>,
+    mut commands: Commands,
+    clients: Query<&ClientPlayerId>,
+    monitors: Query<&ClientMonitor>,
+    tick_counter: Res<TickCounter>,
+    mut limiter: ResMut<ChatRateLimiter>,
+) {
+    let from_client = trigger.event();
+    let Some(client_entity) = from_client.client_id.entity() else {
+        return;
+    };
+
+    // Monitors are read-only spectators.
+    if monitors.get(client_entity).is_ok() {
+        return;
+    }
+    let Ok(&ClientPlayerId(player_id)) = clients.get(client_entity) else {
+        warn!("Received chat from unknown client entity: {client_entity:?}");
+        return;
+    };
+
+    let Some(text) = sanitize_chat_text(&from_client.message.text) else {
+        return;
+    };
+    if !limiter.try_consume(player_id, tick_counter.0) {
+        debug!("Chat from {player_id:?} throttled");
+        return;
+    }
+
+    info!("chat {player_id:?}: {text}");
+    commands.server_trigger(ToClients {
+        mode: SendMode::Broadcast,
+        message: ChatMessage {
+            sender: ChatSender::Player(player_id),
+            text,
+        },
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn burst_then_throttle() {
+        let mut limiter = ChatRateLimiter::default();
+        let pid = PlayerId(1);
+        for _ in 0..CHAT_BURST {
+            assert!(limiter.try_consume(pid, Tick(10)));
+        }
+        assert!(!limiter.try_consume(pid, Tick(10)));
+        assert!(!limiter.try_consume(pid, Tick(10 + CHAT_REFILL_TICKS - 1)));
+        assert!(limiter.try_consume(pid, Tick(10 + CHAT_REFILL_TICKS)));
+        assert!(!limiter.try_consume(pid, Tick(10 + CHAT_REFILL_TICKS)));
+    }
+
+    #[test]
+    fn refill_caps_at_burst() {
+        let mut limiter = ChatRateLimiter::default();
+        let pid = PlayerId(1);
+        assert!(limiter.try_consume(pid, Tick(0)));
+        let later = Tick(CHAT_REFILL_TICKS * 100);
+        for _ in 0..CHAT_BURST {
+            assert!(limiter.try_consume(pid, later));
+        }
+        assert!(!limiter.try_consume(pid, later));
+    }
+
+    #[test]
+    fn players_are_limited_independently() {
+        let mut limiter = ChatRateLimiter::default();
+        for _ in 0..CHAT_BURST {
+            assert!(limiter.try_consume(PlayerId(1), Tick(0)));
+        }
+        assert!(!limiter.try_consume(PlayerId(1), Tick(0)));
+        assert!(limiter.try_consume(PlayerId(2), Tick(0)));
+    }
+}
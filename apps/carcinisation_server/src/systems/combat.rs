@@ -1,8 +1,10 @@
 //! Server-authoritative combat system.
 //!
 //! Runs in `FixedUpdate` (`CombatSet`) after movement.
-//! - Pistol (`NetAttackId::None`): hitscan + cooldown
-//! - Flamethrower (`NetAttackId::Projectile`): progressive burn via `BurnState`
+//! - Hitscan weapons (pistol, melee): hitscan + cooldown, tuned by their
+//!   `weapons.ron` entries, lag-compensated against rewound enemy poses (see
+//!   [`super::lag_compensation`])
+//! - Flame weapons (flamethrower): progressive burn via `BurnState`
 
 use crate::ServerMap;
 use bevy::prelude::*;
@@ -13,6 +15,7 @@ use carcinisation_fps_core::collision::{PartId, PartReactionProfile};
 use carcinisation_fps_core::collision_set;
 use carcinisation_fps_core::combat::{FirePose2d, wall_obstruction_distance_for_pose};
 use carcinisation_fps_core::config::FpsCombatConfig;
+use carcinisation_fps_core::enemy::perception::{Noise, NoiseKind};
 use carcinisation_fps_core::enemy::{Enemy, FpsEnemyKind};
 use carcinisation_fps_core::enemy_collision::{
     DEFAULT_ANIMATION, DEFAULT_FRAME, enemy_fallback_radius,
@@ -23,13 +26,15 @@ use carcinisation_fps_core::hitscan::{
     routed_damage,
 };
 use carcinisation_fps_core::raycast::cast_ray;
+use carcinisation_fps_core::weapons::{WeaponFire, WeaponTable};
 use carcinisation_net::{
-    DamageEffect, DeathEffect, FlameActive, FlameCharMark, HitConfirm, MuzzleFlash, NetAttackId,
-    NetBurning, NetGroundFire, NetPlayer, NetProjectile, NetProjectileType, NetworkObjectId,
-    PlayerId,
+    DamageEffect, DeathEffect, FlameActive, FlameCharMark, HitConfirm, MuzzleFlash, NetBurning,
+    NetGroundFire, NetPlayer, NetProjectile, NetProjectileType, NetworkObjectId, PlayerId, Tick,
+    TickCounter,
 };
 use std::collections::HashMap;
 
+use super::lag_compensation::{EnemyPose, LagCompensation, max_rewind_ticks};
 use crate::systems::NetEnemy;
 use crate::systems::NetHealth;
 use crate::systems::{NetEnemyState, NetEnemyType};
@@ -194,6 +199,7 @@ const FLAME_CHAR_EMIT_INTERVAL: f32 = 0.1;
     clippy::too_many_arguments,
     clippy::too_many_lines,
     clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
     clippy::cast_sign_loss,
     clippy::missing_panics_doc
 )]
@@ -223,9 +229,16 @@ pub fn process_combat(
     mut char_cooldowns: ResMut<FlameCharCooldowns>,
     burn_config: Res<BurnConfig>,
     flame_cfg: Res<carcinisation_fps_core::PlayerFlamethrowerConfig>,
-    combat_config: Res<FpsCombatConfig>,
+    (combat_config, weapons, lag_compensation, tick_counter): (
+        Res<FpsCombatConfig>,
+        Res<WeaponTable>,
+        Res<LagCompensation>,
+        Res<TickCounter>,
+    ),
+    mut noises: ResMut<super::perception::PendingNoises>,
 ) {
     let dt = fixed_time.delta_secs();
+    let max_rewind = max_rewind_ticks(combat_config.lag_compensation_max_ms, fixed_time.timestep());
 
     // Tick pistol cooldowns.
     for cd in cooldowns.0.values_mut() {
@@ -279,9 +292,12 @@ pub fn process_combat(
             );
         }
 
-        match player.current_attack {
-            NetAttackId::None | NetAttackId::Melee => {
-                // -- Pistol: hitscan + cooldown --
+        let Some(weapon) = player.current_attack.weapon(&weapons) else {
+            continue;
+        };
+        match weapon.fire {
+            WeaponFire::Hitscan { damage, .. } => {
+                // -- Pistol / melee: hitscan + cooldown --
                 // Stop flame if was active.
                 if send_flame_stop(&mut commands, &mut flame_tracker, player) {
                     flame_updates.insert(player.player_id, false);
@@ -290,12 +306,15 @@ pub fn process_combat(
                 if !firing {
                     continue;
                 }
+                let base_damage = damage as f32;
+                let reach = weapon.range().unwrap_or(f32::MAX);
+                let shot_pose = weapon.spread_pose(fire_pose);
 
                 let cd = cooldowns.0.entry(player.player_id).or_insert(0.0);
                 if *cd > 0.0 {
                     continue;
                 }
-                *cd = combat_config.fire_cooldown_secs;
+                *cd = weapon.cooldown_secs();
 
                 commands.server_trigger(ToClients {
                     mode: SendMode::Broadcast,
@@ -305,6 +324,15 @@ pub fn process_combat(
                         angle: fire_pose.yaw,
                     },
                 });
+                noises.0.push(Noise {
+                    position: fire_pose.origin_xy,
+                    kind: NoiseKind::Gunshot,
+                });
+
+                // Lag compensation: place enemies where this player saw them.
+                // Only poses rewind; health/state below stay live.
+                let rewind = lag_compensation.rewind_ticks(player.player_id, max_rewind);
+                let rewind_tick = Tick(tick_counter.0.0.wrapping_sub(rewind));
 
                 // Rebuild enemy list with fresh health/state (Vecs reuse capacity).
                 enemy_entities.clear();
@@ -318,15 +346,19 @@ pub fn process_combat(
                     {
                         continue;
                     }
+                    // Enemies younger than the rewound tick are tested live.
+                    let pose = (rewind > 0)
+                        .then(|| lag_compensation.pose_at(entity, rewind_tick))
+                        .flatten()
+                        .unwrap_or(EnemyPose {
+                            position: net_enemy.position,
+                            yaw: gameplay_yaw.0,
+                        });
                     enemy_entities.push(entity);
-                    enemy_list.push(Enemy::new(
-                        net_enemy.position,
-                        net_health.current as u32,
-                        0.0,
-                    ));
+                    enemy_list.push(Enemy::new(pose.position, net_health.current as u32, 0.0));
                     // Collision facing uses the authoritative gameplay yaw, NOT
                     // the (future-visual) NetEnemy.angle.
-                    enemy_meta.push((fps_kind_from_net(net_enemy.enemy_type), gameplay_yaw.0));
+                    enemy_meta.push((fps_kind_from_net(net_enemy.enemy_type), pose.yaw));
                 }
 
                 // Per-part hitscan using authoritative collision identity:
@@ -335,7 +367,7 @@ pub fn process_combat(
                 // Frame is DEFAULT_FRAME=0: no discrete enemy animation frame is
                 // replicated yet (see Phase 3.5 limitations / TODO).
                 let part_hit = hitscan_parts_from_pose(
-                    fire_pose,
+                    shot_pose,
                     &server_map.0,
                     enemy_list
                         .iter()
@@ -349,11 +381,11 @@ pub fn process_combat(
                             frame: DEFAULT_FRAME,
                             fallback_radius: enemy_fallback_radius(kind, &combat_config),
                         }),
-                );
+                )
+                .filter(|hit| hit.distance <= reach);
 
                 // Also check hitscan against enemy projectiles (player can shoot them down).
-                let wall_dist =
-                    wall_obstruction_distance_for_pose(&server_map.0, fire_pose, f32::MAX);
+                let wall_dist = wall_obstruction_distance_for_pose(&server_map.0, shot_pose, reach);
                 let mut closest_proj: Option<(
                     Entity,
                     NetworkObjectId,
@@ -362,8 +394,8 @@ pub fn process_combat(
                     NetProjectileType,
                 )> = None;
                 for (proj_entity, proj) in projectiles.iter() {
-                    let to_proj = proj.position - fire_pose.origin_xy;
-                    let along = to_proj.dot(fire_pose.direction());
+                    let to_proj = proj.position - shot_pose.origin_xy;
+                    let along = to_proj.dot(shot_pose.direction());
                     if along <= 0.0 || along > wall_dist {
                         continue;
                     }
@@ -417,7 +449,7 @@ pub fn process_combat(
                 // scaled by the hit part's multiplier (e.g. 2× headshot), then
                 // the part's flat armour subtracted. The client never routes —
                 // it only renders this `HitConfirm`.
-                let dealt = routed_damage(combat_config.hitscan_damage, damage_scale, armour);
+                let dealt = routed_damage(base_damage, damage_scale, armour);
 
                 // Opt-in per-shot routing trace (disabled by default; see
                 // HIT_DEBUG_TARGET). `fallback` = whole-body circle, no authored
@@ -431,38 +463,35 @@ pub fn process_combat(
                     fallback = part_id == PartId::FALLBACK,
                     damage_scale,
                     armour,
-                    base = combat_config.hitscan_damage,
+                    base = base_damage,
                     dealt,
+                    rewind,
                     "fps hit"
                 );
 
                 let hit_entity = enemy_entities[hit_idx];
 
-                // Hit reaction (Phase 12: weapon pistol profile × hit part's
+                // Hit reaction (Phase 12: weapon profile × hit part's
                 // reaction profile): queue on the enemy's sim reaction state
                 // only if the target survives; the shared sim consumes it on its
                 // next tick (knockback via try_move, poise/stun gating). A
                 // neutral part profile reproduces the Phase 11 result exactly.
                 //
-                // TODO(mp-melee): converge server melee with SP before real MP
-                // melee semantics ship. `NetAttackId::Melee` is currently folded
-                // into this pistol branch (same `match` arm above) and is
-                // therefore PISTOL-EQUIVALENT on the server: it uses
-                // `combat_config.fire_cooldown_secs`, `hitscan_damage`, and the
-                // `enemy_reaction.pistol` reaction profile below. This is an
-                // intentional placeholder — there is no distinct server melee
-                // range/damage path yet. By contrast, SP-local melee
-                // (`carcinisation_fps::player_attack`) applies a ×3 base-damage
-                // multiplier and the `enemy_reaction.melee` profile. These two
-                // paths MUST be reconciled (shared melee config + reaction
-                // profile, server-side range/arc) before MP melee is authored,
-                // or SP and server will disagree on melee damage and stagger.
+                // Pistol and melee both resolve through `weapons.ron`, the same
+                // definitions SP-local combat fires, so base damage, range,
+                // cooldown and reaction agree between authorities.
+                // TODO(mp-melee): melee still fires on `fire_held` with no
+                // swing arc, and nothing selects the melee slot yet (it
+                // is outside the switch loadout).
                 let part_reaction = part_hit.map_or(PartReactionProfile::NEUTRAL, |r| r.reaction);
                 let pending = carcinisation_fps_core::PendingHitReaction::from_profiles(
-                    &combat_config.enemy_reaction.pistol,
+                    &weapon.reaction,
                     part_reaction,
-                    fire_pose.direction(),
+                    shot_pose.direction(),
                 );
+                commands
+                    .entity(hit_entity)
+                    .try_insert(super::game_mode::LastHitBy(player.player_id));
                 let target_survived = apply_damage(
                     &mut commands,
                     &mut enemies,
@@ -506,7 +535,7 @@ pub fn process_combat(
                 }
             }
 
-            NetAttackId::Projectile => {
+            WeaponFire::Flame(_) => {
                 // -- Flamethrower: continuous cone damage --
                 if !firing {
                     if send_flame_stop(&mut commands, &mut flame_tracker, player) {
@@ -532,6 +561,10 @@ pub fn process_combat(
                         },
                     });
                 }
+                noises.0.push(Noise {
+                    position: fire_pose.origin_xy,
+                    kind: NoiseKind::Flamethrower,
+                });
 
                 let dir = fire_pose.direction();
 
@@ -560,6 +593,9 @@ pub fn process_combat(
                         };
                         if let Some(part_hit) = strip.hits_target(&server_map.0, target) {
                             flame_exposed_entities.push(entity);
+                            commands
+                                .entity(entity)
+                                .try_insert(super::game_mode::LastHitBy(player.player_id));
                             // Opt-in flame-exposure trace (disabled by default).
                             // Flame is exposure-based: ignores damage_scale/armour.
                             trace!(
@@ -0,0 +1,94 @@
+//! Server-authoritative doors, wall switches and trigger volumes.
+//!
+//! Alive players moving forward push the cell just ahead of them. The shared
+//! `MapMechanisms` on [`ServerMap`] turns pushes and trigger entries into
+//! door movement, so collision and enemy line of sight follow at once. Each
+//! door's state is replicated to clients as a [`NetDoor`].
+
+use bevy::prelude::*;
+use bevy_replicon::prelude::*;
+use carcinisation_fps_core::pushed_cell;
+use carcinisation_net::{NetDoor, NetEnemy, NetPlayer, PlayerNetState};
+
+use super::input::PlayerIntentBuffer;
+use super::reset::MapResetRequested;
+use crate::ServerMap;
+
+/// Spawn one replicated [`NetDoor`] per map door.
+#[allow(clippy::cast_possible_truncation)]
+pub fn spawn_map_doors(mut commands: Commands, server_map: Res<ServerMap>) {
+    for index in 0..server_map.0.mechanisms.doors.len() {
+        commands.spawn((
+            NetDoor {
+                index: index as u16,
+                open: 0.0,
+            },
+            Replicated,
+        ));
+    }
+}
+
+/// Runs in `FixedUpdate` (`MovementSet`) after `tick_player_impulses`, so
+/// pushes see this tick's final player positions.
+/// Applies player pushes and trigger entries, advances doors, and mirrors
+/// door state into [`NetDoor`].
+#[allow(clippy::cast_possible_truncation)]
+pub fn tick_map_mechanisms(
+    fixed_time: Res<Time<Fixed>>,
+    buffer: Res<PlayerIntentBuffer>,
+    mut server_map: ResMut<ServerMap>,
+    players: Query<&NetPlayer>,
+    enemies: Query<&NetEnemy>,
+    mut net_doors: Query<&mut NetDoor>,
+) {
+    if server_map.0.mechanisms.doors.is_empty() {
+        return;
+    }
+
+    let alive: Vec<&NetPlayer> = players
+        .iter()
+        .filter(|p| matches!(p.state, PlayerNetState::Alive))
+        .collect();
+    let positions: Vec<Vec2> = alive.iter().map(|p| p.position).collect();
+    let pushes: Vec<(i32, i32)> = alive
+        .iter()
+        .filter(|p| buffer.peek_movement(&p.player_id).y > 0.0)
+        .map(|p| pushed_cell(p.position, p.angle))
+        .collect();
+    let occupied: Vec<(i32, i32)> = positions
+        .iter()
+        .copied()
+        .chain(enemies.iter().map(|e| e.position))
+        .map(|p| (p.x.floor() as i32, p.y.floor() as i32))
+        .collect();
+
+    let mechanisms = &mut server_map.0.mechanisms;
+    mechanisms.apply_players(&positions, &pushes);
+    if !mechanisms.tick(fixed_time.delta_secs(), |x, y| occupied.contains(&(x, y))) {
+        return;
+    }
+    for mut net_door in &mut net_doors {
+        let Some(door) = mechanisms.doors.get(usize::from(net_door.index)) else {
+            continue;
+        };
+        if (net_door.open - door.open).abs() > f32::EPSILON {
+            net_door.open = door.open;
+        }
+    }
+}
+
+/// Close every door and re-arm switches and triggers when a map reset is
+/// pending. Runs before `handle_map_reset`, which clears the request.
+pub fn reset_map_mechanisms(
+    reset: Res<MapResetRequested>,
+    mut server_map: ResMut<ServerMap>,
+    mut net_doors: Query<&mut NetDoor>,
+) {
+    if !reset.0 {
+        return;
+    }
+    server_map.0.mechanisms.reset();
+    for mut net_door in &mut net_doors {
+        net_door.open = 0.0;
+    }
+}
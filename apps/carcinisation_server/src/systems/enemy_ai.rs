@@ -6,7 +6,8 @@
 use crate::ServerMap;
 use bevy::prelude::*;
 use carcinisation_fps_core::{
-    EnemyPlayerTarget, EnemySim, FpsEnemyAiState, FpsEnemyKind, MosquitonAiConfig, tick_enemy_ai,
+    EnemyNavigation, EnemyPlayerTarget, EnemySim, FpsEnemyAiState, FpsEnemyKind, MosquitonAiConfig,
+    tick_enemy_ai,
 };
 use carcinisation_net::{
     NetEnemy, NetEnemyState, NetEnemyType, NetHealth, NetPlayer, PlayerNetState,
@@ -32,6 +33,37 @@ impl ServerEnemyAiConfig {
     }
 }
 
+/// Shared enemy pathfinding: the navigation grid plus one flow field per
+/// cell an alive player stands in. Read by every enemy sim this tick.
+#[derive(Resource, Default)]
+pub struct ServerEnemyNavigation(pub EnemyNavigation);
+
+/// Runs in `FixedUpdate` (`EnemyAiSet`) before [`tick_net_enemy_ai`].
+/// Living enemies weigh on the grid so crowds spread over alternate routes.
+pub fn update_enemy_navigation(
+    mut navigation: ResMut<ServerEnemyNavigation>,
+    server_map: Res<ServerMap>,
+    players: Query<&NetPlayer>,
+    enemies: Query<&NetEnemy>,
+) {
+    let goals: Vec<Vec2> = players
+        .iter()
+        .filter(|p| matches!(p.state, PlayerNetState::Alive))
+        .map(|p| p.position)
+        .collect();
+    let occupants: Vec<Vec2> = enemies
+        .iter()
+        .filter(|e| {
+            !matches!(
+                e.state,
+                NetEnemyState::Dying { .. } | NetEnemyState::Dead { .. }
+            )
+        })
+        .map(|e| e.position)
+        .collect();
+    navigation.0.update(&server_map.0, &occupants, &goals);
+}
+
 #[allow(clippy::type_complexity)]
 pub fn tick_net_enemy_ai(
     mut enemies: Query<
@@ -45,6 +77,7 @@ pub fn tick_net_enemy_ai(
     >,
     players: Query<&NetPlayer>,
     server_map: Res<ServerMap>,
+    navigation: Res<ServerEnemyNavigation>,
     fixed_time: Res<Time<Fixed>>,
 ) {
     let target_sims: Vec<EnemyPlayerTarget> = players
@@ -76,6 +109,7 @@ pub fn tick_net_enemy_ai(
             &mut sim,
             &target_sims,
             &server_map.0,
+            Some(&navigation.0),
             fixed_time.delta_secs(),
             ai_config.0,
         );
@@ -25,6 +25,8 @@ use crate::ServerMap;
 
 use super::NetProjectile;
 use super::combat::EnemyGameplayYaw;
+use super::enemy_ai::ServerEnemyNavigation;
+use super::perception::{ServerEnemyPerception, is_unaware};
 
 /// Per-enemy Mosquiton simulation state, attached at spawn time.
 ///
@@ -178,19 +180,28 @@ pub fn tick_enemy_attacks(
         &mut ServerMosquitonSim,
         &ServerMosquitonSimConfig,
         &mut EnemyGameplayYaw,
+        Option<&ServerEnemyPerception>,
     )>,
     players: Query<&NetPlayer>,
     mut player_query: Query<(&NetPlayer, &mut NetHealth), Without<NetEnemy>>,
     fixed_time: Res<Time<Fixed>>,
     mut next_id: ResMut<NextProjectileId>,
     server_map: Res<ServerMap>,
+    navigation: Res<ServerEnemyNavigation>,
     combat_config: Res<FpsCombatConfig>,
 ) {
     let dt = fixed_time.delta_secs();
     let mut melee_hits: Vec<MeleeHit> = Vec::new();
 
-    for (enemy_entity, mut enemy, health, mut mosquiton_sim, sim_config, mut gameplay_yaw) in
-        &mut enemies
+    for (
+        enemy_entity,
+        mut enemy,
+        health,
+        mut mosquiton_sim,
+        sim_config,
+        mut gameplay_yaw,
+        perception,
+    ) in &mut enemies
     {
         if enemy.enemy_type != NetEnemyType::Mosquiton {
             continue;
@@ -205,6 +216,10 @@ pub fn tick_enemy_attacks(
         {
             continue;
         }
+        // Not engaged yet: `tick_enemy_perception` moved it this tick.
+        if is_unaware(perception) {
+            continue;
+        }
 
         // Find nearest alive player per-enemy (not shared).
         let Some(player_pos) = nearest_alive_player_pos(enemy.position, &players) else {
@@ -223,7 +238,14 @@ pub fn tick_enemy_attacks(
             reaction: mosquiton_sim.reaction,
         };
 
-        let output = tick_mosquiton_sim(&mut sim, &sim_config.0, player_pos, &server_map.0, dt);
+        let output = tick_mosquiton_sim(
+            &mut sim,
+            &sim_config.0,
+            player_pos,
+            &server_map.0,
+            Some(&navigation.0),
+            dt,
+        );
 
         // Write back sim state.
         enemy.position = sim.position;
@@ -468,19 +490,28 @@ pub fn tick_spidey_attacks(
         &mut ServerSpideySim,
         &ServerSpideySimConfig,
         &mut EnemyGameplayYaw,
+        Option<&ServerEnemyPerception>,
     )>,
     mut player_query: Query<(Entity, &mut NetPlayer, &mut NetHealth), Without<NetEnemy>>,
     fixed_time: Res<Time<Fixed>>,
     mut next_id: ResMut<NextProjectileId>,
     server_map: Res<ServerMap>,
+    navigation: Res<ServerEnemyNavigation>,
     combat_config: Res<FpsCombatConfig>,
 ) {
     let dt = fixed_time.delta_secs();
     let mut melee_hits: Vec<MeleeHit> = Vec::new();
     let mut lunge_impulse_hits: Vec<LungeHit> = Vec::new();
 
-    for (enemy_entity, mut enemy, health, mut spidey_sim, sim_config, mut gameplay_yaw) in
-        &mut enemies
+    for (
+        enemy_entity,
+        mut enemy,
+        health,
+        mut spidey_sim,
+        sim_config,
+        mut gameplay_yaw,
+        perception,
+    ) in &mut enemies
     {
         if enemy.enemy_type != NetEnemyType::Spidey {
             continue;
@@ -495,6 +526,10 @@ pub fn tick_spidey_attacks(
         {
             continue;
         }
+        // Not engaged yet: `tick_enemy_perception` moved it this tick.
+        if is_unaware(perception) {
+            continue;
+        }
 
         // Find nearest alive player per-enemy.
         let Some(player_pos) = player_query
@@ -518,7 +553,14 @@ pub fn tick_spidey_attacks(
             reaction: spidey_sim.reaction,
         };
 
-        let output = tick_spidey_sim(&mut sim, &sim_config.0, player_pos, &server_map.0, dt);
+        let output = tick_spidey_sim(
+            &mut sim,
+            &sim_config.0,
+            player_pos,
+            &server_map.0,
+            Some(&navigation.0),
+            dt,
+        );
 
         // Write back sim state.
         enemy.position = sim.position;
@@ -711,6 +753,7 @@ fn apply_lunge_displacement(
         &mut ServerSpideySim,
         &ServerSpideySimConfig,
         &mut EnemyGameplayYaw,
+        Option<&ServerEnemyPerception>,
     )>,
     combat_config: &FpsCombatConfig,
     server_map: &ServerMap,
@@ -759,7 +802,7 @@ fn apply_lunge_displacement(
         }
 
         // --- Spidey recoil: opposite of push direction ---
-        if let Ok((_, mut enemy, _, _, _, _)) = enemies.get_mut(hit.source_entity) {
+        if let Ok((_, mut enemy, _, _, _, _, _)) = enemies.get_mut(hit.source_entity) {
             let displacement = -push_dir * spidey_cfg.lunge_spidey_recoil_distance;
             try_move(
                 &mut enemy.position,
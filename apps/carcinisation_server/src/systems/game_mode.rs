@@ -0,0 +1,331 @@
+//! Game-mode rules: kill scoring, round clock, intermission and restarts.
+//!
+//! Only active when a [`GameMode`] resource is installed (from `--mode` or
+//! the map's `game_mode` block). Without it the server keeps running the
+//! open-ended free-for-all.
+//!
+//! Round flow: `WaitingForPlayers` → (first player joins) → `InProgress` →
+//! (end condition) → `Intermission` → map reset via [`MapResetRequested`] →
+//! `InProgress`. Scores reset when a whole match ends; co-op wave breaks keep
+//! them.
+//!
+//! There is no player-vs-player damage, so deathmatch kills are enemy kills:
+//! players race each other for frags on the shared enemy population.
+
+use bevy::prelude::*;
+use carcinisation_fps_core::game_mode::{
+    GameModeConfig, GameModeKind, RoundOutcome, RoundProgress, round_outcome,
+};
+use carcinisation_net::{
+    NetEnemyState, NetHealth, NetMatchPhase, NetMatchState, NetPlayer, NetScore, PlayerId,
+    TickConfig,
+};
+
+use super::NetEnemy;
+use super::chat::broadcast_server_chat;
+use super::combat::EnemyDeathTimer;
+use super::player_lifecycle::RespawnTimer;
+use super::reset::MapResetRequested;
+
+/// Active match rules. Insert to enable game-mode handling.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct GameMode(pub GameModeConfig);
+
+/// Server-only kill credit: the last player whose hitscan or flame touched
+/// this enemy.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct LastHitBy(pub PlayerId);
+
+/// Server-side round phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchPhase {
+    WaitingForPlayers,
+    InProgress,
+    Intermission(RoundOutcome),
+}
+
+/// Server-side match bookkeeping. Mirrored to clients through the replicated
+/// [`NetMatchState`] entity.
+#[derive(Resource, Debug, Clone)]
+pub struct MatchState {
+    pub phase: MatchPhase,
+    /// Co-op wave, 1-based.
+    pub wave: u32,
+    /// Fixed ticks spent in the current phase.
+    pub phase_ticks: u32,
+}
+
+impl Default for MatchState {
+    fn default() -> Self {
+        Self {
+            phase: MatchPhase::WaitingForPlayers,
+            wave: 1,
+            phase_ticks: 0,
+        }
+    }
+}
+
+impl MatchState {
+    fn enter(&mut self, phase: MatchPhase) {
+        self.phase = phase;
+        self.phase_ticks = 0;
+    }
+
+    /// Whole seconds left on the round clock or intermission, if running.
+    #[must_use]
+    pub fn remaining_secs(&self, config: &GameModeConfig, tick_hz: u32) -> Option<u32> {
+        let limit_secs = match self.phase {
+            MatchPhase::WaitingForPlayers => return None,
+            MatchPhase::InProgress if config.time_limit_secs == 0 => return None,
+            MatchPhase::InProgress => config.time_limit_secs,
+            MatchPhase::Intermission(_) => config.intermission_secs,
+        };
+        let elapsed_secs = self.phase_ticks / tick_hz.max(1);
+        Some(limit_secs.saturating_sub(elapsed_secs))
+    }
+
+    fn net_state(&self, config: &GameModeConfig, tick_hz: u32) -> NetMatchState {
+        NetMatchState {
+            kind: config.kind,
+            phase: match self.phase {
+                MatchPhase::WaitingForPlayers => NetMatchPhase::WaitingForPlayers,
+                MatchPhase::InProgress => NetMatchPhase::InProgress,
+                MatchPhase::Intermission(outcome) => NetMatchPhase::Intermission { outcome },
+            },
+            wave: self.wave,
+            remaining_secs: self.remaining_secs(config, tick_hz),
+        }
+    }
+}
+
+/// Spawn the replicated match state entity.
+pub fn spawn_match_state(
+    mut commands: Commands,
+    mode: Res<GameMode>,
+    state: Res<MatchState>,
+    tick_config: Res<TickConfig>,
+) {
+    commands.spawn((
+        state.net_state(&mode.0, tick_config.hz.get()),
+        bevy_replicon::prelude::Replicated,
+        Name::new("MatchState"),
+    ));
+    info!("Game mode: {:?}", mode.0);
+}
+
+/// Credit kills to `LastHitBy` and count player deaths. Runs in `CombatSet`
+/// after every system that can kill an enemy or a player.
+pub fn score_kills_and_deaths(
+    state: Res<MatchState>,
+    killed_enemies: Query<Option<&LastHitBy>, Added<EnemyDeathTimer>>,
+    killed_players: Query<&NetPlayer, Added<RespawnTimer>>,
+    mut scores: Query<(&NetPlayer, &mut NetScore)>,
+) {
+    if state.phase != MatchPhase::InProgress {
+        return;
+    }
+    for last_hit in &killed_enemies {
+        let Some(&LastHitBy(killer)) = last_hit else {
+            continue;
+        };
+        if let Some((_, mut score)) = scores.iter_mut().find(|(p, _)| p.player_id == killer) {
+            score.kills += 1;
+        }
+    }
+    for victim in &killed_players {
+        if let Some((_, mut score)) = scores
+            .iter_mut()
+            .find(|(p, _)| p.player_id == victim.player_id)
+        {
+            score.deaths += 1;
+        }
+    }
+}
+
+/// Advance the round: start, end-condition checks, intermission, restart.
+#[allow(clippy::too_many_arguments)]
+pub fn tick_match(
+    mut commands: Commands,
+    mode: Res<GameMode>,
+    mut state: ResMut<MatchState>,
+    mut reset: ResMut<MapResetRequested>,
+    tick_config: Res<TickConfig>,
+    mut scores: Query<(&NetPlayer, &mut NetScore)>,
+    enemies: Query<(&NetEnemy, &NetHealth)>,
+    mut net_state: Query<&mut NetMatchState>,
+) {
+    let config = mode.0;
+    let tick_hz = tick_config.hz.get();
+    let player_count = scores.iter().count();
+
+    if player_count == 0 {
+        if state.phase != MatchPhase::WaitingForPlayers {
+            info!("All players left; match paused");
+            state.enter(MatchPhase::WaitingForPlayers);
+        }
+    } else {
+        state.phase_ticks = state.phase_ticks.saturating_add(1);
+        match state.phase {
+            MatchPhase::WaitingForPlayers => {
+                start_match(&mut commands, &config, &mut state, &mut reset, &mut scores);
+            }
+            MatchPhase::InProgress => {
+                let progress = round_progress(&state, tick_hz, &scores, &enemies);
+                if let Some(outcome) = round_outcome(&config, &progress) {
+                    announce_outcome(&mut commands, outcome);
+                    state.enter(MatchPhase::Intermission(outcome));
+                }
+            }
+            MatchPhase::Intermission(outcome) => {
+                if state.phase_ticks >= config.intermission_secs * tick_hz {
+                    if outcome.ends_match() {
+                        start_match(&mut commands, &config, &mut state, &mut reset, &mut scores);
+                    } else {
+                        state.wave += 1;
+                        reset.0 = true;
+                        broadcast_server_chat(&mut commands, &format!("Wave {}", state.wave));
+                        state.enter(MatchPhase::InProgress);
+                    }
+                }
+            }
+        }
+    }
+
+    let next = state.net_state(&config, tick_hz);
+    for mut replicated in &mut net_state {
+        replicated.set_if_neq(next.clone());
+    }
+}
+
+fn start_match(
+    commands: &mut Commands,
+    config: &GameModeConfig,
+    state: &mut MatchState,
+    reset: &mut MapResetRequested,
+    scores: &mut Query<(&NetPlayer, &mut NetScore)>,
+) {
+    for (_, mut score) in scores.iter_mut() {
+        score.set_if_neq(NetScore::default());
+    }
+    state.wave = 1;
+    state.enter(MatchPhase::InProgress);
+    reset.0 = true;
+
+    let banner = match config.kind {
+        GameModeKind::Deathmatch if config.frag_limit > 0 => {
+            format!("Deathmatch: first to {} kills", config.frag_limit)
+        }
+        GameModeKind::Deathmatch => "Deathmatch".to_string(),
+        GameModeKind::Coop => format!("Co-op: clear {} waves", config.waves.max(1)),
+    };
+    info!("Match started: {banner}");
+    broadcast_server_chat(commands, &banner);
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn round_progress(
+    state: &MatchState,
+    tick_hz: u32,
+    scores: &Query<(&NetPlayer, &mut NetScore)>,
+    enemies: &Query<(&NetEnemy, &NetHealth)>,
+) -> RoundProgress {
+    let leader = scores
+        .iter()
+        .map(|(p, s)| (p.player_id.0, s.kills))
+        .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)));
+    let enemies_alive = enemies
+        .iter()
+        .filter(|(e, h)| {
+            h.current > 0.0
+                && !matches!(
+                    e.state,
+                    NetEnemyState::Dying { .. } | NetEnemyState::Dead { .. }
+                )
+        })
+        .count();
+    RoundProgress {
+        elapsed_secs: state.phase_ticks as f32 / tick_hz.max(1) as f32,
+        leader,
+        wave: state.wave,
+        enemies_total: enemies.iter().count(),
+        enemies_alive,
+    }
+}
+
+fn announce_outcome(commands: &mut Commands, outcome: RoundOutcome) {
+    let text = match outcome {
+        RoundOutcome::FragLimit { winner } => format!("P{winner} wins the round"),
+        RoundOutcome::TimeLimit => "Time limit reached".to_string(),
+        RoundOutcome::WaveCleared { wave } => format!("Wave {wave} cleared"),
+        RoundOutcome::AllWavesCleared => "All waves cleared. Well played".to_string(),
+    };
+    info!("Round over: {outcome:?}");
+    broadcast_server_chat(commands, &text);
+}
+
+/// Match summary for the admin `status` command.
+#[must_use]
+pub fn match_status_json<'a>(
+    mode: &GameMode,
+    state: &MatchState,
+    tick_hz: u32,
+    scores: impl Iterator<Item = (&'a NetPlayer, &'a NetScore)>,
+) -> serde_json::Value {
+    let mut scoreboard: Vec<_> = scores
+        .map(|(p, s)| (p.player_id.0, s.kills, s.deaths))
+        .collect();
+    scoreboard.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    let phase = match state.phase {
+        MatchPhase::WaitingForPlayers => "waiting_for_players".to_string(),
+        MatchPhase::InProgress => "in_progress".to_string(),
+        MatchPhase::Intermission(outcome) => format!("intermission ({outcome:?})"),
+    };
+    serde_json::json!({
+        "mode": format!("{:?}", mode.0.kind),
+        "phase": phase,
+        "wave": state.wave,
+        "remaining_seconds": state.remaining_secs(&mode.0, tick_hz),
+        "frag_limit": mode.0.frag_limit,
+        "time_limit_seconds": mode.0.time_limit_secs,
+        "scores": scoreboard
+            .iter()
+            .map(|(id, kills, deaths)| serde_json::json!({
+                "player_id": id,
+                "kills": kills,
+                "deaths": deaths,
+            }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remaining_secs_counts_down_whole_seconds() {
+        let config = GameModeConfig {
+            time_limit_secs: 60,
+            intermission_secs: 5,
+            ..GameModeConfig::new(GameModeKind::Deathmatch)
+        };
+        let mut state = MatchState::default();
+        assert_eq!(state.remaining_secs(&config, 30), None);
+
+        state.enter(MatchPhase::InProgress);
+        state.phase_ticks = 45;
+        assert_eq!(state.remaining_secs(&config, 30), Some(59));
+
+        state.enter(MatchPhase::Intermission(RoundOutcome::TimeLimit));
+        state.phase_ticks = 30 * 10;
+        assert_eq!(state.remaining_secs(&config, 30), Some(0));
+    }
+
+    #[test]
+    fn untimed_rounds_have_no_clock() {
+        let config = GameModeConfig::new(GameModeKind::Coop);
+        let mut state = MatchState::default();
+        state.enter(MatchPhase::InProgress);
+        assert_eq!(state.remaining_secs(&config, 30), None);
+    }
+}
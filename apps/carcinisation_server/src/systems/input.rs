@@ -1,5 +1,7 @@
 //! Server input handling: receive semantic intent → buffer → apply in `FixedUpdate`.
 
+use super::lag_compensation::LagCompensation;
+use super::replay::{ReplayEvent, ReplayRecorder};
 use crate::{ClientMonitor, ClientPlayerId, ServerMap};
 use bevy::prelude::*;
 use bevy_replicon::prelude::*;
@@ -138,6 +140,15 @@ impl PlayerIntentBuffer {
             .is_some_and(|e| e.fire_held)
     }
 
+    /// Peek continuous movement without aging. Zero if stale or missing.
+    #[must_use]
+    pub fn peek_movement(&self, pid: &PlayerId) -> Vec2 {
+        self.entries
+            .get(pid)
+            .filter(|e| e.age_ticks < STALE_INPUT_TICKS)
+            .map_or(Vec2::ZERO, |e| e.movement)
+    }
+
     /// Peek `aim_held` without aging.
     #[must_use]
     pub fn peek_aim_held(&self, pid: &PlayerId) -> bool {
@@ -164,6 +175,9 @@ pub(crate) fn receive_client_intent(
     monitors: Query<&ClientMonitor>,
     mut tracker: ResMut<PlayerInputTracker>,
     mut buffer: ResMut<PlayerIntentBuffer>,
+    mut lag_compensation: ResMut<LagCompensation>,
+    tick_counter: Res<TickCounter>,
+    mut recorder: Option<ResMut<ReplayRecorder>>,
 ) {
     let from_client = trigger.event();
     let Some(client_entity) = from_client.client_id.entity() else {
@@ -213,6 +227,21 @@ pub(crate) fn receive_client_intent(
     validated.turn = validated.turn.clamp(-1.0, 1.0);
 
     buffer.set(player_id, &validated);
+    let latency =
+        lag_compensation.observe_echo(player_id, validated.acked_sequence, tick_counter.0);
+    if let Some(recorder) = recorder.as_mut() {
+        recorder.record_event(
+            tick_counter.0,
+            ReplayEvent::Intent {
+                player_id,
+                intent: validated,
+            },
+        );
+        // Replays have no acks to echo; record the estimate itself.
+        if let Some(ticks) = latency {
+            recorder.record_event(tick_counter.0, ReplayEvent::Latency { player_id, ticks });
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -265,6 +294,20 @@ impl ServerQuickTurn {
 
 use carcinisation_fps_core::SnapTurnKind;
 
+/// The loadout weapon a `WEAPON_SWITCH` selects after `current`. `None`
+/// (an empty loadout) leaves the weapon unchanged.
+fn next_switchable_weapon(
+    weapons: &carcinisation_fps_core::WeaponTable,
+    current: carcinisation_net::NetAttackId,
+) -> Option<carcinisation_net::NetAttackId> {
+    let current_id = current
+        .weapon(weapons)
+        .map_or("", |weapon| weapon.id.as_str());
+    weapons
+        .next_in_loadout(current_id)
+        .and_then(|id| carcinisation_net::NetAttackId::of(weapons, id))
+}
+
 /// Runs in `FixedUpdate` (`MovementSet`). Reads latest buffered intent
 /// for each player and applies server-authoritative movement + turning.
 pub fn apply_buffered_movement(
@@ -280,6 +323,7 @@ pub fn apply_buffered_movement(
     fixed_time: Res<Time<Fixed>>,
     movement_config: Res<FpsMovementConfig>,
     combat_config: Res<carcinisation_fps_core::FpsCombatConfig>,
+    weapons: Res<carcinisation_fps_core::WeaponTable>,
 ) {
     let dt = fixed_time.delta_secs();
     let aim_mode = matches!(
@@ -313,14 +357,11 @@ pub fn apply_buffered_movement(
         }
 
         // Process one-shot actions (edge-triggered).
-        // TODO: This toggle is safe only for exactly two weapons. Before adding
-        // 3+ weapons, the intent must carry a target weapon or deterministic
-        // cycle index instead of blindly toggling.
-        if actions.has(PlayerActions::WEAPON_SWITCH) {
-            player.current_attack = match player.current_attack {
-                carcinisation_net::NetAttackId::None => carcinisation_net::NetAttackId::Projectile,
-                _ => carcinisation_net::NetAttackId::None,
-            };
+        // A switch advances through the weapon table's loadout.
+        if actions.has(PlayerActions::WEAPON_SWITCH)
+            && let Some(next) = next_switchable_weapon(&weapons, player.current_attack)
+        {
+            player.current_attack = next;
         }
         if actions.has(PlayerActions::QUICK_TURN) {
             snap_turn.request(
@@ -414,6 +455,9 @@ pub fn apply_buffered_movement(
 ///
 /// When none of these hold (idle player, no snap, no position change), no
 /// ack is sent.
+///
+/// Each newly acked sequence is logged in [`LagCompensation`] so the echo in
+/// later intents measures the player's round trip.
 #[allow(clippy::implicit_hasher, clippy::too_many_arguments)]
 pub fn send_input_acks(
     mut commands: Commands,
@@ -425,6 +469,7 @@ pub fn send_input_acks(
     )>,
     tracker: Res<PlayerInputTracker>,
     tick_counter: Res<TickCounter>,
+    mut lag_compensation: ResMut<LagCompensation>,
     mut last_acked: Local<HashMap<PlayerId, u32>>,
     mut had_snap: Local<HashMap<PlayerId, bool>>,
     mut last_acked_pos: Local<HashMap<PlayerId, Vec2>>,
@@ -455,6 +500,9 @@ pub fn send_input_acks(
             continue;
         }
 
+        if seq_changed {
+            lag_compensation.record_ack(player.player_id, InputSequence(seq), tick_counter.0);
+        }
         last_acked.insert(player.player_id, seq);
         last_acked_pos.insert(player.player_id, player.position);
         commands.server_trigger(ToClients {
@@ -489,6 +537,7 @@ mod tests {
     fn make_intent(movement: Vec2, turn: f32, fire: bool, actions: PlayerActions) -> ClientIntent {
         ClientIntent {
             sequence: InputSequence(1),
+            acked_sequence: InputSequence(0),
             movement,
             turn,
             fire_held: fire,
@@ -505,6 +554,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn weapon_switch_cycles_the_table_loadout() {
+        use carcinisation_net::NetAttackId;
+        let mut weapons = carcinisation_fps_core::WeaponTable::default();
+        let slot = |weapons: &_, id| NetAttackId::of(weapons, id).unwrap();
+        let next = |weapons: &_, current| next_switchable_weapon(weapons, current);
+        let (pistol, flamethrower) = (slot(&weapons, "pistol"), slot(&weapons, "flamethrower"));
+        assert_eq!(next(&weapons, pistol), Some(flamethrower));
+        assert_eq!(next(&weapons, flamethrower), Some(pistol));
+        // Melee is not in the loadout: switching lands on the first entry.
+        assert_eq!(next(&weapons, slot(&weapons, "melee")), Some(flamethrower));
+
+        // A new table entry is selectable as soon as the loadout lists it.
+        let mut railgun = weapons.get("pistol").unwrap().clone();
+        railgun.id = "railgun".into();
+        weapons.weapons.push(railgun);
+        weapons.loadout.push("railgun".into());
+        assert_eq!(next(&weapons, pistol), Some(slot(&weapons, "railgun")));
+    }
+
     #[test]
     fn buffer_stores_and_returns_continuous_state() {
         let mut buf = PlayerIntentBuffer::default();
@@ -674,6 +743,7 @@ mod tests {
         // Simulate what receive_client_intent does.
         let mut intent = ClientIntent {
             sequence: InputSequence(1),
+            acked_sequence: InputSequence(0),
             movement: Vec2::new(5.0, 5.0),
             turn: 3.0,
             fire_held: false,
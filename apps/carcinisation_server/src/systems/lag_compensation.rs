@@ -0,0 +1,253 @@
+//! Server-side lag compensation for hitscan.
+//!
+//! Clients draw remote enemies from replicated snapshots that are already
+//! about a round trip old when a shot reaches the server, and
+//! `RemotePositionInterpolation` lerps them one more snapshot behind. Resolving
+//! hitscan against live positions makes fast movers such as Mosquitons hard to
+//! hit at real latency.
+//!
+//! [`LagCompensation`] keeps a short per-tick history of enemy collision poses
+//! (position + gameplay yaw, which selects the part fixture facing) and a
+//! per-player latency estimate:
+//!
+//! 1. `send_input_acks` logs the tick each `InputAck` sequence was sent on.
+//! 2. The client echoes the newest ack it has seen in
+//!    `ClientIntent::acked_sequence`; `now - ack tick` is one round trip.
+//! 3. The estimate is the minimum over recent samples, so an echo that sat
+//!    unanswered while the player was idle does not inflate it.
+//!
+//! `process_combat` rewinds enemy poses by the estimate plus
+//! [`INTERPOLATION_DELAY_TICKS`], capped by
+//! `FpsCombatConfig::lag_compensation_max_ms`. Only where enemies *were* is
+//! rewound: health and death state, walls, projectiles and the shooter stay
+//! live.
+
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+
+use bevy::prelude::*;
+use carcinisation_net::tick::InputSequence;
+use carcinisation_net::{PlayerId, Tick, TickCounter};
+
+use super::NetEnemy;
+use super::combat::EnemyGameplayYaw;
+
+/// Ticks of enemy history kept (1 s at 30 Hz). Caps above this are clamped.
+pub const ENEMY_HISTORY_TICKS: u32 = 30;
+
+/// Extra ticks clients draw remote enemies behind the newest snapshot.
+pub const INTERPOLATION_DELAY_TICKS: u32 = 1;
+
+/// Sent acks remembered per player, waiting to be echoed back.
+const ACK_LOG_LEN: usize = 64;
+
+/// Echo round trips kept per player for the latency estimate.
+const LATENCY_SAMPLES: usize = 16;
+
+/// Enemy collision pose at the end of one tick.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EnemyPose {
+    pub position: Vec2,
+    pub yaw: f32,
+}
+
+#[derive(Default)]
+struct PlayerLatency {
+    sent_acks: VecDeque<(InputSequence, Tick)>,
+    samples: VecDeque<u32>,
+    latency_ticks: Option<u32>,
+}
+
+/// Enemy pose history and per-player latency for hitscan rewind.
+#[derive(Resource, Default)]
+pub struct LagCompensation {
+    history: VecDeque<(Tick, HashMap<Entity, EnemyPose>)>,
+    players: HashMap<PlayerId, PlayerLatency>,
+}
+
+impl LagCompensation {
+    /// Log that an ack for `sequence` went out on `tick`. Repeat acks of the
+    /// same sequence keep the first tick.
+    pub fn record_ack(&mut self, player_id: PlayerId, sequence: InputSequence, tick: Tick) {
+        let acks = &mut self.players.entry(player_id).or_default().sent_acks;
+        if acks.back().is_some_and(|&(seq, _)| seq == sequence) {
+            return;
+        }
+        if acks.len() == ACK_LOG_LEN {
+            acks.pop_front();
+        }
+        acks.push_back((sequence, tick));
+    }
+
+    /// Take an echoed ack as a round-trip sample. Returns the new latency
+    /// estimate when it changed.
+    pub fn observe_echo(
+        &mut self,
+        player_id: PlayerId,
+        acked: InputSequence,
+        now: Tick,
+    ) -> Option<u32> {
+        let player = self.players.get_mut(&player_id)?;
+        let &(_, sent) = player.sent_acks.iter().find(|&&(seq, _)| seq == acked)?;
+        if player.samples.len() == LATENCY_SAMPLES {
+            player.samples.pop_front();
+        }
+        player.samples.push_back(now.0.wrapping_sub(sent.0));
+        let latency = player.samples.iter().copied().min();
+        if latency == player.latency_ticks {
+            return None;
+        }
+        player.latency_ticks = latency;
+        latency
+    }
+
+    /// Override a player's latency estimate (replays).
+    pub fn set_latency(&mut self, player_id: PlayerId, ticks: u32) {
+        self.players.entry(player_id).or_default().latency_ticks = Some(ticks);
+    }
+
+    #[must_use]
+    pub fn latency_ticks(&self, player_id: PlayerId) -> Option<u32> {
+        self.players.get(&player_id)?.latency_ticks
+    }
+
+    /// How many ticks to rewind this player's shots, at most `max_ticks`.
+    /// Zero until the player has echoed an ack.
+    #[must_use]
+    pub fn rewind_ticks(&self, player_id: PlayerId, max_ticks: u32) -> u32 {
+        self.latency_ticks(player_id).map_or(0, |latency| {
+            (latency + INTERPOLATION_DELAY_TICKS).min(max_ticks)
+        })
+    }
+
+    /// Store enemy poses at the end of `tick`, dropping the oldest frame.
+    pub fn record_enemies(
+        &mut self,
+        tick: Tick,
+        poses: impl IntoIterator<Item = (Entity, EnemyPose)>,
+    ) {
+        if self.history.len() == ENEMY_HISTORY_TICKS as usize {
+            self.history.pop_front();
+        }
+        self.history.push_back((tick, poses.into_iter().collect()));
+    }
+
+    /// Pose of `entity` at the end of `tick`, if still in the history.
+    #[must_use]
+    pub fn pose_at(&self, entity: Entity, tick: Tick) -> Option<EnemyPose> {
+        self.history
+            .iter()
+            .rev()
+            .find(|(t, _)| *t == tick)
+            .and_then(|(_, poses)| poses.get(&entity))
+            .copied()
+    }
+
+    /// Forget all enemy poses (the map was reset; entities are gone).
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
+
+    pub fn remove_player(&mut self, player_id: &PlayerId) {
+        self.players.remove(player_id);
+    }
+}
+
+/// Convert the configured cap to whole ticks, clamped to the history length.
+#[must_use]
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+pub fn max_rewind_ticks(max_ms: u32, timestep: Duration) -> u32 {
+    let ticks = (max_ms as f32 / 1000.0 / timestep.as_secs_f32()).round() as u32;
+    ticks.min(ENEMY_HISTORY_TICKS - 1)
+}
+
+/// Runs in `FixedUpdate` (`TickSet`) before `increment_tick`, so each frame
+/// is tagged with the tick whose end state it holds.
+pub fn record_enemy_history(
+    mut lag_compensation: ResMut<LagCompensation>,
+    tick_counter: Res<TickCounter>,
+    enemies: Query<(Entity, &NetEnemy, &EnemyGameplayYaw)>,
+) {
+    lag_compensation.record_enemies(
+        tick_counter.0,
+        enemies.iter().map(|(entity, enemy, yaw)| {
+            (
+                entity,
+                EnemyPose {
+                    position: enemy.position,
+                    yaw: yaw.0,
+                },
+            )
+        }),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PID: PlayerId = PlayerId(1);
+
+    #[test]
+    fn echo_latency_is_min_of_recent_round_trips() {
+        let mut lag = LagCompensation::default();
+        assert_eq!(lag.observe_echo(PID, InputSequence(1), Tick(10)), None);
+
+        lag.record_ack(PID, InputSequence(1), Tick(10));
+        lag.record_ack(PID, InputSequence(1), Tick(11));
+        lag.record_ack(PID, InputSequence(2), Tick(12));
+        assert_eq!(lag.observe_echo(PID, InputSequence(1), Tick(14)), Some(4));
+        assert_eq!(lag.observe_echo(PID, InputSequence(2), Tick(15)), Some(3));
+        // Stale echo of an old ack (player was idle) does not raise it.
+        assert_eq!(lag.observe_echo(PID, InputSequence(2), Tick(40)), None);
+        assert_eq!(lag.latency_ticks(PID), Some(3));
+        // Unknown sequences are ignored.
+        assert_eq!(lag.observe_echo(PID, InputSequence(9), Tick(41)), None);
+    }
+
+    #[test]
+    fn rewind_adds_interpolation_and_respects_cap() {
+        let mut lag = LagCompensation::default();
+        assert_eq!(lag.rewind_ticks(PID, 6), 0);
+        lag.set_latency(PID, 3);
+        assert_eq!(lag.rewind_ticks(PID, 6), 3 + INTERPOLATION_DELAY_TICKS);
+        assert_eq!(lag.rewind_ticks(PID, 2), 2);
+        assert_eq!(lag.rewind_ticks(PID, 0), 0);
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        let mut lag = LagCompensation::default();
+        let entity = Entity::from_bits(7);
+        for tick in 0..40u16 {
+            lag.record_enemies(
+                Tick(u32::from(tick)),
+                [(
+                    entity,
+                    EnemyPose {
+                        position: Vec2::new(f32::from(tick), 0.0),
+                        yaw: 0.0,
+                    },
+                )],
+            );
+        }
+        assert_eq!(lag.history.len(), ENEMY_HISTORY_TICKS as usize);
+        assert_eq!(lag.pose_at(entity, Tick(5)), None);
+        assert_eq!(
+            lag.pose_at(entity, Tick(35)).map(|p| p.position.x),
+            Some(35.0)
+        );
+    }
+
+    #[test]
+    fn cap_converts_to_ticks() {
+        let step = Duration::from_secs_f64(1.0 / 30.0);
+        assert_eq!(max_rewind_ticks(200, step), 6);
+        assert_eq!(max_rewind_ticks(0, step), 0);
+        assert_eq!(max_rewind_ticks(10_000, step), ENEMY_HISTORY_TICKS - 1);
+    }
+}
@@ -0,0 +1,130 @@
+//! Server-side enemy perception: sight, hearing, alerts and patrols.
+//!
+//! Map-spawned Mosquitons and Spideys carry a [`ServerEnemyPerception`].
+//! [`tick_enemy_perception`] runs in `EnemyAiSet` before the attack sims and
+//! walks every enemy that isn't engaged; the sims skip those enemies.
+
+use bevy::prelude::*;
+use carcinisation_fps_core::config::FpsCombatConfig;
+use carcinisation_fps_core::{
+    EnemyPerception, EnemyPlayerTarget, Noise, Perceiver, facing_yaw_toward, tick_patrol,
+    tick_perception,
+};
+use carcinisation_net::{NetPlayer, PlayerNetState};
+
+use super::combat::EnemyGameplayYaw;
+use super::enemy_ai::ServerEnemyNavigation;
+use super::enemy_attack::{ServerMosquitonSimConfig, ServerSpideySimConfig};
+use crate::ServerMap;
+use crate::systems::{NetEnemy, NetEnemyState, NetHealth};
+
+/// Per-enemy perception state. Absent on enemies spawned outside map data,
+/// which stay always engaged.
+#[derive(Component, Clone, Debug, Default)]
+pub struct ServerEnemyPerception(pub EnemyPerception);
+
+/// Noises made by players during the last `process_combat`, heard by enemies
+/// on the next perception tick.
+#[derive(Resource, Default)]
+pub struct PendingNoises(pub Vec<Noise>);
+
+/// Runs in `FixedUpdate` (`EnemyAiSet`) after `update_enemy_navigation`.
+#[allow(clippy::type_complexity)]
+pub fn tick_enemy_perception(
+    mut enemies: Query<(
+        &mut NetEnemy,
+        &NetHealth,
+        &mut ServerEnemyPerception,
+        &mut EnemyGameplayYaw,
+        Option<&ServerMosquitonSimConfig>,
+        Option<&ServerSpideySimConfig>,
+    )>,
+    players: Query<&NetPlayer>,
+    server_map: Res<ServerMap>,
+    navigation: Res<ServerEnemyNavigation>,
+    combat_config: Res<FpsCombatConfig>,
+    mut noises: ResMut<PendingNoises>,
+    fixed_time: Res<Time<Fixed>>,
+) {
+    let dt = fixed_time.delta_secs();
+    let tuning = &combat_config.enemy_perception;
+    let targets: Vec<EnemyPlayerTarget> = players
+        .iter()
+        .map(|player| EnemyPlayerTarget {
+            position: player.position,
+            alive: matches!(player.state, PlayerNetState::Alive),
+            id: player.player_id.0,
+        })
+        .collect();
+    let noises = std::mem::take(&mut noises.0);
+
+    let mut living: Vec<_> = enemies
+        .iter_mut()
+        .filter(|(enemy, health, ..)| {
+            health.current > 0.0
+                && !matches!(
+                    enemy.state,
+                    NetEnemyState::Dying { .. } | NetEnemyState::Dead { .. }
+                )
+        })
+        .collect();
+    // Alert ties resolve by list order; keep it stable across runs.
+    living.sort_by_key(|(enemy, ..)| enemy.object_id.0);
+
+    let mut perceivers: Vec<Perceiver<'_>> = living
+        .iter_mut()
+        .map(|(enemy, health, perception, ..)| Perceiver {
+            position: enemy.position,
+            health: health.current,
+            perception: &mut perception.0,
+        })
+        .collect();
+    tick_perception(
+        &mut perceivers,
+        &targets,
+        &noises,
+        &server_map.0,
+        tuning,
+        dt,
+    );
+    drop(perceivers);
+
+    for (enemy, _, perception, gameplay_yaw, mosquiton, spidey) in &mut living {
+        if perception.0.is_engaged() {
+            continue;
+        }
+        let (speed, radius) = match (mosquiton, spidey) {
+            (Some(config), _) => (config.0.move_speed, config.0.collision_radius),
+            (None, Some(config)) => (config.0.move_speed, config.0.collision_radius),
+            (None, None) => continue,
+        };
+        let before = enemy.position;
+        let mut position = before;
+        tick_patrol(
+            &mut perception.0,
+            &mut position,
+            speed,
+            radius,
+            &server_map.0,
+            Some(&navigation.0),
+            tuning,
+            dt,
+        );
+        if !matches!(enemy.state, NetEnemyState::Idle) {
+            enemy.state = NetEnemyState::Idle;
+        }
+        if position == before {
+            continue;
+        }
+        enemy.position = position;
+        if let Some(yaw) = facing_yaw_toward(before, position) {
+            gameplay_yaw.0 = yaw;
+            enemy.angle = yaw;
+        }
+    }
+}
+
+/// Whether the attack sims should skip this enemy this tick.
+pub(crate) fn is_unaware(perception: Option<&ServerEnemyPerception>) -> bool {
+    perception.is_some_and(|p| !p.0.is_engaged())
+}
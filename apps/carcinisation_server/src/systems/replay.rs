@@ -0,0 +1,515 @@
+//! Match recording and deterministic re-simulation.
+//!
+//! With a [`ReplayRecorder`] installed, the server logs every input that
+//! reaches the simulation — player joins/leaves, validated `ClientIntent`s and
+//! map resets — tagged with the tick they apply to, plus a `compute_sim_hash`
+//! checkpoint every `hash_interval` ticks.
+//!
+//! [`run_replay`] feeds the same events into a fresh, network-less
+//! `ServerPlugin` one fixed tick at a time and compares hashes, reporting the
+//! first checkpoint that diverges.
+//!
+//! # File layout
+//!
+//! `CRPL` magic, then a stream of length-prefixed (u32 LE) postcard-encoded
+//! [`ReplayRecord`]s. The first record is always the header. Records are
+//! appended as the match runs, so a file cut short by a crash is still
+//! readable up to the last complete record.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use carcinisation_fps_core::game_mode::GameModeConfig;
+use carcinisation_fps_core::{FpsCombatConfig, FpsMovementConfig};
+use carcinisation_net::{
+    AvatarPaletteVariant, ClientIntent, NetHealth, NetPlayer, NetProjectile, PlayerId, Tick,
+    TickCounter, sim_hash::compute_sim_hash,
+};
+use serde::{Deserialize, Serialize};
+
+use super::NetEnemy;
+use super::game_mode::GameMode;
+use super::input::PlayerIntentBuffer;
+use super::lag_compensation::LagCompensation;
+use super::reset::MapResetRequested;
+use crate::{PlayerSessionState, remove_net_player, spawn_net_player};
+
+/// File magic.
+const REPLAY_MAGIC: &[u8; 4] = b"CRPL";
+
+/// Bumped whenever [`ReplayRecord`] changes shape.
+pub const REPLAY_FORMAT_VERSION: u16 = 3;
+
+/// Default checkpoint spacing: one hash per second at 30 Hz.
+pub const DEFAULT_HASH_INTERVAL_TICKS: u32 = 30;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayHeader {
+    pub version: u16,
+    /// Map file the match was played on. Replays load it from this path.
+    pub map_path: String,
+    /// Match rules the server ran with; `None` for the open-ended
+    /// free-for-all. Replays install the same [`GameMode`].
+    pub game_mode: Option<GameModeConfig>,
+    pub hash_interval: u32,
+}
+
+/// A simulation input, applied before the tick it is tagged with.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ReplayEvent {
+    PlayerJoined {
+        player_id: PlayerId,
+        position: Vec2,
+        angle: f32,
+        avatar_variant: AvatarPaletteVariant,
+    },
+    PlayerLeft {
+        player_id: PlayerId,
+    },
+    /// Intent after server-side validation (dedup, clamping).
+    Intent {
+        player_id: PlayerId,
+        intent: ClientIntent,
+    },
+    /// Player's hitscan lag-compensation estimate changed (in ticks).
+    Latency {
+        player_id: PlayerId,
+        ticks: u32,
+    },
+    MapReset,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ReplayRecord {
+    Header(ReplayHeader),
+    Event {
+        tick: Tick,
+        event: ReplayEvent,
+    },
+    /// Sim hash after `tick` was simulated.
+    Hash {
+        tick: Tick,
+        hash: u64,
+    },
+}
+
+/// Streams the running match to a replay file.
+///
+/// Insert this resource to enable recording; every recording system is gated
+/// on its presence. A write failure disables the recorder instead of
+/// stopping the server.
+#[derive(Resource)]
+pub struct ReplayRecorder {
+    writer: Option<BufWriter<File>>,
+    hash_interval: u32,
+}
+
+impl ReplayRecorder {
+    /// Create (truncate) `path` and write the header.
+    ///
+    /// # Errors
+    ///
+    /// Returns the I/O error if the file cannot be created or written.
+    pub fn create(
+        path: impl AsRef<Path>,
+        map_path: &str,
+        game_mode: Option<GameModeConfig>,
+        hash_interval: u32,
+    ) -> std::io::Result<Self> {
+        let hash_interval = hash_interval.max(1);
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(REPLAY_MAGIC)?;
+        write_record(
+            &mut writer,
+            &ReplayRecord::Header(ReplayHeader {
+                version: REPLAY_FORMAT_VERSION,
+                map_path: map_path.to_string(),
+                game_mode,
+                hash_interval,
+            }),
+        )?;
+        writer.flush()?;
+        Ok(Self {
+            writer: Some(writer),
+            hash_interval,
+        })
+    }
+
+    pub fn record_event(&mut self, tick: Tick, event: ReplayEvent) {
+        self.write(&ReplayRecord::Event { tick, event });
+    }
+
+    fn write(&mut self, record: &ReplayRecord) {
+        let Some(writer) = self.writer.as_mut() else {
+            return;
+        };
+        if let Err(e) = write_record(writer, record) {
+            error!("Replay recording stopped: {e}");
+            self.writer = None;
+        }
+    }
+
+    fn flush(&mut self) {
+        if let Some(writer) = self.writer.as_mut()
+            && let Err(e) = writer.flush()
+        {
+            error!("Replay recording stopped: {e}");
+            self.writer = None;
+        }
+    }
+}
+
+fn write_record(writer: &mut impl Write, record: &ReplayRecord) -> std::io::Result<()> {
+    let bytes = postcard::to_allocvec(record).map_err(std::io::Error::other)?;
+    let len = u32::try_from(bytes.len()).map_err(std::io::Error::other)?;
+    writer.write_all(&len.to_le_bytes())?;
+    writer.write_all(&bytes)
+}
+
+/// A fully loaded replay file, indexed by tick.
+#[derive(Debug, Clone)]
+pub struct ReplayLog {
+    pub header: ReplayHeader,
+    events: BTreeMap<u32, Vec<ReplayEvent>>,
+    hashes: BTreeMap<u32, u64>,
+}
+
+impl ReplayLog {
+    /// Read and decode a replay file.
+    ///
+    /// A truncated trailing record (e.g. the server was killed mid-write) is
+    /// ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidData` error for a bad magic, missing header,
+    /// unsupported version or undecodable record.
+    pub fn read(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    /// Decode a replay from memory. See [`Self::read`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::read`], minus file access.
+    pub fn from_bytes(bytes: &[u8]) -> std::io::Result<Self> {
+        let invalid = |msg: String| std::io::Error::new(std::io::ErrorKind::InvalidData, msg);
+
+        let mut rest = bytes
+            .strip_prefix(REPLAY_MAGIC.as_slice())
+            .ok_or_else(|| invalid("not a replay file (bad magic)".to_string()))?;
+        let mut header = None;
+        let mut events: BTreeMap<u32, Vec<ReplayEvent>> = BTreeMap::new();
+        let mut hashes = BTreeMap::new();
+
+        while rest.len() >= 4 {
+            let len = u32::from_le_bytes([rest[0], rest[1], rest[2], rest[3]]) as usize;
+            let Some(body) = rest.get(4..4 + len) else {
+                warn!("Replay ends with a truncated record; ignoring it");
+                break;
+            };
+            rest = &rest[4 + len..];
+            let record: ReplayRecord =
+                postcard::from_bytes(body).map_err(|e| invalid(format!("bad record: {e}")))?;
+            match record {
+                ReplayRecord::Header(h) => {
+                    if h.version != REPLAY_FORMAT_VERSION {
+                        return Err(invalid(format!(
+                            "unsupported replay version {} (expected {REPLAY_FORMAT_VERSION})",
+                            h.version
+                        )));
+                    }
+                    header = Some(h);
+                }
+                ReplayRecord::Event { tick, event } => {
+                    events.entry(tick.0).or_default().push(event);
+                }
+                ReplayRecord::Hash { tick, hash } => {
+                    hashes.insert(tick.0, hash);
+                }
+            }
+        }
+
+        let header = header.ok_or_else(|| invalid("replay has no header".to_string()))?;
+        Ok(Self {
+            header,
+            events,
+            hashes,
+        })
+    }
+
+    /// Last tick with an event or a hash checkpoint.
+    #[must_use]
+    pub fn last_tick(&self) -> Option<u32> {
+        let last_event = self.events.keys().next_back().copied();
+        let last_hash = self.hashes.keys().next_back().copied();
+        last_event.max(last_hash)
+    }
+
+    #[must_use]
+    pub fn hash_count(&self) -> usize {
+        self.hashes.len()
+    }
+
+    /// Keep only the events matching `keep`, e.g. to bisect which input
+    /// triggers a desync.
+    pub fn retain_events(&mut self, mut keep: impl FnMut(&ReplayEvent) -> bool) {
+        for events in self.events.values_mut() {
+            events.retain(&mut keep);
+        }
+        self.events.retain(|_, events| !events.is_empty());
+    }
+}
+
+/// Outcome of [`run_replay`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayReport {
+    /// Every checkpoint matched.
+    Matched { ticks: u32, checkpoints: usize },
+    /// First checkpoint whose hash differs from the recording.
+    Diverged {
+        tick: Tick,
+        expected: u64,
+        actual: u64,
+        /// Last checkpoint that still matched, if any.
+        last_good: Option<Tick>,
+    },
+}
+
+// ---------------------------------------------------------------------------
+// Recording systems
+// ---------------------------------------------------------------------------
+
+/// Sim hash of the current replicated gameplay state.
+fn sim_hash(
+    players: &Query<(&NetPlayer, &NetHealth)>,
+    enemies: &Query<(&NetEnemy, &NetHealth)>,
+    projectiles: &Query<&NetProjectile>,
+) -> u64 {
+    let players: Vec<_> = players
+        .iter()
+        .map(|(p, h)| (p.clone(), h.clone()))
+        .collect();
+    let enemies: Vec<_> = enemies
+        .iter()
+        .map(|(e, h)| (e.clone(), h.current))
+        .collect();
+    let projectiles: Vec<_> = projectiles.iter().cloned().collect();
+    compute_sim_hash(&players, &enemies, &projectiles)
+}
+
+/// Write a hash checkpoint every `hash_interval` ticks. Runs in `TickSet`
+/// before the tick counter advances, so the checkpoint is tagged with the
+/// tick that was just simulated.
+pub fn record_replay_hash(
+    mut recorder: ResMut<ReplayRecorder>,
+    tick_counter: Res<TickCounter>,
+    players: Query<(&NetPlayer, &NetHealth)>,
+    enemies: Query<(&NetEnemy, &NetHealth)>,
+    projectiles: Query<&NetProjectile>,
+) {
+    let tick = tick_counter.0;
+    if tick.0 % recorder.hash_interval != 0 {
+        return;
+    }
+    let hash = sim_hash(&players, &enemies, &projectiles);
+    recorder.write(&ReplayRecord::Hash { tick, hash });
+    recorder.flush();
+}
+
+/// Log a pending map reset before `handle_map_reset` consumes it.
+pub fn record_map_reset(
+    reset: Res<MapResetRequested>,
+    mut recorder: ResMut<ReplayRecorder>,
+    tick_counter: Res<TickCounter>,
+) {
+    if reset.0 {
+        recorder.record_event(tick_counter.0, ReplayEvent::MapReset);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Re-simulation
+// ---------------------------------------------------------------------------
+
+/// Re-simulate `log` on `app` and compare hash checkpoints.
+///
+/// `app` must be a finished, network-less server app (a `ServerPlugin` no
+/// client connects to) on the same map the match was recorded on. It is
+/// switched to one fixed tick per update and given the recorded game mode.
+pub fn run_replay(app: &mut App, log: &ReplayLog) -> ReplayReport {
+    app.insert_resource(TimeUpdateStrategy::FixedTimesteps(1));
+    if let Some(config) = log.header.game_mode {
+        app.insert_resource(GameMode(config));
+    } else {
+        app.world_mut().remove_resource::<GameMode>();
+    }
+
+    let Some(last_tick) = log.last_tick() else {
+        return ReplayReport::Matched {
+            ticks: 0,
+            checkpoints: 0,
+        };
+    };
+
+    let mut checkpoints = 0;
+    let mut last_good = None;
+    loop {
+        let tick = app.world().resource::<TickCounter>().0;
+        if tick.0 > last_tick {
+            break;
+        }
+        for event in log.events.get(&tick.0).into_iter().flatten() {
+            apply_event(app.world_mut(), event);
+        }
+        app.update();
+
+        if let Some(&expected) = log.hashes.get(&tick.0) {
+            let actual = app
+                .world_mut()
+                .run_system_once(
+                    |players: Query<(&NetPlayer, &NetHealth)>,
+                     enemies: Query<(&NetEnemy, &NetHealth)>,
+                     projectiles: Query<&NetProjectile>| {
+                        sim_hash(&players, &enemies, &projectiles)
+                    },
+                )
+                .expect("sim hash system");
+            if actual != expected {
+                return ReplayReport::Diverged {
+                    tick,
+                    expected,
+                    actual,
+                    last_good,
+                };
+            }
+            checkpoints += 1;
+            last_good = Some(tick);
+        }
+    }
+
+    ReplayReport::Matched {
+        ticks: last_tick + 1,
+        checkpoints,
+    }
+}
+
+fn apply_event(world: &mut World, event: &ReplayEvent) {
+    match event.clone() {
+        ReplayEvent::PlayerJoined {
+            player_id,
+            position,
+            angle,
+            avatar_variant,
+        } => {
+            world
+                .run_system_once(
+                    move |mut commands: Commands,
+                          combat_config: Res<FpsCombatConfig>,
+                          movement_config: Res<FpsMovementConfig>| {
+                        spawn_net_player(
+                            &mut commands,
+                            player_id,
+                            position,
+                            angle,
+                            avatar_variant,
+                            &combat_config,
+                            &movement_config,
+                        );
+                    },
+                )
+                .expect("replay join system");
+        }
+        ReplayEvent::PlayerLeft { player_id } => {
+            world
+                .run_system_once(
+                    move |mut commands: Commands,
+                          players: Query<(Entity, &NetPlayer)>,
+                          mut session: PlayerSessionState| {
+                        remove_net_player(&mut commands, &players, &mut session, player_id);
+                    },
+                )
+                .expect("replay leave system");
+        }
+        ReplayEvent::Intent { player_id, intent } => {
+            world
+                .resource_mut::<PlayerIntentBuffer>()
+                .set(player_id, &intent);
+        }
+        ReplayEvent::Latency { player_id, ticks } => {
+            world
+                .resource_mut::<LagCompensation>()
+                .set_latency(player_id, ticks);
+        }
+        ReplayEvent::MapReset => {
+            world.resource_mut::<MapResetRequested>().0 = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use carcinisation_fps_core::game_mode::GameModeKind;
+    use carcinisation_net::InputSequence;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("carc-replay-{}-{name}.crpl", std::process::id()))
+    }
+
+    #[test]
+    fn records_roundtrip_through_file() {
+        let path = temp_path("roundtrip");
+        let mode = GameModeConfig::new(GameModeKind::Coop);
+        let mut recorder =
+            ReplayRecorder::create(&path, "maps/arena.fp_map.ron", Some(mode), 15).unwrap();
+        recorder.record_event(
+            Tick(3),
+            ReplayEvent::Intent {
+                player_id: PlayerId(1),
+                intent: ClientIntent::idle(InputSequence(9)),
+            },
+        );
+        recorder.record_event(Tick(3), ReplayEvent::MapReset);
+        recorder.write(&ReplayRecord::Hash {
+            tick: Tick(15),
+            hash: 0xdead_beef,
+        });
+        drop(recorder);
+
+        let log = ReplayLog::read(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(log.header.map_path, "maps/arena.fp_map.ron");
+        assert_eq!(log.header.game_mode, Some(mode));
+        assert_eq!(log.header.hash_interval, 15);
+        assert_eq!(log.events[&3].len(), 2);
+        assert_eq!(log.hashes[&15], 0xdead_beef);
+        assert_eq!(log.last_tick(), Some(15));
+    }
+
+    #[test]
+    fn truncated_tail_is_ignored() {
+        let path = temp_path("truncated");
+        let mut recorder = ReplayRecorder::create(&path, "m", None, 30).unwrap();
+        recorder.record_event(Tick(1), ReplayEvent::MapReset);
+        drop(recorder);
+
+        let mut bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        bytes.extend_from_slice(&[200, 0, 0, 0, 1, 2]);
+        let log = ReplayLog::from_bytes(&bytes).unwrap();
+        assert_eq!(log.events[&1].len(), 1);
+    }
+
+    #[test]
+    fn rejects_foreign_files() {
+        let err = ReplayLog::from_bytes(b"RIFF....").unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+}
@@ -12,8 +12,8 @@ use carcinisation_net::{
 
 use super::enemy_attack::PendingProjectile;
 use super::{
-    BurnContactCooldowns, FireCooldownMap, FlameActiveTracker, FlameCharCooldowns, NetEnemy,
-    NetProjectile, NextProjectileId, ServerQuickTurn,
+    BurnContactCooldowns, FireCooldownMap, FlameActiveTracker, FlameCharCooldowns, LagCompensation,
+    NetEnemy, NetProjectile, NextProjectileId, ServerQuickTurn,
 };
 use crate::{MapEntities, MapPlayerStarts, SpawnIndex, spawn_map_enemies_inner};
 
@@ -41,6 +41,7 @@ pub fn handle_map_reset(
     mut flame_tracker: ResMut<FlameActiveTracker>,
     mut char_cooldowns: ResMut<FlameCharCooldowns>,
     mut burn_cooldowns: ResMut<BurnContactCooldowns>,
+    mut lag_compensation: ResMut<LagCompensation>,
 ) {
     if !reset.0 {
         return;
@@ -79,7 +80,7 @@ pub fn handle_map_reset(
         np.position = Vec2::new(spawn.x, spawn.y);
         np.angle = spawn.angle_deg.to_radians();
         np.state = PlayerNetState::Alive;
-        np.current_attack = NetAttackId::None;
+        np.current_attack = NetAttackId::default();
         np.flame_active = false;
         health.current = health.max;
         // Remove RespawnTimer if the player was dead.
@@ -112,6 +113,8 @@ pub fn handle_map_reset(
     char_cooldowns.0.clear();
     burn_cooldowns.0.clear();
     next_proj_id.0 = 0;
+    // Rewound hitscan must not see the despawned enemies.
+    lag_compensation.clear_history();
 
     // --- Re-spawn map entities from map definition ---
     let respawned = spawn_map_enemies_inner(&mut commands, &map_entities.0);
@@ -0,0 +1,183 @@
+//! Connect-token issuing for secure mode.
+//!
+//! Backs the `mint-token` and `token-service` subcommands. The service is a
+//! deliberately tiny blocking HTTP/1.0 endpoint meant to run next to the game
+//! server (on loopback or behind a TLS proxy):
+//!
+//! ```text
+//! GET /token?name=<player>&mode=player|monitor  ->  200, raw token bytes
+//! ```
+//!
+//! It does no account checks of its own; anyone who can reach it can mint a
+//! token. Put it behind whatever front door decides who may play.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use carcinisation_net::ConnectMode;
+use carcinisation_net::auth::{PrivateKey, TokenRequest, encode_connect_token, mint_connect_token};
+use carcinisation_net::protocol::PLAYER_NAME_MAX_CHARS;
+use carcinisation_net::sanitize_player_name;
+
+/// Per-connection read/write timeout for the token service.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Mints connect tokens for one server.
+pub struct TokenIssuer {
+    pub private_key: PrivateKey,
+    /// Public UDP address(es) of the server; must include the server's
+    /// `--public-addr`.
+    pub server_addresses: Vec<SocketAddr>,
+}
+
+impl TokenIssuer {
+    /// Mint a token and return its encoded bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if netcode rejects the token parameters.
+    pub fn issue(&self, player_name: Option<String>, mode: ConnectMode) -> Result<Vec<u8>, String> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time went backwards");
+        let request = TokenRequest::new(player_name, mode, self.server_addresses.clone());
+        let token = mint_connect_token(&self.private_key, &request, now)?;
+        encode_connect_token(&token)
+    }
+}
+
+/// Serve tokens over HTTP until the process is killed.
+///
+/// # Errors
+///
+/// Returns an error if the listener cannot be bound.
+pub fn run_token_service(listen: SocketAddr, issuer: &TokenIssuer) -> std::io::Result<()> {
+    let listener = TcpListener::bind(listen)?;
+    eprintln!(
+        "Token service listening on http://{}/token (server {:?})",
+        listener.local_addr()?,
+        issuer.server_addresses
+    );
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => handle_connection(&stream, issuer),
+            Err(e) => eprintln!("token service accept error: {e}"),
+        }
+    }
+    Ok(())
+}
+
+fn handle_connection(stream: &TcpStream, issuer: &TokenIssuer) {
+    let _ = stream.set_read_timeout(Some(REQUEST_TIMEOUT));
+    let _ = stream.set_write_timeout(Some(REQUEST_TIMEOUT));
+
+    let mut line = String::new();
+    if BufReader::new(stream).read_line(&mut line).is_err() {
+        return;
+    }
+    let response = parse_request_line(&line).and_then(|(name, mode)| {
+        issuer
+            .issue(name.clone(), mode)
+            .inspect(|_| eprintln!("Issued {mode:?} token for {name:?}"))
+            .map_err(|e| (500, e))
+    });
+
+    let (status, content_type, body) = match response {
+        Ok(token) => ("200 OK", "application/octet-stream", token),
+        Err((code, message)) => {
+            let reason = match code {
+                404 => "404 Not Found",
+                500 => "500 Internal Server Error",
+                _ => "400 Bad Request",
+            };
+            (reason, "text/plain", format!("{message}\n").into_bytes())
+        }
+    };
+    let mut stream = stream;
+    let _ = write!(
+        stream,
+        "HTTP/1.0 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        body.len()
+    );
+    let _ = stream.write_all(&body);
+}
+
+/// Parse `GET /token?name=..&mode=.. HTTP/1.x` into the token parameters.
+/// Errors carry the HTTP status code to answer with.
+fn parse_request_line(line: &str) -> Result<(Option<String>, ConnectMode), (u16, String)> {
+    let mut parts = line.split_whitespace();
+    let (Some("GET"), Some(target)) = (parts.next(), parts.next()) else {
+        return Err((400, "expected GET /token".to_string()));
+    };
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    if path != "/token" {
+        return Err((404, format!("unknown path {path}")));
+    }
+
+    let mut name = None;
+    let mut mode = ConnectMode::Player;
+    for pair in query.split('&').filter(|p| !p.is_empty()) {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        match key {
+            "name" => {
+                let clean = sanitize_player_name(value);
+                if clean.as_deref() != Some(value) {
+                    return Err((
+                        400,
+                        format!(
+                            "name must be 1-{PLAYER_NAME_MAX_CHARS} characters of A-Z, a-z, 0-9, - or _"
+                        ),
+                    ));
+                }
+                name = clean;
+            }
+            "mode" => {
+                mode = match value {
+                    "player" => ConnectMode::Player,
+                    "monitor" => ConnectMode::Monitor,
+                    _ => return Err((400, format!("unknown mode {value:?}"))),
+                };
+            }
+            _ => return Err((400, format!("unknown parameter {key:?}"))),
+        }
+    }
+    Ok((name, mode))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_token_requests() {
+        assert_eq!(
+            parse_request_line("GET /token?name=crab_01&mode=monitor HTTP/1.0\r\n"),
+            Ok((Some("crab_01".to_string()), ConnectMode::Monitor))
+        );
+        assert_eq!(
+            parse_request_line("GET /token HTTP/1.1\r\n"),
+            Ok((None, ConnectMode::Player))
+        );
+        assert_eq!(
+            parse_request_line("GET /admin HTTP/1.0").unwrap_err().0,
+            404
+        );
+        assert_eq!(
+            parse_request_line("POST /token HTTP/1.0").unwrap_err().0,
+            400
+        );
+        assert_eq!(
+            parse_request_line("GET /token?name=<script> HTTP/1.0")
+                .unwrap_err()
+                .0,
+            400
+        );
+        assert_eq!(
+            parse_request_line("GET /token?mode=admin HTTP/1.0")
+                .unwrap_err()
+                .0,
+            400
+        );
+    }
+}
@@ -154,7 +154,7 @@ fn admin_socket_reset_map_despawns_and_respawns() {
 }
 
 #[test]
-fn admin_socket_say_returns_not_implemented() {
+fn admin_socket_say_broadcasts() {
     let sock = unique_socket_path();
     let mut server = build_reset_server(vec![], Some(sock.clone()));
     tick_server_n(&mut server, 30);
@@ -166,8 +166,25 @@ fn admin_socket_say_returns_not_implemented() {
             message: "hello".to_string(),
         },
     );
+    assert!(resp.ok);
+    assert!(resp.message.unwrap().contains("hello"));
+}
+
+#[test]
+fn admin_socket_say_rejects_empty_message() {
+    let sock = unique_socket_path();
+    let mut server = build_reset_server(vec![], Some(sock.clone()));
+    tick_server_n(&mut server, 30);
+
+    let resp = admin_request(
+        &mut server,
+        &sock,
+        &carcinisation_admin::AdminRequest::Say {
+            message: "   ".to_string(),
+        },
+    );
     assert!(!resp.ok);
-    assert!(resp.error.unwrap().contains("not implemented"));
+    assert!(resp.error.unwrap().contains("empty"));
 }
 
 #[test]
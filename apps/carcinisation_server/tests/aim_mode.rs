@@ -12,9 +12,9 @@ use carcinisation_fps_core::map::test_map;
 use carcinisation_net::{
     ClientIntent, InputSequence, NetHealth, NetPlayer, PlayerActions, PlayerId, PlayerNetState,
 };
-use carcinisation_server::ServerPlugin;
 use carcinisation_server::systems::PlayerIntentBuffer;
 use carcinisation_server::systems::occupancy::ServerPlayerImpulse;
+use carcinisation_server::{ServerAuthMode, ServerPlugin};
 use common::{build_server_app, reserve_port};
 
 // ---------------------------------------------------------------------------
@@ -31,6 +31,8 @@ fn build_aim_server() -> App {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
 
     // Switch to AimCommitment mode.
@@ -49,7 +51,7 @@ fn spawn_player(server: &mut App) {
             player_id: PlayerId(1),
             position: Vec2::new(3.5, 3.5),
             angle: 0.0,
-            current_attack: carcinisation_net::NetAttackId::None,
+            current_attack: carcinisation_net::NetAttackId::default(),
             state: PlayerNetState::Alive,
             flame_active: false,
             avatar_palette_variant: None,
@@ -124,6 +126,7 @@ fn angle_delta(a: f32, b: f32) -> f32 {
 const fn intent_with_action(action: u8, aim_held: bool) -> ClientIntent {
     ClientIntent {
         sequence: InputSequence(1),
+        acked_sequence: InputSequence(0),
         movement: Vec2::ZERO,
         turn: 0.0,
         fire_held: false,
@@ -135,6 +138,7 @@ const fn intent_with_action(action: u8, aim_held: bool) -> ClientIntent {
 fn aim_only_intent() -> ClientIntent {
     ClientIntent {
         sequence: InputSequence(2),
+        acked_sequence: InputSequence(0),
         movement: Vec2::ZERO,
         turn: 0.0,
         fire_held: false,
@@ -183,6 +187,7 @@ fn aim_mode_fire_rejected_without_aim() {
         &mut server,
         &ClientIntent {
             sequence: InputSequence(1),
+            acked_sequence: InputSequence(0),
             movement: Vec2::ZERO,
             turn: 0.0,
             fire_held: true,
@@ -215,6 +220,7 @@ fn aim_mode_movement_suppressed_turn_allowed() {
         &mut server,
         &ClientIntent {
             sequence: InputSequence(1),
+            acked_sequence: InputSequence(0),
             movement: Vec2::new(0.0, 1.0),
             turn: 1.0,
             fire_held: false,
@@ -250,6 +256,7 @@ fn aim_mode_external_impulse_still_moves_player() {
         &mut server,
         &ClientIntent {
             sequence: InputSequence(1),
+            acked_sequence: InputSequence(0),
             movement: Vec2::new(0.0, 1.0),
             turn: 1.0,
             fire_held: false,
@@ -288,7 +295,7 @@ fn aim_mode_preserves_pre_aim_weapon_switch_when_packets_coalesce() {
 
     assert_eq!(
         player_attack(&mut server),
-        carcinisation_net::NetAttackId::Projectile
+        common::attack_slot("flamethrower")
     );
 }
 
@@ -348,6 +355,8 @@ fn legacy_mode_preserves_pre_aim_quick_turn_when_packets_coalesce() {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     server
         .world_mut()
@@ -382,6 +391,7 @@ fn aim_mode_rejects_weapon_switch_while_aiming() {
         &mut server,
         &ClientIntent {
             sequence: InputSequence(1),
+            acked_sequence: InputSequence(0),
             movement: Vec2::ZERO,
             turn: 0.0,
             fire_held: false,
@@ -394,7 +404,7 @@ fn aim_mode_rejects_weapon_switch_while_aiming() {
 
     assert_eq!(
         player_attack(&mut server),
-        carcinisation_net::NetAttackId::None
+        carcinisation_net::NetAttackId::default()
     );
 }
 
@@ -407,6 +417,7 @@ fn aim_mode_accepts_weapon_switch_outside_aim_mode() {
         &mut server,
         &ClientIntent {
             sequence: InputSequence(1),
+            acked_sequence: InputSequence(0),
             movement: Vec2::ZERO,
             turn: 0.0,
             fire_held: false,
@@ -419,7 +430,7 @@ fn aim_mode_accepts_weapon_switch_outside_aim_mode() {
 
     assert_eq!(
         player_attack(&mut server),
-        carcinisation_net::NetAttackId::Projectile
+        common::attack_slot("flamethrower")
     );
 }
 
@@ -434,6 +445,8 @@ fn legacy_mode_preserves_weapon_switch_when_packets_coalesce() {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     server
         .world_mut()
@@ -452,7 +465,7 @@ fn legacy_mode_preserves_weapon_switch_when_packets_coalesce() {
 
     assert_eq!(
         player_attack(&mut server),
-        carcinisation_net::NetAttackId::Projectile
+        common::attack_slot("flamethrower")
     );
 }
 
@@ -473,6 +486,7 @@ fn aim_mode_rejects_snap_actions_while_aiming_but_preserves_fire() {
             &mut server,
             &ClientIntent {
                 sequence: InputSequence(1),
+                acked_sequence: InputSequence(0),
                 movement: Vec2::new(0.0, 1.0),
                 turn: 0.0,
                 fire_held: true,
@@ -524,6 +538,7 @@ fn aim_mode_accepts_snap_actions_outside_aim_mode() {
             &mut server,
             &ClientIntent {
                 sequence: InputSequence(1),
+                acked_sequence: InputSequence(0),
                 movement: Vec2::ZERO,
                 turn: 0.0,
                 fire_held: false,
@@ -554,6 +569,8 @@ fn legacy_mode_fire_works_without_aim() {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
 
     // Explicitly set Legacy mode (RON file may have AimCommitment for playtesting).
@@ -569,6 +586,7 @@ fn legacy_mode_fire_works_without_aim() {
         &mut server,
         &ClientIntent {
             sequence: InputSequence(1),
+            acked_sequence: InputSequence(0),
             movement: Vec2::ZERO,
             turn: 0.0,
             fire_held: true,
@@ -593,6 +611,8 @@ fn legacy_mode_movement_not_suppressed() {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
 
     // Explicitly set Legacy mode (RON file may have AimCommitment for playtesting).
@@ -609,6 +629,7 @@ fn legacy_mode_movement_not_suppressed() {
         &mut server,
         &ClientIntent {
             sequence: InputSequence(1),
+            acked_sequence: InputSequence(0),
             movement: Vec2::new(0.0, 1.0),
             turn: 0.0,
             fire_held: false,
@@ -638,6 +659,8 @@ fn legacy_mode_external_impulse_still_moves_player() {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     server
         .world_mut()
@@ -674,6 +697,8 @@ fn legacy_mode_accepts_snap_actions() {
             admin_socket: None,
             instance_name: "test".to_string(),
             map_path: "test_map".to_string(),
+            ws_port: None,
+            auth: ServerAuthMode::Unsecure,
         });
         server
             .world_mut()
@@ -688,6 +713,7 @@ fn legacy_mode_accepts_snap_actions() {
             &mut server,
             &ClientIntent {
                 sequence: InputSequence(1),
+                acked_sequence: InputSequence(0),
                 movement: Vec2::ZERO,
                 turn: 0.0,
                 fire_held: false,
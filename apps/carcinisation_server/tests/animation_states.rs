@@ -5,10 +5,10 @@
 
 mod common;
 
-use carcinisation_net::{NetAttackId, NetEnemyState};
+use carcinisation_net::NetEnemyState;
 use common::{
-    build_deterministic_server_with_enemy, force_player_attack, get_enemy_state, inject_fire,
-    set_enemy_health, spawn_alive_player, wait_for_deterministic,
+    attack_slot, build_deterministic_server_with_enemy, force_player_attack, get_enemy_state,
+    inject_fire, set_enemy_health, spawn_alive_player, wait_for_deterministic,
 };
 
 // ---------------------------------------------------------------------------
@@ -71,7 +71,7 @@ fn flamethrower_kill_transitions_to_dying_burn() {
     server.update();
 
     spawn_alive_player(&mut server, 1, 1.5, 1.5);
-    force_player_attack(&mut server, 1, NetAttackId::Projectile);
+    force_player_attack(&mut server, 1, attack_slot("flamethrower"));
     set_enemy_health(&mut server, 1.0);
 
     // Fire flamethrower — burn system builds intensity progressively.
@@ -97,7 +97,7 @@ fn pending_projectile_spawns_after_delay() {
 // Hitscan hit confirmation test
 // ---------------------------------------------------------------------------
 
-/// Hitscan hit on enemy produces exactly hitscan_damage (37).
+/// Hitscan hit on enemy produces exactly the pistol damage (37).
 #[test]
 fn hitscan_hit_damages_enemy() {
     // Enemy at (3.0, 1.5), player at (1.5, 1.5) facing east.
@@ -117,9 +117,9 @@ fn hitscan_hit_damages_enemy() {
     let hp_after = get_enemy_health(&mut server).unwrap();
     let damage = hp_before - hp_after;
     assert!(
-        (damage - carcinisation_fps_core::FpsCombatConfig::default().hitscan_damage).abs() < 1.0,
+        (damage - common::pistol_damage()).abs() < 1.0,
         "hitscan should deal {} damage: got {damage}",
-        carcinisation_fps_core::FpsCombatConfig::default().hitscan_damage
+        common::pistol_damage()
     );
 }
 
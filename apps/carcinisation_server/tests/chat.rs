@@ -0,0 +1,181 @@
+//! Chat integration tests: client `ChatSend` → server validation/rate limit →
+//! `ChatMessage` broadcast to every client.
+
+mod common;
+
+use std::net::SocketAddr;
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy_replicon::prelude::*;
+use carcinisation_net::{ChatMessage, ChatSend, ChatSender, NetPlayer};
+use carcinisation_server::systems::chat::{CHAT_BURST, broadcast_server_chat};
+use common::{
+    build_fixed_tick_client, build_fixed_tick_server, reserve_port, tick_with_sleep,
+    tick3_with_sleep,
+};
+
+// ---------------------------------------------------------------------------
+// Test helpers
+// ---------------------------------------------------------------------------
+
+/// Chat lines received by a test client, in arrival order.
+#[derive(Resource, Default)]
+struct ReceivedChat(Vec<ChatMessage>);
+
+/// Lines queued for sending on the next client update.
+#[derive(Resource, Default)]
+struct ChatOutbox(Vec<String>);
+
+fn record_chat(trigger: On<ChatMessage>, mut received: ResMut<ReceivedChat>) {
+    received.0.push(trigger.event().clone());
+}
+
+fn send_outbox(mut commands: Commands, mut outbox: ResMut<ChatOutbox>) {
+    for text in outbox.0.drain(..) {
+        commands.client_trigger(ChatSend { text });
+    }
+}
+
+fn build_chat_client(addr: SocketAddr) -> App {
+    let mut app = build_fixed_tick_client(addr);
+    app.init_resource::<ReceivedChat>()
+        .init_resource::<ChatOutbox>()
+        .add_observer(record_chat)
+        .add_systems(Update, send_outbox);
+    app
+}
+
+fn received(app: &App) -> &[ChatMessage] {
+    &app.world().resource::<ReceivedChat>().0
+}
+
+fn queue_chat(app: &mut App, text: &str) {
+    app.world_mut()
+        .resource_mut::<ChatOutbox>()
+        .0
+        .push(text.to_string());
+}
+
+fn count_server_players(server: &mut App) -> usize {
+    server
+        .world_mut()
+        .query::<&NetPlayer>()
+        .iter(server.world())
+        .count()
+}
+
+fn connect_two(server: &mut App, c1: &mut App, c2: &mut App) {
+    for _ in 0..300 {
+        tick3_with_sleep(server, c1, c2);
+        if count_server_players(server) == 2 {
+            break;
+        }
+    }
+    assert_eq!(count_server_players(server), 2, "both clients should join");
+    // Let PlayerIdAssigned and the first replication land.
+    for _ in 0..30 {
+        tick3_with_sleep(server, c1, c2);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn player_chat_is_broadcast_to_all_clients() {
+    let port = reserve_port();
+    let addr = SocketAddr::new(std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST), port);
+    let mut server = build_fixed_tick_server(port);
+    server.update();
+    let mut c1 = build_chat_client(addr);
+    c1.update();
+    // Client ids are derived from the Startup timestamp in milliseconds.
+    std::thread::sleep(std::time::Duration::from_millis(2));
+    let mut c2 = build_chat_client(addr);
+    c2.update();
+    connect_two(&mut server, &mut c1, &mut c2);
+
+    queue_chat(&mut c1, "  hello\tthere  ");
+    for _ in 0..100 {
+        tick3_with_sleep(&mut server, &mut c1, &mut c2);
+        if !received(&c1).is_empty() && !received(&c2).is_empty() {
+            break;
+        }
+    }
+
+    for client in [&c1, &c2] {
+        let lines = received(client);
+        assert_eq!(lines.len(), 1, "each client should get exactly one line");
+        assert_eq!(lines[0].text, "hello there");
+        assert!(matches!(lines[0].sender, ChatSender::Player(_)));
+    }
+}
+
+#[test]
+fn player_chat_is_rate_limited() {
+    let port = reserve_port();
+    let addr = SocketAddr::new(std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST), port);
+    let mut server = build_fixed_tick_server(port);
+    server.update();
+    let mut client = build_chat_client(addr);
+    for _ in 0..300 {
+        tick_with_sleep(&mut server, &mut client);
+        if count_server_players(&mut server) == 1 {
+            break;
+        }
+    }
+
+    let burst = usize::try_from(CHAT_BURST).unwrap();
+    for i in 0..burst + 3 {
+        queue_chat(&mut client, &format!("spam {i}"));
+    }
+    for _ in 0..100 {
+        tick_with_sleep(&mut server, &mut client);
+    }
+
+    let lines = received(&client);
+    assert_eq!(
+        lines.len(),
+        burst,
+        "only the burst allowance should get through"
+    );
+    assert_eq!(lines[0].text, "spam 0");
+}
+
+#[test]
+fn server_chat_reaches_clients() {
+    let port = reserve_port();
+    let addr = SocketAddr::new(std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST), port);
+    let mut server = build_fixed_tick_server(port);
+    server.update();
+    let mut client = build_chat_client(addr);
+    for _ in 0..300 {
+        tick_with_sleep(&mut server, &mut client);
+        if count_server_players(&mut server) == 1 {
+            break;
+        }
+    }
+    for _ in 0..30 {
+        tick_with_sleep(&mut server, &mut client);
+    }
+
+    server
+        .world_mut()
+        .run_system_once(|mut commands: Commands| {
+            broadcast_server_chat(&mut commands, "Server restart in 2 minutes");
+        })
+        .expect("broadcast system");
+    for _ in 0..100 {
+        tick_with_sleep(&mut server, &mut client);
+        if !received(&client).is_empty() {
+            break;
+        }
+    }
+
+    let lines = received(&client);
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].sender, ChatSender::Server);
+    assert_eq!(lines[0].text, "Server restart in 2 minutes");
+}
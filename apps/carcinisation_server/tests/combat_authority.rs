@@ -15,7 +15,7 @@ use carcinisation_net::{
     ClientIntent, InputSequence, NetAttackId, NetHealth, NetPlayer, PlayerActions, PlayerId,
     PlayerNetState,
 };
-use carcinisation_server::ServerPlugin;
+use carcinisation_server::{ServerAuthMode, ServerPlugin};
 use common::combat::{
     build_combat_client, build_combat_server, get_player_id, queue_fire, wait_for_player,
 };
@@ -147,6 +147,7 @@ fn two_players_same_tick_second_sees_fresh_enemy_state() {
             kind: EntitySpawnKind::Mosquiton {
                 health: 1,
                 speed: 0.0,
+                patrol: Vec::new(),
             },
             x: 3.5,
             y: 1.5,
@@ -155,6 +156,7 @@ fn two_players_same_tick_second_sees_fresh_enemy_state() {
             kind: EntitySpawnKind::Mosquiton {
                 health: 100,
                 speed: 0.0,
+                patrol: Vec::new(),
             },
             x: 5.5,
             y: 1.5,
@@ -168,6 +170,8 @@ fn two_players_same_tick_second_sees_fresh_enemy_state() {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     server.update();
 
@@ -178,7 +182,7 @@ fn two_players_same_tick_second_sees_fresh_enemy_state() {
                 player_id: PlayerId(pid),
                 position: Vec2::new(1.5, 1.5),
                 angle: 0.0,
-                current_attack: NetAttackId::None,
+                current_attack: NetAttackId::default(),
                 state: PlayerNetState::Alive,
                 flame_active: false,
                 avatar_palette_variant: None,
@@ -200,6 +204,7 @@ fn two_players_same_tick_second_sees_fresh_enemy_state() {
                 PlayerId(pid),
                 &ClientIntent {
                     sequence: InputSequence(1),
+                    acked_sequence: InputSequence(0),
                     movement: Vec2::ZERO,
                     turn: 0.0,
                     fire_held: true,
@@ -22,7 +22,7 @@ use carcinisation_net::{
     NetAttackId, NetEnemyState, NetEnemyType, NetHealth, NetPlayer, PlayerId, PlayerNetState,
 };
 use carcinisation_server::{
-    ServerPlugin,
+    ServerAuthMode, ServerPlugin,
     systems::{ServerEnemyAiConfig, ServerMosquitonSimConfig},
 };
 use common::combat::{
@@ -31,7 +31,7 @@ use common::combat::{
     get_player_attack, get_player_id, is_flame_active, load_default_map_data, open_test_map,
     queue_fire, queue_idle, queue_switch, wait_for_client_enemy_position, wait_for_player,
 };
-use common::{build_server_app, get_enemy_health, reserve_port, tick_with_sleep};
+use common::{attack_slot, build_server_app, get_enemy_health, reserve_port, tick_with_sleep};
 
 // ---------------------------------------------------------------------------
 // Default-map / AI tests
@@ -82,7 +82,7 @@ fn default_map_ai_diagnostic_classifies_or_moves_mosquitons() {
         player_id: PlayerId(1),
         position: Vec2::new(start.x, start.y),
         angle: start.angle_deg.to_radians(),
-        current_attack: NetAttackId::None,
+        current_attack: NetAttackId::default(),
         state: PlayerNetState::Alive,
         flame_active: false,
         avatar_palette_variant: None,
@@ -146,6 +146,7 @@ fn map_authored_mosquiton_speed_is_preserved_on_server_spawn() {
         kind: EntitySpawnKind::Mosquiton {
             health: 100,
             speed: 2.75,
+            patrol: Vec::new(),
         },
         x: 1.5,
         y: 1.5,
@@ -159,6 +160,8 @@ fn map_authored_mosquiton_speed_is_preserved_on_server_spawn() {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     server.update();
 
@@ -179,6 +182,7 @@ fn open_map_mosquiton_reaches_preferred_range_then_holds() {
         kind: EntitySpawnKind::Mosquiton {
             health: 100,
             speed: 3.0,
+            patrol: Vec::new(),
         },
         x: 6.5,
         y: 1.5,
@@ -192,6 +196,8 @@ fn open_map_mosquiton_reaches_preferred_range_then_holds() {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     server.update();
     let player_position = Vec2::new(1.5, 1.5);
@@ -199,7 +205,7 @@ fn open_map_mosquiton_reaches_preferred_range_then_holds() {
         player_id: PlayerId(1),
         position: player_position,
         angle: 0.0,
-        current_attack: NetAttackId::None,
+        current_attack: NetAttackId::default(),
         state: PlayerNetState::Alive,
         flame_active: false,
         avatar_palette_variant: None,
@@ -281,6 +287,7 @@ fn server_ai_updates_live_mosquiton_position() {
         kind: EntitySpawnKind::Mosquiton {
             health: 100,
             speed: 1.2,
+            patrol: Vec::new(),
         },
         x: 1.5,
         y: 1.5,
@@ -294,13 +301,15 @@ fn server_ai_updates_live_mosquiton_position() {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     server.update();
     server.world_mut().spawn(NetPlayer {
         player_id: PlayerId(1),
         position: Vec2::new(6.5, 1.5),
         angle: 0.0,
-        current_attack: NetAttackId::None,
+        current_attack: NetAttackId::default(),
         state: PlayerNetState::Alive,
         flame_active: false,
         avatar_palette_variant: None,
@@ -341,6 +350,7 @@ fn map_authored_speed_changes_mosquiton_movement_distance() {
             kind: EntitySpawnKind::Mosquiton {
                 health: 100,
                 speed: 0.5,
+                patrol: Vec::new(),
             },
             x: 1.5,
             y: 2.0,
@@ -349,6 +359,7 @@ fn map_authored_speed_changes_mosquiton_movement_distance() {
             kind: EntitySpawnKind::Mosquiton {
                 health: 100,
                 speed: 2.0,
+                patrol: Vec::new(),
             },
             x: 1.5,
             y: 3.0,
@@ -363,13 +374,15 @@ fn map_authored_speed_changes_mosquiton_movement_distance() {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     server.update();
     server.world_mut().spawn(NetPlayer {
         player_id: PlayerId(1),
         position: Vec2::new(8.5, 2.5),
         angle: 0.0,
-        current_attack: NetAttackId::None,
+        current_attack: NetAttackId::default(),
         state: PlayerNetState::Alive,
         flame_active: false,
         avatar_palette_variant: None,
@@ -423,6 +436,7 @@ fn client_receives_replicated_mosquiton_position_updates() {
         kind: EntitySpawnKind::Mosquiton {
             health: 100,
             speed: 2.0,
+            patrol: Vec::new(),
         },
         x: 7.5,
         y: 2.5,
@@ -436,6 +450,8 @@ fn client_receives_replicated_mosquiton_position_updates() {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     server.update();
 
@@ -465,6 +481,7 @@ fn server_ai_does_not_move_dead_mosquiton() {
         kind: EntitySpawnKind::Mosquiton {
             health: 100,
             speed: 1.2,
+            patrol: Vec::new(),
         },
         x: 1.5,
         y: 1.5,
@@ -478,13 +495,15 @@ fn server_ai_does_not_move_dead_mosquiton() {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     server.update();
     server.world_mut().spawn(NetPlayer {
         player_id: PlayerId(1),
         position: Vec2::new(5.5, 1.5),
         angle: 0.0,
-        current_attack: NetAttackId::None,
+        current_attack: NetAttackId::default(),
         state: PlayerNetState::Alive,
         flame_active: false,
         avatar_palette_variant: None,
@@ -542,6 +561,8 @@ fn default_map_first_spawn_has_live_flamethrower_target() {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     server.update();
 
@@ -623,7 +644,7 @@ fn default_map_flamethrower_damages_from_spawn() {
     }
     assert_eq!(
         get_player_attack(&mut server),
-        Some(NetAttackId::Projectile)
+        Some(attack_slot("flamethrower"))
     );
 
     // Burn system builds intensity progressively — more ticks needed than instant DPS.
@@ -690,7 +711,7 @@ fn flamethrower_e2e_switch_fire_release_uses_server_state() {
 
     assert!(wait_for_player(&mut server, &mut client));
     let player_id = get_player_id(&mut server).unwrap();
-    assert_eq!(get_player_attack(&mut server), Some(NetAttackId::None));
+    assert_eq!(get_player_attack(&mut server), Some(NetAttackId::default()));
 
     queue_switch(&mut client, 1);
     for _ in 0..20 {
@@ -698,7 +719,7 @@ fn flamethrower_e2e_switch_fire_release_uses_server_state() {
     }
     assert_eq!(
         get_player_attack(&mut server),
-        Some(NetAttackId::Projectile),
+        Some(attack_slot("flamethrower")),
         "BTN_SWITCH should change server NetPlayer.current_attack to flamethrower"
     );
 
@@ -914,7 +935,7 @@ fn pistol_still_uses_cooldown_after_switch() {
     let hp_after = get_enemy_health(&mut server).unwrap();
     let damage = initial_hp - hp_after;
 
-    let expected = carcinisation_fps_core::FpsCombatConfig::default().hitscan_damage;
+    let expected = common::pistol_damage();
     assert!(
         (damage - expected).abs() < 1.0,
         "pistol should do hitscan damage ({expected}): got {damage}"
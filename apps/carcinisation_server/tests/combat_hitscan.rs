@@ -199,7 +199,7 @@ fn combat_uses_fixed_tick_cooldown() {
     // Exactly one shot should land (37 damage). Two shots would be 74.
     assert_eq!(
         damage,
-        carcinisation_fps_core::FpsCombatConfig::default().hitscan_damage,
+        common::pistol_damage(),
         "cooldown should allow exactly 1 shot, got damage={damage}"
     );
 
@@ -18,18 +18,14 @@ use carcinisation_net::{
     ClientIntent, InputSequence, NetAttackId, NetEnemyState, NetHealth, NetPlayer, PlayerActions,
     PlayerId, PlayerNetState,
 };
-use carcinisation_server::ServerPlugin;
 use carcinisation_server::systems::ServerQuickTurn;
-use common::{build_deterministic_server_with_enemies, build_server_app};
+use carcinisation_server::{ServerAuthMode, ServerPlugin};
+use common::{attack_slot, build_deterministic_server_with_enemies, build_server_app};
 
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
 
-fn hitscan_damage() -> f32 {
-    carcinisation_fps_core::FpsCombatConfig::default().hitscan_damage
-}
-
 fn build_combat_server(enemy_x: f32, enemy_y: f32) -> App {
     build_deterministic_server_with_enemies(test_map(), vec![(enemy_x, enemy_y, 200, 0.0)])
 }
@@ -40,7 +36,7 @@ fn spawn_player(server: &mut App, pid: u32, x: f32, y: f32, angle: f32) {
             player_id: PlayerId(pid),
             position: Vec2::new(x, y),
             angle,
-            current_attack: NetAttackId::None,
+            current_attack: NetAttackId::default(),
             state: PlayerNetState::Alive,
             flame_active: false,
             avatar_palette_variant: None,
@@ -65,6 +61,7 @@ fn inject(server: &mut App, pid: u32, intent: &ClientIntent) {
 fn fire_intent() -> ClientIntent {
     ClientIntent {
         sequence: InputSequence(0),
+        acked_sequence: InputSequence(0),
         movement: Vec2::ZERO,
         turn: 0.0,
         fire_held: true,
@@ -76,6 +73,7 @@ fn fire_intent() -> ClientIntent {
 const fn switch_intent() -> ClientIntent {
     ClientIntent {
         sequence: InputSequence(0),
+        acked_sequence: InputSequence(0),
         movement: Vec2::ZERO,
         turn: 0.0,
         fire_held: false,
@@ -116,7 +114,7 @@ fn hitscan_shared_function_parity() {
     assert!(result.distance > 2.0 && result.distance < 4.0);
 }
 
-/// MP server pistol fires once per cooldown period, dealing exactly hitscan_damage().
+/// MP server pistol fires once per cooldown period, dealing exactly the pistol damage.
 #[test]
 fn pistol_cooldown_parity() {
     // Enemy at (4.5, 1.5), player at (1.5, 1.5) facing east.
@@ -136,7 +134,7 @@ fn pistol_cooldown_parity() {
     let final_hp = get_enemy_health(&mut server);
     let total_damage = initial_hp - final_hp;
 
-    let dmg = hitscan_damage();
+    let dmg = common::pistol_damage();
 
     // Expected: ~3 shots × dmg. Allow 1 shot variance for edge timing.
     let expected_min = dmg * 2.0;
@@ -155,6 +153,32 @@ fn pistol_cooldown_parity() {
     );
 }
 
+/// Server melee uses the `weapons.ron` melee entry: short reach, heavier hit.
+#[test]
+fn melee_uses_weapon_table_range_and_damage() {
+    let melee_damage = |enemy_x: f32| {
+        let mut server = build_combat_server(enemy_x, 1.5);
+        server.update();
+        spawn_player(&mut server, 1, 1.5, 1.5, 0.0);
+        let mut players = server.world_mut().query::<&mut NetPlayer>();
+        for mut player in players.iter_mut(server.world_mut()) {
+            player.current_attack = attack_slot("melee");
+        }
+        let initial_hp = get_enemy_health(&mut server);
+        for _ in 0..3 {
+            inject(&mut server, 1, &fire_intent());
+            server.update();
+        }
+        initial_hp - get_enemy_health(&mut server)
+    };
+
+    assert_eq!(melee_damage(4.5), 0.0, "target beyond melee range");
+    assert!(
+        melee_damage(2.4) > common::pistol_damage(),
+        "melee in reach outhits the pistol"
+    );
+}
+
 // ---------------------------------------------------------------------------
 // 2. Weapon switch then fire parity
 // ---------------------------------------------------------------------------
@@ -246,11 +270,14 @@ fn flamethrower_wall_blocked_parity() {
             1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0,
             0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1,
         ],
+        mechanisms: carcinisation_fps_core::MapMechanisms::default(),
+        heights: Vec::new(),
     };
     let entities = vec![EntitySpawnData {
         kind: EntitySpawnKind::Mosquiton {
             health: 100,
             speed: 0.0,
+            patrol: Vec::new(),
         },
         x: 5.5,
         y: 1.5,
@@ -263,6 +290,8 @@ fn flamethrower_wall_blocked_parity() {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     server.insert_resource(bevy::time::TimeUpdateStrategy::FixedTimesteps(1));
     server.update();
@@ -297,6 +326,7 @@ fn lethal_damage_dying_dead_parity() {
         kind: EntitySpawnKind::Mosquiton {
             health: 30,
             speed: 0.0,
+            patrol: Vec::new(),
         },
         x: 4.5,
         y: 1.5,
@@ -309,6 +339,8 @@ fn lethal_damage_dying_dead_parity() {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     server.insert_resource(bevy::time::TimeUpdateStrategy::FixedTimesteps(1));
     server.update();
@@ -355,6 +387,7 @@ fn flame_lethal_damage_burn_parity() {
         kind: EntitySpawnKind::Mosquiton {
             health: 10,
             speed: 0.0,
+            patrol: Vec::new(),
         },
         x: 3.5,
         y: 1.5,
@@ -367,6 +400,8 @@ fn flame_lethal_damage_burn_parity() {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     server.insert_resource(bevy::time::TimeUpdateStrategy::FixedTimesteps(1));
     server.update();
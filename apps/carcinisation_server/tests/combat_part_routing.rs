@@ -76,7 +76,7 @@ fn fire_one_shot_delta(angle_facing: f32) -> f32 {
 
 #[test]
 fn server_spidey_headshot_scales_damage_through_live_combat() {
-    let base = FpsCombatConfig::default().hitscan_damage; // 37.0
+    let base = common::pistol_damage(); // 37.0
 
     let body = fire_one_shot_delta(0.0); // facing away → body
     let head = fire_one_shot_delta(PI); // facing player → head
@@ -112,7 +112,7 @@ fn server_spidey_headshot_scales_damage_through_live_combat() {
 #[test]
 fn server_headshot_feedback_is_critical_body_is_not() {
     let combat = FpsCombatConfig::default();
-    let base = combat.hitscan_damage;
+    let base = common::pistol_damage();
     let radius = enemy_fallback_radius(FpsEnemyKind::Spidey, &combat);
 
     // Head (faces player): amplified part → critical, authored part id on wire.
@@ -177,7 +177,7 @@ fn route_via_kernel(yaw: f32, fallback_radius: f32, base: f32) -> (PartId, f32,
 #[test]
 fn sp_server_parity_spidey_headshot() {
     let combat = FpsCombatConfig::default();
-    let base = combat.hitscan_damage;
+    let base = common::pistol_damage();
 
     // SP-style construction: yaw re-derived toward the player, instance radius.
     let sp_yaw = facing_yaw_toward(ENEMY_POS, PLAYER_POS).unwrap_or(0.0);
@@ -250,7 +250,7 @@ fn collision_facing_uses_gameplay_yaw_not_net_enemy_angle() {
     }
     let after = get_enemy_health(&mut server).expect("spidey present");
 
-    let base = FpsCombatConfig::default().hitscan_damage;
+    let base = common::pistol_damage();
     assert_eq!(
         before - after,
         base * 2.0,
@@ -272,7 +272,10 @@ fn collision_facing_uses_gameplay_yaw_not_net_enemy_angle() {
 #[test]
 fn sp_server_parity_part_reaction_profile() {
     let combat = FpsCombatConfig::default();
-    let weapon = combat.enemy_reaction.pistol;
+    let weapon = carcinisation_fps_core::WeaponTable::default()
+        .get("pistol")
+        .expect("stock pistol")
+        .reaction;
     let dir = FirePose2d::new(PLAYER_POS, 0.0, 0.0).direction();
 
     let reaction_of = |yaw: f32, fallback_radius: f32| {
@@ -360,7 +363,7 @@ fn armoured_test_set(armour: f32) -> TargetCollisionSet {
 #[test]
 fn armour_reduces_damage_through_routing_boundary() {
     let map = test_map();
-    let base = FpsCombatConfig::default().hitscan_damage; // 37.0
+    let base = common::pistol_damage(); // 37.0
     let set = armoured_test_set(10.0);
 
     let r = hitscan_parts_from_pose(
@@ -428,13 +431,8 @@ fn armour_reduces_damage_through_routing_boundary() {
 
 #[test]
 fn rounding_contract_shipped_part_data_is_integer_resolving() {
-    let combat = FpsCombatConfig::default();
-    let base = combat.hitscan_damage;
-    assert_eq!(
-        base.fract(),
-        0.0,
-        "shipped base hitscan_damage must be integral"
-    );
+    let base = common::pistol_damage();
+    assert_eq!(base.fract(), 0.0, "shipped pistol damage must be integral");
 
     for kind in [
         FpsEnemyKind::Basic,
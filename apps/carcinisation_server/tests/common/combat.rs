@@ -13,8 +13,8 @@ use carcinisation_net::{
     ClientIntent, FlameActive, InputSequence, NetAttackId, NetEnemyState, NetHealth, NetPlayer,
     NetProtocolPlugin, PlayerActions, PlayerId, register_net_all,
 };
-use carcinisation_server::ServerPlugin;
 use carcinisation_server::systems::FlameActiveTracker;
+use carcinisation_server::{ServerAuthMode, ServerPlugin};
 
 use super::{build_client_app, build_server_app, tick_with_sleep};
 
@@ -51,6 +51,7 @@ pub fn queue_fire(app: &mut App, seq: u32) {
         .0
         .push(ClientIntent {
             sequence: InputSequence(seq),
+            acked_sequence: InputSequence(0),
             movement: Vec2::ZERO,
             turn: 0.0,
             fire_held: true,
@@ -72,6 +73,7 @@ pub fn queue_switch(app: &mut App, seq: u32) {
         .0
         .push(ClientIntent {
             sequence: InputSequence(seq),
+            acked_sequence: InputSequence(0),
             movement: Vec2::ZERO,
             turn: 0.0,
             fire_held: false,
@@ -91,6 +93,7 @@ pub fn build_combat_server(port: u16) -> App {
         kind: EntitySpawnKind::Mosquiton {
             health: 100,
             speed: 0.0,
+            patrol: Vec::new(),
         },
         x: 4.5,
         y: 1.5,
@@ -103,6 +106,8 @@ pub fn build_combat_server(port: u16) -> App {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     })
 }
 
@@ -113,6 +118,7 @@ pub fn build_flame_server(port: u16) -> App {
         kind: EntitySpawnKind::Mosquiton {
             health: 200,
             speed: 0.0,
+            patrol: Vec::new(),
         },
         x: 3.5,
         y: 1.5,
@@ -125,6 +131,8 @@ pub fn build_flame_server(port: u16) -> App {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     })
 }
 
@@ -156,6 +164,8 @@ pub fn build_default_map_server(port: u16) -> App {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     })
 }
 
@@ -173,6 +183,8 @@ pub fn open_test_map(width: usize, height: usize) -> Map {
         width,
         height,
         cells,
+        mechanisms: carcinisation_fps_core::MapMechanisms::default(),
+        heights: Vec::new(),
     }
 }
 
@@ -2,6 +2,8 @@
 
 pub mod combat;
 pub mod reset;
+pub mod systems;
+pub mod ws;
 
 use std::net::SocketAddr;
 use std::sync::OnceLock;
@@ -9,11 +11,27 @@ use std::sync::atomic::{AtomicU16, Ordering};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use bevy::prelude::*;
-use bevy_renet2::netcode::{NativeSocket, NetcodeClientTransport};
+use bevy_renet2::netcode::{ClientAuthentication, NativeSocket, NetcodeClientTransport};
 use bevy_replicon::prelude::*;
 use bevy_replicon_renet2::RenetChannelsExt;
 use carcinisation_fps_core::map::test_map;
-use carcinisation_server::ServerPlugin;
+use carcinisation_net::transport::MultiplayerTransport;
+use carcinisation_server::{ServerAuthMode, ServerPlugin};
+
+/// Base damage of the stock pistol in `weapons.ron`.
+#[allow(clippy::cast_precision_loss)]
+pub fn pistol_damage() -> f32 {
+    carcinisation_fps_core::WeaponTable::default()
+        .get("pistol")
+        .and_then(carcinisation_fps_core::WeaponDef::hitscan_damage)
+        .expect("stock pistol is hitscan") as f32
+}
+
+/// Wire attack id of a stock `weapons.ron` entry.
+pub fn attack_slot(id: &str) -> NetAttackId {
+    NetAttackId::of(&carcinisation_fps_core::WeaponTable::default(), id)
+        .unwrap_or_else(|| panic!("stock weapon table has no '{id}'"))
+}
 
 /// Create a `ServerPlugin` for tests using the hardcoded `test_map` (no entities).
 pub fn test_server_plugin(port: u16) -> ServerPlugin {
@@ -25,6 +43,8 @@ pub fn test_server_plugin(port: u16) -> ServerPlugin {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     }
 }
 
@@ -104,6 +124,24 @@ pub fn build_client_app(
     net_protocol: impl Plugin + 'static,
     register_net: fn(&mut App),
     server_addr: SocketAddr,
+) -> App {
+    build_client_app_with_auth(net_protocol, register_net, move |client_id| {
+        ClientAuthentication::Unsecure {
+            client_id,
+            protocol_id: carcinisation_net::PROTOCOL_ID,
+            socket_id: MultiplayerTransport::NativeUdp.socket_id(),
+            server_addr,
+            user_data: None,
+        }
+    })
+}
+
+/// Build a minimal headless client App with custom netcode authentication.
+/// `authentication` receives a fresh client id (ignored by connect tokens).
+pub fn build_client_app_with_auth(
+    net_protocol: impl Plugin + 'static,
+    register_net: fn(&mut App),
+    authentication: impl Fn(u64) -> ClientAuthentication + Send + Sync + 'static,
 ) -> App {
     let mut app = App::new();
     app.add_plugins((MinimalPlugins, bevy::state::app::StatesPlugin));
@@ -141,16 +179,9 @@ pub fn build_client_app(
                     NativeSocket::new(std::net::UdpSocket::bind(local_addr).expect("bind"))
                         .expect("create socket");
 
-                let authentication = bevy_renet2::netcode::ClientAuthentication::Unsecure {
-                    client_id,
-                    protocol_id: carcinisation_net::PROTOCOL_ID,
-                    socket_id: 0,
-                    server_addr,
-                    user_data: None,
-                };
-
-                let transport = NetcodeClientTransport::new(current_time, authentication, socket)
-                    .expect("create client transport");
+                let transport =
+                    NetcodeClientTransport::new(current_time, authentication(client_id), socket)
+                        .expect("create client transport");
 
                 let client = RenetClient::new(connection_config, transport.is_reliable());
 
@@ -279,7 +310,11 @@ pub fn build_server_with_enemies(
     let entities = enemies
         .into_iter()
         .map(|(x, y, health, speed)| EntitySpawnData {
-            kind: EntitySpawnKind::Mosquiton { health, speed },
+            kind: EntitySpawnKind::Mosquiton {
+                health,
+                speed,
+                patrol: Vec::new(),
+            },
             x,
             y,
         })
@@ -292,6 +327,8 @@ pub fn build_server_with_enemies(
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     })
 }
 
@@ -318,7 +355,11 @@ pub fn build_deterministic_server_with_enemies(
     let entities = enemies
         .into_iter()
         .map(|(x, y, health, speed)| EntitySpawnData {
-            kind: EntitySpawnKind::Mosquiton { health, speed },
+            kind: EntitySpawnKind::Mosquiton {
+                health,
+                speed,
+                patrol: Vec::new(),
+            },
             x,
             y,
         })
@@ -331,6 +372,8 @@ pub fn build_deterministic_server_with_enemies(
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     app.insert_resource(TimeUpdateStrategy::FixedTimesteps(1));
     app
@@ -358,6 +401,8 @@ pub fn build_deterministic_server_with_basic_enemy(enemy_x: f32, enemy_y: f32) -
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     app.insert_resource(TimeUpdateStrategy::FixedTimesteps(1));
     app
@@ -379,6 +424,19 @@ pub fn wait_for_deterministic(
     false
 }
 
+/// Alive player at `position` with the default attack (angle 0, facing east).
+pub fn net_player(pid: u32, position: Vec2) -> NetPlayer {
+    NetPlayer {
+        player_id: PlayerId(pid),
+        position,
+        angle: 0.0,
+        current_attack: NetAttackId::default(),
+        state: PlayerNetState::Alive,
+        flame_active: false,
+        avatar_palette_variant: None,
+    }
+}
+
 /// Spawn an alive player with full health at the given position (angle 0, facing east).
 pub fn spawn_alive_player(server: &mut App, pid: u32, x: f32, y: f32) {
     spawn_player_with_state(server, pid, x, y, PlayerNetState::Alive);
@@ -393,13 +451,8 @@ pub fn spawn_player_with_state(server: &mut App, pid: u32, x: f32, y: f32, state
     };
     server.world_mut().spawn((
         NetPlayer {
-            player_id: PlayerId(pid),
-            position: Vec2::new(x, y),
-            angle: 0.0,
-            current_attack: NetAttackId::None,
             state,
-            flame_active: false,
-            avatar_palette_variant: None,
+            ..net_player(pid, Vec2::new(x, y))
         },
         NetHealth {
             current: hp,
@@ -525,6 +578,7 @@ pub fn inject_fire(server: &mut App, pid: u32) {
         PlayerId(pid),
         &ClientIntent {
             sequence: InputSequence(0),
+            acked_sequence: InputSequence(0),
             movement: Vec2::ZERO,
             turn: 0.0,
             fire_held: true,
@@ -540,6 +594,7 @@ pub fn inject_intent(server: &mut App, pid: u32, movement: Vec2, fire_held: bool
         PlayerId(pid),
         &ClientIntent {
             sequence: InputSequence(0),
+            acked_sequence: InputSequence(0),
             movement,
             turn: 0.0,
             fire_held,
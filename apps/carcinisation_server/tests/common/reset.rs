@@ -6,8 +6,8 @@ use bevy::prelude::*;
 use carcinisation_fps_core::map::{EntitySpawnData, EntitySpawnKind, test_map};
 use carcinisation_fps_core::pickup::PickupKind;
 use carcinisation_net::{NetAttackId, NetHealth, NetPlayer, PlayerId, PlayerNetState};
-use carcinisation_server::ServerPlugin;
 use carcinisation_server::systems::ServerQuickTurn;
+use carcinisation_server::{ServerAuthMode, ServerPlugin};
 
 use super::{build_server_app, reserve_port};
 
@@ -22,6 +22,7 @@ pub fn one_enemy() -> Vec<EntitySpawnData> {
         kind: EntitySpawnKind::Mosquiton {
             health: 100,
             speed: 0.0,
+            patrol: Vec::new(),
         },
     }]
 }
@@ -61,6 +62,8 @@ pub fn build_reset_server(entities: Vec<EntitySpawnData>, admin_socket: Option<S
         admin_socket,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     })
 }
 
@@ -78,7 +81,7 @@ pub fn spawn_player(app: &mut App, pid: u32, x: f32, y: f32) -> Entity {
                 player_id: PlayerId(pid),
                 position: Vec2::new(x, y),
                 angle: 0.0,
-                current_attack: NetAttackId::None,
+                current_attack: NetAttackId::default(),
                 state: PlayerNetState::Alive,
                 flame_active: false,
                 avatar_palette_variant: None,
@@ -0,0 +1,61 @@
+//! Fixtures for driving individual server systems without networking.
+//!
+//! The app has only a `ServerMap` and a `Time<Fixed>` that [`tick`] advances
+//! by hand; each test adds the resources and systems it exercises.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::{Fixed, Time};
+use carcinisation_fps_core::Map;
+use carcinisation_net::{NetEnemy, NetEnemyState, NetEnemyType, NetHealth, NetworkObjectId};
+use carcinisation_server::ServerMap;
+use carcinisation_server::systems::combat::EnemyGameplayYaw;
+
+use super::net_player;
+
+/// Fixed step [`tick`] advances by.
+pub const TICK: Duration = Duration::from_millis(100);
+
+/// Bare app on the map parsed from `map_ron`.
+pub fn system_test_app(map_ron: &str) -> App {
+    let mut app = App::new();
+    app.insert_resource(ServerMap(Map::from_ron(map_ron).expect("test map")))
+        .init_resource::<Time<Fixed>>();
+    app
+}
+
+/// Spawn an alive player with no health or replication components.
+pub fn spawn_player(app: &mut App, pid: u32, position: Vec2) -> Entity {
+    app.world_mut().spawn(net_player(pid, position)).id()
+}
+
+/// Idle 10 HP Mosquiton at `position`, facing east. Tests add the AI or
+/// simulation components they drive.
+pub fn mosquiton(position: Vec2) -> (NetEnemy, NetHealth, EnemyGameplayYaw) {
+    (
+        NetEnemy {
+            object_id: NetworkObjectId(1),
+            position,
+            angle: 0.0,
+            state: NetEnemyState::Idle,
+            enemy_type: NetEnemyType::Mosquiton,
+            visual_height: 0.0,
+            visual_phase: 0.0,
+            stunned: false,
+        },
+        NetHealth {
+            current: 10.0,
+            max: 10.0,
+        },
+        EnemyGameplayYaw(0.0),
+    )
+}
+
+/// Advance `Time<Fixed>` by [`TICK`] and run `Update`.
+pub fn tick(app: &mut App) {
+    app.world_mut()
+        .resource_mut::<Time<Fixed>>()
+        .advance_by(TICK);
+    app.update();
+}
@@ -0,0 +1,242 @@
+//! Native loopback WebSocket client for exercising the server's browser
+//! transport without a browser.
+//!
+//! renet2's own WebSocket client only compiles for wasm, so this mirrors its
+//! wire behaviour with blocking `tungstenite` on a background thread: the
+//! first netcode packet (the connection request) is carried in the upgrade
+//! URL, every later packet is a binary frame.
+
+use std::io::ErrorKind;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, Sender, TryRecvError, channel};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use bevy::prelude::*;
+use bevy_renet2::netcode::{
+    ClientAuthentication, ClientSocket, NetcodeClientTransport, NetcodeServerTransport,
+    NetcodeTransportError,
+};
+use bevy_replicon::prelude::*;
+use bevy_replicon_renet2::RenetChannelsExt;
+use bevy_replicon_renet2::renet2::{ConnectionConfig, RenetClient};
+use carcinisation_net::ConnectMode;
+use carcinisation_net::transport::MultiplayerTransport;
+use tungstenite::Message;
+use tungstenite::stream::MaybeTlsStream;
+
+/// Query key renet2's WebSocket server reads the connection request from.
+const CONNECT_REQ_QUERY: &str = "creq";
+
+#[derive(Debug)]
+pub struct LoopbackWebSocketClient {
+    server_addr: SocketAddr,
+    connect_req: Option<Sender<Vec<u8>>>,
+    outgoing: Sender<Vec<u8>>,
+    incoming: Mutex<Receiver<Vec<u8>>>,
+    closed: Arc<AtomicBool>,
+}
+
+impl LoopbackWebSocketClient {
+    pub fn new(server_addr: SocketAddr) -> Self {
+        let (connect_tx, connect_rx) = channel::<Vec<u8>>();
+        let (outgoing_tx, outgoing_rx) = channel::<Vec<u8>>();
+        let (incoming_tx, incoming_rx) = channel::<Vec<u8>>();
+        let closed = Arc::new(AtomicBool::new(false));
+
+        let thread_closed = closed.clone();
+        std::thread::spawn(move || {
+            run_socket(
+                server_addr,
+                &connect_rx,
+                &outgoing_rx,
+                &incoming_tx,
+                &thread_closed,
+            );
+            thread_closed.store(true, Ordering::Relaxed);
+        });
+
+        Self {
+            server_addr,
+            connect_req: Some(connect_tx),
+            outgoing: outgoing_tx,
+            incoming: Mutex::new(incoming_rx),
+            closed,
+        }
+    }
+}
+
+fn run_socket(
+    server_addr: SocketAddr,
+    connect_rx: &Receiver<Vec<u8>>,
+    outgoing_rx: &Receiver<Vec<u8>>,
+    incoming_tx: &Sender<Vec<u8>>,
+    closed: &AtomicBool,
+) {
+    let Ok(request) = connect_rx.recv() else {
+        return;
+    };
+    let url = format!(
+        "ws://{server_addr}/?{CONNECT_REQ_QUERY}={}",
+        urlencoding::encode_binary(&request)
+    );
+    let Ok((mut ws, _)) = tungstenite::connect(url) else {
+        return;
+    };
+    if let MaybeTlsStream::Plain(stream) = ws.get_mut() {
+        let _ = stream.set_read_timeout(Some(Duration::from_millis(1)));
+    }
+
+    while !closed.load(Ordering::Relaxed) {
+        loop {
+            match outgoing_rx.try_recv() {
+                Ok(packet) => {
+                    if ws.send(Message::binary(packet)).is_err() {
+                        return;
+                    }
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => return,
+            }
+        }
+        match ws.read() {
+            Ok(Message::Binary(data)) => {
+                if incoming_tx.send(data.to_vec()).is_err() {
+                    return;
+                }
+            }
+            Ok(_) => {}
+            Err(tungstenite::Error::Io(e))
+                if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+            Err(_) => return,
+        }
+    }
+    let _ = ws.close(None);
+}
+
+impl ClientSocket for LoopbackWebSocketClient {
+    fn is_encrypted(&self) -> bool {
+        // Plain `ws://`: netcode encrypts packets itself.
+        false
+    }
+
+    fn is_reliable(&self) -> bool {
+        true
+    }
+
+    fn addr(&self) -> std::io::Result<SocketAddr> {
+        Err(std::io::Error::from(ErrorKind::AddrNotAvailable))
+    }
+
+    fn is_closed(&mut self) -> bool {
+        self.closed.load(Ordering::Relaxed)
+    }
+
+    fn close(&mut self) {
+        self.closed.store(true, Ordering::Relaxed);
+    }
+
+    fn preupdate(&mut self) {}
+
+    fn try_recv(&mut self, buffer: &mut [u8]) -> std::io::Result<(usize, SocketAddr)> {
+        if self.closed.load(Ordering::Relaxed) {
+            return Err(std::io::Error::from(ErrorKind::ConnectionAborted));
+        }
+        let packet = self
+            .incoming
+            .lock()
+            .expect("incoming lock")
+            .try_recv()
+            .map_err(|_| std::io::Error::from(ErrorKind::WouldBlock))?;
+        if packet.len() > buffer.len() {
+            return Err(std::io::Error::from(ErrorKind::InvalidData));
+        }
+        buffer[..packet.len()].copy_from_slice(&packet);
+        Ok((packet.len(), self.server_addr))
+    }
+
+    fn postupdate(&mut self) {}
+
+    fn send(&mut self, _addr: SocketAddr, packet: &[u8]) -> Result<(), NetcodeTransportError> {
+        if self.closed.load(Ordering::Relaxed) {
+            return Err(std::io::Error::from(ErrorKind::ConnectionAborted).into());
+        }
+        // The netcode client always sends its connection request first.
+        if let Some(connect_req) = self.connect_req.take() {
+            let _ = connect_req.send(packet.to_vec());
+            return Ok(());
+        }
+        self.outgoing
+            .send(packet.to_vec())
+            .map_err(|_| std::io::Error::from(ErrorKind::ConnectionAborted).into())
+    }
+}
+
+/// Address the server's WebSocket socket was registered with, rewritten to
+/// loopback so a local client can reach it.
+pub fn server_ws_addr(server: &App) -> SocketAddr {
+    let addr = server
+        .world()
+        .resource::<NetcodeServerTransport>()
+        .get_addresses(usize::from(MultiplayerTransport::WebSocket.socket_id()))
+        .expect("server should have a WebSocket socket")[0];
+    SocketAddr::new(
+        std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST),
+        addr.port(),
+    )
+}
+
+/// Build a minimal headless client App connecting over WebSocket.
+pub fn build_ws_client_app(server_addr: SocketAddr, connect_mode: ConnectMode) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, bevy::state::app::StatesPlugin));
+
+    app.add_plugins(carcinisation_net::NetProtocolPlugin)
+        .add_plugins(RepliconSharedPlugin {
+            auth_method: AuthMethod::None,
+        })
+        .add_plugins(bevy_replicon::prelude::ClientPlugin)
+        .add_plugins(bevy_replicon::prelude::ClientMessagePlugin);
+
+    carcinisation_net::register_net_all(&mut app);
+
+    app.add_plugins(bevy_replicon_renet2::RepliconRenetPlugins)
+        .add_systems(
+            Startup,
+            move |mut commands: Commands, channels: Res<RepliconChannels>| {
+                let current_time = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .expect("time went backwards");
+                let client_id = current_time.as_nanos() as u64;
+
+                let connection_config = ConnectionConfig::from_channels(
+                    channels.server_configs(),
+                    channels.client_configs(),
+                );
+
+                let authentication = ClientAuthentication::Unsecure {
+                    client_id,
+                    protocol_id: carcinisation_net::PROTOCOL_ID,
+                    socket_id: MultiplayerTransport::WebSocket.socket_id(),
+                    server_addr,
+                    user_data: Some(connect_mode.to_user_data()),
+                };
+
+                let transport = NetcodeClientTransport::new(
+                    current_time,
+                    authentication,
+                    LoopbackWebSocketClient::new(server_addr),
+                )
+                .expect("create client transport");
+
+                let client = RenetClient::new(connection_config, transport.is_reliable());
+
+                commands.insert_resource(client);
+                commands.insert_resource(transport);
+            },
+        );
+
+    app.finish();
+    app
+}
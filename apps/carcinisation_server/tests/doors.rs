@@ -0,0 +1,87 @@
+mod common;
+
+use bevy::prelude::*;
+use carcinisation_net::tick::InputSequence;
+use carcinisation_net::{ClientIntent, NetDoor, PlayerActions, PlayerId};
+use carcinisation_server::ServerMap;
+use carcinisation_server::systems::reset::MapResetRequested;
+use carcinisation_server::systems::{
+    PlayerIntentBuffer, reset_map_mechanisms, tick_map_mechanisms,
+};
+use common::systems::{spawn_player, system_test_app, tick};
+
+/// 5x3 corridor split by a sliding door at (2, 1).
+const DOOR_MAP: &str = r#"MapData(
+    width: 5, height: 3,
+    cells: [1,1,1,1,1, 1,0,3,0,1, 1,1,1,1,1],
+    doors: [(id: "gate", x: 2, y: 1, kind: Sliding(), speed: 4.0)],
+)"#;
+
+fn test_app() -> App {
+    let mut app = system_test_app(DOOR_MAP);
+    app.init_resource::<PlayerIntentBuffer>()
+        .init_resource::<MapResetRequested>()
+        .add_systems(Update, (reset_map_mechanisms, tick_map_mechanisms).chain());
+    app.world_mut().spawn(NetDoor {
+        index: 0,
+        open: 0.0,
+    });
+    spawn_player(&mut app, 1, Vec2::new(1.75, 1.5));
+    app
+}
+
+fn set_movement(app: &mut App, sequence: u32, movement: Vec2) {
+    app.world_mut().resource_mut::<PlayerIntentBuffer>().set(
+        PlayerId(1),
+        &ClientIntent {
+            sequence: InputSequence(sequence),
+            acked_sequence: InputSequence(0),
+            movement,
+            turn: 0.0,
+            fire_held: false,
+            aim_held: false,
+            actions: PlayerActions::default(),
+        },
+    );
+}
+
+fn net_door_open(app: &mut App) -> f32 {
+    app.world_mut()
+        .query::<&NetDoor>()
+        .single(app.world())
+        .expect("one door")
+        .open
+}
+
+#[test]
+fn idle_player_leaves_door_closed() {
+    let mut app = test_app();
+    tick(&mut app);
+    assert!(net_door_open(&mut app) < f32::EPSILON);
+    assert_eq!(app.world().resource::<ServerMap>().0.get(2, 1), 3);
+}
+
+#[test]
+fn pushing_opens_door_and_replicates_state() {
+    let mut app = test_app();
+    set_movement(&mut app, 1, Vec2::Y);
+    for _ in 0..3 {
+        tick(&mut app);
+    }
+    assert_eq!(app.world().resource::<ServerMap>().0.get(2, 1), 0);
+    assert!((net_door_open(&mut app) - 1.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn map_reset_closes_doors() {
+    let mut app = test_app();
+    set_movement(&mut app, 1, Vec2::Y);
+    for _ in 0..3 {
+        tick(&mut app);
+    }
+    set_movement(&mut app, 2, Vec2::ZERO);
+    app.world_mut().resource_mut::<MapResetRequested>().0 = true;
+    tick(&mut app);
+    assert_eq!(app.world().resource::<ServerMap>().0.get(2, 1), 3);
+    assert!(net_door_open(&mut app) < f32::EPSILON);
+}
@@ -12,8 +12,8 @@ use carcinisation_net::{
     NetEnemyState, NetHealth, NetPlayer, NetProjectile, NetProjectileType, NetSpeedModifier,
     NetworkObjectId, Owner,
 };
-use carcinisation_server::ServerPlugin;
 use carcinisation_server::systems::{ProjectileTtl, ServerSpideySimConfig};
+use carcinisation_server::{ServerAuthMode, ServerPlugin};
 use common::{build_server_app, reserve_port};
 
 // ---------------------------------------------------------------------------
@@ -26,6 +26,7 @@ fn build_attack_server(port: u16) -> App {
         kind: EntitySpawnKind::Mosquiton {
             health: 100,
             speed: 0.0,
+            patrol: Vec::new(),
         },
         x: 3.5,
         y: 1.5,
@@ -38,12 +39,18 @@ fn build_attack_server(port: u16) -> App {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     })
 }
 
 fn build_spidey_attack_server(port: u16, speed: f32) -> App {
     let entities = vec![EntitySpawnData {
-        kind: EntitySpawnKind::Spidey { health: 100, speed },
+        kind: EntitySpawnKind::Spidey {
+            health: 100,
+            speed,
+            patrol: Vec::new(),
+        },
         x: 5.5,
         y: 1.5,
     }];
@@ -55,6 +62,8 @@ fn build_spidey_attack_server(port: u16, speed: f32) -> App {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     })
 }
 
@@ -87,7 +96,7 @@ fn spawn_test_player(server: &mut App) {
             player_id: carcinisation_net::PlayerId(1),
             position: bevy::math::Vec2::new(1.5, 1.5),
             angle: 0.0,
-            current_attack: carcinisation_net::NetAttackId::None,
+            current_attack: carcinisation_net::NetAttackId::default(),
             state: carcinisation_net::PlayerNetState::Alive,
             flame_active: false,
             avatar_palette_variant: None,
@@ -257,6 +266,7 @@ fn each_enemy_targets_nearest_player() {
             kind: EntitySpawnKind::Mosquiton {
                 health: 100,
                 speed: 0.0,
+                patrol: Vec::new(),
             },
             x: 2.5,
             y: 1.5,
@@ -265,6 +275,7 @@ fn each_enemy_targets_nearest_player() {
             kind: EntitySpawnKind::Mosquiton {
                 health: 100,
                 speed: 0.0,
+                patrol: Vec::new(),
             },
             x: 5.5,
             y: 1.5,
@@ -278,6 +289,8 @@ fn each_enemy_targets_nearest_player() {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     server.update();
 
@@ -287,7 +300,7 @@ fn each_enemy_targets_nearest_player() {
             player_id: carcinisation_net::PlayerId(1),
             position: bevy::math::Vec2::new(1.5, 1.5),
             angle: 0.0,
-            current_attack: carcinisation_net::NetAttackId::None,
+            current_attack: carcinisation_net::NetAttackId::default(),
             state: carcinisation_net::PlayerNetState::Alive,
             flame_active: false,
             avatar_palette_variant: None,
@@ -305,7 +318,7 @@ fn each_enemy_targets_nearest_player() {
             player_id: carcinisation_net::PlayerId(2),
             position: bevy::math::Vec2::new(6.5, 1.5),
             angle: 0.0,
-            current_attack: carcinisation_net::NetAttackId::None,
+            current_attack: carcinisation_net::NetAttackId::default(),
             state: carcinisation_net::PlayerNetState::Alive,
             flame_active: false,
             avatar_palette_variant: None,
@@ -482,6 +495,7 @@ fn build_lunge_scenario() -> App {
         kind: EntitySpawnKind::Spidey {
             health: 100,
             speed: 2.0,
+            patrol: Vec::new(),
         },
         x: 5.5,
         y: 3.5,
@@ -494,6 +508,8 @@ fn build_lunge_scenario() -> App {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     server.update();
 
@@ -503,7 +519,7 @@ fn build_lunge_scenario() -> App {
             player_id: carcinisation_net::PlayerId(1),
             position: bevy::math::Vec2::new(3.5, 3.5),
             angle: 0.0,
-            current_attack: carcinisation_net::NetAttackId::None,
+            current_attack: carcinisation_net::NetAttackId::default(),
             state: carcinisation_net::PlayerNetState::Alive,
             flame_active: false,
             avatar_palette_variant: None,
@@ -657,6 +673,7 @@ fn two_spideys_lunge_same_player() {
             kind: EntitySpawnKind::Spidey {
                 health: 100,
                 speed: 2.0,
+                patrol: Vec::new(),
             },
             x: 5.5,
             y: 3.5,
@@ -665,6 +682,7 @@ fn two_spideys_lunge_same_player() {
             kind: EntitySpawnKind::Spidey {
                 health: 100,
                 speed: 2.0,
+                patrol: Vec::new(),
             },
             x: 1.5,
             y: 3.5,
@@ -678,6 +696,8 @@ fn two_spideys_lunge_same_player() {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     server.update();
 
@@ -687,7 +707,7 @@ fn two_spideys_lunge_same_player() {
             player_id: carcinisation_net::PlayerId(1),
             position: bevy::math::Vec2::new(3.5, 3.5),
             angle: 0.0,
-            current_attack: carcinisation_net::NetAttackId::None,
+            current_attack: carcinisation_net::NetAttackId::default(),
             state: carcinisation_net::PlayerNetState::Alive,
             flame_active: false,
             avatar_palette_variant: None,
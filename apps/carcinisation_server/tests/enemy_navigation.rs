@@ -0,0 +1,79 @@
+mod common;
+
+use bevy::prelude::*;
+use carcinisation_fps_core::has_line_of_sight;
+use carcinisation_net::NetEnemy;
+use carcinisation_server::ServerMap;
+use carcinisation_server::systems::{
+    ServerEnemyAiConfig, ServerEnemyNavigation, tick_net_enemy_ai, update_enemy_navigation,
+};
+use common::systems::{mosquiton, spawn_player, system_test_app, tick};
+
+/// A wall across row 3 with a single gap at its east end. The enemy starts
+/// straight above the player, so chasing in a line pins it to the wall.
+const WALL_MAP: &str = r#"MapData(
+    width: 9, height: 10,
+    cells: [
+        1,1,1,1,1,1,1,1,1,
+        1,0,0,0,0,0,0,0,1,
+        1,0,0,0,0,0,0,0,1,
+        1,1,1,1,1,1,1,0,1,
+        1,0,0,0,0,0,0,0,1,
+        1,0,0,0,0,0,0,0,1,
+        1,0,0,0,0,0,0,0,1,
+        1,0,0,0,0,0,0,0,1,
+        1,0,0,0,0,0,0,0,1,
+        1,1,1,1,1,1,1,1,1,
+    ],
+)"#;
+
+fn test_app() -> App {
+    let mut app = system_test_app(WALL_MAP);
+    app.init_resource::<ServerEnemyNavigation>()
+        .add_systems(Update, (update_enemy_navigation, tick_net_enemy_ai).chain());
+    spawn_player(&mut app, 1, Vec2::new(4.5, 8.5));
+    app.world_mut().spawn((
+        mosquiton(Vec2::new(4.5, 1.5)),
+        ServerEnemyAiConfig::mosquiton(1.2),
+    ));
+    app
+}
+
+fn enemy_position(app: &mut App) -> Vec2 {
+    app.world_mut()
+        .query::<&NetEnemy>()
+        .single(app.world())
+        .expect("one enemy")
+        .position
+}
+
+#[test]
+fn mosquiton_routes_through_the_gap_to_the_player() {
+    let mut app = test_app();
+    for _ in 0..150 {
+        tick(&mut app);
+    }
+    let position = enemy_position(&mut app);
+    let player = Vec2::new(4.5, 8.5);
+    assert!(position.y > 4.0, "got past the wall: {position:?}");
+    assert!(has_line_of_sight(
+        position,
+        player,
+        &app.world().resource::<ServerMap>().0
+    ));
+    assert!(position.distance(player) < 3.5, "closed in: {position:?}");
+}
+
+#[test]
+fn navigation_is_deterministic_across_servers() {
+    let mut a = test_app();
+    let mut b = test_app();
+    for _ in 0..60 {
+        tick(&mut a);
+        tick(&mut b);
+        assert_eq!(
+            enemy_position(&mut a).to_array().map(f32::to_bits),
+            enemy_position(&mut b).to_array().map(f32::to_bits)
+        );
+    }
+}
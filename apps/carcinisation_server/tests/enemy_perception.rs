@@ -0,0 +1,120 @@
+mod common;
+
+use bevy::prelude::*;
+use carcinisation_fps_core::{Awareness, EnemyPerception, FpsCombatConfig, Noise, NoiseKind};
+use carcinisation_net::NetEnemy;
+use carcinisation_server::systems::{
+    PendingNoises, ServerEnemyNavigation, ServerEnemyPerception, ServerMosquitonSimConfig,
+    tick_enemy_perception, update_enemy_navigation,
+};
+use common::systems::{mosquiton, spawn_player, system_test_app, tick};
+
+/// Two rooms joined by a gap at the east end of row 3. The enemy starts in
+/// the north room, out of sight of the player in the south room.
+const ROOMS_MAP: &str = r#"MapData(
+    width: 9, height: 10,
+    cells: [
+        1,1,1,1,1,1,1,1,1,
+        1,0,0,0,0,0,0,0,1,
+        1,0,0,0,0,0,0,0,1,
+        1,1,1,1,1,1,1,0,1,
+        1,0,0,0,0,0,0,0,1,
+        1,0,0,0,0,0,0,0,1,
+        1,0,0,0,0,0,0,0,1,
+        1,0,0,0,0,0,0,0,1,
+        1,0,0,0,0,0,0,0,1,
+        1,1,1,1,1,1,1,1,1,
+    ],
+)"#;
+
+const PLAYER: Vec2 = Vec2::new(4.5, 8.5);
+
+fn test_app(enemy_at: Vec2, patrol: &[(f32, f32)]) -> App {
+    let mut app = system_test_app(ROOMS_MAP);
+    app.insert_resource(FpsCombatConfig::default())
+        .init_resource::<ServerEnemyNavigation>()
+        .init_resource::<PendingNoises>()
+        .add_systems(
+            Update,
+            (update_enemy_navigation, tick_enemy_perception).chain(),
+        );
+    spawn_player(&mut app, 1, PLAYER);
+    app.world_mut().spawn((
+        mosquiton(enemy_at),
+        ServerMosquitonSimConfig::with_speed(1.2),
+        ServerEnemyPerception(EnemyPerception::with_patrol(patrol)),
+    ));
+    app
+}
+
+fn enemy(app: &mut App) -> (Vec2, Awareness) {
+    let (enemy, perception) = app
+        .world_mut()
+        .query::<(&NetEnemy, &ServerEnemyPerception)>()
+        .single(app.world())
+        .expect("one enemy");
+    (enemy.position, perception.0.awareness)
+}
+
+fn make_noise(app: &mut App, kind: NoiseKind) {
+    app.world_mut()
+        .resource_mut::<PendingNoises>()
+        .0
+        .push(Noise {
+            position: PLAYER,
+            kind,
+        });
+}
+
+#[test]
+fn unaware_enemy_walks_its_patrol() {
+    let mut app = test_app(Vec2::new(1.5, 1.5), &[(1.5, 1.5), (5.5, 1.5)]);
+    for _ in 0..30 {
+        tick(&mut app);
+    }
+    let (position, awareness) = enemy(&mut app);
+    assert_eq!(awareness, Awareness::Unaware);
+    assert!(position.x > 2.5, "heading east: {position:?}");
+    assert!(
+        (position.y - 1.5).abs() < 1e-4,
+        "stays on the route: {position:?}"
+    );
+}
+
+#[test]
+fn gunshot_draws_the_enemy_through_the_gap() {
+    let mut app = test_app(Vec2::new(5.5, 1.5), &[]);
+    tick(&mut app);
+    assert_eq!(enemy(&mut app).1, Awareness::Unaware);
+
+    make_noise(&mut app, NoiseKind::Gunshot);
+    tick(&mut app);
+    assert!(matches!(
+        enemy(&mut app).1,
+        Awareness::Investigating { point, .. } if point == PLAYER
+    ));
+
+    let mut engaged = false;
+    for _ in 0..150 {
+        tick(&mut app);
+        if matches!(enemy(&mut app).1, Awareness::Engaged { .. }) {
+            engaged = true;
+            break;
+        }
+    }
+    let (position, _) = enemy(&mut app);
+    assert!(engaged, "spotted the player after the search: {position:?}");
+    assert!(position.y > 3.0, "came through the gap: {position:?}");
+}
+
+#[test]
+fn flamethrower_is_too_quiet_to_carry_between_rooms() {
+    let mut app = test_app(Vec2::new(5.5, 1.5), &[]);
+    make_noise(&mut app, NoiseKind::Flamethrower);
+    for _ in 0..10 {
+        tick(&mut app);
+    }
+    let (position, awareness) = enemy(&mut app);
+    assert_eq!(awareness, Awareness::Unaware);
+    assert_eq!(position, Vec2::new(5.5, 1.5));
+}
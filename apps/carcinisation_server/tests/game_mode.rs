@@ -0,0 +1,177 @@
+//! Game-mode integration tests: kill scoring, round end conditions, and
+//! intermission restarts.
+//!
+//! Deterministic: each `app.update()` = exactly one `FixedUpdate` cycle at 30 Hz.
+
+mod common;
+
+use bevy::prelude::*;
+use carcinisation_fps_core::game_mode::{GameModeConfig, GameModeKind, RoundOutcome};
+use carcinisation_net::{NetEnemyState, NetMatchPhase, NetMatchState, NetPlayer, NetScore};
+use carcinisation_server::systems::{GameMode, MatchPhase, MatchState};
+use common::{
+    build_deterministic_server_with_enemy, get_enemy_state, inject_fire, set_enemy_health,
+    set_player_health, spawn_alive_player, wait_for_deterministic,
+};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Server with one enemy east of the player spawn and `config` installed
+/// before startup.
+fn build_mode_server(config: GameModeConfig) -> App {
+    let mut server = build_deterministic_server_with_enemy(3.5, 1.5);
+    server.insert_resource(GameMode(config));
+    server.update();
+    server
+}
+
+/// Spawn an alive player that takes part in scoring.
+fn spawn_scored_player(server: &mut App, pid: u32) {
+    spawn_alive_player(server, pid, 1.5, 1.5);
+    let entity = server
+        .world_mut()
+        .query::<(Entity, &NetPlayer)>()
+        .iter(server.world())
+        .find(|(_, p)| p.player_id.0 == pid)
+        .map(|(e, _)| e)
+        .unwrap();
+    server
+        .world_mut()
+        .entity_mut(entity)
+        .insert(NetScore::default());
+}
+
+fn score(server: &mut App, pid: u32) -> NetScore {
+    server
+        .world_mut()
+        .query::<(&NetPlayer, &NetScore)>()
+        .iter(server.world())
+        .find(|(p, _)| p.player_id.0 == pid)
+        .map(|(_, s)| *s)
+        .unwrap()
+}
+
+fn phase(server: &App) -> MatchPhase {
+    server.world().resource::<MatchState>().phase
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn match_waits_for_players_then_starts() {
+    let mut server = build_mode_server(GameModeConfig::new(GameModeKind::Deathmatch));
+    server.update();
+    assert_eq!(phase(&server), MatchPhase::WaitingForPlayers);
+
+    spawn_scored_player(&mut server, 1);
+    server.update();
+    assert_eq!(phase(&server), MatchPhase::InProgress);
+
+    let replicated = server
+        .world_mut()
+        .query::<&NetMatchState>()
+        .single(server.world())
+        .unwrap()
+        .clone();
+    assert_eq!(replicated.kind, GameModeKind::Deathmatch);
+    assert_eq!(replicated.phase, NetMatchPhase::InProgress);
+    assert_eq!(replicated.remaining_secs, Some(600));
+}
+
+#[test]
+fn deathmatch_kill_reaches_frag_limit_and_restarts() {
+    let config = GameModeConfig {
+        frag_limit: 1,
+        intermission_secs: 1,
+        ..GameModeConfig::new(GameModeKind::Deathmatch)
+    };
+    let mut server = build_mode_server(config);
+    spawn_scored_player(&mut server, 1);
+    // Start the match and let the map reset respawn the enemy.
+    for _ in 0..3 {
+        server.update();
+    }
+    set_enemy_health(&mut server, 1.0);
+
+    let killed = wait_for_deterministic(&mut server, 120, |s| {
+        inject_fire(s, 1);
+        matches!(get_enemy_state(s), Some(NetEnemyState::Dying { .. }))
+    });
+    assert!(killed, "player should kill the enemy");
+    server.update();
+
+    assert_eq!(score(&mut server, 1).kills, 1);
+    assert_eq!(
+        phase(&server),
+        MatchPhase::Intermission(RoundOutcome::FragLimit { winner: 1 })
+    );
+
+    let restarted = wait_for_deterministic(&mut server, 60, |s| phase(s) == MatchPhase::InProgress);
+    assert!(restarted, "a new match should start after the intermission");
+    assert_eq!(score(&mut server, 1), NetScore::default());
+}
+
+#[test]
+fn player_death_counts_during_round() {
+    let mut server = build_mode_server(GameModeConfig::new(GameModeKind::Deathmatch));
+    spawn_scored_player(&mut server, 1);
+    server.update();
+
+    set_player_health(&mut server, 1, 0.0);
+    let counted = wait_for_deterministic(&mut server, 10, |s| score(s, 1).deaths == 1);
+    assert!(counted, "death should be recorded");
+}
+
+#[test]
+fn coop_clearing_a_wave_advances_to_the_next() {
+    let config = GameModeConfig {
+        waves: 2,
+        intermission_secs: 1,
+        ..GameModeConfig::new(GameModeKind::Coop)
+    };
+    let mut server = build_mode_server(config);
+    spawn_scored_player(&mut server, 1);
+    for _ in 0..3 {
+        server.update();
+    }
+    assert_eq!(phase(&server), MatchPhase::InProgress);
+
+    set_enemy_health(&mut server, 0.0);
+    server.update();
+    assert_eq!(
+        phase(&server),
+        MatchPhase::Intermission(RoundOutcome::WaveCleared { wave: 1 })
+    );
+
+    let next_wave = wait_for_deterministic(&mut server, 60, |s| phase(s) == MatchPhase::InProgress);
+    assert!(next_wave);
+    assert_eq!(server.world().resource::<MatchState>().wave, 2);
+    // The map reset brought the enemy back at full health.
+    for _ in 0..2 {
+        server.update();
+    }
+    assert!(common::get_enemy_health(&mut server).unwrap() > 0.0);
+}
+
+#[test]
+fn no_game_mode_keeps_free_for_all() {
+    let mut server = build_deterministic_server_with_enemy(3.5, 1.5);
+    server.update();
+    spawn_scored_player(&mut server, 1);
+    for _ in 0..5 {
+        server.update();
+    }
+    assert_eq!(phase(&server), MatchPhase::WaitingForPlayers);
+    assert_eq!(
+        server
+            .world_mut()
+            .query::<&NetMatchState>()
+            .iter(server.world())
+            .count(),
+        0
+    );
+}
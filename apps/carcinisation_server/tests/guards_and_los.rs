@@ -9,10 +9,10 @@ use bevy::prelude::*;
 use carcinisation_fps_core::map::Map;
 use carcinisation_net::{NetAttackId, NetEnemyState, PlayerNetState};
 use common::{
-    build_deterministic_server_with_basic_enemy, build_deterministic_server_with_enemies,
-    build_deterministic_server_with_enemy, force_enemy_state, force_player_attack,
-    get_enemy_health, get_enemy_state, get_player_health, inject_fire, inject_intent,
-    spawn_alive_player, spawn_player_with_state,
+    attack_slot, build_deterministic_server_with_basic_enemy,
+    build_deterministic_server_with_enemies, build_deterministic_server_with_enemy,
+    force_enemy_state, force_player_attack, get_enemy_health, get_enemy_state, get_player_health,
+    inject_fire, inject_intent, spawn_alive_player, spawn_player_with_state,
 };
 
 // ---------------------------------------------------------------------------
@@ -36,6 +36,8 @@ fn los_test_map() -> Map {
         width: 8,
         height: 8,
         cells,
+        mechanisms: carcinisation_fps_core::MapMechanisms::default(),
+        heights: Vec::new(),
     }
 }
 
@@ -69,7 +71,7 @@ fn assert_dead_player_action_is_noop(
     server.update();
 
     spawn_player_with_state(&mut server, 1, 1.5, 1.5, PlayerNetState::Dead);
-    if attack != NetAttackId::None {
+    if attack != NetAttackId::default() {
         force_player_attack(&mut server, 1, attack);
     }
 
@@ -102,7 +104,7 @@ fn dead_player_cannot_move() {
     assert_dead_player_action_is_noop(
         "movement",
         (6.5, 6.5), // enemy far away
-        NetAttackId::None,
+        NetAttackId::default(),
         false,
         Vec2::new(0.0, 1.0),
         true, // check position
@@ -114,7 +116,7 @@ fn dead_player_cannot_fire_pistol() {
     assert_dead_player_action_is_noop(
         "pistol",
         (4.5, 1.5), // enemy directly east
-        NetAttackId::None,
+        NetAttackId::default(),
         true,
         Vec2::ZERO,
         false,
@@ -126,7 +128,7 @@ fn dead_player_cannot_use_flamethrower() {
     assert_dead_player_action_is_noop(
         "flamethrower",
         (3.5, 1.5), // enemy within flame range
-        NetAttackId::Projectile,
+        attack_slot("flamethrower"),
         true,
         Vec2::ZERO,
         false,
@@ -167,7 +169,7 @@ fn flamethrower_blocked_by_wall() {
     server.update();
 
     spawn_alive_player(&mut server, 1, 1.5, 1.5);
-    force_player_attack(&mut server, 1, NetAttackId::Projectile);
+    force_player_attack(&mut server, 1, attack_slot("flamethrower"));
     let hp_before = get_enemy_health(&mut server).unwrap();
 
     inject_intent(&mut server, 1, Vec2::ZERO, true);
@@ -0,0 +1,210 @@
+//! Hitscan lag compensation.
+//!
+//! A fast enemy dashes off the line of fire while a lagged client is still
+//! looking at it. The shot must land where that client saw it, but only as
+//! far back as the client's measured round trip and the configured cap allow.
+//!
+//! Rewind tests are deterministic (`build_deterministic_server_*`, one
+//! FixedUpdate per `update()`): the round trip is injected straight into
+//! `LagCompensation` as an ack sent on one tick and echoed `LAG_TICKS` later.
+//! The enemy is a sim-less static Spidey (see `spawn_static_spidey`) moved by
+//! the test so its path is exact. The networked test measures a real echo
+//! through a client that holds acks back for `ARTIFICIAL_DELAY`.
+#![allow(clippy::doc_markdown, clippy::float_cmp)]
+
+mod common;
+
+use std::f32::consts::PI;
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+
+use bevy::prelude::*;
+use bevy_replicon::prelude::ClientTriggerExt;
+use carcinisation_fps_core::FpsCombatConfig;
+use carcinisation_net::{
+    ClientIntent, InputAck, InputSequence, NetProtocolPlugin, PlayerId, TickCounter,
+    register_net_all,
+};
+use carcinisation_server::systems::{LagCompensation, NetEnemy};
+use common::combat::{build_combat_server, get_player_id, open_test_map, wait_for_player};
+use common::{
+    build_client_app, build_deterministic_server_with_enemies, get_enemy_health, inject_fire,
+    reserve_port, spawn_alive_player, spawn_static_spidey, tick_with_sleep,
+};
+
+const PLAYER: u32 = 1;
+const PLAYER_POS: Vec2 = Vec2::new(1.5, 5.5);
+const ENEMY_START: Vec2 = Vec2::new(6.5, 5.5);
+const ENEMY_HP: f32 = 1000.0;
+/// Sideways dash per tick (9 units/s): four ticks clear the body by far.
+const DASH_PER_TICK: f32 = 0.3;
+/// Ticks between the acked snapshot and the shot reaching the server.
+const LAG_TICKS: u32 = 4;
+
+fn current_tick(server: &App) -> u32 {
+    server.world().resource::<TickCounter>().0.0
+}
+
+fn dash(server: &mut App, enemy: Entity) {
+    server
+        .world_mut()
+        .get_mut::<NetEnemy>(enemy)
+        .expect("enemy alive")
+        .position
+        .y += DASH_PER_TICK;
+}
+
+/// Script one lagged shot and return the damage dealt.
+///
+/// The enemy sits on the line of fire for two ticks (the acked snapshot and
+/// the one the client interpolates from), then dashes sideways for
+/// `LAG_TICKS` while the ack travels. With `echo`, the client's intent names
+/// that ack, giving the server a `LAG_TICKS + 1` tick round trip.
+fn lagged_shot_damage(max_rewind_ms: u32, echo: bool) -> f32 {
+    let mut server = build_deterministic_server_with_enemies(open_test_map(12, 12), vec![]);
+    server
+        .world_mut()
+        .resource_mut::<FpsCombatConfig>()
+        .lag_compensation_max_ms = max_rewind_ms;
+    server.update();
+    spawn_alive_player(&mut server, PLAYER, PLAYER_POS.x, PLAYER_POS.y);
+    let enemy = spawn_static_spidey(&mut server, 900, ENEMY_START, PI, ENEMY_HP);
+
+    server.update();
+    server.update();
+    let acked_tick = carcinisation_net::Tick(current_tick(&server) - 1);
+    server
+        .world_mut()
+        .resource_mut::<LagCompensation>()
+        .record_ack(PlayerId(PLAYER), InputSequence(1), acked_tick);
+
+    for _ in 0..LAG_TICKS {
+        dash(&mut server, enemy);
+        server.update();
+    }
+
+    if echo {
+        let now = carcinisation_net::Tick(current_tick(&server));
+        let latency = server
+            .world_mut()
+            .resource_mut::<LagCompensation>()
+            .observe_echo(PlayerId(PLAYER), InputSequence(1), now);
+        assert_eq!(latency, Some(LAG_TICKS + 1));
+    }
+
+    inject_fire(&mut server, PLAYER);
+    server.update();
+    ENEMY_HP - get_enemy_health(&mut server).expect("enemy present")
+}
+
+#[test]
+fn shot_hits_where_lagged_client_saw_enemy() {
+    let dealt = lagged_shot_damage(200, true);
+    assert!(
+        dealt > 0.0,
+        "rewound hitscan should hit the enemy the client was aiming at"
+    );
+}
+
+#[test]
+fn shot_without_echo_resolves_against_live_pose() {
+    let dealt = lagged_shot_damage(200, false);
+    assert_eq!(
+        dealt, 0.0,
+        "no measured round trip: the enemy has already dashed away"
+    );
+}
+
+#[test]
+fn rewind_is_capped_by_config() {
+    assert_eq!(
+        lagged_shot_damage(0, true),
+        0.0,
+        "a zero cap disables lag compensation"
+    );
+    // 67 ms = 2 ticks: the enemy had already left the line by then.
+    assert_eq!(
+        lagged_shot_damage(67, true),
+        0.0,
+        "rewind must stop at the cap"
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Networked: round trip measured from real acks held back by the client
+// ---------------------------------------------------------------------------
+
+/// Extra one-way delay the test client adds before it "sees" an ack.
+const ARTIFICIAL_DELAY: Duration = Duration::from_millis(150);
+
+#[derive(Resource, Default)]
+struct DelayedAcks {
+    in_flight: Vec<(Instant, InputSequence)>,
+    seen: InputSequence,
+    sequence: u32,
+}
+
+fn delay_input_ack(trigger: On<InputAck>, mut acks: ResMut<DelayedAcks>) {
+    acks.in_flight.push((
+        Instant::now() + ARTIFICIAL_DELAY,
+        trigger.event().last_processed_sequence,
+    ));
+}
+
+/// Release due acks, then send one intent echoing the newest.
+fn send_echoing_intent(mut commands: Commands, mut acks: ResMut<DelayedAcks>) {
+    let now = Instant::now();
+    let mut seen = acks.seen;
+    acks.in_flight.retain(|&(due, seq)| {
+        if due > now {
+            return true;
+        }
+        if seq.is_after(seen) {
+            seen = seq;
+        }
+        false
+    });
+    acks.seen = seen;
+    acks.sequence += 1;
+    commands.client_trigger(ClientIntent {
+        acked_sequence: seen,
+        ..ClientIntent::idle(InputSequence(acks.sequence))
+    });
+}
+
+#[test]
+fn server_measures_round_trip_from_ack_echo() {
+    let port = reserve_port();
+    let mut server = build_combat_server(port);
+    server.update();
+
+    let addr = SocketAddr::new(std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST), port);
+    let mut client = build_client_app(NetProtocolPlugin, register_net_all, addr);
+    client.init_resource::<DelayedAcks>();
+    client.add_observer(delay_input_ack);
+    client.update();
+
+    assert!(wait_for_player(&mut server, &mut client));
+    let pid = get_player_id(&mut server).unwrap();
+    client.add_systems(Update, send_echoing_intent);
+
+    let mut latency = None;
+    for _ in 0..400 {
+        tick_with_sleep(&mut server, &mut client);
+        latency = server
+            .world()
+            .resource::<LagCompensation>()
+            .latency_ticks(pid);
+        if latency.is_some() && client.world().resource::<DelayedAcks>().sequence > 300 {
+            break;
+        }
+    }
+
+    // 150 ms is 4.5 ticks at 30 Hz; the estimate is the fastest recent echo,
+    // so it can round down to 4 but never below.
+    let latency = latency.expect("server should have measured an echo");
+    assert!(
+        (4..=10).contains(&latency),
+        "measured round trip {latency} ticks for a {ARTIFICIAL_DELAY:?} delay"
+    );
+}
@@ -0,0 +1,172 @@
+//! Match recording tests: record a live networked session with
+//! `ReplayRecorder`, then re-simulate it offline with `run_replay` and check
+//! every sim hash checkpoint.
+
+mod common;
+
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use bevy_replicon::prelude::*;
+use carcinisation_fps_core::game_mode::{GameModeConfig, GameModeKind};
+use carcinisation_net::{ClientIntent, InputSequence, NetPlayer, PlayerActions};
+use carcinisation_server::systems::replay::ReplayEvent;
+use carcinisation_server::systems::{
+    GameMode, ReplayLog, ReplayRecorder, ReplayReport, run_replay,
+};
+use common::{
+    build_fixed_tick_client, build_fixed_tick_server, build_server_app, reserve_port,
+    test_server_plugin, tick_with_sleep,
+};
+
+// ---------------------------------------------------------------------------
+// Test helpers
+// ---------------------------------------------------------------------------
+
+fn unique_replay_path() -> PathBuf {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+    let n = COUNTER.fetch_add(1, Ordering::SeqCst);
+    std::env::temp_dir().join(format!(
+        "carcinisation-test-{}-{n}.crpl",
+        std::process::id()
+    ))
+}
+
+/// Intent the test client sends every frame.
+#[derive(Resource, Default)]
+struct ScriptedIntent {
+    sequence: u32,
+}
+
+/// Walk forward while turning, firing every few packets.
+fn send_scripted_intent(mut commands: Commands, mut script: ResMut<ScriptedIntent>) {
+    script.sequence += 1;
+    let seq = script.sequence;
+    commands.client_trigger(ClientIntent {
+        sequence: InputSequence(seq),
+        acked_sequence: InputSequence(0),
+        movement: Vec2::new(0.0, 1.0),
+        turn: if (seq / 20) % 2 == 0 { 0.5 } else { -0.5 },
+        fire_held: seq % 7 == 0,
+        aim_held: false,
+        actions: PlayerActions::default(),
+    });
+}
+
+/// Play a short networked session with recording enabled and return the
+/// replay file path.
+fn record_session() -> PathBuf {
+    let path = unique_replay_path();
+    let port = reserve_port();
+    let addr = SocketAddr::new(std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST), port);
+
+    let mut server = build_fixed_tick_server(port);
+    server.insert_resource(
+        ReplayRecorder::create(&path, "test_map", None, 1).expect("create replay"),
+    );
+    server.update();
+
+    let mut client = build_fixed_tick_client(addr);
+    client
+        .init_resource::<ScriptedIntent>()
+        .add_systems(Update, send_scripted_intent);
+
+    for _ in 0..300 {
+        tick_with_sleep(&mut server, &mut client);
+        let joined = server
+            .world_mut()
+            .query::<&NetPlayer>()
+            .iter(server.world())
+            .count()
+            == 1;
+        if joined {
+            break;
+        }
+    }
+    for _ in 0..400 {
+        tick_with_sleep(&mut server, &mut client);
+    }
+
+    // Dropping the server flushes the tail of the recording.
+    drop(server);
+    path
+}
+
+fn replay_on_fresh_server(log: &ReplayLog) -> ReplayReport {
+    let mut app = build_server_app(test_server_plugin(0));
+    run_replay(&mut app, log)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+/// A recorded live match re-simulates with every checkpoint matching.
+#[test]
+fn recorded_match_replays_without_divergence() {
+    let path = record_session();
+    let log = ReplayLog::read(&path).expect("read replay");
+    std::fs::remove_file(&path).ok();
+
+    assert_eq!(log.header.map_path, "test_map");
+    assert!(
+        log.hash_count() > 10,
+        "session should produce several checkpoints, got {}",
+        log.hash_count()
+    );
+
+    match replay_on_fresh_server(&log) {
+        ReplayReport::Matched { checkpoints, .. } => {
+            assert_eq!(checkpoints, log.hash_count());
+        }
+        diverged @ ReplayReport::Diverged { .. } => {
+            panic!("replay diverged: {diverged:?}");
+        }
+    }
+}
+
+/// Dropping a recorded input makes the replay diverge, and the report points
+/// at a checkpoint after the last matching one.
+#[test]
+fn missing_input_is_reported_as_divergence() {
+    let path = record_session();
+    let log = ReplayLog::read(&path).expect("read replay");
+    std::fs::remove_file(&path).ok();
+
+    // Without the player's join, every checkpoint that saw the player must
+    // now mismatch.
+    let mut tampered = log.clone();
+    tampered.retain_events(|event| !matches!(event, ReplayEvent::PlayerJoined { .. }));
+
+    match replay_on_fresh_server(&tampered) {
+        ReplayReport::Diverged {
+            tick, last_good, ..
+        } => {
+            if let Some(good) = last_good {
+                assert!(good.0 < tick.0);
+            }
+        }
+        matched @ ReplayReport::Matched { .. } => {
+            panic!("tampered replay should diverge: {matched:?}");
+        }
+    }
+}
+
+/// The recorded match rules are installed on the replay server.
+#[test]
+fn replay_applies_recorded_game_mode() {
+    let path = unique_replay_path();
+    let mode = GameModeConfig::new(GameModeKind::Coop);
+    drop(ReplayRecorder::create(&path, "test_map", Some(mode), 1).expect("create replay"));
+    let log = ReplayLog::read(&path).expect("read replay");
+    std::fs::remove_file(&path).ok();
+
+    let mut app = build_server_app(test_server_plugin(0));
+    assert!(app.world().get_resource::<GameMode>().is_none());
+    run_replay(&mut app, &log);
+
+    let installed = app.world().resource::<GameMode>();
+    assert_eq!(installed.0, mode);
+}
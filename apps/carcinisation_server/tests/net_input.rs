@@ -52,6 +52,7 @@ fn queue_forward(app: &mut App, seq: u32) {
         .0
         .push(ClientIntent {
             sequence: InputSequence(seq),
+            acked_sequence: InputSequence(0),
             movement: Vec2::new(0.0, 1.0),
             turn: 0.0,
             fire_held: false,
@@ -75,6 +76,7 @@ fn queue_turn_left(app: &mut App, seq: u32) {
         .0
         .push(ClientIntent {
             sequence: InputSequence(seq),
+            acked_sequence: InputSequence(0),
             movement: Vec2::ZERO,
             turn: 1.0,
             fire_held: false,
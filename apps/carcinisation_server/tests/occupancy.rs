@@ -7,7 +7,7 @@ use bevy::prelude::*;
 use bevy_replicon::prelude::*;
 use carcinisation_fps_core::map::{EntitySpawnData, EntitySpawnKind, test_map};
 use carcinisation_net::{NetEnemyState, NetHealth, NetPlayer, PlayerId, PlayerNetState};
-use carcinisation_server::ServerPlugin;
+use carcinisation_server::{ServerAuthMode, ServerPlugin};
 use common::{build_server_app, reserve_port};
 
 // ---------------------------------------------------------------------------
@@ -31,7 +31,7 @@ fn spawn_player_at(server: &mut App, id: u32, x: f32, y: f32) {
             player_id: PlayerId(id),
             position: Vec2::new(x, y),
             angle: 0.0,
-            current_attack: carcinisation_net::NetAttackId::None,
+            current_attack: carcinisation_net::NetAttackId::default(),
             state: PlayerNetState::Alive,
             flame_active: false,
             avatar_palette_variant: None,
@@ -76,6 +76,7 @@ fn build_two_overlapping_enemies(x: f32, y: f32) -> App {
             kind: EntitySpawnKind::Mosquiton {
                 health: 100,
                 speed: 0.0, // stationary AI
+                patrol: Vec::new(),
             },
             x,
             y,
@@ -84,6 +85,7 @@ fn build_two_overlapping_enemies(x: f32, y: f32) -> App {
             kind: EntitySpawnKind::Mosquiton {
                 health: 100,
                 speed: 0.0,
+                patrol: Vec::new(),
             },
             x,
             y,
@@ -97,6 +99,8 @@ fn build_two_overlapping_enemies(x: f32, y: f32) -> App {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     server.update();
     server
@@ -142,6 +146,7 @@ fn player_not_hard_blocked_by_enemy() {
         kind: EntitySpawnKind::Mosquiton {
             health: 100,
             speed: 0.0,
+            patrol: Vec::new(),
         },
         x: 3.5,
         y: 3.5,
@@ -154,6 +159,8 @@ fn player_not_hard_blocked_by_enemy() {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     server.update();
 
@@ -186,6 +193,7 @@ fn enemy_yields_more_than_player() {
         kind: EntitySpawnKind::Mosquiton {
             health: 100,
             speed: 0.0,
+            patrol: Vec::new(),
         },
         x: 3.5,
         y: 3.5,
@@ -198,6 +206,8 @@ fn enemy_yields_more_than_player() {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     server.update();
 
@@ -283,6 +293,7 @@ fn lunge_push_works_with_separation() {
         kind: EntitySpawnKind::Spidey {
             health: 100,
             speed: 2.0,
+            patrol: Vec::new(),
         },
         x: 5.5,
         y: 3.5,
@@ -295,6 +306,8 @@ fn lunge_push_works_with_separation() {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     server.update();
     spawn_player_at(&mut server, 1, 3.5, 3.5);
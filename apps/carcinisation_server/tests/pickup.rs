@@ -42,7 +42,7 @@ fn spawn_player(world: &mut World, position: Vec2, current: f32) -> Entity {
                 player_id: PlayerId(1),
                 position,
                 angle: 0.0,
-                current_attack: NetAttackId::None,
+                current_attack: NetAttackId::default(),
                 state: PlayerNetState::Alive,
                 flame_active: false,
                 avatar_palette_variant: None,
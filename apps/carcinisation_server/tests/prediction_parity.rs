@@ -71,6 +71,7 @@ fn queue_intent_with_actions(
         .0
         .push(ClientIntent {
             sequence: InputSequence(seq),
+            acked_sequence: InputSequence(0),
             movement,
             turn,
             fire_held: false,
@@ -15,7 +15,7 @@ use carcinisation_net::{
     PlayerId, PlayerNetState,
     sim_hash::{collect_enemy_state, collect_player_state, compute_sim_hash},
 };
-use carcinisation_server::ServerPlugin;
+use carcinisation_server::{ServerAuthMode, ServerPlugin};
 use common::build_server_app;
 
 // ---------------------------------------------------------------------------
@@ -32,6 +32,7 @@ fn build_replay_server() -> App {
         kind: EntitySpawnKind::Mosquiton {
             health: 100,
             speed: 0.0,
+            patrol: Vec::new(),
         },
         x: 3.5,
         y: 1.5,
@@ -44,6 +45,8 @@ fn build_replay_server() -> App {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     });
     // Deterministic: each app.update() = exactly 1 fixed tick.
     app.insert_resource(TimeUpdateStrategy::FixedTimesteps(1));
@@ -57,7 +60,7 @@ fn spawn_player(server: &mut App, pid: u32, x: f32, y: f32) {
             player_id: PlayerId(pid),
             position: bevy::math::Vec2::new(x, y),
             angle: 0.0,
-            current_attack: NetAttackId::None,
+            current_attack: NetAttackId::default(),
             state: PlayerNetState::Alive,
             flame_active: false,
             avatar_palette_variant: None,
@@ -83,6 +86,7 @@ fn inject_intent(
         PlayerId(pid),
         &ClientIntent {
             sequence: InputSequence(seq),
+            acked_sequence: InputSequence(0),
             movement,
             turn: 0.0,
             fire_held,
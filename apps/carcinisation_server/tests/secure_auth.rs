@@ -0,0 +1,132 @@
+//! Secure-mode (connect token) authentication tests.
+
+mod common;
+
+use std::net::SocketAddr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bevy::prelude::*;
+use bevy_renet2::netcode::ClientAuthentication;
+use bevy_replicon::prelude::*;
+use carcinisation_net::auth::{PrivateKey, TokenRequest, generate_private_key, mint_connect_token};
+use carcinisation_net::{ConnectMode, NetPlayer, NetProtocolPlugin, register_net_all};
+use carcinisation_server::ServerAuthMode;
+use common::{
+    build_client_app, build_client_app_with_auth, build_server_app, reserve_port,
+    test_server_plugin, update_both, wait_for,
+};
+
+fn build_secure_server(private_key: PrivateKey) -> (App, SocketAddr) {
+    let port = reserve_port();
+    let public_addr = SocketAddr::new(std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST), port);
+    let mut plugin = test_server_plugin(port);
+    plugin.auth = ServerAuthMode::Secure {
+        private_key,
+        public_addr,
+    };
+    let mut server = build_server_app(plugin);
+    server.update();
+    (server, public_addr)
+}
+
+fn build_token_client(private_key: PrivateKey, server_addr: SocketAddr, mode: ConnectMode) -> App {
+    let request = TokenRequest::new(Some("crab".to_string()), mode, vec![server_addr]);
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time went backwards");
+    let token = mint_connect_token(&private_key, &request, now).expect("mint token");
+    let mut client = build_client_app_with_auth(NetProtocolPlugin, register_net_all, move |_| {
+        ClientAuthentication::Secure {
+            connect_token: token.clone(),
+        }
+    });
+    client.update();
+    client
+}
+
+fn count_connected(server: &mut App) -> usize {
+    server
+        .world_mut()
+        .query::<&ConnectedClient>()
+        .iter(server.world())
+        .count()
+}
+
+fn count_netplayers(app: &mut App) -> usize {
+    app.world_mut()
+        .query::<&NetPlayer>()
+        .iter(app.world())
+        .count()
+}
+
+#[test]
+fn token_client_joins_secure_server() {
+    let key = generate_private_key();
+    let (mut server, addr) = build_secure_server(key);
+    let mut client = build_token_client(key, addr, ConnectMode::Player);
+
+    let joined = wait_for(240, &mut server, &mut client, |_, client| {
+        count_netplayers(client) == 1
+    });
+    assert!(joined, "token client should join and see its player");
+}
+
+#[test]
+fn token_carries_monitor_mode() {
+    let key = generate_private_key();
+    let (mut server, addr) = build_secure_server(key);
+    let mut client = build_token_client(key, addr, ConnectMode::Monitor);
+
+    let connected = wait_for(240, &mut server, &mut client, |server, _| {
+        count_connected(server) == 1
+    });
+    assert!(connected, "monitor token should connect");
+    for _ in 0..30 {
+        update_both(&mut server, &mut client);
+    }
+    assert_eq!(
+        count_netplayers(&mut server),
+        0,
+        "monitor tokens must not get a player spawned"
+    );
+}
+
+#[test]
+fn secure_server_rejects_unsecure_and_foreign_tokens() {
+    let key = generate_private_key();
+    let (mut server, addr) = build_secure_server(key);
+
+    let mut unsecure = build_client_app(NetProtocolPlugin, register_net_all, addr);
+    unsecure.update();
+    let connected = wait_for(120, &mut server, &mut unsecure, |server, _| {
+        count_connected(server) > 0
+    });
+    assert!(!connected, "unsecure client must not connect");
+
+    let mut forged = build_token_client(generate_private_key(), addr, ConnectMode::Player);
+    let connected = wait_for(120, &mut server, &mut forged, |server, _| {
+        count_connected(server) > 0
+    });
+    assert!(!connected, "token sealed with another key must not connect");
+}
+
+#[cfg(feature = "websocket")]
+#[test]
+fn secure_server_does_not_open_websocket() {
+    let port = reserve_port();
+    let mut plugin = test_server_plugin(port);
+    plugin.ws_port = Some(reserve_port());
+    plugin.auth = ServerAuthMode::Secure {
+        private_key: generate_private_key(),
+        public_addr: SocketAddr::new(std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST), port),
+    };
+    let mut server = build_server_app(plugin);
+    server.update();
+
+    assert!(
+        !server
+            .world()
+            .contains_resource::<carcinisation_server::WebSocketRuntime>(),
+        "connect tokens only cover UDP; browser clients could never join"
+    );
+}
@@ -18,9 +18,9 @@ use carcinisation_net::{
     ClientIntent, InputSequence, NetAttackId, NetHealth, NetPlayer, PlayerActions, PlayerId,
     PlayerNetState,
 };
-use carcinisation_server::ServerPlugin;
 use carcinisation_server::systems::{PlayerIntentBuffer, ServerQuickTurn};
-use common::{build_server_app, reserve_port};
+use carcinisation_server::{ServerAuthMode, ServerPlugin};
+use common::{attack_slot, build_server_app, reserve_port};
 
 // ---------------------------------------------------------------------------
 // Helpers
@@ -37,6 +37,8 @@ fn build_parity_server(port: u16) -> App {
         admin_socket: None,
         instance_name: "test".to_string(),
         map_path: "test_map".to_string(),
+        ws_port: None,
+        auth: ServerAuthMode::Unsecure,
     })
 }
 
@@ -46,7 +48,7 @@ fn spawn_player_at(server: &mut App, pid: u32, x: f32, y: f32, angle: f32) {
             player_id: PlayerId(pid),
             position: Vec2::new(x, y),
             angle,
-            current_attack: NetAttackId::None,
+            current_attack: NetAttackId::default(),
             state: PlayerNetState::Alive,
             flame_active: false,
             avatar_palette_variant: None,
@@ -102,6 +104,7 @@ fn tick_fixed(server: &mut App) {
 fn forward() -> ClientIntent {
     ClientIntent {
         sequence: InputSequence(0),
+        acked_sequence: InputSequence(0),
         movement: Vec2::new(0.0, 1.0),
         turn: 0.0,
         fire_held: false,
@@ -113,6 +116,7 @@ fn forward() -> ClientIntent {
 fn turn_left() -> ClientIntent {
     ClientIntent {
         sequence: InputSequence(0),
+        acked_sequence: InputSequence(0),
         movement: Vec2::ZERO,
         turn: 1.0,
         fire_held: false,
@@ -124,6 +128,7 @@ fn turn_left() -> ClientIntent {
 const fn action(flag: u8) -> ClientIntent {
     ClientIntent {
         sequence: InputSequence(0),
+        acked_sequence: InputSequence(0),
         movement: Vec2::ZERO,
         turn: 0.0,
         fire_held: false,
@@ -321,15 +326,15 @@ fn weapon_switch_toggles() {
     server.update();
     spawn_player_at(&mut server, 1, 3.0, 3.0, 0.0);
 
-    assert_eq!(get_attack(&mut server, 1), NetAttackId::None);
+    assert_eq!(get_attack(&mut server, 1), NetAttackId::default());
 
     inject(&mut server, 1, &action(PlayerActions::WEAPON_SWITCH));
     tick_fixed(&mut server);
-    assert_eq!(get_attack(&mut server, 1), NetAttackId::Projectile);
+    assert_eq!(get_attack(&mut server, 1), attack_slot("flamethrower"));
 
     inject(&mut server, 1, &action(PlayerActions::WEAPON_SWITCH));
     tick_fixed(&mut server);
-    assert_eq!(get_attack(&mut server, 1), NetAttackId::None);
+    assert_eq!(get_attack(&mut server, 1), NetAttackId::default());
 }
 
 // ---------------------------------------------------------------------------
@@ -348,7 +353,7 @@ fn dead_player_ignores_input() {
             player_id: PlayerId(1),
             position: Vec2::new(3.0, 3.0),
             angle: 0.0,
-            current_attack: NetAttackId::None,
+            current_attack: NetAttackId::default(),
             state: PlayerNetState::Dead,
             flame_active: false,
             avatar_palette_variant: None,
@@ -369,6 +374,7 @@ fn dead_player_ignores_input() {
             1,
             &ClientIntent {
                 sequence: InputSequence(1),
+                acked_sequence: InputSequence(0),
                 movement: Vec2::Y,
                 turn: 1.0,
                 fire_held: true,
@@ -386,5 +392,5 @@ fn dead_player_ignores_input() {
         (angle_before - angle_after).abs() < 0.01,
         "dead player angle unchanged"
     );
-    assert_eq!(get_attack(&mut server, 1), NetAttackId::None);
+    assert_eq!(get_attack(&mut server, 1), NetAttackId::default());
 }
@@ -0,0 +1,126 @@
+//! WebSocket transport integration tests.
+//!
+//! The loopback WebSocket client pumps frames on a background thread, so
+//! these tests tick with `tick_with_sleep` to give it wall-clock time between
+//! frames.
+
+mod common;
+
+use std::net::SocketAddr;
+
+use bevy::prelude::*;
+use bevy_replicon::prelude::*;
+use carcinisation_net::{ConnectMode, NetPlayer, NetProtocolPlugin, register_net_all};
+use common::ws::{build_ws_client_app, server_ws_addr};
+use common::{
+    build_client_app, build_server_app, reserve_port, test_server_plugin, tick_with_sleep,
+    tick3_with_sleep,
+};
+
+/// Upper bound on frames to wait for a handshake + first replication.
+const MAX_FRAMES: u32 = 600;
+
+/// Server with both UDP and WebSocket listeners. The WebSocket port is
+/// OS-assigned; tests read it back from the transport.
+fn build_dual_transport_server() -> App {
+    let mut plugin = test_server_plugin(reserve_port());
+    plugin.ws_port = Some(0);
+    let mut server = build_server_app(plugin);
+    server.update();
+    server
+}
+
+fn count_netplayers(app: &mut App) -> usize {
+    app.world_mut()
+        .query::<&NetPlayer>()
+        .iter(app.world())
+        .count()
+}
+
+fn count_connected(server: &mut App) -> usize {
+    server
+        .world_mut()
+        .query::<&ConnectedClient>()
+        .iter(server.world())
+        .count()
+}
+
+fn wait_with_sleep(
+    server: &mut App,
+    client: &mut App,
+    mut condition: impl FnMut(&mut App, &mut App) -> bool,
+) -> bool {
+    for _ in 0..MAX_FRAMES {
+        tick_with_sleep(server, client);
+        if condition(server, client) {
+            return true;
+        }
+    }
+    false
+}
+
+#[test]
+fn websocket_client_connects_and_receives_netplayer() {
+    let mut server = build_dual_transport_server();
+    let mut client = build_ws_client_app(server_ws_addr(&server), ConnectMode::Player);
+    client.update();
+
+    let replicated = wait_with_sleep(&mut server, &mut client, |server, client| {
+        count_connected(server) == 1 && count_netplayers(client) == 1
+    });
+    assert!(
+        replicated,
+        "WebSocket client should connect and receive its replicated NetPlayer"
+    );
+    common::assert_client_connected(&client, "post-connect");
+}
+
+#[test]
+fn websocket_and_udp_clients_share_one_session() {
+    let mut server = build_dual_transport_server();
+    let udp_port = server
+        .world()
+        .resource::<carcinisation_server::ServerPort>()
+        .0;
+    let udp_addr = SocketAddr::new(
+        std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST),
+        udp_port,
+    );
+
+    let mut udp_client = build_client_app(NetProtocolPlugin, register_net_all, udp_addr);
+    let mut ws_client = build_ws_client_app(server_ws_addr(&server), ConnectMode::Player);
+
+    let mut both_see_both = false;
+    for _ in 0..MAX_FRAMES {
+        tick3_with_sleep(&mut server, &mut udp_client, &mut ws_client);
+        if count_netplayers(&mut udp_client) == 2 && count_netplayers(&mut ws_client) == 2 {
+            both_see_both = true;
+            break;
+        }
+    }
+    assert!(
+        both_see_both,
+        "UDP and WebSocket clients should each see both players"
+    );
+}
+
+#[test]
+fn websocket_monitor_uses_connect_mode_handshake() {
+    let mut server = build_dual_transport_server();
+    let mut client = build_ws_client_app(server_ws_addr(&server), ConnectMode::Monitor);
+    client.update();
+
+    let connected = wait_with_sleep(&mut server, &mut client, |server, _| {
+        count_connected(server) == 1
+    });
+    assert!(connected, "WebSocket monitor should connect");
+
+    for _ in 0..30 {
+        tick_with_sleep(&mut server, &mut client);
+    }
+    assert_eq!(
+        count_netplayers(&mut server),
+        0,
+        "monitor clients must not get a player spawned"
+    );
+}
@@ -24,6 +24,7 @@ use crate::{
     palette::Palette,
     position::CxLayer,
     prelude::*,
+    screen::AssetLookup,
 };
 
 /// A built-in filter asset that leaves pixels unchanged.
@@ -223,23 +224,21 @@ impl CxFilterAsset {
 pub struct CxFilter(pub Handle<CxFilterAsset>);
 
 impl CxFilter {
-    /// Look up the loaded [`CxFilterAsset`] from render assets.
-    #[cfg(feature = "headed")]
+    /// Look up the loaded [`CxFilterAsset`] from render or main-world assets.
     #[must_use]
     pub(crate) fn resolve<'a>(
         &self,
-        assets: &'a bevy_render::render_asset::RenderAssets<CxFilterAsset>,
+        assets: &'a impl AssetLookup<CxFilterAsset>,
     ) -> Option<&'a CxFilterAsset> {
         assets.get(&**self)
     }
 }
 
 /// Resolve an optional filter reference against loaded assets.
-#[cfg(feature = "headed")]
 #[must_use]
 pub(crate) fn resolve_filter<'a>(
     filter: Option<&'a CxFilter>,
-    assets: &'a bevy_render::render_asset::RenderAssets<CxFilterAsset>,
+    assets: &'a impl AssetLookup<CxFilterAsset>,
 ) -> Option<&'a CxFilterAsset> {
     filter.and_then(|f| f.resolve(assets))
 }
@@ -313,7 +312,7 @@ impl<L: CxLayer> CxFilterLayers<L> {
 }
 
 #[derive(Resource, Deref)]
-struct InsertDefaultCxFilterLayers(Box<dyn Fn(bool, &mut EntityWorldMut) + Send + Sync>);
+pub(crate) struct InsertDefaultCxFilterLayers(Box<dyn Fn(bool, &mut EntityWorldMut) + Send + Sync>);
 
 impl InsertDefaultCxFilterLayers {
     fn new<L: CxLayer>() -> Self {
@@ -324,6 +323,11 @@ impl InsertDefaultCxFilterLayers {
             });
         }))
     }
+
+    #[cfg(test)]
+    pub(crate) fn noop() -> Self {
+        Self(Box::new(|_, _| {}))
+    }
 }
 
 fn insert_default_px_filter_layers(mut world: DeferredWorld, ctx: HookContext) {
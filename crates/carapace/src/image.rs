@@ -73,10 +73,6 @@ impl CxImage {
         self.image.is_empty()
     }
 
-    pub(crate) fn empty_from_image(image: &Image) -> Self {
-        Self::empty(image.size())
-    }
-
     pub(crate) fn palette_indices(palette: &Palette, image: &Image) -> Result<Self> {
         Ok(Self {
             image: image
@@ -142,7 +142,9 @@ use prelude::*;
 /// window, or GPU dependencies.
 ///
 /// Use this for headless integration tests or server builds where game logic needs `carapace`
-/// components and assets registered, but no rendering occurs.
+/// components and assets registered, but no rendering occurs. Add
+/// [`CxCapturePlugin`](screen::CxCapturePlugin) to also composite frames on the CPU for snapshot
+/// tests.
 ///
 /// # Usage
 ///
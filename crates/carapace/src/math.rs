@@ -54,7 +54,7 @@ impl RectExt for IRect {
 }
 
 /// An orthogonal direction
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Orthogonal {
     /// Right
     Right,
@@ -60,7 +60,10 @@ pub use crate::{
     presentation::CxPresentationTransform,
     primitive::{CxPrimitive, CxPrimitiveFill, CxPrimitiveShape},
     rect::CxFilterRect,
-    screen::{CxOverlayCamera, CxOverlayViewportTransform, CxScreen, CxScreenSize},
+    screen::{
+        CxCapturePlugin, CxCapturedFrame, CxOverlayCamera, CxOverlayViewportTransform, CxScreen,
+        CxScreenSize,
+    },
     sprite::{
         CxAuthoritativeCompositeMetrics, CxCompositePart, CxCompositePartSource, CxCompositeSprite,
         CxSprite, CxSpriteAsset, PartTransform,
@@ -68,8 +71,9 @@ pub use crate::{
     text::{CxText, CxTypeface},
     tilemap::{CxTile, CxTilemap, CxTiles, CxTileset},
     ui::{
-        CxCaret, CxGrid, CxGridRow, CxGridTracks, CxKeyField, CxKeyFieldUpdate, CxMargin,
-        CxMinSize, CxRow, CxRowSlot, CxScroll, CxStack, CxTextField, CxTextFieldUpdate, CxUiRoot,
+        CxActivate, CxCaret, CxFocusFilter, CxFocusable, CxGrid, CxGridRow, CxGridTracks,
+        CxKeyField, CxKeyFieldUpdate, CxLayoutRect, CxMargin, CxMinSize, CxNavigate, CxRow,
+        CxRowSlot, CxScroll, CxStack, CxTextField, CxTextFieldUpdate, CxUiRoot,
     },
 };
 
@@ -57,7 +57,8 @@ pub fn register_types(app: &mut App) {
         .register_type::<CxMargin>()
         .register_type::<CxRow>()
         .register_type::<CxStack>()
-        .register_type::<CxScroll>();
+        .register_type::<CxScroll>()
+        .register_type::<CxFocusable>();
 
     // Feature-gated types
     #[cfg(feature = "line")]
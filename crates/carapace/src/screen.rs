@@ -3,7 +3,9 @@
 //! Data flow: gather render-world components by layer, draw into a CPU `CxImage`,
 //! then upload to a reusable `R8Uint` texture and present via a fullscreen quad.
 //! This is the single compositing path for sprites, text, tilemaps, rects, lines, and filters.
+//! Headless apps can run the same compositing on the main world with [`CxCapturePlugin`].
 
+mod capture;
 mod draw;
 #[cfg(feature = "gpu_palette")]
 mod gpu_sprite;
@@ -24,6 +26,7 @@ use bevy_math::UVec2;
 use bevy_render::{
     Render, RenderApp, RenderSystems,
     extract_resource::{ExtractResource, ExtractResourcePlugin},
+    render_asset::{RenderAsset, RenderAssets},
     render_graph::{RenderGraphExt, RenderLabel, ViewNodeRunner},
 };
 #[cfg(feature = "headed")]
@@ -64,6 +67,7 @@ use crate::{
 )]
 pub struct CxOverlayCamera;
 
+pub use capture::{CxCapturePlugin, CxCapturedFrame};
 #[cfg(feature = "gpu_palette")]
 use gpu_sprite::{CxGpuSpriteBuffer, CxGpuSpriteNode, CxGpuSpritePipeline};
 #[cfg(feature = "headed")]
@@ -97,6 +101,25 @@ impl<L: CxLayer> CxLayerOrder<L> {
     }
 }
 
+/// Read access to loaded assets from either world, so compositing can run on
+/// `RenderAssets` (render node) or the main world's `Assets` (capture).
+pub(crate) trait AssetLookup<A: Asset> {
+    fn get(&self, id: impl Into<AssetId<A>>) -> Option<&A>;
+}
+
+impl<A: Asset> AssetLookup<A> for Assets<A> {
+    fn get(&self, id: impl Into<AssetId<A>>) -> Option<&A> {
+        Self::get(self, id)
+    }
+}
+
+#[cfg(feature = "headed")]
+impl<A: Asset + RenderAsset<SourceAsset = A>> AssetLookup<A> for RenderAssets<A> {
+    fn get(&self, id: impl Into<AssetId<A>>) -> Option<&A> {
+        Self::get(self, id)
+    }
+}
+
 pub(crate) struct Plug<L: CxLayer> {
     size: CxScreenSize,
     _l: PhantomData<L>,
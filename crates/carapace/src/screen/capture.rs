@@ -0,0 +1,598 @@
+//! Headless frame capture.
+//!
+//! [`CxCapturePlugin`] runs the render node's layer compositing on the main
+//! world, so apps without a window or GPU (tests, CI) still get the exact
+//! palette-indexed frame. GPU sprite offload does not apply here: every
+//! sprite is drawn on the CPU.
+
+use std::{marker::PhantomData, path::Path};
+
+use bevy_ecs::query::QueryState;
+
+#[cfg(feature = "line")]
+use crate::line::LineComponents;
+use crate::{
+    atlas::AtlasSpriteComponents,
+    filter::FilterComponents,
+    palette::{Palette, PaletteHandle},
+    position::CxLayer,
+    prelude::*,
+    primitive::PrimitiveComponents,
+    sprite::{CompositeSpriteComponents, SpriteComponents},
+    text::TextComponents,
+    tilemap::{MapComponents, TileComponents},
+};
+
+use super::{
+    CxScreen,
+    draw::{
+        LayerContentsMap, MainWorldAssets, composite_layers, preregister_filter_layer,
+        resolve_filter_layers,
+    },
+};
+
+/// Composites every frame on the main world into [`CxCapturedFrame`].
+///
+/// Works with [`CxHeadlessPlugin`](crate::CxHeadlessPlugin) or alongside
+/// [`CxPlugin`](crate::CxPlugin). Capture runs in [`Last`], after all
+/// `PostUpdate` position and animation syncing.
+#[derive(Debug)]
+pub struct CxCapturePlugin<L: CxLayer> {
+    _l: PhantomData<L>,
+}
+
+impl<L: CxLayer> Default for CxCapturePlugin<L> {
+    fn default() -> Self {
+        Self { _l: PhantomData }
+    }
+}
+
+impl<L: CxLayer> Plugin for CxCapturePlugin<L> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CxCapturedFrame>()
+            .add_systems(Last, capture_frame::<L>);
+    }
+}
+
+/// The most recent frame composited by [`CxCapturePlugin`].
+///
+/// Empty until the screen exists and the palette has loaded.
+#[derive(Resource, Clone, Default, Debug)]
+pub struct CxCapturedFrame {
+    image: Option<CxImage>,
+    palette: Vec<[u8; 3]>,
+}
+
+impl CxCapturedFrame {
+    /// Palette-indexed frame, top row first.
+    #[must_use]
+    pub const fn image(&self) -> Option<&CxImage> {
+        self.image.as_ref()
+    }
+
+    /// sRGB colour of a palette index in the palette the frame was drawn with.
+    /// Indices past the end of the palette are black.
+    #[must_use]
+    pub fn color(&self, index: u8) -> [u8; 3] {
+        self.palette
+            .get(usize::from(index))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// The frame resolved through its palette as opaque RGBA8, top row first.
+    ///
+    /// Index 0 resolves to the palette's first colour, as on screen.
+    #[must_use]
+    pub fn to_rgba(&self) -> Option<Vec<u8>> {
+        let image = self.image.as_ref()?;
+        let mut rgba = Vec::with_capacity(image.area() * 4);
+        for &index in image.data() {
+            let [r, g, b] = self.color(index);
+            rgba.extend_from_slice(&[r, g, b, 255]);
+        }
+        Some(rgba)
+    }
+
+    /// Write the RGBA frame to `path` as a PNG, creating parent directories.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if nothing has been captured yet or the file cannot be
+    /// written.
+    pub fn save_png(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let path = path.as_ref();
+        let size = self
+            .image
+            .as_ref()
+            .ok_or_else(|| "no frame has been captured".to_string())?
+            .size();
+        let rgba = self.to_rgba().unwrap_or_default();
+        let png = ::image::RgbaImage::from_raw(size.x, size.y, rgba)
+            .ok_or_else(|| "failed to assemble RGBA image".to_string())?;
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|err| err.to_string())?;
+        }
+        png.save(path)
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))
+    }
+}
+
+/// Main-world counterpart of the render node's queries. Entities carry an
+/// [`Entity`] so hidden ones can be skipped, as extraction does.
+pub(crate) struct CaptureQueries<L: CxLayer> {
+    maps: QueryState<(Entity, MapComponents<L>)>,
+    tiles: QueryState<TileComponents>,
+    sprites: QueryState<(Entity, SpriteComponents<L>)>,
+    atlas_sprites: QueryState<(Entity, AtlasSpriteComponents<L>)>,
+    composites: QueryState<(Entity, CompositeSpriteComponents<L>)>,
+    texts: QueryState<(Entity, TextComponents<L>)>,
+    primitives: QueryState<(Entity, PrimitiveComponents<L>)>,
+    #[cfg(feature = "line")]
+    lines: QueryState<(Entity, LineComponents<L>)>,
+    filters: QueryState<(Entity, FilterComponents<L>), Without<CxRenderSpace>>,
+}
+
+impl<L: CxLayer> FromWorld for CaptureQueries<L> {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            maps: world.query(),
+            tiles: world.query(),
+            sprites: world.query(),
+            atlas_sprites: world.query(),
+            composites: world.query(),
+            texts: world.query(),
+            primitives: world.query(),
+            #[cfg(feature = "line")]
+            lines: world.query(),
+            filters: world.query_filtered(),
+        }
+    }
+}
+
+impl<L: CxLayer> CaptureQueries<L> {
+    fn update_archetypes(&mut self, world: &World) {
+        self.maps.update_archetypes(world);
+        self.tiles.update_archetypes(world);
+        self.sprites.update_archetypes(world);
+        self.atlas_sprites.update_archetypes(world);
+        self.composites.update_archetypes(world);
+        self.texts.update_archetypes(world);
+        self.primitives.update_archetypes(world);
+        #[cfg(feature = "line")]
+        self.lines.update_archetypes(world);
+        self.filters.update_archetypes(world);
+    }
+
+    /// Gather visible entities by layer, resolving filter targets the same
+    /// two-phase way as the render node.
+    fn collect<'w>(&self, world: &'w World) -> LayerContentsMap<'w, L> {
+        let mut layer_contents: LayerContentsMap<'w, L> = LayerContentsMap::default();
+
+        for (entity, (map, &pos, layer, &canvas, animation, filter)) in self.maps.iter_manual(world)
+        {
+            if is_visible(world, entity) {
+                layer_contents
+                    .entry(layer.clone())
+                    .or_default()
+                    .maps
+                    .push((map, pos, canvas, animation, filter));
+            }
+        }
+
+        for (entity, (prim, &position, &anchor, layer, &canvas, presentation)) in
+            self.primitives.iter_manual(world)
+        {
+            if is_visible(world, entity) {
+                layer_contents
+                    .entry(layer.clone())
+                    .or_default()
+                    .primitives
+                    .push((prim, position, anchor, canvas, presentation.copied()));
+            }
+        }
+
+        for (
+            entity,
+            (sprite, &position, &anchor, layer, &canvas, animation, filter, presentation),
+        ) in self.sprites.iter_manual(world)
+        {
+            if is_visible(world, entity) {
+                layer_contents
+                    .entry(layer.clone())
+                    .or_default()
+                    .sprites
+                    .push((
+                        sprite,
+                        position,
+                        anchor,
+                        canvas,
+                        animation,
+                        filter,
+                        presentation.copied(),
+                    ));
+            }
+        }
+
+        for (
+            entity,
+            (sprite, &position, &anchor, layer, &canvas, animation, filter, presentation),
+        ) in self.atlas_sprites.iter_manual(world)
+        {
+            if is_visible(world, entity) {
+                layer_contents
+                    .entry(layer.clone())
+                    .or_default()
+                    .atlas_sprites
+                    .push((
+                        sprite,
+                        position,
+                        anchor,
+                        canvas,
+                        animation,
+                        filter,
+                        presentation.copied(),
+                    ));
+            }
+        }
+
+        for (
+            entity,
+            (composite, &position, &anchor, layer, &canvas, animation, filter, presentation),
+        ) in self.composites.iter_manual(world)
+        {
+            if is_visible(world, entity) {
+                layer_contents
+                    .entry(layer.clone())
+                    .or_default()
+                    .composites
+                    .push((
+                        composite,
+                        position,
+                        anchor,
+                        canvas,
+                        animation,
+                        filter,
+                        presentation.copied(),
+                    ));
+            }
+        }
+
+        for (entity, (text, &pos, &alignment, layer, &canvas, animation, filter)) in
+            self.texts.iter_manual(world)
+        {
+            if is_visible(world, entity) {
+                layer_contents
+                    .entry(layer.clone())
+                    .or_default()
+                    .texts
+                    .push((text, pos, alignment, canvas, animation, filter));
+            }
+        }
+
+        #[cfg(feature = "line")]
+        let pending_lines: Vec<_> = self
+            .lines
+            .iter_manual(world)
+            .filter(|&(entity, _)| is_visible(world, entity))
+            .map(|(_, (line, filter, layers, &canvas, animation, invert))| {
+                preregister_filter_layer(layers, &mut layer_contents);
+                ((line, filter, canvas, animation, invert), layers)
+            })
+            .collect();
+
+        let pending_filters: Vec<_> = self
+            .filters
+            .iter_manual(world)
+            .filter(|&(entity, _)| is_visible(world, entity))
+            .map(|(_, (filter, layers, animation))| {
+                preregister_filter_layer(layers, &mut layer_contents);
+                ((filter, animation), layers)
+            })
+            .collect();
+
+        let ordered_layers: Vec<L> = layer_contents.keys().cloned().collect();
+        let mut resolved_filter_layers = Vec::with_capacity(8);
+
+        #[cfg(feature = "line")]
+        for (line, layers) in pending_lines {
+            resolve_filter_layers(&mut resolved_filter_layers, layers, &ordered_layers);
+            for (layer, clip) in &resolved_filter_layers {
+                layer_contents
+                    .get_mut(layer)
+                    .unwrap()
+                    .push_line(line, *clip);
+            }
+        }
+
+        for (filter, layers) in pending_filters {
+            resolve_filter_layers(&mut resolved_filter_layers, layers, &ordered_layers);
+            for (layer, clip) in &resolved_filter_layers {
+                layer_contents
+                    .get_mut(layer)
+                    .unwrap()
+                    .push_filter(filter, *clip);
+            }
+        }
+
+        layer_contents
+    }
+}
+
+/// Headless apps usually don't run visibility propagation, so resolve it
+/// here: `Hidden` hides, `Visible` shows, `Inherited` (or none) defers to the
+/// parent.
+fn is_visible(world: &World, mut entity: Entity) -> bool {
+    loop {
+        match world.get::<Visibility>(entity) {
+            Some(Visibility::Hidden) => return false,
+            Some(Visibility::Visible) => return true,
+            Some(Visibility::Inherited) | None => {}
+        }
+        let Some(child_of) = world.get::<ChildOf>(entity) else {
+            return true;
+        };
+        entity = child_of.parent();
+    }
+}
+
+/// Composite the current main-world state, or `None` while the screen or
+/// palette is not ready.
+fn composite_frame<L: CxLayer>(
+    world: &World,
+    queries: &CaptureQueries<L>,
+) -> Option<CxCapturedFrame> {
+    let size = world.get_resource::<CxScreen>()?.size();
+    if size.x == 0 || size.y == 0 {
+        return None;
+    }
+    let palette = world
+        .resource::<Assets<Palette>>()
+        .get(&**world.resource::<PaletteHandle>())?
+        .colors
+        .clone();
+    let &camera = world.resource::<CxCamera>();
+
+    let mut image = CxImage::empty(size);
+    composite_layers(
+        world,
+        &MainWorldAssets::new(world),
+        &mut image.slice_all_mut(),
+        camera,
+        queries.collect(world),
+        &queries.tiles,
+        #[cfg(feature = "gpu_palette")]
+        None,
+        #[cfg(feature = "gpu_palette")]
+        &[],
+    );
+
+    Some(CxCapturedFrame {
+        image: Some(image),
+        palette,
+    })
+}
+
+fn capture_frame<L: CxLayer>(world: &mut World, mut queries: Local<CaptureQueries<L>>) {
+    queries.update_archetypes(world);
+    if let Some(frame) = composite_frame(world, &queries) {
+        *world.resource_mut::<CxCapturedFrame>() = frame;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::RenderAssetUsages;
+    use bevy_render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+    use super::*;
+    use crate::{
+        atlas::CxSpriteAtlasAsset, filter::CxFilterAsset, position::InsertDefaultLayer,
+        sprite::CxSpriteAsset, text::CxTypeface, tilemap::CxTileset,
+    };
+
+    #[cfg_attr(
+        feature = "headed",
+        derive(bevy_render::extract_component::ExtractComponent)
+    )]
+    #[derive(Component, next::Next, Ord, PartialOrd, Eq, PartialEq, Clone, Default, Debug)]
+    #[next(path = next::Next)]
+    enum TestLayer {
+        #[default]
+        Back,
+        Front,
+    }
+
+    const BLACK: [u8; 3] = [0, 0, 0];
+    const RED: [u8; 3] = [255, 0, 0];
+    const BLUE: [u8; 3] = [0, 0, 255];
+
+    /// 4x3 screen, palette `[BLACK, RED, BLUE]` (index 0 is the transparent slot).
+    fn capture_world() -> World {
+        let palette = Image::new(
+            Extent3d {
+                width: 3,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            [[0, 0, 0, 0], [255, 0, 0, 255], [0, 0, 255, 255]].concat(),
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        let mut palettes = Assets::<Palette>::default();
+        let palette = palettes.add(Palette::new(&palette).unwrap());
+
+        let mut world = World::new();
+        world.insert_resource(InsertDefaultLayer::noop());
+        world.insert_resource(palettes);
+        world.insert_resource(PaletteHandle(palette));
+        world.insert_resource(CxScreen::test_resource(UVec2::new(4, 3)));
+        world.init_resource::<CxCamera>();
+        world.init_resource::<CxCapturedFrame>();
+        world.init_resource::<Assets<CxTileset>>();
+        world.init_resource::<Assets<CxSpriteAtlasAsset>>();
+        world.init_resource::<Assets<CxSpriteAsset>>();
+        world.init_resource::<Assets<CxTypeface>>();
+        world.init_resource::<Assets<CxFilterAsset>>();
+        world
+    }
+
+    fn spawn_sprite(world: &mut World, color: u8, size: UVec2, position: IVec2) -> Entity {
+        let sprite = world
+            .resource_mut::<Assets<CxSpriteAsset>>()
+            .add(CxSpriteAsset::from_raw(
+                vec![color; (size.x * size.y) as usize],
+                size.x as usize,
+            ));
+        world
+            .spawn((
+                CxSprite(sprite),
+                CxPosition(position),
+                CxAnchor::BottomLeft,
+                TestLayer::Back,
+                CxRenderSpace::Camera,
+            ))
+            .id()
+    }
+
+    fn capture(world: &mut World) -> CxCapturedFrame {
+        let mut queries = CaptureQueries::<TestLayer>::from_world(world);
+        queries.update_archetypes(world);
+        composite_frame(world, &queries).expect("screen and palette are ready")
+    }
+
+    #[test]
+    fn composites_layers_in_order() {
+        let mut world = capture_world();
+        let front = spawn_sprite(&mut world, 2, UVec2::new(2, 2), IVec2::new(1, 0));
+        world.entity_mut(front).insert(TestLayer::Front);
+        spawn_sprite(&mut world, 1, UVec2::new(2, 2), IVec2::ZERO);
+
+        let frame = capture(&mut world);
+        // Row 0 is the top of the screen; sprites sit on the bottom two rows.
+        assert_eq!(
+            frame.image().unwrap().data(),
+            &[
+                0, 0, 0, 0, //
+                1, 2, 2, 0, //
+                1, 2, 2, 0, //
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_hidden_entities_and_children() {
+        let mut world = capture_world();
+        let parent = spawn_sprite(&mut world, 1, UVec2::ONE, IVec2::ZERO);
+        let child = spawn_sprite(&mut world, 2, UVec2::ONE, IVec2::new(3, 2));
+        world
+            .entity_mut(child)
+            .insert((ChildOf(parent), Visibility::Inherited));
+        world.entity_mut(parent).insert(Visibility::Hidden);
+
+        let frame = capture(&mut world);
+        assert!(
+            frame
+                .image()
+                .unwrap()
+                .data()
+                .iter()
+                .all(|&index| index == 0)
+        );
+    }
+
+    #[test]
+    fn resolves_rgba_through_palette() {
+        let mut world = capture_world();
+        spawn_sprite(&mut world, 2, UVec2::ONE, IVec2::new(0, 2));
+
+        let rgba = capture(&mut world).to_rgba().unwrap();
+        assert_eq!(rgba.len(), 4 * 3 * 4);
+        assert_eq!(rgba[..4], [BLUE[0], BLUE[1], BLUE[2], 255]);
+        assert_eq!(rgba[4..8], [BLACK[0], BLACK[1], BLACK[2], 255]);
+    }
+
+    #[test]
+    fn saves_png() {
+        let mut world = capture_world();
+        spawn_sprite(&mut world, 1, UVec2::ONE, IVec2::ZERO);
+        let frame = capture(&mut world);
+
+        let path = std::env::temp_dir()
+            .join(format!("carapace_capture_{}", std::process::id()))
+            .join("frame.png");
+        frame.save_png(&path).unwrap();
+        let png = ::image::open(&path).unwrap().into_rgba8();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(png.dimensions(), (4, 3));
+        assert_eq!(png.get_pixel(0, 2).0, [RED[0], RED[1], RED[2], 255]);
+        assert_eq!(png.get_pixel(3, 0).0, [BLACK[0], BLACK[1], BLACK[2], 255]);
+    }
+
+    #[test]
+    fn nothing_captured_before_palette_loads() {
+        let mut world = capture_world();
+        world.insert_resource(PaletteHandle(Handle::default()));
+        let queries = CaptureQueries::<TestLayer>::from_world(&mut world);
+
+        assert!(composite_frame(&world, &queries).is_none());
+        assert!(CxCapturedFrame::default().save_png("unused.png").is_err());
+    }
+
+    #[test]
+    fn plugin_captures_frames_in_headless_app() {
+        let mut app = App::new();
+        app.add_plugins((
+            bevy_app::TaskPoolPlugin::default(),
+            bevy_time::TimePlugin,
+            bevy_input::InputPlugin,
+            bevy_asset::AssetPlugin::default(),
+            CxHeadlessPlugin::<TestLayer>::new(UVec2::new(4, 3), "palette/base.png"),
+            CxCapturePlugin::<TestLayer>::default(),
+        ));
+        let sprite = app
+            .world_mut()
+            .resource_mut::<Assets<CxSpriteAsset>>()
+            .add(CxSpriteAsset::from_raw(vec![1], 1));
+        app.world_mut().spawn((
+            CxSprite(sprite),
+            CxPosition(IVec2::new(3, 0)),
+            CxAnchor::BottomLeft,
+            TestLayer::Front,
+            CxRenderSpace::Camera,
+        ));
+
+        // The palette loads asynchronously; nothing is captured until it lands.
+        for _ in 0..200 {
+            app.update();
+            if app.world().resource::<CxCapturedFrame>().image().is_some() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+
+        let world = app.world();
+        let frame = world.resource::<CxCapturedFrame>();
+        let image = frame
+            .image()
+            .expect("palette should load and a frame be captured");
+        assert_eq!(image.size(), UVec2::new(4, 3));
+        assert_eq!(
+            image.data(),
+            &[
+                0, 0, 0, 0, //
+                0, 0, 0, 0, //
+                0, 0, 0, 1, //
+            ]
+        );
+
+        let palette = world
+            .resource::<Assets<Palette>>()
+            .get(&**world.resource::<PaletteHandle>())
+            .unwrap();
+        assert_eq!(frame.color(1), palette.colors[1]);
+    }
+}
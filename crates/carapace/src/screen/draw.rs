@@ -1,6 +1,5 @@
 use std::collections::BTreeMap;
 
-#[cfg(feature = "headed")]
 use bevy_ecs::{query::QueryState, world::World};
 #[cfg(feature = "headed")]
 use bevy_render::render_asset::RenderAssets;
@@ -28,6 +27,7 @@ use crate::{
     tilemap::{CxTile, CxTileset},
 };
 
+use super::AssetLookup;
 #[cfg(feature = "headed")]
 use super::pipeline::{CxRenderBuffer, CxRenderBufferInner};
 use crate::tilemap::TileComponents;
@@ -149,6 +149,108 @@ impl<'a> LayerContents<'a> {
 
 pub type LayerContentsMap<'a, L> = BTreeMap<L, LayerContents<'a>>;
 
+/// Resolves filter layer targets against a pre-built ordered layer index.
+///
+/// Uses binary search (`partition_point`) for `Range` variants, giving `O(log L + K)` cost
+/// instead of the previous `O(L)` full-scan approach. The `ordered_layers` slice must be
+/// sorted (which it is, since it comes from `BTreeMap::keys`).
+pub(crate) fn resolve_filter_layers<L: CxLayer>(
+    out: &mut Vec<(L, bool)>,
+    layers: &CxFilterLayers<L>,
+    ordered_layers: &[L],
+) {
+    out.clear();
+    match layers {
+        CxFilterLayers::Single { layer, clip } => out.push((layer.clone(), *clip)),
+        CxFilterLayers::Range(range) => {
+            let start = ordered_layers.partition_point(|l| l < range.start());
+            let end = start + ordered_layers[start..].partition_point(|l| l <= range.end());
+            out.extend(
+                ordered_layers[start..end]
+                    .iter()
+                    .map(|layer| (layer.clone(), true)),
+            );
+        }
+        CxFilterLayers::Many(layers) => {
+            out.extend(layers.iter().map(|layer| (layer.clone(), true)));
+        }
+    }
+}
+
+/// Pre-registers layers referenced by `Single` and `Many` filter targets so they are present
+/// in the layer map before the ordered index is built. `Range` targets are not pre-registered
+/// because they resolve against the discovered set.
+pub(crate) fn preregister_filter_layer<L: CxLayer>(
+    layers: &CxFilterLayers<L>,
+    layer_contents: &mut LayerContentsMap<'_, L>,
+) {
+    match layers {
+        CxFilterLayers::Single { layer, .. } => {
+            layer_contents.entry(layer.clone()).or_default();
+        }
+        CxFilterLayers::Many(ls) => {
+            for l in ls {
+                layer_contents.entry(l.clone()).or_default();
+            }
+        }
+        CxFilterLayers::Range(_) => {}
+    }
+}
+
+/// Asset storages the compositor reads from: `RenderAssets` in the render
+/// world, the main world's `Assets` for headless capture.
+pub(crate) struct DrawAssets<'w, T, At, S, Ty, F> {
+    tilesets: &'w T,
+    atlas_assets: &'w At,
+    sprite_assets: &'w S,
+    typefaces: &'w Ty,
+    filters: &'w F,
+}
+
+pub(crate) type MainWorldAssets<'w> = DrawAssets<
+    'w,
+    Assets<CxTileset>,
+    Assets<CxSpriteAtlasAsset>,
+    Assets<CxSpriteAsset>,
+    Assets<CxTypeface>,
+    Assets<CxFilterAsset>,
+>;
+
+impl<'w> MainWorldAssets<'w> {
+    pub(crate) fn new(world: &'w World) -> Self {
+        Self {
+            tilesets: world.resource(),
+            atlas_assets: world.resource(),
+            sprite_assets: world.resource(),
+            typefaces: world.resource(),
+            filters: world.resource(),
+        }
+    }
+}
+
+#[cfg(feature = "headed")]
+pub(crate) type RenderWorldAssets<'w> = DrawAssets<
+    'w,
+    RenderAssets<CxTileset>,
+    RenderAssets<CxSpriteAtlasAsset>,
+    RenderAssets<CxSpriteAsset>,
+    RenderAssets<CxTypeface>,
+    RenderAssets<CxFilterAsset>,
+>;
+
+#[cfg(feature = "headed")]
+impl<'w> RenderWorldAssets<'w> {
+    pub(crate) fn new(world: &'w World) -> Self {
+        Self {
+            tilesets: world.resource(),
+            atlas_assets: world.resource(),
+            sprite_assets: world.resource(),
+            typefaces: world.resource(),
+            filters: world.resource(),
+        }
+    }
+}
+
 #[cfg(feature = "headed")]
 #[allow(clippy::significant_drop_tightening)]
 pub fn draw_layers<'w, L: CxLayer>(
@@ -159,591 +261,624 @@ pub fn draw_layers<'w, L: CxLayer>(
     tiles: &QueryState<TileComponents>,
     #[cfg(feature = "gpu_palette")] layer_order: &[L],
 ) {
-    let tilesets = world.resource::<RenderAssets<CxTileset>>();
-    let atlas_assets = world.resource::<RenderAssets<CxSpriteAtlasAsset>>();
-    let sprite_assets = world.resource::<RenderAssets<CxSpriteAsset>>();
-    let typefaces = world.resource::<RenderAssets<CxTypeface>>();
-    let filters = world.resource::<RenderAssets<CxFilterAsset>>();
-
-    {
-        let mut inner = render_buffer.write_inner();
-        let CxRenderBufferInner {
-            image,
-            #[cfg(feature = "gpu_palette")]
-            depth_image,
-            ..
-        } = &mut *inner;
-        let image = image.as_mut().unwrap();
+    let mut inner = render_buffer.write_inner();
+    let CxRenderBufferInner {
+        image,
         #[cfg(feature = "gpu_palette")]
-        let mut depth_data = depth_image
-            .as_mut()
-            .and_then(|depth| depth.data.as_mut())
-            .map(|data| cast_slice_mut::<u8, u16>(data.as_mut_slice()));
+        depth_image,
+        ..
+    } = &mut *inner;
+    let image = image.as_mut().unwrap();
+    #[cfg(feature = "gpu_palette")]
+    let depth_data = depth_image
+        .as_mut()
+        .and_then(|depth| depth.data.as_mut())
+        .map(|data| cast_slice_mut::<u8, u16>(data.as_mut_slice()));
+    let mut image_slice = CxImageSliceMut::from_image_mut(image).unwrap();
+
+    composite_layers(
+        world,
+        &RenderWorldAssets::new(world),
+        &mut image_slice,
+        camera,
+        layer_contents,
+        tiles,
         #[cfg(feature = "gpu_palette")]
-        let (image_width, image_height) = {
-            let height = image.height() as usize;
-            (image.width() as usize, height)
-        };
-        let mut layer_image = CxImage::empty_from_image(image);
-        let mut image_slice = CxImageSliceMut::from_image_mut(image).unwrap();
-
-        #[allow(unused_variables)]
-        for (layer, contents) in layer_contents {
-            let LayerContents {
-                primitives,
-                maps,
-                sprites,
-                atlas_sprites,
-                composites,
-                texts,
-                #[cfg(feature = "line")]
-                clip_lines,
-                clip_filters,
-                #[cfg(feature = "line")]
-                over_lines,
-                over_filters,
-            } = contents;
-            #[cfg(feature = "gpu_palette")]
-            let base_depth = layer_index_for(layer_order, &layer);
-            #[cfg(feature = "gpu_palette")]
-            let over_depth = base_depth.map(|depth| depth.saturating_add(1));
-            layer_image.clear();
-            let mut layer_slice = layer_image.slice_all_mut();
+        depth_data,
+        #[cfg(feature = "gpu_palette")]
+        layer_order,
+    );
+}
 
-            for (map, position, canvas, frame, map_filter) in maps {
-                let Some(tileset) = tilesets.get(&map.tileset) else {
-                    continue;
-                };
+/// Draw every layer in order into `image_slice`, then the filter cursor.
+///
+/// `world` is whichever world `layer_contents` and `tiles` were gathered
+/// from. Depth is only written when `depth_data` is given.
+pub(crate) fn composite_layers<'w, L, T, At, S, Ty, F>(
+    world: &'w World,
+    assets: &DrawAssets<'_, T, At, S, Ty, F>,
+    image_slice: &mut CxImageSliceMut,
+    camera: CxCamera,
+    layer_contents: LayerContentsMap<'w, L>,
+    tiles: &QueryState<TileComponents>,
+    #[cfg(feature = "gpu_palette")] mut depth_data: Option<&mut [u16]>,
+    #[cfg(feature = "gpu_palette")] layer_order: &[L],
+) where
+    L: CxLayer,
+    T: AssetLookup<CxTileset>,
+    At: AssetLookup<CxSpriteAtlasAsset>,
+    S: AssetLookup<CxSpriteAsset>,
+    Ty: AssetLookup<CxTypeface>,
+    F: AssetLookup<CxFilterAsset>,
+{
+    let &DrawAssets {
+        tilesets,
+        atlas_assets,
+        sprite_assets,
+        typefaces,
+        filters,
+    } = assets;
+
+    #[cfg(feature = "gpu_palette")]
+    let (image_width, image_height) = (image_slice.width, image_slice.image.len());
+    let mut layer_image = CxImage::empty(image_slice.size());
+
+    #[allow(unused_variables)]
+    for (layer, contents) in layer_contents {
+        let LayerContents {
+            primitives,
+            maps,
+            sprites,
+            atlas_sprites,
+            composites,
+            texts,
+            #[cfg(feature = "line")]
+            clip_lines,
+            clip_filters,
+            #[cfg(feature = "line")]
+            over_lines,
+            over_filters,
+        } = contents;
+        #[cfg(feature = "gpu_palette")]
+        let base_depth = layer_index_for(layer_order, &layer);
+        #[cfg(feature = "gpu_palette")]
+        let over_depth = base_depth.map(|depth| depth.saturating_add(1));
+        layer_image.clear();
+        let mut layer_slice = layer_image.slice_all_mut();
 
-                let map_filter = resolve_filter(map_filter, filters);
-                let size = map.tiles.size();
+        for (map, position, canvas, frame, map_filter) in maps {
+            let Some(tileset) = tilesets.get(&map.tileset) else {
+                continue;
+            };
 
-                for x in 0..size.x {
-                    for y in 0..size.y {
-                        let pos = UVec2::new(x, y);
+            let map_filter = resolve_filter(map_filter, filters);
+            let size = map.tiles.size();
 
-                        let Some(tile) = map.tiles.get(pos) else {
-                            continue;
-                        };
+            for x in 0..size.x {
+                for y in 0..size.y {
+                    let pos = UVec2::new(x, y);
 
-                        let Ok((&CxTile { texture }, tile_filter)) = tiles.get_manual(world, tile)
-                        else {
-                            continue;
-                        };
+                    let Some(tile) = map.tiles.get(pos) else {
+                        continue;
+                    };
 
-                        let Some(tile) = tileset.tileset.get(texture as usize) else {
-                            error!(
-                                "tile texture index out of bounds: the len is {}, but the index is {texture}",
-                                tileset.tileset.len()
-                            );
-                            continue;
-                        };
+                    let Ok((&CxTile { texture }, tile_filter)) = tiles.get_manual(world, tile)
+                    else {
+                        continue;
+                    };
 
-                        draw_spatial(
-                            tile,
-                            (),
-                            &mut layer_slice,
-                            (*position + pos.as_ivec2() * tileset.tile_size().as_ivec2()).into(),
-                            CxAnchor::BottomLeft,
-                            canvas,
-                            frame.copied(),
-                            [resolve_filter(tile_filter, filters), map_filter]
-                                .into_iter()
-                                .flatten(),
-                            camera,
+                    let Some(tile) = tileset.tileset.get(texture as usize) else {
+                        error!(
+                            "tile texture index out of bounds: the len is {}, but the index is {texture}",
+                            tileset.tileset.len()
                         );
-                    }
-                }
-            }
-
-            // Primitives draw after maps but before sprites, so sprites
-            // always paint on top and map tiles form the backdrop.
-            for (prim, position, anchor, canvas, presentation) in primitives {
-                let size = prim.shape.frame_size();
-                let visual_offset = presentation.map_or(Vec2::ZERO, |pt| pt.visual_offset);
-                let position = *position + visual_offset.round().as_ivec2();
-                let position = position - anchor.ipos(size);
-                let world_origin = position;
-                let position = match canvas {
-                    CxRenderSpace::World => position - *camera,
-                    CxRenderSpace::Camera => position,
-                };
-                // Image space: Y flipped (top-left origin).
-                let image_pos =
-                    IVec2::new(position.x, layer_slice.flip_y(position.y) - size.y as i32);
-                let mut prim_slice = layer_slice.slice_mut(IRect::from_pos_size(image_pos, size));
-                crate::primitive::draw_primitive(prim, &mut prim_slice, world_origin);
-            }
-
-            for (sprite, position, anchor, canvas, frame, filter, presentation) in sprites {
-                let Some(sprite) = sprite_assets.get(&**sprite) else {
-                    continue;
-                };
-
-                let resolved_filters = resolve_filter(filter, filters);
-
-                if let Some(pt) = presentation
-                    && pt.needs_transformed_blit()
-                {
-                    // Transformed path: scratch buffer + nearest-neighbour blit.
-                    draw_spatial_transformed(
-                        sprite,
-                        (),
-                        &mut layer_slice,
-                        position,
-                        anchor,
-                        canvas,
-                        frame.copied(),
-                        resolved_filters,
-                        camera,
-                        pt.clamped_scale(),
-                        pt.sanitised_rotation(),
-                        pt.visual_offset,
-                    );
-                } else {
-                    // Unscaled path: offset-only adjustment (if any), no scratch buffer.
-                    let adjusted_pos = if let Some(pt) = presentation
-                        && pt.has_offset()
-                    {
-                        CxPosition(*position + pt.visual_offset.round().as_ivec2())
-                    } else {
-                        position
+                        continue;
                     };
+
                     draw_spatial(
-                        sprite,
+                        tile,
                         (),
                         &mut layer_slice,
-                        adjusted_pos,
-                        anchor,
+                        (*position + pos.as_ivec2() * tileset.tile_size().as_ivec2()).into(),
+                        CxAnchor::BottomLeft,
                         canvas,
                         frame.copied(),
-                        resolved_filters,
+                        [resolve_filter(tile_filter, filters), map_filter]
+                            .into_iter()
+                            .flatten(),
                         camera,
                     );
                 }
             }
+        }
 
-            for (sprite, position, anchor, canvas, frame, filter, presentation) in atlas_sprites {
-                let Some(atlas) = atlas_assets.get(&sprite.atlas) else {
-                    continue;
-                };
-                let Some(region) = atlas.region(sprite.region) else {
-                    continue;
-                };
-
-                let resolved_filters = resolve_filter(filter, filters);
+        // Primitives draw after maps but before sprites, so sprites
+        // always paint on top and map tiles form the backdrop.
+        for (prim, position, anchor, canvas, presentation) in primitives {
+            let size = prim.shape.frame_size();
+            let visual_offset = presentation.map_or(Vec2::ZERO, |pt| pt.visual_offset);
+            let position = *position + visual_offset.round().as_ivec2();
+            let position = position - anchor.ipos(size);
+            let world_origin = position;
+            let position = match canvas {
+                CxRenderSpace::World => position - *camera,
+                CxRenderSpace::Camera => position,
+            };
+            // Image space: Y flipped (top-left origin).
+            let image_pos = IVec2::new(position.x, layer_slice.flip_y(position.y) - size.y as i32);
+            let mut prim_slice = layer_slice.slice_mut(IRect::from_pos_size(image_pos, size));
+            crate::primitive::draw_primitive(prim, &mut prim_slice, world_origin);
+        }
 
-                if let Some(pt) = presentation
-                    && pt.needs_transformed_blit()
+        for (sprite, position, anchor, canvas, frame, filter, presentation) in sprites {
+            let Some(sprite) = sprite_assets.get(&**sprite) else {
+                continue;
+            };
+
+            let resolved_filters = resolve_filter(filter, filters);
+
+            if let Some(pt) = presentation
+                && pt.needs_transformed_blit()
+            {
+                // Transformed path: scratch buffer + nearest-neighbour blit.
+                draw_spatial_transformed(
+                    sprite,
+                    (),
+                    &mut layer_slice,
+                    position,
+                    anchor,
+                    canvas,
+                    frame.copied(),
+                    resolved_filters,
+                    camera,
+                    pt.clamped_scale(),
+                    pt.sanitised_rotation(),
+                    pt.visual_offset,
+                );
+            } else {
+                // Unscaled path: offset-only adjustment (if any), no scratch buffer.
+                let adjusted_pos = if let Some(pt) = presentation
+                    && pt.has_offset()
                 {
-                    draw_spatial_transformed(
-                        &(atlas, region),
-                        (),
-                        &mut layer_slice,
-                        position,
-                        anchor,
-                        canvas,
-                        frame.copied(),
-                        resolved_filters,
-                        camera,
-                        pt.clamped_scale(),
-                        pt.sanitised_rotation(),
-                        pt.visual_offset,
-                    );
+                    CxPosition(*position + pt.visual_offset.round().as_ivec2())
                 } else {
-                    let adjusted_pos = if let Some(pt) = presentation
-                        && pt.has_offset()
-                    {
-                        CxPosition(*position + pt.visual_offset.round().as_ivec2())
-                    } else {
-                        position
-                    };
-                    draw_spatial(
-                        &(atlas, region),
-                        (),
-                        &mut layer_slice,
-                        adjusted_pos,
-                        anchor,
-                        canvas,
-                        frame.copied(),
-                        resolved_filters,
-                        camera,
-                    );
-                }
+                    position
+                };
+                draw_spatial(
+                    sprite,
+                    (),
+                    &mut layer_slice,
+                    adjusted_pos,
+                    anchor,
+                    canvas,
+                    frame.copied(),
+                    resolved_filters,
+                    camera,
+                );
             }
+        }
 
-            for (composite, position, anchor, canvas, frame, filter, presentation) in composites {
-                let metrics = if composite.size.x == 0 || composite.size.y == 0 {
-                    composite.metrics_with(|source| {
-                        source
-                            .resolve(
-                                |handle| sprite_assets.get(handle),
-                                |handle| atlas_assets.get(handle),
-                            )
-                            .ok()
-                            .map(|resolved| CxCompositePartMetrics {
-                                size: resolved.frame_size(),
-                                frame_count: resolved.frame_count(),
-                            })
-                    })
+        for (sprite, position, anchor, canvas, frame, filter, presentation) in atlas_sprites {
+            let Some(atlas) = atlas_assets.get(&sprite.atlas) else {
+                continue;
+            };
+            let Some(region) = atlas.region(sprite.region) else {
+                continue;
+            };
+
+            let resolved_filters = resolve_filter(filter, filters);
+
+            if let Some(pt) = presentation
+                && pt.needs_transformed_blit()
+            {
+                draw_spatial_transformed(
+                    &(atlas, region),
+                    (),
+                    &mut layer_slice,
+                    position,
+                    anchor,
+                    canvas,
+                    frame.copied(),
+                    resolved_filters,
+                    camera,
+                    pt.clamped_scale(),
+                    pt.sanitised_rotation(),
+                    pt.visual_offset,
+                );
+            } else {
+                let adjusted_pos = if let Some(pt) = presentation
+                    && pt.has_offset()
+                {
+                    CxPosition(*position + pt.visual_offset.round().as_ivec2())
                 } else {
-                    Some(CxCompositeMetrics {
-                        size: composite.size,
-                        origin: composite.origin,
-                        render_size: composite.render_size,
-                        render_origin: composite.render_origin,
-                        frame_count: composite.frame_count,
-                    })
-                };
-                let Some(metrics) = metrics else {
-                    continue;
+                    position
                 };
+                draw_spatial(
+                    &(atlas, region),
+                    (),
+                    &mut layer_slice,
+                    adjusted_pos,
+                    anchor,
+                    canvas,
+                    frame.copied(),
+                    resolved_filters,
+                    camera,
+                );
+            }
+        }
 
-                // Nothing to render for zero-area composites (metrics not yet
-                // populated, e.g. assets still loading).
-                if metrics.render_size.x == 0 || metrics.render_size.y == 0 {
-                    continue;
-                }
+        for (composite, position, anchor, canvas, frame, filter, presentation) in composites {
+            let metrics = if composite.size.x == 0 || composite.size.y == 0 {
+                composite.metrics_with(|source| {
+                    source
+                        .resolve(
+                            |handle| sprite_assets.get(handle),
+                            |handle| atlas_assets.get(handle),
+                        )
+                        .ok()
+                        .map(|resolved| CxCompositePartMetrics {
+                            size: resolved.frame_size(),
+                            frame_count: resolved.frame_count(),
+                        })
+                })
+            } else {
+                Some(CxCompositeMetrics {
+                    size: composite.size,
+                    origin: composite.origin,
+                    render_size: composite.render_size,
+                    render_origin: composite.render_origin,
+                    frame_count: composite.frame_count,
+                })
+            };
+            let Some(metrics) = metrics else {
+                continue;
+            };
 
-                let needs_scaled = presentation.is_some_and(|pt| pt.needs_transformed_blit());
+            // Nothing to render for zero-area composites (metrics not yet
+            // populated, e.g. assets still loading).
+            if metrics.render_size.x == 0 || metrics.render_size.y == 0 {
+                continue;
+            }
 
-                if needs_scaled {
-                    let pt = presentation.unwrap();
-                    let scale = pt.clamped_scale();
+            let needs_scaled = presentation.is_some_and(|pt| pt.needs_transformed_blit());
+
+            if needs_scaled {
+                let pt = presentation.unwrap();
+                let scale = pt.clamped_scale();
+
+                // Compose all parts into a scratch buffer sized to the render
+                // envelope (may be larger than base size for transformed parts).
+                let mut scratch = crate::image::CxImage::empty(metrics.render_size);
+                let mut scratch_slice = scratch.slice_all_mut();
+                let master = frame.copied();
+                let master_count = metrics.frame_count;
+
+                for (part_index, part) in composite.parts.iter().enumerate() {
+                    let resolved = match part.source.resolve(
+                        |handle| sprite_assets.get(handle),
+                        |handle| atlas_assets.get(handle),
+                    ) {
+                        Ok(resolved) => resolved,
+                        Err(error) => {
+                            log_composite_part_resolve_error(part_index, &error);
+                            continue;
+                        }
+                    };
 
-                    // Compose all parts into a scratch buffer sized to the render
-                    // envelope (may be larger than base size for transformed parts).
-                    let mut scratch = crate::image::CxImage::empty(metrics.render_size);
-                    let mut scratch_slice = scratch.slice_all_mut();
-                    let master = frame.copied();
-                    let master_count = metrics.frame_count;
+                    let part_frame = resolve_frame_binding(
+                        master,
+                        master_count,
+                        resolved.frame_count(),
+                        &part.frame,
+                    );
+                    let part_filter = part.filter.as_ref().and_then(|handle| filters.get(handle));
+                    let entity_filter = resolve_filter(filter, filters);
+                    let drawable = CxCompositePartDrawable {
+                        resolved,
+                        flip_x: part.flip_x,
+                        flip_y: part.flip_y,
+                    };
 
-                    for (part_index, part) in composite.parts.iter().enumerate() {
-                        let resolved = match part.source.resolve(
-                            |handle| sprite_assets.get(handle),
-                            |handle| atlas_assets.get(handle),
-                        ) {
-                            Ok(resolved) => resolved,
-                            Err(error) => {
-                                log_composite_part_resolve_error(part_index, &error);
-                                continue;
-                            }
-                        };
-
-                        let part_frame = resolve_frame_binding(
-                            master,
-                            master_count,
-                            resolved.frame_count(),
-                            &part.frame,
-                        );
-                        let part_filter =
-                            part.filter.as_ref().and_then(|handle| filters.get(handle));
-                        let entity_filter = resolve_filter(filter, filters);
-                        let drawable = CxCompositePartDrawable {
-                            resolved,
-                            flip_x: part.flip_x,
-                            flip_y: part.flip_y,
-                        };
-
-                        let needs_part_transform =
-                            part.transform.as_ref().is_some_and(|t| !t.is_identity());
-
-                        if needs_part_transform {
-                            let t = part.transform.as_ref().unwrap();
-                            let part_size = drawable.frame_size();
-                            if part_size.x == 0 || part_size.y == 0 {
-                                continue;
-                            }
-
-                            // Render part into a mini scratch at native size.
-                            let mut mini = CxImage::empty(part_size);
-                            let mut mini_slice = mini.slice_all_mut();
-                            crate::frame::draw_frame(
-                                &drawable,
-                                (),
-                                &mut mini_slice,
-                                part_frame,
-                                [part_filter, entity_filter].into_iter().flatten(),
-                            );
-
-                            // Pivot position in render-scratch engine-space.
-                            let part_bl = (part.offset - metrics.render_origin).as_vec2();
-                            let ps = part_size.as_vec2();
-                            let pivot_pos =
-                                part_bl + Vec2::new(t.pivot.x * ps.x, (1.0 - t.pivot.y) * ps.y);
-
-                            blit_transformed(
-                                &mini,
-                                part_size,
-                                &mut scratch_slice,
-                                CxPosition(pivot_pos.round().as_ivec2()),
-                                t.anchor(),
-                                CxRenderSpace::Camera,
-                                CxCamera(IVec2::ZERO),
-                                t.clamped_scale(),
-                                t.sanitised_rotation(),
-                                Vec2::ZERO,
-                            );
-                        } else {
-                            let part_pos = part.offset - metrics.render_origin;
-                            draw_spatial(
-                                &drawable,
-                                (),
-                                &mut scratch_slice,
-                                part_pos.into(),
-                                CxAnchor::BottomLeft,
-                                CxRenderSpace::Camera,
-                                part_frame,
-                                [part_filter, entity_filter].into_iter().flatten(),
-                                CxCamera(IVec2::ZERO),
-                            );
+                    let needs_part_transform =
+                        part.transform.as_ref().is_some_and(|t| !t.is_identity());
+
+                    if needs_part_transform {
+                        let t = part.transform.as_ref().unwrap();
+                        let part_size = drawable.frame_size();
+                        if part_size.x == 0 || part_size.y == 0 {
+                            continue;
                         }
-                    }
 
-                    // Blit the composed scratch buffer transformed to the layer.
-                    // The scratch is render_size (may be larger than base size).
-                    // Compute a custom anchor that maps the entity's anchor to the
-                    // correct position within the enlarged render scratch: the base
-                    // frame sits at offset (origin - render_origin) inside the scratch.
-                    let base_in_scratch = (metrics.origin - metrics.render_origin).as_vec2();
-                    let base_anchor = anchor.pos(metrics.size).as_vec2();
-                    let render_anchor_px = base_in_scratch + base_anchor;
-                    // render_size is guaranteed non-zero here (zero-area
-                    // composites are skipped above).
-                    let render_anchor = CxAnchor::Custom(Vec2::new(
-                        render_anchor_px.x / metrics.render_size.x as f32,
-                        render_anchor_px.y / metrics.render_size.y as f32,
-                    ));
-                    blit_transformed(
-                        &scratch,
-                        metrics.render_size,
-                        &mut layer_slice,
-                        position,
-                        render_anchor,
-                        canvas,
-                        camera,
-                        scale,
-                        pt.sanitised_rotation(),
-                        pt.visual_offset,
-                    );
-                } else {
-                    // Unscaled path: draw parts directly to layer. Apply offset if present.
-                    let offset_adjust = presentation
-                        .filter(super::super::presentation::CxPresentationTransform::has_offset)
-                        .map_or(IVec2::ZERO, |pt| pt.visual_offset.round().as_ivec2());
-                    let base_pos = *position + offset_adjust - anchor.pos(metrics.size).as_ivec2();
-                    let master = frame.copied();
-                    let master_count = metrics.frame_count;
-
-                    for (part_index, part) in composite.parts.iter().enumerate() {
-                        let resolved = match part.source.resolve(
-                            |handle| sprite_assets.get(handle),
-                            |handle| atlas_assets.get(handle),
-                        ) {
-                            Ok(resolved) => resolved,
-                            Err(error) => {
-                                log_composite_part_resolve_error(part_index, &error);
-                                continue;
-                            }
-                        };
-
-                        let part_frame = resolve_frame_binding(
-                            master,
-                            master_count,
-                            resolved.frame_count(),
-                            &part.frame,
+                        // Render part into a mini scratch at native size.
+                        let mut mini = CxImage::empty(part_size);
+                        let mut mini_slice = mini.slice_all_mut();
+                        crate::frame::draw_frame(
+                            &drawable,
+                            (),
+                            &mut mini_slice,
+                            part_frame,
+                            [part_filter, entity_filter].into_iter().flatten(),
+                        );
+
+                        // Pivot position in render-scratch engine-space.
+                        let part_bl = (part.offset - metrics.render_origin).as_vec2();
+                        let ps = part_size.as_vec2();
+                        let pivot_pos =
+                            part_bl + Vec2::new(t.pivot.x * ps.x, (1.0 - t.pivot.y) * ps.y);
+
+                        blit_transformed(
+                            &mini,
+                            part_size,
+                            &mut scratch_slice,
+                            CxPosition(pivot_pos.round().as_ivec2()),
+                            t.anchor(),
+                            CxRenderSpace::Camera,
+                            CxCamera(IVec2::ZERO),
+                            t.clamped_scale(),
+                            t.sanitised_rotation(),
+                            Vec2::ZERO,
+                        );
+                    } else {
+                        let part_pos = part.offset - metrics.render_origin;
+                        draw_spatial(
+                            &drawable,
+                            (),
+                            &mut scratch_slice,
+                            part_pos.into(),
+                            CxAnchor::BottomLeft,
+                            CxRenderSpace::Camera,
+                            part_frame,
+                            [part_filter, entity_filter].into_iter().flatten(),
+                            CxCamera(IVec2::ZERO),
                         );
-                        let part_filter =
-                            part.filter.as_ref().and_then(|handle| filters.get(handle));
-                        let entity_filter = resolve_filter(filter, filters);
-                        let drawable = CxCompositePartDrawable {
-                            resolved,
-                            flip_x: part.flip_x,
-                            flip_y: part.flip_y,
-                        };
-
-                        let needs_part_transform =
-                            part.transform.as_ref().is_some_and(|t| !t.is_identity());
-
-                        if needs_part_transform {
-                            let t = part.transform.as_ref().unwrap();
-                            let part_size = drawable.frame_size();
-                            if part_size.x == 0 || part_size.y == 0 {
-                                continue;
-                            }
-
-                            // Render part into a mini scratch at native size.
-                            let mut mini = CxImage::empty(part_size);
-                            let mut mini_slice = mini.slice_all_mut();
-                            crate::frame::draw_frame(
-                                &drawable,
-                                (),
-                                &mut mini_slice,
-                                part_frame,
-                                [part_filter, entity_filter].into_iter().flatten(),
-                            );
-
-                            // Pivot position in world engine-space.
-                            let part_bl = (base_pos + (part.offset - metrics.origin)).as_vec2();
-                            let ps = part_size.as_vec2();
-                            let pivot_pos =
-                                part_bl + Vec2::new(t.pivot.x * ps.x, (1.0 - t.pivot.y) * ps.y);
-
-                            blit_transformed(
-                                &mini,
-                                part_size,
-                                &mut layer_slice,
-                                CxPosition(pivot_pos.round().as_ivec2()),
-                                t.anchor(),
-                                canvas,
-                                camera,
-                                t.clamped_scale(),
-                                t.sanitised_rotation(),
-                                Vec2::ZERO,
-                            );
-                        } else {
-                            let part_pos = base_pos + (part.offset - metrics.origin);
-                            draw_spatial(
-                                &drawable,
-                                (),
-                                &mut layer_slice,
-                                part_pos.into(),
-                                CxAnchor::BottomLeft,
-                                canvas,
-                                part_frame,
-                                [part_filter, entity_filter].into_iter().flatten(),
-                                camera,
-                            );
-                        }
                     }
                 }
-            }
-
-            for (text, pos, alignment, canvas, frame, filter) in texts {
-                let Some(typeface) = typefaces.get(&text.typeface) else {
-                    continue;
-                };
 
-                let line_break_count = text.line_breaks.len() as u32;
-                let mut size = uvec2(
-                    0,
-                    (line_break_count + 1) * typeface.height + line_break_count,
+                // Blit the composed scratch buffer transformed to the layer.
+                // The scratch is render_size (may be larger than base size).
+                // Compute a custom anchor that maps the entity's anchor to the
+                // correct position within the enlarged render scratch: the base
+                // frame sits at offset (origin - render_origin) inside the scratch.
+                let base_in_scratch = (metrics.origin - metrics.render_origin).as_vec2();
+                let base_anchor = anchor.pos(metrics.size).as_vec2();
+                let render_anchor_px = base_in_scratch + base_anchor;
+                // render_size is guaranteed non-zero here (zero-area
+                // composites are skipped above).
+                let render_anchor = CxAnchor::Custom(Vec2::new(
+                    render_anchor_px.x / metrics.render_size.x as f32,
+                    render_anchor_px.y / metrics.render_size.y as f32,
+                ));
+                blit_transformed(
+                    &scratch,
+                    metrics.render_size,
+                    &mut layer_slice,
+                    position,
+                    render_anchor,
+                    canvas,
+                    camera,
+                    scale,
+                    pt.sanitised_rotation(),
+                    pt.visual_offset,
                 );
-                let mut x = 0;
-                let mut y = 0;
-                let mut chars = Vec::new();
-                let mut line_break_index = 0;
-
-                for (index, char) in text.value.chars().enumerate() {
-                    if let Some(char) = typeface.characters.get(&char) {
-                        if x != 0 {
-                            x += 1;
+            } else {
+                // Unscaled path: draw parts directly to layer. Apply offset if present.
+                let offset_adjust = presentation
+                    .filter(super::super::presentation::CxPresentationTransform::has_offset)
+                    .map_or(IVec2::ZERO, |pt| pt.visual_offset.round().as_ivec2());
+                let base_pos = *position + offset_adjust - anchor.pos(metrics.size).as_ivec2();
+                let master = frame.copied();
+                let master_count = metrics.frame_count;
+
+                for (part_index, part) in composite.parts.iter().enumerate() {
+                    let resolved = match part.source.resolve(
+                        |handle| sprite_assets.get(handle),
+                        |handle| atlas_assets.get(handle),
+                    ) {
+                        Ok(resolved) => resolved,
+                        Err(error) => {
+                            log_composite_part_resolve_error(part_index, &error);
+                            continue;
                         }
+                    };
+
+                    let part_frame = resolve_frame_binding(
+                        master,
+                        master_count,
+                        resolved.frame_count(),
+                        &part.frame,
+                    );
+                    let part_filter = part.filter.as_ref().and_then(|handle| filters.get(handle));
+                    let entity_filter = resolve_filter(filter, filters);
+                    let drawable = CxCompositePartDrawable {
+                        resolved,
+                        flip_x: part.flip_x,
+                        flip_y: part.flip_y,
+                    };
 
-                        chars.push((x, y, char));
-                        x += char.data.size().x;
+                    let needs_part_transform =
+                        part.transform.as_ref().is_some_and(|t| !t.is_identity());
 
-                        if x > size.x {
-                            size.x = x;
+                    if needs_part_transform {
+                        let t = part.transform.as_ref().unwrap();
+                        let part_size = drawable.frame_size();
+                        if part_size.x == 0 || part_size.y == 0 {
+                            continue;
                         }
-                    } else if let Some(separator) = typeface.separators.get(&char) {
-                        x += separator.width;
+
+                        // Render part into a mini scratch at native size.
+                        let mut mini = CxImage::empty(part_size);
+                        let mut mini_slice = mini.slice_all_mut();
+                        crate::frame::draw_frame(
+                            &drawable,
+                            (),
+                            &mut mini_slice,
+                            part_frame,
+                            [part_filter, entity_filter].into_iter().flatten(),
+                        );
+
+                        // Pivot position in world engine-space.
+                        let part_bl = (base_pos + (part.offset - metrics.origin)).as_vec2();
+                        let ps = part_size.as_vec2();
+                        let pivot_pos =
+                            part_bl + Vec2::new(t.pivot.x * ps.x, (1.0 - t.pivot.y) * ps.y);
+
+                        blit_transformed(
+                            &mini,
+                            part_size,
+                            &mut layer_slice,
+                            CxPosition(pivot_pos.round().as_ivec2()),
+                            t.anchor(),
+                            canvas,
+                            camera,
+                            t.clamped_scale(),
+                            t.sanitised_rotation(),
+                            Vec2::ZERO,
+                        );
                     } else {
-                        error!(r#"character "{char}" in text isn't in typeface"#);
+                        let part_pos = base_pos + (part.offset - metrics.origin);
+                        draw_spatial(
+                            &drawable,
+                            (),
+                            &mut layer_slice,
+                            part_pos.into(),
+                            CxAnchor::BottomLeft,
+                            canvas,
+                            part_frame,
+                            [part_filter, entity_filter].into_iter().flatten(),
+                            camera,
+                        );
                     }
+                }
+            }
+        }
 
-                    if text.line_breaks.get(line_break_index).copied() == Some(index as u32) {
-                        line_break_index += 1;
-                        y += typeface.height + 1;
-                        x = 0;
+        for (text, pos, alignment, canvas, frame, filter) in texts {
+            let Some(typeface) = typefaces.get(&text.typeface) else {
+                continue;
+            };
+
+            let line_break_count = text.line_breaks.len() as u32;
+            let mut size = uvec2(
+                0,
+                (line_break_count + 1) * typeface.height + line_break_count,
+            );
+            let mut x = 0;
+            let mut y = 0;
+            let mut chars = Vec::new();
+            let mut line_break_index = 0;
+
+            for (index, char) in text.value.chars().enumerate() {
+                if let Some(char) = typeface.characters.get(&char) {
+                    if x != 0 {
+                        x += 1;
                     }
-                }
 
-                let top_left = *pos - alignment.pos(size).as_ivec2() + ivec2(0, size.y as i32 - 1);
+                    chars.push((x, y, char));
+                    x += char.data.size().x;
 
-                for (x, y, char) in chars {
-                    draw_spatial(
-                        char,
-                        (),
-                        &mut layer_slice,
-                        CxPosition(top_left + ivec2(x as i32, -(y as i32))),
-                        CxAnchor::TopLeft,
-                        canvas,
-                        frame.copied(),
-                        resolve_filter(filter, filters),
-                        camera,
-                    );
+                    if x > size.x {
+                        size.x = x;
+                    }
+                } else if let Some(separator) = typeface.separators.get(&char) {
+                    x += separator.width;
+                } else {
+                    error!(r#"character "{char}" in text isn't in typeface"#);
                 }
-            }
 
-            #[cfg(feature = "line")]
-            for (line, filter, canvas, frame, invert) in clip_lines {
-                if let Some(filter) = filter.resolve(filters) {
-                    draw_line(
-                        line,
-                        filter,
-                        invert,
-                        &mut layer_slice,
-                        canvas,
-                        frame.copied(),
-                        camera,
-                    );
+                if text.line_breaks.get(line_break_index).copied() == Some(index as u32) {
+                    line_break_index += 1;
+                    y += typeface.height + 1;
+                    x = 0;
                 }
             }
 
-            for (filter, frame) in clip_filters {
-                if let Some(filter) = filter.resolve(filters) {
-                    draw_filter(filter, frame.copied(), &mut layer_slice);
-                }
+            let top_left = *pos - alignment.pos(size).as_ivec2() + ivec2(0, size.y as i32 - 1);
+
+            for (x, y, char) in chars {
+                draw_spatial(
+                    char,
+                    (),
+                    &mut layer_slice,
+                    CxPosition(top_left + ivec2(x as i32, -(y as i32))),
+                    CxAnchor::TopLeft,
+                    canvas,
+                    frame.copied(),
+                    resolve_filter(filter, filters),
+                    camera,
+                );
             }
+        }
 
-            image_slice.draw(&layer_image);
-            #[cfg(feature = "gpu_palette")]
-            if let (Some(depth), Some(base_depth)) = (depth_data.as_mut(), base_depth) {
-                update_depth_from_layer(depth, layer_image.data(), base_depth);
+        #[cfg(feature = "line")]
+        for (line, filter, canvas, frame, invert) in clip_lines {
+            if let Some(filter) = filter.resolve(filters) {
+                draw_line(
+                    line,
+                    filter,
+                    invert,
+                    &mut layer_slice,
+                    canvas,
+                    frame.copied(),
+                    camera,
+                );
             }
+        }
 
-            #[cfg(feature = "line")]
-            for (line, filter, canvas, frame, invert) in over_lines {
-                if let Some(filter) = filter.resolve(filters) {
-                    draw_line(
-                        line,
-                        filter,
-                        invert,
-                        &mut image_slice,
-                        canvas,
-                        frame.copied(),
-                        camera,
-                    );
-                }
-                #[cfg(all(feature = "gpu_palette", feature = "line"))]
-                if let (Some(depth), Some(over_depth)) = (depth_data.as_mut(), over_depth) {
-                    update_depth_line(
-                        depth,
-                        image_width,
-                        image_height,
-                        line,
-                        canvas,
-                        camera,
-                        invert,
-                        over_depth,
-                    );
-                }
+        for (filter, frame) in clip_filters {
+            if let Some(filter) = filter.resolve(filters) {
+                draw_filter(filter, frame.copied(), &mut layer_slice);
             }
+        }
 
-            for (filter, frame) in over_filters {
-                if let Some(filter) = filter.resolve(filters) {
-                    draw_filter(filter, frame.copied(), &mut image_slice);
-                }
-                #[cfg(feature = "gpu_palette")]
-                if let (Some(depth), Some(over_depth)) = (depth_data.as_mut(), over_depth) {
-                    depth.fill(over_depth);
-                }
+        image_slice.draw(&layer_image);
+        #[cfg(feature = "gpu_palette")]
+        if let (Some(depth), Some(base_depth)) = (depth_data.as_mut(), base_depth) {
+            update_depth_from_layer(depth, layer_image.data(), base_depth);
+        }
+
+        #[cfg(feature = "line")]
+        for (line, filter, canvas, frame, invert) in over_lines {
+            if let Some(filter) = filter.resolve(filters) {
+                draw_line(
+                    line,
+                    filter,
+                    invert,
+                    image_slice,
+                    canvas,
+                    frame.copied(),
+                    camera,
+                );
+            }
+            #[cfg(all(feature = "gpu_palette", feature = "line"))]
+            if let (Some(depth), Some(over_depth)) = (depth_data.as_mut(), over_depth) {
+                update_depth_line(
+                    depth,
+                    image_width,
+                    image_height,
+                    line,
+                    canvas,
+                    camera,
+                    invert,
+                    over_depth,
+                );
+            }
+        }
+
+        for (filter, frame) in over_filters {
+            if let Some(filter) = filter.resolve(filters) {
+                draw_filter(filter, frame.copied(), image_slice);
+            }
+            #[cfg(feature = "gpu_palette")]
+            if let (Some(depth), Some(over_depth)) = (depth_data.as_mut(), over_depth) {
+                depth.fill(over_depth);
             }
         }
     }
 
-    let cursor = world.resource::<CursorState>();
+    // Headless capture has no cursor state unless the app provides one.
+    let Some(cursor) = world.get_resource::<CursorState>() else {
+        return;
+    };
 
     if let CxCursor::Filter {
         idle,
@@ -757,20 +892,11 @@ pub fn draw_layers<'w, L: CxLayer>(
             CursorState::Right => right_click,
         })
     {
-        let mut inner = render_buffer.write_inner();
-        let CxRenderBufferInner {
-            image,
-            #[cfg(feature = "gpu_palette")]
-            depth_image,
-            ..
-        } = &mut *inner;
-        let image = image.as_mut().unwrap();
-        let mut cursor_image = CxImageSliceMut::from_image_mut(image).unwrap();
         let cursor_pos = IVec2::new(
             cursor_pos.x as i32,
-            cursor_image.flip_y(cursor_pos.y as i32) - 1,
+            image_slice.flip_y(cursor_pos.y as i32) - 1,
         );
-        if let Some(pixel) = cursor_image.get_pixel_mut(cursor_pos) {
+        if let Some(pixel) = image_slice.get_pixel_mut(cursor_pos) {
             if let Some(new_pixel) = filter.get_pixel(IVec2::new(i32::from(*pixel), 0)) {
                 *pixel = new_pixel;
             } else {
@@ -778,13 +904,12 @@ pub fn draw_layers<'w, L: CxLayer>(
             }
         }
         #[cfg(feature = "gpu_palette")]
-        if let Some(depth) = depth_image.as_mut().and_then(|depth| depth.data.as_mut()) {
-            let width = cursor_image.width;
+        if let Some(depth) = depth_data {
+            let width = image_slice.width;
             if cursor_pos.x >= 0 && cursor_pos.y >= 0 {
                 let x = cursor_pos.x as usize;
                 let y = cursor_pos.y as usize;
-                if x < width && y < cursor_image.image.len() {
-                    let depth = cast_slice_mut::<u8, u16>(depth);
+                if x < width && y < image_slice.image.len() {
                     depth[y * width + x] = u16::MAX;
                 }
             }
@@ -40,61 +40,13 @@ use crate::{
 use super::{CxLayerOrder, gpu_composite_supported, gpu_sprite_supported};
 use super::{
     CxScreen,
-    draw::{self, LayerContentsMap},
+    draw::{self, LayerContentsMap, preregister_filter_layer, resolve_filter_layers},
     pipeline::{CxPipeline, CxRenderBuffer, CxUniformBuffer},
 };
 
 static EXACT_SCREENSHOT_WRITTEN: AtomicBool = AtomicBool::new(false);
 const EXACT_SCREENSHOT_ENV: &str = "CARAPACE_EXACT_SCREENSHOT_PATH";
 
-/// Resolves filter layer targets against a pre-built ordered layer index.
-///
-/// Uses binary search (`partition_point`) for `Range` variants, giving `O(log L + K)` cost
-/// instead of the previous `O(L)` full-scan approach. The `ordered_layers` slice must be
-/// sorted (which it is, since it comes from `BTreeMap::keys`).
-fn resolve_filter_layers<L: CxLayer>(
-    out: &mut Vec<(L, bool)>,
-    layers: &CxFilterLayers<L>,
-    ordered_layers: &[L],
-) {
-    out.clear();
-    match layers {
-        CxFilterLayers::Single { layer, clip } => out.push((layer.clone(), *clip)),
-        CxFilterLayers::Range(range) => {
-            let start = ordered_layers.partition_point(|l| l < range.start());
-            let end = start + ordered_layers[start..].partition_point(|l| l <= range.end());
-            out.extend(
-                ordered_layers[start..end]
-                    .iter()
-                    .map(|layer| (layer.clone(), true)),
-            );
-        }
-        CxFilterLayers::Many(layers) => {
-            out.extend(layers.iter().map(|layer| (layer.clone(), true)));
-        }
-    }
-}
-
-/// Pre-registers layers referenced by `Single` and `Many` filter targets so they are present
-/// in the layer map before the ordered index is built. `Range` targets are not pre-registered
-/// because they resolve against the discovered set.
-fn preregister_filter_layer<L: CxLayer>(
-    layers: &CxFilterLayers<L>,
-    layer_contents: &mut LayerContentsMap<'_, L>,
-) {
-    match layers {
-        CxFilterLayers::Single { layer, .. } => {
-            layer_contents.entry(layer.clone()).or_default();
-        }
-        CxFilterLayers::Many(ls) => {
-            for l in ls {
-                layer_contents.entry(l.clone()).or_default();
-            }
-        }
-        CxFilterLayers::Range(_) => {}
-    }
-}
-
 pub struct CxRenderNode<L: CxLayer> {
     maps: QueryState<MapComponents<L>>,
     tiles: QueryState<TileComponents>,
@@ -5,10 +5,16 @@
 //! use a [`CxStack`] with a white [`CxFilterRect`] background and a [`CxTextField`], and add an observer
 //! on [`CxFilterRect`] that sets [`InputFocus`] to the text field.
 //!
+//! For D-pad or keyboard driven UI, mark selectable nodes [`CxFocusable`] and write
+//! [`CxNavigate`] messages from your input. Focus then moves between nodes by their laid-out
+//! position, [`CxFocusFilter`] shows which one is focused, [`CxActivate`] fires on the focused node,
+//! and a focused node inside a [`CxScroll`] is scrolled into view.
+//!
 //! For more information, browse this module and see the `ui` example.
 
 mod input;
 mod layout;
+mod navigation;
 mod widgets;
 
 use bevy_ecs::schedule::common_conditions::any_with_component;
@@ -22,12 +28,15 @@ use bevy_input_focus::InputFocus;
 use crate::{prelude::*, set::CxSet};
 
 pub use input::{CxCaret, CxKeyField, CxKeyFieldUpdate, CxTextField, CxTextFieldUpdate};
+pub use navigation::{CxActivate, CxFocusFilter, CxFocusable, CxNavigate};
 pub use widgets::{
-    CxGrid, CxGridRow, CxGridTracks, CxMargin, CxMinSize, CxRow, CxRowSlot, CxScroll, CxStack,
-    CxUiRoot,
+    CxGrid, CxGridRow, CxGridTracks, CxLayoutRect, CxMargin, CxMinSize, CxRow, CxRowSlot, CxScroll,
+    CxStack, CxUiRoot,
 };
 
 pub fn plug<L: CxLayer>(app: &mut App) {
+    app.add_message::<CxNavigate>();
+
     #[cfg(feature = "headed")]
     app.add_systems(
         PreUpdate,
@@ -49,6 +58,19 @@ pub fn plug<L: CxLayer>(app: &mut App) {
             input::update_text_field_focus.before(input::caret_blink),
         )
             .run_if(resource_exists::<InputFocus>),
+    )
+    .add_systems(
+        PostUpdate,
+        (
+            navigation::navigate_focus.run_if(on_message::<CxNavigate>),
+            navigation::update_focus_filters,
+            navigation::scroll_to_focus.run_if(resource_exists_and_changed::<InputFocus>),
+        )
+            .chain()
+            .before(input::update_key_field_focus)
+            .before(input::update_text_field_focus)
+            .before(layout::layout::<L>)
+            .run_if(resource_exists::<InputFocus>),
     );
     app.add_systems(
         PostUpdate,
@@ -6,7 +6,10 @@ use crate::{
     screen::CxScreen,
 };
 
-use super::widgets::{CxGrid, CxMargin, CxMinSize, CxRow, CxRowSlot, CxScroll, CxStack};
+use super::{
+    navigation::CxFocusable,
+    widgets::{CxGrid, CxLayoutRect, CxMargin, CxMinSize, CxRow, CxRowSlot, CxScroll, CxStack},
+};
 
 // If layouting ends up being too slow, make a tree of min sizes up front and lookup in that
 fn calc_min_size<L: CxLayer>(
@@ -235,6 +238,7 @@ fn layout_inner<L: CxLayer>(
         Option<(&mut CxPosition, &mut CxRenderSpace)>,
     )>,
     row_slots: Query<&CxRowSlot>,
+    mut layout_rects: Query<&mut CxLayoutRect>,
     typefaces: &Assets<CxTypeface>,
     sprites: &Assets<CxSpriteAsset>,
 ) -> Result<Option<L>> {
@@ -251,6 +255,10 @@ fn layout_inner<L: CxLayer>(
         if y { rect.height() } else { rect.width() }
     }
 
+    if let Ok(mut layout_rect) = layout_rects.get_mut(ui) {
+        layout_rect.set_if_neq(CxLayoutRect(target_rect));
+    }
+
     let Ok(((min_size, margin, row, grid, stack, rect, sprite, text), _, _)) = uis.get(ui) else {
         return Ok(None);
     };
@@ -265,6 +273,7 @@ fn layout_inner<L: CxLayer>(
                 content,
                 uis,
                 row_slots,
+                layout_rects,
                 typefaces,
                 sprites,
             ),
@@ -288,6 +297,7 @@ fn layout_inner<L: CxLayer>(
                 content,
                 uis,
                 row_slots,
+                layout_rects,
                 typefaces,
                 sprites,
             ),
@@ -358,6 +368,7 @@ fn layout_inner<L: CxLayer>(
                 child,
                 uis.reborrow(),
                 row_slots.as_readonly(),
+                layout_rects.reborrow(),
                 typefaces,
                 sprites,
             )? {
@@ -465,6 +476,7 @@ fn layout_inner<L: CxLayer>(
                     entry,
                     uis.reborrow(),
                     row_slots.as_readonly(),
+                    layout_rects.reborrow(),
                     typefaces,
                     sprites,
                 )? {
@@ -502,6 +514,7 @@ fn layout_inner<L: CxLayer>(
                 entry,
                 uis.reborrow(),
                 row_slots.as_readonly(),
+                layout_rects.reborrow(),
                 typefaces,
                 sprites,
             )? {
@@ -565,6 +578,11 @@ fn layout_inner<L: CxLayer>(
             *rect_end_mut(&mut view_rect, horz) =
                 add(rect_end(view_rect, horz), -dim(bar_min_size, horz), horz);
 
+            // Scrolls record their viewport so focus navigation can scroll items into it
+            if let Ok(mut layout_rect) = layout_rects.get_mut(ui) {
+                layout_rect.set_if_neq(CxLayoutRect(view_rect));
+            }
+
             let ((_, _, _, _, _, rect, _, _), _, pos) = uis.get_mut(ui).unwrap();
             let (_, mut rect, _) = rect.unwrap();
             **rect = view_rect.size().as_uvec2();
@@ -595,6 +613,7 @@ fn layout_inner<L: CxLayer>(
                 content,
                 uis.reborrow(),
                 row_slots.as_readonly(),
+                layout_rects.reborrow(),
                 typefaces,
                 sprites,
             )?;
@@ -630,6 +649,7 @@ fn layout_inner<L: CxLayer>(
                         bg,
                         uis.reborrow(),
                         row_slots.as_readonly(),
+                        layout_rects.reborrow(),
                         typefaces,
                         sprites,
                     )
@@ -677,6 +697,7 @@ fn layout_inner<L: CxLayer>(
                         bar,
                         uis.reborrow(),
                         row_slots.as_readonly(),
+                        layout_rects.reborrow(),
                         typefaces,
                         sprites,
                     )
@@ -831,6 +852,7 @@ pub fn layout<L: CxLayer>(
         )>,
     )>,
     row_slots: Query<&CxRowSlot>,
+    mut layout_rects: Query<&mut CxLayoutRect>,
     typefaces: Res<Assets<CxTypeface>>,
     sprites: Res<Assets<CxSpriteAsset>>,
     screen: Res<CxScreen>,
@@ -858,6 +880,7 @@ pub fn layout<L: CxLayer>(
             root,
             uis.p1(),
             row_slots.as_readonly(),
+            layout_rects.reborrow(),
             &typefaces,
             &sprites,
         )?;
@@ -880,6 +903,7 @@ pub fn layout_needs_recompute(
             Changed<CxGrid>,
             Changed<CxStack>,
             Changed<CxRowSlot>,
+            Changed<CxFocusable>,
         )>,
     >,
     changed_content: Query<(), Or<(Changed<CxScroll>, Changed<CxSprite>, Changed<CxText>)>>,
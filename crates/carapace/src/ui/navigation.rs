@@ -0,0 +1,473 @@
+//! Directional focus navigation for D-pad and keyboard driven UI.
+//!
+//! `carapace` doesn't read any buttons for this; write [`CxNavigate`] messages from whatever input
+//! the app uses. Focus moves between [`CxFocusable`] nodes by their computed [`CxLayoutRect`]s,
+//! and focused nodes inside a [`CxScroll`] are scrolled into view.
+
+use std::mem;
+
+#[cfg(feature = "headed")]
+use bevy_camera::visibility::InheritedVisibility;
+#[cfg(feature = "headed")]
+use bevy_input_focus::InputFocus;
+
+use crate::{math::Orthogonal, prelude::*};
+
+use super::widgets::{CxLayoutRect, CxScroll};
+
+/// Marks a UI node as a target for directional focus navigation.
+#[derive(Component, Default, Clone, Copy, Reflect)]
+#[require(CxLayoutRect)]
+pub struct CxFocusable;
+
+/// Navigation request, written by the app from its own input mapping.
+#[derive(Message, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CxNavigate {
+    /// Move focus to the nearest visible [`CxFocusable`] in this direction. Focuses the top-left
+    /// focusable if nothing focusable is focused yet.
+    Move(Orthogonal),
+    /// Trigger [`CxActivate`] on the focused [`CxFocusable`].
+    Activate,
+}
+
+/// Triggered on the focused [`CxFocusable`] when a [`CxNavigate::Activate`] is read.
+#[derive(EntityEvent)]
+pub struct CxActivate {
+    /// Activated entity.
+    pub entity: Entity,
+}
+
+/// Swaps the entity's [`CxFilter`] while it is focused, to show which item has focus.
+#[derive(Component, Clone, Debug)]
+#[require(CxFilter)]
+pub struct CxFocusFilter {
+    /// Filter applied while focused.
+    pub filter: Handle<CxFilterAsset>,
+    unfocused: Option<Handle<CxFilterAsset>>,
+}
+
+impl CxFocusFilter {
+    /// Applies `filter` while the entity is focused.
+    #[must_use]
+    pub const fn new(filter: Handle<CxFilterAsset>) -> Self {
+        Self {
+            filter,
+            unfocused: None,
+        }
+    }
+}
+
+/// Best neighbour of `from` in `direction`. Candidates must lie past `from`'s center; nearer edges
+/// win, and candidates that don't overlap `from` on the cross axis are penalized.
+#[cfg(feature = "headed")]
+fn neighbour(
+    from: IRect,
+    direction: Orthogonal,
+    candidates: impl IntoIterator<Item = (Entity, IRect)>,
+) -> Option<Entity> {
+    // (main axis is y, main axis points toward positive)
+    let (vertical, positive) = match direction {
+        Orthogonal::Right => (false, true),
+        Orthogonal::Up => (true, true),
+        Orthogonal::Left => (false, false),
+        Orthogonal::Down => (true, false),
+    };
+
+    let axis = |vec: IVec2, y: bool| if y { vec.y } else { vec.x };
+    // Doubled centers keep the math in integers
+    let center2 = |rect: IRect, y: bool| axis(rect.min, y) + axis(rect.max, y);
+
+    candidates
+        .into_iter()
+        .filter_map(|(entity, rect)| {
+            let ahead = center2(rect, vertical) - center2(from, vertical);
+            let ahead = if positive { ahead } else { -ahead };
+            if ahead <= 0 {
+                return None;
+            }
+
+            let gap = if positive {
+                axis(rect.min, vertical) - axis(from.max, vertical)
+            } else {
+                axis(from.min, vertical) - axis(rect.max, vertical)
+            }
+            .max(0);
+            let cross_gap = (axis(rect.min, !vertical) - axis(from.max, !vertical))
+                .max(axis(from.min, !vertical) - axis(rect.max, !vertical))
+                .max(0);
+            let cross_offset = (center2(rect, !vertical) - center2(from, !vertical)).abs();
+
+            Some((entity, (gap + 2 * cross_gap, cross_offset)))
+        })
+        .min_by_key(|&(_, score)| score)
+        .map(|(entity, _)| entity)
+}
+
+/// Focusable nearest the top-left of the screen.
+#[cfg(feature = "headed")]
+fn first_focusable(candidates: impl IntoIterator<Item = (Entity, IRect)>) -> Option<Entity> {
+    candidates
+        .into_iter()
+        .min_by_key(|&(_, rect)| (-rect.max.y, rect.min.x))
+        .map(|(entity, _)| entity)
+}
+
+/// Scroll offset that brings `target` into `view`, preferring to show its start if it doesn't fit.
+#[cfg(feature = "headed")]
+fn scroll_to_reveal(scroll: CxScroll, view: IRect, target: IRect) -> u32 {
+    let current = scroll.scroll as i32;
+
+    // Scrolling moves content left/up, so the offset grows toward the right/bottom
+    let next = if scroll.horizontal {
+        if target.min.x < view.min.x {
+            current - (view.min.x - target.min.x)
+        } else if target.max.x > view.max.x {
+            current + (target.max.x - view.max.x)
+        } else {
+            current
+        }
+    } else if target.max.y > view.max.y {
+        current - (target.max.y - view.max.y)
+    } else if target.min.y < view.min.y {
+        current + (view.min.y - target.min.y)
+    } else {
+        current
+    };
+
+    (next.max(0) as u32).min(scroll.max_scroll)
+}
+
+#[cfg(feature = "headed")]
+pub fn navigate_focus(
+    mut navigation: MessageReader<CxNavigate>,
+    mut focus: ResMut<InputFocus>,
+    focusables: Query<(Entity, &CxLayoutRect, Option<&InheritedVisibility>), With<CxFocusable>>,
+    mut cmd: Commands,
+) {
+    for &navigate in navigation.read() {
+        let current = focus
+            .get()
+            .and_then(|entity| focusables.get(entity).ok())
+            .map(|(entity, rect, _)| (entity, **rect));
+
+        match navigate {
+            CxNavigate::Move(direction) => {
+                let candidates = focusables
+                    .iter()
+                    .filter(|&(entity, rect, visibility)| {
+                        current.is_none_or(|(current, _)| current != entity)
+                            && !rect.is_empty()
+                            && visibility.is_none_or(|visibility| visibility.get())
+                    })
+                    .map(|(entity, rect, _)| (entity, **rect));
+
+                let next = match current {
+                    Some((_, from)) => neighbour(from, direction, candidates),
+                    None => first_focusable(candidates),
+                };
+
+                if let Some(next) = next {
+                    focus.set(next);
+                }
+            }
+            CxNavigate::Activate => {
+                if let Some((entity, _)) = current {
+                    cmd.trigger(CxActivate { entity });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "headed")]
+pub fn update_focus_filters(
+    mut prev_focus: Local<Option<Entity>>,
+    mut filters: Query<(&mut CxFocusFilter, &mut CxFilter)>,
+    focus: Res<InputFocus>,
+) {
+    let focus = focus.get();
+
+    if *prev_focus == focus {
+        return;
+    }
+
+    if let Some(prev_focus) = *prev_focus
+        && let Ok((mut focus_filter, mut filter)) = filters.get_mut(prev_focus)
+        && let Some(unfocused) = focus_filter.unfocused.take()
+    {
+        **filter = unfocused;
+    }
+
+    if let Some(focus) = focus
+        && let Ok((mut focus_filter, mut filter)) = filters.get_mut(focus)
+    {
+        let focused = focus_filter.filter.clone();
+        focus_filter.unfocused = Some(mem::replace(&mut **filter, focused));
+    }
+
+    *prev_focus = focus;
+}
+
+#[cfg(feature = "headed")]
+pub fn scroll_to_focus(
+    focus: Res<InputFocus>,
+    rects: Query<&CxLayoutRect, With<CxFocusable>>,
+    parents: Query<&ChildOf>,
+    mut scrolls: Query<(&mut CxScroll, &CxLayoutRect)>,
+) {
+    let Some(focus) = focus.get() else {
+        return;
+    };
+
+    let Ok(rect) = rects.get(focus) else {
+        return;
+    };
+
+    // Not laid out yet
+    if rect.is_empty() {
+        return;
+    }
+
+    let Some((mut scroll, view)) = parents
+        .iter_ancestors(focus)
+        .find_map(|ancestor| scrolls.get_mut(ancestor).ok())
+    else {
+        return;
+    };
+
+    let next = scroll_to_reveal(*scroll, **view, **rect);
+    if scroll.scroll != next {
+        scroll.scroll = next;
+    }
+}
+
+#[cfg(all(test, feature = "headed"))]
+mod tests {
+    use bevy_asset::uuid_handle;
+    use bevy_ecs::{message::Messages, schedule::Schedule};
+
+    use super::*;
+
+    fn entity(index: u32) -> Entity {
+        Entity::from_raw_u32(index).unwrap()
+    }
+
+    fn rect(x: i32, y: i32, width: i32, height: i32) -> IRect {
+        IRect::new(x, y, x + width, y + height)
+    }
+
+    // Three buttons in a row at the top, one wide button below them
+    fn menu() -> Vec<(Entity, IRect)> {
+        vec![
+            (entity(1), rect(0, 20, 10, 10)),
+            (entity(2), rect(12, 20, 10, 10)),
+            (entity(3), rect(24, 20, 10, 10)),
+            (entity(4), rect(0, 0, 34, 10)),
+        ]
+    }
+
+    #[test]
+    fn neighbour_picks_nearest_in_direction() {
+        let menu = menu();
+        let from = menu[1].1;
+        let others = menu.iter().copied().filter(|&(id, _)| id != entity(2));
+
+        assert_eq!(
+            neighbour(from, Orthogonal::Right, others.clone()),
+            Some(entity(3))
+        );
+        assert_eq!(
+            neighbour(from, Orthogonal::Left, others.clone()),
+            Some(entity(1))
+        );
+        assert_eq!(
+            neighbour(from, Orthogonal::Down, others.clone()),
+            Some(entity(4))
+        );
+        assert_eq!(neighbour(from, Orthogonal::Up, others), None);
+    }
+
+    #[test]
+    fn neighbour_prefers_cross_axis_alignment() {
+        let from = rect(24, 0, 10, 10);
+        let candidates = menu().into_iter().take(3);
+
+        assert_eq!(neighbour(from, Orthogonal::Up, candidates), Some(entity(3)));
+    }
+
+    #[test]
+    fn neighbour_penalizes_items_off_the_cross_axis() {
+        let from = rect(0, 0, 10, 10);
+        let candidates = [
+            // Closer, but far off to the side
+            (entity(1), rect(12, 40, 10, 10)),
+            // Further, but straight to the right
+            (entity(2), rect(30, 0, 10, 10)),
+        ];
+
+        assert_eq!(
+            neighbour(from, Orthogonal::Right, candidates),
+            Some(entity(2))
+        );
+    }
+
+    #[test]
+    fn first_focusable_is_top_left() {
+        assert_eq!(first_focusable(menu()), Some(entity(1)));
+        assert_eq!(first_focusable([]), None);
+    }
+
+    #[test]
+    fn scroll_to_reveal_scrolls_vertically() {
+        let scroll = CxScroll {
+            horizontal: false,
+            scroll: 10,
+            max_scroll: 40,
+        };
+        let view = rect(0, 0, 20, 20);
+
+        // Already visible
+        assert_eq!(scroll_to_reveal(scroll, view, rect(0, 5, 20, 5)), 10);
+        // Below the view
+        assert_eq!(scroll_to_reveal(scroll, view, rect(0, -8, 20, 5)), 18);
+        // Above the view
+        assert_eq!(scroll_to_reveal(scroll, view, rect(0, 22, 20, 5)), 3);
+        // Clamped
+        assert_eq!(scroll_to_reveal(scroll, view, rect(0, -100, 20, 5)), 40);
+        assert_eq!(scroll_to_reveal(scroll, view, rect(0, 100, 20, 5)), 0);
+    }
+
+    #[test]
+    fn scroll_to_reveal_scrolls_horizontally() {
+        let scroll = CxScroll {
+            horizontal: true,
+            scroll: 10,
+            max_scroll: 40,
+        };
+        let view = rect(0, 0, 20, 20);
+
+        assert_eq!(scroll_to_reveal(scroll, view, rect(25, 0, 5, 20)), 20);
+        assert_eq!(scroll_to_reveal(scroll, view, rect(-4, 0, 5, 20)), 6);
+    }
+
+    #[derive(Resource, Default)]
+    struct Activated(Vec<Entity>);
+
+    fn test_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<InputFocus>();
+        world.init_resource::<Messages<CxNavigate>>();
+        world.init_resource::<Activated>();
+        // `CxScroll` requires `DefaultLayer` and `DefaultCxFilterLayers`; no-ops keep setup minimal.
+        world.insert_resource(crate::position::InsertDefaultLayer::noop());
+        world.insert_resource(crate::filter::InsertDefaultCxFilterLayers::noop());
+        world.add_observer(
+            |activate: On<CxActivate>, mut activated: ResMut<Activated>| {
+                activated.0.push(activate.entity);
+            },
+        );
+        world
+    }
+
+    fn navigate(world: &mut World, schedule: &mut Schedule, request: CxNavigate) {
+        world.resource_mut::<Messages<CxNavigate>>().write(request);
+        schedule.run(world);
+    }
+
+    #[test]
+    fn navigation_moves_focus_and_activates() {
+        let mut world = test_world();
+        let left = world
+            .spawn((CxFocusable, CxLayoutRect(rect(0, 0, 10, 10))))
+            .id();
+        let right = world
+            .spawn((CxFocusable, CxLayoutRect(rect(12, 0, 10, 10))))
+            .id();
+        let hidden = world
+            .spawn((
+                CxFocusable,
+                CxLayoutRect(rect(24, 0, 10, 10)),
+                InheritedVisibility::HIDDEN,
+            ))
+            .id();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(navigate_focus);
+
+        navigate(
+            &mut world,
+            &mut schedule,
+            CxNavigate::Move(Orthogonal::Right),
+        );
+        assert_eq!(world.resource::<InputFocus>().get(), Some(left));
+
+        navigate(
+            &mut world,
+            &mut schedule,
+            CxNavigate::Move(Orthogonal::Right),
+        );
+        assert_eq!(world.resource::<InputFocus>().get(), Some(right));
+
+        // The hidden item is skipped and focus stays put
+        navigate(
+            &mut world,
+            &mut schedule,
+            CxNavigate::Move(Orthogonal::Right),
+        );
+        assert_eq!(world.resource::<InputFocus>().get(), Some(right));
+
+        navigate(&mut world, &mut schedule, CxNavigate::Activate);
+        assert_eq!(world.resource::<Activated>().0, vec![right]);
+        assert_ne!(world.resource::<InputFocus>().get(), Some(hidden));
+    }
+
+    #[test]
+    fn focus_filter_is_swapped_while_focused() {
+        let mut world = test_world();
+        let focused_filter: Handle<CxFilterAsset> =
+            uuid_handle!("5A3E6C1D-2B7F-4E8A-9C0D-1F2E3D4C5B6A");
+        let item = world
+            .spawn((CxFocusable, CxFocusFilter::new(focused_filter.clone())))
+            .id();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(update_focus_filters);
+
+        world.resource_mut::<InputFocus>().set(item);
+        schedule.run(&mut world);
+        assert_eq!(world.get::<CxFilter>(item).unwrap().0, focused_filter);
+
+        world.resource_mut::<InputFocus>().clear();
+        schedule.run(&mut world);
+        assert_eq!(world.get::<CxFilter>(item).unwrap().0, Handle::default());
+    }
+
+    #[test]
+    fn focus_scrolls_into_view() {
+        let mut world = test_world();
+        let scroll = world
+            .spawn((
+                CxScroll {
+                    horizontal: false,
+                    scroll: 0,
+                    max_scroll: 50,
+                },
+                CxLayoutRect(rect(0, 0, 20, 20)),
+            ))
+            .id();
+        let item = world
+            .spawn((
+                CxFocusable,
+                CxLayoutRect(rect(0, -30, 20, 10)),
+                ChildOf(scroll),
+            ))
+            .id();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(scroll_to_focus);
+
+        world.resource_mut::<InputFocus>().set(item);
+        schedule.run(&mut world);
+        assert_eq!(world.get::<CxScroll>(scroll).unwrap().scroll, 30);
+    }
+}
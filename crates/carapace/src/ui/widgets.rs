@@ -88,7 +88,7 @@ pub struct CxStack;
 
 /// Scroll container that masks and offsets child content.
 #[derive(Component, Default, Clone, Copy, Reflect)]
-#[require(CxInvertMask, crate::rect::CxFilterRect)]
+#[require(CxInvertMask, crate::rect::CxFilterRect, CxLayoutRect)]
 pub struct CxScroll {
     /// If true, scroll horizontally; otherwise vertically.
     pub horizontal: bool,
@@ -97,3 +97,10 @@ pub struct CxScroll {
     /// Maximum scroll offset in pixels.
     pub max_scroll: u32,
 }
+
+/// Screen rect assigned to a UI node by the last layout pass (Y-up, exclusive max).
+///
+/// Only nodes carrying this component have their rect recorded. For [`CxScroll`] this is the
+/// visible viewport, excluding the scroll bar.
+#[derive(Component, Deref, DerefMut, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CxLayoutRect(pub IRect);
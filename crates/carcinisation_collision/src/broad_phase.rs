@@ -0,0 +1,381 @@
+//! Uniform-grid broad phase keyed by world bounds and depth lane.
+//!
+//! The grid only answers "which entries might touch this rect"; callers still
+//! run the exact narrow phase (`ColliderShape::overlaps`, `world_mask_overlap`,
+//! ...) on the returned candidates. Entries are refreshed incrementally: an
+//! update that stays within the same cell span only rewrites the stored
+//! bounds, and entries that were not touched during a frame are pruned by
+//! [`BroadPhaseGrid::end_frame`].
+
+use bevy::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::ops::RangeInclusive;
+
+/// Default cell edge in world pixels. Roughly one mid-depth enemy sprite.
+pub const DEFAULT_BROAD_PHASE_CELL_SIZE: i32 = 32;
+
+/// Bounds and lane stored for one broad-phase entry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BroadPhaseEntry {
+    /// Exclusive world rect in gameplay coordinates (Y-up), same convention
+    /// as `WorldMaskRect::rect`.
+    pub bounds: IRect,
+    /// Depth lane. Only entries in the queried lane range are returned.
+    pub lane: i8,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct TrackedEntry {
+    entry: BroadPhaseEntry,
+    /// Inclusive cell span, `None` for empty bounds.
+    cells: Option<(IVec2, IVec2)>,
+    generation: u32,
+}
+
+/// Spatial hash over fixed-size square cells, one cell map per depth lane.
+#[derive(Clone, Debug)]
+pub struct BroadPhaseGrid<K> {
+    cell_size: i32,
+    cells: HashMap<(i8, IVec2), Vec<K>>,
+    entries: HashMap<K, TrackedEntry>,
+    /// Number of cell-occupying entries per lane, so lane-range queries skip
+    /// lanes with nothing in them.
+    lanes: BTreeMap<i8, usize>,
+    generation: u32,
+}
+
+impl<K> Default for BroadPhaseGrid<K> {
+    fn default() -> Self {
+        Self::new(DEFAULT_BROAD_PHASE_CELL_SIZE)
+    }
+}
+
+impl<K> BroadPhaseGrid<K> {
+    /// # Panics
+    ///
+    /// Panics if `cell_size` is not positive.
+    #[must_use]
+    pub fn new(cell_size: i32) -> Self {
+        assert!(cell_size > 0, "broad-phase cell size must be positive");
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+            entries: HashMap::new(),
+            lanes: BTreeMap::new(),
+            generation: 0,
+        }
+    }
+
+    #[must_use]
+    pub const fn cell_size(&self) -> i32 {
+        self.cell_size
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.entries.clear();
+        self.lanes.clear();
+    }
+
+    /// Starts a new refresh pass. Entries not [`update`](Self::update)d
+    /// before the matching [`end_frame`](Self::end_frame) are removed.
+    pub fn begin_frame(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    /// Inclusive cell span covering `bounds`, or `None` when empty.
+    fn cell_span(&self, bounds: IRect) -> Option<(IVec2, IVec2)> {
+        if bounds.is_empty() {
+            return None;
+        }
+        let min = IVec2::new(
+            bounds.min.x.div_euclid(self.cell_size),
+            bounds.min.y.div_euclid(self.cell_size),
+        );
+        let max = IVec2::new(
+            (bounds.max.x - 1).div_euclid(self.cell_size),
+            (bounds.max.y - 1).div_euclid(self.cell_size),
+        );
+        Some((min, max))
+    }
+
+    fn cell_of(&self, point: IVec2) -> IVec2 {
+        IVec2::new(
+            point.x.div_euclid(self.cell_size),
+            point.y.div_euclid(self.cell_size),
+        )
+    }
+}
+
+impl<K: Copy + Eq + Hash> BroadPhaseGrid<K> {
+    #[must_use]
+    pub fn get(&self, key: &K) -> Option<BroadPhaseEntry> {
+        self.entries.get(key).map(|tracked| tracked.entry)
+    }
+
+    /// Inserts or refreshes `key`. Returns `true` when the entry was inserted
+    /// or moved to different cells, `false` when only its bounds changed.
+    pub fn update(&mut self, key: K, bounds: IRect, lane: i8) -> bool {
+        let entry = BroadPhaseEntry { bounds, lane };
+        let cells = self.cell_span(bounds);
+        let generation = self.generation;
+
+        if let Some(tracked) = self.entries.get_mut(&key)
+            && tracked.entry.lane == lane
+            && tracked.cells == cells
+        {
+            tracked.entry = entry;
+            tracked.generation = generation;
+            return false;
+        }
+
+        if let Some(previous) = self.entries.remove(&key) {
+            self.unlink(key, previous);
+        }
+        let tracked = TrackedEntry {
+            entry,
+            cells,
+            generation,
+        };
+        self.link(key, tracked);
+        self.entries.insert(key, tracked);
+        true
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<BroadPhaseEntry> {
+        let tracked = self.entries.remove(key)?;
+        self.unlink(*key, tracked);
+        Some(tracked.entry)
+    }
+
+    /// Removes every entry not refreshed since [`begin_frame`](Self::begin_frame).
+    /// Returns the number of removed entries.
+    pub fn end_frame(&mut self) -> usize {
+        let generation = self.generation;
+        let stale: Vec<K> = self
+            .entries
+            .iter()
+            .filter(|(_, tracked)| tracked.generation != generation)
+            .map(|(key, _)| *key)
+            .collect();
+        for key in &stale {
+            self.remove(key);
+        }
+        stale.len()
+    }
+
+    /// Calls `visit` once for every entry in `lanes` whose bounds intersect
+    /// `bounds`.
+    pub fn for_each_overlapping(
+        &self,
+        bounds: IRect,
+        lanes: RangeInclusive<i8>,
+        mut visit: impl FnMut(K),
+    ) {
+        let Some((min, max)) = self.cell_span(bounds) else {
+            return;
+        };
+        for (&lane, _) in self.lanes.range(lanes) {
+            for y in min.y..=max.y {
+                for x in min.x..=max.x {
+                    let cell = IVec2::new(x, y);
+                    let Some(keys) = self.cells.get(&(lane, cell)) else {
+                        continue;
+                    };
+                    for key in keys {
+                        let entry = &self.entries[key].entry;
+                        let overlap = entry.bounds.intersect(bounds);
+                        // An entry spanning several cells is reported only from
+                        // the cell holding the overlap's min corner.
+                        if !overlap.is_empty() && self.cell_of(overlap.min) == cell {
+                            visit(*key);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Replaces the contents of `out` with the candidates for `bounds`.
+    pub fn query_into(&self, bounds: IRect, lanes: RangeInclusive<i8>, out: &mut Vec<K>) {
+        out.clear();
+        self.for_each_overlapping(bounds, lanes, |key| out.push(key));
+    }
+
+    fn link(&mut self, key: K, tracked: TrackedEntry) {
+        let Some((min, max)) = tracked.cells else {
+            return;
+        };
+        let lane = tracked.entry.lane;
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                self.cells
+                    .entry((lane, IVec2::new(x, y)))
+                    .or_default()
+                    .push(key);
+            }
+        }
+        *self.lanes.entry(lane).or_default() += 1;
+    }
+
+    fn unlink(&mut self, key: K, tracked: TrackedEntry) {
+        let Some((min, max)) = tracked.cells else {
+            return;
+        };
+        let lane = tracked.entry.lane;
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let cell = (lane, IVec2::new(x, y));
+                let Some(keys) = self.cells.get_mut(&cell) else {
+                    continue;
+                };
+                if let Some(index) = keys.iter().position(|existing| *existing == key) {
+                    keys.swap_remove(index);
+                }
+                if keys.is_empty() {
+                    self.cells.remove(&cell);
+                }
+            }
+        }
+        if let Some(count) = self.lanes.get_mut(&lane) {
+            *count -= 1;
+            if *count == 0 {
+                self.lanes.remove(&lane);
+            }
+        }
+    }
+}
+
+/// Conservative exclusive integer bounds for an axis-aligned box given by its
+/// float center and half extents.
+#[must_use]
+pub fn bounds_from_center(center: Vec2, half_extents: Vec2) -> IRect {
+    let min = (center - half_extents).floor().as_ivec2();
+    let max = (center + half_extents).floor().as_ivec2() + IVec2::ONE;
+    IRect { min, max }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_LANES: RangeInclusive<i8> = i8::MIN..=i8::MAX;
+
+    fn rect(min: (i32, i32), max: (i32, i32)) -> IRect {
+        IRect::from_corners(IVec2::new(min.0, min.1), IVec2::new(max.0, max.1))
+    }
+
+    fn query(grid: &BroadPhaseGrid<u32>, bounds: IRect, lanes: RangeInclusive<i8>) -> Vec<u32> {
+        let mut out = Vec::new();
+        grid.query_into(bounds, lanes, &mut out);
+        out.sort_unstable();
+        out
+    }
+
+    #[test]
+    fn query_returns_overlapping_entries_once() {
+        let mut grid = BroadPhaseGrid::new(8);
+        // Spans four cells; must still be reported a single time.
+        grid.update(1, rect((4, 4), (20, 20)), 0);
+        grid.update(2, rect((40, 40), (48, 48)), 0);
+
+        assert_eq!(query(&grid, rect((0, 0), (64, 64)), ALL_LANES), vec![1, 2]);
+        assert_eq!(query(&grid, rect((10, 10), (12, 12)), ALL_LANES), vec![1]);
+        assert!(query(&grid, rect((24, 24), (32, 32)), ALL_LANES).is_empty());
+    }
+
+    #[test]
+    fn exclusive_max_does_not_touch_neighbour() {
+        let mut grid = BroadPhaseGrid::new(8);
+        grid.update(1, rect((0, 0), (8, 8)), 0);
+
+        assert!(query(&grid, rect((8, 0), (9, 8)), ALL_LANES).is_empty());
+        assert_eq!(query(&grid, rect((7, 7), (8, 8)), ALL_LANES), vec![1]);
+    }
+
+    #[test]
+    fn negative_coordinates_are_bucketed() {
+        let mut grid = BroadPhaseGrid::new(8);
+        grid.update(1, rect((-12, -3), (-9, 2)), 0);
+
+        assert_eq!(query(&grid, rect((-10, 0), (-9, 1)), ALL_LANES), vec![1]);
+        assert!(query(&grid, rect((-8, -8), (0, 0)), ALL_LANES).is_empty());
+    }
+
+    #[test]
+    fn lanes_filter_candidates() {
+        let mut grid = BroadPhaseGrid::new(8);
+        grid.update(1, rect((0, 0), (4, 4)), 1);
+        grid.update(2, rect((0, 0), (4, 4)), 3);
+        grid.update(3, rect((0, 0), (4, 4)), 6);
+
+        let bounds = rect((0, 0), (4, 4));
+        assert_eq!(query(&grid, bounds, 1..=3), vec![1, 2]);
+        assert_eq!(query(&grid, bounds, 6..=6), vec![3]);
+        assert_eq!(query(&grid, bounds, ALL_LANES), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn update_moves_entry_between_cells() {
+        let mut grid = BroadPhaseGrid::new(8);
+        assert!(grid.update(1, rect((0, 0), (4, 4)), 0));
+        // Same cell span: bounds refresh only.
+        assert!(!grid.update(1, rect((1, 1), (5, 5)), 0));
+        assert!(grid.update(1, rect((30, 30), (34, 34)), 0));
+
+        assert!(query(&grid, rect((0, 0), (8, 8)), ALL_LANES).is_empty());
+        assert_eq!(query(&grid, rect((30, 30), (31, 31)), ALL_LANES), vec![1]);
+
+        assert!(grid.update(1, rect((30, 30), (34, 34)), 2));
+        assert!(query(&grid, rect((30, 30), (31, 31)), 0..=0).is_empty());
+        assert_eq!(query(&grid, rect((30, 30), (31, 31)), 2..=2), vec![1]);
+    }
+
+    #[test]
+    fn end_frame_prunes_entries_not_refreshed() {
+        let mut grid = BroadPhaseGrid::new(8);
+        grid.begin_frame();
+        grid.update(1, rect((0, 0), (4, 4)), 0);
+        grid.update(2, rect((0, 0), (4, 4)), 0);
+        assert_eq!(grid.end_frame(), 0);
+
+        grid.begin_frame();
+        grid.update(1, rect((0, 0), (4, 4)), 0);
+        assert_eq!(grid.end_frame(), 1);
+
+        assert_eq!(grid.len(), 1);
+        assert_eq!(query(&grid, rect((0, 0), (4, 4)), ALL_LANES), vec![1]);
+        assert!(grid.cells.values().all(|keys| !keys.contains(&2)));
+    }
+
+    #[test]
+    fn empty_bounds_are_tracked_but_never_returned() {
+        let mut grid = BroadPhaseGrid::new(8);
+        grid.update(1, rect((4, 4), (4, 4)), 0);
+
+        assert_eq!(grid.len(), 1);
+        assert!(query(&grid, rect((0, 0), (8, 8)), ALL_LANES).is_empty());
+        assert_eq!(grid.remove(&1).map(|entry| entry.lane), Some(0));
+        assert!(grid.lanes.is_empty());
+    }
+
+    #[test]
+    fn bounds_from_center_covers_float_extent() {
+        let bounds = bounds_from_center(Vec2::new(10.5, -2.25), Vec2::new(2.0, 0.5));
+        assert_eq!(bounds, rect((8, -3), (13, -1)));
+
+        let point = bounds_from_center(Vec2::new(3.2, 4.9), Vec2::ZERO);
+        assert_eq!(point, rect((3, 4), (4, 5)));
+    }
+}
@@ -1,10 +1,15 @@
-//! Collision detection primitives and optional pixel-mask support.
+//! Collision detection primitives, a grid broad phase and optional pixel-mask
+//! support.
 
+pub mod broad_phase;
 pub mod shapes;
 
 #[cfg(feature = "pixel-mask")]
 pub mod pixel_mask;
 
+pub use broad_phase::{
+    BroadPhaseEntry, BroadPhaseGrid, DEFAULT_BROAD_PHASE_CELL_SIZE, bounds_from_center,
+};
 pub use shapes::{Collider, ColliderData, ColliderShape};
 
 #[cfg(feature = "pixel-mask")]
@@ -140,8 +140,19 @@ impl SpritePixelData {
             return None;
         }
 
-        let height = frame_size / width;
-        if height == 0 {
+        Self::from_pixels(width as u32, (frame_size / width) as u32, pixels.clone())
+    }
+
+    /// Builds a mask from raw palette indices (0 = transparent), stored
+    /// row-major from the top row with frames stacked vertically.
+    ///
+    /// Returns `None` for empty dimensions or when `pixels` holds no complete
+    /// frame.
+    #[must_use]
+    pub fn from_pixels(width: u32, height: u32, pixels: Vec<u8>) -> Option<Self> {
+        let (width, height) = (width as usize, height as usize);
+        let frame_size = width * height;
+        if frame_size == 0 {
             return None;
         }
 
@@ -171,7 +182,7 @@ impl SpritePixelData {
             width: width as u32,
             height: height as u32,
             frame_count,
-            pixels: pixels.clone(),
+            pixels,
             segments_per_row,
             row_masks,
         })
@@ -1253,32 +1264,7 @@ mod tests {
             let index = (frame * height as usize + flipped_y) * width as usize + *x as usize;
             pixels[index] = 1;
         }
-        let segments_per_row = width.div_ceil(64) as usize;
-        let mut row_masks = vec![0u64; frames * height as usize * segments_per_row];
-        for frame in 0..frames {
-            for row in 0..height {
-                for x in 0..width {
-                    let index =
-                        (frame * height as usize + row as usize) * width as usize + x as usize;
-                    if pixels[index] == 0 {
-                        continue;
-                    }
-                    let segment = (x / 64) as usize;
-                    let bit = x % 64;
-                    let offset =
-                        (frame * height as usize + row as usize) * segments_per_row + segment;
-                    row_masks[offset] |= 1u64 << bit;
-                }
-            }
-        }
-        SpritePixelData {
-            width,
-            height,
-            frame_count: frames,
-            pixels,
-            segments_per_row,
-            row_masks,
-        }
+        SpritePixelData::from_pixels(width, height, pixels).unwrap()
     }
 
     fn rect_for_mask(min: IVec2, mask: &SpritePixelData) -> IRect {
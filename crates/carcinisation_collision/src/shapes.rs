@@ -37,6 +37,17 @@ impl ColliderShape {
         }
     }
 
+    /// Half extents of the shape's axis-aligned bounding box, for broad-phase
+    /// bounds. `None` for mask shapes, whose extent comes from the sprite.
+    #[must_use]
+    pub const fn half_extents(&self) -> Option<Vec2> {
+        match *self {
+            Self::Box(half) => Some(half),
+            Self::Circle(radius) => Some(Vec2::splat(radius)),
+            Self::SpriteMask | Self::SpriteMaskClosed => None,
+        }
+    }
+
     #[must_use]
     pub fn overlaps(&self, self_position: Vec2, other: &Self, other_position: Vec2) -> bool {
         match (self, other) {
@@ -185,6 +196,19 @@ mod tests {
         assert!(!box_a.overlaps(Vec2::ZERO, &circle_b, Vec2::new(2.2, 0.0)));
     }
 
+    #[test]
+    fn half_extents_bound_primitive_shapes() {
+        assert_eq!(
+            ColliderShape::Box(Vec2::new(2.0, 1.0)).half_extents(),
+            Some(Vec2::new(2.0, 1.0))
+        );
+        assert_eq!(
+            ColliderShape::Circle(1.5).half_extents(),
+            Some(Vec2::splat(1.5))
+        );
+        assert_eq!(ColliderShape::SpriteMask.half_extents(), None);
+    }
+
     #[test]
     fn box_point_collides() {
         let collider = ColliderShape::Box(Vec2::new(2.0, 1.0));
@@ -0,0 +1,471 @@
+//! Audio mixer: buses, per-bus volume, music crossfades, ducking and voice
+//! limits.
+//!
+//! Gameplay code never sets volumes itself. One-shot sounds are spawned with
+//! [`make_sound_bundle`] and music is requested with [`PlayMusic`] /
+//! [`StopMusic`]; the mixer owns the resulting [`AudioVoice`] entities and
+//! writes their volume every frame. Volumes are applied through
+//! `PlaybackSettings` and, when an audio backend created one, the voice's
+//! `AudioSink`, so the mixer also runs headless with no audio output.
+
+use std::time::Duration;
+
+use bevy::{
+    audio::{AudioPlayer, AudioSink, AudioSinkPlayback, AudioSource, PlaybackMode, Volume},
+    platform::collections::HashMap,
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+
+/// Default length of a music crossfade.
+pub const MUSIC_CROSSFADE: Duration = Duration::from_millis(800);
+/// Music level, relative to its bus, while ducked.
+pub const MUSIC_DUCK_LEVEL: f32 = 0.35;
+/// How fast ducking engages and releases, in level units per second.
+pub const MUSIC_DUCK_RATE: f32 = 3.0;
+/// Concurrent voices of the same sound; the oldest is cut beyond this.
+pub const MAX_VOICES_PER_SOUND: usize = 4;
+/// Concurrent voices per non-music bus.
+pub const MAX_VOICES_PER_BUS: usize = 16;
+
+#[derive(
+    Clone, Component, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Reflect, Serialize,
+)]
+/// Mixer channel a voice is routed through.
+pub enum AudioBus {
+    Music,
+    #[default]
+    Sfx,
+    Ui,
+    Voice,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Resource, Serialize)]
+#[serde(default)]
+/// Linear volume per bus. Persisted by the app between runs.
+pub struct AudioSettings {
+    pub master: f32,
+    pub music: f32,
+    pub sfx: f32,
+    pub ui: f32,
+    pub voice: f32,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            master: 0.8,
+            music: 0.06,
+            sfx: 0.08,
+            ui: 0.08,
+            voice: 0.1,
+        }
+    }
+}
+
+impl AudioSettings {
+    #[must_use]
+    pub const fn with_master_level(self, master: f32) -> Self {
+        Self { master, ..self }
+    }
+
+    /// Effective linear level of `bus`, including master.
+    #[must_use]
+    pub const fn bus_level(&self, bus: AudioBus) -> f32 {
+        let level = match bus {
+            AudioBus::Music => self.music,
+            AudioBus::Sfx => self.sfx,
+            AudioBus::Ui => self.ui,
+            AudioBus::Voice => self.voice,
+        };
+        self.master * level
+    }
+}
+
+#[derive(Clone, Component, Copy, Debug)]
+/// A mixer-managed playing sound.
+pub struct AudioVoice {
+    pub bus: AudioBus,
+    /// Per-voice gain on top of the bus level.
+    pub gain: f32,
+}
+
+impl AudioVoice {
+    #[must_use]
+    pub const fn new(bus: AudioBus) -> Self {
+        Self { bus, gain: 1.0 }
+    }
+}
+
+#[derive(Component, Debug)]
+/// Spawn order of a voice, used to cut the oldest when over a limit.
+struct VoiceOrder(u64);
+
+#[derive(Component, Debug)]
+/// The music track a music voice is playing.
+pub struct MusicTrack {
+    pub path: String,
+}
+
+#[derive(Clone, Component, Copy, Debug)]
+/// Linear fade applied on top of the voice level.
+pub struct AudioFade {
+    pub elapsed: Duration,
+    pub duration: Duration,
+    pub fade_in: bool,
+}
+
+impl AudioFade {
+    #[must_use]
+    pub const fn fade_in(duration: Duration) -> Self {
+        Self {
+            elapsed: Duration::ZERO,
+            duration,
+            fade_in: true,
+        }
+    }
+
+    #[must_use]
+    pub const fn fade_out(duration: Duration) -> Self {
+        Self {
+            elapsed: Duration::ZERO,
+            duration,
+            fade_in: false,
+        }
+    }
+
+    #[must_use]
+    pub fn level(&self) -> f32 {
+        let progress = if self.duration.is_zero() {
+            1.0
+        } else {
+            (self.elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+        };
+        if self.fade_in {
+            progress
+        } else {
+            1.0 - progress
+        }
+    }
+
+    #[must_use]
+    pub fn finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
+#[derive(Component, Debug, Default)]
+/// Ducks music while any entity carries it (e.g. cutscene dialogue). Voice
+/// bus sounds duck music too.
+pub struct DuckMusic;
+
+#[derive(Clone, Debug, Message)]
+/// Switch music to `path`, crossfading from the current track. Requesting the
+/// track that is already playing does nothing.
+pub struct PlayMusic {
+    pub path: String,
+    pub mode: PlaybackMode,
+}
+
+impl PlayMusic {
+    #[must_use]
+    pub fn looped(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            mode: PlaybackMode::Loop,
+        }
+    }
+
+    #[must_use]
+    pub fn once(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            mode: PlaybackMode::Despawn,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Message)]
+/// Fade out the current music track.
+pub struct StopMusic;
+
+#[derive(Debug, Resource)]
+/// Mixer state.
+pub struct AudioMixer {
+    pub crossfade: Duration,
+    /// Current music duck multiplier, eased toward its target.
+    pub music_duck: f32,
+    next_voice: u64,
+}
+
+impl Default for AudioMixer {
+    fn default() -> Self {
+        Self {
+            crossfade: MUSIC_CROSSFADE,
+            music_duck: 1.0,
+            next_voice: 0,
+        }
+    }
+}
+
+/// Build a one-shot sound routed through `bus`. Add an owner marker (e.g. a
+/// stage entity tag) when the sound should stop with its owner.
+#[must_use]
+pub fn make_sound_bundle(
+    asset_server: &AssetServer,
+    path: impl Into<String>,
+    bus: AudioBus,
+) -> (AudioPlayer, PlaybackSettings, AudioVoice) {
+    (
+        AudioPlayer::<AudioSource>(asset_server.load(path.into())),
+        PlaybackSettings {
+            mode: PlaybackMode::Despawn,
+            // Set by the mixer before the backend starts the sink.
+            volume: Volume::SILENT,
+            ..Default::default()
+        },
+        AudioVoice::new(bus),
+    )
+}
+
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+/// Mixer systems; schedule sound spawns before this set to be mixed the same
+/// frame.
+pub struct AudioMixerSystems;
+
+/// Registers the mixer resources, messages and systems.
+pub struct AudioMixerPlugin;
+
+impl Plugin for AudioMixerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AudioSettings>()
+            .init_resource::<AudioMixer>()
+            .add_message::<PlayMusic>()
+            .add_message::<StopMusic>()
+            .add_systems(
+                PostUpdate,
+                (
+                    handle_music_requests,
+                    ApplyDeferred,
+                    limit_voices,
+                    tick_audio_fades,
+                    update_music_duck,
+                    apply_voice_volumes,
+                )
+                    .chain()
+                    .in_set(AudioMixerSystems),
+            );
+    }
+}
+
+/// @system Crossfades to requested music tracks and fades out on stop.
+pub fn handle_music_requests(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mixer: Res<AudioMixer>,
+    mut play_reader: MessageReader<PlayMusic>,
+    mut stop_reader: MessageReader<StopMusic>,
+    music_query: Query<(Entity, &MusicTrack), Without<FadingOut>>,
+) {
+    let stop = stop_reader.read().count() > 0;
+    let Some(request) = play_reader.read().last().cloned() else {
+        if stop {
+            for (entity, _) in &music_query {
+                fade_out(&mut commands, entity, mixer.crossfade);
+            }
+        }
+        return;
+    };
+
+    let mut already_playing = false;
+    for (entity, track) in &music_query {
+        if track.path == request.path && !already_playing {
+            already_playing = true;
+        } else {
+            fade_out(&mut commands, entity, mixer.crossfade);
+        }
+    }
+    if already_playing || request.path.is_empty() {
+        return;
+    }
+
+    commands.spawn((
+        AudioPlayer::<AudioSource>(asset_server.load(request.path.clone())),
+        PlaybackSettings {
+            mode: request.mode,
+            volume: Volume::SILENT,
+            ..Default::default()
+        },
+        AudioVoice::new(AudioBus::Music),
+        AudioFade::fade_in(mixer.crossfade),
+        MusicTrack { path: request.path },
+        Name::new("Music"),
+    ));
+}
+
+#[derive(Component, Debug)]
+/// Music voice on its way out; despawned when its fade completes.
+struct FadingOut;
+
+fn fade_out(commands: &mut Commands, entity: Entity, duration: Duration) {
+    commands
+        .entity(entity)
+        .insert((FadingOut, AudioFade::fade_out(duration)));
+}
+
+/// @system Stamps new voices and cuts the oldest ones past the per-sound and
+/// per-bus limits.
+pub fn limit_voices(
+    mut commands: Commands,
+    mut mixer: ResMut<AudioMixer>,
+    new_voices: Query<Entity, Added<AudioVoice>>,
+    voices: Query<(Entity, &AudioVoice, &AudioPlayer, Option<&VoiceOrder>)>,
+) {
+    if new_voices.is_empty() {
+        return;
+    }
+    for entity in &new_voices {
+        commands.entity(entity).insert(VoiceOrder(mixer.next_voice));
+        mixer.next_voice += 1;
+    }
+
+    // Order by spawn, newest first; voices stamped this frame are newest.
+    let mut ordered: Vec<(Entity, AudioBus, AssetId<AudioSource>, u64)> = voices
+        .iter()
+        .filter(|(_, voice, _, _)| voice.bus != AudioBus::Music)
+        .map(|(entity, voice, player, order)| {
+            (
+                entity,
+                voice.bus,
+                player.0.id(),
+                order.map_or(u64::MAX, |o| o.0),
+            )
+        })
+        .collect();
+    ordered.sort_by_key(|&(entity, _, _, order)| std::cmp::Reverse((order, entity)));
+
+    let mut per_sound: HashMap<AssetId<AudioSource>, usize> = HashMap::default();
+    let mut per_bus: HashMap<AudioBus, usize> = HashMap::default();
+    for (entity, bus, sound, _) in ordered {
+        let sound_count = per_sound.entry(sound).or_default();
+        let bus_count = per_bus.entry(bus).or_default();
+        *sound_count += 1;
+        *bus_count += 1;
+        if *sound_count > MAX_VOICES_PER_SOUND || *bus_count > MAX_VOICES_PER_BUS {
+            commands.entity(entity).try_despawn();
+        }
+    }
+}
+
+/// @system Advances fades and despawns music that finished fading out.
+pub fn tick_audio_fades(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut query: Query<(Entity, &mut AudioFade, Has<FadingOut>)>,
+) {
+    for (entity, mut fade, fading_out) in &mut query {
+        fade.elapsed += time.delta();
+        if fade.finished() {
+            if fading_out {
+                commands.entity(entity).try_despawn();
+            } else {
+                commands.entity(entity).remove::<AudioFade>();
+            }
+        }
+    }
+}
+
+/// @system Eases the music duck toward its target while dialogue is active.
+pub fn update_music_duck(
+    time: Res<Time<Real>>,
+    mut mixer: ResMut<AudioMixer>,
+    duck_query: Query<(), With<DuckMusic>>,
+    voices: Query<&AudioVoice>,
+) {
+    let ducked = !duck_query.is_empty() || voices.iter().any(|v| v.bus == AudioBus::Voice);
+    let target = if ducked { MUSIC_DUCK_LEVEL } else { 1.0 };
+    let step = MUSIC_DUCK_RATE * time.delta_secs();
+    mixer.music_duck = if mixer.music_duck < target {
+        (mixer.music_duck + step).min(target)
+    } else {
+        (mixer.music_duck - step).max(target)
+    };
+}
+
+/// Linear level a voice plays at.
+#[must_use]
+pub fn voice_level(
+    settings: &AudioSettings,
+    mixer: &AudioMixer,
+    voice: &AudioVoice,
+    fade: Option<&AudioFade>,
+) -> f32 {
+    let duck = if voice.bus == AudioBus::Music {
+        mixer.music_duck
+    } else {
+        1.0
+    };
+    settings.bus_level(voice.bus) * voice.gain * duck * fade.map_or(1.0, AudioFade::level)
+}
+
+/// @system Writes the mixed level of every voice to its playback settings and
+/// sink.
+pub fn apply_voice_volumes(
+    settings: Res<AudioSettings>,
+    mixer: Res<AudioMixer>,
+    mut query: Query<(
+        &AudioVoice,
+        Option<&AudioFade>,
+        &mut PlaybackSettings,
+        Option<&mut AudioSink>,
+    )>,
+) {
+    for (voice, fade, mut playback, sink) in &mut query {
+        let volume = Volume::Linear(voice_level(&settings, &mixer, voice, fade));
+        if playback.volume != volume {
+            playback.volume = volume;
+        }
+        if let Some(mut sink) = sink {
+            sink.set_volume(volume);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bus_level_includes_master() {
+        let settings = AudioSettings {
+            master: 0.5,
+            sfx: 0.4,
+            ..Default::default()
+        };
+        assert!((settings.bus_level(AudioBus::Sfx) - 0.2).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn fades_interpolate_linearly() {
+        let mut fade = AudioFade::fade_out(Duration::from_millis(100));
+        assert!((fade.level() - 1.0).abs() < f32::EPSILON);
+        fade.elapsed = Duration::from_millis(25);
+        assert!((fade.level() - 0.75).abs() < 1e-6);
+        fade.elapsed = Duration::from_millis(200);
+        assert!(fade.finished());
+        assert!(fade.level().abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn only_music_is_ducked() {
+        let settings = AudioSettings::default();
+        let mixer = AudioMixer {
+            music_duck: 0.5,
+            ..Default::default()
+        };
+        let music = voice_level(&settings, &mixer, &AudioVoice::new(AudioBus::Music), None);
+        let sfx = voice_level(&settings, &mixer, &AudioVoice::new(AudioBus::Sfx), None);
+        assert!((music - settings.bus_level(AudioBus::Music) * 0.5).abs() < f32::EPSILON);
+        assert!((sfx - settings.bus_level(AudioBus::Sfx)).abs() < f32::EPSILON);
+    }
+}
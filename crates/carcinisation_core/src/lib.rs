@@ -9,6 +9,7 @@
     clippy::needless_pass_by_value
 )]
 
+pub mod audio;
 pub mod components;
 pub mod core;
 pub mod debug;
@@ -263,6 +263,15 @@ pub struct CutsceneAct {
     #[new(default)]
     #[serde(default)]
     pub background_primitive_o: Option<CutsceneBackgroundPrimitive>,
+    /// Voiced dialogue line for this act. Music ducks while it plays.
+    #[new(default)]
+    #[serde(default)]
+    pub voice_spawn_o: Option<CutsceneVoiceSpawn>,
+    /// Ducks music for the whole act, e.g. for captioned dialogue without a
+    /// voice line.
+    #[new(default)]
+    #[serde(default)]
+    pub duck_music: bool,
 }
 
 impl CutsceneAct {
@@ -290,6 +299,18 @@ impl CutsceneAct {
         self
     }
 
+    #[must_use]
+    pub fn spawn_voice(mut self, spawn: CutsceneVoiceSpawn) -> Self {
+        self.voice_spawn_o = Some(spawn);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_duck_music(mut self, value: bool) -> Self {
+        self.duck_music = value;
+        self
+    }
+
     #[must_use]
     pub const fn despawn_music(mut self) -> Self {
         self.music_despawn_o = Some(CutsceneMusicDespawn {});
@@ -317,6 +338,12 @@ pub struct CutsceneMusicSpawn {
 #[derive(new, Clone, Component, Debug, Deserialize, Reflect, Serialize)]
 pub struct CutsceneMusicDespawn {}
 
+#[derive(new, Clone, Component, Debug, Deserialize, Reflect, Serialize)]
+/// Dialogue line played on the voice bus.
+pub struct CutsceneVoiceSpawn {
+    pub voice_path: String,
+}
+
 #[derive(new, Clone, Component, Debug, Deserialize, Reflect, Serialize)]
 pub struct CutsceneSpriteSpawn {
     pub image_path: String,
@@ -1,10 +1,13 @@
 //! RON-serializable data types for first-person maps.
 
+use carcinisation_fps_core::{
+    CellHeightSpec, Door, MapMechanisms, PatrolRoute, TriggerVolume, WallSwitch, build_height_grid,
+};
 use serde::Deserialize;
 
 use crate::camera::Camera;
 use crate::map::Map;
-use crate::render::Palette;
+use crate::render::{FlatSurfaces, Palette};
 
 /// Top-level map definition loaded from `.fp_map.ron`.
 #[derive(Deserialize, Debug)]
@@ -23,6 +26,28 @@ pub struct MapData {
     pub player_start: PlayerStart,
     #[serde(default)]
     pub entities: Vec<EntitySpawn>,
+    /// Doors, wall switches and trigger volumes, linked by door id.
+    #[serde(default)]
+    pub doors: Vec<Door>,
+    #[serde(default)]
+    pub switches: Vec<WallSwitch>,
+    #[serde(default)]
+    pub triggers: Vec<TriggerVolume>,
+    /// Per-cell floor and ceiling height overrides.
+    #[serde(default)]
+    pub heights: Vec<CellHeightSpec>,
+    /// Textures for floors and ceilings, indexed by `floor_cells` /
+    /// `ceiling_cells` ids (1-based).
+    #[serde(default)]
+    pub flat_textures: Vec<WallTextureSpec>,
+    /// Row-major floor texture ids. 0 = solid `floor_color`.
+    /// Empty = solid floor everywhere.
+    #[serde(default)]
+    pub floor_cells: Vec<u8>,
+    /// Row-major ceiling texture ids. 0 = open ceiling (sky, or solid
+    /// `ceiling_color` without one). Empty = open everywhere.
+    #[serde(default)]
+    pub ceiling_cells: Vec<u8>,
 }
 
 /// How to generate or load a wall texture.
@@ -46,6 +71,50 @@ pub enum WallTextureSpec {
     },
     /// Raw indexed pixel data (palette indices 0-15, row-major).
     Raw { size: u32, data: Vec<u8> },
+    /// Indexed `.pxi` image, path relative to the workspace root (e.g.
+    /// `"assets/sprites/..."`), like sky layers.
+    Pxi { path: String },
+}
+
+impl WallTextureSpec {
+    /// Generate or load the texture. `Pxi` paths are resolved against
+    /// `workspace_root`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a `Raw` size is 0 or a `Pxi` file can't be read or decoded.
+    #[must_use]
+    pub fn build(&self, workspace_root: &str) -> carapace::image::CxImage {
+        use crate::render::{make_brick_texture, make_checker_texture};
+        match self {
+            Self::Brick {
+                color,
+                mortar,
+                size,
+            } => make_brick_texture(*size, *color, *mortar),
+            Self::Checker {
+                color_a,
+                color_b,
+                block,
+                size,
+            } => make_checker_texture(*size, *block, *color_a, *color_b),
+            Self::Raw { size, data } => {
+                assert!(*size > 0, "Raw wall texture size must be > 0");
+                let expected = (*size as usize) * (*size as usize);
+                let mut pixels = data.clone();
+                pixels.resize(expected, 0);
+                carapace::image::CxImage::new(pixels, *size as usize)
+            }
+            Self::Pxi { path } => {
+                let path = format!("{workspace_root}/{path}");
+                let bytes = std::fs::read(&path)
+                    .unwrap_or_else(|e| panic!("failed to read texture .pxi {path}: {e}"));
+                let (width, _, indices) = crate::sky::decode_pxi(&bytes)
+                    .unwrap_or_else(|e| panic!("failed to decode texture .pxi {path}: {e}"));
+                carapace::image::CxImage::new(indices, width as usize)
+            }
+        }
+    }
 }
 
 const fn default_tex_size() -> u32 {
@@ -105,6 +174,8 @@ pub enum EntityKind {
         health: u32,
         #[serde(default = "default_enemy_speed")]
         speed: f32,
+        #[serde(default)]
+        patrol: PatrolRoute,
     },
     /// Spidey enemy — hops, leaps, fires web.
     Spidey {
@@ -112,6 +183,8 @@ pub enum EntityKind {
         health: u32,
         #[serde(default = "default_spidey_speed")]
         speed: f32,
+        #[serde(default)]
+        patrol: PatrolRoute,
     },
     /// Pickup entity (health, ammo, weapon).
     /// Parsed for deserialization only; the client skips these since the server
@@ -144,7 +217,8 @@ impl MapData {
     ///
     /// # Panics
     ///
-    /// `cells` length does not equal `width * height`.
+    /// `cells` length does not equal `width * height`, or a door, switch,
+    /// trigger or height override is invalid.
     #[must_use]
     pub fn to_map(&self) -> Map {
         let expected = self.width * self.height;
@@ -156,11 +230,25 @@ impl MapData {
             self.width,
             self.height,
         );
-        Map {
+        let mut map = Map {
             width: self.width,
             height: self.height,
             cells: self.cells.clone(),
+            mechanisms: MapMechanisms::default(),
+            heights: Vec::new(),
+        };
+        let mechanisms = MapMechanisms::new(
+            self.doors.clone(),
+            self.switches.clone(),
+            self.triggers.clone(),
+        );
+        if let Err(error) = mechanisms.validate(&map) {
+            panic!("MapData: {error}");
         }
+        map.mechanisms = mechanisms;
+        map.heights =
+            build_height_grid(&map, &self.heights).unwrap_or_else(|e| panic!("MapData: {e}"));
+        map
     }
 
     /// Build the camera from player start.
@@ -187,33 +275,54 @@ impl MapData {
 
     /// Generate wall textures from specs.
     #[must_use]
-    pub fn build_wall_textures(&self) -> Vec<carapace::image::CxImage> {
-        use crate::render::{make_brick_texture, make_checker_texture};
+    pub fn build_wall_textures(&self, workspace_root: &str) -> Vec<carapace::image::CxImage> {
         self.wall_textures
             .iter()
-            .map(|spec| match spec {
-                WallTextureSpec::Brick {
-                    color,
-                    mortar,
-                    size,
-                } => make_brick_texture(*size, *color, *mortar),
-                WallTextureSpec::Checker {
-                    color_a,
-                    color_b,
-                    block,
-                    size,
-                } => make_checker_texture(*size, *block, *color_a, *color_b),
-                WallTextureSpec::Raw { size, data } => {
-                    assert!(*size > 0, "Raw wall texture size must be > 0");
-                    let expected = (*size as usize) * (*size as usize);
-                    let mut pixels = data.clone();
-                    pixels.resize(expected, 0);
-                    carapace::image::CxImage::new(pixels, *size as usize)
-                }
-            })
+            .map(|spec| spec.build(workspace_root))
             .collect()
     }
 
+    /// Build per-cell floor and ceiling textures.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a non-empty `floor_cells` or `ceiling_cells` doesn't match
+    /// `width * height`, or an id has no matching `flat_textures` entry.
+    #[must_use]
+    pub fn to_flat_surfaces(&self, workspace_root: &str) -> FlatSurfaces {
+        let expected = self.width * self.height;
+        for (name, cells) in [
+            ("floor_cells", &self.floor_cells),
+            ("ceiling_cells", &self.ceiling_cells),
+        ] {
+            assert!(
+                cells.is_empty() || cells.len() == expected,
+                "MapData: {name} length {} != width*height {expected}",
+                cells.len(),
+            );
+            if let Some(&id) = cells
+                .iter()
+                .find(|&&id| usize::from(id) > self.flat_textures.len())
+            {
+                panic!(
+                    "MapData: {name} id {id} has no flat texture ({} defined)",
+                    self.flat_textures.len()
+                );
+            }
+        }
+        FlatSurfaces {
+            width: self.width,
+            height: self.height,
+            floor: self.floor_cells.clone(),
+            ceiling: self.ceiling_cells.clone(),
+            textures: self
+                .flat_textures
+                .iter()
+                .map(|spec| spec.build(workspace_root))
+                .collect(),
+        }
+    }
+
     /// Load an `MapData` from a RON string.
     ///
     /// # Errors
@@ -267,11 +376,70 @@ mod tests {
                 angle_deg: 0.0,
             },
             entities: vec![],
+            doors: vec![],
+            switches: vec![],
+            triggers: vec![],
+            heights: vec![],
+            flat_textures: vec![],
+            floor_cells: vec![],
+            ceiling_cells: vec![],
         };
         let result = std::panic::catch_unwind(|| data.to_map());
         assert!(result.is_err());
     }
 
+    #[test]
+    fn flat_surfaces_parse_and_build() {
+        let data = MapData::from_ron(
+            r"
+            MapData(
+                width: 2,
+                height: 1,
+                cells: [0, 0],
+                wall_textures: [],
+                ceiling_color: 1,
+                floor_color: 3,
+                player_start: (x: 0.5, y: 0.5, angle_deg: 0.0),
+                flat_textures: [Checker(color_a: 4, color_b: 5, size: 8)],
+                floor_cells: [1, 0],
+            )
+        ",
+        )
+        .unwrap();
+        let flats = data.to_flat_surfaces("");
+        assert_eq!(flats.floor, vec![1, 0]);
+        assert!(flats.ceiling.is_empty());
+        assert_eq!(flats.textures.len(), 1);
+        assert_eq!(flats.textures[0].width(), 8);
+        assert!(
+            MapData::from_ron(MINIMAL_RON)
+                .unwrap()
+                .to_flat_surfaces("")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn flat_surfaces_reject_unknown_texture_id() {
+        let data = MapData::from_ron(
+            r"
+            MapData(
+                width: 1,
+                height: 1,
+                cells: [0],
+                wall_textures: [],
+                ceiling_color: 1,
+                floor_color: 3,
+                player_start: (x: 0.5, y: 0.5, angle_deg: 0.0),
+                ceiling_cells: [2],
+            )
+        ",
+        )
+        .unwrap();
+        let result = std::panic::catch_unwind(|| data.to_flat_surfaces(""));
+        assert!(result.is_err());
+    }
+
     #[test]
     fn to_camera_converts_degrees_to_radians() {
         let data = MapData::from_ron(MINIMAL_RON).unwrap();
@@ -283,4 +451,16 @@ mod tests {
     fn invalid_ron_returns_error() {
         assert!(MapData::from_ron("not valid ron {{{").is_err());
     }
+
+    #[test]
+    fn pxi_texture_resolves_against_workspace_root() {
+        let spec = WallTextureSpec::Pxi {
+            path: format!(
+                "assets/{}",
+                assert_assets_path::assert_assets_path!("sprites/sky/clouds_fps_c.pxi")
+            ),
+        };
+        let root = format!("{}/../..", env!("CARGO_MANIFEST_DIR"));
+        assert!(spec.build(&root).width() > 0);
+    }
 }
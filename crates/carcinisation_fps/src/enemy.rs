@@ -85,7 +85,7 @@ mod tests {
         use bevy_math::Vec2;
         let map = test_map();
         let mut enemies = vec![make_enemy(3.0, 1.5)];
-        let _ = tick_enemies(&mut enemies, Vec2::new(1.5, 1.5), &map, 0.016);
+        let _ = tick_enemies(&mut enemies, Vec2::new(1.5, 1.5), &map, None, 0.016);
         assert!(matches!(enemies[0].state, EnemyState::Chasing));
     }
 
@@ -95,7 +95,7 @@ mod tests {
         let map = test_map();
         let mut enemies = vec![make_enemy(2.0, 1.5)];
         enemies[0].state = EnemyState::Chasing;
-        let _ = tick_enemies(&mut enemies, Vec2::new(1.5, 1.5), &map, 0.016);
+        let _ = tick_enemies(&mut enemies, Vec2::new(1.5, 1.5), &map, None, 0.016);
         assert!(matches!(enemies[0].state, EnemyState::Attacking { .. }));
     }
 
@@ -105,7 +105,7 @@ mod tests {
         let map = test_map();
         let mut enemies = vec![make_enemy(1.5, 1.5)];
         enemies[0].state = EnemyState::Attacking { cooldown: 0.01 };
-        let projectiles = tick_enemies(&mut enemies, Vec2::new(2.0, 1.5), &map, 0.02);
+        let projectiles = tick_enemies(&mut enemies, Vec2::new(2.0, 1.5), &map, None, 0.02);
         assert!(!projectiles.is_empty());
         assert!(projectiles[0].damage > 0);
     }
@@ -116,7 +116,7 @@ mod tests {
         let map = test_map();
         let mut enemies = vec![make_enemy(4.0, 4.0)];
         enemies[0].state = EnemyState::Dying { timer: 0.1 };
-        let _ = tick_enemies(&mut enemies, Vec2::new(1.5, 1.5), &map, 0.2);
+        let _ = tick_enemies(&mut enemies, Vec2::new(1.5, 1.5), &map, None, 0.2);
         assert!(matches!(enemies[0].state, EnemyState::Dead));
     }
 
@@ -129,7 +129,7 @@ mod tests {
             timer: 0.1,
             seed: 123,
         };
-        let projectiles = tick_enemies(&mut enemies, Vec2::new(2.0, 1.5), &map, 0.2);
+        let projectiles = tick_enemies(&mut enemies, Vec2::new(2.0, 1.5), &map, None, 0.2);
         assert!(projectiles.is_empty());
         assert!(matches!(enemies[0].state, EnemyState::Dead));
     }
@@ -10,6 +10,7 @@ use asset_pipeline::composed_ron::{CompactComposedAtlas, CompactFrame, CompactPo
 use bevy::prelude::Component;
 use bevy_math::Vec2;
 use carapace::{image::CxImage, palette::TRANSPARENT_INDEX};
+use carcinisation_fps_core::EnemyNavigation;
 use carcinisation_fps_core::burning::BurnState;
 use carcinisation_fps_core::fire_death::DamageKind;
 use flate2::bufread::DeflateDecoder;
@@ -125,6 +126,8 @@ pub struct Mosquiton {
     /// Hit-reaction runtime state (poise, stun, knockback) — round-tripped
     /// through the shared sim each tick, written by the hitscan damage path.
     pub reaction: carcinisation_fps_core::EnemyReactionState,
+    /// Sight, hearing and patrol state. The sim only runs while engaged.
+    pub perception: carcinisation_fps_core::EnemyPerception,
 }
 
 impl Mosquiton {
@@ -148,6 +151,7 @@ impl Mosquiton {
             burn_state: BurnState::default(),
             seed: carcinisation_fps_core::corpse_seed(position),
             reaction: carcinisation_fps_core::EnemyReactionState::default(),
+            perception: carcinisation_fps_core::EnemyPerception::default(),
         }
     }
 
@@ -211,6 +215,7 @@ pub fn tick_single_mosquiton(
     mosquiton: &mut Mosquiton,
     player_pos: Vec2,
     map: &Map,
+    nav: Option<&EnemyNavigation>,
     dt: f32,
 ) -> (Option<Projectile>, Option<(u32, Vec2)>) {
     use carcinisation_fps_core::mosquiton::{MosquitonSim, MosquitonSimConfig, tick_mosquiton_sim};
@@ -257,7 +262,7 @@ pub fn tick_single_mosquiton(
         reaction: mosquiton.reaction,
     };
 
-    let output = tick_mosquiton_sim(&mut sim, &sim_config, player_pos, map, dt);
+    let output = tick_mosquiton_sim(&mut sim, &sim_config, player_pos, map, nav, dt);
 
     // Write back sim state.
     mosquiton.position = sim.position;
@@ -283,12 +288,13 @@ pub fn tick_mosquitons(
     mosquitons: &mut [Mosquiton],
     player_pos: Vec2,
     map: &Map,
+    nav: Option<&EnemyNavigation>,
     dt: f32,
 ) -> MosquitonTickResult {
     let mut result = MosquitonTickResult::default();
 
     for m in mosquitons.iter_mut() {
-        let (proj, dmg) = tick_single_mosquiton(m, player_pos, map, dt);
+        let (proj, dmg) = tick_single_mosquiton(m, player_pos, map, nav, dt);
         if let Some(p) = proj {
             result.projectiles.push(p);
         }
@@ -1412,7 +1418,7 @@ mod tests {
         };
         let mut ms = vec![Mosquiton::new(Vec2::new(1.5, 1.5), config)];
         let player = Vec2::new(5.5, 1.5);
-        let _ = tick_mosquitons(&mut ms, player, &map, 0.1);
+        let _ = tick_mosquitons(&mut ms, player, &map, None, 0.1);
         // Should have moved toward player (x increased).
         assert!(ms[0].position.x > 1.5);
         assert!(ms[0].animation_time > 0.0);
@@ -1428,7 +1434,7 @@ mod tests {
         let mut ms = vec![Mosquiton::new(Vec2::new(3.5, 1.5), config)];
         let player = Vec2::new(1.5, 1.5);
         // Distance = 2.0, at preferred range.
-        let _ = tick_mosquitons(&mut ms, player, &map, 0.016);
+        let _ = tick_mosquitons(&mut ms, player, &map, None, 0.016);
         assert!(matches!(ms[0].state, MosquitonState::RangedAttack { .. }));
     }
 
@@ -1442,7 +1448,7 @@ mod tests {
             1.5,
         )];
         let player = Vec2::new(1.5, 1.5);
-        let _ = tick_mosquitons(&mut ms, player, &map, 0.016);
+        let _ = tick_mosquitons(&mut ms, player, &map, None, 0.016);
         assert!(matches!(ms[0].state, MosquitonState::MeleeAttack { .. }));
     }
 
@@ -1475,7 +1481,7 @@ mod tests {
             timer: 0.1,
             seed: 123,
         };
-        let result = tick_mosquitons(&mut ms, Vec2::new(1.5, 1.5), &map, 0.2);
+        let result = tick_mosquitons(&mut ms, Vec2::new(1.5, 1.5), &map, None, 0.2);
         assert_eq!(result.player_damage, 0);
         assert!(result.projectiles.is_empty());
         assert!(matches!(ms[0].state, MosquitonState::Dead));
@@ -1491,7 +1497,7 @@ mod tests {
             seed: 123,
         };
 
-        let _ = tick_mosquitons(&mut ms, Vec2::new(1.5, 1.5), &map, 0.2);
+        let _ = tick_mosquitons(&mut ms, Vec2::new(1.5, 1.5), &map, None, 0.2);
 
         assert_eq!(ms[0].animation_time, 0.4);
     }
@@ -1510,7 +1516,7 @@ mod tests {
             dealt_damage: false,
         };
 
-        let result = tick_mosquitons(&mut ms, Vec2::new(1.5, 1.5), &map, 0.016);
+        let result = tick_mosquitons(&mut ms, Vec2::new(1.5, 1.5), &map, None, 0.016);
 
         assert_eq!(result.player_damage, 17);
         assert_eq!(result.damage_source, Some(melee_source));
@@ -1533,8 +1539,8 @@ mod tests {
             dealt_damage: false,
         };
 
-        let first = tick_mosquitons(&mut ms, Vec2::new(1.5, 1.5), &map, 0.016);
-        let second = tick_mosquitons(&mut ms, Vec2::new(1.5, 1.5), &map, 0.016);
+        let first = tick_mosquitons(&mut ms, Vec2::new(1.5, 1.5), &map, None, 0.016);
+        let second = tick_mosquitons(&mut ms, Vec2::new(1.5, 1.5), &map, None, 0.016);
 
         assert!(first.player_damage > 0);
         assert_eq!(second.player_damage, 0);
@@ -1554,7 +1560,7 @@ mod tests {
         let player = Vec2::new(5.5, 1.5);
 
         // First tick starts the shoot animation.
-        let result = tick_mosquitons(&mut ms, player, &map, 0.016);
+        let result = tick_mosquitons(&mut ms, player, &map, None, 0.016);
         assert!(
             result.projectiles.is_empty(),
             "projectile should not spawn immediately"
@@ -1565,7 +1571,7 @@ mod tests {
         );
 
         // Tick past the cue point — projectile spawns.
-        let result = tick_mosquitons(&mut ms, player, &map, 0.5);
+        let result = tick_mosquitons(&mut ms, player, &map, None, 0.5);
         assert!(
             !result.projectiles.is_empty(),
             "should fire after cue delay"
@@ -1586,6 +1592,8 @@ mod tests {
                 1, 0, 1, 0, 1, //
                 1, 1, 1, 1, 1,
             ],
+            mechanisms: carcinisation_fps_core::MapMechanisms::default(),
+            heights: Vec::new(),
         };
         let config = MosquitonConfig {
             shoot_range: 10.0,
@@ -1594,7 +1602,7 @@ mod tests {
         let mut ms = vec![Mosquiton::new(Vec2::new(1.5, 1.5), config)];
         ms[0].shoot_cooldown = 0.0;
         let player = Vec2::new(3.5, 1.5);
-        let result = tick_mosquitons(&mut ms, player, &map, 0.016);
+        let result = tick_mosquitons(&mut ms, player, &map, None, 0.016);
         assert!(result.projectiles.is_empty());
         assert!(!has_line_of_sight(ms[0].position, player, &map));
     }
@@ -1604,7 +1612,7 @@ mod tests {
         let map = test_map();
         let mut ms = vec![make_mosquiton(1.5, 1.5)];
         ms[0].state = MosquitonState::Dying { timer: 0.1 };
-        let _ = tick_mosquitons(&mut ms, Vec2::ZERO, &map, 0.2);
+        let _ = tick_mosquitons(&mut ms, Vec2::ZERO, &map, None, 0.2);
         assert!(matches!(ms[0].state, MosquitonState::Dead));
     }
 
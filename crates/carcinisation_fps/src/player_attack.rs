@@ -2,8 +2,8 @@
 
 use bevy::prelude::{Reflect, ReflectResource, Resource, Vec2};
 use carcinisation_fps_core::{
-    EnemyReactionTuning, FirePose2d, FlameStrip, FpsEnemyKind, HIT_DEBUG_TARGET, PartHitscanTarget,
-    PendingHitReaction, WeaponReactionProfile,
+    FirePose2d, FlameStrip, FpsEnemyKind, HIT_DEBUG_TARGET, NoiseKind, PartHitscanTarget,
+    PendingHitReaction, WeaponDef, WeaponReactionProfile, WeaponTable,
     collision::{PartId, PartReactionProfile},
     collision_set,
     enemy_collision::{DEFAULT_ANIMATION, DEFAULT_FRAME},
@@ -89,25 +89,79 @@ const BULLET_REGION: &str = "bullet_particles";
 const MELEE_REGION: &str = "melee_slash";
 const FLAME_REGION: &str = "flame";
 const FLAME_WALL_HIT_REGION: &str = "flame_wall_hit";
-const FLAMETHROWER_IDLE_REGION: &str = "flamethrower_idle";
-const FLAMETHROWER_SHOOTING_REGION: &str = "flamethrower_shooting";
 const STAGE_IDLE_FLAME_REGION: &str = "flamethrower_flame";
-const GUN_IDLE_REGION: &str = "idle";
-const GUN_SHOOTING_REGION: &str = "shooting";
 const GUN_MUZZLE_FLASH_REGION: &str = "shooting";
 const PISTOL_EFFECT_POS: Vec2 = Vec2::new(80.0, 72.0);
 const MELEE_EFFECT_POS: Vec2 = Vec2::new(80.0, 72.0);
-const MELEE_RANGE_UNITS: f32 = 1.1;
 const FLAME_WALL_IMPACT_WIDTH: f32 = 0.30;
 const FLAME_WALL_IMPACT_HEIGHT: f32 = 0.30;
 const FLAME_CHAR_DECAL_WIDTH: f32 = FLAME_WALL_IMPACT_WIDTH;
 const FLAME_CHAR_DECAL_HEIGHT: f32 = FLAME_WALL_IMPACT_HEIGHT;
 const MAX_FLAME_CHAR_DECALS: usize = 128;
 
+/// Embed a stock view-model atlas directory (relative to `assets/`).
+macro_rules! embedded_view_model_atlas {
+    ($dir:literal) => {
+        (
+            $dir,
+            include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../../assets/",
+                $dir,
+                "/atlas.px_atlas.ron"
+            )),
+            include_bytes!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../../assets/",
+                $dir,
+                "/atlas.pxi"
+            )) as &[u8],
+        )
+    };
+}
+
+/// View-model atlases of the stock weapons, embedded like the other attack
+/// sprites. A `weapons.ron` sprite outside this list is read from disk.
+const EMBEDDED_VIEW_MODEL_ATLASES: [(&str, &str, &[u8]); 4] = [
+    embedded_view_model_atlas!("sprites/ui/stage_flamethrower_weapon"),
+    embedded_view_model_atlas!("sprites/ui/stage_flamethrower_weapon_shooting"),
+    embedded_view_model_atlas!("sprites/ui/stage_gun_weapon"),
+    embedded_view_model_atlas!("sprites/ui/stage_gun_weapon_shooting"),
+];
+
+/// How a loadout weapon behaves on the view-model, from its `fire` mode.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
-pub enum AttackId {
-    Pistol,
-    Flamethrower,
+pub enum AttackKind {
+    /// Gun handling: muzzle flash and a shooting loop per shot.
+    Hitscan,
+    /// Flamethrower handling: nozzle flame and the flame stream.
+    Flame,
+}
+
+/// A weapon the view-model can present.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub struct AttackId {
+    /// Index into [`WeaponTable::weapons`]; the same slot the wire
+    /// `NetAttackId` carries.
+    pub slot: u8,
+    pub kind: AttackKind,
+}
+
+impl AttackId {
+    #[must_use]
+    pub fn from_slot(weapons: &WeaponTable, slot: u8) -> Option<Self> {
+        let kind = if weapons.by_slot(slot)?.is_flame() {
+            AttackKind::Flame
+        } else {
+            AttackKind::Hitscan
+        };
+        Some(Self { slot, kind })
+    }
+
+    #[must_use]
+    pub fn from_weapon_id(weapons: &WeaponTable, id: &str) -> Option<Self> {
+        Self::from_slot(weapons, weapons.slot_of(id)?)
+    }
 }
 
 #[derive(Resource, Debug, Reflect)]
@@ -119,14 +173,26 @@ pub struct AttackLoadout {
 
 impl Default for AttackLoadout {
     fn default() -> Self {
-        Self {
-            options: vec![AttackId::Flamethrower, AttackId::Pistol],
-            index: 0,
-        }
+        Self::from_table(&WeaponTable::default())
     }
 }
 
 impl AttackLoadout {
+    /// The weapon table's loadout, in order. An unusable table falls back to
+    /// the stock one.
+    #[must_use]
+    pub fn from_table(weapons: &WeaponTable) -> Self {
+        let options: Vec<AttackId> = weapons
+            .loadout
+            .iter()
+            .filter_map(|id| AttackId::from_weapon_id(weapons, id))
+            .collect();
+        if options.is_empty() {
+            return Self::default();
+        }
+        Self { options, index: 0 }
+    }
+
     #[must_use]
     pub fn options(&self) -> &[AttackId] {
         &self.options
@@ -443,17 +509,47 @@ pub struct PlayerAttackSprites {
     melee: AtlasAnimation,
     flame: AtlasAnimation,
     flame_wall_hit: AtlasAnimation,
-    weapon_idle: AtlasAnimation,
-    weapon_shooting: AtlasAnimation,
     idle_flame: AtlasAnimation,
-    gun_idle: AtlasAnimation,
-    gun_shooting: AtlasAnimation,
     gun_muzzle_flash: AtlasAnimation,
+    /// View-model of each loadout weapon, keyed by [`AttackId::slot`].
+    view_models: HashMap<u8, ViewModelSprites>,
+}
+
+/// Idle and firing frames of one weapon's view-model.
+#[derive(Clone, Debug)]
+struct ViewModelSprites {
+    idle: AtlasAnimation,
+    shooting: AtlasAnimation,
+}
+
+impl ViewModelSprites {
+    /// Load `<sprite>` and `<sprite>_shooting` (directories under `assets/`).
+    fn load(sprite: &str) -> Result<Self, String> {
+        Ok(Self {
+            idle: load_view_model_atlas(sprite)?,
+            shooting: load_view_model_atlas(&format!("{sprite}_shooting"))?,
+        })
+    }
 }
 
 impl PlayerAttackSprites {
+    /// Load the attack sprites plus the view-model of every weapon in the
+    /// shipped `weapons.ron` loadout.
     #[must_use]
     pub fn load() -> Self {
+        let weapons = WeaponTable::load();
+        let view_models = weapons
+            .loadout
+            .iter()
+            .filter_map(|id| {
+                let slot = weapons.slot_of(id)?;
+                let sprite = &weapons.by_slot(slot)?.sprite;
+                let view_model = ViewModelSprites::load(sprite).unwrap_or_else(|err| {
+                    panic!("view-model {sprite} of weapon '{id}' must load: {err}")
+                });
+                Some((slot, view_model))
+            })
+            .collect();
         Self {
             bullet: load_sprite_atlas!(
                 "assets/sprites/attacks/player_bullet/atlas.px_atlas.ron",
@@ -479,45 +575,26 @@ impl PlayerAttackSprites {
                 FLAME_WALL_HIT_REGION
             )
             .expect("player flame wall hit atlas must load"),
-            weapon_idle: load_sprite_atlas!(
-                "assets/sprites/ui/stage_flamethrower_weapon/atlas.px_atlas.ron",
-                "assets/sprites/ui/stage_flamethrower_weapon/atlas.pxi",
-                FLAMETHROWER_IDLE_REGION
-            )
-            .expect("stage flamethrower idle weapon atlas must load"),
-            weapon_shooting: load_sprite_atlas!(
-                "assets/sprites/ui/stage_flamethrower_weapon_shooting/atlas.px_atlas.ron",
-                "assets/sprites/ui/stage_flamethrower_weapon_shooting/atlas.pxi",
-                FLAMETHROWER_SHOOTING_REGION
-            )
-            .expect("stage flamethrower shooting weapon atlas must load"),
             idle_flame: load_sprite_atlas!(
                 "assets/sprites/ui/stage_flamethrower_flame/atlas.px_atlas.ron",
                 "assets/sprites/ui/stage_flamethrower_flame/atlas.pxi",
                 STAGE_IDLE_FLAME_REGION
             )
             .expect("stage flamethrower idle flame atlas must load"),
-            gun_idle: load_sprite_atlas!(
-                "assets/sprites/ui/stage_gun_weapon/atlas.px_atlas.ron",
-                "assets/sprites/ui/stage_gun_weapon/atlas.pxi",
-                GUN_IDLE_REGION
-            )
-            .expect("stage gun idle atlas must load"),
-            gun_shooting: load_sprite_atlas!(
-                "assets/sprites/ui/stage_gun_weapon_shooting/atlas.px_atlas.ron",
-                "assets/sprites/ui/stage_gun_weapon_shooting/atlas.pxi",
-                GUN_SHOOTING_REGION
-            )
-            .expect("stage gun shooting atlas must load"),
             gun_muzzle_flash: load_sprite_atlas!(
                 "assets/sprites/ui/stage_gun_muzzle_flash/atlas.px_atlas.ron",
                 "assets/sprites/ui/stage_gun_muzzle_flash/atlas.pxi",
                 GUN_MUZZLE_FLASH_REGION
             )
             .expect("stage gun muzzle flash atlas must load"),
+            view_models,
         }
     }
 
+    fn view_model(&self, attack: AttackId) -> Option<&ViewModelSprites> {
+        self.view_models.get(&attack.slot)
+    }
+
     #[must_use]
     pub fn flame_frame_loop(&self, elapsed_secs: f32) -> &Arc<CxImage> {
         self.flame.frame_loop(elapsed_secs)
@@ -553,6 +630,11 @@ pub struct PlayerAttackState {
     /// `Res<PlayerFlamethrowerConfig>` by the hot reload system in `plugin.rs`.
     shared: carcinisation_fps_core::PlayerFlamethrowerConfig,
     gun_config: GunConfig,
+    /// Noise the last [`process_player_attacks`] call made, for enemy hearing.
+    noise: Option<NoiseKind>,
+    /// Seconds until the next pistol or melee shot. Mirrors the server's
+    /// `FireCooldownMap`, so `fire_rate` holds on both authorities.
+    hitscan_cooldown: f32,
 }
 
 impl PlayerAttackState {
@@ -582,12 +664,14 @@ impl Default for PlayerAttackState {
             weapon_raise_offset,
             weapon_base_pose_offset: 0.0,
             weapon_phase: WeaponPresentationPhase::Raised,
-            weapon_presented: AttackId::Flamethrower,
+            weapon_presented: AttackLoadout::default().current(),
             weapon_switch_target: None,
             snap_turn_offset: Vec2::ZERO,
             config,
             shared,
             gun_config,
+            noise: None,
+            hitscan_cooldown: 0.0,
         }
     }
 }
@@ -617,14 +701,21 @@ impl PlayerAttackState {
             weapon_raise_offset,
             weapon_base_pose_offset: 0.0,
             weapon_phase: WeaponPresentationPhase::Raised,
-            weapon_presented: AttackId::Flamethrower,
+            weapon_presented: AttackLoadout::default().current(),
             weapon_switch_target: None,
             config,
             shared,
             gun_config,
+            noise: None,
+            hitscan_cooldown: 0.0,
         }
     }
 
+    #[must_use]
+    pub const fn noise(&self) -> Option<NoiseKind> {
+        self.noise
+    }
+
     #[must_use]
     pub const fn config(&self) -> &PlayerFlamethrower1pConfig {
         &self.config
@@ -840,7 +931,6 @@ pub fn process_player_attacks(
     camera: &Camera,
     map: &Map,
     sprites: &PlayerAttackSprites,
-    hitscan_damage: u32,
     dt: f32,
     elapsed_secs: f32,
     input: &mut AttackInput,
@@ -859,7 +949,7 @@ pub fn process_player_attacks(
     view_bob_amplitude: f32,
     view_bob_freq_mult: f32,
     snap_turn: SnapTurnVisualInput,
-    reaction_tuning: &EnemyReactionTuning,
+    weapons: &WeaponTable,
 ) {
     let fire_pose = FirePose2d::from(camera);
 
@@ -869,49 +959,51 @@ pub fn process_player_attacks(
 
     let legacy_shot = *legacy_shoot_request;
     *legacy_shoot_request = false;
+    state.noise = None;
+    state.hitscan_cooldown = (state.hitscan_cooldown - dt).max(0.0);
+    let hitscan_ready = state.hitscan_cooldown <= 0.0;
 
     if input.melee_triggered {
-        state.one_shots.push(OneShotEffect {
-            kind: OneShotEffectKind::Melee,
-            elapsed: 0.0,
-            position: MELEE_EFFECT_POS,
-        });
-        // NOTE: this ×3 melee multiplier is applied to the BASE damage, then
-        // `apply_hitscan_damage` additionally applies the hit part's
-        // `damage_scale` (Phase 5). So a melee headshot stacks both:
-        //   melee headshot = 3 (melee) × 2.0 (head) = 6× base.
-        // TODO(balance): this melee × part-scale interaction has not been
-        // balance-reviewed. Pre-existing multiplier; left as-is here (no config
-        // field exists yet). Revisit when melee or part scaling is tuned.
-        //
-        // TODO(mp-melee): this is the SP-only melee path (×3 base damage +
-        // `enemy_reaction.melee` profile). The server has NO distinct melee
-        // path — `NetAttackId::Melee` is pistol-equivalent there (see
-        // `apps/carcinisation_server/src/systems/combat.rs`, TODO(mp-melee)).
-        // These must converge into shared melee config before MP melee ships.
-        apply_hitscan_damage(
-            fire_pose,
-            map,
-            enemies,
-            mosquitons,
-            spideys,
-            projectiles,
-            impacts,
-            hitscan_damage.saturating_mul(3),
-            Some(MELEE_RANGE_UNITS),
-            &reaction_tuning.melee,
-        );
+        // The melee weapon's base damage, range and reaction come from
+        // `weapons.ron`; `apply_hitscan_damage` then applies the hit part's
+        // `damage_scale`, so a melee headshot is melee base × 2.0 (head).
+        // The server resolves the melee slot to the same definition.
+        if hitscan_ready && let Some(melee) = weapons.melee() {
+            state.hitscan_cooldown = melee.cooldown_secs();
+            state.one_shots.push(OneShotEffect {
+                kind: OneShotEffectKind::Melee,
+                elapsed: 0.0,
+                position: MELEE_EFFECT_POS,
+            });
+            fire_hitscan_weapon(
+                melee,
+                fire_pose,
+                map,
+                enemies,
+                mosquitons,
+                spideys,
+                projectiles,
+                impacts,
+            );
+        }
     } else {
-        match loadout.current() {
-            AttackId::Pistol => {
-                if input.shoot_just_pressed || legacy_shot {
+        let current = loadout.current();
+        match current.kind {
+            AttackKind::Hitscan => {
+                if (input.shoot_just_pressed || legacy_shot)
+                    && hitscan_ready
+                    && let Some(gun) = weapons.by_slot(current.slot)
+                {
+                    state.hitscan_cooldown = gun.cooldown_secs();
                     state.gun_muzzle_flash_elapsed = Some(0.0);
+                    state.noise = Some(NoiseKind::Gunshot);
                     state.one_shots.push(OneShotEffect {
                         kind: OneShotEffectKind::Bullet,
                         elapsed: 0.0,
                         position: PISTOL_EFFECT_POS,
                     });
-                    apply_hitscan_damage(
+                    fire_hitscan_weapon(
+                        gun,
                         fire_pose,
                         map,
                         enemies,
@@ -919,28 +1011,30 @@ pub fn process_player_attacks(
                         spideys,
                         projectiles,
                         impacts,
-                        hitscan_damage,
-                        None,
-                        &reaction_tuning.pistol,
                     );
                 }
             }
-            AttackId::Flamethrower => update_flamethrower_attack(
-                fire_pose,
-                map,
-                dt,
-                elapsed_secs,
-                input,
-                state,
-                enemies,
-                mosquitons,
-                spideys,
-                projectiles,
-                impacts,
-                char_decals,
-                screen_height_px,
-                burn_config,
-            ),
+            AttackKind::Flame => {
+                update_flamethrower_attack(
+                    fire_pose,
+                    map,
+                    dt,
+                    elapsed_secs,
+                    input,
+                    state,
+                    enemies,
+                    mosquitons,
+                    spideys,
+                    projectiles,
+                    impacts,
+                    char_decals,
+                    screen_height_px,
+                    burn_config,
+                );
+                if state.flamethrower.as_ref().is_some_and(|ft| ft.spawning) {
+                    state.noise = Some(NoiseKind::Flamethrower);
+                }
+            }
         }
     }
 
@@ -974,10 +1068,10 @@ pub fn process_player_attacks(
 }
 
 fn clear_inactive_weapon_state(state: &mut PlayerAttackState, current_weapon: AttackId) {
-    if current_weapon != AttackId::Flamethrower {
+    if current_weapon.kind != AttackKind::Flame {
         state.flamethrower = None;
     }
-    if current_weapon != AttackId::Pistol {
+    if current_weapon.kind != AttackKind::Hitscan {
         state.gun_muzzle_flash_elapsed = None;
     }
 }
@@ -1091,8 +1185,8 @@ fn update_weapon_presentation(
 
     let presentation_weapon = state.presented_weapon(current_weapon);
     let (raise_px, raise_speed, bob_enabled, bob_h, bob_v, bob_speed, bob_return, st_h, st_v) =
-        match presentation_weapon {
-            AttackId::Flamethrower => (
+        match presentation_weapon.kind {
+            AttackKind::Flame => (
                 state.config.weapon_raise_px,
                 state.config.weapon_raise_speed,
                 state.config.weapon_bob_enabled,
@@ -1103,7 +1197,7 @@ fn update_weapon_presentation(
                 state.config.snap_turn_horizontal_px,
                 state.config.snap_turn_vertical_px,
             ),
-            AttackId::Pistol => (
+            AttackKind::Hitscan => (
                 state.gun_config.weapon_raise_px,
                 state.gun_config.weapon_raise_speed,
                 state.gun_config.weapon_bob_enabled,
@@ -1119,12 +1213,12 @@ fn update_weapon_presentation(
     // Weapon stays raised while flame chain is active (draining or spawning),
     // Weapon raised and bob suppressed only while actively spawning flames.
     // Draining flame chain keeps visuals alive but doesn't affect weapon pose.
-    let (weapon_raised, suppress_bob) = match current_weapon {
-        AttackId::Flamethrower => {
+    let (weapon_raised, suppress_bob) = match current_weapon.kind {
+        AttackKind::Flame => {
             let spawning = state.flamethrower.as_ref().is_some_and(|ft| ft.spawning);
             (spawning, spawning)
         }
-        AttackId::Pistol => {
+        AttackKind::Hitscan => {
             let flash = state.gun_muzzle_flash_elapsed.is_some();
             (flash, flash)
         }
@@ -1620,6 +1714,36 @@ fn tick_one_shot_effects(
     });
 }
 
+/// Resolve a hitscan weapon definition (spread, damage, range, reaction)
+/// into one `apply_hitscan_damage` call. Flame definitions are ignored.
+#[allow(clippy::too_many_arguments)]
+fn fire_hitscan_weapon(
+    weapon: &WeaponDef,
+    fire_pose: FirePose2d,
+    map: &Map,
+    enemies: &mut [Enemy],
+    mosquitons: &mut [Mosquiton],
+    spideys: &mut [Spidey],
+    projectiles: &mut Vec<Projectile>,
+    impacts: &mut Vec<ProjectileImpact>,
+) {
+    let Some(damage) = weapon.hitscan_damage() else {
+        return;
+    };
+    apply_hitscan_damage(
+        weapon.spread_pose(fire_pose),
+        map,
+        enemies,
+        mosquitons,
+        spideys,
+        projectiles,
+        impacts,
+        damage,
+        weapon.range(),
+        &weapon.reaction,
+    );
+}
+
 #[allow(clippy::too_many_arguments)]
 fn apply_hitscan_damage(
     fire_pose: FirePose2d,
@@ -1870,7 +1994,8 @@ pub fn draw_player_attack_overlays(
     }
 
     let rendered_weapon = state.presented_weapon(loadout.current());
-    if rendered_weapon == AttackId::Flamethrower {
+    let view_model = sprites.view_model(rendered_weapon);
+    if rendered_weapon.kind == AttackKind::Flame {
         let config = &state.config;
         let screen_height = image.height() as f32;
         let presentation_offset = weapon_presentation_offset(state);
@@ -1893,13 +2018,15 @@ pub fn draw_player_attack_overlays(
             );
         }
 
-        draw_image_scaled_center(
-            image,
-            flamethrower_weapon_animation(sprites, state).frame_loop(elapsed_secs),
-            weapon_center,
-            1.0,
-        );
-    } else if rendered_weapon == AttackId::Pistol {
+        if let Some(view_model) = view_model {
+            draw_image_scaled_center(
+                image,
+                flamethrower_weapon_animation(view_model, state).frame_loop(elapsed_secs),
+                weapon_center,
+                1.0,
+            );
+        }
+    } else {
         let gun_config = &state.gun_config;
         let screen_height = image.height() as f32;
         let presentation_offset = weapon_presentation_offset(state);
@@ -1917,12 +2044,14 @@ pub fn draw_player_attack_overlays(
         }
 
         // Gun weapon sprite: idle shows first frame only, shooting loops.
-        let gun_frame = if state.gun_muzzle_flash_elapsed.is_some() {
-            sprites.gun_shooting.frame_loop(elapsed_secs)
-        } else {
-            sprites.gun_idle.frame_clamped(0.0)
-        };
-        draw_image_scaled_center(image, gun_frame, weapon_center, 1.0);
+        if let Some(view_model) = view_model {
+            let gun_frame = if state.gun_muzzle_flash_elapsed.is_some() {
+                view_model.shooting.frame_loop(elapsed_secs)
+            } else {
+                view_model.idle.frame_clamped(0.0)
+            };
+            draw_image_scaled_center(image, gun_frame, weapon_center, 1.0);
+        }
     }
 }
 
@@ -1949,13 +2078,13 @@ fn weapon_presentation_offset(state: &PlayerAttackState) -> Vec2 {
 }
 
 fn flamethrower_weapon_animation<'a>(
-    sprites: &'a PlayerAttackSprites,
+    view_model: &'a ViewModelSprites,
     state: &PlayerAttackState,
 ) -> &'a AtlasAnimation {
     if state.flamethrower.as_ref().is_some_and(|ft| ft.spawning) {
-        &sprites.weapon_shooting
+        &view_model.shooting
     } else {
-        &sprites.weapon_idle
+        &view_model.idle
     }
 }
 
@@ -2089,6 +2218,38 @@ fn load_atlas_animation(
     })
 }
 
+/// Load a view-model atlas directory (relative to `assets/`) by its only
+/// region. Stock atlases come from [`EMBEDDED_VIEW_MODEL_ATLASES`] (disk
+/// first under `hot_reload`); anything else is read from disk.
+fn load_view_model_atlas(dir: &str) -> Result<AtlasAnimation, String> {
+    let embedded = EMBEDDED_VIEW_MODEL_ATLASES
+        .iter()
+        .find(|(path, _, _)| *path == dir);
+    #[cfg(not(feature = "hot_reload"))]
+    if let Some(&(_, ron_text, pxi_data)) = embedded {
+        return load_first_atlas_region(ron_text, pxi_data);
+    }
+    let ron_path = format!("assets/{dir}/atlas.px_atlas.ron");
+    let pxi_path = format!("assets/{dir}/atlas.pxi");
+    match (std::fs::read_to_string(&ron_path), std::fs::read(&pxi_path)) {
+        (Ok(ron_text), Ok(pxi_data)) => load_first_atlas_region(&ron_text, &pxi_data),
+        (Err(err), _) | (_, Err(err)) => embedded.map_or_else(
+            || Err(format!("{dir}: {err}")),
+            |&(_, ron_text, pxi_data)| load_first_atlas_region(ron_text, pxi_data),
+        ),
+    }
+}
+
+fn load_first_atlas_region(atlas_ron: &str, pxi_bytes: &[u8]) -> Result<AtlasAnimation, String> {
+    let descriptor: PxAtlasDescriptor = ron::from_str(atlas_ron).map_err(|err| err.to_string())?;
+    let region_name = descriptor
+        .names
+        .iter()
+        .find_map(|(name, &index)| (index == 0).then_some(name.as_str()))
+        .ok_or_else(|| "atlas has no named region 0".to_owned())?;
+    load_atlas_animation(atlas_ron, pxi_bytes, region_name)
+}
+
 fn extract_atlas_rect(atlas_pixels: &[u8], atlas_width: u32, rect: PxAtlasRect) -> Option<CxImage> {
     let mut data = vec![TRANSPARENT_INDEX; (rect.w * rect.h) as usize];
     for local_y in 0..rect.h {
@@ -2164,6 +2325,8 @@ mod tests {
             width: 32,
             height: 32,
             cells: vec![0; 32 * 32],
+            mechanisms: carcinisation_fps_core::MapMechanisms::default(),
+            heights: Vec::new(),
         }
     }
 
@@ -2176,6 +2339,8 @@ mod tests {
                 1, 0, 0, 0, 0, 1, //
                 1, 1, 1, 1, 1, 1,
             ],
+            mechanisms: carcinisation_fps_core::MapMechanisms::default(),
+            heights: Vec::new(),
         };
         if let Some(x) = wall_x {
             map.cells[map.width + x] = 1;
@@ -2207,6 +2372,14 @@ mod tests {
         state.weapon_phase = WeaponPresentationPhase::Lowered;
     }
 
+    fn pistol() -> AttackId {
+        AttackId::from_weapon_id(&WeaponTable::default(), "pistol").unwrap()
+    }
+
+    fn flamethrower() -> AttackId {
+        AttackId::from_weapon_id(&WeaponTable::default(), "flamethrower").unwrap()
+    }
+
     #[test]
     fn fps_attack_configs_load() {
         let _ = GroundFireVisualConfig::load();
@@ -2217,15 +2390,41 @@ mod tests {
     #[test]
     fn attack_loadout_reports_next_without_mutating() {
         let loadout = AttackLoadout::default();
+        assert_eq!(loadout.options(), &[flamethrower(), pistol()]);
+        assert!(loadout.contains(flamethrower()));
+        assert!(loadout.contains(pistol()));
+        assert_eq!(loadout.current(), flamethrower());
+        assert_eq!(loadout.next(), pistol());
+        assert_eq!(loadout.current(), flamethrower());
+    }
+
+    #[test]
+    fn attack_loadout_follows_weapon_table_order() {
+        let mut weapons = WeaponTable::default();
+        assert_eq!(
+            AttackLoadout::from_table(&weapons).options(),
+            AttackLoadout::default().options()
+        );
+
+        weapons.loadout = vec!["pistol".into(), "flamethrower".into()];
         assert_eq!(
-            loadout.options(),
-            &[AttackId::Flamethrower, AttackId::Pistol]
+            AttackLoadout::from_table(&weapons).options(),
+            &[pistol(), flamethrower()]
         );
-        assert!(loadout.contains(AttackId::Flamethrower));
-        assert!(loadout.contains(AttackId::Pistol));
-        assert_eq!(loadout.current(), AttackId::Flamethrower);
-        assert_eq!(loadout.next(), AttackId::Pistol);
-        assert_eq!(loadout.current(), AttackId::Flamethrower);
+    }
+
+    #[test]
+    fn new_loadout_weapon_needs_only_table_data() {
+        let mut weapons = WeaponTable::default();
+        let mut railgun = weapons.get("pistol").unwrap().clone();
+        railgun.id = "railgun".into();
+        weapons.weapons.push(railgun);
+        weapons.loadout.push("railgun".into());
+
+        let railgun = AttackId::from_weapon_id(&weapons, "railgun").unwrap();
+        assert_eq!(railgun.kind, AttackKind::Hitscan);
+        assert!(AttackLoadout::from_table(&weapons).contains(railgun));
+        assert!(ViewModelSprites::load(&weapons.get("railgun").unwrap().sprite).is_ok());
     }
 
     #[test]
@@ -2235,12 +2434,14 @@ mod tests {
         assert_eq!(sprites.melee.frames.len(), 9);
         assert_eq!(sprites.flame.frames.len(), 4);
         assert_eq!(sprites.flame_wall_hit.frames.len(), 3);
-        assert_eq!(sprites.weapon_idle.frames.len(), 1);
-        assert_eq!(sprites.weapon_shooting.frames.len(), 2);
+        let flame = sprites.view_model(flamethrower()).unwrap();
+        assert_eq!(flame.idle.frames.len(), 1);
+        assert_eq!(flame.shooting.frames.len(), 2);
         assert_eq!(sprites.idle_flame.frames.len(), 4);
         assert_eq!(sprites.idle_flame.frames[0].size(), UVec2::new(6, 8));
-        assert_eq!(sprites.gun_idle.frames.len(), 4);
-        assert_eq!(sprites.gun_shooting.frames.len(), 4);
+        let gun = sprites.view_model(pistol()).unwrap();
+        assert_eq!(gun.idle.frames.len(), 4);
+        assert_eq!(gun.shooting.frames.len(), 4);
         assert_eq!(sprites.gun_muzzle_flash.frames.len(), 4);
     }
 
@@ -2249,8 +2450,8 @@ mod tests {
         let sprites = PlayerAttackSprites::load();
         let mut state = PlayerAttackState::default();
         let mut loadout = AttackLoadout::default();
-        commit_test_weapon(&mut loadout, &mut state, AttackId::Pistol);
-        assert_eq!(loadout.current(), AttackId::Pistol);
+        commit_test_weapon(&mut loadout, &mut state, pistol());
+        assert_eq!(loadout.current(), pistol());
 
         // No flash initially.
         assert!(state.gun_muzzle_flash_elapsed.is_none());
@@ -2261,6 +2462,8 @@ mod tests {
             width: 8,
             height: 8,
             cells: vec![0; 64],
+            mechanisms: carcinisation_fps_core::MapMechanisms::default(),
+            heights: Vec::new(),
         };
         let mut input = AttackInput {
             shoot_just_pressed: true,
@@ -2279,7 +2482,6 @@ mod tests {
             &camera,
             &map,
             &sprites,
-            37,
             1.0 / 60.0,
             0.0,
             &mut input,
@@ -2298,7 +2500,7 @@ mod tests {
             1.5,
             2.0,
             SnapTurnVisualInput::default(),
-            &carcinisation_fps_core::EnemyReactionTuning::default(),
+            &WeaponTable::default(),
         );
 
         // Flash should be active.
@@ -2311,7 +2513,6 @@ mod tests {
             &camera,
             &map,
             &sprites,
-            37,
             sprites.gun_muzzle_flash.duration_secs + 0.01,
             1.0,
             &mut input,
@@ -2330,19 +2531,72 @@ mod tests {
             1.5,
             2.0,
             SnapTurnVisualInput::default(),
-            &carcinisation_fps_core::EnemyReactionTuning::default(),
+            &WeaponTable::default(),
         );
 
         // Flash should have expired.
         assert!(state.gun_muzzle_flash_elapsed.is_none());
     }
 
+    #[test]
+    fn pistol_respects_weapon_table_fire_rate() {
+        let sprites = PlayerAttackSprites::load();
+        let mut state = PlayerAttackState::default();
+        let mut loadout = AttackLoadout::default();
+        commit_test_weapon(&mut loadout, &mut state, pistol());
+        let weapons = WeaponTable::default();
+        let cooldown = weapons.get("pistol").unwrap().cooldown_secs();
+        let map = corridor_map(None);
+
+        let mut shoot = |state: &mut PlayerAttackState, dt: f32| {
+            let mut input = AttackInput {
+                shoot_just_pressed: true,
+                shoot_held: true,
+                cursor_x: 80.0,
+                ..Default::default()
+            };
+            process_player_attacks(
+                &Camera::default(),
+                &map,
+                &sprites,
+                dt,
+                0.0,
+                &mut input,
+                &mut loadout,
+                state,
+                &mut [],
+                &mut [],
+                &mut [],
+                &mut Vec::new(),
+                &mut Vec::new(),
+                &mut Vec::new(),
+                144.0,
+                0.0,
+                &mut false,
+                &carcinisation_fps_core::BurnConfig::default(),
+                1.5,
+                2.0,
+                SnapTurnVisualInput::default(),
+                &weapons,
+            );
+            state.noise()
+        };
+
+        assert_eq!(shoot(&mut state, 1.0 / 60.0), Some(NoiseKind::Gunshot));
+        assert_eq!(
+            shoot(&mut state, cooldown * 0.5),
+            None,
+            "still cooling down"
+        );
+        assert_eq!(shoot(&mut state, cooldown * 0.6), Some(NoiseKind::Gunshot));
+    }
+
     #[test]
     fn gun_muzzle_flash_clears_on_weapon_switch() {
         let sprites = PlayerAttackSprites::load();
         let mut state = PlayerAttackState::default();
         let mut loadout = AttackLoadout::default();
-        commit_test_weapon(&mut loadout, &mut state, AttackId::Pistol);
+        commit_test_weapon(&mut loadout, &mut state, pistol());
 
         state.gun_muzzle_flash_elapsed = Some(0.05);
 
@@ -2351,6 +2605,8 @@ mod tests {
             width: 8,
             height: 8,
             cells: vec![0; 64],
+            mechanisms: carcinisation_fps_core::MapMechanisms::default(),
+            heights: Vec::new(),
         };
         let mut input = AttackInput {
             cycle_requested: true,
@@ -2368,7 +2624,6 @@ mod tests {
             &camera,
             &map,
             &sprites,
-            37,
             1.0 / 60.0,
             0.0,
             &mut input,
@@ -2387,10 +2642,10 @@ mod tests {
             1.5,
             2.0,
             SnapTurnVisualInput::default(),
-            &carcinisation_fps_core::EnemyReactionTuning::default(),
+            &WeaponTable::default(),
         );
 
-        assert_eq!(loadout.current(), AttackId::Flamethrower);
+        assert_eq!(loadout.current(), flamethrower());
         assert!(state.gun_muzzle_flash_elapsed.is_none());
     }
 
@@ -2404,6 +2659,8 @@ mod tests {
             width: 8,
             height: 8,
             cells: vec![0; 64],
+            mechanisms: carcinisation_fps_core::MapMechanisms::default(),
+            heights: Vec::new(),
         };
         let mut input = AttackInput {
             cycle_requested: true,
@@ -2423,7 +2680,6 @@ mod tests {
             &camera,
             &map,
             &sprites,
-            37,
             0.01,
             0.0,
             &mut input,
@@ -2442,18 +2698,17 @@ mod tests {
             1.5,
             2.0,
             SnapTurnVisualInput::default(),
-            &carcinisation_fps_core::EnemyReactionTuning::default(),
+            &WeaponTable::default(),
         );
 
-        assert_eq!(loadout.current(), AttackId::Flamethrower);
-        assert_eq!(state.weapon_switch_target, Some(AttackId::Pistol));
-        assert_eq!(state.weapon_presented, AttackId::Flamethrower);
+        assert_eq!(loadout.current(), flamethrower());
+        assert_eq!(state.weapon_switch_target, Some(pistol()));
+        assert_eq!(state.weapon_presented, flamethrower());
 
         process_player_attacks(
             &camera,
             &map,
             &sprites,
-            37,
             1.0,
             1.0,
             &mut input,
@@ -2472,11 +2727,11 @@ mod tests {
             1.5,
             2.0,
             SnapTurnVisualInput::default(),
-            &carcinisation_fps_core::EnemyReactionTuning::default(),
+            &WeaponTable::default(),
         );
 
-        assert_eq!(loadout.current(), AttackId::Pistol);
-        assert_eq!(state.weapon_presented, AttackId::Pistol);
+        assert_eq!(loadout.current(), pistol());
+        assert_eq!(state.weapon_presented, pistol());
     }
 
     #[test]
@@ -2489,6 +2744,8 @@ mod tests {
             width: 8,
             height: 8,
             cells: vec![0; 64],
+            mechanisms: carcinisation_fps_core::MapMechanisms::default(),
+            heights: Vec::new(),
         };
         let mut input = AttackInput {
             cursor_x: 80.0,
@@ -2501,12 +2758,11 @@ mod tests {
         let mut char_decals = Vec::new();
         let mut shoot = false;
 
-        state.request_weapon_switch_to(AttackId::Pistol);
+        state.request_weapon_switch_to(pistol());
         process_player_attacks(
             &camera,
             &map,
             &sprites,
-            37,
             0.01,
             0.0,
             &mut input,
@@ -2525,18 +2781,17 @@ mod tests {
             1.5,
             2.0,
             SnapTurnVisualInput::default(),
-            &carcinisation_fps_core::EnemyReactionTuning::default(),
+            &WeaponTable::default(),
         );
 
-        assert_eq!(loadout.current(), AttackId::Flamethrower);
-        assert_eq!(state.weapon_presented, AttackId::Flamethrower);
+        assert_eq!(loadout.current(), flamethrower());
+        assert_eq!(state.weapon_presented, flamethrower());
         assert!(state.has_pending_weapon_switch());
 
         process_player_attacks(
             &camera,
             &map,
             &sprites,
-            37,
             1.0,
             1.0,
             &mut input,
@@ -2555,11 +2810,11 @@ mod tests {
             1.5,
             2.0,
             SnapTurnVisualInput::default(),
-            &carcinisation_fps_core::EnemyReactionTuning::default(),
+            &WeaponTable::default(),
         );
 
-        assert_eq!(loadout.current(), AttackId::Pistol);
-        assert_eq!(state.weapon_presented, AttackId::Pistol);
+        assert_eq!(loadout.current(), pistol());
+        assert_eq!(state.weapon_presented, pistol());
         assert_eq!(state.weapon_phase, WeaponPresentationPhase::SwitchingIn);
         assert_eq!(state.weapon_pose_offset_px(), 20.0);
     }
@@ -2574,6 +2829,8 @@ mod tests {
             width: 8,
             height: 8,
             cells: vec![0; 64],
+            mechanisms: carcinisation_fps_core::MapMechanisms::default(),
+            heights: Vec::new(),
         };
         let mut input = AttackInput {
             shoot_just_pressed: true,
@@ -2588,12 +2845,11 @@ mod tests {
         let mut char_decals = Vec::new();
         let mut shoot = false;
 
-        state.request_weapon_switch_to(AttackId::Pistol);
+        state.request_weapon_switch_to(pistol());
         process_player_attacks(
             &camera,
             &map,
             &sprites,
-            37,
             0.01,
             0.0,
             &mut input,
@@ -2612,11 +2868,11 @@ mod tests {
             1.5,
             2.0,
             SnapTurnVisualInput::default(),
-            &carcinisation_fps_core::EnemyReactionTuning::default(),
+            &WeaponTable::default(),
         );
 
-        assert_eq!(loadout.current(), AttackId::Flamethrower);
-        assert_eq!(state.weapon_presented, AttackId::Flamethrower);
+        assert_eq!(loadout.current(), flamethrower());
+        assert_eq!(state.weapon_presented, flamethrower());
         assert!(state.flamethrower.is_some());
         assert!(state.gun_muzzle_flash_elapsed.is_none());
     }
@@ -2631,6 +2887,8 @@ mod tests {
             width: 8,
             height: 8,
             cells: vec![0; 64],
+            mechanisms: carcinisation_fps_core::MapMechanisms::default(),
+            heights: Vec::new(),
         };
         let mut input = AttackInput {
             cycle_requested: true,
@@ -2650,7 +2908,6 @@ mod tests {
             &camera,
             &map,
             &sprites,
-            37,
             0.01,
             0.0,
             &mut input,
@@ -2669,10 +2926,10 @@ mod tests {
             1.5,
             2.0,
             SnapTurnVisualInput::default(),
-            &carcinisation_fps_core::EnemyReactionTuning::default(),
+            &WeaponTable::default(),
         );
 
-        assert_eq!(loadout.current(), AttackId::Flamethrower);
+        assert_eq!(loadout.current(), flamethrower());
         assert_eq!(state.presented_weapon(loadout.current()), loadout.current());
         assert!(state.flamethrower.is_some());
         assert!(state.gun_muzzle_flash_elapsed.is_none());
@@ -2688,6 +2945,8 @@ mod tests {
             width: 8,
             height: 8,
             cells: vec![0; 64],
+            mechanisms: carcinisation_fps_core::MapMechanisms::default(),
+            heights: Vec::new(),
         };
         let mut input = AttackInput {
             cursor_x: 80.0,
@@ -2700,7 +2959,7 @@ mod tests {
         let mut char_decals = Vec::new();
         let mut shoot = false;
 
-        state.request_weapon_switch_to(AttackId::Pistol);
+        state.request_weapon_switch_to(pistol());
         let _ = update_weapon_presentation(
             &mut state,
             loadout.current(),
@@ -2715,17 +2974,16 @@ mod tests {
         );
         let lowered_progress = state.weapon_pose_offset_px();
 
-        state.sync_to_authoritative_weapon(loadout.current(), AttackId::Flamethrower);
+        state.sync_to_authoritative_weapon(loadout.current(), flamethrower());
 
         assert!(!state.has_pending_weapon_switch());
-        assert_eq!(state.weapon_presented, AttackId::Flamethrower);
+        assert_eq!(state.weapon_presented, flamethrower());
         assert_eq!(state.weapon_pose_offset_px(), lowered_progress);
 
         process_player_attacks(
             &camera,
             &map,
             &sprites,
-            37,
             1.0,
             1.0,
             &mut input,
@@ -2744,11 +3002,11 @@ mod tests {
             1.5,
             2.0,
             SnapTurnVisualInput::default(),
-            &carcinisation_fps_core::EnemyReactionTuning::default(),
+            &WeaponTable::default(),
         );
 
-        assert_eq!(loadout.current(), AttackId::Flamethrower);
-        assert_eq!(state.weapon_presented, AttackId::Flamethrower);
+        assert_eq!(loadout.current(), flamethrower());
+        assert_eq!(state.weapon_presented, flamethrower());
     }
 
     #[test]
@@ -2756,7 +3014,7 @@ mod tests {
         let mut state = PlayerAttackState::default();
         let loadout = AttackLoadout::default();
 
-        state.request_weapon_switch_to(AttackId::Pistol);
+        state.request_weapon_switch_to(pistol());
         let _ = update_weapon_presentation(
             &mut state,
             loadout.current(),
@@ -2772,9 +3030,9 @@ mod tests {
         let phase_before = state.weapon_phase;
         let progress_before = state.weapon_pose_offset_px();
 
-        state.sync_to_authoritative_weapon(loadout.current(), AttackId::Pistol);
+        state.sync_to_authoritative_weapon(loadout.current(), pistol());
 
-        assert_eq!(state.weapon_switch_target, Some(AttackId::Pistol));
+        assert_eq!(state.weapon_switch_target, Some(pistol()));
         assert_eq!(state.weapon_phase, phase_before);
         assert_eq!(state.weapon_pose_offset_px(), progress_before);
     }
@@ -2782,11 +3040,14 @@ mod tests {
     #[test]
     fn flamethrower_weapon_animation_follows_spawning_state() {
         let sprites = PlayerAttackSprites::load();
+        let view_model = sprites.view_model(flamethrower()).unwrap();
         let mut state = PlayerAttackState::default();
 
         // No flame → idle animation (1 frame).
         assert_eq!(
-            flamethrower_weapon_animation(&sprites, &state).frames.len(),
+            flamethrower_weapon_animation(view_model, &state)
+                .frames
+                .len(),
             1
         );
 
@@ -2808,7 +3069,9 @@ mod tests {
             last_decal_impact: None,
         });
         assert_eq!(
-            flamethrower_weapon_animation(&sprites, &state).frames.len(),
+            flamethrower_weapon_animation(view_model, &state)
+                .frames
+                .len(),
             1,
             "draining flame should use idle animation"
         );
@@ -2816,7 +3079,9 @@ mod tests {
         // Actively spawning → shooting animation (2 frames).
         state.flamethrower.as_mut().unwrap().spawning = true;
         assert_eq!(
-            flamethrower_weapon_animation(&sprites, &state).frames.len(),
+            flamethrower_weapon_animation(view_model, &state)
+                .frames
+                .len(),
             2,
             "spawning flame should use shooting animation"
         );
@@ -2827,7 +3092,11 @@ mod tests {
         let config = PlayerFlamethrower1pConfig::load();
         let sprites = PlayerAttackSprites::load();
         let idle_frame = sprites.idle_flame.frame_loop(0.0);
-        let weapon_frame = sprites.weapon_idle.frame_loop(0.0);
+        let weapon_frame = sprites
+            .view_model(flamethrower())
+            .unwrap()
+            .idle
+            .frame_loop(0.0);
 
         let flame_center_y = idle_frame.height() as f32 * config.idle_flame_scale * 0.5;
         let (ox, oy) = config.idle_flame_offset();
@@ -2905,7 +3174,7 @@ mod tests {
 
         let _ = update_weapon_presentation(
             &mut state,
-            AttackId::Flamethrower,
+            flamethrower(),
             true,
             20.0,
             false,
@@ -2926,7 +3195,7 @@ mod tests {
 
         let _ = update_weapon_presentation(
             &mut state,
-            AttackId::Flamethrower,
+            flamethrower(),
             false,
             20.0,
             false,
@@ -2947,7 +3216,7 @@ mod tests {
 
         let _ = update_weapon_presentation(
             &mut state,
-            AttackId::Flamethrower,
+            flamethrower(),
             false,
             20.0,
             false,
@@ -2962,7 +3231,7 @@ mod tests {
 
         let _ = update_weapon_presentation(
             &mut state,
-            AttackId::Flamethrower,
+            flamethrower(),
             false,
             20.0,
             false,
@@ -2982,7 +3251,7 @@ mod tests {
         assert_eq!(
             update_weapon_presentation(
                 &mut state,
-                AttackId::Pistol,
+                pistol(),
                 false,
                 20.0,
                 false,
@@ -2992,17 +3261,17 @@ mod tests {
                 0.0,
                 1.0,
             ),
-            Some(AttackId::Pistol)
+            Some(pistol())
         );
 
         assert_eq!(state.weapon_phase, WeaponPresentationPhase::SwitchingIn);
-        assert_eq!(state.weapon_presented, AttackId::Pistol);
+        assert_eq!(state.weapon_presented, pistol());
         assert_eq!(state.weapon_pose_offset_px(), 20.0);
 
         assert_eq!(
             update_weapon_presentation(
                 &mut state,
-                AttackId::Pistol,
+                pistol(),
                 false,
                 20.0,
                 false,
@@ -3026,7 +3295,7 @@ mod tests {
         assert_eq!(
             update_weapon_presentation(
                 &mut state,
-                AttackId::Pistol,
+                pistol(),
                 true,
                 20.0,
                 false,
@@ -3036,7 +3305,7 @@ mod tests {
                 0.0,
                 1.0,
             ),
-            Some(AttackId::Pistol)
+            Some(pistol())
         );
 
         assert_eq!(state.weapon_phase, WeaponPresentationPhase::SwitchingIn);
@@ -3044,7 +3313,7 @@ mod tests {
 
         let _ = update_weapon_presentation(
             &mut state,
-            AttackId::Pistol,
+            pistol(),
             true,
             20.0,
             false,
@@ -3082,7 +3351,6 @@ mod tests {
                 &camera,
                 &map,
                 &sprites,
-                37,
                 dt,
                 dt * i as f32,
                 &mut input,
@@ -3101,7 +3369,7 @@ mod tests {
                 1.5,
                 2.0,
                 SnapTurnVisualInput::default(),
-                &carcinisation_fps_core::EnemyReactionTuning::default(),
+                &WeaponTable::default(),
             );
         }
     }
@@ -3256,8 +3524,8 @@ mod tests {
         let mut state_pitched = PlayerAttackState::default();
         let mut loadout_flat = AttackLoadout::default();
         let mut loadout_pitched = AttackLoadout::default();
-        commit_test_weapon(&mut loadout_flat, &mut state_flat, AttackId::Pistol);
-        commit_test_weapon(&mut loadout_pitched, &mut state_pitched, AttackId::Pistol);
+        commit_test_weapon(&mut loadout_flat, &mut state_flat, pistol());
+        commit_test_weapon(&mut loadout_pitched, &mut state_pitched, pistol());
 
         // Place enemy directly in front of camera at (1.5, 1.5) facing east.
         let mut enemies_flat = vec![Enemy::new(Vec2::new(3.0, 1.5), 100, 1.0)];
@@ -3286,7 +3554,6 @@ mod tests {
             &flat_camera,
             &map,
             &sprites,
-            37,
             1.0 / 60.0,
             0.0,
             &mut input.clone(),
@@ -3305,7 +3572,7 @@ mod tests {
             1.5,
             2.0,
             SnapTurnVisualInput::default(),
-            &carcinisation_fps_core::EnemyReactionTuning::default(),
+            &WeaponTable::default(),
         );
 
         // Shoot pitched.
@@ -3313,7 +3580,6 @@ mod tests {
             &pitched_camera,
             &map,
             &sprites,
-            37,
             1.0 / 60.0,
             0.0,
             &mut input,
@@ -3332,7 +3598,7 @@ mod tests {
             1.5,
             2.0,
             SnapTurnVisualInput::default(),
-            &carcinisation_fps_core::EnemyReactionTuning::default(),
+            &WeaponTable::default(),
         );
 
         // Verify hitscan actually hit the enemy (prevent vacuous pass).
@@ -3485,6 +3751,8 @@ mod tests {
             width: 4,
             height: 3,
             cells: vec![0; 12],
+            mechanisms: carcinisation_fps_core::MapMechanisms::default(),
+            heights: Vec::new(),
         };
         map.cells[map.width + 2] = 1; // wall at (2,1)
         let target = Vec2::new(2.5, 1.5); // behind wall
@@ -3775,9 +4043,11 @@ mod tests {
             Vec2::new(3.5, 1.5),
             crate::mosquiton::MosquitonConfig::default(),
         )];
-        let tuning = carcinisation_fps_core::EnemyReactionTuning::default();
+        let weapons = WeaponTable::default();
+        let pistol = weapons.get("pistol").expect("stock pistol");
 
-        apply_hitscan_damage(
+        fire_hitscan_weapon(
+            pistol,
             fire_pose,
             &map,
             &mut [],
@@ -3785,16 +4055,13 @@ mod tests {
             &mut [],
             &mut Vec::new(),
             &mut Vec::new(),
-            37,
-            None,
-            &tuning.pistol,
         );
 
         let pending = mosquitons[0]
             .reaction
             .pending_next
             .expect("SP-local reaction queued after current sim tick");
-        assert_eq!(pending.poise_damage, tuning.pistol.poise_damage);
+        assert_eq!(pending.poise_damage, pistol.reaction.poise_damage);
         assert_eq!(pending.direction, Vec2::X, "knockback along shot direction");
     }
 
@@ -3836,7 +4103,8 @@ mod tests {
 
         let player = fire_pose.origin_xy;
         let x_before = mosquitons[0].position.x;
-        let _ = crate::mosquiton::tick_single_mosquiton(&mut mosquitons[0], player, &map, 0.1);
+        let _ =
+            crate::mosquiton::tick_single_mosquiton(&mut mosquitons[0], player, &map, None, 0.1);
         assert!(
             mosquitons[0].position.x < x_before,
             "current SP sim tick is not suppressed by a same-frame shot"
@@ -3847,7 +4115,8 @@ mod tests {
         );
 
         let x_after_first_tick = mosquitons[0].position.x;
-        let _ = crate::mosquiton::tick_single_mosquiton(&mut mosquitons[0], player, &map, 0.1);
+        let _ =
+            crate::mosquiton::tick_single_mosquiton(&mut mosquitons[0], player, &map, None, 0.1);
         assert!(mosquitons[0].reaction.is_stunned());
         assert_eq!(
             mosquitons[0].position.x, x_after_first_tick,
@@ -3963,7 +4232,6 @@ mod tests {
             &camera,
             &map,
             &sprites,
-            37,
             1.0,
             0.0,
             &mut input,
@@ -3982,7 +4250,7 @@ mod tests {
             1.5,
             2.0,
             SnapTurnVisualInput::default(),
-            &carcinisation_fps_core::EnemyReactionTuning::default(),
+            &WeaponTable::default(),
         );
 
         let positions: Vec<Vec2> = state.flame_world_positions().collect();
@@ -4016,7 +4284,6 @@ mod tests {
             &camera,
             &map,
             &sprites,
-            37,
             1.0,
             0.0,
             &mut input,
@@ -4035,7 +4302,7 @@ mod tests {
             1.5,
             2.0,
             SnapTurnVisualInput::default(),
-            &carcinisation_fps_core::EnemyReactionTuning::default(),
+            &WeaponTable::default(),
         );
 
         let positions: Vec<Vec2> = state.flame_world_positions().collect();
@@ -8,12 +8,15 @@ use std::marker::PhantomData;
 
 use bevy::{ecs::system::SystemParam, prelude::*};
 use carapace::prelude::*;
-use carcinisation_fps_core::ScreenParticleConfig;
 use carcinisation_fps_core::fire_death::perimeter_flames_from_mask;
 use carcinisation_fps_core::ground_fire::{
     GroundFire, GroundFireConfig, GroundFireContactState, ground_fire_contact_damage,
     ground_fire_flame_layout, tick_ground_fires, try_spawn_ground_fire,
 };
+use carcinisation_fps_core::{
+    EnemyNavigation, EnemyPerception, EnemyPlayerTarget, Noise, Perceiver, ScreenParticleConfig,
+    tick_patrol, tick_perception,
+};
 
 /// System set for First-person plugin systems. External input systems should run
 /// `.before(Systems)` so the First-person plugin reads updated state.
@@ -45,7 +48,7 @@ use crate::{
         draw_player_attack_overlays, flame_wall_mask, process_player_attacks, wall_impact_sprite,
     },
     render::{
-        CharDecal, FpWallRenderEffects, Palette, draw_crosshair, draw_overlay_tint,
+        CharDecal, FlatSurfaces, FpWallRenderEffects, Palette, draw_crosshair, draw_overlay_tint,
         render_fp_scene, render_fp_scene_with_effects,
     },
     screen_particles::{
@@ -85,8 +88,6 @@ pub struct Config {
     pub move_speed: f32,
     /// Player manual turn speed in radians per second.
     pub turn_speed: f32,
-    /// Damage dealt by the player's hitscan weapon per shot.
-    pub hitscan_damage: u32,
     /// Maximum player health points.
     pub player_max_health: u32,
     /// Duration of the 180° quick-turn animation in seconds.
@@ -142,7 +143,6 @@ impl Default for Config {
             screen_height: 144,
             move_speed: 2.0,
             turn_speed: 2.0,
-            hitscan_damage: 37,
             player_max_health: 100,
             quick_turn_duration_secs: 0.4,
             death_turn_duration_secs: 0.45,
@@ -171,12 +171,20 @@ pub struct SpriteHandle(pub Handle<CxSpriteAsset>);
 #[derive(Resource)]
 pub struct WallTextures(pub Vec<CxImage>);
 
+/// Per-cell floor and ceiling textures for the current map.
+#[derive(Resource, Default)]
+pub struct FlatSurfacesRes(pub FlatSurfaces);
+
 #[derive(Resource)]
 pub struct CameraRes(pub Camera);
 
 #[derive(Resource)]
 pub struct MapRes(pub Map);
 
+/// Enemy pathfinding state for the current map, refreshed every frame.
+#[derive(Resource, Default)]
+pub struct EnemyNavigationRes(pub EnemyNavigation);
+
 #[derive(Resource)]
 pub struct PaletteRes(pub Palette);
 
@@ -263,6 +271,7 @@ struct RenderSources<'w> {
 #[derive(SystemParam)]
 struct ViewResources<'w> {
     textures: Res<'w, WallTextures>,
+    flats: Res<'w, FlatSurfacesRes>,
     camera: Res<'w, CameraRes>,
     map: Res<'w, MapRes>,
     palette: Res<'w, PaletteRes>,
@@ -307,6 +316,14 @@ pub struct FpsViewSprite;
 #[derive(Resource, Default)]
 pub struct PlayerSpeedModifier(pub Option<carcinisation_fps_core::movement::SpeedModifier>);
 
+/// Whether the player is pressing forward this frame.
+///
+/// Set by the movement owner (`fps_test`). In `LocalAuthority` mode,
+/// `tick_map_mechanisms` turns it into a push against the cell ahead, which
+/// opens doors and presses wall switches.
+#[derive(Resource, Default)]
+pub struct PlayerPush(pub bool);
+
 /// Resolved FP player intent. Integration layers can build this from any input source.
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct PlayerIntent {
@@ -959,7 +976,9 @@ impl<L: CxLayer + Default> bevy::prelude::Plugin for FpsPlugin<L> {
         app.init_resource::<ExtraBillboards>();
         app.init_resource::<ShootRequest>();
         app.init_resource::<AttackInput>();
-        app.init_resource::<AttackLoadout>();
+        let weapons = carcinisation_fps_core::WeaponTable::load();
+        app.insert_resource(AttackLoadout::from_table(&weapons));
+        app.insert_resource(weapons);
         app.init_resource::<CharDecals>();
         app.init_resource::<BurningCorpseContactHazardState>();
         app.init_resource::<GroundFires>();
@@ -974,7 +993,9 @@ impl<L: CxLayer + Default> bevy::prelude::Plugin for FpsPlugin<L> {
         let flame_cfg = carcinisation_fps_core::PlayerFlamethrowerConfig::load();
         app.insert_resource(PlayerAttackState::new(flame_cfg));
         app.insert_resource(flame_cfg);
+        app.init_resource::<PlayerPush>();
         app.init_resource::<QuickTurnState>();
+        app.init_resource::<EnemyNavigationRes>();
         app.init_resource::<TurnChordState>();
         app.init_resource::<SelectActionTurnState>();
         app.init_resource::<DeathViewState>();
@@ -985,6 +1006,7 @@ impl<L: CxLayer + Default> bevy::prelude::Plugin for FpsPlugin<L> {
         #[cfg(feature = "hot_reload")]
         {
             carcinisation_core::watch_config!(app, "assets/config/attacks/player_flamethrower.ron");
+            carcinisation_core::watch_config!(app, "assets/config/fp/weapons.ron");
             carcinisation_core::watch_config!(app, "assets/config/status/burning.ron");
             carcinisation_core::watch_config!(app, "assets/config/fp/movement.ron");
             carcinisation_core::watch_config!(app, "assets/config/fp/combat.ron");
@@ -1018,6 +1040,13 @@ impl<L: CxLayer + Default> bevy::prelude::Plugin for FpsPlugin<L> {
                 reload_attack_sprites.in_set(Systems),
                 apply_quick_turn_animation.in_set(Systems),
                 handle_shooting.in_set(Systems),
+                tick_map_mechanisms.in_set(Systems).before(tick_enemy_ai),
+                (update_enemy_navigation, tick_enemy_perception)
+                    .chain()
+                    .in_set(Systems)
+                    .after(tick_map_mechanisms)
+                    .after(handle_shooting)
+                    .before(tick_enemy_ai),
                 tick_enemy_ai.in_set(Systems).after(handle_shooting),
                 apply_death_view.in_set(Systems),
                 tick_projectile_impact_effects.in_set(Systems),
@@ -1036,6 +1065,15 @@ impl<L: CxLayer + Default> bevy::prelude::Plugin for FpsPlugin<L> {
     }
 }
 
+/// Root that data-named files (sky layers, `.pxi` map textures) are
+/// resolved against: the working directory, i.e. the workspace root.
+fn workspace_root() -> String {
+    std::env::current_dir()
+        .unwrap_or_else(|e| panic!("failed to get current dir: {e}"))
+        .to_string_lossy()
+        .to_string()
+}
+
 /// Setup system: parses the map from `Config`, builds all resources.
 ///
 /// Input handling is NOT included — the caller (binary or game plugin)
@@ -1050,7 +1088,9 @@ fn setup_fp<L: CxLayer + Default>(
     let map = map_data.to_map();
     let camera = map_data.to_camera();
     let palette = map_data.to_palette();
-    let textures = map_data.build_wall_textures();
+    let workspace_root = workspace_root();
+    let textures = map_data.build_wall_textures(&workspace_root);
+    let flats = map_data.to_flat_surfaces(&workspace_root);
 
     let procedural_alive = make_enemy_sprite(24, 2);
     let procedural_death = make_death_sprite(24, 1);
@@ -1094,10 +1134,6 @@ fn setup_fp<L: CxLayer + Default>(
         .collect();
     let sky_ron = std::fs::read_to_string(&config.sky_path)
         .unwrap_or_else(|e| panic!("failed to read sky RON {}: {}", config.sky_path, e));
-    let workspace_root = std::env::current_dir()
-        .unwrap_or_else(|e| panic!("failed to get current dir: {e}"))
-        .to_string_lossy()
-        .to_string();
     let sky = Sky::from_ron(&sky_ron, &workspace_root);
     let mut image = CxImage::empty(UVec2::new(config.screen_width, config.screen_height));
     render_fp_scene(
@@ -1105,6 +1141,7 @@ fn setup_fp<L: CxLayer + Default>(
         &map,
         &camera,
         &textures,
+        &flats,
         &palette,
         &all_bbs,
         Some(&sky),
@@ -1126,6 +1163,7 @@ fn setup_fp<L: CxLayer + Default>(
 
     commands.insert_resource(SpriteHandle(handle));
     commands.insert_resource(WallTextures(textures));
+    commands.insert_resource(FlatSurfacesRes(flats));
     commands.insert_resource(CameraRes(camera));
     commands.insert_resource(MapRes(map));
     commands.insert_resource(PaletteRes(palette));
@@ -1195,7 +1233,11 @@ fn build_map_entity_setup(
                     setup.enemies.push(enemy);
                 }
             }
-            EntityKind::Mosquiton { health, speed } => {
+            EntityKind::Mosquiton {
+                health,
+                speed,
+                patrol,
+            } => {
                 if authority_mode.uses_local_combat() {
                     let config = MosquitonConfig {
                         health: *health,
@@ -1203,21 +1245,27 @@ fn build_map_entity_setup(
                         shoot_cue_secs: mosquiton_sprites.shoot_cue_elapsed_secs,
                         ..Default::default()
                     };
-                    let mosquiton = Mosquiton::new(pos, config);
+                    let mut mosquiton = Mosquiton::new(pos, config);
+                    mosquiton.perception = EnemyPerception::with_patrol(patrol);
                     setup
                         .initial_mosquiton_billboards
                         .push(billboard_from_mosquiton(&mosquiton, mosquiton_sprites));
                     setup.mosquitons.push(mosquiton);
                 }
             }
-            EntityKind::Spidey { health, speed } => {
+            EntityKind::Spidey {
+                health,
+                speed,
+                patrol,
+            } => {
                 if authority_mode.uses_local_combat() {
                     let config = SpideyConfig {
                         health: *health,
                         ..SpideyConfig::from_combat_config(combat_config)
                     }
                     .with_authored_speed(*speed);
-                    let spidey = Spidey::new(pos, config);
+                    let mut spidey = Spidey::new(pos, config);
+                    spidey.perception = EnemyPerception::with_patrol(patrol);
                     setup
                         .initial_spidey_billboards
                         .push(billboard_from_spidey(&spidey, spidey_sprites));
@@ -1322,11 +1370,190 @@ struct EnemyQueries<'w, 's> {
     spideys: Query<'w, 's, (Entity, &'static mut Spidey)>,
 }
 
+/// Run map doors, switches and triggers against the local player.
+///
+/// `LocalAuthority` only: multiplayer clients receive door state from the
+/// server. Doors do not close on the player or on enemies.
+#[allow(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+fn tick_map_mechanisms(
+    time: Res<Time>,
+    config: Res<Config>,
+    camera: Res<CameraRes>,
+    push: Res<PlayerPush>,
+    dead: Res<PlayerDead>,
+    mut map: ResMut<MapRes>,
+    enemies: Query<&Enemy>,
+    mosquitons: Query<&Mosquiton>,
+    spideys: Query<&Spidey>,
+) {
+    if !config.authority_mode.uses_local_combat() || map.0.mechanisms.doors.is_empty() {
+        return;
+    }
+
+    let player = camera.0.position;
+    let (players, pushes) = if dead.0 {
+        (Vec::new(), Vec::new())
+    } else if push.0 {
+        (
+            vec![player],
+            vec![carcinisation_fps_core::pushed_cell(player, camera.0.angle)],
+        )
+    } else {
+        (vec![player], Vec::new())
+    };
+    let occupied: Vec<(i32, i32)> = players
+        .iter()
+        .copied()
+        .chain(enemies.iter().map(|e| e.position))
+        .chain(mosquitons.iter().map(|m| m.position))
+        .chain(spideys.iter().map(|s| s.position))
+        .map(|p| (p.x.floor() as i32, p.y.floor() as i32))
+        .collect();
+
+    let mechanisms = &mut map.0.mechanisms;
+    mechanisms.apply_players(&players, &pushes);
+    mechanisms.tick(time.delta_secs(), |x, y| occupied.contains(&(x, y)));
+}
+
+/// Rebuild the navigation grid and the flow field toward the local player.
+///
+/// `LocalAuthority` only, like [`tick_enemy_ai`] which consumes it.
+#[allow(clippy::needless_pass_by_value)]
+fn update_enemy_navigation(
+    config: Res<Config>,
+    camera: Res<CameraRes>,
+    dead: Res<PlayerDead>,
+    map: Res<MapRes>,
+    mut navigation: ResMut<EnemyNavigationRes>,
+    enemies: Query<&Enemy>,
+    mosquitons: Query<&Mosquiton>,
+    spideys: Query<&Spidey>,
+) {
+    if !config.authority_mode.uses_local_combat() || dead.0 {
+        return;
+    }
+    let occupants: Vec<Vec2> = enemies
+        .iter()
+        .filter(|e| e.is_alive())
+        .map(|e| e.position)
+        .chain(
+            mosquitons
+                .iter()
+                .filter(|m| m.is_alive())
+                .map(|m| m.position),
+        )
+        .chain(spideys.iter().filter(|s| s.is_alive()).map(|s| s.position))
+        .collect();
+    navigation
+        .0
+        .update(&map.0, &occupants, &[camera.0.position]);
+}
+
+/// Update what local Mosquitons and Spideys know about the player, then walk
+/// the ones that aren't engaged along their patrols. Mirrors the server's
+/// perception pass so SP and MP enemies notice the player the same way.
+#[allow(clippy::too_many_arguments)]
+fn tick_enemy_perception(
+    time: Res<Time>,
+    config: Res<Config>,
+    camera: Res<CameraRes>,
+    dead: Res<PlayerDead>,
+    (map, navigation): (Res<MapRes>, Res<EnemyNavigationRes>),
+    combat: Res<carcinisation_fps_core::FpsCombatConfig>,
+    attack_state: Res<PlayerAttackState>,
+    mut mosquitons: Query<(Entity, &mut Mosquiton)>,
+    mut spideys: Query<(Entity, &mut Spidey)>,
+) {
+    if !config.authority_mode.uses_local_combat() {
+        return;
+    }
+    let dt = time.delta_secs();
+    let tuning = &combat.enemy_perception;
+    let position = camera.0.position;
+    let players = [EnemyPlayerTarget {
+        position,
+        alive: !dead.0,
+        id: 0,
+    }];
+    let noises: Vec<Noise> = attack_state
+        .noise()
+        .filter(|_| !dead.0)
+        .map(|kind| Noise { position, kind })
+        .into_iter()
+        .collect();
+
+    // Same order every frame so alert ties resolve deterministically.
+    let mut mosquitons: Vec<_> = mosquitons
+        .iter_mut()
+        .filter(|(_, m)| m.is_alive())
+        .collect();
+    mosquitons.sort_by_key(|(entity, _)| *entity);
+    let mut spideys: Vec<_> = spideys.iter_mut().filter(|(_, s)| s.is_alive()).collect();
+    spideys.sort_by_key(|(entity, _)| *entity);
+
+    let mut perceivers: Vec<Perceiver<'_>> = mosquitons
+        .iter_mut()
+        .map(|(_, m)| {
+            let m = &mut **m;
+            Perceiver {
+                position: m.position,
+                health: m.health as f32,
+                perception: &mut m.perception,
+            }
+        })
+        .chain(spideys.iter_mut().map(|(_, s)| {
+            let s = &mut **s;
+            Perceiver {
+                position: s.position,
+                health: s.health as f32,
+                perception: &mut s.perception,
+            }
+        }))
+        .collect();
+    tick_perception(&mut perceivers, &players, &noises, &map.0, tuning, dt);
+    drop(perceivers);
+
+    for (_, mosquiton) in &mut mosquitons {
+        if mosquiton.perception.is_engaged() {
+            continue;
+        }
+        let m = &mut **mosquiton;
+        m.velocity = tick_patrol(
+            &mut m.perception,
+            &mut m.position,
+            m.config.move_speed,
+            m.config.collision_radius,
+            &map.0,
+            Some(&navigation.0),
+            tuning,
+            dt,
+        );
+        m.animation_time += dt;
+    }
+    for (_, spidey) in &mut spideys {
+        if spidey.perception.is_engaged() {
+            continue;
+        }
+        let s = &mut **spidey;
+        s.velocity = tick_patrol(
+            &mut s.perception,
+            &mut s.position,
+            s.config.sim.move_speed,
+            s.config.sim.collision_radius,
+            &map.0,
+            Some(&navigation.0),
+            tuning,
+            dt,
+        );
+        s.animation_time += dt;
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn tick_enemy_ai(
     time: Res<Time>,
     camera: Res<CameraRes>,
-    map: Res<MapRes>,
+    (map, navigation): (Res<MapRes>, Res<EnemyNavigationRes>),
     mut enemies: EnemyQueries,
     mut projectiles: ResMut<Projectiles>,
     mut impacts: ResMut<ProjectileImpacts>,
@@ -1361,7 +1588,9 @@ fn tick_enemy_ai(
     // Tick enemies and collect dead entities for despawning.
     let mut dead_enemies = Vec::new();
     for (entity, mut enemy) in &mut enemies.enemies {
-        if let Some(proj) = tick_single_enemy(&mut enemy, player_pos, &map.0, dt) {
+        if let Some(proj) =
+            tick_single_enemy(&mut enemy, player_pos, &map.0, Some(&navigation.0), dt)
+        {
             projectiles.0.push(proj);
         }
 
@@ -1395,7 +1624,12 @@ fn tick_enemy_ai(
     // Tick mosquitons and collect dead entities for despawning.
     let mut dead_mosquitons = Vec::new();
     for (entity, mut mosquiton) in &mut enemies.mosquitons {
-        let (proj, dmg) = tick_single_mosquiton(&mut mosquiton, player_pos, &map.0, dt);
+        // Unaware enemies were moved by `tick_enemy_perception`.
+        let (proj, dmg) = if !mosquiton.is_alive() || mosquiton.perception.is_engaged() {
+            tick_single_mosquiton(&mut mosquiton, player_pos, &map.0, Some(&navigation.0), dt)
+        } else {
+            (None, None)
+        };
         if let Some(p) = proj {
             projectiles.0.push(p);
         }
@@ -1442,7 +1676,11 @@ fn tick_enemy_ai(
     // Tick spideys and collect dead entities for despawning.
     let mut dead_spideys = Vec::new();
     for (entity, mut spidey) in &mut enemies.spideys {
-        let (proj, dmg) = tick_single_spidey(&mut spidey, player_pos, &map.0, dt);
+        let (proj, dmg) = if !spidey.is_alive() || spidey.perception.is_engaged() {
+            tick_single_spidey(&mut spidey, player_pos, &map.0, Some(&navigation.0), dt)
+        } else {
+            (None, None)
+        };
         if let Some(p) = proj {
             projectiles.0.push(p);
         }
@@ -1646,14 +1884,16 @@ fn tick_burning_corpse_contact_damage(
 
 // ── Hot reload systems ────────────────────────────────────────────────────
 
-/// Reload `PlayerFlamethrowerConfig` from disk on Cmd+R.
+/// Reload `WeaponTable` and `PlayerFlamethrowerConfig` from disk on Cmd+R.
 ///
 /// Updates both the `Res<PlayerFlamethrowerConfig>` and the cached copy inside
 /// `PlayerAttackState` so gameplay systems see the new values immediately.
+/// An invalid weapon table is rejected and the previous one kept.
 #[cfg(feature = "hot_reload")]
 fn reload_flamethrower_config(
     events: Option<bevy::prelude::MessageReader<carcinisation_core::dev_reload::DevReloadRequest>>,
     mut flame_res: bevy::prelude::ResMut<carcinisation_fps_core::PlayerFlamethrowerConfig>,
+    mut weapons: bevy::prelude::ResMut<carcinisation_fps_core::WeaponTable>,
     mut attack_state: bevy::prelude::ResMut<PlayerAttackState>,
 ) {
     let Some(mut events) = events else { return };
@@ -1661,8 +1901,17 @@ fn reload_flamethrower_config(
     if events.read().count() == 0 {
         return;
     }
-    let reloaded: carcinisation_fps_core::PlayerFlamethrowerConfig =
+    let table: carcinisation_fps_core::WeaponTable =
+        carcinisation_core::ron_config!("assets/config/fp/weapons.ron");
+    match table.validate() {
+        Ok(()) => *weapons = table,
+        Err(err) => bevy::log::warn!("weapons.ron rejected ({err}), keeping previous table"),
+    }
+    let mut reloaded: carcinisation_fps_core::PlayerFlamethrowerConfig =
         carcinisation_core::ron_config!("assets/config/attacks/player_flamethrower.ron");
+    if let Some(flame) = weapons.flame_params() {
+        flame.apply_to(&mut reloaded);
+    }
     *flame_res = reloaded;
     attack_state.update_shared(reloaded);
     bevy::log::info!(
@@ -1733,6 +1982,7 @@ fn reload_map(
     events: Option<bevy::prelude::MessageReader<carcinisation_core::dev_reload::DevReloadRequest>>,
     config: bevy::prelude::Res<Config>,
     map_res: Option<bevy::prelude::ResMut<MapRes>>,
+    flats_res: Option<bevy::prelude::ResMut<FlatSurfacesRes>>,
 ) {
     let Some(mut events) = events else { return };
     if events.read().count() == 0 {
@@ -1763,6 +2013,9 @@ fn reload_map(
     if let Some(mut mr) = map_res {
         mr.0 = map_data.to_map();
     }
+    if let Some(mut fr) = flats_res {
+        fr.0 = map_data.to_flat_surfaces(&workspace_root());
+    }
     bevy::log::warn!(
         "Map reloaded from {} — geometry/collision rebuilt, entities NOT re-spawned",
         config.map_path
@@ -1983,6 +2236,7 @@ struct AttackResources<'w> {
     burn_config: Res<'w, carcinisation_fps_core::BurnConfig>,
     combat_config: Res<'w, carcinisation_fps_core::FpsCombatConfig>,
     visual_config: Res<'w, carcinisation_fps_core::FpsVisualConfig>,
+    weapons: Res<'w, carcinisation_fps_core::WeaponTable>,
 }
 
 const fn weapon_lowered_offset_px(config: &carcinisation_fps_core::FpsCombatConfig) -> f32 {
@@ -2039,7 +2293,6 @@ fn handle_shooting(
             &camera.0,
             &map.0,
             &attack.sprites,
-            config.hitscan_damage,
             time.delta_secs(),
             time.elapsed_secs(),
             &mut attack.input,
@@ -2062,7 +2315,7 @@ fn handle_shooting(
                 total: quick_turn.total_radians(),
                 direction: quick_turn.direction(),
             },
-            &attack.combat_config.enemy_reaction,
+            &attack.weapons,
         );
         return;
     }
@@ -2095,7 +2348,6 @@ fn handle_shooting(
         &camera.0,
         &map.0,
         &attack.sprites,
-        config.hitscan_damage,
         time.delta_secs(),
         time.elapsed_secs(),
         &mut attack.input,
@@ -2118,7 +2370,7 @@ fn handle_shooting(
             total: quick_turn.total_radians(),
             direction: quick_turn.direction(),
         },
-        &attack.combat_config.enemy_reaction,
+        &attack.weapons,
     );
 
     // Scatter: write back changes to entities.
@@ -2262,6 +2514,7 @@ fn update_fp_view(
         &view.map.0,
         &view.camera.0,
         &view.textures.0,
+        &view.flats.0,
         &view.palette.0,
         &all_bbs,
         &wall_effects,
@@ -3948,6 +4201,8 @@ mod tests {
             width: 8,
             height: 8,
             cells: vec![0; 64],
+            mechanisms: carcinisation_fps_core::MapMechanisms::default(),
+            heights: Vec::new(),
         };
         let mut input = AttackInput {
             shoot_just_pressed: true,
@@ -3970,7 +4225,6 @@ mod tests {
             &camera,
             &map,
             &sprites,
-            15,
             1.0 / 60.0,
             0.0,
             &mut input,
@@ -3989,7 +4243,7 @@ mod tests {
             1.5,
             2.0,
             SnapTurnVisualInput::default(),
-            &carcinisation_fps_core::EnemyReactionTuning::default(),
+            &carcinisation_fps_core::WeaponTable::default(),
         );
 
         let mut projectiles = vec![Projectile {
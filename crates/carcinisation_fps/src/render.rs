@@ -1,11 +1,13 @@
 //! Render orchestration: produces a full-frame [`CxImage`] from map + camera.
 
+use bevy_math::Vec2;
 use carapace::image::CxImage;
+use carcinisation_fps_core::heights::{CellHeights, EYE_HEIGHT};
 
 use crate::billboard::{Billboard, draw_billboard, project_billboard};
 use crate::camera::Camera;
 use crate::map::Map;
-use crate::raycast::{HitSide, WallSurfaceId, cast_ray};
+use crate::raycast::{HitSide, RayHit, WallSurfaceId, cast_ray, walk_ray};
 use crate::sky::Sky;
 
 /// 4x4 Bayer ordered-dither threshold matrix (values 0..15).
@@ -36,6 +38,36 @@ impl Default for Palette {
     }
 }
 
+/// Per-cell floor and ceiling textures, laid out like `Map::cells`.
+///
+/// The default has no cells: floors and ceilings are solid palette fills.
+#[derive(Default)]
+pub struct FlatSurfaces {
+    pub width: usize,
+    pub height: usize,
+    /// Floor texture id per cell. 0 = solid [`Palette::floor`], >0 = `textures[id - 1]`.
+    pub floor: Vec<u8>,
+    /// Ceiling texture id per cell. 0 = open ceiling: the sky when there is
+    /// one, else solid [`Palette::ceiling`].
+    pub ceiling: Vec<u8>,
+    pub textures: Vec<CxImage>,
+}
+
+impl FlatSurfaces {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.floor.is_empty() && self.ceiling.is_empty()
+    }
+
+    fn texture_at(&self, ids: &[u8], x: i32, y: i32) -> Option<&CxImage> {
+        if x < 0 || y < 0 || x >= self.width as i32 || y >= self.height as i32 {
+            return None;
+        }
+        let id = *ids.get(y as usize * self.width + x as usize)?;
+        self.textures.get(usize::from(id.checked_sub(1)?))
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct CharDecal {
     pub surface_id: WallSurfaceId,
@@ -111,28 +143,40 @@ fn apply_column_fog(
 ///
 /// `wall_textures` is indexed by `wall_id - 1` (`wall_id` 0 is empty).
 /// Image is cleared and fully redrawn.
-/// If `sky` is provided, escaped ray columns render the sky instead of a solid ceiling.
+/// If `sky` is provided, open-ceiling areas render the sky instead of a solid ceiling.
 pub fn render_fp_view(
     image: &mut CxImage,
     map: &Map,
     camera: &Camera,
     wall_textures: &[CxImage],
+    flats: &FlatSurfaces,
     palette: &Palette,
     sky: Option<&Sky>,
 ) {
-    render_walls(image, map, camera, wall_textures, palette, None, None, sky);
+    render_walls(
+        image,
+        map,
+        camera,
+        wall_textures,
+        flats,
+        palette,
+        None,
+        None,
+        sky,
+    );
 }
 
 /// Render walls + billboard entities into `image`.
 ///
 /// Billboards are depth-sorted and drawn back-to-front with per-column
 /// z-buffer occlusion against walls.
-/// If `sky` is provided, escaped ray columns render the sky instead of a solid ceiling.
+#[allow(clippy::too_many_arguments)]
 pub fn render_fp_scene(
     image: &mut CxImage,
     map: &Map,
     camera: &Camera,
     wall_textures: &[CxImage],
+    flats: &FlatSurfaces,
     palette: &Palette,
     billboards: &[Billboard],
     sky: Option<&Sky>,
@@ -150,6 +194,7 @@ pub fn render_fp_scene(
         map,
         camera,
         wall_textures,
+        flats,
         palette,
         billboards,
         &effects,
@@ -159,15 +204,16 @@ pub fn render_fp_scene(
 }
 
 /// Render walls with wall-anchored effects + billboard entities.
-/// If `sky` is provided, escaped ray columns render the sky instead of a solid ceiling.
 ///
 /// `zbuffer` is caller-owned to avoid per-frame allocation. It is resized and
 /// reset internally — the caller only needs to keep the `Vec` alive across frames.
+#[allow(clippy::too_many_arguments)]
 pub fn render_fp_scene_with_effects(
     image: &mut CxImage,
     map: &Map,
     camera: &Camera,
     wall_textures: &[CxImage],
+    flats: &FlatSurfaces,
     palette: &Palette,
     billboards: &[Billboard],
     effects: &FpWallRenderEffects<'_>,
@@ -184,6 +230,7 @@ pub fn render_fp_scene_with_effects(
         map,
         camera,
         wall_textures,
+        flats,
         palette,
         Some(zbuffer.as_mut_slice()),
         Some(effects),
@@ -223,12 +270,17 @@ pub fn render_fp_scene_with_effects(
 
 /// Internal wall rendering pass. Optionally writes per-column depth to `zbuffer`.
 /// If `sky` is provided, the ceiling area (above walls and in open columns)
-/// renders the sky instead of a solid ceiling color.
+/// renders the sky instead of a solid ceiling color; textured ceiling cells
+/// in `flats` are drawn over it.
+/// Maps with height overrides take the slower sector path, which draws
+/// partial columns for steps, low walls and windows.
+#[allow(clippy::too_many_arguments)]
 fn render_walls(
     image: &mut CxImage,
     map: &Map,
     camera: &Camera,
     wall_textures: &[CxImage],
+    flats: &FlatSurfaces,
     palette: &Palette,
     mut zbuffer: Option<&mut [f32]>,
     effects: Option<&FpWallRenderEffects<'_>>,
@@ -245,6 +297,9 @@ fn render_walls(
     let base_half_h = h / 2;
     let view_bob = camera.view_bob;
     let aim_pitch_px = camera.aim_pitch.round() as i32;
+    // Flats span every distance along a column, so they follow the pitched
+    // horizon only; a per-column bob would tear them at wall edges.
+    let flat_half_h = (base_half_h + aim_pitch_px).clamp(0, h);
     let yaw_offset = camera.angle / std::f32::consts::TAU;
 
     // Sky pitch scroll: ease-out quadratic so scroll slows near max look-up.
@@ -261,6 +316,16 @@ fn render_walls(
     // Scale to a fraction of the sky height (0.4 = scroll up to 40% of sky texture).
     let sky_pitch_v = eased * 0.4;
 
+    let sector = SectorView {
+        map,
+        origin: camera.position,
+        eye_z: map.eye_z(camera.position),
+        wall_textures,
+        flats,
+        palette,
+        effects,
+    };
+
     for x in 0..w {
         let camera_x = 2.0 * x as f32 / w as f32 - 1.0;
         let ray_dir = dir + plane * camera_x;
@@ -283,6 +348,20 @@ fn render_walls(
         let half_h =
             (base_half_h + (view_bob * bob_strength).round() as i32 + aim_pitch_px).clamp(0, h);
 
+        if !map.heights.is_empty() {
+            if let Some(sky_ref) = sky {
+                sky_ref.draw_column(image, x, half_h, palette.ceiling, yaw_offset, sky_pitch_v);
+            } else {
+                fill_column(image, x, 0, half_h, palette.ceiling);
+            }
+            fill_column(image, x, half_h, h, palette.floor);
+            let depth = draw_sector_column(image, x, ray_dir, half_h, &sector);
+            if let Some(ref mut zb) = zbuffer {
+                zb[x as usize] = depth;
+            }
+            continue;
+        }
+
         if hit.wall_id == 0 {
             if let Some(sky_ref) = sky {
                 sky_ref.draw_column(image, x, half_h, palette.ceiling, yaw_offset, sky_pitch_v);
@@ -290,6 +369,14 @@ fn render_walls(
                 fill_column(image, x, 0, half_h, palette.ceiling);
             }
             fill_column(image, x, half_h, h, palette.floor);
+            if !flats.is_empty() {
+                let ray = FlatRay {
+                    origin: camera.position,
+                    ray_dir,
+                    half_h: flat_half_h,
+                };
+                draw_flat_column(image, x, &ray, half_h, half_h, flats, palette);
+            }
             continue;
         }
 
@@ -323,6 +410,7 @@ fn render_walls(
                 x,
                 draw_start,
                 draw_end,
+                (0, h),
                 tex,
                 tex_x,
                 hit.side == HitSide::Horizontal,
@@ -335,6 +423,15 @@ fn render_walls(
         // Floor below wall.
         fill_column(image, x, draw_end.min(h), h, palette.floor);
 
+        if !flats.is_empty() {
+            let ray = FlatRay {
+                origin: camera.position,
+                ray_dir,
+                half_h: flat_half_h,
+            };
+            draw_flat_column(image, x, &ray, draw_start, draw_end, flats, palette);
+        }
+
         // Distance fog on the wall strip.
         if fog_range > 0.0 {
             let fog_t = ((hit.distance - palette.fog_start) / fog_range).clamp(0.0, 1.0);
@@ -343,6 +440,324 @@ fn render_walls(
     }
 }
 
+/// The ray one screen column casts onto the map, for floor casting.
+struct FlatRay {
+    origin: Vec2,
+    ray_dir: Vec2,
+    /// Horizon row, without view bob.
+    half_h: i32,
+}
+
+/// A run of screen rows showing one floor or ceiling plane.
+struct FlatRows {
+    rows: std::ops::Range<i32>,
+    /// Vertical distance from the eye to the plane, in wall units.
+    depth: f32,
+    ceiling: bool,
+}
+
+/// Floor-cast a column of a flat map: ceiling rows above `ceiling_end` and
+/// floor rows from `floor_start` down, both half a wall unit from the eye.
+fn draw_flat_column(
+    image: &mut CxImage,
+    x: i32,
+    ray: &FlatRay,
+    ceiling_end: i32,
+    floor_start: i32,
+    flats: &FlatSurfaces,
+    palette: &Palette,
+) {
+    let h = image.height() as i32;
+    let ceiling = FlatRows {
+        rows: 0..ceiling_end,
+        depth: EYE_HEIGHT,
+        ceiling: true,
+    };
+    draw_flat_rows(image, x, ray, &ceiling, flats, palette);
+    let floor = FlatRows {
+        rows: floor_start..h,
+        depth: EYE_HEIGHT,
+        ceiling: false,
+    };
+    draw_flat_rows(image, x, ray, &floor, flats, palette);
+}
+
+/// Floor-cast rows of one column: project each row back onto the map and
+/// draw the texture of the cell it lands on, with distance fog. Cells
+/// without a texture keep the solid fill (or sky) already drawn.
+fn draw_flat_rows(
+    image: &mut CxImage,
+    x: i32,
+    ray: &FlatRay,
+    plane: &FlatRows,
+    flats: &FlatSurfaces,
+    palette: &Palette,
+) {
+    let w = image.width() as i32;
+    let h = image.height() as i32;
+    if x < 0 || x >= w || plane.depth <= 0.0 {
+        return;
+    }
+    let fog_range = palette.fog_distance - palette.fog_start;
+    let ids = if plane.ceiling {
+        &flats.ceiling
+    } else {
+        &flats.floor
+    };
+    let data = image.data_mut();
+
+    for y in plane.rows.start.clamp(0, h)..plane.rows.end.clamp(0, h) {
+        // Rows from the horizon to this row's pixel center. A plane `depth`
+        // below (or above) the eye at perpendicular distance `d` sits
+        // `depth * h / d` rows from the horizon.
+        let rows = if plane.ceiling {
+            (ray.half_h - y) as f32 - 0.5
+        } else {
+            (y - ray.half_h) as f32 + 0.5
+        };
+        if rows <= 0.0 {
+            continue;
+        }
+        let distance = plane.depth * h as f32 / rows;
+        let world = ray.origin + ray.ray_dir * distance;
+        let cell_x = world.x.floor() as i32;
+        let cell_y = world.y.floor() as i32;
+        let Some(texture) = flats.texture_at(ids, cell_x, cell_y) else {
+            continue;
+        };
+
+        let tex_w = texture.width() as i32;
+        let tex_h = texture.height() as i32;
+        if tex_w <= 0 || tex_h <= 0 {
+            continue;
+        }
+        let tex_x = (((world.x - cell_x as f32) * tex_w as f32) as i32).clamp(0, tex_w - 1);
+        let tex_y = (((world.y - cell_y as f32) * tex_h as f32) as i32).clamp(0, tex_h - 1);
+        let pixel = texture.data()[(tex_y * tex_w + tex_x) as usize];
+        if pixel == 0 {
+            continue;
+        }
+
+        let fog_level = if fog_range > 0.0 {
+            (((distance - palette.fog_start) / fog_range).clamp(0.0, 1.0) * 16.0) as u8
+        } else {
+            0
+        };
+        let threshold = BAYER_4X4[(y & 3) as usize][(x & 3) as usize];
+        data[(y * w + x) as usize] = if fog_level > threshold {
+            palette.fog_color
+        } else {
+            pixel
+        };
+    }
+}
+
+/// Frame-wide inputs of the height-mapped wall pass.
+struct SectorView<'a> {
+    map: &'a Map,
+    origin: Vec2,
+    eye_z: f32,
+    wall_textures: &'a [CxImage],
+    flats: &'a FlatSurfaces,
+    palette: &'a Palette,
+    effects: Option<&'a FpWallRenderEffects<'a>>,
+}
+
+/// Draw one column of a map with height overrides, front to back.
+///
+/// Each cell the ray crosses shows its floor and ceiling, then the step or
+/// lintel faces where the next cell's span narrows. `top..bottom` tracks
+/// the rows still open; the column ends at the first solid cell or once
+/// nothing is left to see. The column must already hold the sky or ceiling
+/// fill above `half_h` and the floor fill below it.
+///
+/// Returns the depth billboards are occluded at.
+fn draw_sector_column(
+    image: &mut CxImage,
+    x: i32,
+    ray_dir: Vec2,
+    half_h: i32,
+    view: &SectorView<'_>,
+) -> f32 {
+    let h = image.height() as i32;
+    let eye_z = view.eye_z;
+    // Screen row of world height `z` at perpendicular distance `t`.
+    let project = |z: f32, t: f32| ((eye_z - z) * h as f32 / t + half_h as f32).round() as i32;
+    let ray = FlatRay {
+        origin: view.origin,
+        ray_dir,
+        half_h,
+    };
+    let flats = !view.flats.is_empty();
+
+    let mut cell = view
+        .map
+        .cell_heights(view.origin.x.floor() as i32, view.origin.y.floor() as i32);
+    if cell.is_solid() {
+        cell = CellHeights::OPEN;
+    }
+    let mut top = 0;
+    let mut bottom = h;
+    let mut depth = f32::MAX;
+
+    let stop = walk_ray(view.map, view.origin, ray_dir, |hit, _| {
+        let t = hit.distance;
+
+        // Floor and ceiling of the cell the ray is leaving.
+        let floor_y = project(cell.floor, t).clamp(top, bottom);
+        if flats && cell.floor < eye_z {
+            let plane = FlatRows {
+                rows: floor_y..bottom,
+                depth: eye_z - cell.floor,
+                ceiling: false,
+            };
+            draw_flat_rows(image, x, &ray, &plane, view.flats, view.palette);
+        }
+        bottom = floor_y;
+        let ceiling_y = project(cell.ceiling, t).clamp(top, bottom);
+        if flats && cell.ceiling > eye_z {
+            let plane = FlatRows {
+                rows: top..ceiling_y,
+                depth: cell.ceiling - eye_z,
+                ceiling: true,
+            };
+            draw_flat_rows(image, x, &ray, &plane, view.flats, view.palette);
+        }
+        top = ceiling_y;
+        if top >= bottom {
+            depth = t;
+            return true;
+        }
+
+        let Some(surface) = hit.surface_id else {
+            return true;
+        };
+        let next = view.map.cell_heights(surface.cell_x, surface.cell_y);
+        if next.is_solid() {
+            let low = eye_z - (bottom - half_h) as f32 * t / h as f32;
+            let high = eye_z + (half_h - top) as f32 * t / h as f32;
+            draw_sector_face(
+                image,
+                x,
+                hit,
+                next.texture,
+                (low, high),
+                (top, bottom),
+                view,
+                &project,
+            );
+            depth = t;
+            return true;
+        }
+        if next.floor > cell.floor {
+            let step_y = project(next.floor, t).clamp(top, bottom);
+            draw_sector_face(
+                image,
+                x,
+                hit,
+                next.texture,
+                (cell.floor, next.floor),
+                (step_y, bottom),
+                view,
+                &project,
+            );
+            bottom = step_y;
+        }
+        if next.ceiling < cell.ceiling {
+            let lintel_y = project(next.ceiling, t).clamp(top, bottom);
+            draw_sector_face(
+                image,
+                x,
+                hit,
+                next.texture,
+                (next.ceiling, cell.ceiling),
+                (top, lintel_y),
+                view,
+                &project,
+            );
+            top = lintel_y;
+        }
+        if top >= bottom {
+            depth = t;
+            return true;
+        }
+        cell = next;
+        false
+    });
+
+    if stop.is_none() && flats {
+        // Escaped: the last cell's planes run to the horizon.
+        let horizon = half_h.clamp(top, bottom);
+        let floor = FlatRows {
+            rows: horizon..bottom,
+            depth: eye_z - cell.floor,
+            ceiling: false,
+        };
+        draw_flat_rows(image, x, &ray, &floor, view.flats, view.palette);
+        let ceiling = FlatRows {
+            rows: top..horizon,
+            depth: cell.ceiling - eye_z,
+            ceiling: true,
+        };
+        draw_flat_rows(image, x, &ray, &ceiling, view.flats, view.palette);
+    }
+    depth
+}
+
+/// Draw the part of a wall face spanning heights `span` that falls in rows
+/// `clip`, tiling the texture once per wall unit, then fog it.
+#[allow(clippy::too_many_arguments)]
+fn draw_sector_face(
+    image: &mut CxImage,
+    x: i32,
+    hit: &RayHit,
+    texture_id: u8,
+    span: (f32, f32),
+    clip: (i32, i32),
+    view: &SectorView<'_>,
+    project: &impl Fn(f32, f32) -> i32,
+) {
+    if clip.0 >= clip.1 {
+        return;
+    }
+    let t = hit.distance;
+    if let Some(tex) = texture_id
+        .checked_sub(1)
+        .and_then(|i| view.wall_textures.get(usize::from(i)))
+    {
+        let tex_x = ((hit.wall_x * tex.width() as f32) as i32).clamp(0, tex.width() as i32 - 1);
+        let (low, high) = span;
+        for unit in (low.floor() as i32)..(high.ceil() as i32) {
+            let unit_low = unit as f32;
+            let unit_high = unit_low + 1.0;
+            let rows = (
+                project(high.min(unit_high), t).max(clip.0),
+                project(low.max(unit_low), t).min(clip.1),
+            );
+            draw_wall_column_textured(
+                image,
+                x,
+                project(unit_high, t),
+                project(unit_low, t),
+                rows,
+                tex,
+                tex_x,
+                hit.side == HitSide::Horizontal,
+                hit.surface_id,
+                hit.wall_x,
+                view.effects,
+            );
+        }
+    }
+
+    let palette = view.palette;
+    let fog_range = palette.fog_distance - palette.fog_start;
+    if fog_range > 0.0 {
+        let fog_t = ((t - palette.fog_start) / fog_range).clamp(0.0, 1.0);
+        apply_column_fog(image, x, clip.0, clip.1, palette.fog_color, fog_t);
+    }
+}
+
 /// Fill a vertical span of a single column with a solid palette index.
 fn fill_column(image: &mut CxImage, x: i32, y_start: i32, y_end: i32, color: u8) {
     let w = image.width() as i32;
@@ -415,11 +830,14 @@ pub fn draw_crosshair_offset(image: &mut CxImage, color: u8, y_offset: i32) {
 }
 
 #[allow(clippy::too_many_arguments)]
+/// Draw one textured wall strip spanning `y_start..y_end`, limited to rows
+/// `clip`.
 fn draw_wall_column_textured(
     image: &mut CxImage,
     x: i32,
     y_start: i32,
     y_end: i32,
+    clip: (i32, i32),
     texture: &CxImage,
     tex_x: i32,
     shaded: bool,
@@ -441,8 +859,8 @@ fn draw_wall_column_textured(
         return;
     }
 
-    let y_min = y_start.max(0);
-    let y_max = y_end.min(img_h);
+    let y_min = y_start.max(clip.0).max(0);
+    let y_max = y_end.min(clip.1).min(img_h);
     let data = image.data_mut();
     let tex_data = texture.data();
 
@@ -658,6 +1076,7 @@ mod tests {
             &map,
             &camera,
             &[tex.clone(), tex],
+            &FlatSurfaces::default(),
             &Palette::default(),
             None,
         );
@@ -666,6 +1085,154 @@ mod tests {
         assert!(image.data().iter().any(|&p| p != 0));
     }
 
+    fn flat_test_surfaces(floor_id: u8, ceiling_id: u8) -> FlatSurfaces {
+        FlatSurfaces {
+            width: 8,
+            height: 8,
+            floor: vec![floor_id; 64],
+            ceiling: vec![ceiling_id; 64],
+            textures: vec![CxImage::new(vec![7; 16], 4)],
+        }
+    }
+
+    /// Faces +X from the middle of the room; the nearest wall is 1.5 cells
+    /// away, so the top and bottom rows always show ceiling and floor.
+    fn flat_test_camera() -> Camera {
+        Camera {
+            position: Vec2::new(3.5, 5.5),
+            ..Default::default()
+        }
+    }
+
+    fn render_flats(flats: &FlatSurfaces, camera: &Camera) -> CxImage {
+        let map = test_map();
+        let tex = make_checker_texture(16, 4, 1, 2);
+        let mut image = CxImage::empty(UVec2::new(32, 24));
+        let palette = Palette {
+            fog_start: 100.0,
+            fog_distance: 200.0,
+            ..Default::default()
+        };
+        render_fp_view(
+            &mut image,
+            &map,
+            camera,
+            &[tex.clone(), tex],
+            flats,
+            &palette,
+            None,
+        );
+        image
+    }
+
+    #[test]
+    fn textured_floor_replaces_solid_floor_fill() {
+        let image = render_flats(&flat_test_surfaces(1, 0), &flat_test_camera());
+        let w = image.width();
+        let data = image.data();
+        let bottom = &data[data.len() - w..];
+        assert!(bottom.iter().all(|&p| p == 7), "bottom row: {bottom:?}");
+        // Ceiling id 0 is open: the solid ceiling fill stays.
+        assert!(data[..w].iter().all(|&p| p == Palette::default().ceiling));
+    }
+
+    #[test]
+    fn textured_ceiling_replaces_open_ceiling() {
+        let image = render_flats(&flat_test_surfaces(0, 1), &flat_test_camera());
+        let w = image.width();
+        let data = image.data();
+        assert!(data[..w].iter().all(|&p| p == 7));
+        let bottom = &data[data.len() - w..];
+        assert!(bottom.iter().all(|&p| p == Palette::default().floor));
+    }
+
+    fn low_wall_view(heights: &[carcinisation_fps_core::CellHeightSpec]) -> CxImage {
+        let mut map = test_map();
+        map.heights = carcinisation_fps_core::build_height_grid(&map, heights).unwrap();
+        let tex = make_checker_texture(16, 4, 5, 6);
+        let mut image = CxImage::empty(UVec2::new(32, 24));
+        let camera = Camera {
+            position: Vec2::new(1.5, 2.5),
+            ..Default::default()
+        };
+        render_fp_view(
+            &mut image,
+            &map,
+            &camera,
+            &[tex.clone(), tex],
+            &FlatSurfaces::default(),
+            &Palette::default(),
+            None,
+        );
+        image
+    }
+
+    #[test]
+    fn low_walls_draw_partial_columns() {
+        let low = |x| carcinisation_fps_core::CellHeightSpec {
+            x,
+            y: 2,
+            floor: 0.3,
+            ceiling: 1.0,
+            texture: 1,
+        };
+        // Interior walls (3,2) and (4,2) sit 1.5 cells ahead of the camera.
+        let full = low_wall_view(&[]);
+        let lowered = low_wall_view(&[low(3), low(4)]);
+        let at = |image: &CxImage, y: usize| image.data()[y * image.width() + 16];
+
+        assert!([5, 6].contains(&at(&full, 8)), "full wall fills row 8");
+        assert_eq!(
+            at(&lowered, 8),
+            Palette::default().ceiling,
+            "row 8 looks over the low wall"
+        );
+        assert!([5, 6].contains(&at(&lowered, 17)), "low wall face below");
+    }
+
+    #[test]
+    fn flats_ignore_view_bob() {
+        let mut flats = flat_test_surfaces(1, 0);
+        flats.textures = vec![make_checker_texture(4, 1, 7, 8)];
+        let still = render_flats(&flats, &flat_test_camera());
+        let bobbing = render_flats(
+            &flats,
+            &Camera {
+                view_bob: 2.0,
+                ..flat_test_camera()
+            },
+        );
+
+        let w = still.width();
+        let bottom = |image: &CxImage| image.data()[image.data().len() - w..].to_vec();
+        assert_eq!(bottom(&still), bottom(&bobbing));
+    }
+
+    #[test]
+    fn flat_texture_fogs_with_distance() {
+        let map = test_map();
+        let tex = make_checker_texture(16, 4, 1, 2);
+        let mut image = CxImage::empty(UVec2::new(32, 24));
+        let palette = Palette {
+            fog_color: 5,
+            fog_start: 0.0,
+            fog_distance: 0.5,
+            ..Default::default()
+        };
+        render_fp_view(
+            &mut image,
+            &map,
+            &flat_test_camera(),
+            &[tex.clone(), tex],
+            &flat_test_surfaces(1, 0),
+            &palette,
+            None,
+        );
+        let w = image.width();
+        let data = image.data();
+        assert!(data[data.len() - w..].iter().all(|&p| p == 5));
+    }
+
     #[test]
     fn crosshair_overlay_stays_camera_centered_when_world_pitch_changes() {
         let mut images = Vec::new();
@@ -820,6 +1387,7 @@ mod tests {
             &map,
             cam,
             &[tex.clone(), tex],
+            &FlatSurfaces::default(),
             &Palette::default(),
             None,
         );
@@ -982,6 +1550,7 @@ mod tests {
                 &map,
                 &cam,
                 &[tex.clone(), tex],
+                &FlatSurfaces::default(),
                 &Palette::default(),
                 None,
             );
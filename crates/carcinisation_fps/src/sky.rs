@@ -54,7 +54,7 @@ impl SkyLayer {
 }
 
 /// Decode a PXI file into width, height, and palette indices.
-fn decode_pxi(bytes: &[u8]) -> Result<(u32, u32, Vec<u8>), String> {
+pub(crate) fn decode_pxi(bytes: &[u8]) -> Result<(u32, u32, Vec<u8>), String> {
     const HEADER_SIZE: usize = 10;
     if bytes.len() < HEADER_SIZE {
         return Err(format!("PXI file too short: {} bytes", bytes.len()));
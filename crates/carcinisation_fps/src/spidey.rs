@@ -8,7 +8,7 @@ use bevy_math::Vec2;
 use carcinisation_fps_core::burning::BurnState;
 use carcinisation_fps_core::fire_death::DamageKind;
 
-use carcinisation_fps_core::FpsCombatConfig;
+use carcinisation_fps_core::{EnemyNavigation, FpsCombatConfig};
 
 use crate::camera::Camera;
 use crate::enemy::{DamageFlicker, Projectile};
@@ -118,6 +118,8 @@ pub struct Spidey {
     /// Hit-reaction runtime state (poise, stun, knockback) — round-tripped
     /// through the shared sim each tick, written by the hitscan damage path.
     pub reaction: carcinisation_fps_core::EnemyReactionState,
+    /// Sight, hearing and patrol state. The sim only runs while engaged.
+    pub perception: carcinisation_fps_core::EnemyPerception,
 }
 
 impl Spidey {
@@ -140,6 +142,7 @@ impl Spidey {
             burn_state: BurnState::default(),
             seed: carcinisation_fps_core::corpse_seed(position),
             reaction: carcinisation_fps_core::EnemyReactionState::default(),
+            perception: carcinisation_fps_core::EnemyPerception::default(),
         }
     }
 
@@ -259,6 +262,7 @@ pub fn tick_single_spidey(
     spidey: &mut Spidey,
     player_pos: Vec2,
     map: &Map,
+    nav: Option<&EnemyNavigation>,
     dt: f32,
 ) -> (Option<Projectile>, Option<(u32, Vec2)>) {
     use carcinisation_fps_core::spidey::{SpideySim, tick_spidey_sim};
@@ -287,7 +291,7 @@ pub fn tick_single_spidey(
         reaction: spidey.reaction,
     };
 
-    let output = tick_spidey_sim(&mut sim, &spidey.config.sim, player_pos, map, dt);
+    let output = tick_spidey_sim(&mut sim, &spidey.config.sim, player_pos, map, nav, dt);
 
     // Write back sim state.
     spidey.position = sim.position;
@@ -326,12 +330,13 @@ pub fn tick_spideys(
     spideys: &mut [Spidey],
     player_pos: Vec2,
     map: &Map,
+    nav: Option<&EnemyNavigation>,
     dt: f32,
 ) -> SpideyTickResult {
     let mut result = SpideyTickResult::default();
 
     for s in spideys.iter_mut() {
-        let (proj, dmg) = tick_single_spidey(s, player_pos, map, dt);
+        let (proj, dmg) = tick_single_spidey(s, player_pos, map, nav, dt);
         if let Some(p) = proj {
             result.projectiles.push(p);
         }
@@ -658,7 +663,7 @@ mod tests {
         let config = SpideyConfig::default();
         let mut ss = vec![Spidey::new(Vec2::new(2.0, 3.5), config)];
         let player = Vec2::new(3.0, 3.5);
-        let _ = tick_spideys(&mut ss, player, &map, 0.016);
+        let _ = tick_spideys(&mut ss, player, &map, None, 0.016);
         // Should have left idle (either hop wait, leap windup, or web windup).
         assert!(
             !matches!(ss[0].state, SpideyState::Idle),
@@ -683,7 +688,7 @@ mod tests {
         let map = test_map();
         let mut ss = vec![make_spidey(1.5, 1.5)];
         ss[0].state = SpideyState::Dying { timer: 0.1 };
-        let _ = tick_spideys(&mut ss, Vec2::ZERO, &map, 0.2);
+        let _ = tick_spideys(&mut ss, Vec2::ZERO, &map, None, 0.2);
         assert!(matches!(ss[0].state, SpideyState::Dead));
     }
 
@@ -731,7 +736,7 @@ mod tests {
         };
 
         // Tick past the cue.
-        let (proj, _) = tick_single_spidey(&mut spidey, player, &map, 0.2);
+        let (proj, _) = tick_single_spidey(&mut spidey, player, &map, None, 0.2);
         let proj = proj.expect("should spawn projectile past cue");
         assert!(
             matches!(
@@ -760,8 +765,8 @@ mod tests {
 
         // Tick both through several hop cycles.
         for _ in 0..200 {
-            let _ = tick_single_spidey(&mut a, player, &map, 0.016);
-            let _ = tick_single_spidey(&mut b, player, &map, 0.016);
+            let _ = tick_single_spidey(&mut a, player, &map, None, 0.016);
+            let _ = tick_single_spidey(&mut b, player, &map, None, 0.016);
         }
 
         // Seeds should have advanced from their initial values.
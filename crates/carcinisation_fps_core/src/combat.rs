@@ -258,6 +258,8 @@ mod tests {
                 1, 0, 0, 0, 0, 0, 0, 1, //
                 1, 1, 1, 1, 1, 1, 1, 1,
             ],
+            mechanisms: crate::mechanisms::MapMechanisms::default(),
+            heights: Vec::new(),
         };
         let origin = Vec2::new(1.5, 1.5);
         let dir = Vec2::new(1.0, 0.0);
@@ -276,6 +278,8 @@ mod tests {
                 1, 0, 1, 0, 1, //
                 1, 1, 1, 1, 1,
             ],
+            mechanisms: crate::mechanisms::MapMechanisms::default(),
+            heights: Vec::new(),
         };
         let distance = wall_obstruction_distance(&map, Vec2::new(1.5, 1.5), Vec2::X, 10.0);
         assert!((distance - 0.5).abs() < 0.001, "{distance}");
@@ -291,6 +295,8 @@ mod tests {
                 1, 0, 1, 0, 1, //
                 1, 1, 1, 1, 1,
             ],
+            mechanisms: crate::mechanisms::MapMechanisms::default(),
+            heights: Vec::new(),
         };
 
         let distance = wall_obstruction_distance(&map, Vec2::new(2.5, 1.5), Vec2::X, 10.0);
@@ -308,6 +314,8 @@ mod tests {
                 1, 0, 0, 1, 0, 1, //
                 1, 1, 1, 1, 1, 1,
             ],
+            mechanisms: crate::mechanisms::MapMechanisms::default(),
+            heights: Vec::new(),
         };
         let flat = FirePose2d::new(Vec2::new(1.5, 1.5), 0.0, 0.0);
         let pitched = FirePose2d::new(Vec2::new(1.5, 1.5), 0.0, 48.0);
@@ -71,8 +71,9 @@ impl Default for FpsMovementConfig {
 
 /// Shared flamethrower gameplay and stream tuning.
 ///
-/// Loaded from `assets/config/attacks/player_flamethrower.ron`.
-/// Used by both singleplayer and multiplayer (client + server).
+/// Loaded from `assets/config/attacks/player_flamethrower.ron`, with the
+/// flame-strip fields (`range` through `tick_ms`) taken from the flame weapon
+/// in `weapons.ron`. Used by both singleplayer and multiplayer (client + server).
 #[derive(
     Clone, Copy, Debug, serde::Deserialize, bevy::prelude::Resource, bevy::prelude::Reflect,
 )]
@@ -81,20 +82,26 @@ impl Default for FpsMovementConfig {
 pub struct PlayerFlamethrowerConfig {
     /// Maximum distance a flame can reach from the player (world units).
     /// Controls both the damage hitbox range and the visual stream lifetime.
+    #[serde(skip)]
     pub range: f32,
     /// Half-width of the flame damage line (world units).
     /// The server checks perpendicular distance from the flame centre-line;
     /// targets within this distance are considered hit.
+    #[serde(skip)]
     pub hit_half_width: f32,
     /// Travel speed of flame stream samples (world units per second).
     /// Shared between 1P and 3P visual rendering.
+    #[serde(skip)]
     pub speed: f32,
     /// Minimum interval between flame sample emissions (milliseconds).
     /// Lower values produce a denser stream. Shared between 1P and 3P.
+    #[serde(skip, default = "unset_interval_ms")]
     pub emit_interval_ms: NonZeroU64,
     /// Damage applied per flamethrower tick while a target is in the flame.
+    #[serde(skip)]
     pub damage_per_tick: u32,
     /// Interval between damage ticks (milliseconds).
+    #[serde(skip, default = "unset_interval_ms")]
     pub tick_ms: NonZeroU64,
     /// Maximum ammo pool. Ammo drains continuously while firing.
     pub max_ammo: f32,
@@ -125,7 +132,12 @@ pub struct PlayerFlamethrowerConfig {
 impl PlayerFlamethrowerConfig {
     #[must_use]
     pub fn load() -> Self {
-        carcinisation_core::ron_config!("assets/config/attacks/player_flamethrower.ron")
+        let mut config: Self =
+            carcinisation_core::ron_config!("assets/config/attacks/player_flamethrower.ron");
+        if let Some(flame) = crate::weapons::WeaponTable::load().flame_params() {
+            flame.apply_to(&mut config);
+        }
+        config
     }
 
     /// Maximum age of a flame sample before it expires (seconds).
@@ -153,6 +165,11 @@ impl PlayerFlamethrowerConfig {
     }
 }
 
+/// Placeholder for the flame-strip intervals until `weapons.ron` is applied.
+const fn unset_interval_ms() -> NonZeroU64 {
+    NonZeroU64::MIN
+}
+
 /// Spidey enemy combat tuning — extracted from [`FpsCombatConfig`] to reduce
 /// per-field serde default boilerplate.
 #[derive(Clone, Copy, Debug, serde::Deserialize, bevy::prelude::Reflect)]
@@ -258,11 +275,6 @@ impl Default for SpideyCombatConfig {
 #[reflect(Resource)]
 #[serde(rename = "FpsCombatConfig")]
 pub struct FpsCombatConfig {
-    // -- Pistol --
-    /// Damage per hitscan shot.
-    pub hitscan_damage: f32,
-    /// Minimum seconds between pistol shots.
-    pub fire_cooldown_secs: f32,
     // -- Flamethrower --
     /// Flamethrower damage per second (continuous while held).
     pub flame_dps: f32,
@@ -354,10 +366,19 @@ pub struct FpsCombatConfig {
     #[serde(default = "FpsCombatConfig::default_weapon_lowered_offset_px")]
     pub weapon_lowered_offset_px: f32,
     // -- Hit reactions --
-    /// Enemy poise/stun rules + per-weapon reaction profiles (Phase 11,
-    /// weapon-only). Defaults are provisional; override in `combat.ron`.
+    /// Enemy poise/stun rules (Phase 11). Per-weapon profiles live in
+    /// `weapons.ron`. Defaults are provisional; override in `combat.ron`.
     #[serde(default)]
     pub enemy_reaction: crate::reaction::EnemyReactionTuning,
+    // -- Perception --
+    /// Enemy sight, hearing and alert tuning.
+    #[serde(default)]
+    pub enemy_perception: crate::enemy::perception::PerceptionConfig,
+    // -- Lag compensation --
+    /// Furthest back (milliseconds) the server rewinds enemy poses when
+    /// resolving a multiplayer hitscan shot. Zero disables lag compensation.
+    #[serde(default = "FpsCombatConfig::default_lag_compensation_max_ms")]
+    pub lag_compensation_max_ms: u32,
 }
 
 /// Soft occupancy separation tuning.
@@ -417,6 +438,10 @@ impl FpsCombatConfig {
         20.0 // pixels downward when weapon is lowered
     }
 
+    const fn default_lag_compensation_max_ms() -> u32 {
+        200 // covers ~170 ms ping plus one interpolated snapshot
+    }
+
     /// Legacy alias — equivalent to `self.mosquiton_shoot_cooldown`.
     #[must_use]
     pub const fn mosquiton_attack_interval(&self) -> f32 {
@@ -492,8 +517,6 @@ impl FpsCombatConfig {
 impl Default for FpsCombatConfig {
     fn default() -> Self {
         Self {
-            hitscan_damage: 37.0,
-            fire_cooldown_secs: 0.33,
             flame_dps: 580.0,
             projectile_speed: 4.0,
             projectile_hit_radius: 0.3,
@@ -531,6 +554,8 @@ impl Default for FpsCombatConfig {
             aim_pitch_speed: Self::default_aim_pitch_speed(),
             weapon_lowered_offset_px: Self::default_weapon_lowered_offset_px(),
             enemy_reaction: crate::reaction::EnemyReactionTuning::default(),
+            enemy_perception: crate::enemy::perception::PerceptionConfig::default(),
+            lag_compensation_max_ms: Self::default_lag_compensation_max_ms(),
         }
     }
 }
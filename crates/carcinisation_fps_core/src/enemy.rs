@@ -9,8 +9,11 @@ use crate::camera::Camera;
 use crate::combat::{FirePose2d, wall_obstruction_distance_for_pose};
 use crate::fire_death::{DamageKind, corpse_seed};
 use crate::map::Map;
+use crate::pathfinding::{EnemyNavigation, chase_direction};
 use crate::raycast::{cast_ray, has_line_of_sight};
 
+pub mod perception;
+
 /// Headless FPS enemy kind.
 ///
 /// This lives in `carcinisation_fps_core` so single-player and server code can
@@ -112,11 +115,13 @@ pub enum EnemyAiDisposition {
 /// Initial Mosquiton behavior is intentionally small and portable:
 /// nearest alive target inside aggro range is faced; Mosquitons move toward
 /// that target until preferred range, then hold in an attacking-ready state.
-/// Dead enemies never move.
+/// Dead enemies never move. With `nav`, chasing routes around walls along
+/// the target's flow field (see [`chase_direction`]).
 pub fn tick_enemy_ai(
     enemy: &mut EnemySim,
     players: &[EnemyPlayerTarget],
     map: &Map,
+    nav: Option<&EnemyNavigation>,
     dt: f32,
     config: MosquitonAiConfig,
 ) -> EnemyAiOutput {
@@ -132,7 +137,7 @@ pub fn tick_enemy_ai(
         // Spidey uses its own dedicated sim (`tick_spidey_sim`), not this
         // shared AI dispatcher. Mark as unsupported here for safety.
         FpsEnemyKind::Basic | FpsEnemyKind::Spidey => tick_basic_enemy_ai(enemy),
-        FpsEnemyKind::Mosquiton => tick_mosquiton_ai(enemy, players, map, dt, config),
+        FpsEnemyKind::Mosquiton => tick_mosquiton_ai(enemy, players, map, nav, dt, config),
     }
 }
 
@@ -150,6 +155,7 @@ fn tick_mosquiton_ai(
     enemy: &mut EnemySim,
     players: &[EnemyPlayerTarget],
     map: &Map,
+    nav: Option<&EnemyNavigation>,
     dt: f32,
     config: MosquitonAiConfig,
 ) -> EnemyAiOutput {
@@ -203,11 +209,15 @@ fn tick_mosquiton_ai(
 
     enemy.state = FpsEnemyAiState::Chasing;
     let before = enemy.position;
-    let dir = target.position - enemy.position;
-    let len = dir.length();
     let mut attempted_step = Vec2::ZERO;
-    let desired_direction = (len > f32::EPSILON).then_some(dir / len);
-    if len > f32::EPSILON {
+    let desired_direction = chase_direction(
+        nav,
+        map,
+        enemy.position,
+        target.position,
+        config.collision_radius,
+    );
+    if let Some(dir) = desired_direction {
         let max_step = (distance - config.preferred_range).max(0.0);
         let step_len = (config.move_speed * dt).min(max_step);
         if step_len <= f32::EPSILON {
@@ -222,7 +232,7 @@ fn tick_mosquiton_ai(
                 blocked_by_collision: false,
             };
         }
-        attempted_step = dir / len * step_len;
+        attempted_step = dir * step_len;
         crate::collision::try_move(
             &mut enemy.position,
             attempted_step,
@@ -526,11 +536,13 @@ impl Enemy {
 }
 
 /// Tick a single enemy for one frame. Returns a newly spawned projectile if any.
+/// `nav` steers the chase around walls when given.
 #[must_use]
 pub fn tick_single_enemy(
     enemy: &mut Enemy,
     player_pos: Vec2,
     map: &Map,
+    nav: Option<&EnemyNavigation>,
     dt: f32,
 ) -> Option<Projectile> {
     if let Some(flicker) = enemy.damage_flicker {
@@ -562,8 +574,10 @@ pub fn tick_single_enemy(
                 enemy.state = EnemyState::Attacking {
                     cooldown: enemy.attack_interval,
                 };
-            } else if dist > 0.01 {
-                let move_dir = to_player / dist;
+            } else if dist > 0.01
+                && let Some(move_dir) =
+                    chase_direction(nav, map, enemy.position, player_pos, enemy.radius)
+            {
                 let step = move_dir * enemy.speed * dt;
                 try_move_enemy(enemy, step, map);
             }
@@ -598,11 +612,12 @@ pub fn tick_enemies(
     enemies: &mut [Enemy],
     player_pos: Vec2,
     map: &Map,
+    nav: Option<&EnemyNavigation>,
     dt: f32,
 ) -> Vec<Projectile> {
     enemies
         .iter_mut()
-        .filter_map(|e| tick_single_enemy(e, player_pos, map, dt))
+        .filter_map(|e| tick_single_enemy(e, player_pos, map, nav, dt))
         .collect()
 }
 
@@ -1166,7 +1181,7 @@ mod tests {
             ..Default::default()
         };
 
-        let output = tick_enemy_ai(&mut enemy, &[target_at(5.5, 1.5)], &map, 1.0, config);
+        let output = tick_enemy_ai(&mut enemy, &[target_at(5.5, 1.5)], &map, None, 1.0, config);
 
         assert!(output.moved);
         assert!(enemy.position.x > 1.5);
@@ -1185,7 +1200,7 @@ mod tests {
             ..Default::default()
         };
 
-        let output = tick_enemy_ai(&mut enemy, &[target_at(4.0, 1.5)], &map, 1.0, config);
+        let output = tick_enemy_ai(&mut enemy, &[target_at(4.0, 1.5)], &map, None, 1.0, config);
 
         assert!(!output.moved);
         assert_eq!(enemy.position, Vec2::new(1.5, 1.5));
@@ -1203,7 +1218,7 @@ mod tests {
             ..Default::default()
         };
 
-        let output = tick_enemy_ai(&mut enemy, &[target_at(4.5, 2.5)], &map, 1.0, config);
+        let output = tick_enemy_ai(&mut enemy, &[target_at(4.5, 2.5)], &map, None, 1.0, config);
 
         assert!(!output.moved);
         assert!(enemy.position.x < 3.0);
@@ -1224,6 +1239,8 @@ mod tests {
                 1, 0, 0, 0, 0, 1,
                 1, 1, 1, 1, 1, 1,
             ],
+            mechanisms: crate::mechanisms::MapMechanisms::default(),
+            heights: Vec::new(),
         };
         let mut enemy = make_mosquiton_sim(2.7, 2.5);
         let config = MosquitonAiConfig {
@@ -1233,7 +1250,7 @@ mod tests {
             ..Default::default()
         };
 
-        let output = tick_enemy_ai(&mut enemy, &[target_at(4.5, 4.5)], &map, 1.0, config);
+        let output = tick_enemy_ai(&mut enemy, &[target_at(4.5, 4.5)], &map, None, 1.0, config);
 
         assert!(output.moved);
         assert!(enemy.position.x < 3.0, "x should be blocked by wall");
@@ -1252,6 +1269,7 @@ mod tests {
             &mut enemy,
             &[target_at(5.5, 1.5)],
             &map,
+            None,
             1.0,
             MosquitonAiConfig::default(),
         );
@@ -1274,14 +1292,27 @@ mod tests {
 
         let mut chasing = make_mosquiton_sim(1.5, 1.5);
         chasing.state = FpsEnemyAiState::Chasing;
-        let chasing_output = tick_enemy_ai(&mut chasing, &[target_at(4.6, 1.5)], &map, 0.1, config);
+        let chasing_output = tick_enemy_ai(
+            &mut chasing,
+            &[target_at(4.6, 1.5)],
+            &map,
+            None,
+            0.1,
+            config,
+        );
         assert_eq!(chasing.state, FpsEnemyAiState::Chasing);
         assert!(chasing_output.moved);
 
         let mut attacking = make_mosquiton_sim(1.5, 1.5);
         attacking.state = FpsEnemyAiState::Attacking;
-        let attacking_output =
-            tick_enemy_ai(&mut attacking, &[target_at(4.6, 1.5)], &map, 0.1, config);
+        let attacking_output = tick_enemy_ai(
+            &mut attacking,
+            &[target_at(4.6, 1.5)],
+            &map,
+            None,
+            0.1,
+            config,
+        );
         assert_eq!(attacking.state, FpsEnemyAiState::Attacking);
         assert!(!attacking_output.moved);
     }
@@ -1299,7 +1330,7 @@ mod tests {
         let mut enemy = make_mosquiton_sim(1.5, 1.5);
         enemy.state = FpsEnemyAiState::Chasing;
 
-        let output = tick_enemy_ai(&mut enemy, &[target_at(4.5, 1.5)], &map, 1.0, config);
+        let output = tick_enemy_ai(&mut enemy, &[target_at(4.5, 1.5)], &map, None, 1.0, config);
 
         assert!(!output.moved);
         assert_eq!(enemy.state, FpsEnemyAiState::Attacking);
@@ -1322,7 +1353,7 @@ mod tests {
         let mut enemy = make_mosquiton_sim(1.5, 1.5);
         enemy.state = FpsEnemyAiState::Chasing;
 
-        let output = tick_enemy_ai(&mut enemy, &[target_at(4.6, 1.5)], &map, 0.0, config);
+        let output = tick_enemy_ai(&mut enemy, &[target_at(4.6, 1.5)], &map, None, 0.0, config);
 
         assert!(!output.moved);
         assert_eq!(enemy.state, FpsEnemyAiState::Attacking);
@@ -1347,6 +1378,7 @@ mod tests {
             &mut enemy,
             &[target_at(5.5, 1.5)],
             &map,
+            None,
             1.0,
             MosquitonAiConfig::default(),
         );
@@ -1421,7 +1453,7 @@ mod tests {
         let map = test_map();
         let mut enemies = vec![make_enemy(3.0, 1.5)];
         // Player close and in LOS.
-        let _ = tick_enemies(&mut enemies, Vec2::new(1.5, 1.5), &map, 0.016);
+        let _ = tick_enemies(&mut enemies, Vec2::new(1.5, 1.5), &map, None, 0.016);
         assert!(matches!(enemies[0].state, EnemyState::Chasing));
     }
 
@@ -1431,7 +1463,7 @@ mod tests {
         let mut enemies = vec![make_enemy(2.0, 1.5)];
         enemies[0].state = EnemyState::Chasing;
         // Player within attack range.
-        let _ = tick_enemies(&mut enemies, Vec2::new(1.5, 1.5), &map, 0.016);
+        let _ = tick_enemies(&mut enemies, Vec2::new(1.5, 1.5), &map, None, 0.016);
         assert!(matches!(enemies[0].state, EnemyState::Attacking { .. }));
     }
 
@@ -1441,7 +1473,7 @@ mod tests {
         let mut enemies = vec![make_enemy(1.5, 1.5)];
         enemies[0].state = EnemyState::Attacking { cooldown: 0.01 };
         // Player nearby but not at same position (avoids zero-direction filter).
-        let projectiles = tick_enemies(&mut enemies, Vec2::new(2.0, 1.5), &map, 0.02);
+        let projectiles = tick_enemies(&mut enemies, Vec2::new(2.0, 1.5), &map, None, 0.02);
         assert!(!projectiles.is_empty());
         assert!(projectiles[0].damage > 0);
     }
@@ -1451,7 +1483,7 @@ mod tests {
         let map = test_map();
         let mut enemies = vec![make_enemy(4.0, 4.0)];
         enemies[0].state = EnemyState::Dying { timer: 0.1 };
-        let _ = tick_enemies(&mut enemies, Vec2::new(1.5, 1.5), &map, 0.2);
+        let _ = tick_enemies(&mut enemies, Vec2::new(1.5, 1.5), &map, None, 0.2);
         assert!(matches!(enemies[0].state, EnemyState::Dead));
     }
 
@@ -1463,7 +1495,7 @@ mod tests {
             timer: 0.1,
             seed: 123,
         };
-        let projectiles = tick_enemies(&mut enemies, Vec2::new(2.0, 1.5), &map, 0.2);
+        let projectiles = tick_enemies(&mut enemies, Vec2::new(2.0, 1.5), &map, None, 0.2);
         assert!(projectiles.is_empty());
         assert!(matches!(enemies[0].state, EnemyState::Dead));
     }
@@ -1623,6 +1655,8 @@ mod tests {
             width: 3,
             height: 3,
             cells: vec![0; 9],
+            mechanisms: crate::mechanisms::MapMechanisms::default(),
+            heights: Vec::new(),
         };
         let mut proj = Projectile::new(Vec2::new(1.5, 1.5), Vec2::new(5.5, 1.5), 10).unwrap();
         proj.speed = 100.0;
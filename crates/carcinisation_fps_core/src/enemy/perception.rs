@@ -0,0 +1,574 @@
+//! Enemy perception: sight, hearing and alerts passed between enemies.
+//!
+//! Every Mosquiton and Spidey carries an [`EnemyPerception`]. Only engaged
+//! enemies run their combat sim; the others walk their authored patrol or
+//! search a position they heard or were told about ([`tick_patrol`]).
+//!
+//! [`tick_perception`] updates all enemies of a map in one pass from player
+//! positions, this tick's [`Noise`]s and the map. It has no hidden state, so
+//! the server and local simulation agree as long as they pass the same
+//! inputs in the same order (enemies sorted by a stable id).
+
+use std::collections::VecDeque;
+
+use bevy::reflect::Reflect;
+use bevy_math::Vec2;
+
+use super::EnemyPlayerTarget;
+use crate::collision::try_move;
+use crate::map::Map;
+use crate::pathfinding::{EnemyNavigation, chase_direction};
+use crate::raycast::has_line_of_sight;
+
+/// Perception tuning, part of `combat.ron`.
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, Reflect)]
+#[serde(default)]
+pub struct PerceptionConfig {
+    /// Players in line of sight within this distance engage an enemy.
+    pub sight_range: f32,
+    /// How far a gunshot carries, in cells walked through open space.
+    pub gunshot_radius: u32,
+    /// How far a burning flamethrower carries, in open cells.
+    pub flamethrower_radius: u32,
+    /// Enemies within this distance and in sight of an engaged one are
+    /// sent to investigate where it last saw a player.
+    pub alert_radius: f32,
+    /// Seconds an engaged enemy keeps fighting after losing sight of every
+    /// player, before searching where it saw one last.
+    pub memory_secs: f32,
+    /// Seconds spent searching a position before resuming patrol.
+    pub investigate_secs: f32,
+    /// Distance at which a waypoint or searched position counts as reached.
+    pub arrive_distance: f32,
+    /// Patrol walking speed as a fraction of chase speed.
+    pub patrol_speed_scale: f32,
+}
+
+impl Default for PerceptionConfig {
+    fn default() -> Self {
+        Self {
+            sight_range: 16.0,
+            gunshot_radius: 14,
+            flamethrower_radius: 6,
+            alert_radius: 5.0,
+            memory_secs: 6.0,
+            investigate_secs: 8.0,
+            arrive_distance: 0.3,
+            patrol_speed_scale: 0.5,
+        }
+    }
+}
+
+/// What made a noise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NoiseKind {
+    Gunshot,
+    Flamethrower,
+}
+
+/// A sound made this tick.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Noise {
+    pub position: Vec2,
+    pub kind: NoiseKind,
+}
+
+impl Noise {
+    const fn radius(self, config: &PerceptionConfig) -> u32 {
+        match self.kind {
+            NoiseKind::Gunshot => config.gunshot_radius,
+            NoiseKind::Flamethrower => config.flamethrower_radius,
+        }
+    }
+}
+
+/// How aware an enemy is of the players.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Awareness {
+    /// Walking its patrol route, or standing still without one.
+    #[default]
+    Unaware,
+    /// Heading to `point` after a noise or a neighbour's alert.
+    Investigating { point: Vec2, remaining: f32 },
+    /// Fighting. `remaining` counts down while no player is in sight.
+    Engaged { last_seen: Vec2, remaining: f32 },
+}
+
+/// Patrol waypoints `(x, y)` as authored on enemy spawns in map files,
+/// walked in a loop until the enemy notices a player. Turned into perception
+/// state by [`EnemyPerception::with_patrol`].
+pub type PatrolRoute = Vec<(f32, f32)>;
+
+/// Per-enemy perception state, persisted by the server component or the
+/// local enemy like the sim cooldowns.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EnemyPerception {
+    pub awareness: Awareness,
+    /// Authored waypoints, walked in a loop.
+    pub patrol: Vec<Vec2>,
+    pub next_waypoint: usize,
+    /// Health seen last tick; a drop engages the enemy.
+    pub last_health: Option<f32>,
+}
+
+impl EnemyPerception {
+    /// Unaware enemy walking `waypoints` from map spawn data.
+    #[must_use]
+    pub fn with_patrol(waypoints: &[(f32, f32)]) -> Self {
+        Self {
+            patrol: waypoints.iter().map(|&(x, y)| Vec2::new(x, y)).collect(),
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub const fn is_engaged(&self) -> bool {
+        matches!(self.awareness, Awareness::Engaged { .. })
+    }
+
+    /// Where a non-engaged enemy at `position` should walk, advancing past
+    /// reached waypoints. `None` to stand still.
+    fn destination(&mut self, position: Vec2, config: &PerceptionConfig) -> Option<Vec2> {
+        match self.awareness {
+            Awareness::Engaged { .. } => None,
+            Awareness::Investigating { point, .. } => {
+                (position.distance(point) > config.arrive_distance).then_some(point)
+            }
+            Awareness::Unaware => {
+                let count = self.patrol.len();
+                if count == 0 {
+                    return None;
+                }
+                self.next_waypoint %= count;
+                if position.distance(self.patrol[self.next_waypoint]) <= config.arrive_distance {
+                    self.next_waypoint = (self.next_waypoint + 1) % count;
+                }
+                Some(self.patrol[self.next_waypoint])
+            }
+        }
+    }
+}
+
+/// One living enemy's input to [`tick_perception`].
+pub struct Perceiver<'a> {
+    pub position: Vec2,
+    pub health: f32,
+    pub perception: &'a mut EnemyPerception,
+}
+
+/// Update every living enemy's awareness for one tick.
+///
+/// In order, an enemy engages when it sees a living player within
+/// `sight_range` or was hurt since last tick; otherwise it investigates the
+/// nearest noise that reached its cell through open cells. Afterwards each
+/// enemy not engaged investigates the last sighting of the nearest engaged
+/// enemy it can see within `alert_radius`, so alerts spread one hop per tick.
+pub fn tick_perception(
+    enemies: &mut [Perceiver<'_>],
+    players: &[EnemyPlayerTarget],
+    noises: &[Noise],
+    map: &Map,
+    config: &PerceptionConfig,
+    dt: f32,
+) {
+    let reaches: Vec<Vec<u32>> = noises
+        .iter()
+        .map(|noise| noise_reach(map, noise.position, noise.radius(config)))
+        .collect();
+
+    for enemy in enemies.iter_mut() {
+        let hurt = enemy
+            .perception
+            .last_health
+            .is_some_and(|last| enemy.health < last);
+        enemy.perception.last_health = Some(enemy.health);
+
+        let position = enemy.position;
+        let seen = nearest_player(position, players, |p| {
+            position.distance(p.position) <= config.sight_range
+                && has_line_of_sight(position, p.position, map)
+        });
+        let engaged_by = if seen.is_some() || !hurt {
+            seen
+        } else {
+            nearest_player(position, players, |_| true)
+        };
+        if let Some(last_seen) = engaged_by {
+            enemy.perception.awareness = Awareness::Engaged {
+                last_seen,
+                remaining: config.memory_secs,
+            };
+            continue;
+        }
+
+        let awareness = &mut enemy.perception.awareness;
+        if let Awareness::Engaged {
+            last_seen,
+            remaining,
+        } = awareness
+        {
+            *remaining -= dt;
+            if *remaining <= 0.0 {
+                *awareness = Awareness::Investigating {
+                    point: *last_seen,
+                    remaining: config.investigate_secs,
+                };
+            }
+            continue;
+        }
+
+        let cell = map_index(map, enemy.position);
+        let heard = cell.and_then(|cell| {
+            noises
+                .iter()
+                .zip(&reaches)
+                .filter(|(_, reach)| reach[cell] != u32::MAX)
+                .min_by_key(|(_, reach)| reach[cell])
+                .map(|(noise, _)| noise.position)
+        });
+        if let Some(point) = heard {
+            *awareness = Awareness::Investigating {
+                point,
+                remaining: config.investigate_secs,
+            };
+        } else if let Awareness::Investigating { remaining, .. } = awareness {
+            *remaining -= dt;
+            if *remaining <= 0.0 {
+                *awareness = Awareness::Unaware;
+            }
+        }
+    }
+
+    // Alerts read this tick's engagements, not each other's, so the result
+    // is independent of processing order beyond ties.
+    let engaged: Vec<(Vec2, Vec2)> = enemies
+        .iter()
+        .filter_map(|e| match e.perception.awareness {
+            Awareness::Engaged { last_seen, .. } => Some((e.position, last_seen)),
+            _ => None,
+        })
+        .collect();
+    for enemy in enemies.iter_mut() {
+        if enemy.perception.is_engaged() {
+            continue;
+        }
+        let alert = engaged
+            .iter()
+            .filter(|(position, _)| {
+                enemy.position.distance(*position) <= config.alert_radius
+                    && has_line_of_sight(enemy.position, *position, map)
+            })
+            .min_by(|a, b| {
+                enemy
+                    .position
+                    .distance_squared(a.0)
+                    .total_cmp(&enemy.position.distance_squared(b.0))
+            });
+        if let Some(&(_, last_seen)) = alert {
+            enemy.perception.awareness = Awareness::Investigating {
+                point: last_seen,
+                remaining: config.investigate_secs,
+            };
+        }
+    }
+}
+
+/// Move a non-engaged enemy along its patrol or toward the position it is
+/// investigating. `speed` is its chase speed. Returns the velocity.
+#[allow(clippy::too_many_arguments)]
+pub fn tick_patrol(
+    perception: &mut EnemyPerception,
+    position: &mut Vec2,
+    speed: f32,
+    radius: f32,
+    map: &Map,
+    nav: Option<&EnemyNavigation>,
+    config: &PerceptionConfig,
+    dt: f32,
+) -> Vec2 {
+    let Some(target) = perception.destination(*position, config) else {
+        return Vec2::ZERO;
+    };
+    let Some(dir) = chase_direction(nav, map, *position, target, radius) else {
+        return Vec2::ZERO;
+    };
+    let speed = if matches!(perception.awareness, Awareness::Unaware) {
+        speed * config.patrol_speed_scale
+    } else {
+        speed
+    };
+    let before = *position;
+    let step = dir * (speed * dt).min(position.distance(target));
+    try_move(position, step, radius, map);
+    (*position - before) / dt.max(f32::EPSILON)
+}
+
+fn nearest_player(
+    position: Vec2,
+    players: &[EnemyPlayerTarget],
+    filter: impl Fn(&EnemyPlayerTarget) -> bool,
+) -> Option<Vec2> {
+    players
+        .iter()
+        .filter(|p| p.alive && filter(p))
+        .min_by(|a, b| {
+            position
+                .distance_squared(a.position)
+                .total_cmp(&position.distance_squared(b.position))
+                .then(a.id.cmp(&b.id))
+        })
+        .map(|p| p.position)
+}
+
+fn map_index(map: &Map, position: Vec2) -> Option<usize> {
+    let (x, y) = (position.x.floor() as i32, position.y.floor() as i32);
+    (x >= 0 && y >= 0 && (x as usize) < map.width && (y as usize) < map.height)
+        .then(|| y as usize * map.width + x as usize)
+}
+
+/// Steps from `origin` to every cell within `radius` through open cells
+/// (4-connected, so sound doesn't leak through wall corners). Closed doors
+/// block. Cells out of earshot hold `u32::MAX`.
+fn noise_reach(map: &Map, origin: Vec2, radius: u32) -> Vec<u32> {
+    let mut steps = vec![u32::MAX; map.width * map.height];
+    let Some(start) = map_index(map, origin) else {
+        return steps;
+    };
+    steps[start] = 0;
+    let mut queue = VecDeque::from([start]);
+    while let Some(index) = queue.pop_front() {
+        let distance = steps[index];
+        if distance >= radius {
+            continue;
+        }
+        let (x, y) = ((index % map.width) as i32, (index / map.width) as i32);
+        for (dx, dy) in [(1, 0), (0, 1), (-1, 0), (0, -1)] {
+            let (nx, ny) = (x + dx, y + dy);
+            if map.get(nx, ny) != 0 {
+                continue;
+            }
+            let next = ny as usize * map.width + nx as usize;
+            if steps[next] == u32::MAX {
+                steps[next] = distance + 1;
+                queue.push_back(next);
+            }
+        }
+    }
+    steps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map::test_map;
+
+    fn player_at(x: f32, y: f32) -> EnemyPlayerTarget {
+        EnemyPlayerTarget {
+            position: Vec2::new(x, y),
+            alive: true,
+            id: 1,
+        }
+    }
+
+    fn tick(
+        enemies: &mut [(Vec2, EnemyPerception)],
+        players: &[EnemyPlayerTarget],
+        noises: &[Noise],
+        map: &Map,
+    ) {
+        let mut perceivers: Vec<Perceiver<'_>> = enemies
+            .iter_mut()
+            .map(|(position, perception)| Perceiver {
+                position: *position,
+                health: 10.0,
+                perception,
+            })
+            .collect();
+        tick_perception(
+            &mut perceivers,
+            players,
+            noises,
+            map,
+            &PerceptionConfig::default(),
+            0.1,
+        );
+    }
+
+    #[test]
+    fn sight_engages_and_walls_hide() {
+        let map = test_map();
+        // (3, 2) and (4, 2) are walls between these two.
+        let mut enemies = [(Vec2::new(3.5, 1.5), EnemyPerception::default())];
+        tick(&mut enemies, &[player_at(3.5, 3.5)], &[], &map);
+        assert_eq!(enemies[0].1.awareness, Awareness::Unaware);
+
+        tick(&mut enemies, &[player_at(5.5, 1.5)], &[], &map);
+        assert!(enemies[0].1.is_engaged());
+    }
+
+    #[test]
+    fn noise_travels_through_open_cells_only() {
+        #[rustfmt::skip]
+        let cells = vec![
+            1, 1, 1, 1, 1, 1, 1,
+            1, 0, 0, 1, 0, 0, 1,
+            1, 0, 0, 1, 0, 0, 1,
+            1, 1, 1, 1, 1, 1, 1,
+        ];
+        let map = Map {
+            width: 7,
+            height: 4,
+            cells,
+            mechanisms: crate::mechanisms::MapMechanisms::default(),
+            heights: Vec::new(),
+        };
+        let shot = Noise {
+            position: Vec2::new(1.5, 1.5),
+            kind: NoiseKind::Gunshot,
+        };
+        // Sealed room: the shot never reaches the other side.
+        let mut enemies = [
+            (Vec2::new(2.5, 2.5), EnemyPerception::default()),
+            (Vec2::new(4.5, 1.5), EnemyPerception::default()),
+        ];
+        tick(&mut enemies, &[], &[shot], &map);
+        assert_eq!(
+            enemies[0].1.awareness,
+            Awareness::Investigating {
+                point: shot.position,
+                remaining: PerceptionConfig::default().investigate_secs,
+            }
+        );
+        assert_eq!(enemies[1].1.awareness, Awareness::Unaware);
+
+        // Reach stops at the noise radius.
+        let reach = noise_reach(&map, shot.position, 1);
+        assert_ne!(
+            reach[map_index(&map, Vec2::new(2.5, 1.5)).unwrap()],
+            u32::MAX
+        );
+        assert_eq!(
+            reach[map_index(&map, Vec2::new(2.5, 2.5)).unwrap()],
+            u32::MAX
+        );
+    }
+
+    #[test]
+    fn alerts_spread_to_visible_neighbours() {
+        let map = test_map();
+        let players = [player_at(1.5, 6.5)];
+        let mut enemies = [
+            // Sees the player down the west column.
+            (Vec2::new(1.5, 3.5), EnemyPerception::default()),
+            // Out of sight range of the player, but sees its neighbour.
+            (Vec2::new(3.5, 3.5), EnemyPerception::default()),
+            // Too far from the engaged enemy.
+            (Vec2::new(6.5, 1.5), EnemyPerception::default()),
+        ];
+        let mut config = PerceptionConfig::default();
+        config.sight_range = 3.5;
+        let mut perceivers: Vec<Perceiver<'_>> = enemies
+            .iter_mut()
+            .map(|(position, perception)| Perceiver {
+                position: *position,
+                health: 10.0,
+                perception,
+            })
+            .collect();
+        tick_perception(&mut perceivers, &players, &[], &map, &config, 0.1);
+        drop(perceivers);
+
+        assert!(enemies[0].1.is_engaged());
+        assert!(matches!(
+            enemies[1].1.awareness,
+            Awareness::Investigating { point, .. } if point == players[0].position
+        ));
+        assert_eq!(enemies[2].1.awareness, Awareness::Unaware);
+    }
+
+    #[test]
+    fn hurt_enemies_engage_and_lost_targets_are_searched() {
+        let map = test_map();
+        let mut enemies = [(Vec2::new(3.5, 1.5), EnemyPerception::default())];
+        let hidden = [player_at(3.5, 3.5)];
+        tick(&mut enemies, &hidden, &[], &map);
+        assert!(!enemies[0].1.is_engaged());
+
+        let mut perceivers = [Perceiver {
+            position: enemies[0].0,
+            health: 5.0,
+            perception: &mut enemies[0].1,
+        }];
+        let config = PerceptionConfig {
+            memory_secs: 0.15,
+            ..PerceptionConfig::default()
+        };
+        tick_perception(&mut perceivers, &hidden, &[], &map, &config, 0.1);
+        assert!(perceivers[0].perception.is_engaged(), "hit from hiding");
+
+        for _ in 0..2 {
+            tick_perception(&mut perceivers, &hidden, &[], &map, &config, 0.1);
+        }
+        assert!(matches!(
+            perceivers[0].perception.awareness,
+            Awareness::Investigating { point, .. } if point == hidden[0].position
+        ));
+    }
+
+    #[test]
+    fn patrol_loops_through_waypoints() {
+        let map = test_map();
+        let mut perception = EnemyPerception::with_patrol(&[(1.5, 1.5), (1.5, 5.5)]);
+        let mut position = Vec2::new(1.5, 1.5);
+        let config = PerceptionConfig::default();
+        let velocity = tick_patrol(
+            &mut perception,
+            &mut position,
+            2.0,
+            0.3,
+            &map,
+            None,
+            &config,
+            0.1,
+        );
+        assert_eq!(
+            perception.next_waypoint, 1,
+            "first waypoint already reached"
+        );
+        assert!(velocity.y > 0.0 && (velocity.length() - 1.0).abs() < 1e-4);
+
+        for _ in 0..50 {
+            tick_patrol(
+                &mut perception,
+                &mut position,
+                2.0,
+                0.3,
+                &map,
+                None,
+                &config,
+                0.1,
+            );
+        }
+        assert_eq!(perception.next_waypoint, 0, "turned back at the last one");
+
+        perception.awareness = Awareness::Engaged {
+            last_seen: Vec2::ZERO,
+            remaining: 1.0,
+        };
+        let before = position;
+        tick_patrol(
+            &mut perception,
+            &mut position,
+            2.0,
+            0.3,
+            &map,
+            None,
+            &config,
+            0.1,
+        );
+        assert_eq!(
+            position, before,
+            "engaged enemies leave movement to their sim"
+        );
+    }
+}
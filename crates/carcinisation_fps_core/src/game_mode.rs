@@ -0,0 +1,225 @@
+//! Multiplayer game-mode rules shared by the map format and the server.
+//!
+//! A map may carry an optional `game_mode` block; the dedicated server can
+//! override it from the command line. Without one, the server runs the
+//! open-ended free-for-all it always has (no scoring limits, no rounds).
+
+use bevy::reflect::Reflect;
+
+/// Which ruleset a match uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, Reflect)]
+pub enum GameModeKind {
+    /// Players race for kills. The round ends at the frag limit or time limit.
+    Deathmatch,
+    /// Players clear the map's enemies together, wave after wave.
+    Coop,
+}
+
+/// Match rules, as authored in `.fp_map.ron` (`game_mode: Some((kind: Coop))`).
+///
+/// Limits of `0` disable that condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GameModeConfig {
+    pub kind: GameModeKind,
+    /// Deathmatch: kills needed to win the round.
+    #[serde(default = "default_frag_limit")]
+    pub frag_limit: u32,
+    /// Round length in seconds.
+    #[serde(default)]
+    pub time_limit_secs: u32,
+    /// Co-op: waves to clear before the match is won.
+    #[serde(default = "default_waves")]
+    pub waves: u32,
+    /// Pause between rounds/waves, in seconds.
+    #[serde(default = "default_intermission_secs")]
+    pub intermission_secs: u32,
+}
+
+const fn default_frag_limit() -> u32 {
+    20
+}
+
+const fn default_waves() -> u32 {
+    3
+}
+
+const fn default_intermission_secs() -> u32 {
+    10
+}
+
+impl GameModeConfig {
+    /// Default rules for `kind`.
+    #[must_use]
+    pub const fn new(kind: GameModeKind) -> Self {
+        Self {
+            kind,
+            frag_limit: default_frag_limit(),
+            time_limit_secs: match kind {
+                GameModeKind::Deathmatch => 600,
+                GameModeKind::Coop => 0,
+            },
+            waves: default_waves(),
+            intermission_secs: default_intermission_secs(),
+        }
+    }
+
+    /// Resolve the rules for a match: a command-line `kind` wins over the
+    /// map's block, but the map's limits are kept when the kinds agree.
+    #[must_use]
+    pub fn resolve(map: Option<Self>, cli_kind: Option<GameModeKind>) -> Option<Self> {
+        match (map, cli_kind) {
+            (Some(map), Some(kind)) if map.kind == kind => Some(map),
+            (_, Some(kind)) => Some(Self::new(kind)),
+            (map, None) => map,
+        }
+    }
+}
+
+/// Why a round ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, Reflect)]
+pub enum RoundOutcome {
+    /// Deathmatch: a player reached the frag limit.
+    FragLimit { winner: u32 },
+    /// The round clock ran out.
+    TimeLimit,
+    /// Co-op: a wave was cleared and more remain.
+    WaveCleared { wave: u32 },
+    /// Co-op: the final wave was cleared.
+    AllWavesCleared,
+}
+
+impl RoundOutcome {
+    /// Whether the whole match is over (scores reset on restart), as opposed
+    /// to a co-op wave break.
+    #[must_use]
+    pub const fn ends_match(self) -> bool {
+        !matches!(self, Self::WaveCleared { .. })
+    }
+}
+
+/// Live round state the end condition is evaluated against.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RoundProgress {
+    pub elapsed_secs: f32,
+    /// Highest kill count and the player holding it (lowest id on ties).
+    pub leader: Option<(u32, u32)>,
+    /// Co-op: current wave, 1-based.
+    pub wave: u32,
+    /// Co-op: enemies spawned this wave / still alive.
+    pub enemies_total: usize,
+    pub enemies_alive: usize,
+}
+
+/// Evaluate the end-of-round condition for `config`.
+#[must_use]
+pub fn round_outcome(config: &GameModeConfig, progress: &RoundProgress) -> Option<RoundOutcome> {
+    match config.kind {
+        GameModeKind::Deathmatch => {
+            if config.frag_limit > 0
+                && let Some((winner, kills)) = progress.leader
+                && kills >= config.frag_limit
+            {
+                return Some(RoundOutcome::FragLimit { winner });
+            }
+        }
+        GameModeKind::Coop => {
+            if progress.enemies_total > 0 && progress.enemies_alive == 0 {
+                return Some(if progress.wave >= config.waves.max(1) {
+                    RoundOutcome::AllWavesCleared
+                } else {
+                    RoundOutcome::WaveCleared {
+                        wave: progress.wave,
+                    }
+                });
+            }
+        }
+    }
+    if config.time_limit_secs > 0 && progress.elapsed_secs >= config.time_limit_secs as f32 {
+        return Some(RoundOutcome::TimeLimit);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cli_kind_overrides_map_kind() {
+        let map = GameModeConfig {
+            frag_limit: 5,
+            ..GameModeConfig::new(GameModeKind::Deathmatch)
+        };
+        let coop = GameModeConfig::resolve(Some(map), Some(GameModeKind::Coop)).unwrap();
+        assert_eq!(coop, GameModeConfig::new(GameModeKind::Coop));
+        let same = GameModeConfig::resolve(Some(map), Some(GameModeKind::Deathmatch)).unwrap();
+        assert_eq!(same.frag_limit, 5);
+        assert_eq!(GameModeConfig::resolve(None, None), None);
+    }
+
+    #[test]
+    fn deathmatch_ends_at_frag_limit_or_clock() {
+        let config = GameModeConfig {
+            frag_limit: 3,
+            time_limit_secs: 60,
+            ..GameModeConfig::new(GameModeKind::Deathmatch)
+        };
+        let mut progress = RoundProgress {
+            leader: Some((2, 2)),
+            ..Default::default()
+        };
+        assert_eq!(round_outcome(&config, &progress), None);
+        progress.leader = Some((2, 3));
+        assert_eq!(
+            round_outcome(&config, &progress),
+            Some(RoundOutcome::FragLimit { winner: 2 })
+        );
+        progress.leader = None;
+        progress.elapsed_secs = 60.0;
+        assert_eq!(
+            round_outcome(&config, &progress),
+            Some(RoundOutcome::TimeLimit)
+        );
+    }
+
+    #[test]
+    fn coop_clears_waves_then_match() {
+        let config = GameModeConfig {
+            waves: 2,
+            ..GameModeConfig::new(GameModeKind::Coop)
+        };
+        let mut progress = RoundProgress {
+            wave: 1,
+            enemies_total: 4,
+            enemies_alive: 1,
+            ..Default::default()
+        };
+        assert_eq!(round_outcome(&config, &progress), None);
+        progress.enemies_alive = 0;
+        let outcome = round_outcome(&config, &progress).unwrap();
+        assert_eq!(outcome, RoundOutcome::WaveCleared { wave: 1 });
+        assert!(!outcome.ends_match());
+        progress.wave = 2;
+        assert_eq!(
+            round_outcome(&config, &progress),
+            Some(RoundOutcome::AllWavesCleared)
+        );
+    }
+
+    #[test]
+    fn coop_without_enemies_never_clears() {
+        let config = GameModeConfig::new(GameModeKind::Coop);
+        let progress = RoundProgress {
+            wave: 1,
+            ..Default::default()
+        };
+        assert_eq!(round_outcome(&config, &progress), None);
+    }
+
+    #[test]
+    fn parses_from_ron_with_defaults() {
+        let config: GameModeConfig = ron::from_str("(kind: Coop, waves: 5)").unwrap();
+        assert_eq!(config.waves, 5);
+        assert_eq!(config.intermission_secs, 10);
+    }
+}
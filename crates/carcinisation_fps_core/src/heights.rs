@@ -0,0 +1,190 @@
+//! Per-cell floor and ceiling heights authored in `.fp_map.ron`.
+//!
+//! Heights are in wall units: a plain wall spans `0.0..1.0` and eyes sit
+//! [`EYE_HEIGHT`] above the floor of the cell they stand in. Every cell has
+//! an open span between its floor and ceiling:
+//!
+//! - Empty cells default to `0.0..1.0`. Overriding them makes raised
+//!   platforms, pits and low or high ceilings. They stay walkable.
+//! - Wall cells default to fully solid. Overriding them opens a gap, so a
+//!   low wall is solid below its floor and a window is solid outside its
+//!   span. They still block movement.
+//!
+//! Rays travelling through a cell are blocked wherever they leave its open
+//! span, so hitscan, flames and line of sight pass over low walls and
+//! through windows.
+
+use crate::map::Map;
+
+/// Eye height above the floor of the viewer's cell, in wall units.
+pub const EYE_HEIGHT: f32 = 0.5;
+
+/// Resolved heights of one cell.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CellHeights {
+    pub floor: f32,
+    pub ceiling: f32,
+    /// Wall texture id for the step and lintel faces this cell shows to its
+    /// neighbours (1-based, like `Map::cells`).
+    pub texture: u8,
+}
+
+impl CellHeights {
+    /// Default empty cell.
+    pub const OPEN: Self = Self {
+        floor: 0.0,
+        ceiling: 1.0,
+        texture: 1,
+    };
+
+    /// Fully solid cell with wall texture `texture`.
+    #[must_use]
+    pub const fn solid(texture: u8) -> Self {
+        Self {
+            floor: 0.0,
+            ceiling: 0.0,
+            texture,
+        }
+    }
+
+    /// Whether nothing can pass through this cell at any height.
+    #[must_use]
+    pub fn is_solid(&self) -> bool {
+        self.ceiling <= self.floor
+    }
+
+    /// Whether height `z` lies in the open span.
+    #[must_use]
+    pub fn is_open_at(&self, z: f32) -> bool {
+        self.floor < z && z < self.ceiling
+    }
+}
+
+const fn default_ceiling() -> f32 {
+    1.0
+}
+const fn default_texture() -> u8 {
+    1
+}
+
+/// Height override for one cell.
+#[derive(serde::Deserialize, Debug, Clone, Copy)]
+pub struct CellHeightSpec {
+    pub x: i32,
+    pub y: i32,
+    #[serde(default)]
+    pub floor: f32,
+    #[serde(default = "default_ceiling")]
+    pub ceiling: f32,
+    /// Texture for step faces of empty cells. Wall cells use their own id.
+    #[serde(default = "default_texture")]
+    pub texture: u8,
+}
+
+/// Expand `specs` into a row-major grid for `map.heights`. No specs give an
+/// empty grid, which keeps every cell at its default.
+///
+/// # Errors
+/// Returns a message if a spec is out of bounds, duplicated, inverted, or
+/// sits on a cell a door occupies or slides through.
+pub fn build_height_grid(
+    map: &Map,
+    specs: &[CellHeightSpec],
+) -> Result<Vec<Option<CellHeights>>, String> {
+    if specs.is_empty() {
+        return Ok(Vec::new());
+    }
+    let door_cells = map.mechanisms.door_cells();
+    let mut grid = vec![None; map.width * map.height];
+    for spec in specs {
+        let (x, y) = (spec.x, spec.y);
+        if x < 0 || y < 0 || x as usize >= map.width || y as usize >= map.height {
+            return Err(format!("heights at ({x}, {y}) is outside the map"));
+        }
+        if spec.ceiling < spec.floor {
+            return Err(format!(
+                "heights at ({x}, {y}) has ceiling {} below floor {}",
+                spec.ceiling, spec.floor
+            ));
+        }
+        if spec.texture == 0 {
+            return Err(format!(
+                "heights at ({x}, {y}) texture must be a wall id (>0)"
+            ));
+        }
+        if door_cells.contains(&(x, y)) {
+            return Err(format!("heights at ({x}, {y}) overlaps a door"));
+        }
+        let index = y as usize * map.width + x as usize;
+        if grid[index].is_some() {
+            return Err(format!("duplicate heights at ({x}, {y})"));
+        }
+        let wall = map.cells[index];
+        grid[index] = Some(CellHeights {
+            floor: spec.floor,
+            ceiling: spec.ceiling,
+            texture: if wall > 0 { wall } else { spec.texture },
+        });
+    }
+    Ok(grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map::test_map;
+
+    fn spec(x: i32, y: i32, floor: f32, ceiling: f32) -> CellHeightSpec {
+        CellHeightSpec {
+            x,
+            y,
+            floor,
+            ceiling,
+            texture: 1,
+        }
+    }
+
+    #[test]
+    fn defaults_follow_wall_cells() {
+        let map = test_map();
+        assert_eq!(map.cell_heights(1, 1), CellHeights::OPEN);
+        assert!(map.cell_heights(3, 2).is_solid());
+        assert!(map.cell_heights(-1, 0).is_solid());
+        assert!((map.eye_z(bevy_math::Vec2::new(1.5, 1.5)) - EYE_HEIGHT).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn overrides_shape_walls_and_floors() {
+        let mut map = test_map();
+        let grid = build_height_grid(
+            &map,
+            &[
+                spec(3, 2, 0.3, 1.0),
+                spec(1, 1, 0.25, 1.0),
+                spec(2, 1, -0.5, 1.5),
+            ],
+        )
+        .unwrap();
+        map.heights = grid;
+
+        let low_wall = map.cell_heights(3, 2);
+        assert!(!low_wall.is_solid());
+        assert_eq!(low_wall.texture, 2, "wall cells keep their wall texture");
+        assert!(low_wall.is_open_at(EYE_HEIGHT));
+        assert!(!low_wall.is_open_at(0.2));
+        // Still a wall for movement.
+        assert_eq!(map.get(3, 2), 2);
+
+        assert!((map.eye_z(bevy_math::Vec2::new(1.5, 1.5)) - 0.75).abs() < 1e-6);
+        assert!((map.eye_z(bevy_math::Vec2::new(2.5, 1.5)) - 0.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn rejects_invalid_specs() {
+        let map = test_map();
+        assert!(build_height_grid(&map, &[spec(8, 0, 0.0, 1.0)]).is_err());
+        assert!(build_height_grid(&map, &[spec(1, 1, 0.8, 0.2)]).is_err());
+        assert!(build_height_grid(&map, &[spec(1, 1, 0.0, 1.0), spec(1, 1, 0.2, 1.0)]).is_err());
+        assert!(build_height_grid(&map, &[]).unwrap().is_empty());
+    }
+}
@@ -138,8 +138,8 @@ pub fn is_critical_hit(damage_scale: f32) -> bool {
 /// Canonical stage order, fixed now so future stages slot in without
 /// re-deciding it per call site:
 ///
-/// 1. **base** weapon damage (config, e.g. `hitscan_damage`)
-/// 2. **× weapon/context multiplier** (e.g. the single-player melee ×3)
+/// 1. **base** weapon damage (`weapons.ron`, e.g. the pistol's `damage`)
+/// 2. **× weapon/context multiplier** (none shipped; melee has its own base)
 /// 3. **× part `damage_scale`** — [`scaled_damage`]
 /// 4. **− armour flat subtraction** (ORS semantics) — [`routed_damage`]
 /// 5. **clamp to ≥ 0**, then round at the integer-health boundary
@@ -14,18 +14,23 @@ pub mod config;
 pub mod enemy;
 pub mod enemy_collision;
 pub mod fire_death;
+pub mod game_mode;
 pub mod ground_fire;
 pub mod hash_util;
+pub mod heights;
 pub mod hitscan;
 pub mod map;
+pub mod mechanisms;
 pub mod mosquiton;
 pub mod movement;
 pub mod occupancy;
+pub mod pathfinding;
 pub mod pickup;
 pub mod presentation;
 pub mod raycast;
 pub mod reaction;
 pub mod spidey;
+pub mod weapons;
 
 // Re-export pickup types
 pub use pickup::{PickupKind, PickupRules};
@@ -55,6 +60,10 @@ pub use config::{
     CombatControlMode, FpsCombatConfig, FpsMovementConfig, FpsVisualConfig, OccupancyConfig,
     PlayerFlamethrowerConfig, ScreenParticleConfig, SizeTierConfig, SpideyCombatConfig,
 };
+pub use enemy::perception::{
+    Awareness, EnemyPerception, Noise, NoiseKind, PatrolRoute, Perceiver, PerceptionConfig,
+    tick_patrol, tick_perception,
+};
 pub use enemy::{
     DamageOutcome, Enemy, EnemyAiDisposition, EnemyAiOutput, EnemyPlayerTarget, EnemySim,
     EnemyState, FpsEnemyAiState, FpsEnemyKind, HitscanResult, MosquitonAiConfig, Projectile,
@@ -68,10 +77,12 @@ pub use fire_death::{
     DamageKind, FireDeathConfig, PerimeterFlame, centered_flames_from_mask, corpse_seed,
     perimeter_flames_from_mask,
 };
+pub use game_mode::{GameModeConfig, GameModeKind, RoundOutcome, RoundProgress, round_outcome};
 pub use ground_fire::{
     GroundFire, GroundFireConfig, GroundFireContactResult, GroundFireContactState,
     ground_fire_contact_damage, ground_fire_flame_layout, tick_ground_fires, try_spawn_ground_fire,
 };
+pub use heights::{CellHeightSpec, CellHeights, EYE_HEIGHT, build_height_grid};
 pub use hitscan::{
     FlamePartHit, FlameStrip, HIT_DEBUG_TARGET, NEUTRAL_DAMAGE_SCALE, PartHitscanResult,
     PartHitscanTarget, flame_hits_target_parts, flame_hits_target_parts_configured,
@@ -80,6 +91,7 @@ pub use hitscan::{
 pub use map::{
     EntitySpawnData, EntitySpawnKind, Map, MapError, MapLoadData, PlayerStartData, test_map,
 };
+pub use mechanisms::{Door, DoorKind, MapMechanisms, TriggerVolume, WallSwitch, pushed_cell};
 pub use mosquiton::{
     MosquitonSim, MosquitonSimConfig, MosquitonSimOutput, MosquitonSimState, tick_mosquiton_sim,
 };
@@ -87,11 +99,13 @@ pub use movement::{
     SnapTurnKind, SnapTurnParams, SpeedModifier, angular_velocity_clamped, apply_movement,
     apply_movement_with_modifier, local_to_world, snap_turn_params, tick_snap_turn,
 };
+pub use pathfinding::{EnemyNavigation, FlowField, NavBudget, NavGrid, chase_direction, find_path};
 pub use presentation::{AttackPresentationKind, EnemyPresentationState};
-pub use raycast::{HitSide, RayHit, cast_ray};
+pub use raycast::{HitSide, RayHit, cast_ray, walk_ray};
 pub use raycast::{WallSurfaceId, has_line_of_sight};
 pub use reaction::{
     EnemyReactionConfig, EnemyReactionState, EnemyReactionTuning, PendingHitReaction,
     WeaponReactionProfile,
 };
 pub use spidey::{SpideySim, SpideySimConfig, SpideySimOutput, SpideySimState, tick_spidey_sim};
+pub use weapons::{AmmoKind, FlameParams, WeaponDef, WeaponFire, WeaponTable};
@@ -1,5 +1,12 @@
 //! Grid-based map representation for first-person stages.
 
+use bevy_math::Vec2;
+
+use crate::enemy::perception::PatrolRoute;
+use crate::game_mode::GameModeConfig;
+use crate::heights::{CellHeightSpec, CellHeights, EYE_HEIGHT, build_height_grid};
+use crate::mechanisms::{Door, MapMechanisms, TriggerVolume, WallSwitch};
+
 /// Error type for map loading.
 #[derive(Debug, thiserror::Error)]
 pub enum MapError {
@@ -17,10 +24,17 @@ pub struct Map {
     /// Row-major cell data. `cells[y * width + x]`.
     /// 0 = empty, >0 = wall texture ID.
     pub cells: Vec<u8>,
+    /// Doors, switches and triggers. Open doors override `cells` in
+    /// [`Self::get`].
+    pub mechanisms: MapMechanisms,
+    /// Row-major floor/ceiling overrides, see [`crate::heights`]. Empty when
+    /// the map has none.
+    pub heights: Vec<Option<CellHeights>>,
 }
 
 impl Map {
-    /// Look up the cell at grid position `(x, y)`.
+    /// Look up the cell at grid position `(x, y)`, with doors in their
+    /// current state.
     /// Returns 1 (solid wall) for out-of-bounds coordinates so that
     /// collision treats the map boundary as impassable.
     #[must_use]
@@ -28,15 +42,72 @@ impl Map {
         if x < 0 || y < 0 || x >= self.width as i32 || y >= self.height as i32 {
             return 1;
         }
-        self.cells[y as usize * self.width + x as usize]
+        let cell = self.cells[y as usize * self.width + x as usize];
+        self.mechanisms
+            .doors_at(x, y)
+            .find_map(|door| {
+                door.cell_at(
+                    x,
+                    y,
+                    self.cells[door.y as usize * self.width + door.x as usize],
+                )
+            })
+            .unwrap_or(cell)
+    }
+
+    /// Floor and ceiling heights at grid position `(x, y)`, with doors in
+    /// their current state. Out-of-bounds cells are solid.
+    #[must_use]
+    pub fn cell_heights(&self, x: i32, y: i32) -> CellHeights {
+        let wall = self.get(x, y);
+        if !self.heights.is_empty()
+            && x >= 0
+            && y >= 0
+            && (x as usize) < self.width
+            && (y as usize) < self.height
+            && let Some(heights) = self.heights[y as usize * self.width + x as usize]
+        {
+            return heights;
+        }
+        if wall > 0 {
+            CellHeights::solid(wall)
+        } else {
+            CellHeights::OPEN
+        }
+    }
+
+    /// Eye height of a viewer standing at `position`.
+    #[must_use]
+    pub fn eye_z(&self, position: Vec2) -> f32 {
+        let cell = self.cell_heights(position.x.floor() as i32, position.y.floor() as i32);
+        if cell.is_solid() {
+            EYE_HEIGHT
+        } else {
+            cell.floor + EYE_HEIGHT
+        }
+    }
+
+    /// Validate `mechanisms` against the static cells and attach them.
+    fn with_mechanisms(mut self, mechanisms: MapMechanisms) -> Result<Self, MapError> {
+        mechanisms.validate(&self).map_err(MapError::Validation)?;
+        self.mechanisms = mechanisms;
+        Ok(self)
+    }
+
+    /// Validate height overrides and attach them. Runs after
+    /// [`Self::with_mechanisms`] so door cells are known.
+    fn with_heights(mut self, specs: &[CellHeightSpec]) -> Result<Self, MapError> {
+        self.heights = build_height_grid(&self, specs).map_err(MapError::Validation)?;
+        Ok(self)
     }
 
     /// Load a map from a RON string (the `MapData(...)` format used by `.fp_map.ron` files).
-    /// Only reads `width`, `height`, and `cells`; rendering-specific fields are ignored.
+    /// Only reads `width`, `height`, `cells` and the mechanisms; rendering-specific
+    /// fields are ignored.
     ///
     /// # Errors
     /// Returns `MapError::Parse` if RON deserialization fails, or `MapError::Validation`
-    /// if cell count doesn't match width × height.
+    /// if cell count doesn't match width × height or a mechanism is invalid.
     pub fn from_ron(ron_str: &str) -> Result<Self, MapError> {
         #[derive(serde::Deserialize)]
         #[allow(dead_code)]
@@ -44,6 +115,14 @@ impl Map {
             width: usize,
             height: usize,
             cells: Vec<u8>,
+            #[serde(default)]
+            doors: Vec<Door>,
+            #[serde(default)]
+            switches: Vec<WallSwitch>,
+            #[serde(default)]
+            triggers: Vec<TriggerVolume>,
+            #[serde(default)]
+            heights: Vec<CellHeightSpec>,
         }
 
         let data: MapData = ron::from_str(ron_str)?;
@@ -56,11 +135,15 @@ impl Map {
                 data.height,
             )));
         }
-        Ok(Self {
+        Self {
             width: data.width,
             height: data.height,
             cells: data.cells,
-        })
+            mechanisms: MapMechanisms::default(),
+            heights: Vec::new(),
+        }
+        .with_mechanisms(MapMechanisms::new(data.doors, data.switches, data.triggers))?
+        .with_heights(&data.heights)
     }
 
     /// Load a map AND its entity spawn list from a RON string.
@@ -73,11 +156,11 @@ impl Map {
         Ok((data.map, data.entities))
     }
 
-    /// Load map geometry, entity spawns, and player starts from a RON string.
+    /// Load map geometry, mechanisms, entity spawns, and player starts from a RON string.
     ///
     /// # Errors
     /// Returns `MapError::Parse` if RON deserialization fails, or `MapError::Validation`
-    /// if cell count doesn't match width × height.
+    /// if cell count doesn't match width × height or a mechanism is invalid.
     pub fn load_data(ron_str: &str) -> Result<MapLoadData, MapError> {
         #[derive(serde::Deserialize)]
         #[allow(dead_code)]
@@ -91,6 +174,16 @@ impl Map {
             player_starts: Vec<PlayerStartData>,
             #[serde(default)]
             entities: Vec<EntitySpawnData>,
+            #[serde(default)]
+            game_mode: Option<GameModeConfig>,
+            #[serde(default)]
+            doors: Vec<Door>,
+            #[serde(default)]
+            switches: Vec<WallSwitch>,
+            #[serde(default)]
+            triggers: Vec<TriggerVolume>,
+            #[serde(default)]
+            heights: Vec<CellHeightSpec>,
         }
 
         let data: MapData = ron::from_str(ron_str)?;
@@ -107,7 +200,11 @@ impl Map {
             width: data.width,
             height: data.height,
             cells: data.cells,
-        };
+            mechanisms: MapMechanisms::default(),
+            heights: Vec::new(),
+        }
+        .with_mechanisms(MapMechanisms::new(data.doors, data.switches, data.triggers))?
+        .with_heights(&data.heights)?;
 
         let mut player_starts = data.player_starts;
         if player_starts.is_empty()
@@ -123,6 +220,7 @@ impl Map {
             map,
             entities: data.entities,
             player_starts,
+            game_mode: data.game_mode,
         })
     }
 }
@@ -133,6 +231,8 @@ pub struct MapLoadData {
     pub map: Map,
     pub entities: Vec<EntitySpawnData>,
     pub player_starts: Vec<PlayerStartData>,
+    /// Multiplayer rules authored in the map, if any.
+    pub game_mode: Option<GameModeConfig>,
 }
 
 impl MapLoadData {
@@ -194,12 +294,16 @@ pub enum EntitySpawnKind {
         health: u32,
         #[serde(default = "default_enemy_speed")]
         speed: f32,
+        #[serde(default)]
+        patrol: PatrolRoute,
     },
     Spidey {
         #[serde(default = "default_spidey_health")]
         health: u32,
         #[serde(default = "default_spidey_speed")]
         speed: f32,
+        #[serde(default)]
+        patrol: PatrolRoute,
     },
     Pickup {
         kind: crate::pickup::PickupKind,
@@ -280,6 +384,8 @@ pub fn test_map() -> Map {
         width: 8,
         height: 8,
         cells,
+        mechanisms: MapMechanisms::default(),
+        heights: Vec::new(),
     }
 }
 
@@ -344,6 +450,43 @@ mod tests {
         assert_eq!(map.width, 2);
     }
 
+    #[test]
+    fn load_data_parses_and_validates_mechanisms() {
+        let ron = r#"MapData(
+            width: 4, height: 3,
+            cells: [1,1,1,1, 1,0,2,1, 1,1,1,1],
+            doors: [(id: "gate", x: 2, y: 1, kind: Sliding(close_after: Some(3.0)))],
+            switches: [(x: 0, y: 1, targets: ["gate"])],
+            triggers: [(x: 1.0, y: 1.0, width: 1.0, height: 1.0, targets: ["gate"], once: false)],
+        )"#;
+        let map = Map::load_data(ron).expect("parse").map;
+        assert_eq!(map.mechanisms.doors.len(), 1);
+        assert!((map.mechanisms.doors[0].speed - 1.0).abs() < f32::EPSILON);
+        assert!(!map.mechanisms.triggers[0].once);
+        assert_eq!(map.get(2, 1), 2);
+
+        let bad = ron.replace(r#"targets: ["gate"])]"#, r#"targets: ["nope"])]"#);
+        assert!(matches!(Map::from_ron(&bad), Err(MapError::Validation(_))));
+    }
+
+    #[test]
+    fn from_ron_parses_and_validates_heights() {
+        let ron = r"MapData(
+            width: 4, height: 3,
+            cells: [1,1,1,1, 1,0,2,1, 1,1,1,1],
+            heights: [(x: 1, y: 1, floor: 0.25), (x: 2, y: 1, floor: 0.3, ceiling: 0.8)],
+        )";
+        let map = Map::from_ron(ron).expect("parse");
+        let platform = map.cell_heights(1, 1);
+        assert!((platform.floor - 0.25).abs() < f32::EPSILON);
+        assert!((platform.ceiling - 1.0).abs() < f32::EPSILON);
+        assert_eq!(map.cell_heights(2, 1).texture, 2);
+        assert!(map.cell_heights(0, 0).is_solid());
+
+        let bad = ron.replace("x: 2, y: 1", "x: 1, y: 1");
+        assert!(matches!(Map::from_ron(&bad), Err(MapError::Validation(_))));
+    }
+
     #[test]
     fn out_of_bounds_returns_wall_all_edges() {
         let map = test_map();
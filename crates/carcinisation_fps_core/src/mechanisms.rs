@@ -0,0 +1,636 @@
+//! Doors, wall switches and floor triggers authored in `.fp_map.ron`.
+//!
+//! Mechanisms are linked by door id. A [`WallSwitch`] toggles its target
+//! doors when a player pushes against it, and a [`TriggerVolume`] opens its
+//! targets when a player walks in. Doors no switch or trigger targets open
+//! when pushed directly.
+//!
+//! Door state feeds [`Map::get`], so wall collision, line of sight and the
+//! raycaster all see the same open and closed cells.
+
+use std::collections::{HashMap, HashSet};
+
+use bevy_math::Vec2;
+
+use crate::map::Map;
+use crate::movement::direction_from_angle;
+
+/// How far ahead of the player a push reaches, in cells. Must exceed the
+/// movement collision margin so a player pressed against a wall reaches it.
+pub const PUSH_REACH: f32 = 0.5;
+
+/// How a door moves once activated.
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum DoorKind {
+    /// Blocks its cell until fully open. Closes again after `close_after`
+    /// seconds when set, otherwise stays open until toggled by a switch.
+    Sliding {
+        #[serde(default)]
+        close_after: Option<f32>,
+    },
+    /// Secret wall that slides `distance` cells along `direction` once
+    /// pushed and stays there.
+    PushWall {
+        direction: (i8, i8),
+        #[serde(default = "default_push_distance")]
+        distance: u8,
+    },
+}
+
+const fn default_push_distance() -> u8 {
+    2
+}
+const fn default_door_speed() -> f32 {
+    1.0
+}
+const fn once_true() -> bool {
+    true
+}
+
+/// A wall cell that can open. The cell's value in `cells` is the wall
+/// texture drawn while the door blocks.
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct Door {
+    pub id: String,
+    pub x: i32,
+    pub y: i32,
+    pub kind: DoorKind,
+    /// Travel speed in cells per second.
+    #[serde(default = "default_door_speed")]
+    pub speed: f32,
+    /// Travel progress: 0 = closed, 1 = fully open.
+    #[serde(skip)]
+    pub open: f32,
+    /// Whether the door is moving towards (or resting) open.
+    #[serde(skip)]
+    pub opening: bool,
+    /// Seconds spent fully open, for `close_after`.
+    #[serde(skip)]
+    held: f32,
+}
+
+impl Door {
+    fn travel(&self) -> f32 {
+        match self.kind {
+            DoorKind::Sliding { .. } => 1.0,
+            DoorKind::PushWall { distance, .. } => f32::from(distance),
+        }
+    }
+
+    /// Every cell this door can occupy: its own cell plus a push wall's path.
+    fn reachable_cells(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
+        let path = match self.kind {
+            DoorKind::Sliding { .. } => None,
+            DoorKind::PushWall {
+                direction,
+                distance,
+            } => Some((1..=i32::from(distance)).map(move |step| {
+                (
+                    self.x + i32::from(direction.0) * step,
+                    self.y + i32::from(direction.1) * step,
+                )
+            })),
+        };
+        std::iter::once((self.x, self.y)).chain(path.into_iter().flatten())
+    }
+
+    /// Cell a push wall occupies at `open`.
+    fn push_cell(&self, direction: (i8, i8), distance: u8, open: f32) -> (i32, i32) {
+        let offset = push_offset(open, distance);
+        (
+            self.x + i32::from(direction.0) * offset,
+            self.y + i32::from(direction.1) * offset,
+        )
+    }
+
+    /// Override for cell `(x, y)`, if this door affects it. `wall` is the
+    /// texture authored at the door's cell.
+    #[must_use]
+    pub fn cell_at(&self, x: i32, y: i32, wall: u8) -> Option<u8> {
+        match self.kind {
+            DoorKind::Sliding { .. } => {
+                ((x, y) == (self.x, self.y)).then_some(if self.is_open() { 0 } else { wall })
+            }
+            DoorKind::PushWall {
+                direction,
+                distance,
+            } => {
+                if (x, y) == self.push_cell(direction, distance, self.open) {
+                    Some(wall)
+                } else if (x, y) == (self.x, self.y) {
+                    Some(0)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Whether the door no longer blocks its own cell.
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        match self.kind {
+            DoorKind::Sliding { .. } => self.open >= 1.0,
+            DoorKind::PushWall { distance, .. } => push_offset(self.open, distance) > 0,
+        }
+    }
+
+    fn activate(&mut self) {
+        self.opening = true;
+        self.held = 0.0;
+    }
+
+    fn toggle(&mut self) {
+        match self.kind {
+            DoorKind::Sliding { .. } => {
+                self.opening = !self.opening;
+                self.held = 0.0;
+            }
+            // Push walls never move back.
+            DoorKind::PushWall { .. } => self.activate(),
+        }
+    }
+
+    /// Advance the door by `dt`. A closing door or a push wall entering a
+    /// new cell waits while `occupied` reports that cell taken. Returns
+    /// whether `open` changed.
+    fn tick(&mut self, dt: f32, occupied: &impl Fn(i32, i32) -> bool) -> bool {
+        let rate = self.speed / self.travel();
+        let previous = self.open;
+        match self.kind {
+            DoorKind::Sliding { close_after } => {
+                if self.opening {
+                    self.open = (self.open + rate * dt).min(1.0);
+                    if let Some(close_after) = close_after.filter(|_| self.open >= 1.0) {
+                        self.held += dt;
+                        if self.held >= close_after {
+                            self.opening = false;
+                            self.held = 0.0;
+                        }
+                    }
+                } else if self.open > 0.0 && !occupied(self.x, self.y) {
+                    self.open = (self.open - rate * dt).max(0.0);
+                }
+            }
+            DoorKind::PushWall {
+                direction,
+                distance,
+            } => {
+                if self.opening && self.open < 1.0 {
+                    let next = (self.open + rate * dt).min(1.0);
+                    let cell = self.push_cell(direction, distance, next);
+                    if cell == self.push_cell(direction, distance, self.open)
+                        || !occupied(cell.0, cell.1)
+                    {
+                        self.open = next;
+                    }
+                }
+            }
+        }
+        (self.open - previous).abs() > f32::EPSILON
+    }
+
+    fn reset(&mut self) {
+        self.open = 0.0;
+        self.opening = false;
+        self.held = 0.0;
+    }
+}
+
+/// Whole cells a push wall has moved from its origin at `open`.
+fn push_offset(open: f32, distance: u8) -> i32 {
+    ((open * f32::from(distance)).floor() as i32).min(i32::from(distance))
+}
+
+/// A wall cell that toggles its target doors when pushed.
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct WallSwitch {
+    pub x: i32,
+    pub y: i32,
+    pub targets: Vec<String>,
+    /// Whether a player pushed the switch on the previous update.
+    #[serde(skip)]
+    pressed: bool,
+}
+
+/// A floor rectangle that opens its target doors when a player enters it.
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct TriggerVolume {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub targets: Vec<String>,
+    /// Fire only on the first entry.
+    #[serde(default = "once_true")]
+    pub once: bool,
+    #[serde(skip)]
+    occupied: bool,
+    #[serde(skip)]
+    spent: bool,
+}
+
+impl TriggerVolume {
+    #[must_use]
+    pub fn contains(&self, position: Vec2) -> bool {
+        position.x >= self.x
+            && position.y >= self.y
+            && position.x < self.x + self.width
+            && position.y < self.y + self.height
+    }
+}
+
+/// Doors, switches and triggers of one map.
+#[derive(Debug, Clone, Default)]
+pub struct MapMechanisms {
+    /// Build through [`Self::new`] so [`Map::get`] finds them; door state may
+    /// change in place, but positions and kinds must not.
+    pub doors: Vec<Door>,
+    pub switches: Vec<WallSwitch>,
+    pub triggers: Vec<TriggerVolume>,
+    /// Indices into `doors` of the doors that can occupy each cell.
+    door_lookup: HashMap<(i32, i32), Vec<usize>>,
+}
+
+impl MapMechanisms {
+    /// Collect a map's mechanisms and index its doors by cell.
+    #[must_use]
+    pub fn new(doors: Vec<Door>, switches: Vec<WallSwitch>, triggers: Vec<TriggerVolume>) -> Self {
+        let mut door_lookup: HashMap<(i32, i32), Vec<usize>> = HashMap::new();
+        for (index, door) in doors.iter().enumerate() {
+            for cell in door.reachable_cells() {
+                door_lookup.entry(cell).or_default().push(index);
+            }
+        }
+        Self {
+            doors,
+            switches,
+            triggers,
+            door_lookup,
+        }
+    }
+
+    /// Doors that can occupy cell `(x, y)`.
+    pub fn doors_at(&self, x: i32, y: i32) -> impl Iterator<Item = &Door> {
+        self.door_lookup
+            .get(&(x, y))
+            .into_iter()
+            .flatten()
+            .map(|&index| &self.doors[index])
+    }
+
+    fn door_mut(&mut self, id: &str) -> Option<&mut Door> {
+        self.doors.iter_mut().find(|door| door.id == id)
+    }
+
+    /// Start opening door `id`. Returns `false` for an unknown id.
+    pub fn open_door(&mut self, id: &str) -> bool {
+        self.door_mut(id).map(Door::activate).is_some()
+    }
+
+    /// Reverse door `id`'s direction. Push walls only ever open. Returns
+    /// `false` for an unknown id.
+    pub fn toggle_door(&mut self, id: &str) -> bool {
+        self.door_mut(id).map(Door::toggle).is_some()
+    }
+
+    /// Apply one update of player input: `players` are the positions of
+    /// live players and `pushes` the cells they push against (see
+    /// [`pushed_cell`]). Switches fire on the first update they are pushed.
+    pub fn apply_players(&mut self, players: &[Vec2], pushes: &[(i32, i32)]) {
+        let mut toggled = Vec::new();
+        for switch in &mut self.switches {
+            let pressed = pushes.contains(&(switch.x, switch.y));
+            if pressed && !switch.pressed {
+                toggled.extend(switch.targets.iter().cloned());
+            }
+            switch.pressed = pressed;
+        }
+        for id in &toggled {
+            self.toggle_door(id);
+        }
+
+        let mut opened = Vec::new();
+        for trigger in &mut self.triggers {
+            let occupied = players.iter().any(|&p| trigger.contains(p));
+            if occupied && !trigger.occupied && !trigger.spent {
+                opened.extend(trigger.targets.iter().cloned());
+                trigger.spent = trigger.once;
+            }
+            trigger.occupied = occupied;
+        }
+
+        let remote: HashSet<&str> = self
+            .switches
+            .iter()
+            .flat_map(|s| &s.targets)
+            .chain(self.triggers.iter().flat_map(|t| &t.targets))
+            .map(String::as_str)
+            .collect();
+        for door in &mut self.doors {
+            if !remote.contains(door.id.as_str())
+                && !door.opening
+                && pushes.contains(&(door.x, door.y))
+            {
+                door.activate();
+            }
+        }
+        for id in &opened {
+            self.open_door(id);
+        }
+    }
+
+    /// Advance every door by `dt`. `occupied` reports cells a player or
+    /// enemy stands in. Returns whether any door moved.
+    pub fn tick(&mut self, dt: f32, occupied: impl Fn(i32, i32) -> bool) -> bool {
+        self.doors
+            .iter_mut()
+            .fold(false, |moved, door| door.tick(dt, &occupied) | moved)
+    }
+
+    /// Close every door and re-arm every switch and trigger.
+    pub fn reset(&mut self) {
+        for door in &mut self.doors {
+            door.reset();
+        }
+        for switch in &mut self.switches {
+            switch.pressed = false;
+        }
+        for trigger in &mut self.triggers {
+            trigger.occupied = false;
+            trigger.spent = false;
+        }
+    }
+
+    /// Every cell a door can occupy: its own cell plus a push wall's path.
+    #[must_use]
+    pub fn door_cells(&self) -> HashSet<(i32, i32)> {
+        self.doors.iter().flat_map(Door::reachable_cells).collect()
+    }
+
+    /// Check the mechanisms against the static geometry of `map`.
+    ///
+    /// # Errors
+    /// Describes the first invalid door, switch or trigger.
+    pub fn validate(&self, map: &Map) -> Result<(), String> {
+        let in_bounds = |x: i32, y: i32| {
+            x >= 0 && y >= 0 && (x as usize) < map.width && (y as usize) < map.height
+        };
+        let cell = |x: i32, y: i32| map.cells[y as usize * map.width + x as usize];
+
+        let mut ids = HashSet::new();
+        for door in &self.doors {
+            if !ids.insert(door.id.as_str()) {
+                return Err(format!("duplicate door id '{}'", door.id));
+            }
+            if !in_bounds(door.x, door.y) || cell(door.x, door.y) == 0 {
+                return Err(format!(
+                    "door '{}' at ({}, {}) must sit on a wall cell",
+                    door.id, door.x, door.y
+                ));
+            }
+            if door.speed <= 0.0 {
+                return Err(format!("door '{}' speed must be positive", door.id));
+            }
+            if let DoorKind::PushWall {
+                direction,
+                distance,
+            } = door.kind
+            {
+                if direction.0.abs() + direction.1.abs() != 1 {
+                    return Err(format!(
+                        "push wall '{}' direction must be one cell along an axis",
+                        door.id
+                    ));
+                }
+                if distance == 0 {
+                    return Err(format!("push wall '{}' distance must be positive", door.id));
+                }
+                for step in 1..=i32::from(distance) {
+                    let x = door.x + i32::from(direction.0) * step;
+                    let y = door.y + i32::from(direction.1) * step;
+                    if !in_bounds(x, y) || cell(x, y) != 0 {
+                        return Err(format!(
+                            "push wall '{}' path is blocked at ({x}, {y})",
+                            door.id
+                        ));
+                    }
+                }
+            }
+        }
+
+        for switch in &self.switches {
+            if !in_bounds(switch.x, switch.y) || cell(switch.x, switch.y) == 0 {
+                return Err(format!(
+                    "switch at ({}, {}) must sit on a wall cell",
+                    switch.x, switch.y
+                ));
+            }
+            if let Some(id) = switch.targets.iter().find(|id| !ids.contains(id.as_str())) {
+                return Err(format!("switch targets unknown door '{id}'"));
+            }
+        }
+        for trigger in &self.triggers {
+            if trigger.width <= 0.0
+                || trigger.height <= 0.0
+                || trigger.x < 0.0
+                || trigger.y < 0.0
+                || trigger.x + trigger.width > map.width as f32
+                || trigger.y + trigger.height > map.height as f32
+            {
+                return Err(format!(
+                    "trigger at ({}, {}) must be a non-empty area inside the map",
+                    trigger.x, trigger.y
+                ));
+            }
+            if let Some(id) = trigger.targets.iter().find(|id| !ids.contains(id.as_str())) {
+                return Err(format!("trigger targets unknown door '{id}'"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Cell a player at `position` facing `angle` pushes against.
+#[must_use]
+pub fn pushed_cell(position: Vec2, angle: f32) -> (i32, i32) {
+    let reach = position + direction_from_angle(angle) * PUSH_REACH;
+    (reach.x.floor() as i32, reach.y.floor() as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raycast::has_line_of_sight;
+
+    /// 5x3 corridor: a sliding door at (2, 1) splits the open row.
+    fn door_map(kind: DoorKind) -> Map {
+        #[rustfmt::skip]
+        let cells = vec![
+            1, 1, 1, 1, 1,
+            1, 0, 3, 0, 1,
+            1, 1, 1, 1, 1,
+        ];
+        let door = Door {
+            id: "gate".into(),
+            x: 2,
+            y: 1,
+            kind,
+            speed: 2.0,
+            open: 0.0,
+            opening: false,
+            held: 0.0,
+        };
+        Map {
+            width: 5,
+            height: 3,
+            cells,
+            mechanisms: MapMechanisms::new(vec![door], Vec::new(), Vec::new()),
+            heights: Vec::new(),
+        }
+    }
+
+    const SLIDING: DoorKind = DoorKind::Sliding { close_after: None };
+
+    #[test]
+    fn sliding_door_blocks_until_open() {
+        let mut map = door_map(SLIDING);
+        let west = Vec2::new(1.5, 1.5);
+        let east = Vec2::new(3.5, 1.5);
+        assert_eq!(map.get(2, 1), 3);
+        assert!(!has_line_of_sight(west, east, &map));
+
+        map.mechanisms.apply_players(&[west], &[(2, 1)]);
+        map.mechanisms.tick(0.25, |_, _| false);
+        assert_eq!(map.get(2, 1), 3, "half-open doors still block");
+        map.mechanisms.tick(0.25, |_, _| false);
+        assert_eq!(map.get(2, 1), 0);
+        assert!(has_line_of_sight(west, east, &map));
+    }
+
+    #[test]
+    fn closing_door_waits_for_its_cell_to_clear() {
+        let mut map = door_map(DoorKind::Sliding {
+            close_after: Some(1.0),
+        });
+        map.mechanisms.open_door("gate");
+        map.mechanisms.tick(0.5, |_, _| false);
+        map.mechanisms.tick(1.0, |_, _| false);
+        assert!(!map.mechanisms.doors[0].opening);
+
+        assert!(!map.mechanisms.tick(0.5, |x, y| (x, y) == (2, 1)));
+        assert!(map.mechanisms.tick(0.5, |_, _| false));
+        assert_eq!(map.get(2, 1), 3);
+    }
+
+    #[test]
+    fn push_wall_slides_along_its_path() {
+        #[rustfmt::skip]
+        let cells = vec![
+            1, 1, 1, 1, 1,
+            1, 0, 2, 0, 0,
+            1, 1, 1, 1, 1,
+        ];
+        let door = Door {
+            id: "secret".into(),
+            x: 2,
+            y: 1,
+            kind: DoorKind::PushWall {
+                direction: (1, 0),
+                distance: 2,
+            },
+            speed: 2.0,
+            open: 0.0,
+            opening: false,
+            held: 0.0,
+        };
+        let mut map = Map {
+            width: 5,
+            height: 3,
+            cells,
+            mechanisms: MapMechanisms::new(vec![door], Vec::new(), Vec::new()),
+            heights: Vec::new(),
+        };
+        assert!(map.mechanisms.validate(&map).is_ok());
+
+        map.mechanisms.apply_players(&[], &[(2, 1)]);
+        map.mechanisms.tick(0.5, |_, _| false);
+        assert_eq!((map.get(2, 1), map.get(3, 1), map.get(4, 1)), (0, 2, 0));
+        map.mechanisms.tick(0.5, |_, _| false);
+        assert_eq!((map.get(3, 1), map.get(4, 1)), (0, 2));
+    }
+
+    #[test]
+    fn switch_toggles_once_per_press_and_locks_direct_pushes() {
+        let mut map = door_map(SLIDING);
+        map.mechanisms.switches.push(WallSwitch {
+            x: 1,
+            y: 2,
+            targets: vec!["gate".into()],
+            pressed: false,
+        });
+        assert!(map.mechanisms.validate(&map).is_ok());
+
+        map.mechanisms.apply_players(&[], &[(2, 1)]);
+        assert!(
+            !map.mechanisms.doors[0].opening,
+            "switched doors ignore pushes"
+        );
+
+        map.mechanisms.apply_players(&[], &[(1, 2)]);
+        map.mechanisms.apply_players(&[], &[(1, 2)]);
+        assert!(map.mechanisms.doors[0].opening);
+        map.mechanisms.apply_players(&[], &[]);
+        map.mechanisms.apply_players(&[], &[(1, 2)]);
+        assert!(!map.mechanisms.doors[0].opening);
+    }
+
+    #[test]
+    fn trigger_fires_once() {
+        let mut map = door_map(SLIDING);
+        map.mechanisms.triggers.push(TriggerVolume {
+            x: 1.0,
+            y: 1.0,
+            width: 1.0,
+            height: 1.0,
+            targets: vec!["gate".into()],
+            once: true,
+            occupied: false,
+            spent: false,
+        });
+        let inside = [Vec2::new(1.5, 1.5)];
+        map.mechanisms.apply_players(&inside, &[]);
+        assert!(map.mechanisms.doors[0].opening);
+
+        map.mechanisms.toggle_door("gate");
+        map.mechanisms.apply_players(&[], &[]);
+        map.mechanisms.apply_players(&inside, &[]);
+        assert!(!map.mechanisms.doors[0].opening);
+
+        map.mechanisms.reset();
+        map.mechanisms.apply_players(&inside, &[]);
+        assert!(map.mechanisms.doors[0].opening);
+    }
+
+    #[test]
+    fn validate_rejects_bad_links() {
+        let mut map = door_map(SLIDING);
+        map.mechanisms.switches.push(WallSwitch {
+            x: 0,
+            y: 0,
+            targets: vec!["missing".into()],
+            pressed: false,
+        });
+        assert!(map.mechanisms.validate(&map).is_err());
+
+        let mut map = door_map(SLIDING);
+        map.mechanisms.doors[0].x = 1;
+        assert!(map.mechanisms.validate(&map).is_err(), "door on floor");
+    }
+
+    #[test]
+    fn pushed_cell_is_just_ahead() {
+        assert_eq!(pushed_cell(Vec2::new(1.75, 1.5), 0.0), (2, 1));
+        assert_eq!(pushed_cell(Vec2::new(1.2, 1.5), 0.0), (1, 1));
+    }
+}
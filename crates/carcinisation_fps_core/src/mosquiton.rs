@@ -9,6 +9,7 @@ use crate::collision::try_move;
 use crate::config;
 use crate::enemy::Projectile;
 use crate::map::Map;
+use crate::pathfinding::{EnemyNavigation, chase_direction};
 use crate::raycast::has_line_of_sight;
 use crate::reaction::{EnemyReactionConfig, EnemyReactionState};
 
@@ -128,6 +129,7 @@ pub fn tick_mosquiton_sim(
     config: &MosquitonSimConfig,
     player_pos: Vec2,
     map: &Map,
+    nav: Option<&EnemyNavigation>,
     dt: f32,
 ) -> MosquitonSimOutput {
     let mut output = MosquitonSimOutput::default();
@@ -206,9 +208,11 @@ pub fn tick_mosquiton_sim(
                 return output;
             }
 
-            // Move toward player.
-            if dist > 0.01 {
-                let move_dir = to_player / dist;
+            // Move toward player, around walls when navigation is given.
+            if dist > 0.01
+                && let Some(move_dir) =
+                    chase_direction(nav, map, sim.position, player_pos, config.collision_radius)
+            {
                 let step = move_dir * config.move_speed * dt;
                 output.velocity = step / dt.max(f32::EPSILON);
                 try_move(&mut sim.position, step, config.collision_radius, map);
@@ -383,7 +387,7 @@ mod tests {
         let player = Vec2::new(1.5 + config.preferred_range + 1.0, 1.5);
         let pos_before = sim.position;
 
-        let _ = tick_mosquiton_sim(&mut sim, &config, player, &map, 0.016);
+        let _ = tick_mosquiton_sim(&mut sim, &config, player, &map, None, 0.016);
 
         assert!(
             sim.position.x > pos_before.x,
@@ -400,7 +404,7 @@ mod tests {
         // Place at preferred_range distance.
         let mut sim = make_sim(1.5 + config.preferred_range - 0.1, 1.5);
 
-        let _ = tick_mosquiton_sim(&mut sim, &config, player, &map, 0.016);
+        let _ = tick_mosquiton_sim(&mut sim, &config, player, &map, None, 0.016);
 
         assert!(matches!(sim.state, MosquitonSimState::RangedAttack { .. }));
     }
@@ -412,7 +416,7 @@ mod tests {
         let player = Vec2::new(1.5, 1.5);
         let mut sim = make_sim(config.melee_range.mul_add(0.5, 1.5), 1.5);
 
-        let output = tick_mosquiton_sim(&mut sim, &config, player, &map, 0.016);
+        let output = tick_mosquiton_sim(&mut sim, &config, player, &map, None, 0.016);
 
         assert!(matches!(sim.state, MosquitonSimState::MeleeAttack { .. }));
         assert!(output.started_melee);
@@ -429,8 +433,8 @@ mod tests {
             dealt_damage: false,
         };
 
-        let out1 = tick_mosquiton_sim(&mut sim, &config, player, &map, 0.016);
-        let out2 = tick_mosquiton_sim(&mut sim, &config, player, &map, 0.016);
+        let out1 = tick_mosquiton_sim(&mut sim, &config, player, &map, None, 0.016);
+        let out2 = tick_mosquiton_sim(&mut sim, &config, player, &map, None, 0.016);
 
         assert!(out1.melee_damage.is_some(), "first tick should deal damage");
         assert_eq!(out1.melee_damage.unwrap().0, config.melee_damage);
@@ -452,7 +456,7 @@ mod tests {
         let mut sim = make_sim(1.5, 1.5);
         sim.shoot_cooldown = 0.0;
 
-        let output = tick_mosquiton_sim(&mut sim, &config, player, &map, 0.016);
+        let output = tick_mosquiton_sim(&mut sim, &config, player, &map, None, 0.016);
 
         assert!(output.started_shoot_anim, "should start shoot anim");
         assert!(sim.shoot_anim_elapsed.is_some());
@@ -478,12 +482,12 @@ mod tests {
         sim.shoot_cooldown = 10.0;
 
         // Not yet at cue.
-        let out1 = tick_mosquiton_sim(&mut sim, &config, player, &map, 0.3);
+        let out1 = tick_mosquiton_sim(&mut sim, &config, player, &map, None, 0.3);
         assert!(out1.projectile.is_none());
         assert!(sim.shoot_anim_elapsed.is_some());
 
         // Past cue.
-        let out2 = tick_mosquiton_sim(&mut sim, &config, player, &map, 0.3);
+        let out2 = tick_mosquiton_sim(&mut sim, &config, player, &map, None, 0.3);
         assert!(out2.projectile.is_some());
         assert!(sim.shoot_anim_elapsed.is_none());
 
@@ -504,14 +508,14 @@ mod tests {
         let mut sim = make_sim(1.5, 1.5);
 
         // First tick starts shoot anim.
-        let out1 = tick_mosquiton_sim(&mut sim, &config, player, &map, 0.016);
+        let out1 = tick_mosquiton_sim(&mut sim, &config, player, &map, None, 0.016);
         assert!(out1.started_shoot_anim);
 
         // Clear shoot anim (simulate projectile spawned).
         sim.shoot_anim_elapsed = None;
 
         // Second tick should NOT start another shoot anim (cooldown active).
-        let out2 = tick_mosquiton_sim(&mut sim, &config, player, &map, 0.016);
+        let out2 = tick_mosquiton_sim(&mut sim, &config, player, &map, None, 0.016);
         assert!(!out2.started_shoot_anim);
         assert!(sim.shoot_cooldown > 0.0);
     }
@@ -525,7 +529,7 @@ mod tests {
         sim.state = MosquitonSimState::Dead;
         let pos_before = sim.position;
 
-        let output = tick_mosquiton_sim(&mut sim, &config, player, &map, 1.0);
+        let output = tick_mosquiton_sim(&mut sim, &config, player, &map, None, 1.0);
 
         assert_eq!(sim.position, pos_before);
         assert!(output.projectile.is_none());
@@ -539,7 +543,7 @@ mod tests {
         let mut sim = make_sim(1.5, 1.5);
         sim.state = MosquitonSimState::Dying { timer: 0.1 };
 
-        let _ = tick_mosquiton_sim(&mut sim, &config, Vec2::ZERO, &map, 0.2);
+        let _ = tick_mosquiton_sim(&mut sim, &config, Vec2::ZERO, &map, None, 0.2);
 
         assert_eq!(sim.state, MosquitonSimState::Dead);
     }
@@ -551,7 +555,7 @@ mod tests {
         let mut sim = make_sim(1.5, 1.5);
         sim.state = MosquitonSimState::Recover { timer: 0.1 };
 
-        let _ = tick_mosquiton_sim(&mut sim, &config, Vec2::ZERO, &map, 0.2);
+        let _ = tick_mosquiton_sim(&mut sim, &config, Vec2::ZERO, &map, None, 0.2);
 
         assert_eq!(sim.state, MosquitonSimState::Pursue);
     }
@@ -562,6 +566,8 @@ mod tests {
             width: 5,
             height: 3,
             cells: vec![1, 1, 1, 1, 1, 1, 0, 1, 0, 1, 1, 1, 1, 1, 1],
+            mechanisms: crate::mechanisms::MapMechanisms::default(),
+            heights: Vec::new(),
         };
         let config = MosquitonSimConfig {
             shoot_range: 10.0,
@@ -571,7 +577,7 @@ mod tests {
         let mut sim = make_sim(1.5, 1.5);
         sim.shoot_cooldown = 0.0;
 
-        let output = tick_mosquiton_sim(&mut sim, &config, player, &map, 0.016);
+        let output = tick_mosquiton_sim(&mut sim, &config, player, &map, None, 0.016);
 
         assert!(!output.started_shoot_anim);
         assert!(!has_line_of_sight(sim.position, player, &map));
@@ -602,7 +608,7 @@ mod tests {
         let player = Vec2::new(1.5 + config.preferred_range + 1.0, 1.5);
         let pos_before = sim.position;
 
-        let output = tick_mosquiton_sim(&mut sim, &config, player, &map, 0.016);
+        let output = tick_mosquiton_sim(&mut sim, &config, player, &map, None, 0.016);
 
         assert!(sim.reaction.is_stunned());
         assert_eq!(sim.position, pos_before, "stunned: no pursuit movement");
@@ -618,7 +624,7 @@ mod tests {
         let mut sim = make_sim(config.melee_range.mul_add(0.5, 1.5), 1.5);
         sim.reaction.queue_hit(stun_hit());
 
-        let output = tick_mosquiton_sim(&mut sim, &config, player, &map, 0.016);
+        let output = tick_mosquiton_sim(&mut sim, &config, player, &map, None, 0.016);
 
         assert!(!output.started_melee, "stunned: melee must not start");
         assert!(matches!(sim.state, MosquitonSimState::Pursue));
@@ -637,7 +643,7 @@ mod tests {
         };
         sim.reaction.queue_hit(stun_hit());
 
-        let output = tick_mosquiton_sim(&mut sim, &config, player, &map, 0.016);
+        let output = tick_mosquiton_sim(&mut sim, &config, player, &map, None, 0.016);
 
         assert!(sim.reaction.is_stunned());
         assert!(
@@ -663,7 +669,7 @@ mod tests {
 
         // Stun-free knockback: tick through the impulse duration.
         for _ in 0..10 {
-            let _ = tick_mosquiton_sim(&mut sim, &config, player, &map, 0.02);
+            let _ = tick_mosquiton_sim(&mut sim, &config, player, &map, None, 0.02);
         }
 
         assert!(
@@ -690,7 +696,7 @@ mod tests {
         });
 
         for _ in 0..10 {
-            let _ = tick_mosquiton_sim(&mut sim, &config, player, &map, 0.02);
+            let _ = tick_mosquiton_sim(&mut sim, &config, player, &map, None, 0.02);
         }
 
         assert!(
@@ -714,7 +720,7 @@ mod tests {
         });
         let pos_before = sim.position;
 
-        let _ = tick_mosquiton_sim(&mut sim, &config, Vec2::ZERO, &map, 0.02);
+        let _ = tick_mosquiton_sim(&mut sim, &config, Vec2::ZERO, &map, None, 0.02);
 
         assert_eq!(sim.position, pos_before, "corpse never slides");
         assert!(!sim.reaction.is_stunned());
@@ -730,18 +736,19 @@ mod tests {
         let player = Vec2::new(1.5 + config.preferred_range + 1.0, 1.5);
 
         // Consume + ride out the stun.
-        let _ = tick_mosquiton_sim(&mut sim, &config, player, &map, 0.016);
+        let _ = tick_mosquiton_sim(&mut sim, &config, player, &map, None, 0.016);
         let _ = tick_mosquiton_sim(
             &mut sim,
             &config,
             player,
             &map,
+            None,
             config.reaction.hit_stun_secs + 0.05,
         );
         assert!(!sim.reaction.is_stunned());
 
         let pos_before = sim.position;
-        let _ = tick_mosquiton_sim(&mut sim, &config, player, &map, 0.016);
+        let _ = tick_mosquiton_sim(&mut sim, &config, player, &map, None, 0.016);
         assert!(sim.position.x > pos_before.x, "pursuit resumes after stun");
     }
 
@@ -756,7 +763,7 @@ mod tests {
             dealt_damage: false,
         };
 
-        let _ = tick_mosquiton_sim(&mut sim, &config, player, &map, 0.016);
+        let _ = tick_mosquiton_sim(&mut sim, &config, player, &map, None, 0.016);
 
         assert_eq!(sim.state, MosquitonSimState::Pursue);
     }
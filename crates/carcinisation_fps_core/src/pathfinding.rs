@@ -0,0 +1,558 @@
+//! Grid pathfinding for first-person enemies.
+//!
+//! A [`NavGrid`] mirrors the walkable cells of a [`Map`] (closed doors block,
+//! open ones don't) and adds a soft cost to cells other bodies stand in, so
+//! crowds spread out instead of queueing through one gap. On top of it sit
+//! point-to-point A* queries and one Dijkstra [`FlowField`] per chased cell
+//! that every enemy heading there shares.
+//!
+//! Everything is integer-costed and visits cells in a fixed order, so the
+//! same map, occupants and goals always produce the same steering on the
+//! server and in client prediction.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap};
+
+use bevy_math::Vec2;
+
+use crate::map::Map;
+use crate::raycast::has_line_of_sight;
+
+/// Cost of an orthogonal step.
+const STEP_COST: u32 = 10;
+/// Cost of a diagonal step (≈ 10·√2).
+const DIAGONAL_COST: u32 = 14;
+/// Extra cost to enter a cell another body stands in.
+pub const OCCUPIED_CELL_COST: u32 = 20;
+
+/// Neighbour offsets in visiting order. Orthogonals come first so ties
+/// prefer straight moves.
+const NEIGHBOURS: [(i32, i32); 8] = [
+    (1, 0),
+    (0, 1),
+    (-1, 0),
+    (0, -1),
+    (1, 1),
+    (-1, 1),
+    (-1, -1),
+    (1, -1),
+];
+
+/// Grid cell coordinates.
+pub type Cell = (i32, i32);
+
+/// Cell containing `position`.
+#[must_use]
+pub fn cell_of(position: Vec2) -> Cell {
+    (position.x.floor() as i32, position.y.floor() as i32)
+}
+
+fn cell_center(cell: Cell) -> Vec2 {
+    Vec2::new(cell.0 as f32 + 0.5, cell.1 as f32 + 0.5)
+}
+
+/// Walkability and entry costs for every map cell.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NavGrid {
+    pub width: usize,
+    pub height: usize,
+    walkable: Vec<bool>,
+    penalty: Vec<u32>,
+}
+
+impl NavGrid {
+    /// Build from the current map state. Each position in `occupants` adds
+    /// [`OCCUPIED_CELL_COST`] to its cell; pass grounded bodies only.
+    #[must_use]
+    pub fn build(map: &Map, occupants: &[Vec2]) -> Self {
+        let mut walkable = Vec::with_capacity(map.width * map.height);
+        for y in 0..map.height {
+            for x in 0..map.width {
+                walkable.push(map.get(x as i32, y as i32) == 0);
+            }
+        }
+        let mut grid = Self {
+            width: map.width,
+            height: map.height,
+            walkable,
+            penalty: vec![0; map.width * map.height],
+        };
+        for &position in occupants {
+            if let Some(index) = grid.index(cell_of(position)) {
+                grid.penalty[index] += OCCUPIED_CELL_COST;
+            }
+        }
+        grid
+    }
+
+    fn index(&self, (x, y): Cell) -> Option<usize> {
+        (x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height)
+            .then(|| y as usize * self.width + x as usize)
+    }
+
+    /// Whether an enemy can stand in `cell`.
+    #[must_use]
+    pub fn is_walkable(&self, cell: Cell) -> bool {
+        self.index(cell).is_some_and(|i| self.walkable[i])
+    }
+
+    /// Walkable neighbours of `cell` with the cost of stepping into each.
+    /// Diagonals need both orthogonal cells free so paths never cut corners.
+    fn neighbours(&self, cell: Cell) -> impl Iterator<Item = (Cell, u32)> + '_ {
+        NEIGHBOURS.iter().filter_map(move |&(dx, dy)| {
+            let next = (cell.0 + dx, cell.1 + dy);
+            let index = self.index(next)?;
+            if !self.walkable[index] {
+                return None;
+            }
+            let diagonal = dx != 0 && dy != 0;
+            if diagonal
+                && !(self.is_walkable((cell.0 + dx, cell.1))
+                    && self.is_walkable((cell.0, cell.1 + dy)))
+            {
+                return None;
+            }
+            let step = if diagonal { DIAGONAL_COST } else { STEP_COST };
+            Some((next, step + self.penalty[index]))
+        })
+    }
+}
+
+/// Octile distance, admissible for [`NavGrid`] costs.
+fn heuristic(a: Cell, b: Cell) -> u32 {
+    let dx = a.0.abs_diff(b.0);
+    let dy = a.1.abs_diff(b.1);
+    STEP_COST * dx.max(dy) + (DIAGONAL_COST - STEP_COST) * dx.min(dy)
+}
+
+/// A* from `start` to `goal`, inclusive of both ends. Gives up after
+/// `max_expansions` cells so a query against an unreachable goal stays
+/// bounded.
+#[must_use]
+pub fn find_path(
+    grid: &NavGrid,
+    start: Cell,
+    goal: Cell,
+    max_expansions: usize,
+) -> Option<Vec<Cell>> {
+    let start_index = grid.index(start)?;
+    let goal_index = grid.index(goal)?;
+    if !grid.walkable[start_index] || !grid.walkable[goal_index] {
+        return None;
+    }
+
+    let mut best = vec![u32::MAX; grid.walkable.len()];
+    let mut came_from = vec![usize::MAX; grid.walkable.len()];
+    let mut open = BinaryHeap::new();
+    best[start_index] = 0;
+    // Ties on f break on h, then on cell index, so the order is total.
+    open.push(Reverse((
+        heuristic(start, goal),
+        heuristic(start, goal),
+        start_index,
+    )));
+
+    let mut expansions = 0;
+    while let Some(Reverse((_, _, index))) = open.pop() {
+        if index == goal_index {
+            let mut path = vec![goal];
+            let mut current = index;
+            while current != start_index {
+                current = came_from[current];
+                path.push(((current % grid.width) as i32, (current / grid.width) as i32));
+            }
+            path.reverse();
+            return Some(path);
+        }
+        expansions += 1;
+        if expansions > max_expansions {
+            return None;
+        }
+        let cell = ((index % grid.width) as i32, (index / grid.width) as i32);
+        for (next, step) in grid.neighbours(cell) {
+            let next_index = next.1 as usize * grid.width + next.0 as usize;
+            let cost = best[index] + step;
+            if cost < best[next_index] {
+                best[next_index] = cost;
+                came_from[next_index] = index;
+                let h = heuristic(next, goal);
+                open.push(Reverse((cost + h, h, next_index)));
+            }
+        }
+    }
+    None
+}
+
+/// Cost-to-goal for every cell, shared by all enemies chasing that goal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FlowField {
+    pub goal: Cell,
+    width: usize,
+    height: usize,
+    cost: Vec<u32>,
+    /// [`EnemyNavigation`] grid generation this field was built against.
+    generation: u64,
+}
+
+impl FlowField {
+    /// Dijkstra outward from `goal`. Cells that can't reach it keep
+    /// `u32::MAX`.
+    #[must_use]
+    pub fn build(grid: &NavGrid, goal: Cell) -> Self {
+        let mut cost = vec![u32::MAX; grid.walkable.len()];
+        if let Some(goal_index) = grid.index(goal).filter(|&i| grid.walkable[i]) {
+            let mut open = BinaryHeap::new();
+            cost[goal_index] = 0;
+            open.push(Reverse((0, goal_index)));
+            while let Some(Reverse((current, index))) = open.pop() {
+                if current > cost[index] {
+                    continue;
+                }
+                let cell = ((index % grid.width) as i32, (index / grid.width) as i32);
+                // Walking outward, `step` carries the penalty of the cell an
+                // enemy would leave, so crowded cells cost more to route
+                // through and to stand in.
+                for (next, step) in grid.neighbours(cell) {
+                    let next_index = next.1 as usize * grid.width + next.0 as usize;
+                    let candidate = current + step;
+                    if candidate < cost[next_index] {
+                        cost[next_index] = candidate;
+                        open.push(Reverse((candidate, next_index)));
+                    }
+                }
+            }
+        }
+        Self {
+            goal,
+            width: grid.width,
+            height: grid.height,
+            cost,
+            generation: 0,
+        }
+    }
+
+    fn index(&self, (x, y): Cell) -> Option<usize> {
+        (x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height)
+            .then(|| y as usize * self.width + x as usize)
+    }
+
+    /// Cost from `cell` to the goal, or `None` if it can't get there.
+    #[must_use]
+    pub fn cost_at(&self, cell: Cell) -> Option<u32> {
+        self.index(cell)
+            .map(|i| self.cost[i])
+            .filter(|&c| c != u32::MAX)
+    }
+
+    /// Unit direction from `position` toward the centre of the neighbouring
+    /// cell with the cheapest step-plus-remaining cost. `None` in the goal cell or
+    /// where the goal is unreachable.
+    #[must_use]
+    pub fn direction_from(&self, position: Vec2) -> Option<Vec2> {
+        let cell = cell_of(position);
+        if self.cost_at(cell)? == 0 {
+            return None;
+        }
+        let mut best: Option<(u32, Cell)> = None;
+        for &(dx, dy) in &NEIGHBOURS {
+            let next = (cell.0 + dx, cell.1 + dy);
+            let Some(remaining) = self.cost_at(next) else {
+                continue;
+            };
+            let diagonal = dx != 0 && dy != 0;
+            if diagonal
+                && (self.cost_at((cell.0 + dx, cell.1)).is_none()
+                    || self.cost_at((cell.0, cell.1 + dy)).is_none())
+            {
+                continue;
+            }
+            let cost = remaining + if diagonal { DIAGONAL_COST } else { STEP_COST };
+            if best.is_none_or(|(best_cost, _)| cost < best_cost) {
+                best = Some((cost, next));
+            }
+        }
+        let (_, next) = best?;
+        let dir = (cell_center(next) - position).normalize_or_zero();
+        (dir != Vec2::ZERO).then_some(dir)
+    }
+}
+
+/// How much pathfinding work [`EnemyNavigation`] may do per tick.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NavBudget {
+    /// Flow fields rebuilt per [`EnemyNavigation::update`]. Goals past the
+    /// budget keep their stale field (or none) until a later tick.
+    pub field_rebuilds_per_tick: usize,
+    /// Cell expansions allowed per A* query.
+    pub max_path_expansions: usize,
+}
+
+impl Default for NavBudget {
+    fn default() -> Self {
+        Self {
+            field_rebuilds_per_tick: 4,
+            max_path_expansions: 4096,
+        }
+    }
+}
+
+/// Navigation state shared by every enemy in a map: the grid, one flow field
+/// per chased cell and a cache of A* paths.
+#[derive(Clone, Debug, Default)]
+pub struct EnemyNavigation {
+    pub budget: NavBudget,
+    grid: NavGrid,
+    /// Bumped whenever the grid changes. Fields built against an older
+    /// generation are stale.
+    generation: u64,
+    /// Sorted by goal cell.
+    fields: Vec<FlowField>,
+    paths: BTreeMap<(Cell, Cell), Option<Vec<Cell>>>,
+}
+
+impl EnemyNavigation {
+    #[must_use]
+    pub fn new(budget: NavBudget) -> Self {
+        Self {
+            budget,
+            ..Self::default()
+        }
+    }
+
+    /// Refresh for this tick. Rebuilds the grid from `map` and `occupants`
+    /// (dropping cached paths if anything changed), forgets fields for cells
+    /// no longer in `goals`, and rebuilds missing or stale fields in goal
+    /// order up to the per-tick budget. Returns the number rebuilt.
+    pub fn update(&mut self, map: &Map, occupants: &[Vec2], goals: &[Vec2]) -> usize {
+        let grid = NavGrid::build(map, occupants);
+        if grid != self.grid {
+            self.grid = grid;
+            self.generation += 1;
+            self.paths.clear();
+        }
+
+        let mut goal_cells: Vec<Cell> = goals.iter().map(|&g| cell_of(g)).collect();
+        goal_cells.sort_unstable();
+        goal_cells.dedup();
+        self.fields
+            .retain(|field| goal_cells.binary_search(&field.goal).is_ok());
+
+        let mut rebuilt = 0;
+        for goal in goal_cells {
+            let existing = self.fields.binary_search_by_key(&goal, |f| f.goal);
+            if let Ok(i) = existing
+                && self.fields[i].generation == self.generation
+            {
+                continue;
+            }
+            if rebuilt >= self.budget.field_rebuilds_per_tick {
+                continue;
+            }
+            let mut field = FlowField::build(&self.grid, goal);
+            field.generation = self.generation;
+            match existing {
+                Ok(i) => self.fields[i] = field,
+                Err(i) => self.fields.insert(i, field),
+            }
+            rebuilt += 1;
+        }
+        rebuilt
+    }
+
+    /// Current grid.
+    #[must_use]
+    pub fn grid(&self) -> &NavGrid {
+        &self.grid
+    }
+
+    /// Flow field toward the cell containing `target`, if one has been built.
+    #[must_use]
+    pub fn field_toward(&self, target: Vec2) -> Option<&FlowField> {
+        let goal = cell_of(target);
+        self.fields
+            .binary_search_by_key(&goal, |f| f.goal)
+            .ok()
+            .map(|i| &self.fields[i])
+    }
+
+    /// Cached A* path between the cells of `from` and `to`. Cleared whenever
+    /// the grid changes.
+    pub fn path(&mut self, from: Vec2, to: Vec2) -> Option<&[Cell]> {
+        let key = (cell_of(from), cell_of(to));
+        let grid = &self.grid;
+        let max_expansions = self.budget.max_path_expansions;
+        self.paths
+            .entry(key)
+            .or_insert_with(|| find_path(grid, key.0, key.1, max_expansions))
+            .as_deref()
+    }
+}
+
+/// Unit direction an enemy of `radius` at `from` should move to chase
+/// `target`. Heads straight when a body-wide corridor is clear, otherwise
+/// follows the flow field toward `target`, falling back to straight when
+/// there is none. `None` when already on the target.
+#[must_use]
+pub fn chase_direction(
+    nav: Option<&EnemyNavigation>,
+    map: &Map,
+    from: Vec2,
+    target: Vec2,
+    radius: f32,
+) -> Option<Vec2> {
+    let to_target = target - from;
+    let dist = to_target.length();
+    if dist <= f32::EPSILON {
+        return None;
+    }
+    let direct = to_target / dist;
+    let Some(field) = nav.and_then(|nav| nav.field_toward(target)) else {
+        return Some(direct);
+    };
+    let side = direct.perp() * radius;
+    let clear = has_line_of_sight(from, target, map)
+        && has_line_of_sight(from + side, target + side, map)
+        && has_line_of_sight(from - side, target - side, map);
+    if clear {
+        return Some(direct);
+    }
+    Some(field.direction_from(from).unwrap_or(direct))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mechanisms::MapMechanisms;
+
+    /// `#` is a wall, anything else is floor.
+    fn map_from_rows(rows: &[&str]) -> Map {
+        Map {
+            width: rows[0].len(),
+            height: rows.len(),
+            cells: rows
+                .iter()
+                .flat_map(|row| row.bytes().map(|b| u8::from(b == b'#')))
+                .collect(),
+            mechanisms: MapMechanisms::default(),
+            heights: Vec::new(),
+        }
+    }
+
+    fn pillar_map() -> Map {
+        map_from_rows(&[
+            "#########",
+            "#.......#",
+            "#.......#",
+            "#..###..#",
+            "#..###..#",
+            "#..###..#",
+            "#.......#",
+            "#########",
+        ])
+    }
+
+    #[test]
+    fn a_star_routes_around_a_pillar_without_cutting_corners() {
+        let map = pillar_map();
+        let grid = NavGrid::build(&map, &[]);
+        let path = find_path(&grid, (4, 2), (4, 6), 1024).expect("path exists");
+        assert_eq!(path.first(), Some(&(4, 2)));
+        assert_eq!(path.last(), Some(&(4, 6)));
+        for pair in path.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            assert!(grid.is_walkable(b));
+            assert!(a.0.abs_diff(b.0) <= 1 && a.1.abs_diff(b.1) <= 1);
+            if a.0 != b.0 && a.1 != b.1 {
+                assert!(grid.is_walkable((b.0, a.1)) && grid.is_walkable((a.0, b.1)));
+            }
+        }
+        assert!(
+            find_path(&grid, (4, 2), (4, 4), 1024).is_none(),
+            "goal in a wall"
+        );
+        assert!(
+            find_path(&grid, (1, 1), (7, 6), 2).is_none(),
+            "budget exceeded"
+        );
+    }
+
+    #[test]
+    fn flow_field_leads_around_a_corner() {
+        let map = map_from_rows(&["#######", "#.....#", "#####.#", "#.....#", "#######"]);
+        let mut nav = EnemyNavigation::default();
+        let player = Vec2::new(1.5, 3.5);
+        assert_eq!(nav.update(&map, &[], &[player]), 1);
+
+        let enemy = Vec2::new(1.5, 1.5);
+        let field = nav.field_toward(player).expect("field for the player cell");
+        assert_eq!(field.cost_at((2, 2)), None);
+        let dir = field.direction_from(enemy).unwrap();
+        assert!(dir.x > 0.9, "heads east along the corridor first: {dir:?}");
+
+        let dir = chase_direction(Some(&nav), &map, enemy, player, 0.2).unwrap();
+        assert!(dir.x > 0.9, "no line of sight, follows the field: {dir:?}");
+        let straight = chase_direction(None, &map, enemy, player, 0.2).unwrap();
+        assert!(straight.y > 0.9, "without navigation, heads straight");
+
+        // Walking the field reaches the goal cell.
+        let mut position = enemy;
+        for _ in 0..200 {
+            let Some(dir) = field.direction_from(position) else {
+                break;
+            };
+            crate::collision::try_move(&mut position, dir * 0.1, 0.2, &map);
+        }
+        assert_eq!(cell_of(position), cell_of(player));
+    }
+
+    #[test]
+    fn occupants_spread_the_field_and_invalidate_paths() {
+        let map = pillar_map();
+        let mut nav = EnemyNavigation::default();
+        let player = Vec2::new(4.5, 6.5);
+        nav.update(&map, &[], &[player]);
+        let free = nav.field_toward(player).unwrap().cost_at((4, 2)).unwrap();
+        assert!(nav.path(Vec2::new(4.5, 2.5), player).is_some());
+
+        // Crowd the east route: the west one becomes the cheaper descent.
+        let crowd = [
+            Vec2::new(6.5, 4.5),
+            Vec2::new(6.5, 4.5),
+            Vec2::new(7.5, 4.5),
+        ];
+        nav.update(&map, &crowd, &[player]);
+        let field = nav.field_toward(player).unwrap();
+        assert!(field.cost_at((4, 2)).unwrap() >= free);
+        let dir = field.direction_from(Vec2::new(4.5, 2.5)).unwrap();
+        assert!(dir.x < 0.0, "prefers the uncrowded side: {dir:?}");
+        assert!(nav.paths.is_empty(), "grid change drops cached paths");
+    }
+
+    #[test]
+    fn rebuilds_respect_budget_and_are_deterministic() {
+        let map = pillar_map();
+        let goals = [
+            Vec2::new(7.5, 6.5),
+            Vec2::new(1.5, 1.5),
+            Vec2::new(4.5, 6.5),
+        ];
+        let budget = NavBudget {
+            field_rebuilds_per_tick: 2,
+            ..NavBudget::default()
+        };
+        let mut a = EnemyNavigation::new(budget);
+        assert_eq!(a.update(&map, &[], &goals), 2);
+        // Goals are served in cell order, so the last one waits a tick.
+        assert!(a.field_toward(goals[0]).is_none());
+        assert_eq!(a.update(&map, &[], &goals), 1);
+        assert_eq!(a.update(&map, &[], &goals), 0);
+
+        let mut b = EnemyNavigation::new(budget);
+        let reversed: Vec<Vec2> = goals.iter().rev().copied().collect();
+        b.update(&map, &[], &reversed);
+        b.update(&map, &[], &reversed);
+        for goal in goals {
+            assert_eq!(a.field_toward(goal), b.field_toward(goal));
+        }
+    }
+}
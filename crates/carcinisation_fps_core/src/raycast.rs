@@ -37,13 +37,23 @@ pub struct RayHit {
     pub surface_id: Option<WallSurfaceId>,
 }
 
-/// Cast a ray from `origin` in direction `dir` through `map` using DDA.
+/// Walk the cells a ray from `origin` along `dir` enters, nearest first,
+/// using DDA.
 ///
-/// `dir` does NOT need to be normalized — the perpendicular distance
-/// calculation accounts for the ray direction magnitude, which avoids
-/// fisheye distortion when used with a camera-plane projection.
-#[must_use]
-pub fn cast_ray(map: &Map, origin: Vec2, dir: Vec2) -> RayHit {
+/// `visit` receives the boundary crossed into each cell (`wall_id` is the
+/// cell's value, 0 for open cells) and the distance at which the ray leaves
+/// that cell again. The walk stops at the first hit `visit` accepts and
+/// returns it, or returns `None` once the ray leaves the map.
+///
+/// `dir` does NOT need to be normalized — distances are perpendicular and
+/// in units of `dir`, which avoids fisheye distortion when used with a
+/// camera-plane projection.
+pub fn walk_ray(
+    map: &Map,
+    origin: Vec2,
+    dir: Vec2,
+    mut visit: impl FnMut(&RayHit, f32) -> bool,
+) -> Option<RayHit> {
     let mut map_x = origin.x.floor() as i32;
     let mut map_y = origin.y.floor() as i32;
 
@@ -83,48 +93,107 @@ pub fn cast_ray(map: &Map, origin: Vec2, dir: Vec2) -> RayHit {
             HitSide::Horizontal
         };
 
-        let cell = map.get(map_x, map_y);
-        if cell > 0 {
-            let perp_dist = match side {
-                HitSide::Vertical => side_dist_x - delta_dist_x,
-                HitSide::Horizontal => side_dist_y - delta_dist_y,
-            };
-
-            let wall_x = match side {
-                HitSide::Vertical => origin.y + perp_dist * dir.y,
-                HitSide::Horizontal => origin.x + perp_dist * dir.x,
-            };
-            let wall_x = wall_x - wall_x.floor();
-
-            return RayHit {
-                distance: perp_dist.max(0.001),
-                wall_id: cell,
-                wall_x,
+        let perp_dist = match side {
+            HitSide::Vertical => side_dist_x - delta_dist_x,
+            HitSide::Horizontal => side_dist_y - delta_dist_y,
+        };
+
+        let wall_x = match side {
+            HitSide::Vertical => origin.y + perp_dist * dir.y,
+            HitSide::Horizontal => origin.x + perp_dist * dir.x,
+        };
+        let wall_x = wall_x - wall_x.floor();
+
+        let hit = RayHit {
+            distance: perp_dist.max(0.001),
+            wall_id: map.get(map_x, map_y),
+            wall_x,
+            side,
+            surface_id: Some(WallSurfaceId {
+                cell_x: map_x,
+                cell_y: map_y,
                 side,
-                surface_id: Some(WallSurfaceId {
-                    cell_x: map_x,
-                    cell_y: map_y,
-                    side,
-                    normal_sign: match side {
-                        HitSide::Vertical => -step_x as i8,
-                        HitSide::Horizontal => -step_y as i8,
-                    },
-                }),
-            };
+                normal_sign: match side {
+                    HitSide::Vertical => -step_x as i8,
+                    HitSide::Horizontal => -step_y as i8,
+                },
+            }),
+        };
+        if visit(&hit, side_dist_x.min(side_dist_y)) {
+            return Some(hit);
         }
     }
 
-    // Ray escaped the map.
-    RayHit {
-        distance: f32::MAX,
-        wall_id: 0,
-        wall_x: 0.0,
-        side: HitSide::Vertical,
-        surface_id: None,
+    None
+}
+
+/// Ray that escaped the map.
+const ESCAPED: RayHit = RayHit {
+    distance: f32::MAX,
+    wall_id: 0,
+    wall_x: 0.0,
+    side: HitSide::Vertical,
+    surface_id: None,
+};
+
+/// Cast a ray from `origin` in direction `dir` through `map` using DDA.
+///
+/// The ray travels level at the eye height of `origin`'s cell and stops at
+/// the first cell whose open span doesn't contain it, so it passes over
+/// low walls and through windows. On maps without height overrides this is
+/// the first wall cell.
+///
+/// `dir` does NOT need to be normalized — the perpendicular distance
+/// calculation accounts for the ray direction magnitude, which avoids
+/// fisheye distortion when used with a camera-plane projection.
+#[must_use]
+pub fn cast_ray(map: &Map, origin: Vec2, dir: Vec2) -> RayHit {
+    let z = map.eye_z(origin);
+    cast_sloped_ray(map, origin, dir, z, 0.0, f32::MAX)
+}
+
+/// Cast a ray whose height is `z + slope * distance`, stopping at the first
+/// cell it enters or leaves outside the open span. Cells beyond
+/// `max_distance` are not tested.
+fn cast_sloped_ray(
+    map: &Map,
+    origin: Vec2,
+    dir: Vec2,
+    z: f32,
+    slope: f32,
+    max_distance: f32,
+) -> RayHit {
+    let hit = walk_ray(map, origin, dir, |hit, exit| {
+        if hit.distance > max_distance {
+            return true;
+        }
+        let Some(surface) = hit.surface_id else {
+            return true;
+        };
+        let cell = map.cell_heights(surface.cell_x, surface.cell_y);
+        let enter_z = slope.mul_add(hit.distance, z);
+        let exit_z = slope.mul_add(exit.min(max_distance), z);
+        !cell.is_open_at(enter_z) || !cell.is_open_at(exit_z)
+    });
+    let Some(mut hit) = hit else {
+        return ESCAPED;
+    };
+    if hit.distance > max_distance {
+        return ESCAPED;
     }
+    if hit.wall_id == 0
+        && let Some(surface) = hit.surface_id
+    {
+        // Blocked by the floor or ceiling of an open cell.
+        hit.wall_id = map.cell_heights(surface.cell_x, surface.cell_y).texture;
+    }
+    hit
 }
 
 /// Returns `true` if a straight ray from `from` to `to` does not hit any wall.
+///
+/// The ray runs between the eye heights of both positions, so viewers can
+/// see over low walls and onto (or off) raised platforms.
 #[must_use]
 pub fn has_line_of_sight(from: Vec2, to: Vec2, map: &Map) -> bool {
     let dir = to - from;
@@ -132,7 +201,9 @@ pub fn has_line_of_sight(from: Vec2, to: Vec2, map: &Map) -> bool {
     if dist < 0.01 {
         return true;
     }
-    let hit = cast_ray(map, from, dir / dist);
+    let from_z = map.eye_z(from);
+    let slope = (map.eye_z(to) - from_z) / dist;
+    let hit = cast_sloped_ray(map, from, dir / dist, from_z, slope, dist);
     hit.distance > dist
 }
 
@@ -278,4 +349,71 @@ mod tests {
             &map
         ));
     }
+
+    // -----------------------------------------------------------------------
+    // Height overrides
+    // -----------------------------------------------------------------------
+
+    fn with_heights(specs: &[crate::heights::CellHeightSpec]) -> Map {
+        let mut map = test_map();
+        map.heights = crate::heights::build_height_grid(&map, specs).unwrap();
+        map
+    }
+
+    fn spec(x: i32, y: i32, floor: f32, ceiling: f32) -> crate::heights::CellHeightSpec {
+        crate::heights::CellHeightSpec {
+            x,
+            y,
+            floor,
+            ceiling,
+            texture: 1,
+        }
+    }
+
+    #[test]
+    fn ray_passes_over_low_wall() {
+        // (3,2) is a wall cell; lowered to knee height.
+        let map = with_heights(&[spec(3, 2, 0.3, 1.0)]);
+        let hit = cast_ray(&map, Vec2::new(1.5, 2.5), Vec2::new(1.0, 0.0));
+        assert_eq!(
+            hit.surface_id.unwrap().cell_x,
+            4,
+            "stops at the full wall behind"
+        );
+        assert!(has_line_of_sight(
+            Vec2::new(1.5, 2.5),
+            Vec2::new(3.5, 2.5),
+            &map
+        ));
+    }
+
+    #[test]
+    fn window_only_lets_rays_through_its_span() {
+        let map = with_heights(&[spec(3, 2, 0.3, 0.7), spec(4, 2, 0.6, 1.0)]);
+        let from = Vec2::new(1.5, 2.5);
+        let to = Vec2::new(5.5, 2.5);
+        // (3,2) is open at eye height; (4,2)'s sill sits above it.
+        let hit = cast_ray(&map, from, Vec2::new(1.0, 0.0));
+        assert_eq!(hit.surface_id.unwrap().cell_x, 4);
+        assert_eq!(hit.wall_id, 2);
+        assert!(!has_line_of_sight(from, to, &map));
+    }
+
+    #[test]
+    fn raised_ledge_blocks_level_rays_but_not_viewers_on_it() {
+        // Open cell (3,1) raised above eye height: a ledge.
+        let map = with_heights(&[spec(3, 1, 1.0, 2.0), spec(4, 1, 1.0, 2.0)]);
+        let below = Vec2::new(1.5, 1.5);
+        let hit = cast_ray(&map, below, Vec2::new(1.0, 0.0));
+        assert_eq!(hit.surface_id.unwrap().cell_x, 3);
+        assert_eq!(hit.wall_id, 1, "ledge faces use the override texture");
+        // Both viewers on the ledge see each other.
+        assert!(has_line_of_sight(
+            Vec2::new(3.5, 1.5),
+            Vec2::new(4.5, 1.5),
+            &map
+        ));
+        // From the floor, the ledge edge hides a viewer standing back on it.
+        assert!(!has_line_of_sight(below, Vec2::new(4.9, 1.5), &map));
+    }
 }
@@ -141,37 +141,12 @@ impl WeaponReactionProfile {
     };
 }
 
-/// Bundled reaction tuning: enemy-side poise rules + per-weapon profiles.
-///
-/// Provisional defaults (documented, tune in `combat.ron` later):
-/// pistol = light poise chip with a tiny nudge; melee = heavier poise and a
-/// meaningful shove; flamethrower has **no** profile by design.
-#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, bevy::prelude::Reflect)]
+/// Enemy-side reaction tuning. Per-weapon [`WeaponReactionProfile`]s live on
+/// the weapon definitions in `weapons.ron` ([`crate::weapons::WeaponTable`]).
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Deserialize, bevy::prelude::Reflect)]
 #[serde(default)]
 pub struct EnemyReactionTuning {
     pub enemy: EnemyReactionConfig,
-    pub pistol: WeaponReactionProfile,
-    pub melee: WeaponReactionProfile,
-}
-
-impl Default for EnemyReactionTuning {
-    fn default() -> Self {
-        Self {
-            enemy: EnemyReactionConfig::default(),
-            // 4 pistol shots to stagger; barely perceptible nudge.
-            pistol: WeaponReactionProfile {
-                poise_damage: 25.0,
-                knockback_distance: 0.05,
-                knockback_duration: 0.12,
-            },
-            // 2 melee hits to stagger; a real shove.
-            melee: WeaponReactionProfile {
-                poise_damage: 60.0,
-                knockback_distance: 0.3,
-                knockback_duration: 0.18,
-            },
-        }
-    }
 }
 
 // ---------------------------------------------------------------------------
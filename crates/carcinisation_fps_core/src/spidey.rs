@@ -8,6 +8,7 @@ use bevy_math::Vec2;
 use crate::collision::try_move;
 use crate::enemy::Projectile;
 use crate::map::Map;
+use crate::pathfinding::{EnemyNavigation, chase_direction};
 use crate::raycast::has_line_of_sight;
 use crate::reaction::{EnemyReactionConfig, EnemyReactionState};
 
@@ -211,6 +212,7 @@ pub fn tick_spidey_sim(
     config: &SpideySimConfig,
     player_pos: Vec2,
     map: &Map,
+    nav: Option<&EnemyNavigation>,
     dt: f32,
 ) -> SpideySimOutput {
     if config.lunge_range >= config.web_range {
@@ -328,10 +330,13 @@ pub fn tick_spidey_sim(
                     output.started_lunge = true;
                 } else if try_start_web(sim, config, player_pos, dist, map, &mut output) {
                     // Web wind-up started.
-                } else if dist > 0.01 {
-                    // Start a hop toward the player with randomized height
-                    // and lateral jitter for zigzag approach.
-                    let base_dir = to_player / dist;
+                } else if dist > 0.01
+                    && let Some(base_dir) =
+                        chase_direction(nav, map, sim.position, player_pos, config.collision_radius)
+                {
+                    // Start a hop toward the player (around walls when
+                    // navigation is given) with randomized height and
+                    // lateral jitter for zigzag approach.
                     let jitter_angle = hop_lateral_jitter(sim.seed);
                     sim.seed = sim.seed.wrapping_add(1);
                     let direction = Vec2::new(
@@ -628,7 +633,7 @@ mod tests {
 
         // Tick enough to complete the hop.
         for _ in 0..100 {
-            let _ = tick_spidey_sim(&mut sim, &config, Vec2::new(0.5, 3.5), &map, 0.016);
+            let _ = tick_spidey_sim(&mut sim, &config, Vec2::new(0.5, 3.5), &map, None, 0.016);
         }
 
         // Should not have crossed through the wall at x=1.0.
@@ -650,7 +655,7 @@ mod tests {
         sim.lunge_cooldown = 0.0;
         sim.reaction.queue_hit(stun_hit());
 
-        let output = tick_spidey_sim(&mut sim, &config, player, &map, 0.016);
+        let output = tick_spidey_sim(&mut sim, &config, player, &map, None, 0.016);
 
         assert!(sim.reaction.is_stunned());
         assert!(!output.started_lunge, "stunned: lunge must not start");
@@ -676,7 +681,7 @@ mod tests {
         sim.reaction.queue_hit(stun_hit());
         let x_before = sim.position.x;
 
-        let _ = tick_spidey_sim(&mut sim, &config, player, &map, 0.05);
+        let _ = tick_spidey_sim(&mut sim, &config, player, &map, None, 0.05);
 
         assert!(sim.reaction.is_stunned());
         assert!(
@@ -696,7 +701,7 @@ mod tests {
         let mut sim = make_sim(config.lunge_range.mul_add(0.5, 2.5), 3.5);
         sim.lunge_cooldown = 0.0;
 
-        let output = tick_spidey_sim(&mut sim, &config, player, &map, 0.016);
+        let output = tick_spidey_sim(&mut sim, &config, player, &map, None, 0.016);
 
         assert!(
             matches!(sim.state, SpideySimState::LungeWindup { .. }),
@@ -721,7 +726,7 @@ mod tests {
         let mut sim = make_sim(1.5, 3.5);
         sim.web_cooldown = 0.0;
 
-        let output = tick_spidey_sim(&mut sim, &config, player, &map, 0.016);
+        let output = tick_spidey_sim(&mut sim, &config, player, &map, None, 0.016);
 
         assert!(
             matches!(sim.state, SpideySimState::WebWindup { .. }),
@@ -745,13 +750,13 @@ mod tests {
             dealt_damage: false,
         };
 
-        let out1 = tick_spidey_sim(&mut sim, &config, player, &map, 0.016);
+        let out1 = tick_spidey_sim(&mut sim, &config, player, &map, None, 0.016);
         assert!(out1.melee_damage.is_some(), "first tick should deal damage");
         assert_eq!(out1.melee_damage.unwrap().0, config.lunge_melee_damage);
 
         // If still in LungeAttack, second tick should not re-deal.
         if matches!(sim.state, SpideySimState::LungeAttack { .. }) {
-            let out2 = tick_spidey_sim(&mut sim, &config, player, &map, 0.016);
+            let out2 = tick_spidey_sim(&mut sim, &config, player, &map, None, 0.016);
             assert!(
                 out2.melee_damage.is_none(),
                 "second tick should not re-deal damage"
@@ -768,6 +773,8 @@ mod tests {
             width: 5,
             height: 3,
             cells: vec![1, 1, 1, 1, 1, 1, 0, 1, 0, 1, 1, 1, 1, 1, 1],
+            mechanisms: crate::mechanisms::MapMechanisms::default(),
+            heights: Vec::new(),
         };
         let config = SpideySimConfig {
             lunge_speed: 10.0,
@@ -786,7 +793,7 @@ mod tests {
         // Tick until state changes (blocked by wall).
         let mut any_damage = false;
         for _ in 0..200 {
-            let out = tick_spidey_sim(&mut sim, &config, player, &map, 0.016);
+            let out = tick_spidey_sim(&mut sim, &config, player, &map, None, 0.016);
             if out.melee_damage.is_some() {
                 any_damage = true;
             }
@@ -823,12 +830,12 @@ mod tests {
         };
 
         // Not yet at cue.
-        let out1 = tick_spidey_sim(&mut sim, &config, player, &map, 0.1);
+        let out1 = tick_spidey_sim(&mut sim, &config, player, &map, None, 0.1);
         assert!(out1.projectile.is_none());
         assert!(sim.web_anim_elapsed.is_some());
 
         // Past cue.
-        let out2 = tick_spidey_sim(&mut sim, &config, player, &map, 0.3);
+        let out2 = tick_spidey_sim(&mut sim, &config, player, &map, None, 0.3);
         assert!(out2.projectile.is_some());
         assert!(sim.web_anim_elapsed.is_none());
 
@@ -852,7 +859,7 @@ mod tests {
         let mut sim = make_sim(1.5, 3.5);
 
         // First tick starts web anim.
-        let out1 = tick_spidey_sim(&mut sim, &config, player, &map, 0.016);
+        let out1 = tick_spidey_sim(&mut sim, &config, player, &map, None, 0.016);
         assert!(out1.started_web_anim);
 
         // Force back to idle, clear anim.
@@ -860,7 +867,7 @@ mod tests {
         sim.web_anim_elapsed = None;
 
         // Second tick should NOT start web (cooldown active).
-        let out2 = tick_spidey_sim(&mut sim, &config, player, &map, 0.016);
+        let out2 = tick_spidey_sim(&mut sim, &config, player, &map, None, 0.016);
         assert!(!out2.started_web_anim);
         assert!(sim.web_cooldown > 0.0);
     }
@@ -873,7 +880,7 @@ mod tests {
         let mut sim = make_sim(config.lunge_range.mul_add(0.5, 2.0), 3.5);
 
         // First tick starts leap.
-        let out1 = tick_spidey_sim(&mut sim, &config, player, &map, 0.016);
+        let out1 = tick_spidey_sim(&mut sim, &config, player, &map, None, 0.016);
         assert!(out1.started_lunge);
 
         // Force back to idle, set leap on cooldown.
@@ -881,7 +888,7 @@ mod tests {
         sim.lunge_cooldown = config.lunge_cooldown;
 
         // Second tick should NOT start leap.
-        let out2 = tick_spidey_sim(&mut sim, &config, player, &map, 0.016);
+        let out2 = tick_spidey_sim(&mut sim, &config, player, &map, None, 0.016);
         assert!(!out2.started_lunge);
         assert!(
             !matches!(sim.state, SpideySimState::LungeWindup { .. }),
@@ -898,7 +905,7 @@ mod tests {
         let mut sim = make_sim(1.5, 1.5);
         sim.state = SpideySimState::Dying { timer: 0.1 };
 
-        let _ = tick_spidey_sim(&mut sim, &config, Vec2::ZERO, &map, 0.2);
+        let _ = tick_spidey_sim(&mut sim, &config, Vec2::ZERO, &map, None, 0.2);
 
         assert_eq!(sim.state, SpideySimState::Dead);
     }
@@ -913,7 +920,7 @@ mod tests {
             seed: 42,
         };
 
-        let _ = tick_spidey_sim(&mut sim, &config, Vec2::ZERO, &map, 0.2);
+        let _ = tick_spidey_sim(&mut sim, &config, Vec2::ZERO, &map, None, 0.2);
 
         assert_eq!(sim.state, SpideySimState::Dead);
     }
@@ -927,7 +934,7 @@ mod tests {
         sim.state = SpideySimState::Dead;
         let pos_before = sim.position;
 
-        let output = tick_spidey_sim(&mut sim, &config, player, &map, 1.0);
+        let output = tick_spidey_sim(&mut sim, &config, player, &map, None, 1.0);
 
         assert_eq!(sim.position, pos_before);
         assert!(output.projectile.is_none());
@@ -950,7 +957,14 @@ mod tests {
         };
 
         // Tick to roughly midpoint of hop.
-        let out = tick_spidey_sim(&mut sim, &config, player, &map, config.hop_duration * 0.5);
+        let out = tick_spidey_sim(
+            &mut sim,
+            &config,
+            player,
+            &map,
+            None,
+            config.hop_duration * 0.5,
+        );
 
         assert!(
             out.visual_height > 0.0,
@@ -968,7 +982,7 @@ mod tests {
         let player = Vec2::new(100.0, 100.0); // Way out of aggro range.
         let mut sim = make_sim(1.5, 3.5);
 
-        let _ = tick_spidey_sim(&mut sim, &config, player, &map, 0.016);
+        let _ = tick_spidey_sim(&mut sim, &config, player, &map, None, 0.016);
 
         assert_eq!(sim.state, SpideySimState::Idle);
     }
@@ -985,7 +999,7 @@ mod tests {
             recoil_arc: 0.0,
         };
 
-        let _ = tick_spidey_sim(&mut sim, &config, Vec2::ZERO, &map, 0.2);
+        let _ = tick_spidey_sim(&mut sim, &config, Vec2::ZERO, &map, None, 0.2);
 
         assert_eq!(sim.state, SpideySimState::Idle);
     }
@@ -1022,7 +1036,7 @@ mod tests {
         // Tick until damage is dealt.
         let mut damaged = false;
         for _ in 0..30 {
-            let output = tick_spidey_sim(&mut sim, &config, player_pos, &map, 1.0 / 30.0);
+            let output = tick_spidey_sim(&mut sim, &config, player_pos, &map, None, 1.0 / 30.0);
             if output.melee_damage.is_some() {
                 damaged = true;
                 break;
@@ -1031,7 +1045,7 @@ mod tests {
         assert!(damaged, "lunge should deal damage");
 
         // Tick once more to transition to Recover.
-        let _ = tick_spidey_sim(&mut sim, &config, player_pos, &map, 1.0 / 30.0);
+        let _ = tick_spidey_sim(&mut sim, &config, player_pos, &map, None, 1.0 / 30.0);
         assert!(
             matches!(
                 sim.state,
@@ -1045,7 +1059,7 @@ mod tests {
         );
 
         // Recoil arc should produce visual_height > 0 during early recovery.
-        let early = tick_spidey_sim(&mut sim, &config, player_pos, &map, 1.0 / 30.0);
+        let early = tick_spidey_sim(&mut sim, &config, player_pos, &map, None, 1.0 / 30.0);
         assert!(
             early.visual_height > 0.0,
             "recoil arc should produce positive visual_height: {}",
@@ -1066,7 +1080,7 @@ mod tests {
         // Collect heights through recovery.
         let mut heights = Vec::new();
         for _ in 0..20 {
-            let output = tick_spidey_sim(&mut sim, &config, Vec2::ZERO, &map, 1.0 / 30.0);
+            let output = tick_spidey_sim(&mut sim, &config, Vec2::ZERO, &map, None, 1.0 / 30.0);
             heights.push(output.visual_height);
             if matches!(sim.state, SpideySimState::Idle) {
                 break;
@@ -1106,7 +1120,7 @@ mod tests {
 
         let mut final_height = f32::MAX;
         for _ in 0..20 {
-            let output = tick_spidey_sim(&mut sim, &config, Vec2::ZERO, &map, 1.0 / 30.0);
+            let output = tick_spidey_sim(&mut sim, &config, Vec2::ZERO, &map, None, 1.0 / 30.0);
             final_height = output.visual_height;
             if matches!(sim.state, SpideySimState::Idle) {
                 break;
@@ -1128,7 +1142,7 @@ mod tests {
             recoil_arc: 0.0,
         };
 
-        let output = tick_spidey_sim(&mut sim, &config, Vec2::ZERO, &map, 1.0 / 30.0);
+        let output = tick_spidey_sim(&mut sim, &config, Vec2::ZERO, &map, None, 1.0 / 30.0);
         assert!(
             output.visual_height < f32::EPSILON,
             "non-lunge recovery should have zero visual_height: {}",
@@ -0,0 +1,433 @@
+//! Data-driven first-person weapon definitions.
+//!
+//! Every weapon the player can hold is described in
+//! `assets/config/fp/weapons.ron`: how it fires (hitscan or flame strip), how
+//! hard it hits, how often, how far, what ammo it draws, how hit enemies
+//! react and which view-model sprite it shows. Single-player
+//! (`carcinisation_fps::player_attack`) and the server (`process_combat`,
+//! weapon-switch intents) read the same [`WeaponTable`], so tuning a weapon is
+//! a data edit on both authorities at once.
+//!
+//! Weapons are referenced by their string `id` in the table and by their
+//! slot (index into [`WeaponTable::weapons`]) on the wire and in the
+//! view-model, so adding a weapon that uses an existing fire mode is a table
+//! entry plus its sprite.
+
+use std::collections::HashSet;
+use std::num::NonZeroU64;
+
+use crate::combat::FirePose2d;
+use crate::config::PlayerFlamethrowerConfig;
+use crate::reaction::WeaponReactionProfile;
+
+/// Flame-strip parameters of a flame weapon.
+///
+/// Overlaid onto [`PlayerFlamethrowerConfig`] on load, so the stream visuals,
+/// `FlameStrip` damage sweep and burn ticks all follow the weapon table.
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, bevy::prelude::Reflect)]
+pub struct FlameParams {
+    /// Maximum reach of the flame from the shooter (world units).
+    pub range: f32,
+    /// Half-width of the damage strip (world units).
+    pub hit_half_width: f32,
+    /// Travel speed of stream samples (world units per second).
+    pub speed: f32,
+    /// Minimum interval between stream sample emissions (milliseconds).
+    pub emit_interval_ms: NonZeroU64,
+    /// Damage per tick while a target is inside the strip.
+    pub damage_per_tick: u32,
+    /// Interval between damage ticks (milliseconds).
+    pub tick_ms: NonZeroU64,
+}
+
+impl FlameParams {
+    /// Copy these parameters over the matching flamethrower config fields.
+    pub const fn apply_to(&self, config: &mut PlayerFlamethrowerConfig) {
+        config.range = self.range;
+        config.hit_half_width = self.hit_half_width;
+        config.speed = self.speed;
+        config.emit_interval_ms = self.emit_interval_ms;
+        config.damage_per_tick = self.damage_per_tick;
+        config.tick_ms = self.tick_ms;
+    }
+}
+
+/// How a weapon delivers damage.
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, bevy::prelude::Reflect)]
+pub enum WeaponFire {
+    /// Instant ray against enemy parts and projectiles.
+    Hitscan {
+        /// Base damage before part scaling and armour.
+        damage: u32,
+        /// Shots per second while the trigger is held (server cadence).
+        fire_rate: f32,
+        /// Maximum reach (world units). `None` = until the ray hits a wall.
+        #[serde(default)]
+        range: Option<f32>,
+        /// Half-angle of the shot cone (degrees). `0.0` = perfectly accurate.
+        #[serde(default)]
+        spread_degrees: f32,
+    },
+    /// Continuous flame strip.
+    Flame(FlameParams),
+}
+
+/// Ammo pool a weapon draws from.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, bevy::prelude::Reflect,
+)]
+pub enum AmmoKind {
+    /// Never runs dry.
+    #[default]
+    Infinite,
+    Bullets,
+    Fuel,
+}
+
+/// One weapon entry of `weapons.ron`.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, bevy::prelude::Reflect)]
+pub struct WeaponDef {
+    /// Unique id, referenced by `loadout`, `melee` and the net layer.
+    pub id: String,
+    /// View-model atlas directory, relative to `assets/`. The firing frames
+    /// live in the sibling `<sprite>_shooting` directory.
+    pub sprite: String,
+    pub fire: WeaponFire,
+    #[serde(default)]
+    pub ammo: AmmoKind,
+    /// Poise/knockback applied to hit enemies. Flame weapons leave this at
+    /// the default ([`WeaponReactionProfile::NONE`]).
+    #[serde(default)]
+    pub reaction: WeaponReactionProfile,
+}
+
+impl WeaponDef {
+    /// Whether this weapon fires a flame strip rather than a hitscan ray.
+    #[must_use]
+    pub const fn is_flame(&self) -> bool {
+        matches!(self.fire, WeaponFire::Flame(_))
+    }
+
+    /// Hitscan base damage, or `None` for flame weapons.
+    #[must_use]
+    pub const fn hitscan_damage(&self) -> Option<u32> {
+        match self.fire {
+            WeaponFire::Hitscan { damage, .. } => Some(damage),
+            WeaponFire::Flame(_) => None,
+        }
+    }
+
+    /// Hitscan reach, `None` when unlimited or not a hitscan weapon.
+    #[must_use]
+    pub const fn range(&self) -> Option<f32> {
+        match self.fire {
+            WeaponFire::Hitscan { range, .. } => range,
+            WeaponFire::Flame(_) => None,
+        }
+    }
+
+    /// Seconds between hitscan shots (`1 / fire_rate`); zero for flame weapons.
+    #[must_use]
+    pub fn cooldown_secs(&self) -> f32 {
+        match self.fire {
+            WeaponFire::Hitscan { fire_rate, .. } => fire_rate.recip(),
+            WeaponFire::Flame(_) => 0.0,
+        }
+    }
+
+    /// The pose a shot actually travels along once spread is applied.
+    ///
+    /// The yaw offset is a deterministic hash of the fire pose, so both
+    /// authorities and replays agree on where a spread shot lands.
+    #[must_use]
+    pub fn spread_pose(&self, pose: FirePose2d) -> FirePose2d {
+        let WeaponFire::Hitscan { spread_degrees, .. } = self.fire else {
+            return pose;
+        };
+        if spread_degrees <= 0.0 {
+            return pose;
+        }
+        let seed = pose.origin_xy.x.to_bits()
+            ^ pose.origin_xy.y.to_bits().rotate_left(11)
+            ^ pose.yaw.to_bits().rotate_left(22);
+        let offset = crate::hash_util::signed_unit(seed) * spread_degrees.to_radians();
+        FirePose2d::new(pose.origin_xy, pose.yaw + offset, pose.visual_pitch_px)
+    }
+}
+
+/// All weapon definitions plus the switchable loadout.
+///
+/// Loaded from `assets/config/fp/weapons.ron`.
+/// Used by both singleplayer and multiplayer (client + server).
+#[derive(
+    Clone, Debug, PartialEq, serde::Deserialize, bevy::prelude::Resource, bevy::prelude::Reflect,
+)]
+#[reflect(Resource)]
+#[serde(rename = "WeaponTable")]
+pub struct WeaponTable {
+    /// Weapons cycled by the weapon-switch input, in order.
+    pub loadout: Vec<String>,
+    /// Weapon used by the melee input. Not part of the switch cycle.
+    pub melee: String,
+    pub weapons: Vec<WeaponDef>,
+}
+
+impl WeaponTable {
+    /// Load and validate the shipped weapon table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weapons.ron` fails [`Self::validate`].
+    #[must_use]
+    pub fn load() -> Self {
+        let table: Self = carcinisation_core::ron_config!("assets/config/fp/weapons.ron");
+        if let Err(err) = table.validate() {
+            panic!("invalid weapons.ron: {err}");
+        }
+        table
+    }
+
+    /// Check ids, references and value ranges.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the first problem found.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.weapons.len() > usize::from(u8::MAX) + 1 {
+            return Err(format!(
+                "{} weapons do not fit a one-byte slot",
+                self.weapons.len()
+            ));
+        }
+        let mut ids = HashSet::new();
+        for weapon in &self.weapons {
+            if !ids.insert(weapon.id.as_str()) {
+                return Err(format!("duplicate weapon id '{}'", weapon.id));
+            }
+            if weapon.sprite.is_empty() {
+                return Err(format!("weapon '{}' has no sprite", weapon.id));
+            }
+            match weapon.fire {
+                WeaponFire::Hitscan {
+                    fire_rate,
+                    range,
+                    spread_degrees,
+                    ..
+                } => {
+                    if !(fire_rate.is_finite() && fire_rate > 0.0) {
+                        return Err(format!("weapon '{}': fire_rate must be > 0", weapon.id));
+                    }
+                    if range.is_some_and(|r| !(r.is_finite() && r > 0.0)) {
+                        return Err(format!("weapon '{}': range must be > 0", weapon.id));
+                    }
+                    if !(spread_degrees.is_finite() && (0.0..90.0).contains(&spread_degrees)) {
+                        return Err(format!(
+                            "weapon '{}': spread_degrees must be in [0, 90)",
+                            weapon.id
+                        ));
+                    }
+                }
+                WeaponFire::Flame(flame) => {
+                    let positive = |v: f32| v.is_finite() && v > 0.0;
+                    if !(positive(flame.range)
+                        && positive(flame.hit_half_width)
+                        && positive(flame.speed))
+                    {
+                        return Err(format!(
+                            "weapon '{}': flame range, hit_half_width and speed must be > 0",
+                            weapon.id
+                        ));
+                    }
+                }
+            }
+        }
+        if self.loadout.is_empty() {
+            return Err("loadout is empty".into());
+        }
+        if let Some(missing) = self.loadout.iter().find(|id| self.get(id).is_none()) {
+            return Err(format!("loadout references unknown weapon '{missing}'"));
+        }
+        match self.get(&self.melee) {
+            None => Err(format!("melee references unknown weapon '{}'", self.melee)),
+            Some(def) if def.hitscan_damage().is_none() => {
+                Err(format!("melee weapon '{}' must be hitscan", self.melee))
+            }
+            Some(_) => Ok(()),
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, id: &str) -> Option<&WeaponDef> {
+        self.weapons.iter().find(|weapon| weapon.id == id)
+    }
+
+    /// Slot of the weapon `id`: its index in [`Self::weapons`]. Slots are
+    /// what the wire and the view-model carry; both peers ship the same table.
+    #[must_use]
+    pub fn slot_of(&self, id: &str) -> Option<u8> {
+        self.weapons
+            .iter()
+            .position(|weapon| weapon.id == id)
+            .and_then(|index| u8::try_from(index).ok())
+    }
+
+    /// The weapon in `slot`, if the table has one.
+    #[must_use]
+    pub fn by_slot(&self, slot: u8) -> Option<&WeaponDef> {
+        self.weapons.get(usize::from(slot))
+    }
+
+    /// The melee weapon. Present in any table that passed validation.
+    #[must_use]
+    pub fn melee(&self) -> Option<&WeaponDef> {
+        self.get(&self.melee)
+    }
+
+    /// The loadout entry after `current`, wrapping. Weapons outside the
+    /// loadout (e.g. melee) switch to the first entry.
+    #[must_use]
+    pub fn next_in_loadout(&self, current: &str) -> Option<&str> {
+        let next = self
+            .loadout
+            .iter()
+            .position(|entry| entry == current)
+            .map_or(0, |index| (index + 1) % self.loadout.len());
+        self.loadout.get(next).map(String::as_str)
+    }
+
+    /// Flame-strip parameters of the first flame weapon, if any.
+    #[must_use]
+    pub fn flame_params(&self) -> Option<FlameParams> {
+        self.weapons.iter().find_map(|weapon| match weapon.fire {
+            WeaponFire::Flame(flame) => Some(flame),
+            WeaponFire::Hitscan { .. } => None,
+        })
+    }
+}
+
+impl Default for WeaponTable {
+    fn default() -> Self {
+        Self {
+            loadout: vec!["flamethrower".into(), "pistol".into()],
+            melee: "melee".into(),
+            weapons: vec![
+                WeaponDef {
+                    id: "pistol".into(),
+                    sprite: "sprites/ui/stage_gun_weapon".into(),
+                    fire: WeaponFire::Hitscan {
+                        damage: 37,
+                        fire_rate: 3.0,
+                        range: None,
+                        spread_degrees: 0.0,
+                    },
+                    ammo: AmmoKind::Infinite,
+                    // 4 pistol shots to stagger; barely perceptible nudge.
+                    reaction: WeaponReactionProfile {
+                        poise_damage: 25.0,
+                        knockback_distance: 0.05,
+                        knockback_duration: 0.12,
+                    },
+                },
+                WeaponDef {
+                    id: "melee".into(),
+                    sprite: "sprites/attacks/player_melee".into(),
+                    fire: WeaponFire::Hitscan {
+                        damage: 111,
+                        fire_rate: 3.0,
+                        range: Some(1.1),
+                        spread_degrees: 0.0,
+                    },
+                    ammo: AmmoKind::Infinite,
+                    // 2 melee hits to stagger; a real shove.
+                    reaction: WeaponReactionProfile {
+                        poise_damage: 60.0,
+                        knockback_distance: 0.3,
+                        knockback_duration: 0.18,
+                    },
+                },
+                WeaponDef {
+                    id: "flamethrower".into(),
+                    sprite: "sprites/ui/stage_flamethrower_weapon".into(),
+                    fire: WeaponFire::Flame(FlameParams {
+                        range: 5.0,
+                        hit_half_width: 0.5,
+                        speed: 4.8,
+                        emit_interval_ms: NonZeroU64::new(40).unwrap(),
+                        damage_per_tick: 35,
+                        tick_ms: NonZeroU64::new(60).unwrap(),
+                    }),
+                    ammo: AmmoKind::Fuel,
+                    reaction: WeaponReactionProfile::NONE,
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_math::Vec2;
+
+    #[test]
+    fn shipped_table_matches_default() {
+        assert_eq!(WeaponTable::load(), WeaponTable::default());
+    }
+
+    #[test]
+    fn validate_rejects_bad_references() {
+        let mut table = WeaponTable::default();
+        table.loadout.push("railgun".into());
+        assert!(table.validate().unwrap_err().contains("railgun"));
+
+        let mut table = WeaponTable::default();
+        table.weapons[0].sprite.clear();
+        assert!(table.validate().unwrap_err().contains("sprite"));
+
+        let mut table = WeaponTable::default();
+        table.melee = "flamethrower".into();
+        assert!(table.validate().is_err());
+
+        let mut table = WeaponTable::default();
+        let pistol = table.weapons[0].clone();
+        table.weapons.push(pistol);
+        assert!(table.validate().unwrap_err().contains("duplicate"));
+    }
+
+    #[test]
+    fn slots_index_the_weapon_list() {
+        let table = WeaponTable::default();
+        for (index, weapon) in table.weapons.iter().enumerate() {
+            let slot = table.slot_of(&weapon.id).unwrap();
+            assert_eq!(usize::from(slot), index);
+            assert_eq!(table.by_slot(slot), Some(weapon));
+        }
+        assert_eq!(table.slot_of("railgun"), None);
+        assert_eq!(table.by_slot(u8::MAX), None);
+    }
+
+    #[test]
+    fn next_in_loadout_wraps_and_leaves_melee() {
+        let table = WeaponTable::default();
+        assert_eq!(table.next_in_loadout("flamethrower"), Some("pistol"));
+        assert_eq!(table.next_in_loadout("pistol"), Some("flamethrower"));
+        assert_eq!(table.next_in_loadout("melee"), Some("flamethrower"));
+    }
+
+    #[test]
+    fn spread_is_deterministic_and_bounded() {
+        let mut def = WeaponTable::default().get("pistol").unwrap().clone();
+        let pose = FirePose2d::new(Vec2::new(2.5, 3.5), 0.4, 0.0);
+        assert_eq!(def.spread_pose(pose), pose);
+
+        def.fire = WeaponFire::Hitscan {
+            damage: 10,
+            fire_rate: 1.0,
+            range: None,
+            spread_degrees: 5.0,
+        };
+        let spread = def.spread_pose(pose);
+        assert_eq!(spread, def.spread_pose(pose));
+        assert!((spread.yaw - pose.yaw).abs() <= 5f32.to_radians());
+    }
+}
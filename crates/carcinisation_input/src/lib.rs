@@ -1,13 +1,24 @@
 //! GB-style input abstraction for Carcinisation.
 //!
-//! Defines the [`GBInput`] action enum (A, B, D-pad, Start, Select) and its
-//! default keyboard mapping. Shared across all game modes (ORS, FPS, menus).
+//! Defines the [`GBInput`] action enum (A, B, D-pad, Start, Select) and the
+//! player's [`InputBindings`] (one keyboard key and one gamepad button per
+//! action). Shared across all game modes (ORS, FPS, menus).
+//!
+//! Screens with their own action enums implement [`GBActions`] to map each
+//! action onto GB actions; [`GBActionsPlugin`] keeps their input maps in sync
+//! with the bindings when the player rebinds.
+//!
+//! Debug actions live in the separate [`GBDebugInput`] map with fixed keys,
+//! so release builds can leave them out entirely.
+
+use std::{collections::HashMap, marker::PhantomData};
 
 use bevy::prelude::*;
 use leafwing_input_manager::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// Gameboy-style input actions.
-#[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
+#[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect, Serialize, Deserialize)]
 pub enum GBInput {
     A,
     B,
@@ -17,59 +28,291 @@ pub enum GBInput {
     Right,
     Start,
     Select,
-    // DEBUG
-    DUp,
-    DDown,
-    DLeft,
-    DRight,
-    DToGame,
-    DToMainMenu,
-    DExit,
-}
-
-impl From<GBInput> for KeyCode {
-    fn from(x: GBInput) -> Self {
+}
+
+impl GBInput {
+    /// Every action, in the order the controls screen lists them.
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::Down,
+        Self::Left,
+        Self::Right,
+        Self::A,
+        Self::B,
+        Self::Start,
+        Self::Select,
+    ];
+
+    #[must_use]
+    pub const fn default_key(self) -> KeyCode {
+        match self {
+            Self::A => KeyCode::KeyX,
+            Self::B => KeyCode::ShiftLeft,
+            Self::Up => KeyCode::ArrowUp,
+            Self::Down => KeyCode::ArrowDown,
+            Self::Left => KeyCode::ArrowLeft,
+            Self::Right => KeyCode::ArrowRight,
+            Self::Start => KeyCode::Enter,
+            Self::Select => KeyCode::KeyZ,
+        }
+    }
+
+    #[must_use]
+    pub const fn default_button(self) -> GamepadButton {
+        match self {
+            Self::A => GamepadButton::South,
+            Self::B => GamepadButton::East,
+            Self::Up => GamepadButton::DPadUp,
+            Self::Down => GamepadButton::DPadDown,
+            Self::Left => GamepadButton::DPadLeft,
+            Self::Right => GamepadButton::DPadRight,
+            Self::Start => GamepadButton::Start,
+            Self::Select => GamepadButton::Select,
+        }
+    }
+}
+
+/// Debug-only actions with fixed keyboard keys.
+#[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
+pub enum GBDebugInput {
+    Up,
+    Down,
+    Left,
+    Right,
+    ToGame,
+    ToMainMenu,
+    Exit,
+}
+
+impl From<GBDebugInput> for KeyCode {
+    fn from(x: GBDebugInput) -> Self {
         match x {
-            GBInput::A => Self::KeyX,
-            GBInput::B => Self::ShiftLeft,
-            GBInput::Up => Self::ArrowUp,
-            GBInput::Down => Self::ArrowDown,
-            GBInput::Left => Self::ArrowLeft,
-            GBInput::Right => Self::ArrowRight,
-            GBInput::Start => Self::Enter,
-            GBInput::Select => Self::KeyZ,
-            // DEBUG
-            GBInput::DUp => Self::KeyW,
-            GBInput::DDown => Self::KeyS,
-            GBInput::DLeft => Self::KeyA,
-            GBInput::DRight => Self::KeyD,
-            GBInput::DToGame => Self::KeyI,
-            GBInput::DToMainMenu => Self::Delete,
-            GBInput::DExit => Self::Escape,
+            GBDebugInput::Up => Self::KeyW,
+            GBDebugInput::Down => Self::KeyS,
+            GBDebugInput::Left => Self::KeyA,
+            GBDebugInput::Right => Self::KeyD,
+            GBDebugInput::ToGame => Self::KeyI,
+            GBDebugInput::ToMainMenu => Self::Delete,
+            GBDebugInput::Exit => Self::Escape,
         }
     }
 }
 
-/// Spawn the default GB input resources (action state + key map).
-pub fn init_gb_input(mut commands: Commands) {
-    let mappings: Vec<(GBInput, KeyCode)> = vec![
-        (GBInput::Left, GBInput::Left.into()),
-        (GBInput::Up, GBInput::Up.into()),
-        (GBInput::Right, GBInput::Right.into()),
-        (GBInput::Down, GBInput::Down.into()),
-        (GBInput::B, GBInput::B.into()),
-        (GBInput::A, GBInput::A.into()),
-        (GBInput::Start, GBInput::Start.into()),
-        (GBInput::Select, GBInput::Select.into()),
-        // DEBUG
-        (GBInput::DToGame, GBInput::DToGame.into()),
-        (GBInput::DToMainMenu, GBInput::DToMainMenu.into()),
-        (GBInput::DExit, GBInput::DExit.into()),
-        (GBInput::DLeft, GBInput::DLeft.into()),
-        (GBInput::DUp, GBInput::DUp.into()),
-        (GBInput::DRight, GBInput::DRight.into()),
-        (GBInput::DDown, GBInput::DDown.into()),
+/// A physical input two actions are both bound to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BindingConflict {
+    Key(KeyCode, GBInput, GBInput),
+    Button(GamepadButton, GBInput, GBInput),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Resource, Serialize, Deserialize)]
+#[serde(default)]
+/// The player's keyboard and gamepad binding for each [`GBInput`]. Persisted
+/// by the app between runs.
+pub struct InputBindings {
+    pub keys: HashMap<GBInput, KeyCode>,
+    pub buttons: HashMap<GBInput, GamepadButton>,
+}
+
+impl Default for InputBindings {
+    fn default() -> Self {
+        Self {
+            keys: GBInput::ALL.map(|a| (a, a.default_key())).into(),
+            buttons: GBInput::ALL.map(|a| (a, a.default_button())).into(),
+        }
+    }
+}
+
+impl InputBindings {
+    #[must_use]
+    pub fn key(&self, action: GBInput) -> KeyCode {
+        self.keys
+            .get(&action)
+            .copied()
+            .unwrap_or_else(|| action.default_key())
+    }
+
+    #[must_use]
+    pub fn button(&self, action: GBInput) -> GamepadButton {
+        self.buttons
+            .get(&action)
+            .copied()
+            .unwrap_or_else(|| action.default_button())
+    }
+
+    /// Bind `key` to `action`. An action already using `key` takes over
+    /// `action`'s previous key, so bindings never conflict.
+    pub fn bind_key(&mut self, action: GBInput, key: KeyCode) {
+        let previous = self.key(action);
+        if let Some(other) = GBInput::ALL
+            .into_iter()
+            .find(|&other| other != action && self.key(other) == key)
+        {
+            self.keys.insert(other, previous);
+        }
+        self.keys.insert(action, key);
+    }
+
+    /// Bind `button` to `action`, swapping like [`Self::bind_key`].
+    pub fn bind_button(&mut self, action: GBInput, button: GamepadButton) {
+        let previous = self.button(action);
+        if let Some(other) = GBInput::ALL
+            .into_iter()
+            .find(|&other| other != action && self.button(other) == button)
+        {
+            self.buttons.insert(other, previous);
+        }
+        self.buttons.insert(action, button);
+    }
+
+    /// Pairs of actions sharing a key or button.
+    #[must_use]
+    pub fn conflicts(&self) -> Vec<BindingConflict> {
+        let mut conflicts = Vec::new();
+        for (i, &a) in GBInput::ALL.iter().enumerate() {
+            for &b in &GBInput::ALL[i + 1..] {
+                if self.key(a) == self.key(b) {
+                    conflicts.push(BindingConflict::Key(self.key(a), a, b));
+                }
+                if self.button(a) == self.button(b) {
+                    conflicts.push(BindingConflict::Button(self.button(a), a, b));
+                }
+            }
+        }
+        conflicts
+    }
+
+    /// Input map binding each screen action to the key and button of the GB
+    /// action it maps to.
+    #[must_use]
+    pub fn input_map<A: Actionlike + Copy>(&self, actions: &[(A, GBInput)]) -> InputMap<A> {
+        let mut map = InputMap::default();
+        for &(action, gb) in actions {
+            map.insert(action, self.key(gb));
+            map.insert(action, self.button(gb));
+        }
+        map
+    }
+}
+
+/// Action enums driven by GB bindings.
+pub trait GBActions: Actionlike + Copy {
+    /// The GB action(s) each action is triggered by.
+    const GB_ACTIONS: &'static [(Self, GBInput)];
+}
+
+impl GBActions for GBInput {
+    const GB_ACTIONS: &'static [(Self, GBInput)] = &[
+        (Self::A, Self::A),
+        (Self::B, Self::B),
+        (Self::Up, Self::Up),
+        (Self::Down, Self::Down),
+        (Self::Left, Self::Left),
+        (Self::Right, Self::Right),
+        (Self::Start, Self::Start),
+        (Self::Select, Self::Select),
     ];
-    commands.insert_resource(ActionState::<GBInput>::default());
-    commands.insert_resource(InputMap::<GBInput>::new(mappings));
+}
+
+/// Insert the action state and input map of `A`, built from the current
+/// bindings (or the defaults when none are loaded).
+pub fn init_gb_actions<A: GBActions>(mut commands: Commands, bindings: Option<Res<InputBindings>>) {
+    let map = bindings
+        .as_deref()
+        .cloned()
+        .unwrap_or_default()
+        .input_map(A::GB_ACTIONS);
+    commands.insert_resource(ActionState::<A>::default());
+    commands.insert_resource(map);
+}
+
+/// Rebuild the input map of `A` after the bindings change.
+pub fn sync_gb_actions<A: GBActions>(bindings: Res<InputBindings>, mut map: ResMut<InputMap<A>>) {
+    *map = bindings.input_map(A::GB_ACTIONS);
+}
+
+/// Spawn the GB input resources (action state + key map).
+pub fn init_gb_input(commands: Commands, bindings: Option<Res<InputBindings>>) {
+    init_gb_actions::<GBInput>(commands, bindings);
+}
+
+/// Spawn the debug input resources.
+pub fn init_gb_debug_input(mut commands: Commands) {
+    let mappings = [
+        GBDebugInput::ToGame,
+        GBDebugInput::ToMainMenu,
+        GBDebugInput::Exit,
+        GBDebugInput::Left,
+        GBDebugInput::Up,
+        GBDebugInput::Right,
+        GBDebugInput::Down,
+    ]
+    .map(|action| (action, KeyCode::from(action)));
+    commands.insert_resource(ActionState::<GBDebugInput>::default());
+    commands.insert_resource(InputMap::<GBDebugInput>::new(mappings));
+}
+
+/// Registers `A` with the input manager and keeps its input map in sync with
+/// [`InputBindings`].
+pub struct GBActionsPlugin<A>(PhantomData<A>);
+
+impl<A> Default for GBActionsPlugin<A> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A: GBActions> Plugin for GBActionsPlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(InputManagerPlugin::<A>::default())
+            .add_systems(Startup, init_gb_actions::<A>)
+            .add_systems(
+                PreUpdate,
+                sync_gb_actions::<A>.run_if(
+                    resource_exists_and_changed::<InputBindings>
+                        .and(resource_exists::<InputMap<A>>),
+                ),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_have_no_conflicts() {
+        assert!(InputBindings::default().conflicts().is_empty());
+    }
+
+    #[test]
+    fn rebinding_a_used_key_swaps_it() {
+        let mut bindings = InputBindings::default();
+        bindings.bind_key(GBInput::A, KeyCode::Enter);
+        assert_eq!(bindings.key(GBInput::A), KeyCode::Enter);
+        assert_eq!(bindings.key(GBInput::Start), KeyCode::KeyX);
+        assert!(bindings.conflicts().is_empty());
+
+        bindings.bind_button(GBInput::Up, GamepadButton::South);
+        assert_eq!(bindings.button(GBInput::A), GamepadButton::DPadUp);
+        assert!(bindings.conflicts().is_empty());
+    }
+
+    #[test]
+    fn conflicts_are_reported() {
+        let mut bindings = InputBindings::default();
+        bindings.keys.insert(GBInput::B, KeyCode::KeyX);
+        assert_eq!(
+            bindings.conflicts(),
+            vec![BindingConflict::Key(KeyCode::KeyX, GBInput::A, GBInput::B)]
+        );
+    }
+
+    #[test]
+    fn missing_entries_fall_back_to_defaults() {
+        let bindings: InputBindings = ron::from_str("(keys: {A: KeyC})").unwrap();
+        assert_eq!(bindings.key(GBInput::A), KeyCode::KeyC);
+        assert_eq!(bindings.key(GBInput::B), GBInput::B.default_key());
+        assert_eq!(bindings.button(GBInput::B), GBInput::B.default_button());
+    }
 }
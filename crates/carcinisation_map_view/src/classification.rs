@@ -164,6 +164,8 @@ mod tests {
             width,
             height,
             cells,
+            mechanisms: carcinisation_fps_core::MapMechanisms::default(),
+            heights: Vec::new(),
         }
     }
 
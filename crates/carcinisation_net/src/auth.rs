@@ -0,0 +1,279 @@
+//! Netcode connect-token authentication.
+//!
+//! In secure mode the dedicated server holds a 32-byte private key and only
+//! admits clients presenting a connect token sealed with that key. Tokens are
+//! minted by the `carcinisation_server mint-token` subcommand or by its small
+//! HTTP `token-service`, and carry the [`ConnectUserData`] (player name and
+//! [`ConnectMode`]) so the server no longer trusts client-supplied user data.
+//!
+//! Key files hold the key as 64 hex characters. Token files hold the raw
+//! netcode token bytes, exactly as served by the token service.
+//!
+//! Unsecure mode (no key, no token) stays the default for local development.
+
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::path::Path;
+use std::time::Duration;
+
+use bevy_replicon_renet2::netcode::{
+    ConnectToken, NETCODE_KEY_BYTES, NETCODE_USER_DATA_BYTES, generate_random_bytes,
+};
+
+use crate::protocol::{ConnectMode, ConnectUserData};
+use crate::transport::MultiplayerTransport;
+
+/// Shared netcode private key.
+pub type PrivateKey = [u8; NETCODE_KEY_BYTES];
+
+/// Seconds a freshly minted token stays valid for its first handshake.
+pub const CONNECT_TOKEN_EXPIRE_SECS: u64 = 300;
+
+/// Seconds of silence before an authenticated connection times out.
+pub const CONNECT_TOKEN_TIMEOUT_SECS: i32 = 15;
+
+/// Timeout for each read/write when fetching a token over HTTP.
+const TOKEN_FETCH_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Generate a fresh random private key.
+#[must_use]
+pub fn generate_private_key() -> PrivateKey {
+    generate_random_bytes()
+}
+
+/// Format a key as lowercase hex (the key file format).
+#[must_use]
+pub fn private_key_to_hex(key: &PrivateKey) -> String {
+    key.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Parse a key from hex. Surrounding whitespace is ignored.
+///
+/// # Errors
+///
+/// Returns an error unless the text is exactly 64 hex characters.
+pub fn parse_private_key(text: &str) -> Result<PrivateKey, String> {
+    let text = text.trim();
+    if text.len() != NETCODE_KEY_BYTES * 2 || !text.is_ascii() {
+        return Err(format!(
+            "private key must be {} hex characters",
+            NETCODE_KEY_BYTES * 2
+        ));
+    }
+    let mut key = [0u8; NETCODE_KEY_BYTES];
+    for (byte, pair) in key.iter_mut().zip(text.as_bytes().chunks(2)) {
+        let pair = std::str::from_utf8(pair).map_err(|e| e.to_string())?;
+        *byte = u8::from_str_radix(pair, 16)
+            .map_err(|_| format!("private key has invalid hex digits {pair:?}"))?;
+    }
+    Ok(key)
+}
+
+/// Read and parse a key file.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or does not hold a valid key.
+pub fn load_private_key(path: &Path) -> Result<PrivateKey, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read private key {}: {e}", path.display()))?;
+    parse_private_key(&text).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Everything the issuer needs to mint one connect token.
+#[derive(Debug, Clone)]
+pub struct TokenRequest {
+    /// Netcode client id. Must be unique among connected clients.
+    pub client_id: u64,
+    /// Handshake payload sealed into the token.
+    pub user_data: ConnectUserData,
+    /// Which server socket the token is valid for.
+    pub transport: MultiplayerTransport,
+    /// Public addresses of that socket, as configured on the server.
+    pub server_addresses: Vec<SocketAddr>,
+}
+
+impl TokenRequest {
+    /// Request for a UDP player or monitor token with a random client id.
+    #[must_use]
+    pub fn new(
+        player_name: Option<String>,
+        mode: ConnectMode,
+        server_addresses: Vec<SocketAddr>,
+    ) -> Self {
+        Self {
+            client_id: u64::from_le_bytes(generate_random_bytes()),
+            user_data: ConnectUserData { mode, player_name },
+            transport: MultiplayerTransport::NativeUdp,
+            server_addresses,
+        }
+    }
+}
+
+/// Seal a connect token with the server's private key.
+///
+/// `now` is the wall-clock time since the Unix epoch.
+///
+/// # Errors
+///
+/// Returns an error if netcode rejects the request (e.g. no or too many
+/// server addresses).
+pub fn mint_connect_token(
+    private_key: &PrivateKey,
+    request: &TokenRequest,
+    now: Duration,
+) -> Result<ConnectToken, String> {
+    let user_data: [u8; NETCODE_USER_DATA_BYTES] = request.user_data.to_user_data();
+    ConnectToken::generate(
+        now,
+        crate::PROTOCOL_ID,
+        CONNECT_TOKEN_EXPIRE_SECS,
+        request.client_id,
+        CONNECT_TOKEN_TIMEOUT_SECS,
+        request.transport.socket_id(),
+        request.server_addresses.clone(),
+        Some(&user_data),
+        private_key,
+    )
+    .map_err(|e| format!("failed to mint connect token: {e}"))
+}
+
+/// Serialize a token to its wire/file representation.
+///
+/// # Errors
+///
+/// Returns an error if the token cannot be written.
+pub fn encode_connect_token(token: &ConnectToken) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::new();
+    token
+        .write(&mut bytes)
+        .map_err(|e| format!("failed to encode connect token: {e}"))?;
+    Ok(bytes)
+}
+
+/// Parse a token from its wire/file representation.
+///
+/// # Errors
+///
+/// Returns an error if the bytes are not a valid connect token.
+pub fn decode_connect_token(bytes: &[u8]) -> Result<ConnectToken, String> {
+    ConnectToken::read(&mut &bytes[..]).map_err(|e| format!("invalid connect token: {e}"))
+}
+
+/// Load a token from a file path or an `http://` URL (e.g. the local token
+/// service: `http://127.0.0.1:7150/token?name=crab`).
+///
+/// # Errors
+///
+/// Returns an error if the token cannot be read, fetched or parsed.
+pub fn load_connect_token(source: &str) -> Result<ConnectToken, String> {
+    let bytes = if source.starts_with("http://") {
+        fetch_http(source)?
+    } else {
+        std::fs::read(source).map_err(|e| format!("failed to read token {source}: {e}"))?
+    };
+    decode_connect_token(&bytes)
+}
+
+/// Minimal blocking HTTP/1.0 GET. Only plain `http://` is supported: the
+/// token service is meant to run next to the server, behind whatever proxy
+/// terminates TLS.
+fn fetch_http(url: &str) -> Result<Vec<u8>, String> {
+    let rest = url
+        .strip_prefix("http://")
+        .ok_or_else(|| format!("unsupported token URL {url}"))?;
+    let (host, path) = rest
+        .split_once('/')
+        .map_or((rest, "/".to_string()), |(host, path)| {
+            (host, format!("/{path}"))
+        });
+    let authority = if host.contains(':') {
+        host.to_string()
+    } else {
+        format!("{host}:80")
+    };
+
+    let mut stream =
+        TcpStream::connect(&authority).map_err(|e| format!("failed to reach {authority}: {e}"))?;
+    let _ = stream.set_read_timeout(Some(TOKEN_FETCH_TIMEOUT));
+    let _ = stream.set_write_timeout(Some(TOKEN_FETCH_TIMEOUT));
+    write!(
+        stream,
+        "GET {path} HTTP/1.0\r\nHost: {host}\r\nConnection: close\r\n\r\n"
+    )
+    .map_err(|e| format!("failed to request token from {url}: {e}"))?;
+
+    let mut response = Vec::new();
+    stream
+        .read_to_end(&mut response)
+        .map_err(|e| format!("failed to read token from {url}: {e}"))?;
+    parse_http_response(&response).map_err(|e| format!("{url}: {e}"))
+}
+
+/// Split an HTTP response into status and body; non-200 is an error carrying
+/// the body text.
+fn parse_http_response(response: &[u8]) -> Result<Vec<u8>, String> {
+    let header_end = response
+        .windows(4)
+        .position(|w| w == b"\r\n\r\n")
+        .ok_or("malformed HTTP response")?;
+    let head = String::from_utf8_lossy(&response[..header_end]);
+    let body = &response[header_end + 4..];
+    let status = head
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .ok_or("malformed HTTP status line")?;
+    if status == "200" {
+        Ok(body.to_vec())
+    } else {
+        Err(format!(
+            "token service returned {status}: {}",
+            String::from_utf8_lossy(body).trim()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_addr() -> SocketAddr {
+        "127.0.0.1:7142".parse().unwrap()
+    }
+
+    #[test]
+    fn private_key_hex_roundtrip() {
+        let key = generate_private_key();
+        let hex = private_key_to_hex(&key);
+        assert_eq!(hex.len(), 64);
+        assert_eq!(parse_private_key(&format!("{hex}\n")).unwrap(), key);
+        assert!(parse_private_key("abcd").is_err());
+        assert!(parse_private_key(&"zz".repeat(32)).is_err());
+    }
+
+    #[test]
+    fn minted_token_survives_encoding() {
+        let key = generate_private_key();
+        let request = TokenRequest::new(
+            Some("crab".to_string()),
+            ConnectMode::Player,
+            vec![test_addr()],
+        );
+        let token = mint_connect_token(&key, &request, Duration::from_secs(1_000)).unwrap();
+        let bytes = encode_connect_token(&token).unwrap();
+        let decoded = decode_connect_token(&bytes).unwrap();
+        assert_eq!(encode_connect_token(&decoded).unwrap(), bytes);
+    }
+
+    #[test]
+    fn parse_http_response_checks_status() {
+        assert_eq!(
+            parse_http_response(b"HTTP/1.0 200 OK\r\nContent-Length: 3\r\n\r\nabc").unwrap(),
+            b"abc"
+        );
+        let err = parse_http_response(b"HTTP/1.0 400 Bad Request\r\n\r\nbad name\n").unwrap_err();
+        assert!(err.contains("400") && err.contains("bad name"), "{err}");
+        assert!(parse_http_response(b"garbage").is_err());
+    }
+}
@@ -2,16 +2,18 @@ use bevy::prelude::*;
 use bevy_replicon::prelude::*;
 
 use crate::protocol::{
-    ClientIntent, DamageEffect, DeathEffect, EnemyAttackVisual, FlameActive, FlameCharMark,
-    HitConfirm, InputAck, MonitorAck, MuzzleFlash, PickupEffect, PlayerIdAssigned,
+    ChatMessage, ChatSend, ClientIntent, DamageEffect, DeathEffect, EnemyAttackVisual, FlameActive,
+    FlameCharMark, HitConfirm, InputAck, MonitorAck, MuzzleFlash, PickupEffect, PlayerIdAssigned,
 };
 
-/// Register reliable (ordered) channels for input and identity.
+/// Register reliable (ordered) channels for input, identity and chat.
 pub fn register_reliable_channels(app: &mut App) {
     app.add_client_event::<ClientIntent>(Channel::Ordered)
+        .add_client_event::<ChatSend>(Channel::Ordered)
         .add_server_event::<PlayerIdAssigned>(Channel::Ordered)
         .add_server_event::<MonitorAck>(Channel::Ordered)
-        .add_server_event::<InputAck>(Channel::Ordered);
+        .add_server_event::<InputAck>(Channel::Ordered)
+        .add_server_event::<ChatMessage>(Channel::Ordered);
 }
 
 /// Register unreliable (unordered) channels for visual/effect events.
@@ -2,6 +2,9 @@ use bevy::prelude::*;
 use bevy_math::Vec2;
 use serde::{Deserialize, Serialize};
 
+use carcinisation_fps_core::game_mode::{GameModeKind, RoundOutcome};
+use carcinisation_fps_core::weapons::{WeaponDef, WeaponTable};
+
 use crate::protocol::{NetPickupKind, NetworkObjectId, Owner, PlayerId};
 
 /// Net-safe enemy state enum.
@@ -62,14 +65,26 @@ impl AvatarPaletteVariant {
     pub const COUNT: usize = 6;
 }
 
-/// Net-safe attack ID enum.
+/// Net-safe attack ID: the weapon's slot in `weapons.ron`.
+///
+/// Client and server ship the same [`WeaponTable`], so the one-byte slot
+/// names the same weapon on both peers. The default is slot 0.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Reflect)]
 #[reflect(Serialize, Deserialize)]
-pub enum NetAttackId {
-    #[default]
-    None,
-    Melee,
-    Projectile,
+pub struct NetAttackId(pub u8);
+
+impl NetAttackId {
+    /// Wire id of the weapon-table entry `id`.
+    #[must_use]
+    pub fn of(weapons: &WeaponTable, id: &str) -> Option<Self> {
+        weapons.slot_of(id).map(Self)
+    }
+
+    /// The weapon-table entry this wire id stands for.
+    #[must_use]
+    pub fn weapon(self, weapons: &WeaponTable) -> Option<&WeaponDef> {
+        weapons.by_slot(self.0)
+    }
 }
 
 /// Player network state.
@@ -183,6 +198,19 @@ pub struct NetPickup {
     pub respawnable: bool,
 }
 
+/// Replicated door state. One entity per door, in map authoring order.
+///
+/// Clients write `open` into their map's door so collision, prediction and
+/// rendering follow the server.
+#[derive(Component, Debug, Clone, Copy, Serialize, Deserialize, Reflect)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct NetDoor {
+    /// Index into the map's `mechanisms.doors`.
+    pub index: u16,
+    /// Travel progress: 0 = closed, 1 = fully open.
+    pub open: f32,
+}
+
 /// Replicated ground fire hazard spawned when an enemy dies from burning.
 #[derive(Component, Debug, Clone, Serialize, Deserialize, Reflect)]
 #[reflect(Component, Serialize, Deserialize)]
@@ -210,6 +238,48 @@ pub struct NetBurning {
     pub intensity: f32,
 }
 
+/// Replicated per-player match score. Lives on the player entity.
+///
+/// Kills are enemies the player landed the killing blow on (hitscan) or last
+/// set alight (burn). Reset when a match restarts.
+#[derive(
+    Component, Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Reflect,
+)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct NetScore {
+    pub kills: u32,
+    pub deaths: u32,
+}
+
+/// Match phase, as seen by clients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[reflect(Serialize, Deserialize)]
+pub enum NetMatchPhase {
+    /// No players connected; the round clock is paused.
+    WaitingForPlayers,
+    InProgress,
+    /// Between rounds/waves. `outcome` says why the last one ended.
+    Intermission {
+        outcome: RoundOutcome,
+    },
+}
+
+/// Replicated match state singleton. Only spawned when the server runs a
+/// game mode.
+///
+/// Time fields are whole seconds so they replicate at most once per second.
+#[derive(Component, Debug, Clone, PartialEq, Serialize, Deserialize, Reflect)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct NetMatchState {
+    pub kind: GameModeKind,
+    pub phase: NetMatchPhase,
+    /// Co-op wave, 1-based. Always 1 in deathmatch.
+    pub wave: u32,
+    /// Seconds left in the round clock or the intermission, if either is
+    /// running.
+    pub remaining_secs: Option<u32>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,7 +295,7 @@ mod tests {
             player_id: PlayerId(1),
             position: Vec2::new(100.0, 200.0),
             angle: 1.57,
-            current_attack: NetAttackId::Projectile,
+            current_attack: NetAttackId(2),
             state: PlayerNetState::Alive,
             flame_active: false,
             avatar_palette_variant: None,
@@ -235,6 +305,17 @@ mod tests {
         assert!(matches!(back.state, PlayerNetState::Alive));
     }
 
+    #[test]
+    fn attack_ids_are_weapon_table_slots() {
+        let table = WeaponTable::default();
+        for weapon in &table.weapons {
+            let attack = NetAttackId::of(&table, &weapon.id).unwrap();
+            assert_eq!(attack.weapon(&table), Some(weapon));
+        }
+        assert_eq!(NetAttackId::of(&table, "railgun"), None);
+        assert_eq!(NetAttackId::default().weapon(&table), table.get("pistol"));
+    }
+
     #[test]
     fn net_enemy_roundtrip() {
         let enemy = NetEnemy {
@@ -345,7 +426,7 @@ mod tests {
             player_id: PlayerId(1),
             position: Vec2::new(100.0, 200.0),
             angle: 1.57,
-            current_attack: NetAttackId::Projectile,
+            current_attack: NetAttackId(2),
             state: PlayerNetState::Alive,
             flame_active: false,
             avatar_palette_variant: Some(AvatarPaletteVariant::Bca),
@@ -362,7 +443,7 @@ mod tests {
             player_id: PlayerId(1),
             position: Vec2::ZERO,
             angle: 0.0,
-            current_attack: NetAttackId::None,
+            current_attack: NetAttackId::default(),
             state: PlayerNetState::Alive,
             flame_active: false,
             avatar_palette_variant: None,
@@ -411,4 +492,33 @@ mod tests {
         assert!(back.available);
         assert_eq!(back.respawn_remaining, None);
     }
+
+    #[test]
+    fn net_door_roundtrip() {
+        let door = NetDoor {
+            index: 2,
+            open: 0.5,
+        };
+        let back = roundtrip_component(&door);
+        assert_eq!(back.index, 2);
+        assert!((back.open - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn net_match_state_roundtrip() {
+        let state = NetMatchState {
+            kind: GameModeKind::Deathmatch,
+            phase: NetMatchPhase::Intermission {
+                outcome: RoundOutcome::FragLimit { winner: 3 },
+            },
+            wave: 1,
+            remaining_secs: Some(7),
+        };
+        assert_eq!(roundtrip_component(&state), state);
+        let score = NetScore {
+            kills: 4,
+            deaths: 2,
+        };
+        assert_eq!(roundtrip_component(&score), score);
+    }
 }
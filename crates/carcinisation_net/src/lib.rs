@@ -1,6 +1,8 @@
 // carcinisation_net: Shared protocol + replication types
 #![allow(clippy::used_underscore_binding)]
 
+#[cfg(feature = "native")]
+pub mod auth;
 pub mod channels;
 pub mod components;
 pub mod plugin;
@@ -14,14 +16,20 @@ pub use components::{
     AvatarPaletteVariant, NetAttackId, NetEnemyState, NetEnemyType, NetProjectileType,
 };
 pub use components::{
-    NetBurning, NetEnemy, NetGroundFire, NetHealth, NetPickup, NetPlayer, NetProjectile,
-    NetSpeedModifier, PlayerNetState,
+    NetBurning, NetDoor, NetEnemy, NetGroundFire, NetHealth, NetMatchPhase, NetMatchState,
+    NetPickup, NetPlayer, NetProjectile, NetScore, NetSpeedModifier, PlayerNetState,
 };
 pub use protocol::{
+    // Chat
+    CHAT_MAX_CHARS,
+    ChatMessage,
+    ChatSend,
+    ChatSender,
     // Semantic intent protocol
     ClientIntent,
     // Connect mode
     ConnectMode,
+    ConnectUserData,
     // Events
     DamageEffect,
     DeathEffect,
@@ -41,6 +49,8 @@ pub use protocol::{
     PlayerActions,
     PlayerId,
     PlayerIdAssigned,
+    sanitize_chat_text,
+    sanitize_player_name,
 };
 pub use tick::{
     CombatSet, InputSequence, MovementSet, Tick, TickConfig, TickCounter, TickPlugin, TickSet,
@@ -3,8 +3,9 @@ use bevy_replicon::prelude::*;
 
 use crate::channels::{register_reliable_channels, register_unreliable_channels};
 use crate::components::{
-    AvatarPaletteVariant, NetBurning, NetEnemy, NetEnemyType, NetGroundFire, NetHealth, NetPickup,
-    NetPlayer, NetProjectile, NetSpeedModifier, PlayerNetState,
+    AvatarPaletteVariant, NetBurning, NetDoor, NetEnemy, NetEnemyType, NetGroundFire, NetHealth,
+    NetMatchPhase, NetMatchState, NetPickup, NetPlayer, NetProjectile, NetScore, NetSpeedModifier,
+    PlayerNetState,
 };
 use crate::protocol::{NetworkObjectId, Owner, PlayerId};
 use crate::tick::{TickConfig, TickCounter, TickPlugin};
@@ -48,9 +49,13 @@ fn register_types(app: &mut App) {
         .register_type::<NetSpeedModifier>()
         .register_type::<NetPickup>()
         .register_type::<NetGroundFire>()
+        .register_type::<NetDoor>()
         .register_type::<NetHealth>()
         .register_type::<NetBurning>()
         .register_type::<PlayerNetState>()
+        .register_type::<NetScore>()
+        .register_type::<NetMatchPhase>()
+        .register_type::<NetMatchState>()
         .register_type::<TickConfig>()
         .register_type::<TickCounter>();
 }
@@ -62,8 +67,11 @@ fn register_replication(app: &mut App) {
         .replicate::<NetSpeedModifier>()
         .replicate::<NetPickup>()
         .replicate::<NetGroundFire>()
+        .replicate::<NetDoor>()
         .replicate::<NetHealth>()
         .replicate::<NetBurning>()
+        .replicate::<NetScore>()
+        .replicate::<NetMatchState>()
         .replicate::<PlayerId>()
         .replicate::<Owner>();
 }
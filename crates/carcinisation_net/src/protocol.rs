@@ -11,9 +11,11 @@ use crate::tick::InputSequence;
 
 /// Client connection mode, communicated via renet2 `user_data` during handshake.
 ///
-/// Encoded into the 256-byte `user_data` field of `ClientAuthentication::Unsecure`.
-/// The server reads this in `handle_client_connect` to decide whether to spawn a
-/// player entity or treat the connection as a passive observer.
+/// Encoded into the 256-byte `user_data` field — supplied by the client in
+/// unsecure mode, or sealed into the connect token in secure mode (see
+/// [`ConnectUserData`]). The server reads this in `handle_client_connect` to
+/// decide whether to spawn a player entity or treat the connection as a
+/// passive observer.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum ConnectMode {
     /// Standard player client — server spawns a `NetPlayer` entity.
@@ -49,6 +51,64 @@ impl ConnectMode {
     }
 }
 
+/// Maximum player name length carried in the handshake `user_data`.
+pub const PLAYER_NAME_MAX_CHARS: usize = 24;
+
+/// Full handshake payload: connect mode plus an optional player name.
+///
+/// Layout: byte 0 is the [`ConnectMode`], byte 1 the name length, bytes 2..
+/// the ASCII name. A zero length means "no name", which is what unsecure
+/// clients send, so the layout stays compatible with
+/// [`ConnectMode::from_user_data`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ConnectUserData {
+    pub mode: ConnectMode,
+    pub player_name: Option<String>,
+}
+
+impl ConnectUserData {
+    /// Encode into the 256-byte `user_data` field. The name is sanitized
+    /// with [`sanitize_player_name`] first.
+    #[must_use]
+    pub fn to_user_data(&self) -> [u8; USER_DATA_BYTES] {
+        let mut data = self.mode.to_user_data();
+        if let Some(name) = self.player_name.as_deref().and_then(sanitize_player_name) {
+            let bytes = name.as_bytes();
+            data[1] = u8::try_from(bytes.len()).expect("name capped at PLAYER_NAME_MAX_CHARS");
+            data[2..2 + bytes.len()].copy_from_slice(bytes);
+        }
+        data
+    }
+
+    /// Decode from the 256-byte `user_data` field. Malformed names decode as
+    /// `None` rather than rejecting the connection.
+    #[must_use]
+    pub fn from_user_data(data: &[u8; USER_DATA_BYTES]) -> Self {
+        let len = usize::from(data[1]).min(PLAYER_NAME_MAX_CHARS);
+        let player_name = std::str::from_utf8(&data[2..2 + len])
+            .ok()
+            .and_then(sanitize_player_name);
+        Self {
+            mode: ConnectMode::from_user_data(data),
+            player_name,
+        }
+    }
+}
+
+/// Normalise a player name: keep ASCII letters, digits, `-` and `_`, and cap
+/// at [`PLAYER_NAME_MAX_CHARS`].
+///
+/// Returns `None` when nothing is left.
+#[must_use]
+pub fn sanitize_player_name(name: &str) -> Option<String> {
+    let clean: String = name
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'))
+        .take(PLAYER_NAME_MAX_CHARS)
+        .collect();
+    (!clean.is_empty()).then_some(clean)
+}
+
 // ---- Protocol types -------------------------------------------------------
 
 /// Stable player identifier.
@@ -128,6 +188,10 @@ impl PlayerActions {
 #[derive(Debug, Clone, Event, Serialize, Deserialize)]
 pub struct ClientIntent {
     pub sequence: InputSequence,
+    /// Newest `InputAck::last_processed_sequence` the client has received
+    /// (`InputSequence(0)` before the first ack). The server maps it back to
+    /// the tick that ack was sent on to rewind hitscan (lag compensation).
+    pub acked_sequence: InputSequence,
     /// Player-local movement intent: y = forward(+)/back(-), x = strafe right(+)/left(-).
     /// Normalized to unit length. Server scales by `move_speed` × dt.
     pub movement: Vec2,
@@ -149,6 +213,7 @@ impl ClientIntent {
     pub fn idle(sequence: InputSequence) -> Self {
         Self {
             sequence,
+            acked_sequence: InputSequence::default(),
             movement: Vec2::ZERO,
             turn: 0.0,
             fire_held: false,
@@ -290,6 +355,51 @@ pub struct PlayerIdAssigned(pub PlayerId);
 #[derive(Debug, Clone, Event, Serialize, Deserialize)]
 pub struct MonitorAck;
 
+// ---- Chat -------------------------------------------------------------------
+
+/// Maximum chat line length in characters. The server truncates anything longer.
+pub const CHAT_MAX_CHARS: usize = 64;
+
+/// Origin of a chat line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChatSender {
+    /// Server or operator broadcast (e.g. `carcinisationctl say`).
+    Server,
+    Player(PlayerId),
+}
+
+/// Chat line — server → client (reliable, ordered).
+/// Broadcast to every client, including monitors.
+#[derive(Debug, Clone, Event, Serialize, Deserialize)]
+pub struct ChatMessage {
+    pub sender: ChatSender,
+    pub text: String,
+}
+
+/// Player chat request — client → server (reliable, ordered).
+/// The server validates and rate-limits it, then rebroadcasts as [`ChatMessage`].
+#[derive(Debug, Clone, Event, Serialize, Deserialize)]
+pub struct ChatSend {
+    pub text: String,
+}
+
+/// Normalise untrusted chat text: keep printable ASCII only (the HUD typeface
+/// has no other glyphs), trim surrounding whitespace and cap at
+/// [`CHAT_MAX_CHARS`].
+///
+/// Returns `None` when nothing printable is left.
+#[must_use]
+pub fn sanitize_chat_text(text: &str) -> Option<String> {
+    let printable: String = text
+        .chars()
+        .map(|c| if c.is_whitespace() { ' ' } else { c })
+        .filter(|c| c.is_ascii_graphic() || *c == ' ')
+        .collect();
+    let capped: String = printable.trim().chars().take(CHAT_MAX_CHARS).collect();
+    let capped = capped.trim_end();
+    (!capped.is_empty()).then(|| capped.to_string())
+}
+
 /// Server acknowledgement of processed input — server → client (reliable, ordered).
 ///
 /// Sent once per server tick for each player that has unacked input.
@@ -370,6 +480,7 @@ mod tests {
     fn client_intent_roundtrip() {
         let intent = ClientIntent {
             sequence: InputSequence(42),
+            acked_sequence: InputSequence(40),
             movement: Vec2::new(0.0, 1.0),
             turn: -1.0,
             fire_held: true,
@@ -378,6 +489,7 @@ mod tests {
         };
         let back = roundtrip(&intent);
         assert_eq!(back.sequence.0, 42);
+        assert_eq!(back.acked_sequence.0, 40);
         assert!((back.movement.y - 1.0).abs() < 1e-5);
         assert!((back.turn - (-1.0)).abs() < 1e-5);
         assert!(back.fire_held);
@@ -395,6 +507,29 @@ mod tests {
         assert_eq!(all_bits.raw() & 0x80, 0);
     }
 
+    #[test]
+    fn chat_message_roundtrip() {
+        let msg = ChatMessage {
+            sender: ChatSender::Player(PlayerId(7)),
+            text: "hello".to_string(),
+        };
+        let back = roundtrip(&msg);
+        assert_eq!(back.sender, ChatSender::Player(PlayerId(7)));
+        assert_eq!(back.text, "hello");
+    }
+
+    #[test]
+    fn sanitize_chat_text_strips_and_truncates() {
+        assert_eq!(
+            sanitize_chat_text("  hi\n\tthere  ").as_deref(),
+            Some("hi  there")
+        );
+        assert_eq!(sanitize_chat_text("caf\u{e9}").as_deref(), Some("caf"));
+        assert_eq!(sanitize_chat_text(" \n "), None);
+        let long = "x".repeat(CHAT_MAX_CHARS * 2);
+        assert_eq!(sanitize_chat_text(&long).unwrap().len(), CHAT_MAX_CHARS);
+    }
+
     #[test]
     fn player_actions_defined_bits_preserved() {
         let actions =
@@ -412,6 +547,7 @@ mod tests {
         // Tiny movement below epsilon is idle.
         let tiny = ClientIntent {
             sequence: InputSequence(0),
+            acked_sequence: InputSequence(0),
             movement: Vec2::new(1e-7, 1e-7),
             turn: 1e-7,
             fire_held: false,
@@ -423,6 +559,7 @@ mod tests {
         // Meaningful movement is not idle.
         let moving = ClientIntent {
             sequence: InputSequence(0),
+            acked_sequence: InputSequence(0),
             movement: Vec2::new(0.0, 0.5),
             turn: 0.0,
             fire_held: false,
@@ -434,6 +571,7 @@ mod tests {
         // Fire held is not idle.
         let firing = ClientIntent {
             sequence: InputSequence(0),
+            acked_sequence: InputSequence(0),
             movement: Vec2::ZERO,
             turn: 0.0,
             fire_held: true,
@@ -446,6 +584,7 @@ mod tests {
         // so the server can suppress translation and gate fire.
         let aiming = ClientIntent {
             sequence: InputSequence(0),
+            acked_sequence: InputSequence(0),
             movement: Vec2::ZERO,
             turn: 0.0,
             fire_held: false,
@@ -620,6 +759,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn connect_user_data_roundtrip_with_name() {
+        let data = ConnectUserData {
+            mode: ConnectMode::Monitor,
+            player_name: Some("crab_01".to_string()),
+        };
+        let encoded = data.to_user_data();
+        assert_eq!(ConnectMode::from_user_data(&encoded), ConnectMode::Monitor);
+        assert_eq!(ConnectUserData::from_user_data(&encoded), data);
+    }
+
+    #[test]
+    fn connect_user_data_sanitizes_name() {
+        let data = ConnectUserData {
+            mode: ConnectMode::Player,
+            player_name: Some(format!("<b>{}</b>", "x".repeat(40))),
+        };
+        let decoded = ConnectUserData::from_user_data(&data.to_user_data());
+        let name = decoded.player_name.expect("name survives sanitizing");
+        assert_eq!(name.len(), PLAYER_NAME_MAX_CHARS);
+        assert!(name.starts_with("bxx"));
+        assert_eq!(sanitize_player_name(" !? "), None);
+        assert_eq!(
+            ConnectUserData::from_user_data(&ConnectMode::Player.to_user_data()),
+            ConnectUserData::default()
+        );
+    }
+
     #[test]
     fn connect_mode_from_zeroed_user_data_defaults_to_player() {
         let zeroed = [0u8; 256];
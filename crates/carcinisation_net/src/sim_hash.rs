@@ -31,7 +31,7 @@ pub fn compute_sim_hash(
         h = fnv_f32(h, p.position.x);
         h = fnv_f32(h, p.position.y);
         h = fnv_f32(h, p.angle);
-        h = fnv_u8(h, p.current_attack as u8);
+        h = fnv_u8(h, p.current_attack.0);
         h = fnv_u8(
             h,
             match p.state {
@@ -154,7 +154,7 @@ mod tests {
                 player_id: PlayerId(1),
                 position: Vec2::new(1.5, 2.5),
                 angle: 0.5,
-                current_attack: NetAttackId::None,
+                current_attack: NetAttackId::default(),
                 state: PlayerNetState::Alive,
                 flame_active: false,
                 avatar_palette_variant: None,
@@ -180,7 +180,7 @@ mod tests {
                     player_id: PlayerId(1),
                     position: Vec2::new(x, 2.5),
                     angle: 0.5,
-                    current_attack: NetAttackId::None,
+                    current_attack: NetAttackId::default(),
                     state: PlayerNetState::Alive,
                     flame_active: false,
                     avatar_palette_variant: None,
@@ -203,7 +203,7 @@ mod tests {
                 player_id: PlayerId(1),
                 position: Vec2::new(1.0, 1.0),
                 angle: 0.0,
-                current_attack: NetAttackId::None,
+                current_attack: NetAttackId::default(),
                 state: PlayerNetState::Alive,
                 flame_active: false,
                 avatar_palette_variant: None,
@@ -218,7 +218,7 @@ mod tests {
                 player_id: PlayerId(2),
                 position: Vec2::new(2.0, 2.0),
                 angle: 1.0,
-                current_attack: NetAttackId::Projectile,
+                current_attack: NetAttackId(2),
                 state: PlayerNetState::Alive,
                 flame_active: true,
                 avatar_palette_variant: None,
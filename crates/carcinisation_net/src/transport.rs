@@ -1,27 +1,68 @@
 //! Multiplayer transport boundary.
 //!
-//! Native multiplayer currently uses the native UDP/netcode transport only. Browser
-//! clients cannot use raw UDP sockets, so browser multiplayer is intentionally not
-//! exposed by the deployment flow yet.
+//! Native clients use the UDP/netcode transport. Browser clients cannot open raw
+//! UDP sockets, so the dedicated server can additionally listen for WebSocket
+//! connections. Both transports feed the same netcode server: the `ConnectMode`
+//! handshake and replicon channels are identical regardless of how a client
+//! reached the server.
 //!
-//! Future browser multiplayer should add a transport behind this boundary instead
-//! of coupling gameplay replication directly to platform socket APIs. Prefer
-//! WebTransport when hosting/support is ready; WebSocket is acceptable as an
-//! earlier bridge if it keeps the gameplay protocol isolated from the browser
-//! transport details.
+//! WebSocket is a reliable stream, so renet downgrades every channel to
+//! unreliable for clients on that transport instead of layering a second
+//! reliability protocol on top. Prefer WebTransport once hosting/support is
+//! ready; it can be added here as another variant.
+//!
+//! Only the server side of WebSocket exists so far: the game's client and
+//! monitor setup is native-only and always dials UDP. A browser client still
+//! needs a wasm connect path that opens a WebSocket socket and authenticates
+//! with [`MultiplayerTransport::WebSocket`]'s socket id.
 
 /// Implemented multiplayer transports.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum MultiplayerTransport {
     /// Native UDP transport used by the dedicated server and native clients.
     NativeUdp,
+    /// WebSocket transport for browser (wasm) clients.
+    WebSocket,
 }
 
 impl MultiplayerTransport {
+    /// Netcode socket id for this transport on the dedicated server.
+    ///
+    /// The server registers its UDP socket first, so UDP is always socket 0.
+    /// Clients must pass this id in `ClientAuthentication` so netcode routes
+    /// the handshake to the matching server socket.
+    #[must_use]
+    pub const fn socket_id(self) -> u8 {
+        match self {
+            Self::NativeUdp => 0,
+            Self::WebSocket => 1,
+        }
+    }
+
     #[must_use]
     pub const fn supports_browser(self) -> bool {
         match self {
             Self::NativeUdp => false,
+            Self::WebSocket => true,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_websocket_supports_browser() {
+        assert!(!MultiplayerTransport::NativeUdp.supports_browser());
+        assert!(MultiplayerTransport::WebSocket.supports_browser());
+    }
+
+    #[test]
+    fn socket_ids_are_distinct() {
+        assert_ne!(
+            MultiplayerTransport::NativeUdp.socket_id(),
+            MultiplayerTransport::WebSocket.socket_id()
+        );
+    }
+}
@@ -14,13 +14,13 @@ use self::{
     },
     spawns::blood_shot::arm_pending_blood_shot_motion,
     spawns::spider_shot::arm_pending_spider_shot_motion,
-    systems::player::check_got_hit,
+    systems::player::{check_got_hit, refresh_collision_broad_phase},
     systems::{
         check_health_at_0, despawn_dead_attacks, hovering::hovering_damage_on_reached,
         miss_on_reached, on_enemy_attack_depth_changed,
     },
 };
-use super::CollisionStateSystems;
+use super::{CollisionStateSystems, collision::CollisionBroadPhase};
 use activable::{Activable, ActivableAppExt};
 use bevy::prelude::*;
 
@@ -33,13 +33,18 @@ impl Plugin for AttackPlugin {
         app.insert_resource(BloodShotConfig::load());
         app.insert_resource(BoulderThrowConfig::load());
         app.insert_resource(SpiderShotConfig::load());
+        app.init_resource::<CollisionBroadPhase>();
         app.add_active_systems::<Self, _>(
             // Only advance attack behaviour when the plugin is explicitly active.
             (
                 // check_got_hit reads CxPresentationTransform.collision_offset and
                 // ComposedCollisionState for hit detection — both are produced by
                 // systems in CollisionStateSystems.
-                (check_got_hit, check_health_at_0)
+                (
+                    refresh_collision_broad_phase,
+                    check_got_hit,
+                    check_health_at_0,
+                )
                     .chain()
                     .after(CollisionStateSystems),
                 #[cfg(debug_assertions)]
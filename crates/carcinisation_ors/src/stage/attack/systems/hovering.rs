@@ -16,14 +16,11 @@ use crate::stage::{
     resources::StageTimeDomain,
 };
 use assert_assets_path::assert_assets_path;
-use bevy::{
-    audio::{AudioPlayer, PlaybackMode, PlaybackSettings},
-    prelude::*,
-};
+use bevy::prelude::*;
 use carapace::prelude::{CxAnchor, CxAtlasSprite, CxSpriteAtlasAsset, WorldPos};
+use carcinisation_core::audio::{AudioBus, make_sound_bundle};
 use carcinisation_core::components::DelayedDespawnOnCxAnimationFinished;
 use carcinisation_core::components::DespawnMark;
-use carcinisation_core::components::{AudioSystemBundle, AudioSystemType, VolumeSettings};
 use cween::linear::components::{LinearValueReached, TargetingValueZ};
 
 use crate::stage::attack::components::bundles::REGION_HIT;
@@ -51,15 +48,11 @@ pub fn hovering_damage_on_reached(
         ),
     >,
     asset_server: Res<AssetServer>,
-    volume_settings: Res<VolumeSettings>,
 ) {
     let is_spider_shot =
         |attack: &EnemyHoveringAttackType| matches!(attack, EnemyHoveringAttackType::SpiderShot);
 
     for (entity, attack, damage, position, depth, existing_sprite) in &mut depth_query.iter() {
-        let sound_effect: Handle<AudioSource> =
-            asset_server.load(assert_assets_path!("audio/sfx/enemy_melee.ogg"));
-
         for (player_entity, ref mut webbed) in &mut player_query.iter_mut() {
             damage_event_writer.write(DamageMessage::new(player_entity, damage.0));
 
@@ -75,16 +68,10 @@ pub fn hovering_damage_on_reached(
             }
         }
 
-        commands.spawn((
-            AudioPlayer(sound_effect),
-            PlaybackSettings {
-                mode: PlaybackMode::Despawn,
-                volume: volume_settings.sfx,
-                ..Default::default()
-            },
-            AudioSystemBundle {
-                system_type: AudioSystemType::SFX,
-            },
+        commands.spawn(make_sound_bundle(
+            &asset_server,
+            assert_assets_path!("audio/sfx/enemy_melee.ogg"),
+            AudioBus::Sfx,
         ));
 
         // Reuse the atlas handle from the attack's own sprite when available.
@@ -12,8 +12,9 @@ use crate::{
             SCORE_RANGED_REGULAR_HIT,
         },
         collision::{
-            CollisionTarget, MaskCollisionAssets, TargetCollisionHit, TargetCollisionResult,
-            build_attack_mask, resolve_target_mask_hit, resolve_target_point_hit,
+            CollisionBroadPhase, CollisionTarget, MaskCollisionAssets, NO_DEPTH_LANE,
+            TargetCollisionHit, TargetCollisionResult, build_attack_mask, resolve_target_mask_hit,
+            resolve_target_point_hit, target_broad_phase_bounds, target_broad_phase_lane,
         },
         components::{
             interactive::{ColliderData, Hittable},
@@ -36,10 +37,10 @@ use crate::{
     },
 };
 use carcinisation_collision::{
-    AtlasMaskFrames, PixelMaskSource, WorldMaskInstance, world_mask_rect_from_spatial,
+    AtlasMaskFrames, PixelMaskSource, WorldMaskInstance, bounds_from_center,
+    world_mask_rect_from_spatial,
 };
 use carcinisation_core::components::DespawnMark;
-use carcinisation_core::components::VolumeSettings;
 
 const CRITICAL_THRESHOLD: f32 = 0.5;
 const MELEE_DEPTH_MIN: crate::stage::components::placement::Depth =
@@ -47,11 +48,135 @@ const MELEE_DEPTH_MIN: crate::stage::components::placement::Depth =
 const MELEE_DEPTH_MAX: crate::stage::components::placement::Depth =
     crate::stage::components::placement::Depth::Three;
 
+/// Hittables whose broad-phase bounds may have moved since the last refresh.
+type BroadPhaseDirty = Or<(
+    Added<Hittable>,
+    Changed<CxPosition>,
+    Changed<WorldPos>,
+    Changed<CxAnchor>,
+    Changed<CxFrameView>,
+    Changed<CxSprite>,
+    Changed<CxAtlasSprite>,
+    Changed<CxPresentationTransform>,
+    Changed<ColliderData>,
+    Changed<ComposedCollisionState>,
+    Changed<ComposedResolvedParts>,
+    Changed<ComposedAtlasBindings>,
+    Changed<Depth>,
+)>;
+
+/// @system Keeps [`CollisionBroadPhase`] in sync with hittable bounds while
+/// player attacks exist.
+///
+/// Frames without player attacks only drop removed hittables. The first frame
+/// with attacks, or one after sprite assets changed, rebuilds the grid; other
+/// frames re-resolve only hittables whose position, sprite, frame or depth
+/// changed, plus camera-space ones when the camera moved.
+#[allow(clippy::type_complexity)]
+pub fn refresh_collision_broad_phase(
+    camera: Res<CxCamera>,
+    mut collision_assets: MaskCollisionAssets<'_, '_>,
+    attack_query: Query<(), With<PlayerAttack>>,
+    hittable_query: Query<
+        (
+            Entity,
+            &CxPosition,
+            &WorldPos,
+            (&CxAnchor, &CxRenderSpace),
+            (
+                Option<&CxFrameView>,
+                Option<&CxSprite>,
+                Option<&CxAtlasSprite>,
+                Option<&CxPresentationTransform>,
+            ),
+            Option<&ColliderData>,
+            Option<&ComposedCollisionState>,
+            Option<&ComposedResolvedParts>,
+            Option<&ComposedAtlasBindings>,
+            Option<&Depth>,
+            Option<&Enemy>,
+            Option<&crate::stage::destructible::components::Destructible>,
+        ),
+        With<Hittable>,
+    >,
+    dirty_query: Query<(), (With<Hittable>, BroadPhaseDirty)>,
+    mut removed: RemovedComponents<Hittable>,
+    mut broad_phase: ResMut<CollisionBroadPhase>,
+    mut synced: Local<bool>,
+) {
+    for entity in removed.read() {
+        broad_phase.remove(&entity);
+    }
+    if attack_query.is_empty() {
+        // Changes made meanwhile are not tracked; rebuild on the next attack.
+        *synced = false;
+        return;
+    }
+    // Loaded or reloaded sprites can change mask bounds without touching
+    // any component.
+    let assets_changed = collision_assets.refresh();
+    let camera_world = camera.0;
+    let rebuild = !*synced || assets_changed;
+    let camera_moved = camera.is_changed();
+
+    if rebuild {
+        broad_phase.begin_frame();
+    }
+    for (
+        entity,
+        entity_position,
+        entity_world_pos,
+        (entity_anchor, entity_canvas),
+        (entity_frame, entity_sprite, entity_atlas_sprite, entity_presentation),
+        collider_data,
+        composed_collision_state,
+        composed_resolved_parts,
+        composed_atlas_bindings,
+        entity_depth,
+        enemy,
+        destructible,
+    ) in &hittable_query
+    {
+        let dirty = rebuild
+            || dirty_query.contains(entity)
+            || (camera_moved && *entity_canvas == CxRenderSpace::Camera);
+        if !dirty {
+            continue;
+        }
+        let target = CollisionTarget {
+            position: entity_position,
+            world_pos: entity_world_pos,
+            anchor: entity_anchor,
+            canvas: entity_canvas,
+            frame: entity_frame,
+            sprite: entity_sprite,
+            atlas_sprite: entity_atlas_sprite,
+            presentation: entity_presentation,
+            collider_data,
+            composed_collision_state,
+            composed_resolved_parts,
+            composed_atlas_bindings,
+            enemy,
+            destructible,
+        };
+        let bounds = target_broad_phase_bounds(target, camera_world, &mut collision_assets);
+        broad_phase.update(entity, bounds, target_broad_phase_lane(entity_depth));
+    }
+    if rebuild {
+        broad_phase.end_frame();
+        *synced = true;
+    }
+}
+
 /// @system Checks player attacks against hittable entities.
 ///
 /// Collision resolution is delegated to the shared stage collision pipeline:
 /// player attacks provide world-space probe shapes and targets resolve
 /// sprite/atlas/composed/fallback collision through a single API.
+///
+/// Candidates come from [`CollisionBroadPhase`] (see
+/// [`refresh_collision_broad_phase`]), so each attack only runs the narrow
+/// phase against targets sharing its grid cells and depth lanes.
 #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
 pub fn check_got_hit(
     mut commands: Commands,
@@ -63,7 +188,6 @@ pub fn check_got_hit(
     mut part_event_writer: MessageWriter<PartDamageMessage>,
     time: Res<Time<StageTimeDomain>>,
     attack_definitions: Res<AttackDefinitions>,
-    volume_settings: Res<VolumeSettings>,
     mut attack_query: Query<(
         Entity,
         &PlayerAttack,
@@ -100,7 +224,12 @@ pub fn check_got_hit(
         With<Hittable>,
     >,
     mut score: ResMut<Score>,
+    broad_phase: Res<CollisionBroadPhase>,
+    mut candidates: Local<Vec<Entity>>,
 ) {
+    if attack_query.is_empty() {
+        return;
+    }
     collision_assets.refresh();
     let delta_secs = time.delta().as_secs_f32();
     let camera_world = camera.0;
@@ -216,21 +345,50 @@ pub fn check_got_hit(
             None
         };
 
-        for (
-            entity,
-            entity_position,
-            entity_world_pos,
-            (entity_anchor, entity_canvas),
-            (entity_frame, entity_sprite, entity_atlas_sprite, entity_presentation),
-            collider_data,
-            composed_collision_state,
-            composed_resolved_parts,
-            composed_atlas_bindings,
-            entity_depth,
-            enemy,
-            destructible,
-        ) in hittable_query
-        {
+        let probe_bounds = match attack_definition.collision {
+            AttackCollisionMode::Point => attack_points
+                .iter()
+                .flatten()
+                .map(|point| bounds_from_center(*point, Vec2::splat(0.5)))
+                .reduce(IRect::union),
+            AttackCollisionMode::SpriteMask => attack_mask.map(|mask| mask.mask.world.rect),
+            AttackCollisionMode::Radial { radius } => {
+                Some(bounds_from_center(attack_world, Vec2::splat(radius)))
+            }
+            AttackCollisionMode::None => None,
+        };
+        let Some(probe_bounds) = probe_bounds else {
+            continue;
+        };
+        // Lanes only pre-filter; the depth rules below stay authoritative.
+        let lanes = match (attack.attack_id, bomb_depth) {
+            (AttackId::Bomb, Some(depth)) if *depth != Depth::Six => depth.to_i8()..=depth.to_i8(),
+            _ if attack_definition.category == AttackCategory::Melee => {
+                NO_DEPTH_LANE..=MELEE_DEPTH_MAX.to_i8()
+            }
+            _ => i8::MIN..=i8::MAX,
+        };
+        broad_phase.query_into(probe_bounds, lanes, &mut candidates);
+
+        for &candidate in &*candidates {
+            let Ok((
+                entity,
+                entity_position,
+                entity_world_pos,
+                (entity_anchor, entity_canvas),
+                (entity_frame, entity_sprite, entity_atlas_sprite, entity_presentation),
+                collider_data,
+                composed_collision_state,
+                composed_resolved_parts,
+                composed_atlas_bindings,
+                entity_depth,
+                enemy,
+                destructible,
+            )) = hittable_query.get(candidate)
+            else {
+                continue;
+            };
+
             if attack.attack_id == AttackId::Bomb {
                 let depth_match = match (bomb_depth, entity_depth) {
                     (Some(attack_depth), Some(entity_depth)) => attack_depth == entity_depth,
@@ -331,7 +489,6 @@ pub fn check_got_hit(
                             &mut assets_sprite,
                             asset_server.as_ref(),
                             collision_assets.atlas_asset_store(),
-                            volume_settings.as_ref(),
                         );
                     }
                     effect_state.follow_up_spawned = true;
@@ -406,3 +563,81 @@ pub fn check_got_hit(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use carcinisation_collision::Collider;
+
+    fn broad_phase_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<CxSpriteAsset>()
+            .init_asset::<CxSpriteAtlasAsset>()
+            .init_resource::<CxCamera>()
+            .init_resource::<CollisionBroadPhase>()
+            .add_systems(Update, refresh_collision_broad_phase);
+        app
+    }
+
+    fn spawn_target(app: &mut App, position: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                Hittable,
+                WorldPos(position),
+                CxPosition(position.as_ivec2()),
+                CxAnchor::Center,
+                CxRenderSpace::World,
+                ColliderData::from_one(Collider::new_circle(4.0)),
+            ))
+            .id()
+    }
+
+    fn spawn_attack(app: &mut App) -> Entity {
+        app.world_mut()
+            .spawn(PlayerAttack {
+                attack_id: AttackId::Pistol,
+                position: Vec2::ZERO,
+            })
+            .id()
+    }
+
+    fn grid_bounds(app: &App, entity: Entity) -> Option<IRect> {
+        app.world()
+            .resource::<CollisionBroadPhase>()
+            .get(&entity)
+            .map(|entry| entry.bounds)
+    }
+
+    #[test]
+    fn broad_phase_idles_without_attacks() {
+        let mut app = broad_phase_app();
+        let target = spawn_target(&mut app, Vec2::new(40.0, 40.0));
+        app.update();
+        assert!(app.world().resource::<CollisionBroadPhase>().is_empty());
+
+        spawn_attack(&mut app);
+        app.update();
+        assert!(grid_bounds(&app, target).is_some());
+    }
+
+    #[test]
+    fn broad_phase_tracks_moves_and_removals() {
+        let mut app = broad_phase_app();
+        spawn_attack(&mut app);
+        let target = spawn_target(&mut app, Vec2::new(40.0, 40.0));
+        app.update();
+        let before = grid_bounds(&app, target).unwrap();
+
+        app.world_mut()
+            .entity_mut(target)
+            .insert(WorldPos(Vec2::new(100.0, 40.0)));
+        app.update();
+        let after = grid_bounds(&app, target).unwrap();
+        assert_eq!(after.min.x - before.min.x, 60);
+
+        app.world_mut().despawn(target);
+        app.update();
+        assert!(grid_bounds(&app, target).is_none());
+    }
+}
@@ -3,15 +3,15 @@ use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use carapace::prelude::*;
 use carcinisation_collision::{
-    AtlasMaskFrames, AtlasPixelCollisionCache, AtlasPixelData, Collider, ColliderShape,
-    PixelCollisionCache, PixelMaskSource, SpritePixelData, WorldMaskInstance, atlas_data,
-    sprite_data, world_mask_contains_point, world_mask_overlap, world_mask_rect_from_spatial,
-    world_mask_rect_from_top_left,
+    AtlasMaskFrames, AtlasPixelCollisionCache, AtlasPixelData, BroadPhaseGrid, Collider,
+    ColliderShape, PixelCollisionCache, PixelMaskSource, SpritePixelData, WorldMaskInstance,
+    atlas_data, bounds_from_center, sprite_data, world_mask_contains_point, world_mask_overlap,
+    world_mask_rect_from_spatial, world_mask_rect_from_top_left,
 };
 use std::sync::Arc;
 
 use crate::stage::{
-    components::interactive::ColliderData,
+    components::{interactive::ColliderData, placement::Depth},
     destructible::components::Destructible,
     enemy::{
         components::Enemy,
@@ -33,13 +33,18 @@ pub struct MaskCollisionAssets<'w, 's> {
 }
 
 impl MaskCollisionAssets<'_, '_> {
-    pub fn refresh(&mut self) {
-        if self.sprite_asset_events.read().next().is_some() {
+    /// Drops cached pixel data when sprite or atlas assets changed. Returns
+    /// whether anything was dropped.
+    pub fn refresh(&mut self) -> bool {
+        let sprites_changed = self.sprite_asset_events.read().next().is_some();
+        if sprites_changed {
             self.sprite_cache.clear();
         }
-        if self.atlas_asset_events.read().next().is_some() {
+        let atlases_changed = self.atlas_asset_events.read().next().is_some();
+        if atlases_changed {
             self.atlas_cache.clear();
         }
+        sprites_changed || atlases_changed
     }
 
     pub fn sprite_pixels(
@@ -116,6 +121,17 @@ pub struct AttackMask<'a> {
     pub mask: WorldMaskInstance<'a>,
 }
 
+/// Broad-phase lane for hittable targets without a [`Depth`].
+pub const NO_DEPTH_LANE: i8 = -1;
+
+/// Grid broad phase over hittable targets, keyed by entity.
+///
+/// Kept current from [`target_broad_phase_bounds`]; candidates it
+/// returns still go through [`resolve_target_point_hit`] /
+/// [`resolve_target_mask_hit`].
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct CollisionBroadPhase(pub BroadPhaseGrid<Entity>);
+
 #[derive(Clone, Debug, PartialEq)]
 struct ComposedHitSelection {
     part_id: String,
@@ -173,6 +189,55 @@ pub fn resolve_target_mask_hit(
     )
 }
 
+#[must_use]
+pub fn target_broad_phase_lane(depth: Option<&Depth>) -> i8 {
+    depth.map_or(NO_DEPTH_LANE, Depth::to_i8)
+}
+
+/// Conservative world bounds of everything the narrow phase may test for
+/// `target`: its masks, primitive colliders and collision origin.
+pub fn target_broad_phase_bounds(
+    target: CollisionTarget<'_>,
+    camera_world: IVec2,
+    assets: &mut MaskCollisionAssets<'_, '_>,
+) -> IRect {
+    let origin = simple_collision_origin(target);
+    let mut bounds = bounds_from_center(origin, Vec2::ZERO);
+
+    visit_simple_target_masks(target, camera_world, assets, |mask| {
+        bounds = bounds.union(mask.world.rect);
+    });
+    visit_composed_target_masks(target, assets, |mask, _, _| {
+        bounds = bounds.union(mask.world.rect);
+    });
+
+    if let Some(collision_state) = target.composed_collision_state {
+        for collision in collision_state.collisions() {
+            if let Some(collider_bounds) =
+                collider_bounds(collision.pivot_position, &collision.collider)
+            {
+                bounds = bounds.union(collider_bounds);
+            }
+        }
+    }
+    if let Some(collider_data) = target.collider_data {
+        for collider in &collider_data.0 {
+            if let Some(collider_bounds) = collider_bounds(origin, collider) {
+                bounds = bounds.union(collider_bounds);
+            }
+        }
+    }
+
+    bounds
+}
+
+fn collider_bounds(position: Vec2, collider: &Collider) -> Option<IRect> {
+    collider
+        .shape
+        .half_extents()
+        .map(|half| bounds_from_center(position + collider.offset, half))
+}
+
 pub fn visit_target_debug_collider<FMask, FPrimitive>(
     target: CollisionTarget<'_>,
     camera_world: IVec2,
@@ -738,6 +803,19 @@ mod tests {
         assert_eq!(point, Vec2::new(4.0, 6.0));
     }
 
+    #[test]
+    fn collider_bounds_include_offset_and_skip_masks() {
+        let collider = Collider::new_box(Vec2::new(2.0, 1.0)).with_offset(Vec2::new(4.0, 0.0));
+        assert_eq!(
+            collider_bounds(Vec2::new(10.0, 10.0), &collider),
+            Some(IRect::new(12, 9, 17, 12))
+        );
+        assert_eq!(
+            collider_bounds(Vec2::ZERO, &Collider::new(ColliderShape::SpriteMask)),
+            None
+        );
+    }
+
     #[test]
     fn simple_collision_origin_includes_collision_offset() {
         let position = CxPosition::default();
@@ -10,7 +10,7 @@ use super::{
     projection::ProjectionProfile,
 };
 use bevy::prelude::*;
-use carcinisation_cutscene::data::CutsceneAnimationsSpawn;
+use carcinisation_cutscene::data::{CutsceneAct, CutsceneAnimationsSpawn, CutsceneData};
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 use serde_with::{DurationSecondsWithFrac, serde_as};
@@ -75,10 +75,46 @@ pub struct StageElapsedStarted(pub Duration);
 
 #[derive(Component, Clone, Debug, Deserialize, Reflect, Serialize)]
 /// Scripted cinematic step triggered during stage progression.
+///
+/// Plays a cutscene while the stage clock and the player are paused; the stage
+/// advances once the cutscene finishes or is skipped.
 pub enum CinematicStageStep {
+    /// Animations played as a single cutscene act lasting as long as they do.
     CutsceneAnimationSpawn(CutsceneAnimationsSpawn),
+    /// Cutscene authored inline in the stage file.
+    Cutscene(CutsceneData),
+    /// Path of a `.cs.ron` cutscene asset.
+    CutsceneAsset(String),
 }
 
+impl CinematicStageStep {
+    /// The cutscene to play, or `None` when it must be loaded from an asset.
+    #[must_use]
+    pub fn inline_cutscene(&self) -> Option<CutsceneData> {
+        match self {
+            Self::CutsceneAnimationSpawn(spawns) => {
+                let elapse = spawns
+                    .spawns
+                    .iter()
+                    .fold(Duration::ZERO, |total, spawn| total + spawn.duration);
+                Some(
+                    CutsceneData::new("Stage cinematic".to_string()).set_steps(vec![CutsceneAct {
+                        elapse,
+                        ..CutsceneAct::new().spawn_animations(spawns.clone())
+                    }]),
+                )
+            }
+            Self::Cutscene(data) => Some(data.clone()),
+            Self::CutsceneAsset(_) => None,
+        }
+    }
+}
+
+#[derive(Component, Debug)]
+/// Cutscene asset of the current [`CinematicStageStep::CutsceneAsset`] step,
+/// played once loaded.
+pub struct StageCinematicAsset(pub Handle<CutsceneData>);
+
 const fn default_base_speed() -> f32 {
     1.0
 }
@@ -183,9 +219,13 @@ pub struct StopStageStep {
     #[serde_as(as = "Option<DurationSecondsWithFrac>")]
     #[serde(default)]
     pub max_duration: Option<Duration>,
-    #[new(value = "true")]
+    /// Ends the stop once every enemy alive during it is dead and no enemy
+    /// spawns are pending. With `kill_boss`, both must hold.
+    #[new(default)]
     #[serde(default)]
     pub kill_all: bool,
+    /// Ends the stop once every boss alive during it is dead and no boss
+    /// spawns are pending.
     #[new(default)]
     #[serde(default)]
     pub kill_boss: bool,
@@ -260,3 +300,11 @@ impl StopStageStep {
         self
     }
 }
+
+#[derive(Component, Debug, Default)]
+/// Enemies a [`StopStageStep`] waits on for its `kill_all` / `kill_boss`
+/// conditions: those alive when the stop began plus those spawned or dropped
+/// during it.
+pub struct StopStepTargets {
+    pub enemies: Vec<Entity>,
+}
@@ -6,7 +6,7 @@ use super::{
     components::{CinematicStageStep, StopStageStep, TweenStageStep, placement::Depth},
     destructible::data::DestructibleSpawn,
     enemy::{data::steps::EnemyStep, entity::EnemyType},
-    projection::ProjectionProfile,
+    projection::{ProjectionProfile, ProjectionSource},
 };
 use bevy::{asset::Asset, prelude::*, reflect::Reflect};
 use carcinisation_core::globals::SCREEN_RESOLUTION;
@@ -674,6 +674,10 @@ pub struct StageData {
     /// [`ProjectionProfile::default()`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub projection: Option<ProjectionProfile>,
+    /// Time-driven projection for the whole stage.  When set, it replaces
+    /// `projection` and the step-level overrides.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub projection_source: Option<ProjectionSource>,
     /// Optional mid-stage checkpoint for continue-after-death.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub checkpoint: Option<StageCheckpoint>,
@@ -1,5 +1,6 @@
 //! Enemy entity definitions, behaviours, and species-specific logic.
 
+pub mod boss;
 pub mod bundles;
 pub mod components;
 pub mod composed;
@@ -8,10 +9,12 @@ pub mod entity;
 pub mod mosquito;
 pub mod mosquiton;
 pub mod spidey;
+pub mod spidomonsta;
 mod systems;
 pub mod tardigrade;
 
 use self::{
+    boss::{advance_boss_phases, init_boss_phases},
     mosquito::systems::{
         assign_mosquito_animation, check_idle_mosquito, clear_finished_mosquito_attacks,
         despawn_dead_mosquitoes,
@@ -25,6 +28,7 @@ use self::{
         assign_spidey_animation, check_idle_spidey, clear_finished_spidey_attacks,
         despawn_dead_spideys, trigger_spidey_authored_attack_cues, update_spidey_death_effect,
     },
+    spidomonsta::systems::{award_spidomonsta_kill, on_spidomonsta_phase_changed},
     systems::{
         animation::on_composed_enemy_depth_changed,
         animation::on_enemy_depth_changed,
@@ -33,6 +37,7 @@ use self::{
             ensure_enemy_continuous_depth, tick_enemy_behavior_timer,
             tied_components_enemy_current_behavior_circle_around,
         },
+        cues::{play_composed_sound_cues, spawn_composed_effect_cues},
         damage::{start_burning_corpses, tick_burning_corpses},
     },
     tardigrade::systems::{
@@ -46,7 +51,8 @@ use bevy::prelude::*;
 use carapace::set::CxSet;
 use composed::{
     CompositionAtlasAsset, CompositionAtlasLoader, apply_composed_enemy_visuals,
-    ensure_composed_enemy_parts, prepare_composed_atlas_assets, update_composed_enemy_visuals,
+    apply_composed_part_damage, emit_composed_sound_and_effect_cues, ensure_composed_enemy_parts,
+    prepare_composed_atlas_assets, update_composed_enemy_visuals,
 };
 
 /// Registers shared enemy behaviour systems and species handlers.
@@ -56,7 +62,8 @@ pub struct EnemyPlugin;
 impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
         app.init_asset::<CompositionAtlasAsset>()
-            .register_asset_loader(CompositionAtlasLoader);
+            .register_asset_loader(CompositionAtlasLoader)
+            .add_observer(on_spidomonsta_phase_changed);
         app.add_active_systems_in::<Self, _>(
             PostUpdate,
             apply_composed_enemy_visuals.in_set(CxSet::CompositePresentationWrites),
@@ -110,6 +117,7 @@ impl Plugin for EnemyPlugin {
                     update_composed_enemy_visuals.in_set(CollisionStateSystems),
                     detect_part_breakage,
                     trigger_mosquiton_authored_attack_cues,
+                    emit_composed_sound_and_effect_cues,
                     ApplyDeferred,
                     (
                         assign_mosquiton_animation.after(check_idle_mosquito),
@@ -137,6 +145,20 @@ impl Plugin for EnemyPlugin {
                     despawn_dead_spideys,
                     update_spidey_death_effect,
                 ),
+                (
+                    // Bosses
+                    // Phases are seeded once the composed pipeline binds the
+                    // atlas health pools, and advance after part damage lands.
+                    init_boss_phases.after(ensure_composed_enemy_parts),
+                    advance_boss_phases
+                        .after(init_boss_phases)
+                        .after(apply_composed_part_damage),
+                    // Spidomonsta shares the Spidey systems above.
+                    award_spidomonsta_kill,
+                ),
+                // Authored sound/effect cues from any composed enemy.
+                (play_composed_sound_cues, spawn_composed_effect_cues)
+                    .after(emit_composed_sound_and_effect_cues),
                 (
                     // Tardigrade
                     assign_tardigrade_animation,
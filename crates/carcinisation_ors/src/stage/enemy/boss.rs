@@ -0,0 +1,214 @@
+//! Boss framework shared by Marauder, Spidomonsta and Kyle.
+//!
+//! A boss's phases come from its composed atlas: every `HealthPool` in the
+//! atlas gameplay metadata is one phase, in authored order, with the entity
+//! health pool last. Parts drawing from a later phase's pool shrug off damage
+//! until that phase starts. A phase ends when its pool is depleted or when
+//! every breakable part drawing from it is broken; clearing the last phase
+//! kills the boss.
+
+use crate::stage::{
+    components::interactive::{BurningCorpse, Dead},
+    enemy::{
+        components::Boss,
+        composed::{ComposedHealthPools, ComposedPartStates},
+    },
+    messages::BossPhaseChangedEvent,
+};
+use bevy::prelude::*;
+
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+/// Phase progression of a composed boss, seeded from its atlas health pools.
+pub struct BossPhases {
+    pools: Vec<String>,
+    current: usize,
+}
+
+impl BossPhases {
+    #[must_use]
+    pub const fn new(pools: Vec<String>) -> Self {
+        Self { pools, current: 0 }
+    }
+
+    /// Index of the phase being fought.
+    #[must_use]
+    pub const fn current(&self) -> usize {
+        self.current
+    }
+
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.pools.len()
+    }
+
+    /// Health pool of the phase being fought, or `None` once all are cleared.
+    #[must_use]
+    pub fn current_pool(&self) -> Option<&str> {
+        self.pools.get(self.current).map(String::as_str)
+    }
+
+    /// Whether `pool_id` belongs to a phase that has not started yet.
+    #[must_use]
+    pub fn is_locked(&self, pool_id: &str) -> bool {
+        self.pools
+            .iter()
+            .position(|id| id == pool_id)
+            .is_some_and(|phase| phase > self.current)
+    }
+
+    /// Remaining share of the boss's health across every phase pool.
+    #[must_use]
+    pub fn health_fraction(&self, pools: &ComposedHealthPools) -> f32 {
+        let (health, max_health) = self.pools.iter().fold((0, 0), |(health, max), id| {
+            (
+                health + pools.health(id).unwrap_or(0),
+                max + pools.max_health(id).unwrap_or(0),
+            )
+        });
+        if max_health == 0 {
+            0.0
+        } else {
+            health as f32 / max_health as f32
+        }
+    }
+}
+
+/// A phase is cleared once its pool is depleted or every breakable part
+/// drawing from it is broken.
+fn phase_cleared(pool_id: &str, pools: &ComposedHealthPools, parts: &ComposedPartStates) -> bool {
+    if pools.health(pool_id).is_none_or(|health| health == 0) {
+        return true;
+    }
+    let mut breakable = parts
+        .iter()
+        .map(|(_, part)| part)
+        .filter(|part| part.breakable && part.health_pool.as_deref() == Some(pool_id))
+        .peekable();
+    breakable.peek().is_some() && breakable.all(|part| part.broken)
+}
+
+/// @system Seeds boss phases once the composed visual has bound its health
+/// pools.
+pub fn init_boss_phases(
+    mut commands: Commands,
+    query: Query<(Entity, &ComposedHealthPools), (With<Boss>, Without<BossPhases>)>,
+) {
+    for (entity, pools) in &query {
+        commands
+            .entity(entity)
+            .insert(BossPhases::new(pools.ids().to_vec()));
+    }
+}
+
+/// @system Moves bosses past every cleared phase, triggering
+/// [`BossPhaseChangedEvent`] for each phase started, and kills bosses that
+/// clear their last phase.
+pub fn advance_boss_phases(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &mut BossPhases,
+            &ComposedHealthPools,
+            &ComposedPartStates,
+        ),
+        (Without<Dead>, Without<BurningCorpse>),
+    >,
+) {
+    for (entity, mut phases, pools, parts) in &mut query {
+        while phases
+            .current_pool()
+            .is_some_and(|pool_id| phase_cleared(pool_id, pools, parts))
+        {
+            phases.current += 1;
+            if phases.current < phases.count() {
+                info!("Boss {:?} entered phase {}", entity, phases.current);
+                commands.trigger(BossPhaseChangedEvent {
+                    entity,
+                    phase: phases.current,
+                });
+            } else {
+                info!("Boss {:?} cleared its last phase", entity);
+                commands.entity(entity).insert(Dead);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Resource, Default)]
+    struct PhaseChanges(Vec<usize>);
+
+    fn boss_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<PhaseChanges>()
+            .add_systems(Update, (init_boss_phases, advance_boss_phases).chain())
+            .add_observer(
+                |trigger: On<BossPhaseChangedEvent>, mut changes: ResMut<PhaseChanges>| {
+                    changes.0.push(trigger.event().phase);
+                },
+            );
+        app
+    }
+
+    fn parts(broken_legs: bool) -> ComposedPartStates {
+        let mut parts = ComposedPartStates::test_with_parts(vec![
+            ("leg_l", broken_legs, vec![]),
+            ("leg_r", broken_legs, vec![]),
+            ("body", false, vec![]),
+        ]);
+        for (id, part) in parts.iter_mut() {
+            part.health_pool = Some(if id == "body" { "core" } else { "legs" }.to_string());
+            part.breakable = id != "body";
+        }
+        parts
+    }
+
+    #[test]
+    fn later_phase_pools_are_locked() {
+        let mut phases = BossPhases::new(vec!["legs".into(), "core".into()]);
+        assert!(!phases.is_locked("legs"));
+        assert!(phases.is_locked("core"));
+        assert!(!phases.is_locked("unknown"));
+        phases.current = 1;
+        assert!(!phases.is_locked("core"));
+    }
+
+    #[test]
+    fn health_fraction_spans_all_phases() {
+        let phases = BossPhases::new(vec!["legs".into(), "core".into()]);
+        let pools = ComposedHealthPools::test_with_pools(vec![("legs", 0, 20), ("core", 30, 60)]);
+        assert!((phases.health_fraction(&pools) - 0.375).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn breaking_every_part_of_a_phase_advances() {
+        let mut app = boss_app();
+        let pools = ComposedHealthPools::test_with_pools(vec![("legs", 20, 20), ("core", 30, 30)]);
+        let boss = app.world_mut().spawn((Boss, pools, parts(false))).id();
+        app.update();
+        assert_eq!(app.world().get::<BossPhases>(boss).unwrap().current(), 0);
+
+        app.world_mut().entity_mut(boss).insert(parts(true));
+        app.update();
+        assert_eq!(app.world().get::<BossPhases>(boss).unwrap().current(), 1);
+        assert_eq!(app.world().resource::<PhaseChanges>().0, vec![1]);
+        assert!(app.world().get::<Dead>(boss).is_none());
+    }
+
+    #[test]
+    fn clearing_the_last_phase_kills_the_boss() {
+        let mut app = boss_app();
+        let pools = ComposedHealthPools::test_with_pools(vec![("legs", 0, 20), ("core", 0, 30)]);
+        let boss = app.world_mut().spawn((Boss, pools, parts(false))).id();
+        app.update();
+        app.update();
+
+        assert_eq!(app.world().resource::<PhaseChanges>().0, vec![1]);
+        assert!(app.world().get::<Dead>(boss).is_some());
+    }
+}
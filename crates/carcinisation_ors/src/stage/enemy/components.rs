@@ -60,11 +60,12 @@ pub struct LinearTween {
 
 // Bosses
 
-#[derive(Component)]
-pub struct EnemyMarauder;
+#[derive(Component, Debug, Default)]
+/// Marks the enemy a stop step's `kill_boss` condition waits on.
+pub struct Boss;
 
 #[derive(Component)]
-pub struct EnemySpidomonsta {}
+pub struct EnemyMarauder;
 
 #[derive(Component)]
 pub struct EnemyKyle {}
@@ -25,8 +25,11 @@ use crate::stage::{
         interactive::{BurningCorpse, Dead, Flickerer, Health},
         placement::{AnchorOffsets, Depth},
     },
-    enemy::{components::composed_state::Dying, entity::EnemyType},
-    messages::{ComposedAnimationCueMessage, PartDamageMessage},
+    enemy::{boss::BossPhases, components::composed_state::Dying, entity::EnemyType},
+    messages::{
+        ComposedAnimationCueMessage, ComposedEffectCueMessage, ComposedSoundCueMessage,
+        PartDamageMessage,
+    },
     resources::StageTimeDomain,
 };
 use assert_assets_path::assert_assets_path;
@@ -136,6 +139,8 @@ struct CompositionAtlasCache {
     animations: HashMap<String, CachedAnimation>,
     entity_health_pool: Option<String>,
     health_pools: HashMap<String, u32>,
+    /// Health pool ids in authored order.
+    health_pool_ids: Vec<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -356,6 +361,10 @@ impl ComposedCollisionState {
 /// Mutable gameplay health pools for a live composed enemy instance.
 pub struct ComposedHealthPools {
     pools: HashMap<String, u32>,
+    /// Starting health of each pool.
+    max_pools: HashMap<String, u32>,
+    /// Pool ids in authored order.
+    ids: Vec<String>,
 }
 
 impl ComposedHealthPools {
@@ -375,7 +384,11 @@ impl ComposedHealthPools {
             *pool_health = override_health;
         }
 
-        Self { pools }
+        Self {
+            max_pools: pools.clone(),
+            pools,
+            ids: cache.health_pool_ids.clone(),
+        }
     }
 
     #[must_use]
@@ -383,6 +396,39 @@ impl ComposedHealthPools {
         &self.pools
     }
 
+    /// Pool ids in the order the atlas metadata authors them.
+    #[must_use]
+    pub fn ids(&self) -> &[String] {
+        &self.ids
+    }
+
+    #[must_use]
+    pub fn health(&self, pool_id: &str) -> Option<u32> {
+        self.pools.get(pool_id).copied()
+    }
+
+    #[must_use]
+    pub fn max_health(&self, pool_id: &str) -> Option<u32> {
+        self.max_pools.get(pool_id).copied()
+    }
+
+    /// Test-only constructor taking `(id, health, max_health)` in authored order.
+    #[cfg(test)]
+    #[must_use]
+    pub fn test_with_pools(pools: Vec<(&str, u32, u32)>) -> Self {
+        Self {
+            ids: pools.iter().map(|(id, _, _)| (*id).to_string()).collect(),
+            max_pools: pools
+                .iter()
+                .map(|(id, _, max)| ((*id).to_string(), *max))
+                .collect(),
+            pools: pools
+                .iter()
+                .map(|(id, health, _)| ((*id).to_string(), *health))
+                .collect(),
+        }
+    }
+
     fn apply_damage(&mut self, pool_id: &str, amount: u32) -> Option<u32> {
         let value = self.pools.get_mut(pool_id)?;
         *value = value.saturating_sub(amount);
@@ -422,6 +468,7 @@ impl ComposedPartStates {
                         visible: true,
                         hit_blink: None,
                         tags: part.tags.clone(),
+                        health_pool: part.gameplay.health_pool.clone(),
                     },
                 ))
             })
@@ -466,6 +513,7 @@ impl ComposedPartStates {
                         visible: !broken,
                         hit_blink: None,
                         tags: tags.into_iter().map(String::from).collect(),
+                        health_pool: None,
                     },
                 )
             })
@@ -492,6 +540,8 @@ pub struct PartGameplayState {
     /// Enables gameplay systems to query parts by semantic role (e.g., "wings")
     /// without depending on specific visual part IDs.
     pub tags: Vec<String>,
+    /// Health pool the part draws from, copied at load time.
+    pub health_pool: Option<String>,
 }
 
 impl PartGameplayState {
@@ -1452,6 +1502,77 @@ pub fn update_composed_enemy_visuals(
     }
 }
 
+/// Resolves an authored cue origin to the position it is presented at.
+///
+/// Uses the cue's part pivot plus `local_offset` when the part resolved this
+/// frame, otherwise the entity's visual centre. Includes the parallax
+/// `collision_offset` so effects line up with what is drawn.
+#[must_use]
+pub fn composed_cue_position(
+    root: Vec2,
+    resolved_parts: Option<&ComposedResolvedParts>,
+    presentation: Option<&CxPresentationTransform>,
+    part_id: Option<&str>,
+    local_offset: IVec2,
+) -> Vec2 {
+    let scaled_offset =
+        resolved_parts.map_or(Vec2::ZERO, ComposedResolvedParts::scaled_visual_offset);
+    let collision_offset = presentation.map_or(Vec2::ZERO, |pt| pt.collision_offset);
+    let resolved_part = part_id.and_then(|part_id| {
+        resolved_parts.and_then(|parts| parts.parts().iter().find(|part| part.part_id == part_id))
+    });
+    resolved_part.map_or(root + scaled_offset + collision_offset, |part| {
+        part.presented_point_from_local_offset(local_offset, scaled_offset, collision_offset)
+    })
+}
+
+/// @system Re-emits authored `sound_play` and `effect_spawn` cues as typed
+/// messages carrying the cue's world position.
+pub fn emit_composed_sound_and_effect_cues(
+    mut cue_reader: MessageReader<ComposedAnimationCueMessage>,
+    mut sound_writer: MessageWriter<ComposedSoundCueMessage>,
+    mut effect_writer: MessageWriter<ComposedEffectCueMessage>,
+    query: Query<(
+        &WorldPos,
+        Option<&ComposedResolvedParts>,
+        Option<&CxPresentationTransform>,
+    )>,
+) {
+    for cue in cue_reader.read() {
+        if !matches!(
+            cue.kind,
+            AnimationEventKind::SoundPlay | AnimationEventKind::EffectSpawn
+        ) {
+            continue;
+        }
+        let Ok((position, resolved_parts, presentation)) = query.get(cue.entity) else {
+            continue;
+        };
+        let position = composed_cue_position(
+            position.0,
+            resolved_parts,
+            presentation,
+            cue.part_id.as_deref(),
+            IVec2::new(cue.local_offset.x, cue.local_offset.y),
+        );
+
+        if cue.kind == AnimationEventKind::SoundPlay {
+            sound_writer.write(ComposedSoundCueMessage {
+                entity: cue.entity,
+                id: cue.id.clone(),
+                position,
+            });
+        } else {
+            effect_writer.write(ComposedEffectCueMessage {
+                entity: cue.entity,
+                id: cue.id.clone(),
+                part_id: cue.part_id.clone(),
+                position,
+            });
+        }
+    }
+}
+
 /// Applies frame-derived render data to presentation components.
 ///
 /// Runs in `PostUpdate` so all gameplay mutations (position, depth, animation)
@@ -1502,12 +1623,14 @@ pub fn apply_composed_part_damage(
             &mut ComposedHealthPools,
             &mut ComposedPartStates,
             Option<&mut Health>,
+            Option<&BossPhases>,
         ),
         (Without<Dead>, Without<BurningCorpse>),
     >,
 ) {
     for event in event_reader.read() {
-        let Ok((visual, mut health_pools, mut part_states, health)) = query.get_mut(event.entity)
+        let Ok((visual, mut health_pools, mut part_states, health, boss_phases)) =
+            query.get_mut(event.entity)
         else {
             continue;
         };
@@ -1526,6 +1649,17 @@ pub fn apply_composed_part_damage(
             continue;
         };
 
+        // Parts feeding a boss phase that has not started yet shrug off hits.
+        if let Some(boss_phases) = boss_phases
+            && let Some(pool_id) = cache
+                .parts_by_id
+                .get(&event.part_id)
+                .and_then(|part| part.gameplay.health_pool.as_deref())
+            && boss_phases.is_locked(pool_id)
+        {
+            continue;
+        }
+
         let Ok(result) = apply_part_damage(
             cache,
             &mut health_pools,
@@ -1821,6 +1955,12 @@ fn build_runtime_cache_compact(
         .iter()
         .map(|pool| (pool.id.clone(), u32::from(pool.max_health)))
         .collect();
+    let health_pool_ids = atlas
+        .gameplay
+        .health_pools
+        .iter()
+        .map(|pool| pool.id.clone())
+        .collect();
 
     Ok(CompositionAtlasCache {
         visual_parts_in_draw_order,
@@ -1828,6 +1968,7 @@ fn build_runtime_cache_compact(
         animations,
         entity_health_pool: atlas.gameplay.entity_health_pool.clone(),
         health_pools,
+        health_pool_ids,
     })
 }
 
@@ -2105,6 +2246,12 @@ fn build_runtime_cache(atlas: &CompositionAtlas) -> Result<CompositionAtlasCache
         .iter()
         .map(|pool| (pool.id.clone(), pool.max_health))
         .collect();
+    let health_pool_ids = atlas
+        .gameplay
+        .health_pools
+        .iter()
+        .map(|pool| pool.id.clone())
+        .collect();
 
     Ok(CompositionAtlasCache {
         visual_parts_in_draw_order,
@@ -2112,6 +2259,7 @@ fn build_runtime_cache(atlas: &CompositionAtlas) -> Result<CompositionAtlasCache
         animations,
         entity_health_pool: atlas.gameplay.entity_health_pool.clone(),
         health_pools,
+        health_pool_ids,
     })
 }
 
@@ -3464,6 +3612,27 @@ mod tests {
         assert_eq!(atlas.atlas.entity, "mosquiton");
     }
 
+    #[test]
+    fn exported_spidomonsta_pools_follow_boss_phase_order() {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../../assets/sprites/enemies/spidomonsta_3/atlas.composed.ron");
+        let bytes = fs::read(path).expect("atlas.composed.ron should exist");
+        let mut atlas: CompositionAtlasAsset =
+            ron::de::from_bytes(&bytes).expect("atlas.composed.ron should deserialize");
+        atlas.prepare_runtime();
+        let cache = atlas.runtime().expect("spidomonsta atlas should be valid");
+
+        let pools = ComposedHealthPools::from_cache(cache);
+        assert_eq!(pools.ids(), ["legs", "pedipalps", "core"]);
+        assert_eq!(cache.entity_health_pool.as_deref(), Some("core"));
+        let parts = ComposedPartStates::from_cache(cache);
+        let leg = parts
+            .part("legs_l")
+            .expect("legs should have gameplay state");
+        assert!(leg.breakable);
+        assert_eq!(leg.health_pool.as_deref(), Some("legs"));
+    }
+
     #[test]
     fn compact_sprite_tables_reject_mismatched_lengths() {
         let mut atlas = load_exported_mosquiton();
@@ -5688,6 +5857,7 @@ mod tests {
                         remaining_invert_cycles: 1,
                     }),
                     tags: vec![],
+                    health_pool: None,
                 },
             )]),
         };
@@ -6655,6 +6825,48 @@ mod tests {
         assert_eq!(visual, Vec2::new(41.0, 553.0));
     }
 
+    #[test]
+    fn composed_cue_position_uses_part_or_falls_back_to_visual_centre() {
+        let part = ResolvedPartState {
+            part_id: "head".to_string(),
+            parent_id: None,
+            draw_order: 0,
+            sprite_id: "s".to_string(),
+            frame_size: UVec2::new(6, 16),
+            flip_x: false,
+            flip_y: false,
+            part_pivot: IVec2::ZERO,
+            world_top_left_position: Vec2::new(35.0, 513.0),
+            world_pivot_position: Vec2::new(35.0, 513.0),
+            tags: vec![],
+            targetable: false,
+            health_pool: None,
+            armour: 0,
+            current_durability: None,
+            max_durability: None,
+            breakable: false,
+            broken: false,
+            blinking: false,
+            collisions: vec![],
+        };
+        let resolved =
+            ComposedResolvedParts::with_parts_and_offset(vec![part], Vec2::new(0.0, 49.0));
+        let root = Vec2::new(30.0, 500.0);
+
+        let on_part =
+            composed_cue_position(root, Some(&resolved), None, Some("head"), IVec2::new(6, 9));
+        assert_eq!(on_part, Vec2::new(41.0, 553.0));
+
+        let missing_part =
+            composed_cue_position(root, Some(&resolved), None, Some("tail"), IVec2::new(6, 9));
+        assert_eq!(missing_part, Vec2::new(30.0, 549.0));
+
+        assert_eq!(
+            composed_cue_position(root, None, None, None, IVec2::ZERO),
+            root
+        );
+    }
+
     // ── flip_authored_offset tests ─────────────────────────────────────────
 
     #[test]
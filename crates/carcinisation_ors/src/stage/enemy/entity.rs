@@ -40,6 +40,14 @@ impl EnemyType {
         }
     }
 
+    /// Whether spawns of this type are bosses (see [`Boss`]).
+    ///
+    /// [`Boss`]: super::components::Boss
+    #[must_use]
+    pub const fn is_boss(&self) -> bool {
+        matches!(self, Self::Marauder | Self::Spidomonsta | Self::Kyle)
+    }
+
     /// Returns the base authored depth for composed-animation enemy types.
     ///
     /// Assets are authored at a single canonical depth; other depths use
@@ -48,7 +56,7 @@ impl EnemyType {
     #[must_use]
     pub const fn composed_authored_depth(&self) -> Option<Depth> {
         match self {
-            Self::Mosquiton | Self::Spidey => Some(Depth::Three),
+            Self::Mosquiton | Self::Spidey | Self::Spidomonsta => Some(Depth::Three),
             _ => None,
         }
     }
@@ -17,6 +17,8 @@ use std::{collections::VecDeque, time::Duration};
 
 pub const ENEMY_SPIDEY_RADIUS: f32 = 8.0;
 pub const ENEMY_SPIDEY_BASE_HEALTH: u32 = 20;
+/// Default cooldown between consecutive spider shot attacks.
+pub const ENEMY_SPIDEY_ATTACK_COOLDOWN: Duration = Duration::from_millis(4000);
 
 #[derive(Component, Clone, Debug, Default, Reflect)]
 #[reflect(Component)]
@@ -48,6 +50,8 @@ pub enum EnemySpideyAnimation {
 pub struct EnemySpideyAttacking {
     pub active: bool,
     pub last_attack_started: Duration,
+    /// Time between consecutive shots.
+    pub cooldown: Duration,
 }
 
 impl Default for EnemySpideyAttacking {
@@ -55,6 +59,7 @@ impl Default for EnemySpideyAttacking {
         Self {
             active: false,
             last_attack_started: Duration::ZERO,
+            cooldown: ENEMY_SPIDEY_ATTACK_COOLDOWN,
         }
     }
 }
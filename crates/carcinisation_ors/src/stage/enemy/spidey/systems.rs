@@ -30,9 +30,6 @@ use carapace::prelude::{CxPresentationTransform, CxSpriteAtlasAsset, WorldPos};
 use carcinisation_core::components::DespawnMark;
 use std::time::Duration;
 
-/// Cooldown between consecutive spider shot attacks.
-pub const ENEMY_SPIDEY_ATTACK_COOLDOWN: Duration = Duration::from_millis(4000);
-
 /// How long the shoot animation plays before the attack state clears.
 /// 7 frames * 200ms = 1400ms.
 pub const ENEMY_SPIDEY_RANGED_PRESENTATION: Duration = Duration::from_millis(1400);
@@ -40,8 +37,12 @@ pub const ENEMY_SPIDEY_RANGED_PRESENTATION: Duration = Duration::from_millis(140
 /// Event ID authored in the composed RON `shoot` animation.
 const SPIDEY_SPIDER_SHOT_EVENT_ID: &str = "spider_shot";
 
-fn spidey_attack_cooldown_ready(cooldown_anchor: Duration, stage_elapsed: Duration) -> bool {
-    stage_elapsed >= cooldown_anchor + ENEMY_SPIDEY_ATTACK_COOLDOWN
+fn spidey_attack_cooldown_ready(
+    cooldown_anchor: Duration,
+    cooldown: Duration,
+    stage_elapsed: Duration,
+) -> bool {
+    stage_elapsed >= cooldown_anchor + cooldown
 }
 
 fn spidey_attack_presentation_finished(
@@ -227,7 +228,8 @@ pub fn check_idle_spidey(
         }
 
         let cooldown_anchor = attacking.last_attack_started.max(behavior.started);
-        if !spidey_attack_cooldown_ready(cooldown_anchor, stage_time.elapsed()) {
+        if !spidey_attack_cooldown_ready(cooldown_anchor, attacking.cooldown, stage_time.elapsed())
+        {
             continue;
         }
 
@@ -325,7 +327,10 @@ pub fn trigger_spidey_authored_attack_cues(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::stage::enemy::data::spidey::{ACTION_IDLE, ACTION_JUMP, ACTION_LANDING};
+    use crate::stage::enemy::{
+        data::spidey::{ACTION_IDLE, ACTION_JUMP, ACTION_LANDING},
+        spidey::entity::ENEMY_SPIDEY_ATTACK_COOLDOWN,
+    };
     use carcinisation_base::direction::SpriteDirection;
     use std::time::Duration;
 
@@ -333,10 +338,12 @@ mod tests {
     fn spidey_attack_cooldown_gates_attack() {
         assert!(!spidey_attack_cooldown_ready(
             Duration::ZERO,
+            ENEMY_SPIDEY_ATTACK_COOLDOWN,
             Duration::ZERO
         ));
         assert!(spidey_attack_cooldown_ready(
             Duration::ZERO,
+            ENEMY_SPIDEY_ATTACK_COOLDOWN,
             ENEMY_SPIDEY_ATTACK_COOLDOWN
         ));
     }
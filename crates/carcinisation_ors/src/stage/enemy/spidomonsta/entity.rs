@@ -0,0 +1,57 @@
+use bevy::prelude::*;
+use std::time::Duration;
+
+pub const ENEMY_SPIDOMONSTA_BASE_HEALTH: u32 = 60;
+
+/// Giant spider boss.
+///
+/// Spidomonsta is built on the spidey rig, so it also carries
+/// [`EnemySpidey`](crate::stage::enemy::spidey::entity::EnemySpidey) and reuses
+/// the spidey animation, ranged attack and death systems. Its boss phases,
+/// one per atlas health pool (legs, pedipalps, core), retune those through
+/// [`SPIDOMONSTA_PHASES`].
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct EnemySpidomonsta;
+
+impl EnemySpidomonsta {
+    /// Awarded on top of the spidey kill score.
+    #[must_use]
+    pub const fn kill_score(&self) -> u32 {
+        250
+    }
+}
+
+/// Tuning applied when a Spidomonsta phase starts.
+#[derive(Clone, Copy, Debug)]
+pub struct SpidomonstaPhase {
+    pub attack_cooldown: Duration,
+    /// Stops jumping; set once the legs are gone.
+    pub grounded: bool,
+}
+
+pub const SPIDOMONSTA_PHASES: [SpidomonstaPhase; 3] = [
+    // Legs: jumps between depths following its authored steps.
+    SpidomonstaPhase {
+        attack_cooldown: Duration::from_millis(3000),
+        grounded: false,
+    },
+    // Pedipalps: legs broken, stays put and fires faster.
+    SpidomonstaPhase {
+        attack_cooldown: Duration::from_millis(2000),
+        grounded: true,
+    },
+    // Core: exposed, fires as fast as the shoot animation allows.
+    SpidomonstaPhase {
+        attack_cooldown: Duration::from_millis(1400),
+        grounded: true,
+    },
+];
+
+impl SpidomonstaPhase {
+    /// Tuning for `phase`, holding the last entry for any phase past it.
+    #[must_use]
+    pub fn get(phase: usize) -> Self {
+        SPIDOMONSTA_PHASES[phase.min(SPIDOMONSTA_PHASES.len() - 1)]
+    }
+}
@@ -0,0 +1,82 @@
+use super::entity::{EnemySpidomonsta, SpidomonstaPhase};
+use crate::stage::{
+    components::interactive::{BurningCorpse, Dead},
+    enemy::{
+        components::behavior::EnemyBehaviors,
+        spidey::entity::{EnemySpideyAttacking, EnemySpideyBehaviorLoop},
+    },
+    messages::BossPhaseChangedEvent,
+};
+use crate::stubs::Score;
+use bevy::prelude::*;
+
+/// @trigger Applies the tuning of the phase a Spidomonsta just entered.
+///
+/// Grounded phases drop the authored step loop and any queued steps, so the
+/// boss idles in place once the current step finishes.
+pub fn on_spidomonsta_phase_changed(
+    trigger: On<BossPhaseChangedEvent>,
+    mut commands: Commands,
+    mut query: Query<(&mut EnemySpideyAttacking, &mut EnemyBehaviors), With<EnemySpidomonsta>>,
+) {
+    let event = trigger.event();
+    let Ok((mut attacking, mut behaviors)) = query.get_mut(event.entity) else {
+        return;
+    };
+    let phase = SpidomonstaPhase::get(event.phase);
+    attacking.cooldown = phase.attack_cooldown;
+    if phase.grounded {
+        behaviors.0.clear();
+        commands
+            .entity(event.entity)
+            .remove::<EnemySpideyBehaviorLoop>();
+    }
+}
+
+/// @system Awards the boss bonus when a Spidomonsta dies; the shared spidey
+/// systems handle the death effect.
+pub fn award_spidomonsta_kill(
+    mut score: ResMut<Score>,
+    query: Query<&EnemySpidomonsta, (Added<Dead>, Without<BurningCorpse>)>,
+) {
+    for spidomonsta in &query {
+        score.add_u(spidomonsta.kill_score());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stage::enemy::data::steps::EnemyStep;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn grounded_phase_drops_the_step_loop() {
+        let mut app = App::new();
+        app.add_observer(on_spidomonsta_phase_changed);
+        let steps = VecDeque::from([EnemyStep::default()]);
+        let boss = app
+            .world_mut()
+            .spawn((
+                EnemySpidomonsta,
+                EnemySpideyAttacking::default(),
+                EnemyBehaviors(steps.clone()),
+                EnemySpideyBehaviorLoop(steps),
+            ))
+            .id();
+
+        app.world_mut().trigger(BossPhaseChangedEvent {
+            entity: boss,
+            phase: 1,
+        });
+        app.world_mut().flush();
+
+        let entity = app.world().entity(boss);
+        assert_eq!(
+            entity.get::<EnemySpideyAttacking>().unwrap().cooldown,
+            SpidomonstaPhase::get(1).attack_cooldown
+        );
+        assert!(entity.get::<EnemyBehaviors>().unwrap().0.is_empty());
+        assert!(entity.get::<EnemySpideyBehaviorLoop>().is_none());
+    }
+}
@@ -1,3 +1,4 @@
 pub mod animation;
 pub mod behaviors;
+pub mod cues;
 pub mod damage;
@@ -0,0 +1,133 @@
+//! Built-in handlers for authored composed-animation sound and effect cues.
+
+use std::time::Duration;
+
+use crate::stage::{
+    components::StageEntity,
+    messages::{ComposedEffectCueMessage, ComposedSoundCueMessage},
+    resources::StageTimeDomain,
+};
+use assert_assets_path::assert_assets_path;
+use bevy::prelude::*;
+use carapace::prelude::{
+    CxAnchor, CxEmitter, CxEmitterFrequency, CxRenderSpace, CxVelocity, ParticleLifetime,
+};
+use carcinisation_base::layer::{Layer, OrsLayer};
+use carcinisation_core::{
+    audio::{AudioBus, make_sound_bundle},
+    components::DespawnAfterDelay,
+};
+
+const CUE_SFX_ROOT: &str = "audio/sfx";
+const EFFECT_PARTICLE_SPRITE_DEFAULT: &str =
+    assert_assets_path!("sprites/bullet_particles.px_sprite.png");
+const EFFECT_PARTICLE_SPRITE_BLOOD: &str =
+    assert_assets_path!("sprites/attacks/blood_attack_hit_0.px_sprite.png");
+const EFFECT_PARTICLE_SPRITE_DEBRIS: &str =
+    assert_assets_path!("sprites/attacks/boulder_attack_hit_0.px_sprite.png");
+
+/// Half-size of the square particles spawn in around the cue origin.
+const EFFECT_SPREAD: i32 = 2;
+/// How long an effect cue keeps emitting.
+const EFFECT_EMIT_DURATION: Duration = Duration::from_millis(200);
+const EFFECT_EMIT_INTERVAL: Duration = Duration::from_millis(40);
+const EFFECT_PARTICLE_LIFETIME: Duration = Duration::from_millis(400);
+const EFFECT_PARTICLE_VELOCITY: Vec2 = Vec2::new(0., 12.);
+
+/// Asset path played for a `sound_play` cue id, or `None` if the id is not a
+/// plain file stem.
+#[must_use]
+pub fn cue_sfx_path(id: &str) -> Option<String> {
+    let valid = !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    valid.then(|| format!("{CUE_SFX_ROOT}/{id}.ogg"))
+}
+
+/// Particle sprite emitted for an `effect_spawn` cue id.
+#[must_use]
+pub fn cue_effect_sprite_path(id: &str) -> &'static str {
+    match id {
+        "blood" | "blood_splash" => EFFECT_PARTICLE_SPRITE_BLOOD,
+        "debris" | "dust" => EFFECT_PARTICLE_SPRITE_DEBRIS,
+        _ => EFFECT_PARTICLE_SPRITE_DEFAULT,
+    }
+}
+
+/// @system Plays the SFX named by each composed `sound_play` cue.
+pub fn play_composed_sound_cues(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut reader: MessageReader<ComposedSoundCueMessage>,
+) {
+    for cue in reader.read() {
+        let Some(path) = cue_sfx_path(&cue.id) else {
+            warn!("Ignoring sound cue with invalid id '{}'", cue.id);
+            continue;
+        };
+        commands.spawn((
+            make_sound_bundle(&asset_server, path, AudioBus::Sfx),
+            StageEntity,
+        ));
+    }
+}
+
+/// @system Spawns a short-lived particle emitter at each composed
+/// `effect_spawn` cue.
+pub fn spawn_composed_effect_cues(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    stage_time: Res<Time<StageTimeDomain>>,
+    mut reader: MessageReader<ComposedEffectCueMessage>,
+) {
+    for cue in reader.read() {
+        let origin = cue.position.round().as_ivec2();
+        commands.spawn((
+            CxEmitter {
+                sprites: vec![asset_server.load(cue_effect_sprite_path(&cue.id))],
+                range: IRect::from_center_half_size(origin, IVec2::splat(EFFECT_SPREAD)),
+                frequency: CxEmitterFrequency::single(EFFECT_EMIT_INTERVAL),
+                ..default()
+            },
+            CxAnchor::Center,
+            CxRenderSpace::World,
+            Layer::Ors(OrsLayer::Attack),
+            ParticleLifetime(EFFECT_PARTICLE_LIFETIME),
+            CxVelocity(EFFECT_PARTICLE_VELOCITY),
+            DespawnAfterDelay {
+                elapsed: stage_time.elapsed(),
+                duration: EFFECT_EMIT_DURATION,
+            },
+            StageEntity,
+            Name::new(format!("EffectCue<{}>", cue.id)),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sound_cue_ids_resolve_under_sfx_root() {
+        assert_eq!(
+            cue_sfx_path("enemy_shot").as_deref(),
+            Some("audio/sfx/enemy_shot.ogg")
+        );
+        assert_eq!(cue_sfx_path(""), None);
+        assert_eq!(cue_sfx_path("../music/title"), None);
+    }
+
+    #[test]
+    fn unknown_effect_ids_use_default_particle() {
+        assert_eq!(
+            cue_effect_sprite_path("blood"),
+            EFFECT_PARTICLE_SPRITE_BLOOD
+        );
+        assert_eq!(
+            cue_effect_sprite_path("sparkle"),
+            EFFECT_PARTICLE_SPRITE_DEFAULT
+        );
+    }
+}
@@ -264,6 +264,9 @@ fn resolve_depth_y(
 /// During stop/cinematic steps, holds the current step's resolved state.
 /// Zero-duration tweens snap to the destination (no interpolation).
 ///
+/// A stage [`ProjectionSource`](super::projection::ProjectionSource) replaces
+/// the step projections on both sides of the tween.
+///
 /// # Panics
 /// Panics if `Depth::try_from` fails for any depth in the visible range.
 #[must_use]
@@ -271,7 +274,14 @@ pub fn evaluate_floors_at(stage_data: &StageData, elapsed: Duration) -> ActiveFl
     let info = walk_steps_at_elapsed(stage_data, elapsed);
 
     let curr_layout = effective_floor_layout(stage_data, info.step_index);
-    let curr_projection = effective_projection(stage_data, info.step_index);
+    // A projection source drives both sides of the tween; only the layout
+    // still interpolates between steps.
+    let sourced_projection = stage_data
+        .projection_source
+        .as_ref()
+        .map(|source| source.profile_at(stage_data, &info, elapsed));
+    let curr_projection =
+        sourced_projection.unwrap_or_else(|| effective_projection(stage_data, info.step_index));
 
     // Interpolate only during tween steps with meaningful progress.
     if info.tween_progress < 1.0
@@ -286,7 +296,9 @@ pub fn evaluate_floors_at(stage_data: &StageData, elapsed: Duration) -> ActiveFl
             } else {
                 SurfaceLayout::default()
             };
-            let prev_projection = if info.step_index > 0 {
+            let prev_projection = if let Some(projection) = sourced_projection {
+                projection
+            } else if info.step_index > 0 {
                 effective_projection(stage_data, info.step_index - 1)
             } else {
                 stage_data.projection.unwrap_or_default()
@@ -376,6 +388,7 @@ mod tests {
             on_end_transition_o: None,
             gravity: None,
             projection: None,
+            projection_source: None,
             checkpoint: None,
             parallax_attenuation: None,
             primitives: vec![],
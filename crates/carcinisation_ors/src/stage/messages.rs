@@ -22,6 +22,11 @@ pub struct StageRestart {
 /// Signals that the scripted sequence should advance to the next step.
 pub struct NextStepEvent;
 
+#[derive(Event, Message)]
+/// Triggered by the cutscene player when a cinematic step's cutscene finishes
+/// or is skipped.
+pub struct StageCinematicFinishedEvent;
+
 #[derive(Clone, Event, Message)]
 /// Triggered when the stage clears all objectives.
 pub struct StageClearedEvent;
@@ -30,6 +35,14 @@ pub struct StageClearedEvent;
 /// Indicates the player died during the current stage run.
 pub struct StageDeathEvent;
 
+#[derive(Clone, Copy, Debug, Event, Message)]
+/// Triggered when a boss clears a phase and starts the next one.
+pub struct BossPhaseChangedEvent {
+    pub entity: Entity,
+    /// Index of the phase that just started.
+    pub phase: usize,
+}
+
 #[derive(Event, Message)]
 /// Requests spawning of a concrete `StageSpawn` instruction.
 pub struct StageSpawnEvent {
@@ -124,3 +137,22 @@ pub struct ComposedAnimationCueMessage {
     pub part_id: Option<String>,
     pub local_offset: Point,
 }
+
+#[derive(Clone, Debug, Message)]
+/// Authored `sound_play` cue from a composed animation, resolved to a world position.
+pub struct ComposedSoundCueMessage {
+    pub entity: Entity,
+    /// Authored event id; the built-in handler plays `audio/sfx/<id>.ogg`.
+    pub id: String,
+    pub position: Vec2,
+}
+
+#[derive(Clone, Debug, Message)]
+/// Authored `effect_spawn` cue from a composed animation, resolved to the
+/// world position of its part.
+pub struct ComposedEffectCueMessage {
+    pub entity: Entity,
+    pub id: String,
+    pub part_id: Option<String>,
+    pub position: Vec2,
+}
@@ -55,8 +55,10 @@ use self::{
     enemy::EnemyPlugin,
     enemy::composed::{apply_composed_part_damage, check_composed_damage_flicker_taken},
     messages::{
-        ComposedAnimationCueMessage, DamageMessage, DepthChangedMessage, NextStepEvent,
-        PartDamageMessage, StageClearedEvent, StageDeathEvent, StageSpawnEvent, StageStartupEvent,
+        ComposedAnimationCueMessage, ComposedEffectCueMessage, ComposedSoundCueMessage,
+        DamageMessage, DepthChangedMessage, NextStepEvent, PartDamageMessage,
+        StageCinematicFinishedEvent, StageClearedEvent, StageDeathEvent, StageSpawnEvent,
+        StageStartupEvent,
     },
     parallax::{
         ActiveParallaxAttenuation, compose_presentation_offsets,
@@ -76,7 +78,7 @@ use self::{
             update_camera_pos_y, update_lateral_view_offset,
         },
         check_movement_step_reached, check_stage_death, check_stage_step_timer,
-        check_staged_cleared, check_stop_step_finished_by_duration,
+        check_staged_cleared, check_stop_step_finished,
         damage::{add_invert_filter, check_damage_flicker_taken, on_damage, remove_invert_filter},
         initialise_cinematic_step, initialise_movement_step, initialise_stop_step,
         movement::{
@@ -86,7 +88,8 @@ use self::{
             update_enemy_pos_x, update_enemy_pos_y, update_non_enemy_depth_from_targeting_z,
         },
         on_death, on_next_step_cleanup_cinematic_step, on_next_step_cleanup_movement_step,
-        on_next_step_cleanup_stop_step, on_stage_cleared, read_step_trigger,
+        on_next_step_cleanup_stop_step, on_stage_cinematic_finished, on_stage_cleared,
+        read_step_trigger,
         setup::on_stage_startup,
         spawn::{check_dead_drop, on_stage_spawn},
         tick_stage_step_timer, toggle_game, update_active_floor_layout, update_active_floors,
@@ -116,6 +119,7 @@ use cween::{
     pursue::PursueMovementPlugin,
 };
 use data::StageData;
+use std::sync::Arc;
 
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
 /// Systems that load stage data and assets before play begins.
@@ -153,6 +157,19 @@ pub struct StageHooks {
     /// Fire a visual transition (venetian wipe, etc).
     /// Default: no-op. App provides the real implementation.
     pub trigger_transition: fn(&mut Commands, &carcinisation_cutscene::data::TransitionRequest),
+    /// Play a cinematic step's cutscene, triggering
+    /// [`messages::StageCinematicFinishedEvent`] once it finishes or is skipped.
+    /// Default: [`skip_stage_cinematic`]. App provides the real implementation.
+    pub play_cutscene: fn(&mut Commands, Arc<carcinisation_cutscene::data::CutsceneData>),
+}
+
+/// Default [`StageHooks::play_cutscene`] for apps without a cutscene player:
+/// finishes the cinematic step immediately.
+pub fn skip_stage_cinematic(
+    commands: &mut Commands,
+    _data: Arc<carcinisation_cutscene::data::CutsceneData>,
+) {
+    commands.trigger(StageCinematicFinishedEvent);
 }
 
 /// Registers all stage-related plugins, assets, events, and frame drives.
@@ -274,6 +291,7 @@ impl<G: Activable, M: Activable> Plugin for StagePlugin<G, M> {
             trigger_transition: |_commands, _request| {
                 // No-op default. App overrides via StageHooks::with_transition().
             },
+            play_cutscene: skip_stage_cinematic,
         });
 
         app
@@ -297,6 +315,8 @@ impl<G: Activable, M: Activable> Plugin for StagePlugin<G, M> {
             .add_message::<DamageMessage>()
             .add_message::<PartDamageMessage>()
             .add_message::<ComposedAnimationCueMessage>()
+            .add_message::<ComposedSoundCueMessage>()
+            .add_message::<ComposedEffectCueMessage>()
             .add_message::<DepthChangedMessage>()
             .add_message::<StageDeathEvent>()
             .add_observer(on_death)
@@ -304,6 +324,8 @@ impl<G: Activable, M: Activable> Plugin for StagePlugin<G, M> {
             .add_observer(on_next_step_cleanup_movement_step)
             .add_observer(on_next_step_cleanup_cinematic_step)
             .add_observer(on_next_step_cleanup_stop_step)
+            .add_message::<StageCinematicFinishedEvent>()
+            .add_observer(on_stage_cinematic_finished)
             .add_message::<StageStartupEvent>()
             .add_observer(on_stage_startup)
             .add_message::<StageSpawnEvent>()
@@ -335,6 +357,15 @@ impl<G: Activable, M: Activable> Plugin for StagePlugin<G, M> {
                 OnExit(GameProgressState::Paused),
                 activate_system::<PlayerPlugin>,
             )
+            // Cinematic steps hold the player for the length of the cutscene.
+            .add_systems(
+                OnEnter(GameProgressState::Cutscene),
+                deactivate_system::<PlayerPlugin>,
+            )
+            .add_systems(
+                OnExit(GameProgressState::Cutscene),
+                activate_system::<PlayerPlugin>,
+            )
             // Shared movement helpers (linear/pursue) reused by multiple enemy types.
             .add_plugins(PursueMovementPlugin::<StageTimeDomain, WorldPos>::default())
             .add_plugins(LinearTweenPlugin::<StageTimeDomain, TargetingValueX>::default())
@@ -461,7 +492,9 @@ impl<G: Activable, M: Activable> Plugin for StagePlugin<G, M> {
                         ),
                         (
                             update_cinematic_step,
-                            check_stop_step_finished_by_duration,
+                            check_stop_step_finished
+                                .after(check_step_spawn)
+                                .after(check_dead_drop),
                             check_movement_step_reached,
                         ),
                     )
@@ -531,6 +564,7 @@ mod tests {
             deactivate_game: activable::deactivate::<MockGame>,
             activate_menu: activable::activate::<MockMenu>,
             trigger_transition: |_commands, _request| {},
+            play_cutscene: skip_stage_cinematic,
         };
 
         // Verify the pointers resolve to the expected functions.
@@ -562,6 +596,7 @@ mod tests {
             deactivate_game: activable::deactivate::<MockGame>,
             activate_menu: activable::activate::<MockMenu>,
             trigger_transition: |_commands, _request| {},
+            play_cutscene: skip_stage_cinematic,
         };
 
         // Calling the no-op handler with a real TransitionRequest must not panic.
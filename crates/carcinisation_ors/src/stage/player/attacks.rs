@@ -2,6 +2,7 @@ use assert_assets_path::assert_assets_path;
 use bevy::prelude::*;
 use carapace::prelude::{CxAnchor, CxAnimationFinishBehavior, CxFrameTransition, CxRenderSpace};
 use carcinisation_base::layer::{Layer, OrsLayer};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 pub const PLAYER_MELEE_ATLAS_PATH: &str = "sprites/attacks/player_melee/atlas.px_atlas.ron";
@@ -11,7 +12,7 @@ pub const PLAYER_MELEE_REGION: &str = "melee_slash";
 pub const PLAYER_BULLET_REGION: &str = "bullet_particles";
 pub const PLAYER_FLAME_REGION: &str = "flame";
 
-#[derive(Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum AttackId {
     Pincer,
     Pistol,
@@ -364,6 +365,11 @@ impl AttackCycle {
         self.options[self.index]
     }
 
+    #[must_use]
+    pub fn options(&self) -> &[AttackId] {
+        &self.options
+    }
+
     pub fn cycle(&mut self) -> AttackId {
         if self.options.is_empty() {
             return AttackId::Pincer;
@@ -379,6 +385,24 @@ pub struct AttackLoadout {
 }
 
 impl AttackLoadout {
+    /// Loadout cycling through `unlocked`, e.g. restored from a save.
+    /// Falls back to the default loadout when empty.
+    #[must_use]
+    pub fn from_unlocked(unlocked: Vec<AttackId>) -> Self {
+        if unlocked.is_empty() {
+            return Self::default();
+        }
+        Self {
+            cycle: AttackCycle::new(unlocked),
+        }
+    }
+
+    /// Attacks the player can cycle to.
+    #[must_use]
+    pub fn unlocked(&self) -> &[AttackId] {
+        self.cycle.options()
+    }
+
     #[must_use]
     pub fn current(&self) -> AttackId {
         self.cycle.current()
@@ -7,15 +7,12 @@ use crate::stage::{
         AttackLifetime, AttackVisualSource,
     },
 };
-use bevy::{
-    audio::{AudioPlayer, AudioSource, PlaybackMode, PlaybackSettings},
-    prelude::*,
-};
+use bevy::prelude::*;
 use carapace::prelude::{
     CxAnimationBundle, CxAnimationDirection, CxAnimationDuration, CxAtlasSprite, CxSprite,
     CxSpriteAtlasAsset, WorldPos,
 };
-use carcinisation_core::components::{AudioSystemBundle, AudioSystemType, VolumeSettings};
+use carcinisation_core::audio::{AudioBus, make_sound_bundle};
 use std::time::Duration;
 
 #[derive(Component)]
@@ -42,7 +39,6 @@ impl PlayerAttack {
         assets_sprite: &mut CxAssets<CxSprite>,
         asset_server: &AssetServer,
         atlas_assets: &Assets<CxSpriteAtlasAsset>,
-        volume_settings: &VolumeSettings,
     ) -> Entity {
         let position = WorldPos::from(self.position);
         let name = Name::new(format!("PlayerAttack<{}>", definition.name));
@@ -124,19 +120,7 @@ impl PlayerAttack {
         let entity = entity_commands.id();
 
         if let Some(sfx_path) = definition.sfx_path {
-            let audio_player = AudioPlayer::<AudioSource>(asset_server.load(sfx_path));
-            let playback_settings = PlaybackSettings {
-                mode: PlaybackMode::Despawn,
-                volume: volume_settings.sfx,
-                ..Default::default()
-            };
-            commands.spawn((
-                audio_player,
-                playback_settings,
-                AudioSystemBundle {
-                    system_type: AudioSystemType::SFX,
-                },
-            ));
+            commands.spawn(make_sound_bundle(asset_server, sfx_path, AudioBus::Sfx));
         }
 
         entity
@@ -16,7 +16,6 @@ use crate::stage::resources::StageTimeDomain;
 use bevy::prelude::*;
 use carapace::prelude::{CxCamera, CxSprite, CxSpriteAtlasAsset, WorldPos};
 use carcinisation_core::components::DespawnMark;
-use carcinisation_core::components::VolumeSettings;
 use carcinisation_core::globals::HUD_HEIGHT;
 use carcinisation_core::globals::SCREEN_RESOLUTION;
 use cween::linear::components::{
@@ -114,7 +113,6 @@ pub fn detect_player_attack(
     player_attack_query: Query<Entity, (With<PlayerAttack>, Without<ActiveFlamethrower>)>,
     player_query: Query<&WorldPos, With<Player>>,
     camera: Res<CxCamera>,
-    volume_settings: Res<VolumeSettings>,
     time: Res<Time<StageTimeDomain>>,
     attack_definitions: Res<AttackDefinitions>,
     mut loadout: ResMut<AttackLoadout>,
@@ -149,7 +147,6 @@ pub fn detect_player_attack(
                 &mut assets_sprite,
                 asset_server.as_ref(),
                 &atlas_assets,
-                volume_settings.as_ref(),
             );
         }
         // The A press was consumed by melee — clear any armed ranged state.
@@ -194,7 +191,6 @@ pub fn detect_player_attack(
             &mut assets_sprite,
             asset_server.as_ref(),
             &atlas_assets,
-            volume_settings.as_ref(),
         );
 
         if attack_id == AttackId::Bomb {
@@ -315,7 +311,6 @@ pub fn despawn_expired_attacks(
     mut assets_sprite: CxAssets<CxSprite>,
     asset_server: Res<AssetServer>,
     atlas_assets: Res<Assets<CxSpriteAtlasAsset>>,
-    volume_settings: Res<VolumeSettings>,
     attack_definitions: Res<AttackDefinitions>,
     mut query: Query<
         (
@@ -352,7 +347,6 @@ pub fn despawn_expired_attacks(
                 &mut assets_sprite,
                 asset_server.as_ref(),
                 &atlas_assets,
-                volume_settings.as_ref(),
             );
             effect_state.follow_up_spawned = true;
         }
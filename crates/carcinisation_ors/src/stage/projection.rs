@@ -10,26 +10,27 @@
 //! overlay (`depth_debug.rs`) and the editor preview consume this shared
 //! geometry, adapting only the rendering backend.
 //!
-//! # V2 extension points
+//! # Projection sources
 //!
-//! `ProjectionProfile` is a plain data struct for V1.  V2 may wrap it in a
-//! driver enum:
+//! Stages normally resolve their profile from the step timeline: a stage-level
+//! default plus sticky per-step overrides, interpolated across tweens.  A
+//! stage may instead author a [`ProjectionSource`] that drives the profile
+//! for its whole duration:
 //!
-//! ```ignore
-//! enum ProjectionSource {
-//!     Static(ProjectionProfile),
-//!     CameraFollow { base: ProjectionProfile, sensitivity: f32 },
-//!     Keyframed(Vec<(f32, ProjectionProfile)>),
-//! }
-//! ```
+//! - [`ProjectionSource::Static`] — one profile for the whole stage.
+//! - [`ProjectionSource::CameraFollow`] — a base profile whose horizon reacts
+//!   to lateral camera motion.
+//! - [`ProjectionSource::Keyframed`] — profiles interpolated between
+//!   keyframes on the stage clock.
 //!
 //! The evaluator signature stays the same — callers always receive a
 //! `ProjectionProfile` regardless of source.
 
-use std::time::Duration;
+use std::{f32::consts::PI, time::Duration};
 
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use serde_with::{DurationSecondsWithFrac, serde_as};
 
 use super::{
     components::{CinematicStageStep, StopStageStep, TweenStageStep},
@@ -223,6 +224,138 @@ impl ProjectionProfile {
     }
 }
 
+// ---------------------------------------------------------------------------
+// ProjectionSource
+// ---------------------------------------------------------------------------
+
+/// Minimum gap kept between a camera-follow horizon and its floor base so the
+/// derived profile stays valid.
+const CAMERA_FOLLOW_MIN_GAP: f32 = 1.0;
+
+/// A projection profile pinned to a point on the stage clock.
+#[serde_as]
+#[derive(Clone, Copy, Debug, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct ProjectionKeyframe {
+    /// Stage elapsed time at which `profile` is fully reached.
+    #[serde_as(as = "DurationSecondsWithFrac")]
+    pub at: Duration,
+    pub profile: ProjectionProfile,
+}
+
+/// Drives the stage projection over time in place of the per-step profiles.
+#[derive(Clone, Debug, PartialEq, Reflect, Serialize, Deserialize)]
+pub enum ProjectionSource {
+    /// One profile for the whole stage.
+    Static(ProjectionProfile),
+    /// `base` with its horizon raised by `sensitivity` pixels per pixel/second
+    /// of lateral camera speed.  The response eases in and out over each
+    /// tween, so the horizon settles back on `base` at step boundaries.
+    CameraFollow {
+        base: ProjectionProfile,
+        sensitivity: f32,
+    },
+    /// Profiles interpolated between keyframes, sorted by `at`.  Holds the
+    /// first profile before the first keyframe and the last one after it.
+    Keyframed(Vec<ProjectionKeyframe>),
+}
+
+impl ProjectionSource {
+    /// Validate the source, returning a descriptive error on failure.
+    ///
+    /// # Errors
+    ///
+    /// Returns a descriptive `String` if a profile is invalid, the
+    /// sensitivity is not finite, or the keyframes are empty or not strictly
+    /// increasing in time.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::Static(profile) => profile.validate(),
+            Self::CameraFollow { base, sensitivity } => {
+                if !sensitivity.is_finite() {
+                    return Err(format!("sensitivity ({sensitivity}) is not finite"));
+                }
+                base.validate()
+            }
+            Self::Keyframed(keyframes) => {
+                if keyframes.is_empty() {
+                    return Err("keyframes must not be empty".into());
+                }
+                for (i, keyframe) in keyframes.iter().enumerate() {
+                    keyframe
+                        .profile
+                        .validate()
+                        .map_err(|e| format!("keyframe {i}: {e}"))?;
+                    if i > 0 && keyframe.at <= keyframes[i - 1].at {
+                        return Err(format!(
+                            "keyframe {i} at {:?} must come after keyframe {} at {:?}",
+                            keyframe.at,
+                            i - 1,
+                            keyframes[i - 1].at,
+                        ));
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Evaluate the source at `elapsed`, with `info` the step progress at
+    /// that time.
+    #[must_use]
+    pub fn profile_at(
+        &self,
+        stage_data: &StageData,
+        info: &StepProgressInfo,
+        elapsed: Duration,
+    ) -> ProjectionProfile {
+        match self {
+            Self::Static(profile) => *profile,
+            Self::CameraFollow { base, sensitivity } => {
+                let lift = sensitivity * lateral_camera_speed(stage_data, info).abs();
+                ProjectionProfile {
+                    horizon_y: (base.horizon_y + lift)
+                        .max(base.floor_base_y + CAMERA_FOLLOW_MIN_GAP),
+                    ..*base
+                }
+            }
+            Self::Keyframed(keyframes) => keyframed_profile(keyframes, elapsed),
+        }
+    }
+}
+
+/// Eased lateral camera speed (pixels per second) at the given progress.
+///
+/// Zero outside tweens.  During a tween the constant tween speed is scaled by
+/// `sin(π·t)`, so it ramps up from and back down to zero at the step
+/// boundaries instead of jumping.
+fn lateral_camera_speed(stage_data: &StageData, info: &StepProgressInfo) -> f32 {
+    let Some(StageStep::Tween(tween)) = stage_data.steps.get(info.step_index) else {
+        return 0.0;
+    };
+    let dur = tween_duration(info.step_start_position, tween).as_secs_f32();
+    if dur < MIN_INTERPOLATION_DURATION_SECS {
+        return 0.0;
+    }
+    let speed = (tween.coordinates.x - info.step_start_position.x) / dur;
+    speed * (PI * info.tween_progress).sin()
+}
+
+fn keyframed_profile(keyframes: &[ProjectionKeyframe], elapsed: Duration) -> ProjectionProfile {
+    let next = keyframes.partition_point(|keyframe| keyframe.at <= elapsed);
+    match (
+        next.checked_sub(1).map(|i| &keyframes[i]),
+        keyframes.get(next),
+    ) {
+        (Some(prev), Some(next)) => {
+            let span = (next.at - prev.at).as_secs_f32();
+            let t = (elapsed - prev.at).as_secs_f32() / span;
+            ProjectionProfile::lerp(&prev.profile, &next.profile, t)
+        }
+        (Some(only), None) | (None, Some(only)) => only.profile,
+        (None, None) => ProjectionProfile::default(),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Step progress + projection evaluation
 // ---------------------------------------------------------------------------
@@ -248,7 +381,8 @@ pub struct StepProgressInfo {
 ///
 /// Duration model matches the editor timeline: tween steps contribute
 /// `distance / base_speed`, stop steps contribute `max_duration` (or zero
-/// for infinite / None), cinematic steps contribute cutscene spawn sums.
+/// for infinite / None), cinematic steps contribute nothing (they hold the
+/// stage clock).
 #[must_use]
 pub fn walk_steps_at_elapsed(stage_data: &StageData, elapsed: Duration) -> StepProgressInfo {
     let mut pos = stage_data.start_coordinates;
@@ -365,6 +499,9 @@ const MIN_INTERPOLATION_DURATION_SECS: f32 = 0.01;
 /// Tweens shorter than [`MIN_INTERPOLATION_DURATION_SECS`] snap directly to
 /// `curr` to avoid jitter from near-zero denominators.
 ///
+/// When the stage authors a [`ProjectionSource`], the source is evaluated
+/// instead and step-level profiles are not consulted.
+///
 /// # V2 extension
 ///
 /// TODO: Projection evaluation may later be derived directly from
@@ -376,6 +513,10 @@ const MIN_INTERPOLATION_DURATION_SECS: f32 = 0.01;
 pub fn evaluate_projection_at(stage_data: &StageData, elapsed: Duration) -> ProjectionProfile {
     let info = walk_steps_at_elapsed(stage_data, elapsed);
 
+    if let Some(source) = &stage_data.projection_source {
+        return source.profile_at(stage_data, &info, elapsed);
+    }
+
     // Resolve the projection at the current step (sticky carry-forward).
     let curr = effective_projection(stage_data, info.step_index);
 
@@ -405,7 +546,9 @@ pub fn evaluate_projection_at(stage_data: &StageData, elapsed: Duration) -> Proj
 
 /// Validate all projection profiles in a [`StageData`].
 ///
-/// Checks the stage-level default (if present) and every step-level override.
+/// Checks the stage-level default (if present), every step-level override and
+/// the [`ProjectionSource`] (if present).  A source replaces the step
+/// timeline, so authoring it alongside any profile override is rejected.
 /// Returns `Ok(())` if all profiles are valid, or an error with the location
 /// and reason of the first invalid profile found.
 ///
@@ -428,10 +571,23 @@ pub fn validate_stage_projections(stage_data: &StageData) -> Result<(), String>
             StageStep::Cinematic(_) => None,
         };
         if let Some(p) = proj {
+            if stage_data.projection_source.is_some() {
+                return Err(format!(
+                    "Projection at step {i} conflicts with the stage projection_source"
+                ));
+            }
             p.validate()
                 .map_err(|e| format!("Invalid projection at step {i}: {e}"))?;
         }
     }
+    if let Some(ref source) = stage_data.projection_source {
+        if stage_data.projection.is_some() {
+            return Err("Stage-level projection conflicts with the stage projection_source".into());
+        }
+        source
+            .validate()
+            .map_err(|e| format!("Invalid projection_source: {e}"))?;
+    }
     Ok(())
 }
 
@@ -447,13 +603,10 @@ fn stop_step_duration(step: &StopStageStep) -> Duration {
     step.max_duration.unwrap_or(Duration::ZERO)
 }
 
-fn cinematic_step_duration(step: &CinematicStageStep) -> Duration {
-    match step {
-        CinematicStageStep::CutsceneAnimationSpawn(s) => s
-            .spawns
-            .iter()
-            .fold(Duration::ZERO, |acc, sp| acc + sp.duration),
-    }
+/// Cinematic steps hold the stage clock while their cutscene plays, so they
+/// take no stage time.
+const fn cinematic_step_duration(_step: &CinematicStageStep) -> Duration {
+    Duration::ZERO
 }
 
 // ---------------------------------------------------------------------------
@@ -1400,6 +1553,7 @@ mod tests {
             on_end_transition_o: None,
             gravity: None,
             projection: None,
+            projection_source: None,
             checkpoint: None,
             parallax_attenuation: None,
             primitives: vec![],
@@ -1606,6 +1760,86 @@ mod tests {
         assert_eq!(p, profile_a());
     }
 
+    // --- ProjectionSource ---
+
+    fn keyframe(secs: u64, profile: ProjectionProfile) -> ProjectionKeyframe {
+        ProjectionKeyframe {
+            at: Duration::from_secs(secs),
+            profile,
+        }
+    }
+
+    #[test]
+    fn keyframed_source_interpolates_and_holds_ends() {
+        let mut stage = make_stage(vec![stop_step(100.0)]);
+        stage.projection_source = Some(ProjectionSource::Keyframed(vec![
+            keyframe(10, profile_a()),
+            keyframe(30, profile_b()),
+        ]));
+        assert_eq!(evaluate_projection_at(&stage, Duration::ZERO), profile_a());
+        assert_eq!(
+            evaluate_projection_at(&stage, Duration::from_secs(20)),
+            ProjectionProfile::lerp(&profile_a(), &profile_b(), 0.5)
+        );
+        assert_eq!(
+            evaluate_projection_at(&stage, Duration::from_secs(90)),
+            profile_b()
+        );
+    }
+
+    #[test]
+    fn camera_follow_source_lifts_horizon_while_panning() {
+        // 100 px lateral tween at 10 px/s, then a stop.
+        let mut stage = make_stage(vec![tween_step(100.0, 0.0, 10.0), stop_step(10.0)]);
+        stage.projection_source = Some(ProjectionSource::CameraFollow {
+            base: profile_a(),
+            sensitivity: 0.5,
+        });
+        // Mid-tween the eased speed peaks at 10 px/s → +5 px.
+        let mid = evaluate_projection_at(&stage, Duration::from_secs(5));
+        assert!((mid.horizon_y - (profile_a().horizon_y + 5.0)).abs() < 1e-3);
+        assert!((mid.floor_base_y - profile_a().floor_base_y).abs() < f32::EPSILON);
+        // Settled at the tween start and during the stop.
+        assert_eq!(evaluate_projection_at(&stage, Duration::ZERO), profile_a());
+        assert_eq!(
+            evaluate_projection_at(&stage, Duration::from_secs(15)),
+            profile_a()
+        );
+    }
+
+    #[test]
+    fn camera_follow_horizon_stays_above_floor_base() {
+        let mut stage = make_stage(vec![tween_step(100.0, 0.0, 10.0)]);
+        stage.projection_source = Some(ProjectionSource::CameraFollow {
+            base: profile_a(),
+            sensitivity: -100.0,
+        });
+        assert!(evaluate_projection_at(&stage, Duration::from_secs(5)).is_valid());
+    }
+
+    #[test]
+    fn validate_rejects_unsorted_keyframes() {
+        let source =
+            ProjectionSource::Keyframed(vec![keyframe(30, profile_a()), keyframe(10, profile_b())]);
+        assert!(source.validate().is_err());
+        assert!(ProjectionSource::Keyframed(vec![]).validate().is_err());
+    }
+
+    #[test]
+    fn validate_rejects_source_alongside_step_override() {
+        let mut stage = make_stage(vec![tween_step_with_projection(
+            100.0,
+            0.0,
+            1.0,
+            profile_a(),
+        )]);
+        stage.projection_source = Some(ProjectionSource::Static(profile_b()));
+        assert!(validate_stage_projections(&stage).is_err());
+
+        stage.steps = vec![tween_step(100.0, 0.0, 1.0)];
+        assert!(validate_stage_projections(&stage).is_ok());
+    }
+
     // --- Perimeter-filter grid certification tests ---
 
     #[test]
@@ -17,7 +17,6 @@ use crate::stage::{
     systems::{CameraStepTween, camera},
 };
 use crate::stubs::CameraPos;
-use carcinisation_core::components::Music;
 use carcinisation_core::globals::mark_for_despawn_by_query;
 
 /// Bundles stage-level resources used by the restart system, keeping
@@ -35,7 +34,8 @@ pub struct StageRestartResources<'w> {
 /// Despawns all entities that belong to the current stage run.
 ///
 /// Covers the `Stage` controller entity and every entity tagged with
-/// `StageEntity` (HUD, background, skybox, music, attack effects, etc.).
+/// `StageEntity` (HUD, background, skybox, attack effects, etc.). Music is
+/// owned by the audio mixer and crossfades on the next stage startup.
 /// Used by both the checkpoint-restart and game-over-exit paths to ensure
 /// a clean slate.
 pub fn despawn_stage_entities(
@@ -59,7 +59,6 @@ pub fn handle_stage_restart(
     stage_entity_query: Query<Entity, With<StageEntity>>,
     destructible_query: Query<Entity, With<Destructible>>,
     enemy_query: Query<Entity, With<Enemy>>,
-    music_query: Query<Entity, With<Music>>,
     object_query: Query<Entity, With<Object>>,
     player_query: Query<Entity, With<Player>>,
     mut camera_query: Query<(Entity, Option<&CameraShake>, &mut WorldPos), With<CameraPos>>,
@@ -95,7 +94,6 @@ pub fn handle_stage_restart(
         // already handles these for the game-over path).
         mark_for_despawn_by_query(&mut commands, &destructible_query);
         mark_for_despawn_by_query(&mut commands, &enemy_query);
-        mark_for_despawn_by_query(&mut commands, &music_query);
         mark_for_despawn_by_query(&mut commands, &object_query);
         mark_for_despawn_by_query(&mut commands, &player_query);
 
@@ -13,15 +13,15 @@ use super::{
     StageProgressState,
     attack::components::EnemyAttack,
     components::{
-        CinematicStageStep, CurrentStageStep, Stage, StageElapsedStarted, StageEntity,
-        StopStageStep, TweenStageStep,
+        CinematicStageStep, CurrentStageStep, Stage, StageCinematicAsset, StageElapsedStarted,
+        StageEntity, StopStageStep, StopStepTargets, TweenStageStep,
         interactive::{Dead, Object},
     },
-    data::{OrsGameplayConfig, StageData, StageStep},
+    data::{OrsGameplayConfig, StageData, StageSpawn, StageStep},
     destructible::components::Destructible,
-    enemy::components::Enemy,
+    enemy::components::{Boss, Enemy},
     floors::{ActiveFloors, ActiveSurfaceLayout, effective_floor_layout, evaluate_floors_at},
-    messages::{NextStepEvent, StageClearedEvent, StageDeathEvent},
+    messages::{NextStepEvent, StageCinematicFinishedEvent, StageClearedEvent, StageDeathEvent},
     player::components::{CameraShake, Player},
     projection::evaluate_projection_at,
     resources::{
@@ -35,20 +35,20 @@ use crate::stubs::GameOverEvent;
 use crate::stubs::GameProgressState;
 use crate::stubs::Lives;
 use crate::stubs::Score;
-use crate::stubs::make_music_bundle;
 use assert_assets_path::assert_assets_path;
-use bevy::{audio::PlaybackMode, prelude::*};
+use bevy::prelude::*;
 use carapace::prelude::WorldPos;
+use carcinisation_core::audio::PlayMusic;
 use carcinisation_core::components::DespawnMark;
-use carcinisation_core::components::Music;
-use carcinisation_core::components::VolumeSettings;
 use carcinisation_core::core::time::TimeShouldRun;
 use carcinisation_core::globals::mark_for_despawn_by_query;
+use carcinisation_cutscene::data::CutsceneData;
 use carcinisation_input::GBInput;
 use cween::linear::components::{
     TargetingValueX, TargetingValueY, TweenChildBundle, extra::LinearTween2DReachCheck,
 };
 use leafwing_input_manager::prelude::ActionState;
+use std::sync::Arc;
 
 /// @system Toggles the game state between running and paused on `Start`.
 pub fn toggle_game(
@@ -57,16 +57,21 @@ pub fn toggle_game(
     mut next_state: ResMut<NextState<GameProgressState>>,
 ) {
     if gb_input.just_pressed(&GBInput::Start) {
-        if *state.get() == GameProgressState::Running {
-            #[cfg(debug_assertions)]
-            info!("Game Paused.");
+        match state.get() {
+            GameProgressState::Running => {
+                #[cfg(debug_assertions)]
+                info!("Game Paused.");
 
-            next_state.set(GameProgressState::Paused);
-        } else {
-            #[cfg(debug_assertions)]
-            info!("Game Running.");
+                next_state.set(GameProgressState::Paused);
+            }
+            GameProgressState::Paused => {
+                #[cfg(debug_assertions)]
+                info!("Game Running.");
 
-            next_state.set(GameProgressState::Running);
+                next_state.set(GameProgressState::Running);
+            }
+            // Start belongs to the cutscene (skip) while a cinematic plays.
+            GameProgressState::Loading | GameProgressState::Cutscene => {}
         }
     }
 }
@@ -77,11 +82,10 @@ pub fn update_stage_time_should_run(
     game_state: Res<State<GameProgressState>>,
     mut should_run: ResMut<TimeShouldRun<StageTimeDomain>>,
 ) {
+    // Cinematic steps (`GameProgressState::Cutscene`) hold the stage clock so
+    // enemies and attacks freeze while the cutscene plays.
     should_run.value = *stage_state.get() == StageProgressState::Running
-        && matches!(
-            *game_state.get(),
-            GameProgressState::Running | GameProgressState::Cutscene
-        );
+        && *game_state.get() == GameProgressState::Running;
 }
 
 // TODO combine the two and use just_finished
@@ -208,7 +212,10 @@ pub fn check_staged_cleared(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::stage::{data::EnemySpawn, enemy::entity::EnemyType};
     use bevy::ecs::system::SystemState;
+    use carcinisation_base::layer::{Layer, MenuLayer};
+    use carcinisation_cutscene::data::{CutsceneAnimationSpawn, CutsceneAnimationsSpawn};
 
     fn run_gate(stage: StageProgressState, game: GameProgressState) -> bool {
         let mut world = World::new();
@@ -246,8 +253,8 @@ mod tests {
     }
 
     #[test]
-    fn stage_time_runs_during_cutscene() {
-        assert!(run_gate(
+    fn stage_time_stops_during_cinematic() {
+        assert!(!run_gate(
             StageProgressState::Running,
             GameProgressState::Cutscene
         ));
@@ -308,6 +315,7 @@ mod tests {
             on_end_transition_o: None,
             gravity: None,
             projection: None,
+            projection_source: None,
             checkpoint: None,
             parallax_attenuation: None,
             primitives: vec![],
@@ -335,6 +343,239 @@ mod tests {
         update_active_projection(data, time, active);
         system_state.apply(&mut world);
     }
+
+    #[derive(Resource, Default)]
+    struct StepsAdvanced(u32);
+
+    /// Headless stage running `step` through the stop-step systems.
+    fn stop_step_app(step: StopStageStep) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<Time<StageTimeDomain>>()
+            .init_resource::<StepsAdvanced>()
+            .add_observer(
+                |_: On<NextStepEvent>, mut advanced: ResMut<StepsAdvanced>| {
+                    advanced.0 += 1;
+                },
+            )
+            .add_systems(
+                Update,
+                (initialise_stop_step, check_stop_step_finished).chain(),
+            );
+        app.world_mut().spawn((
+            Stage,
+            CurrentStageStep {
+                started: std::time::Duration::ZERO,
+            },
+            step,
+        ));
+        app
+    }
+
+    fn steps_advanced(app: &App) -> u32 {
+        app.world().resource::<StepsAdvanced>().0
+    }
+
+    fn enemy_spawn(enemy_type: EnemyType) -> StageSpawn {
+        StageSpawn::Enemy(EnemySpawn::mosquito_base().with_enemy_type(enemy_type))
+    }
+
+    #[test]
+    fn kill_all_waits_for_enemies_alive_at_start() {
+        let mut app = stop_step_app(StopStageStep::new().with_kill_all(true));
+        let enemy = app.world_mut().spawn(Enemy).id();
+        app.update();
+        assert_eq!(steps_advanced(&app), 0);
+
+        app.world_mut().entity_mut(enemy).insert(Dead);
+        app.update();
+        assert_eq!(steps_advanced(&app), 1);
+    }
+
+    #[test]
+    fn kill_all_waits_for_pending_and_spawned_enemies() {
+        let mut app = stop_step_app(
+            StopStageStep::new()
+                .with_kill_all(true)
+                .add_spawns(vec![enemy_spawn(EnemyType::Mosquito)]),
+        );
+        app.update();
+        assert_eq!(steps_advanced(&app), 0, "spawn still pending");
+
+        // What `check_step_spawn` + `on_stage_spawn` do once the spawn is due.
+        let enemy = app.world_mut().spawn(Enemy).id();
+        let mut stage_query = app
+            .world_mut()
+            .query_filtered::<(&mut StageStepSpawner, &mut StopStepTargets), With<Stage>>();
+        let (mut spawner, mut targets) = stage_query.single_mut(app.world_mut()).unwrap();
+        spawner.spawns.clear();
+        targets.enemies.push(enemy);
+        app.update();
+        assert_eq!(steps_advanced(&app), 0, "spawned enemy alive");
+
+        app.world_mut().despawn(enemy);
+        app.update();
+        assert_eq!(steps_advanced(&app), 1);
+    }
+
+    #[test]
+    fn kill_boss_ignores_regular_enemies() {
+        let mut app = stop_step_app(
+            StopStageStep::new()
+                .with_kill_all(false)
+                .with_kill_boss(true),
+        );
+        app.world_mut().spawn(Enemy);
+        let boss = app.world_mut().spawn((Enemy, Boss)).id();
+        app.update();
+        assert_eq!(steps_advanced(&app), 0);
+
+        app.world_mut().entity_mut(boss).insert(Dead);
+        app.update();
+        assert_eq!(steps_advanced(&app), 1);
+    }
+
+    #[test]
+    fn kill_boss_waits_for_pending_boss_spawn() {
+        let mut app = stop_step_app(
+            StopStageStep::new()
+                .with_kill_all(false)
+                .with_kill_boss(true)
+                .add_spawns(vec![enemy_spawn(EnemyType::Kyle)]),
+        );
+        app.update();
+        assert_eq!(steps_advanced(&app), 0);
+    }
+
+    #[derive(Resource)]
+    struct PlayedCutscene(String);
+
+    fn record_cutscene(commands: &mut Commands, data: Arc<CutsceneData>) {
+        commands.insert_resource(PlayedCutscene(data.name.clone()));
+    }
+
+    /// Headless stage running `step` with `play_cutscene` as the cutscene
+    /// player.
+    fn cinematic_step_app(
+        step: CinematicStageStep,
+        play_cutscene: fn(&mut Commands, Arc<CutsceneData>),
+    ) -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            bevy::state::app::StatesPlugin,
+        ))
+        .init_state::<GameProgressState>()
+        .init_resource::<StepsAdvanced>()
+        .add_message::<PlayMusic>()
+        .insert_resource(StageHooks {
+            activate_stage: |_| {},
+            deactivate_stage: |_| {},
+            deactivate_game: |_| {},
+            activate_menu: |_| {},
+            trigger_transition: |_, _| {},
+            play_cutscene,
+        })
+        .add_observer(on_stage_cinematic_finished)
+        .add_observer(on_next_step_cleanup_cinematic_step)
+        .add_observer(
+            |_: On<NextStepEvent>, mut advanced: ResMut<StepsAdvanced>| {
+                advanced.0 += 1;
+            },
+        )
+        .add_systems(Update, (initialise_cinematic_step, update_cinematic_step));
+        app.world_mut().spawn((
+            Stage,
+            CurrentStageStep {
+                started: std::time::Duration::ZERO,
+            },
+            step,
+        ));
+        app
+    }
+
+    fn game_state(app: &App) -> GameProgressState {
+        app.world()
+            .resource::<State<GameProgressState>>()
+            .get()
+            .clone()
+    }
+
+    #[test]
+    fn cinematic_step_pauses_stage_until_cutscene_finishes() {
+        let step = CinematicStageStep::Cutscene(CutsceneData::new("Mid-stage".to_string()));
+        let mut app = cinematic_step_app(step, record_cutscene);
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world().resource::<PlayedCutscene>().0,
+            "Mid-stage".to_string()
+        );
+        assert_eq!(game_state(&app), GameProgressState::Cutscene);
+        assert_eq!(steps_advanced(&app), 0);
+
+        app.world_mut().trigger(StageCinematicFinishedEvent);
+        app.update();
+        assert_eq!(game_state(&app), GameProgressState::Running);
+        assert_eq!(steps_advanced(&app), 1);
+        let mut steps = app
+            .world_mut()
+            .query_filtered::<(), (With<Stage>, With<CinematicStageStep>)>();
+        assert_eq!(steps.iter(app.world()).count(), 0);
+    }
+
+    #[test]
+    fn cinematic_step_without_cutscene_player_is_skipped() {
+        let step = CinematicStageStep::Cutscene(CutsceneData::new("Mid-stage".to_string()));
+        let mut app = cinematic_step_app(step, super::super::skip_stage_cinematic);
+        app.update();
+        app.update();
+        assert_eq!(game_state(&app), GameProgressState::Running);
+        assert_eq!(steps_advanced(&app), 1);
+    }
+
+    #[test]
+    fn animation_cinematic_plays_as_one_timed_act() {
+        let spawns = CutsceneAnimationsSpawn {
+            spawns: vec![CutsceneAnimationSpawn::new(
+                "cinematics/x.png".to_string(),
+                2,
+                std::time::Duration::from_millis(1500),
+                Layer::Menu(MenuLayer::Background),
+            )],
+        };
+        let data = CinematicStageStep::CutsceneAnimationSpawn(spawns)
+            .inline_cutscene()
+            .unwrap();
+        assert_eq!(data.steps.len(), 1);
+        assert_eq!(data.steps[0].elapse, std::time::Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn new_stop_without_enemies_stays_open() {
+        let mut app = stop_step_app(StopStageStep::new());
+        app.update();
+        app.update();
+        assert_eq!(steps_advanced(&app), 0);
+    }
+
+    #[test]
+    fn stop_without_kill_conditions_ends_by_duration_only() {
+        let mut app = stop_step_app(
+            StopStageStep::new()
+                .with_kill_all(false)
+                .with_max_duration(5.0),
+        );
+        app.update();
+        assert_eq!(steps_advanced(&app), 0, "no enemies, but no kill condition");
+
+        app.world_mut()
+            .resource_mut::<Time<StageTimeDomain>>()
+            .advance_by(std::time::Duration::from_secs(5));
+        app.update();
+        assert_eq!(steps_advanced(&app), 1);
+    }
 }
 
 /// @trigger Handles cleanup and celebration when the stage is cleared.
@@ -345,11 +586,9 @@ pub fn on_stage_cleared(
     mut next_state: ResMut<NextState<StageProgressState>>,
     destructible_query: Query<Entity, With<Destructible>>,
     enemy_query: Query<Entity, With<Enemy>>,
-    music_query: Query<Entity, With<Music>>,
     object_query: Query<Entity, With<Object>>,
     player_query: Query<Entity, With<Player>>,
-    asset_server: Res<AssetServer>,
-    volume_settings: Res<VolumeSettings>,
+    mut play_music_writer: MessageWriter<PlayMusic>,
     stage_data: Res<StageData>,
     stage_hooks: Res<StageHooks>,
 ) {
@@ -359,18 +598,12 @@ pub fn on_stage_cleared(
 
     mark_for_despawn_by_query(&mut commands, &destructible_query);
     mark_for_despawn_by_query(&mut commands, &enemy_query);
-    mark_for_despawn_by_query(&mut commands, &music_query);
     mark_for_despawn_by_query(&mut commands, &object_query);
     mark_for_despawn_by_query(&mut commands, &player_query);
 
-    let (player, settings, system_bundle, music_tag) = make_music_bundle(
-        &asset_server,
-        &volume_settings,
-        assert_assets_path!("audio/music/intro.ogg").to_string(),
-        PlaybackMode::Despawn,
-    );
-
-    commands.spawn((player, settings, system_bundle, music_tag, StageEntity));
+    play_music_writer.write(PlayMusic::once(assert_assets_path!(
+        "audio/music/intro.ogg"
+    )));
 
     next_state.set(StageProgressState::Cleared);
 }
@@ -406,30 +639,23 @@ pub fn on_death(
     attack_query: Query<Entity, With<EnemyAttack>>,
     destructible_query: Query<Entity, With<Destructible>>,
     enemy_query: Query<Entity, With<Enemy>>,
-    music_query: Query<Entity, With<Music>>,
     object_query: Query<Entity, With<Object>>,
     player_query: Query<Entity, With<Player>>,
     mut camera_query: Query<(Entity, Option<&CameraShake>, &mut WorldPos), With<CameraPos>>,
     camera_tween_query: Query<Entity, With<CameraStepTween>>,
-    asset_server: Res<AssetServer>,
-    volume_settings: Res<VolumeSettings>,
+    mut play_music_writer: MessageWriter<PlayMusic>,
 ) {
     camera::cleanup_camera_stage_state(&mut commands, &mut camera_query, &camera_tween_query);
 
     mark_for_despawn_by_query(&mut commands, &attack_query);
     mark_for_despawn_by_query(&mut commands, &destructible_query);
     mark_for_despawn_by_query(&mut commands, &enemy_query);
-    mark_for_despawn_by_query(&mut commands, &music_query);
     mark_for_despawn_by_query(&mut commands, &object_query);
     mark_for_despawn_by_query(&mut commands, &player_query);
 
-    let (player, settings, system_bundle, music_tag) = make_music_bundle(
-        &asset_server,
-        &volume_settings,
-        assert_assets_path!("audio/music/game_over.ogg").to_string(),
-        PlaybackMode::Despawn,
-    );
-    commands.spawn((player, settings, system_bundle, music_tag, StageEntity));
+    play_music_writer.write(PlayMusic::once(assert_assets_path!(
+        "audio/music/game_over.ogg"
+    )));
 
     if lives.0 > 0 && stage_data.checkpoint.is_some() {
         next_state.set(StageProgressState::Death);
@@ -475,13 +701,25 @@ pub fn read_step_trigger(
     }
 }
 
-/// @system Prepares cinematic steps (stub — cutscene integration pending).
+/// @system Starts cinematic steps: pauses the stage and plays the step's
+/// cutscene, or starts loading it.
 pub fn initialise_cinematic_step(
-    _next_state: ResMut<NextState<GameProgressState>>,
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<GameProgressState>>,
     query: Query<(Entity, &CinematicStageStep), (With<Stage>, Added<CinematicStageStep>)>,
+    asset_server: Res<AssetServer>,
+    stage_hooks: Res<StageHooks>,
 ) {
-    if query.single().is_ok() {
-        // next_state.set(GameState::Cutscene);
+    let Ok((entity, step)) = query.single() else {
+        return;
+    };
+    next_state.set(GameProgressState::Cutscene);
+    if let Some(data) = step.inline_cutscene() {
+        (stage_hooks.play_cutscene)(&mut commands, Arc::new(data));
+    } else if let CinematicStageStep::CutsceneAsset(src) = step {
+        commands
+            .entity(entity)
+            .insert(StageCinematicAsset(asset_server.load(src.clone())));
     }
 }
 
@@ -551,15 +789,19 @@ pub fn initialise_movement_step(
     }
 }
 
-/// @system Seeds stop-step spawners and optional floor markers.
+/// @system Seeds stop-step spawners and the enemies its kill conditions wait on.
 pub fn initialise_stop_step(
     mut commands: Commands,
     query: Query<(Entity, &StopStageStep), (With<Stage>, Added<StopStageStep>)>,
+    enemy_query: Query<Entity, (With<Enemy>, Without<Dead>)>,
 ) {
     if let Ok((entity, StopStageStep { spawns, .. })) = query.single() {
-        commands
-            .entity(entity)
-            .insert(StageStepSpawner::new(spawns.clone()));
+        commands.entity(entity).insert((
+            StageStepSpawner::new(spawns.clone()),
+            StopStepTargets {
+                enemies: enemy_query.iter().collect(),
+            },
+        ));
     }
 }
 
@@ -588,28 +830,115 @@ pub fn check_movement_step_reached(
     commands.trigger(NextStepEvent);
 }
 
-/// @system Advances stop steps once their optional duration expires.
-pub fn check_stop_step_finished_by_duration(
+/// Whether the stop step's `kill_all` / `kill_boss` conditions are met.
+///
+/// `targets` must only hold living enemies. Pending enemy spawns keep the
+/// conditions open.
+fn stop_step_kills_met(
+    step: &StopStageStep,
+    pending: &[StageSpawn],
+    targets: &StopStepTargets,
+    is_boss: impl Fn(Entity) -> bool,
+) -> bool {
+    let pending_enemy = |boss_only: bool| {
+        pending.iter().any(|spawn| {
+            matches!(spawn, StageSpawn::Enemy(enemy) if !boss_only || enemy.enemy_type.is_boss())
+        })
+    };
+    let alive_enemy = |boss_only: bool| {
+        targets
+            .enemies
+            .iter()
+            .any(|&entity| !boss_only || is_boss(entity))
+    };
+    let kill_all_met = !step.kill_all || !(pending_enemy(false) || alive_enemy(false));
+    let kill_boss_met = !step.kill_boss || !(pending_enemy(true) || alive_enemy(true));
+    kill_all_met && kill_boss_met
+}
+
+/// @system Advances stop steps once their optional duration expires, or once
+/// their `kill_all` / `kill_boss` conditions are met.
+///
+/// A step with neither condition only ends by duration.
+pub fn check_stop_step_finished(
     mut commands: Commands,
-    query: Query<(&StopStageStep, &CurrentStageStep), With<Stage>>,
+    mut query: Query<
+        (
+            &StopStageStep,
+            &CurrentStageStep,
+            &StageStepSpawner,
+            &mut StopStepTargets,
+        ),
+        With<Stage>,
+    >,
+    enemy_query: Query<Has<Boss>, (With<Enemy>, Without<Dead>)>,
     stage_time: Res<Time<StageTimeDomain>>,
 ) {
-    for (step, current_step) in query.iter() {
-        if step
-            .max_duration
-            .is_some_and(|max_duration| current_step.started + max_duration <= stage_time.elapsed())
-        {
+    for (step, current_step, spawner, mut targets) in &mut query {
+        let timed_out = step.max_duration.is_some_and(|max_duration| {
+            current_step.started + max_duration <= stage_time.elapsed()
+        });
+
+        targets
+            .enemies
+            .retain(|&entity| enemy_query.contains(entity));
+        let killed = (step.kill_all || step.kill_boss)
+            && stop_step_kills_met(step, &spawner.spawns, &targets, |entity| {
+                enemy_query.get(entity).unwrap_or(false)
+            });
+
+        if timed_out || killed {
             commands.trigger(NextStepEvent);
         }
     }
 }
 
-/// @system Updates cinematic steps each frame (stub — not yet wired).
+/// @system Plays asset-backed cinematic steps once their cutscene loads.
+///
+/// A cutscene that fails to load is skipped so the stage does not stall.
 pub fn update_cinematic_step(
-    _commands: Commands,
-    query: Query<(Entity, &CinematicStageStep), With<Stage>>,
+    mut commands: Commands,
+    query: Query<(Entity, &StageCinematicAsset), With<Stage>>,
+    asset_server: Res<AssetServer>,
+    cutscene_assets: Option<Res<Assets<CutsceneData>>>,
+    stage_hooks: Res<StageHooks>,
+) {
+    for (entity, asset) in query.iter() {
+        if let Some(data) = cutscene_assets
+            .as_deref()
+            .and_then(|assets| assets.get(&asset.0))
+        {
+            commands.entity(entity).remove::<StageCinematicAsset>();
+            (stage_hooks.play_cutscene)(&mut commands, Arc::new(data.clone()));
+        } else if asset_server.load_state(&asset.0).is_failed() {
+            error!("Cinematic step cutscene failed to load, skipping it");
+            commands.entity(entity).remove::<StageCinematicAsset>();
+            commands.trigger(StageCinematicFinishedEvent);
+        }
+    }
+}
+
+/// @trigger Resumes the stage and advances past the cinematic step once its
+/// cutscene finishes or is skipped.
+pub fn on_stage_cinematic_finished(
+    _trigger: On<StageCinematicFinishedEvent>,
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<GameProgressState>>,
+    mut play_music_writer: MessageWriter<PlayMusic>,
+    stage_data: Option<Res<StageData>>,
+    query: Query<(), (With<Stage>, With<CinematicStageStep>)>,
 ) {
-    for (_entity, _) in query.iter() {}
+    if query.is_empty() {
+        return;
+    }
+    next_state.set(GameProgressState::Running);
+    // The cutscene fades out its music when it ends; bring the stage's back.
+    if let Some(stage_data) = stage_data
+        && !stage_data.music_path.is_empty()
+    {
+        play_music_writer.write(PlayMusic::looped(stage_data.music_path.clone()));
+    }
+    commands.trigger(NextStepEvent);
 }
 
 /// @trigger Removes cinematic step markers after `NextStepEvent` fires.
@@ -622,6 +951,7 @@ pub fn on_next_step_cleanup_cinematic_step(
         commands
             .entity(entity)
             .remove::<CinematicStageStep>()
+            .remove::<StageCinematicAsset>()
             .remove::<CurrentStageStep>();
     }
 }
@@ -652,6 +982,7 @@ pub fn on_next_step_cleanup_stop_step(
             .entity(entity)
             .remove::<StopStageStep>()
             .remove::<StageStepSpawner>()
+            .remove::<StopStepTargets>()
             .remove::<CurrentStageStep>();
     }
 }
@@ -1,6 +1,5 @@
 use super::spawn::{spawn_destructible, spawn_enemy, spawn_object, spawn_pickup};
 use crate::stubs::GameProgressState;
-use crate::stubs::make_music_bundle;
 use crate::{
     assets::CxAssets,
     stage::{
@@ -14,7 +13,10 @@ use crate::{
         floors::{ActiveSurfaceLayout, effective_floor_layout, evaluate_floors_at},
         messages::StageStartupEvent,
         player::messages::PlayerStartupEvent,
-        projection::{ProjectionProfile, effective_projection, validate_stage_projections},
+        projection::{
+            ProjectionProfile, effective_projection, evaluate_projection_at,
+            validate_stage_projections,
+        },
         resources::{self, ActiveProjection, StageGravity},
         ui::hud::spawn::spawn_hud,
     },
@@ -22,11 +24,11 @@ use crate::{
 use carcinisation_base::layer::Layer;
 use carcinisation_base::layer::OrsLayer;
 use carcinisation_base::layer::SharedLayer;
-use carcinisation_core::components::VolumeSettings;
+use carcinisation_core::audio::{PlayMusic, StopMusic};
 use carcinisation_core::globals::SCREEN_RESOLUTION;
 use std::time::Duration;
 
-use bevy::{audio::PlaybackMode, prelude::*};
+use bevy::prelude::*;
 use carapace::prelude::{CxFilter, CxSprite, CxTypeface};
 
 /// @trigger Builds the stage world: spawns HUD, enemies, pickups, background, and music.
@@ -43,7 +45,8 @@ pub fn on_stage_startup(
     _filters: CxAssets<CxFilter>,
     mut typefaces: CxAssets<CxTypeface>,
     asset_server: Res<AssetServer>,
-    volume_settings: Res<VolumeSettings>,
+    mut play_music_writer: MessageWriter<PlayMusic>,
+    mut stop_music_writer: MessageWriter<StopMusic>,
     stage_hooks: Res<StageHooks>,
 ) {
     let event = trigger.event();
@@ -100,7 +103,11 @@ pub fn on_stage_startup(
 
     spawn_hud(&mut commands, &mut typefaces, &asset_server);
 
-    let initial_projection = effective_projection(data, 0);
+    let initial_projection = if data.projection_source.is_some() {
+        evaluate_projection_at(data, Duration::ZERO)
+    } else {
+        effective_projection(data, 0)
+    };
     let initial_active_projection = ActiveProjection(initial_projection);
     let initial_floor_layout = effective_floor_layout(data, 0);
     let initial_floors = evaluate_floors_at(data, Duration::ZERO);
@@ -191,15 +198,12 @@ pub fn on_stage_startup(
             }
         });
 
-    // TODO turn this into a spawn, like in cutscene, or make it a StageSpawn
-    if !data.music_path.is_empty() {
-        let (player, settings, system_bundle, music_tag) = make_music_bundle(
-            &asset_server,
-            &volume_settings,
-            data.music_path.clone(),
-            PlaybackMode::Loop,
-        );
-        commands.spawn((player, settings, system_bundle, music_tag, StageEntity));
+    // Crossfades from the previous step's music; a checkpoint restart keeps
+    // the same track playing.
+    if data.music_path.is_empty() {
+        stop_music_writer.write(StopMusic);
+    } else {
+        play_music_writer.write(PlayMusic::looped(data.music_path.clone()));
     }
 
     *next_game_state = NextState::PendingIfNeq(GameProgressState::Running);
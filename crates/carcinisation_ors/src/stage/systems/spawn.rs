@@ -3,7 +3,7 @@
 use crate::assets::CxAssets;
 use crate::stage::{
     components::{
-        SpawnDrop, StageEntity,
+        SpawnDrop, Stage, StageEntity, StopStepTargets,
         interactive::{Collider, ColliderData, Dead},
         placement::{Airborne, AuthoredDepths, Depth},
     },
@@ -26,6 +26,7 @@ use crate::stage::{
             ENEMY_SPIDEY_BASE_HEALTH, EnemySpideyAttacking, EnemySpideyBehaviorLoop, SpideyBundle,
             SpideyDefaultBundle,
         },
+        spidomonsta::entity::{ENEMY_SPIDOMONSTA_BASE_HEALTH, EnemySpidomonsta, SpidomonstaPhase},
         tardigrade::entity::{
             ENEMY_TARDIGRADE_BASE_HEALTH, ENEMY_TARDIGRADE_RADIUS, TardigradeBundle,
             TardigradeDefaultBundle,
@@ -47,7 +48,7 @@ use crate::stage::{
     data::{EnemySpawn, ObjectSpawn, ObjectType, PickupSpawn, StageSpawn},
     destructible::{components::Destructible, data::DestructibleSpawn},
     enemy::components::{
-        Enemy, EnemyContinuousDepth,
+        Boss, Enemy, EnemyContinuousDepth,
         behavior::{EnemyBehaviors, GroundedEnemyFall},
     },
     messages::StageSpawnEvent,
@@ -206,6 +207,7 @@ pub fn on_stage_spawn(
     projection_view: Option<Res<ProjectionView>>,
     parallax_attenuation: Option<Res<ActiveParallaxAttenuation>>,
     camera_query: Query<&WorldPos, With<CameraPos>>,
+    mut stop_targets_query: Query<&mut StopStepTargets, With<Stage>>,
 ) {
     match &trigger.event().spawn {
         StageSpawn::Destructible(x) => {
@@ -213,7 +215,7 @@ pub fn on_stage_spawn(
         }
         StageSpawn::Enemy(x) => {
             let camera_pos = camera_query.single().unwrap();
-            spawn_enemy(
+            let entity = spawn_enemy(
                 &mut commands,
                 &asset_server,
                 camera_pos.0,
@@ -224,6 +226,9 @@ pub fn on_stage_spawn(
                 projection_view.as_deref(),
                 parallax_attenuation.map(|a| a.0),
             );
+            if let Ok(mut stop_targets) = stop_targets_query.single_mut() {
+                stop_targets.enemies.push(entity);
+            }
         }
         StageSpawn::Object(x) => {
             spawn_object(&mut commands, &assets_sprite, x);
@@ -297,7 +302,7 @@ pub fn spawn_enemy(
     let continuous_depth = EnemyContinuousDepth::from_depth(*depth);
     let authored =
         authored_depths_from_spawn(*depth, Some(*enemy_type), spawn.authored_depths.as_ref());
-    match enemy_type {
+    let entity = match enemy_type {
         EnemyType::Mosquito => {
             let collider: Collider =
                 Collider::new_circle(ENEMY_MOSQUITO_RADIUS).with_offset(Vec2::new(0., 2.));
@@ -399,7 +404,7 @@ pub fn spawn_enemy(
             }
             entity
         }
-        EnemyType::Spidey => {
+        EnemyType::Spidey | EnemyType::Spidomonsta => {
             let initial_presentation = compute_presentation_basis(
                 position,
                 *depth,
@@ -409,6 +414,11 @@ pub fn spawn_enemy(
                 projection_view,
                 parallax_attenuation,
             );
+            let base_health = if *enemy_type == EnemyType::Spidomonsta {
+                ENEMY_SPIDOMONSTA_BASE_HEALTH
+            } else {
+                ENEMY_SPIDEY_BASE_HEALTH
+            };
             let entity = commands
                 .spawn((
                     SpideyBundle {
@@ -419,10 +429,8 @@ pub fn spawn_enemy(
                         ),
                         composed_visual: ComposedEnemyVisual::for_enemy(
                             asset_server,
-                            EnemyType::Spidey,
-                            EnemyType::Spidey
-                                .composed_authored_depth()
-                                .unwrap_or(*depth),
+                            *enemy_type,
+                            enemy_type.composed_authored_depth().unwrap_or(*depth),
                         ),
                         transform: Transform::default(),
                         global_transform: GlobalTransform::default(),
@@ -435,7 +443,8 @@ pub fn spawn_enemy(
                         position: WorldPos::from(position),
                         speed: Speed(*speed),
                         default: SpideyDefaultBundle {
-                            health: Health(health.unwrap_or(ENEMY_SPIDEY_BASE_HEALTH)),
+                            name,
+                            health: Health(health.unwrap_or(base_health)),
                             ..SpideyDefaultBundle::default()
                         },
                     },
@@ -446,9 +455,19 @@ pub fn spawn_enemy(
                 ))
                 .id();
 
-            commands
-                .entity(entity)
-                .insert(EnemySpideyAttacking::default());
+            if *enemy_type == EnemyType::Spidomonsta {
+                commands.entity(entity).insert((
+                    EnemySpidomonsta,
+                    EnemySpideyAttacking {
+                        cooldown: SpidomonstaPhase::get(0).attack_cooldown,
+                        ..default()
+                    },
+                ));
+            } else {
+                commands
+                    .entity(entity)
+                    .insert(EnemySpideyAttacking::default());
+            }
 
             if let Some(depth_fallback) = initial_presentation.depth_fallback {
                 commands.entity(entity).insert(depth_fallback);
@@ -474,7 +493,7 @@ pub fn spawn_enemy(
             );
             entity
         }
-        EnemyType::Kyle | EnemyType::Marauder | EnemyType::Spidomonsta => commands
+        EnemyType::Kyle | EnemyType::Marauder => commands
             .spawn((name, Enemy, continuous_depth, behaviors, authored))
             .id(),
         EnemyType::Tardigrade => {
@@ -502,7 +521,11 @@ pub fn spawn_enemy(
                 ))
                 .id()
         }
+    };
+    if enemy_type.is_boss() {
+        commands.entity(entity).insert(Boss);
     }
+    entity
 }
 
 /// Spawns a destructible entity with its animation bundle.
@@ -590,6 +613,7 @@ pub fn check_dead_drop(
     projection_view: Option<Res<ProjectionView>>,
     parallax_attenuation: Option<Res<ActiveParallaxAttenuation>>,
     mut attack_query: Query<&mut AttackHitTracker, With<PlayerAttack>>,
+    mut stop_targets_query: Query<&mut StopStepTargets, With<Stage>>,
     query: Query<(&SpawnDrop, &WorldPos, &Depth), Added<Dead>>,
 ) {
     for (spawn_drop, position, depth) in &mut query.iter() {
@@ -620,17 +644,23 @@ pub fn check_dead_drop(
                 // TODO: in zero-gravity (space), pickup just appears in place.
                 spawned
             }
-            ContainerSpawn::Enemy(spawn) => spawn_enemy(
-                &mut commands,
-                &asset_server,
-                Vec2::ZERO,
-                &spawn.from_spawn(position.0, *depth),
-                &active_floors,
-                &depth_scale_config,
-                active_projection.as_deref(),
-                projection_view.as_deref(),
-                parallax_attenuation.as_ref().map(|a| a.0),
-            ),
+            ContainerSpawn::Enemy(spawn) => {
+                let spawned = spawn_enemy(
+                    &mut commands,
+                    &asset_server,
+                    Vec2::ZERO,
+                    &spawn.from_spawn(position.0, *depth),
+                    &active_floors,
+                    &depth_scale_config,
+                    active_projection.as_deref(),
+                    projection_view.as_deref(),
+                    parallax_attenuation.as_ref().map(|a| a.0),
+                );
+                if let Ok(mut stop_targets) = stop_targets_query.single_mut() {
+                    stop_targets.enemies.push(spawned);
+                }
+                spawned
+            }
         };
 
         for mut hit_tracker in &mut attack_query.iter_mut() {
@@ -5,7 +5,7 @@ mod systems;
 
 use self::{
     components::{ClearedScreen, InfoText, UIBackground},
-    input::{ClearScreenInput, init_input},
+    input::ClearScreenInput,
     messages::ClearScreenShutdownMessage,
     systems::check_press_continue_input,
 };
@@ -25,7 +25,7 @@ use carcinisation_base::layer::MenuLayer;
 use carcinisation_core::globals::SCREEN_RESOLUTION_F32_H;
 use carcinisation_core::globals::SCREEN_RESOLUTION_H;
 use carcinisation_core::globals::mark_for_despawn_by_query;
-use leafwing_input_manager::plugin::InputManagerPlugin;
+use carcinisation_input::GBActionsPlugin;
 
 pub fn render_cleared_screen(
     mut commands: Commands,
@@ -125,8 +125,7 @@ pub struct ClearedScreenPlugin;
 impl Plugin for ClearedScreenPlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<ClearScreenShutdownMessage>()
-            .add_plugins(InputManagerPlugin::<ClearScreenInput>::default())
-            .add_systems(Startup, init_input)
+            .add_plugins(GBActionsPlugin::<ClearScreenInput>::default())
             .add_active_systems::<StageUiPlugin, _>((render_cleared_screen, despawn_cleared_screen))
             .add_active_systems_in::<StageUiPlugin, _>(
                 PostUpdate,
@@ -1,20 +1,16 @@
-use bevy::prelude::*;
 use bevy::reflect::Reflect;
-use carcinisation_input::GBInput;
+use carcinisation_input::{GBActions, GBInput};
 use leafwing_input_manager::Actionlike;
-use leafwing_input_manager::{action_state::ActionState, input_map::InputMap};
 
 #[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
 pub enum ClearScreenInput {
     Continue,
 }
 
-pub fn init_input(mut commands: Commands) {
-    let ms: Vec<(ClearScreenInput, KeyCode)> = vec![
-        (ClearScreenInput::Continue, GBInput::B.into()),
-        (ClearScreenInput::Continue, GBInput::A.into()),
-        (ClearScreenInput::Continue, GBInput::Start.into()),
+impl GBActions for ClearScreenInput {
+    const GB_ACTIONS: &'static [(Self, GBInput)] = &[
+        (Self::Continue, GBInput::B),
+        (Self::Continue, GBInput::A),
+        (Self::Continue, GBInput::Start),
     ];
-    commands.insert_resource(ActionState::<ClearScreenInput>::default());
-    commands.insert_resource(InputMap::<ClearScreenInput>::new(ms));
 }
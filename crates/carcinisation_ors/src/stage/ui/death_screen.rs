@@ -5,7 +5,7 @@ mod systems;
 
 use self::{
     components::{CurrentScoreText, DeathScreen, InfoText, UIBackground},
-    input::{DeathScreenInput, init_input},
+    input::DeathScreenInput,
     messages::DeathScreenRestartMessage,
     systems::{check_press_continue_input, handle_death_screen_continue},
 };
@@ -26,7 +26,7 @@ use carcinisation_base::layer::MenuLayer;
 use carcinisation_core::globals::SCREEN_RESOLUTION_F32_H;
 use carcinisation_core::globals::SCREEN_RESOLUTION_H;
 use carcinisation_core::globals::mark_for_despawn_by_query;
-use leafwing_input_manager::plugin::InputManagerPlugin;
+use carcinisation_input::GBActionsPlugin;
 
 pub fn render_death_screen(
     mut commands: Commands,
@@ -127,8 +127,7 @@ pub struct DeathScreenPlugin;
 impl Plugin for DeathScreenPlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<DeathScreenRestartMessage>()
-            .add_plugins(InputManagerPlugin::<DeathScreenInput>::default())
-            .add_systems(Startup, init_input)
+            .add_plugins(GBActionsPlugin::<DeathScreenInput>::default())
             .add_active_systems::<StageUiPlugin, _>((render_death_screen, despawn_death_screen))
             .add_active_systems_in::<StageUiPlugin, _>(
                 PostUpdate,
@@ -1,20 +1,16 @@
-use bevy::prelude::*;
 use bevy::reflect::Reflect;
-use carcinisation_input::GBInput;
+use carcinisation_input::{GBActions, GBInput};
 use leafwing_input_manager::Actionlike;
-use leafwing_input_manager::{action_state::ActionState, input_map::InputMap};
 
 #[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
 pub enum DeathScreenInput {
     Restart,
 }
 
-pub fn init_input(mut commands: Commands) {
-    let ms: Vec<(DeathScreenInput, KeyCode)> = vec![
-        (DeathScreenInput::Restart, GBInput::B.into()),
-        (DeathScreenInput::Restart, GBInput::A.into()),
-        (DeathScreenInput::Restart, GBInput::Start.into()),
+impl GBActions for DeathScreenInput {
+    const GB_ACTIONS: &'static [(Self, GBInput)] = &[
+        (Self::Restart, GBInput::B),
+        (Self::Restart, GBInput::A),
+        (Self::Restart, GBInput::Start),
     ];
-    commands.insert_resource(ActionState::<DeathScreenInput>::default());
-    commands.insert_resource(InputMap::<DeathScreenInput>::new(ms));
 }
@@ -5,7 +5,7 @@ mod systems;
 
 use self::{
     components::{FinalScoreText, GameOverScreen, InfoText, UIBackground},
-    input::{GameOverScreenInput, init_input},
+    input::GameOverScreenInput,
     messages::GameOverScreenShutdownMessage,
     systems::{check_press_continue_input, handle_game_over_screen_continue},
 };
@@ -25,7 +25,7 @@ use carcinisation_base::layer::MenuLayer;
 use carcinisation_core::globals::SCREEN_RESOLUTION_F32_H;
 use carcinisation_core::globals::SCREEN_RESOLUTION_H;
 use carcinisation_core::globals::mark_for_despawn_by_query;
-use leafwing_input_manager::plugin::InputManagerPlugin;
+use carcinisation_input::GBActionsPlugin;
 
 pub fn render_game_over_screen(
     mut commands: Commands,
@@ -126,8 +126,7 @@ pub struct GameOverScreenPlugin;
 impl Plugin for GameOverScreenPlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<GameOverScreenShutdownMessage>()
-            .add_plugins(InputManagerPlugin::<GameOverScreenInput>::default())
-            .add_systems(Startup, init_input)
+            .add_plugins(GBActionsPlugin::<GameOverScreenInput>::default())
             .add_active_systems::<StageUiPlugin, _>((
                 render_game_over_screen,
                 despawn_game_over_screen,
@@ -1,20 +1,16 @@
-use bevy::prelude::*;
 use bevy::reflect::Reflect;
-use carcinisation_input::GBInput;
+use carcinisation_input::{GBActions, GBInput};
 use leafwing_input_manager::Actionlike;
-use leafwing_input_manager::{action_state::ActionState, input_map::InputMap};
 
 #[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
 pub enum GameOverScreenInput {
     BackToMenu,
 }
 
-pub fn init_input(mut commands: Commands) {
-    let ms: Vec<(GameOverScreenInput, KeyCode)> = vec![
-        (GameOverScreenInput::BackToMenu, GBInput::B.into()),
-        (GameOverScreenInput::BackToMenu, GBInput::A.into()),
-        (GameOverScreenInput::BackToMenu, GBInput::Start.into()),
+impl GBActions for GameOverScreenInput {
+    const GB_ACTIONS: &'static [(Self, GBInput)] = &[
+        (Self::BackToMenu, GBInput::B),
+        (Self::BackToMenu, GBInput::A),
+        (Self::BackToMenu, GBInput::Start),
     ];
-    commands.insert_resource(ActionState::<GameOverScreenInput>::default());
-    commands.insert_resource(InputMap::<GameOverScreenInput>::new(ms));
 }
@@ -2,18 +2,25 @@ pub mod components;
 pub mod spawn;
 
 use crate::stage::{
-    components::interactive::Health, player::components::Player, ui::hud::components::HealthText,
+    components::interactive::{Dead, Health},
+    enemy::{boss::BossPhases, composed::ComposedHealthPools},
+    player::components::Player,
+    ui::hud::{
+        components::{BossHealthBar, HealthText},
+        spawn::HUD_BOSS_BAR_SIZE,
+    },
 };
 use activable::{Activable, ActivableAppExt};
 use bevy::prelude::*;
 use carapace::prelude::CxText;
+use carapace::primitive::{CxPrimitive, CxPrimitiveShape};
 
 #[derive(Activable)]
 pub struct HudPlugin;
 
 impl Plugin for HudPlugin {
     fn build(&self, app: &mut App) {
-        app.add_active_systems::<Self, _>(update_health_text);
+        app.add_active_systems::<Self, _>((update_health_text, update_boss_health_bar));
     }
 }
 
@@ -28,3 +35,29 @@ pub fn update_health_text(
         text.value = health.0.to_string();
     }
 }
+
+/// @system Sizes the boss bar to the first living boss's remaining health
+/// across all its phases, hiding it when no boss is alive.
+pub fn update_boss_health_bar(
+    mut bar_query: Query<(&mut CxPrimitive, &mut Visibility), With<BossHealthBar>>,
+    boss_query: Query<(&BossPhases, &ComposedHealthPools), Without<Dead>>,
+) {
+    let fraction = boss_query
+        .iter()
+        .next()
+        .map_or(0.0, |(phases, pools)| phases.health_fraction(pools));
+    let width = (HUD_BOSS_BAR_SIZE.x as f32 * fraction).ceil() as u32;
+    for (mut primitive, mut visibility) in &mut bar_query {
+        let shape = CxPrimitiveShape::Rect {
+            size: UVec2::new(width, HUD_BOSS_BAR_SIZE.y),
+        };
+        if primitive.shape != shape {
+            primitive.shape = shape;
+        }
+        visibility.set_if_neq(if width == 0 {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        });
+    }
+}
@@ -11,3 +11,7 @@ pub struct HealthIcon;
 
 #[derive(Component)]
 pub struct UIBackground;
+
+/// HUD bar showing the remaining health of the boss being fought.
+#[derive(Component)]
+pub struct BossHealthBar;
@@ -1,4 +1,4 @@
-use super::components::{HealthIcon, HealthText, Hud, UIBackground};
+use super::components::{BossHealthBar, HealthIcon, HealthText, Hud, UIBackground};
 use crate::stage::ui::typeface::load_inverted_typeface;
 use crate::{
     assets::CxAssets,
@@ -22,6 +22,8 @@ pub(super) const LAYOUT_Y: i32 = 3;
 pub(super) const HUD_BOTTOM_Y: i32 = SCREEN_RESOLUTION.y as i32 - HUD_HEIGHT as i32;
 pub(super) const HUD_HEALTH_ICON_X: f32 = 8.0;
 pub(super) const HUD_HEALTH_TEXT_X: i32 = 48;
+pub(super) const HUD_BOSS_BAR_X: i32 = 58;
+pub(super) const HUD_BOSS_BAR_SIZE: UVec2 = UVec2::new(44, 4);
 
 // pub(super) const HUD_HEALTH_LAYOUT_Y: i32 = HUD_BOTTOM_Y + LAYOUT_Y;
 
@@ -98,6 +100,25 @@ pub fn spawn_hud(
                         ),
                     ],
                 ),
+                (
+                    CxPrimitive {
+                        shape: CxPrimitiveShape::Rect {
+                            size: HUD_BOSS_BAR_SIZE,
+                        },
+                        fill: CxPrimitiveFill::Solid(1),
+                    },
+                    CxAnchor::BottomLeft,
+                    CxRenderSpace::Camera,
+                    CxPosition::from(IVec2::new(
+                        HUD_BOSS_BAR_X,
+                        (HUD_HEIGHT - HUD_BOSS_BAR_SIZE.y) as i32 / 2,
+                    )),
+                    Layer::Ors(OrsLayer::Hud),
+                    // Shown by `update_boss_health_bar` while a boss is alive.
+                    Visibility::Hidden,
+                    BossHealthBar,
+                    Name::new("BossHealthBar"),
+                ),
                 (
                     Name::new("Score"),
                     Visibility::Visible,
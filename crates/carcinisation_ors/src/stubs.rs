@@ -45,12 +45,6 @@ pub const DEFAULT_CROSSHAIR_INDEX: u8 = 1;
 
 pub use carcinisation_cutscene::data::{CutsceneAnimationSpawn, CutsceneAnimationsSpawn};
 
-// ---------------------------------------------------------------------------
-// Systems / spawn — resolved: now in carcinisation_core::components
-// ---------------------------------------------------------------------------
-
-pub use carcinisation_core::components::make_music_bundle;
-
 // ---------------------------------------------------------------------------
 // Movement — resolved: now in carcinisation_core::globals
 // ---------------------------------------------------------------------------
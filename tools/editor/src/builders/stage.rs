@@ -137,7 +137,7 @@ pub fn spawn_path(
     for (index, step) in stage_data.steps.iter().enumerate() {
         match step {
             StageStep::Cinematic(s) => {
-                current_elapsed += cinematic_duration(s, timeline_config);
+                current_elapsed += cinematic_duration(s);
             }
             StageStep::Tween(s) => {
                 path = path.line_to(s.coordinates + h_screen_resolution);
@@ -499,7 +499,7 @@ pub fn spawn_stage(
     for (index, step) in stage_data.steps.iter().enumerate() {
         match step {
             StageStep::Cinematic(s) => {
-                current_elapsed += cinematic_duration(s, timeline_config);
+                current_elapsed += cinematic_duration(s);
             }
             StageStep::Tween(s) => {
                 let step_started = stage_controls_ui.elapsed_duration >= current_elapsed;
@@ -303,6 +303,13 @@ fn stage_inspector(world: &mut World, ui: &mut egui::Ui, elapsed: Duration) {
 
     ui.add_space(4.0);
     changed |= projection_override_fields(ui, "stage_projection", &mut stage.projection);
+    changed |= reflected_field_narrow(
+        &mut env,
+        ui,
+        "projection_source",
+        140.0,
+        &mut stage.projection_source,
+    );
 
     // Effective projection summary at current scrub position.
     {
@@ -675,8 +682,14 @@ fn projection_source_label(
     stage: &carcinisation_ors::stage::data::StageData,
     step_index: usize,
 ) -> String {
-    use carcinisation_ors::stage::data::StageStep;
+    use carcinisation_ors::stage::{data::StageStep, projection::ProjectionSource};
 
+    match stage.projection_source {
+        Some(ProjectionSource::Static(_)) => return "static source".into(),
+        Some(ProjectionSource::CameraFollow { .. }) => return "camera follow".into(),
+        Some(ProjectionSource::Keyframed(_)) => return "keyframed".into(),
+        None => {}
+    }
     let limit = step_index.min(stage.steps.len().saturating_sub(1));
     for i in (0..=limit).rev() {
         let has_proj = match &stage.steps[i] {
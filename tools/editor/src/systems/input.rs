@@ -831,6 +831,11 @@ pub fn on_mouse_drag(
                 && let Some(ref controls) = controls
                 && let SceneData::Stage(stage_data) = scene_data.bypass_change_detection()
             {
+                // A projection source replaces the step profiles, so there is
+                // no step override to edit.
+                if stage_data.projection_source.is_some() {
+                    continue;
+                }
                 let info = carcinisation_ors::stage::projection::walk_steps_at_elapsed(
                     stage_data,
                     controls.elapsed_duration,
@@ -1468,6 +1473,7 @@ mod tests {
             on_end_transition_o: None,
             gravity: None,
             projection: None,
+            projection_source: None,
             checkpoint: None,
             parallax_attenuation: None,
             primitives: vec![],
@@ -1609,6 +1615,7 @@ mod tests {
             on_end_transition_o: None,
             gravity: None,
             projection: None,
+            projection_source: None,
             checkpoint: None,
             parallax_attenuation: None,
             primitives: vec![],
@@ -1881,6 +1888,7 @@ mod tests {
             on_end_transition_o: None,
             gravity: None,
             projection: None,
+            projection_source: None,
             checkpoint: None,
             parallax_attenuation: None,
             primitives: vec![],
@@ -2010,6 +2018,7 @@ mod tests {
             on_end_transition_o: None,
             gravity: None,
             projection: None,
+            projection_source: None,
             checkpoint: None,
             parallax_attenuation: None,
             primitives: vec![],
@@ -1,24 +1,20 @@
 use std::time::Duration;
 
 use bevy::math::Vec2;
-use carcinisation_cutscene::data::CutsceneAnimationsSpawn;
 use carcinisation_ors::stage::components::{CinematicStageStep, StopStageStep, TweenStageStep};
 use carcinisation_ors::stage::data::{StageData, StageStep};
 
 /// Configures how stage steps contribute to timeline durations.
 #[derive(Clone, Copy, Debug)]
-#[allow(clippy::struct_excessive_bools)]
 pub struct StageTimelineConfig {
     pub include_spawn_delays: bool,
     pub include_stop_durations: bool,
-    pub include_cinematic_durations: bool,
 }
 
 impl StageTimelineConfig {
     pub const SLIDER: Self = Self {
         include_spawn_delays: false,
         include_stop_durations: true,
-        include_cinematic_durations: true,
     };
 }
 
@@ -104,16 +100,11 @@ pub const fn stop_duration(step: &StopStageStep, config: StageTimelineConfig) ->
     }
 }
 
-/// Duration of a cinematic step derived from cutscene spawn durations.
-pub fn cinematic_duration(step: &CinematicStageStep, config: StageTimelineConfig) -> Duration {
-    if !config.include_cinematic_durations {
-        return Duration::ZERO;
-    }
-    match step {
-        CinematicStageStep::CutsceneAnimationSpawn(CutsceneAnimationsSpawn { spawns }) => spawns
-            .iter()
-            .fold(Duration::ZERO, |total, spawn| total + spawn.duration),
-    }
+/// Duration of a cinematic step. Cinematics hold the stage clock while their
+/// cutscene plays, so they take no stage time — matching the runtime
+/// projection walk.
+pub const fn cinematic_duration(_step: &CinematicStageStep) -> Duration {
+    Duration::ZERO
 }
 
 /// Computes the duration of a step and advances the current position when needed.
@@ -142,6 +133,6 @@ fn step_duration(
             }
             duration
         }
-        StageStep::Cinematic(cinematic_step) => cinematic_duration(cinematic_step, config),
+        StageStep::Cinematic(cinematic_step) => cinematic_duration(cinematic_step),
     }
 }